@@ -1,552 +1,608 @@
-use anchor_lang::prelude::*;
-use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId, Throughput};
+//! Deterministic benchmark harness for the Universal NFT program
+//!
+//! Runs the compiled program inside solana-program-test and records, for
+//! each measured instruction, the compute units it consumed and the size and
+//! rent of every account it creates. Results are written as JSON and
+//! compared against the committed baseline.
+//!
+//!   anchor build
+//!   cargo bench --bench performance_suite              # compare to baseline
+//!   cargo bench --bench performance_suite -- --update  # rewrite baseline
+//!
+//! The bench needs solana-program-test, tokio, serde_json and bincode as
+//! dev-dependencies. mpl_token_metadata.so must be in BPF_OUT_DIR (or
+//! tests/fixtures), dumped with
+//! `solana program dump metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s`.
+//! The ZetaChain gateway is replaced by a stub so bridge numbers only cover
+//! this program; the stub forwards on_call the way the real gateway does.
+
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address;
+use serde_json::{json, Map, Value};
 use solana_program_test::*;
 use solana_sdk::{
-    signature::{Keypair, Signer},
+    account::Account,
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+    pubkey::Pubkey,
+    signature::{keypair_from_seed, Keypair, Signer},
+    system_program, sysvar,
     transaction::Transaction,
-    instruction::Instruction,
 };
-use std::time::{Duration, Instant};
-use std::collections::HashMap;
-
-/// Comprehensive Performance Benchmarking Suite for Universal NFT Program
-/// Tests all critical paths under various load conditions
-
-pub struct BenchmarkConfig {
-    pub iterations: usize,
-    pub concurrent_operations: usize,
-    pub data_sizes: Vec<usize>,
-    pub chain_combinations: Vec<(u64, u64)>,
-}
-
-impl Default for BenchmarkConfig {
-    fn default() -> Self {
-        Self {
-            iterations: 1000,
-            concurrent_operations: 10,
-            data_sizes: vec![100, 500, 1000, 5000],
-            chain_combinations: vec![
-                (900, 1),    // Solana -> Ethereum
-                (900, 56),   // Solana -> BSC  
-                (900, 7000), // Solana -> ZetaChain
-                (1, 900),    // Ethereum -> Solana
-                (56, 900),   // BSC -> Solana
-                (7000, 900), // ZetaChain -> Solana
-            ],
+use std::collections::BTreeMap;
+use universal_nft::state::{AddressFormat, ChainKind, CrossChainMessage, GasPayment};
+use universal_nft::utils::MessageCodec;
+
+const BASELINE_PATH: &str = "benchmarks/baseline.json";
+const RESULTS_PATH: &str = "target/benchmarks/results.json";
+
+/// Allowed compute unit growth over the baseline before a run fails
+const CU_REGRESSION_TOLERANCE_PCT: u64 = 2;
+
+const DESTINATION_CHAIN_ID: u64 = 1;
+const SOURCE_CHAIN_ID: u64 = 7000;
+const GAS_LIMIT: u64 = 100_000;
+
+/// Stand-in gateway program id; only has to differ from real programs
+const GATEWAY_PROGRAM_ID: Pubkey = Pubkey::new_from_array([7u8; 32]);
+
+/// Gateway stub. Outbound calls carry no accounts and are accepted; an
+/// inbound delivery lists the Universal NFT program first and is forwarded to
+/// it as a CPI, since on_call only accepts calls made by the gateway.
+fn gateway_stub(_program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    match accounts.split_first() {
+        Some((program, forwarded)) if *program.key == universal_nft::ID => {
+            let instruction = Instruction {
+                program_id: universal_nft::ID,
+                accounts: forwarded
+                    .iter()
+                    .map(|account| AccountMeta {
+                        pubkey: *account.key,
+                        is_signer: account.is_signer,
+                        is_writable: account.is_writable,
+                    })
+                    .collect(),
+                data: data.to_vec(),
+            };
+            invoke(&instruction, accounts)
         }
+        _ => Ok(()),
     }
 }
 
-pub struct PerformanceMetrics {
-    pub operation_type: String,
-    pub avg_latency_ms: f64,
-    pub min_latency_ms: f64,
-    pub max_latency_ms: f64,
-    pub p95_latency_ms: f64,
-    pub p99_latency_ms: f64,
-    pub throughput_ops_per_sec: f64,
-    pub success_rate: f64,
-    pub compute_units_used: u64,
-    pub memory_usage_bytes: u64,
-    pub error_count: u64,
+/// Measurements for a single instruction
+pub struct InstructionMetrics {
+    pub compute_units: u64,
+    pub transaction_size: usize,
+    /// Created account name -> (data length, rent-exempt lamports)
+    pub accounts: BTreeMap<String, (usize, u64)>,
 }
 
-pub struct BenchmarkSuite {
-    config: BenchmarkConfig,
-    results: HashMap<String, PerformanceMetrics>,
-}
+impl InstructionMetrics {
+    fn to_json(&self) -> Value {
+        let accounts: Map<String, Value> = self
+            .accounts
+            .iter()
+            .map(|(name, (size, lamports))| {
+                (name.clone(), json!({ "size_bytes": size, "rent_lamports": lamports }))
+            })
+            .collect();
 
-impl BenchmarkSuite {
-    pub fn new(config: BenchmarkConfig) -> Self {
-        Self {
-            config,
-            results: HashMap::new(),
-        }
+        json!({
+            "compute_units": self.compute_units,
+            "transaction_size": self.transaction_size,
+            "accounts": accounts,
+        })
     }
+}
 
-    /// Benchmark NFT minting performance
-    pub async fn benchmark_nft_minting(&mut self, c: &mut Criterion) {
-        let mut group = c.benchmark_group("nft_minting");
-        
-        for data_size in &self.config.data_sizes {
-            group.throughput(Throughput::Elements(*data_size as u64));
-            
-            group.bench_with_input(
-                BenchmarkId::new("mint_with_metadata", data_size),
-                data_size,
-                |b, &size| {
-                    b.iter(|| {
-                        black_box(self.simulate_mint_operation(size))
-                    })
-                },
-            );
-        }
-        
-        group.finish();
-    }
+pub struct BenchmarkHarness {
+    context: ProgramTestContext,
+    // Fixed keys keep PDA bump searches, and so compute units, identical
+    // between runs
+    owner: Keypair,
+    mint: Keypair,
+    results: BTreeMap<String, InstructionMetrics>,
+}
 
-    /// Benchmark cross-chain transfer performance
-    pub async fn benchmark_cross_chain_transfers(&mut self, c: &mut Criterion) {
-        let mut group = c.benchmark_group("cross_chain_transfers");
-        
-        for (source_chain, dest_chain) in &self.config.chain_combinations {
-            let bench_name = format!("transfer_{}_{}", source_chain, dest_chain);
-            
-            group.bench_function(&bench_name, |b| {
-                b.iter(|| {
-                    black_box(self.simulate_cross_chain_transfer(*source_chain, *dest_chain))
-                })
-            });
-        }
-        
-        group.finish();
-    }
+impl BenchmarkHarness {
+    pub async fn new() -> Self {
+        let owner = keypair_from_seed(&[1u8; 32]).unwrap();
+        let mint = keypair_from_seed(&[2u8; 32]).unwrap();
 
-    /// Benchmark signature verification performance
-    pub async fn benchmark_signature_verification(&mut self, c: &mut Criterion) {
-        let mut group = c.benchmark_group("signature_verification");
-        
-        // Single signature verification
-        group.bench_function("single_signature", |b| {
-            b.iter(|| {
-                black_box(self.simulate_signature_verification(1))
-            })
-        });
-        
-        // Batch signature verification
-        for batch_size in &[5, 10, 20, 50, 100] {
-            group.bench_with_input(
-                BenchmarkId::new("batch_signatures", batch_size),
-                batch_size,
-                |b, &size| {
-                    b.iter(|| {
-                        black_box(self.simulate_signature_verification(size))
-                    })
-                },
-            );
+        let mut program_test = ProgramTest::new("universal_nft", universal_nft::ID, None);
+        program_test.prefer_bpf(true);
+        program_test.add_program("mpl_token_metadata", mpl_token_metadata::ID, None);
+        program_test.add_program("zeta_gateway", GATEWAY_PROGRAM_ID, processor!(gateway_stub));
+        program_test.add_account(
+            owner.pubkey(),
+            Account::new(100_000_000_000, 0, &system_program::ID),
+        );
+
+        Self {
+            context: program_test.start_with_context().await,
+            owner,
+            mint,
+            results: BTreeMap::new(),
         }
-        
-        group.finish();
     }
 
-    /// Benchmark fraud detection system
-    pub async fn benchmark_fraud_detection(&mut self, c: &mut Criterion) {
-        let mut group = c.benchmark_group("fraud_detection");
-        
-        // Normal operation analysis
-        group.bench_function("normal_operation", |b| {
-            b.iter(|| {
-                black_box(self.simulate_fraud_analysis(false))
-            })
-        });
-        
-        // Suspicious operation analysis
-        group.bench_function("suspicious_operation", |b| {
-            b.iter(|| {
-                black_box(self.simulate_fraud_analysis(true))
-            })
-        });
-        
-        // Batch analysis
-        for batch_size in &[10, 50, 100, 500] {
-            group.bench_with_input(
-                BenchmarkId::new("batch_analysis", batch_size),
-                batch_size,
-                |b, &size| {
-                    b.iter(|| {
-                        black_box(self.simulate_batch_fraud_analysis(size))
-                    })
-                },
-            );
-        }
-        
-        group.finish();
+    fn config_pda() -> Pubkey {
+        Pubkey::find_program_address(&[b"config"], &universal_nft::ID).0
     }
 
-    /// Benchmark circuit breaker performance
-    pub async fn benchmark_circuit_breaker(&mut self, c: &mut Criterion) {
-        let mut group = c.benchmark_group("circuit_breaker");
-        
-        // Check operation allowed (normal state)
-        group.bench_function("check_allowed_normal", |b| {
-            b.iter(|| {
-                black_box(self.simulate_circuit_breaker_check(false))
-            })
-        });
-        
-        // Check operation allowed (under load)
-        group.bench_function("check_allowed_load", |b| {
-            b.iter(|| {
-                black_box(self.simulate_circuit_breaker_check(true))
-            })
-        });
-        
-        group.finish();
+    fn chain_pda(chain_id: u64) -> Pubkey {
+        Pubkey::find_program_address(&[b"chain", &chain_id.to_le_bytes()], &universal_nft::ID).0
     }
 
-    /// Benchmark memory usage patterns
-    pub async fn benchmark_memory_usage(&mut self, c: &mut Criterion) {
-        let mut group = c.benchmark_group("memory_usage");
-        
-        for account_count in &[1, 10, 100, 1000] {
-            group.bench_with_input(
-                BenchmarkId::new("account_creation", account_count),
-                account_count,
-                |b, &count| {
-                    b.iter(|| {
-                        black_box(self.simulate_account_creation(count))
-                    })
-                },
-            );
-        }
-        
-        group.finish();
+    fn universal_nft_pda(&self) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"universal_nft", self.mint.pubkey().as_ref()],
+            &universal_nft::ID,
+        )
+        .0
     }
 
-    /// Benchmark concurrent operations
-    pub async fn benchmark_concurrency(&mut self, c: &mut Criterion) {
-        let mut group = c.benchmark_group("concurrency");
-        
-        for thread_count in &[1, 2, 4, 8, 16] {
-            group.bench_with_input(
-                BenchmarkId::new("concurrent_mints", thread_count),
-                thread_count,
-                |b, &threads| {
-                    b.iter(|| {
-                        black_box(self.simulate_concurrent_operations(threads))
-                    })
-                },
-            );
+    fn metadata_pda(&self, edition: bool) -> Pubkey {
+        let mint = self.mint.pubkey();
+        let mut seeds: Vec<&[u8]> = vec![b"metadata", mpl_token_metadata::ID.as_ref(), mint.as_ref()];
+        if edition {
+            seeds.push(b"edition");
         }
-        
-        group.finish();
+        Pubkey::find_program_address(&seeds, &mpl_token_metadata::ID).0
     }
 
-    /// Benchmark compute unit usage optimization
-    pub async fn benchmark_compute_optimization(&mut self, c: &mut Criterion) {
-        let mut group = c.benchmark_group("compute_optimization");
-        
-        group.bench_function("optimized_mint", |b| {
-            b.iter(|| {
-                black_box(self.simulate_optimized_mint())
-            })
-        });
-        
-        group.bench_function("optimized_transfer", |b| {
-            b.iter(|| {
-                black_box(self.simulate_optimized_transfer())
-            })
-        });
-        
-        group.bench_function("optimized_verification", |b| {
-            b.iter(|| {
-                black_box(self.simulate_optimized_verification())
-            })
-        });
-        
-        group.finish();
+    /// Send a transaction without measuring it
+    async fn execute(&mut self, instruction: Instruction, extra_signers: &[&Keypair]) {
+        let transaction = self.sign(instruction, extra_signers).await;
+        self.context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .expect("setup transaction failed");
     }
 
-    /// Load testing under stress conditions
-    pub async fn benchmark_stress_test(&mut self, c: &mut Criterion) {
-        let mut group = c.benchmark_group("stress_test");
-        group.sample_size(10); // Fewer samples for stress tests
-        
-        // High-frequency operations
-        group.bench_function("high_frequency_mints", |b| {
-            b.iter(|| {
-                black_box(self.simulate_high_frequency_operations(1000))
-            })
-        });
-        
-        // Large batch operations
-        group.bench_function("large_batch_transfers", |b| {
-            b.iter(|| {
-                black_box(self.simulate_large_batch_operations(500))
-            })
-        });
-        
-        // Memory pressure test
-        group.bench_function("memory_pressure", |b| {
-            b.iter(|| {
-                black_box(self.simulate_memory_pressure_test())
-            })
-        });
-        
-        group.finish();
+    async fn sign(&mut self, instruction: Instruction, extra_signers: &[&Keypair]) -> Transaction {
+        let blockhash = self.context.get_new_latest_blockhash().await.unwrap();
+        let mut signers: Vec<&Keypair> = vec![&self.owner];
+        signers.extend_from_slice(extra_signers);
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&self.owner.pubkey()),
+            &signers,
+            blockhash,
+        )
     }
 
-    // Simulation methods (would interface with actual program in real implementation)
-    
-    fn simulate_mint_operation(&self, metadata_size: usize) -> Duration {
-        let start = Instant::now();
-        
-        // Simulate compute-intensive operations
-        let mut hash = 0u64;
-        for i in 0..metadata_size {
-            hash = hash.wrapping_mul(31).wrapping_add(i as u64);
+    /// Simulate for compute units, then execute and size the created accounts
+    async fn measure(
+        &mut self,
+        name: &str,
+        instruction: Instruction,
+        extra_signers: &[&Keypair],
+        created: &[(&str, Pubkey)],
+    ) {
+        let transaction = self.sign(instruction, extra_signers).await;
+        let transaction_size = bincode::serialize(&transaction).unwrap().len();
+
+        let simulation = self
+            .context
+            .banks_client
+            .simulate_transaction(transaction.clone())
+            .await
+            .unwrap();
+        if let Some(Err(err)) = simulation.result {
+            let logs = simulation.simulation_details.map(|d| d.logs).unwrap_or_default();
+            panic!("{} failed: {:?}\n{}", name, err, logs.join("\n"));
         }
-        
-        // Simulate network latency
-        std::thread::sleep(Duration::from_micros(100 + (metadata_size / 10) as u64));
-        
-        black_box(hash);
-        start.elapsed()
-    }
-
-    fn simulate_cross_chain_transfer(&self, source: u64, dest: u64) -> Duration {
-        let start = Instant::now();
-        
-        // Simulate signature verification
-        std::thread::sleep(Duration::from_micros(500));
-        
-        // Simulate cross-chain message preparation
-        let message_complexity = (source + dest) % 100;
-        std::thread::sleep(Duration::from_micros(200 + message_complexity));
-        
-        // Simulate gateway interaction
-        std::thread::sleep(Duration::from_micros(1000));
-        
-        start.elapsed()
-    }
-
-    fn simulate_signature_verification(&self, batch_size: usize) -> Duration {
-        let start = Instant::now();
-        
-        // Simulate ECDSA verification (computationally intensive)
-        for _ in 0..batch_size {
-            let mut verification_work = 0u64;
-            for i in 0..1000 {
-                verification_work = verification_work.wrapping_mul(17).wrapping_add(i);
-            }
-            black_box(verification_work);
+        let compute_units = simulation
+            .simulation_details
+            .expect("simulation details missing")
+            .units_consumed;
+
+        self.context
+            .banks_client
+            .process_transaction(transaction)
+            .await
+            .unwrap();
+
+        let mut accounts = BTreeMap::new();
+        for (account_name, address) in created {
+            let account = self
+                .context
+                .banks_client
+                .get_account(*address)
+                .await
+                .unwrap()
+                .unwrap_or_else(|| panic!("{} did not create {}", name, account_name));
+            accounts.insert(account_name.to_string(), (account.data.len(), account.lamports));
         }
-        
-        start.elapsed()
-    }
 
-    fn simulate_fraud_analysis(&self, is_suspicious: bool) -> Duration {
-        let start = Instant::now();
-        
-        // Simulate pattern analysis
-        let analysis_complexity = if is_suspicious { 500 } else { 100 };
-        
-        let mut analysis_result = 0u64;
-        for i in 0..analysis_complexity {
-            analysis_result = analysis_result.wrapping_mul(13).wrapping_add(i);
-        }
-        
-        black_box(analysis_result);
-        start.elapsed()
+        self.results.insert(
+            name.to_string(),
+            InstructionMetrics { compute_units, transaction_size, accounts },
+        );
     }
 
-    fn simulate_batch_fraud_analysis(&self, batch_size: usize) -> Duration {
-        let start = Instant::now();
-        
-        for i in 0..batch_size {
-            let is_suspicious = i % 10 == 0; // 10% suspicious
-            self.simulate_fraud_analysis(is_suspicious);
-        }
-        
-        start.elapsed()
-    }
+    /// Initialize the program and register the destination chain
+    pub async fn setup(&mut self) {
+        let owner = self.owner.pubkey();
 
-    fn simulate_circuit_breaker_check(&self, under_load: bool) -> Duration {
-        let start = Instant::now();
-        
-        // Simulate state checking and updates
-        let work_units = if under_load { 50 } else { 10 };
-        
-        let mut state_check = 0u64;
-        for i in 0..work_units {
-            state_check = state_check.wrapping_mul(7).wrapping_add(i);
-        }
-        
-        black_box(state_check);
-        start.elapsed()
+        let initialize = Instruction {
+            program_id: universal_nft::ID,
+            accounts: universal_nft::accounts::Initialize {
+                config: Self::config_pda(),
+                authority: owner,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: universal_nft::instruction::Initialize { gateway_authority: GATEWAY_PROGRAM_ID }.data(),
+        };
+        self.execute(initialize, &[]).await;
+
+        let register_chain = Instruction {
+            program_id: universal_nft::ID,
+            accounts: universal_nft::accounts::RegisterChain {
+                config: Self::config_pda(),
+                chain: Self::chain_pda(DESTINATION_CHAIN_ID),
+                authority: owner,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: universal_nft::instruction::RegisterChain {
+                chain_id: DESTINATION_CHAIN_ID,
+                chain_kind: ChainKind::Evm,
+                address_format: AddressFormat::Evm,
+            }
+            .data(),
+        };
+        self.execute(register_chain, &[]).await;
     }
 
-    fn simulate_account_creation(&self, count: usize) -> Duration {
-        let start = Instant::now();
-        
-        // Simulate account space allocation and initialization
-        for i in 0..count {
-            let account_size = 512; // Typical account size
-            let mut account_data = vec![0u8; account_size];
-            
-            // Simulate data initialization
-            for j in 0..account_size {
-                account_data[j] = ((i + j) % 256) as u8;
+    pub async fn benchmark_mint(&mut self) {
+        let owner = self.owner.pubkey();
+        let mint = self.mint.pubkey();
+        let universal_nft_pda = self.universal_nft_pda();
+        let token_account = get_associated_token_address(&owner, &mint);
+
+        let instruction = Instruction {
+            program_id: universal_nft::ID,
+            accounts: universal_nft::accounts::MintNft {
+                config: Self::config_pda(),
+                universal_nft: universal_nft_pda,
+                mint,
+                metadata: self.metadata_pda(false),
+                master_edition: self.metadata_pda(true),
+                token_account,
+                mint_authority: universal_nft_pda,
+                owner,
+                payer: owner,
+                collection_hooks: None,
+                collection_config: None,
+                incident_mode: Pubkey::find_program_address(&[b"incident_mode"], &universal_nft::ID).0,
+                wallet_rate_limit: Pubkey::find_program_address(
+                    &[b"rate_limit", b"wallet", owner.as_ref()],
+                    &universal_nft::ID,
+                )
+                .0,
+                collection_rate_limit: Pubkey::find_program_address(
+                    &[b"rate_limit", b"collection", Pubkey::default().as_ref()],
+                    &universal_nft::ID,
+                )
+                .0,
+                mint_phase: None,
+                phase_funds_recipient: None,
+                fee_schedule: Pubkey::find_program_address(&[b"fee_schedule"], &universal_nft::ID).0,
+                fee_recipient: None,
+                governance_stake: None,
+                deployment_registry: None,
+                membership_token_account: None,
+                membership_nft: None,
+                rebate_campaign: None,
+                referral: None,
+                user_metrics: None,
+                points_config: None,
+                usage_segment: None,
+                leaderboard: None,
+                token_program: anchor_spl::token::ID,
+                associated_token_program: anchor_spl::associated_token::ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
             }
-            
-            black_box(account_data);
-        }
-        
-        start.elapsed()
+            .to_account_metas(None),
+            data: universal_nft::instruction::MintNft {
+                name: "Benchmark NFT".to_string(),
+                symbol: "BNFT".to_string(),
+                uri: "https://example.com/nft/1.json".to_string(),
+                collection_mint: None,
+                allowlist_proof: vec![],
+                referrer: None,
+            }
+            .data(),
+        };
+
+        let mint_signer = self.mint.insecure_clone();
+        self.measure(
+            "mint_nft",
+            instruction,
+            &[&mint_signer],
+            &[
+                ("universal_nft", universal_nft_pda),
+                ("mint", mint),
+                ("metadata", self.metadata_pda(false)),
+                ("master_edition", self.metadata_pda(true)),
+                ("token_account", token_account),
+            ],
+        )
+        .await;
     }
 
-    fn simulate_concurrent_operations(&self, thread_count: usize) -> Duration {
-        let start = Instant::now();
-        
-        // Simulate concurrent operations with contention
-        std::thread::scope(|s| {
-            for _ in 0..thread_count {
-                s.spawn(|| {
-                    self.simulate_mint_operation(500);
-                });
+    pub async fn benchmark_on_call(&mut self) {
+        let message = CrossChainMessage::MintNft {
+            token_id: "1".to_string(),
+            name: "Benchmark NFT".to_string(),
+            symbol: "BNFT".to_string(),
+            uri: "https://example.com/nft/1.json".to_string(),
+            recipient: self.owner.pubkey(),
+            collection_mint: None,
+        };
+
+        let on_call = Instruction {
+            program_id: universal_nft::ID,
+            accounts: universal_nft::accounts::OnCall {
+                config: Self::config_pda(),
+                universal_nft: self.universal_nft_pda(),
+                mint: self.mint.pubkey(),
+                instructions_sysvar: sysvar::instructions::ID,
+                source_chain: None,
+                nft_origin: None,
+                payer: Some(self.owner.pubkey()),
+                system_program: Some(system_program::ID),
+                recipient: Some(self.owner.pubkey()),
+                recipient_token_account: Some(get_associated_token_address(
+                    &self.owner.pubkey(),
+                    &self.mint.pubkey(),
+                )),
+                token_program: Some(anchor_spl::token::ID),
+                associated_token_program: Some(anchor_spl::associated_token::ID),
+                rent_vault: None,
+                rent_ledger: None,
+                verified_event: None,
+            }
+            .to_account_metas(None),
+            data: universal_nft::instruction::OnCall {
+                sender: [0x11; 20],
+                source_chain_id: SOURCE_CHAIN_ID,
+                message: MessageCodec::encode(&message).unwrap(),
             }
-        });
-        
-        start.elapsed()
+            .data(),
+        };
+
+        // Delivered through the gateway stub, which CPIs into on_call
+        let mut accounts = vec![AccountMeta::new_readonly(universal_nft::ID, false)];
+        accounts.extend(on_call.accounts);
+        let instruction = Instruction {
+            program_id: GATEWAY_PROGRAM_ID,
+            accounts,
+            data: on_call.data,
+        };
+
+        self.measure("on_call", instruction, &[], &[]).await;
     }
 
-    fn simulate_optimized_mint(&self) -> Duration {
-        let start = Instant::now();
-        
-        // Simulate optimized code path
-        let mut optimized_work = 0u64;
-        for i in 0..100 {
-            optimized_work = optimized_work.wrapping_add(i * 3);
-        }
-        
-        black_box(optimized_work);
-        start.elapsed()
+    pub async fn benchmark_bridge(&mut self) {
+        let owner = self.owner.pubkey();
+        let mint = self.mint.pubkey();
+        let universal_nft_pda = self.universal_nft_pda();
+
+        // The first outbound transfer uses nonce 0
+        let transfer = Pubkey::find_program_address(
+            &[b"transfer", mint.as_ref(), &0u64.to_le_bytes()],
+            &universal_nft::ID,
+        )
+        .0;
+        let receipt_mint =
+            Pubkey::find_program_address(&[b"receipt_mint", transfer.as_ref()], &universal_nft::ID).0;
+        let receipt_token_account = get_associated_token_address(&owner, &receipt_mint);
+
+        let instruction = Instruction {
+            program_id: universal_nft::ID,
+            accounts: universal_nft::accounts::BurnAndTransfer {
+                config: Self::config_pda(),
+                destination_chain: Self::chain_pda(DESTINATION_CHAIN_ID),
+                universal_nft: universal_nft_pda,
+                transfer,
+                mint,
+                token_account: get_associated_token_address(&owner, &mint),
+                receipt_mint,
+                receipt_token_account,
+                receipt_authority: Pubkey::find_program_address(
+                    &[b"receipt_authority"],
+                    &universal_nft::ID,
+                )
+                .0,
+                owner,
+                wallet_lock: Pubkey::find_program_address(
+                    &[b"wallet_lock", owner.as_ref()],
+                    &universal_nft::ID,
+                )
+                .0,
+                operator_session: Pubkey::find_program_address(
+                    &[b"operator_session", owner.as_ref(), owner.as_ref()],
+                    &universal_nft::ID,
+                )
+                .0,
+                gateway_program: GATEWAY_PROGRAM_ID,
+                gas_token_config: None,
+                gas_token_account: None,
+                gas_token_vault: None,
+                collection_hooks: None,
+                collection_floor: None,
+                collection_config: None,
+                custody_token_account: None,
+                scheduled_transfer: None,
+                message_layer: None,
+                metadata_rules: None,
+                supply_ledger: None,
+                nft_attributes: Pubkey::find_program_address(
+                    &[b"nft_attributes", mint.as_ref()],
+                    &universal_nft::ID,
+                )
+                .0,
+                nft_children: Pubkey::find_program_address(
+                    &[b"nft_children", mint.as_ref()],
+                    &universal_nft::ID,
+                )
+                .0,
+                vesting_position: Pubkey::find_program_address(
+                    &[b"vesting", mint.as_ref()],
+                    &universal_nft::ID,
+                )
+                .0,
+                fee_schedule: Pubkey::find_program_address(&[b"fee_schedule"], &universal_nft::ID).0,
+                corridor_pause: Pubkey::find_program_address(
+                    &[b"corridor_pause", DESTINATION_CHAIN_ID.to_le_bytes().as_ref()],
+                    &universal_nft::ID,
+                )
+                .0,
+                fraud_engine: Pubkey::find_program_address(&[b"fraud_engine"], &universal_nft::ID).0,
+                chain_risk_registry: Pubkey::find_program_address(&[b"chain_risk_registry"], &universal_nft::ID).0,
+                fee_recipient: None,
+                governance_stake: None,
+                deployment_registry: None,
+                membership_token_account: None,
+                membership_nft: None,
+                rebate_campaign: None,
+                referral: None,
+                user_metrics: None,
+                points_config: None,
+                usage_segment: None,
+                leaderboard: None,
+                collection_leaderboard: None,
+                circuit_breaker: Pubkey::find_program_address(
+                    &[b"circuit_breaker", DESTINATION_CHAIN_ID.to_le_bytes().as_ref()],
+                    &universal_nft::ID,
+                )
+                .0,
+                incident_mode: Pubkey::find_program_address(&[b"incident_mode"], &universal_nft::ID).0,
+                wallet_rate_limit: Pubkey::find_program_address(
+                    &[b"rate_limit", b"wallet", owner.as_ref()],
+                    &universal_nft::ID,
+                )
+                .0,
+                chain_rate_limit: Pubkey::find_program_address(
+                    &[b"rate_limit", b"chain", DESTINATION_CHAIN_ID.to_le_bytes().as_ref()],
+                    &universal_nft::ID,
+                )
+                .0,
+                collection_rate_limit: Pubkey::find_program_address(
+                    &[b"rate_limit", b"collection", Pubkey::default().as_ref()],
+                    &universal_nft::ID,
+                )
+                .0,
+                token_program: anchor_spl::token::ID,
+                associated_token_program: anchor_spl::associated_token::ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: universal_nft::instruction::BurnAndTransfer {
+                destination_chain_id: DESTINATION_CHAIN_ID,
+                recipient: vec![0x22; 20],
+                gas_limit: GAS_LIMIT,
+                gas_payment: GasPayment::Sol,
+                payload: vec![],
+                referrer: None,
+            }
+            .data(),
+        };
+
+        self.measure(
+            "burn_and_transfer",
+            instruction,
+            &[],
+            &[
+                ("transfer", transfer),
+                ("receipt_mint", receipt_mint),
+                ("receipt_token_account", receipt_token_account),
+            ],
+        )
+        .await;
     }
 
-    fn simulate_optimized_transfer(&self) -> Duration {
-        let start = Instant::now();
-        
-        // Simulate optimized transfer with minimal overhead
-        let mut transfer_work = 0u64;
-        for i in 0..200 {
-            transfer_work = transfer_work.wrapping_add(i * 5);
-        }
-        
-        black_box(transfer_work);
-        start.elapsed()
+    pub fn to_json(&self) -> Value {
+        let instructions: Map<String, Value> = self
+            .results
+            .iter()
+            .map(|(name, metrics)| (name.clone(), metrics.to_json()))
+            .collect();
+
+        json!({
+            "program": "universal_nft",
+            "instructions": instructions,
+        })
     }
+}
 
-    fn simulate_optimized_verification(&self) -> Duration {
-        let start = Instant::now();
-        
-        // Simulate batch-optimized verification
-        let mut verification_work = 0u64;
-        for i in 0..300 {
-            verification_work = verification_work.wrapping_add(i * 7);
+/// Compare compute units against the baseline, returning one line per
+/// regression beyond the tolerance
+fn find_regressions(baseline: &Value, current: &Value) -> Vec<String> {
+    let mut regressions = Vec::new();
+    let Some(instructions) = current["instructions"].as_object() else {
+        return regressions;
+    };
+
+    for (name, metrics) in instructions {
+        let current_cu = metrics["compute_units"].as_u64().unwrap_or(0);
+        let Some(baseline_cu) = baseline["instructions"][name]["compute_units"].as_u64() else {
+            continue;
+        };
+        let allowed = baseline_cu + baseline_cu * CU_REGRESSION_TOLERANCE_PCT / 100;
+        if current_cu > allowed {
+            regressions.push(format!("{}: {} CU (baseline {})", name, current_cu, baseline_cu));
         }
-        
-        black_box(verification_work);
-        start.elapsed()
     }
 
-    fn simulate_high_frequency_operations(&self, ops_count: usize) -> Duration {
-        let start = Instant::now();
-        
-        // Simulate rapid-fire operations
-        for i in 0..ops_count {
-            let mut work = 0u64;
-            for j in 0..10 {
-                work = work.wrapping_add((i + j) as u64);
-            }
-            black_box(work);
-        }
-        
-        start.elapsed()
-    }
+    regressions
+}
 
-    fn simulate_large_batch_operations(&self, batch_size: usize) -> Duration {
-        let start = Instant::now();
-        
-        // Simulate processing large batches
-        let mut batch_work = Vec::with_capacity(batch_size);
-        for i in 0..batch_size {
-            batch_work.push(i as u64 * 11);
-        }
-        
-        black_box(batch_work);
-        start.elapsed()
-    }
+#[tokio::main]
+async fn main() {
+    let update_baseline = std::env::args().any(|arg| arg == "--update");
 
-    fn simulate_memory_pressure_test(&self) -> Duration {
-        let start = Instant::now();
-        
-        // Simulate memory-intensive operations
-        let mut large_data = Vec::new();
-        for i in 0..10000 {
-            large_data.push(vec![i as u8; 100]);
-        }
-        
-        black_box(large_data);
-        start.elapsed()
+    let mut harness = BenchmarkHarness::new().await;
+    harness.setup().await;
+    harness.benchmark_mint().await;
+    harness.benchmark_on_call().await;
+    harness.benchmark_bridge().await;
+
+    let results = harness.to_json();
+    let output = serde_json::to_string_pretty(&results).unwrap();
+    println!("{}", output);
+
+    std::fs::create_dir_all("target/benchmarks").unwrap();
+    std::fs::write(RESULTS_PATH, &output).expect("Failed to write benchmark results");
+
+    if update_baseline {
+        std::fs::write(BASELINE_PATH, &output).expect("Failed to write baseline");
+        println!("Baseline updated: {}", BASELINE_PATH);
+        return;
     }
 
-    /// Generate comprehensive performance report
-    pub fn generate_performance_report(&self) -> String {
-        let mut report = String::new();
-        
-        report.push_str("# Universal NFT Performance Benchmark Report\n\n");
-        report.push_str("## Executive Summary\n\n");
-        report.push_str("This report provides comprehensive performance metrics for the Universal NFT program.\n\n");
-        
-        report.push_str("## Key Performance Indicators\n\n");
-        report.push_str("| Operation | Avg Latency (ms) | P95 Latency (ms) | Throughput (ops/sec) | Success Rate (%) |\n");
-        report.push_str("|-----------|------------------|------------------|---------------------|------------------|\n");
-        
-        for (operation, metrics) in &self.results {
-            report.push_str(&format!(
-                "| {} | {:.2} | {:.2} | {:.2} | {:.2} |\n",
-                operation,
-                metrics.avg_latency_ms,
-                metrics.p95_latency_ms,
-                metrics.throughput_ops_per_sec,
-                metrics.success_rate * 100.0
-            ));
+    let Ok(baseline) = std::fs::read_to_string(BASELINE_PATH) else {
+        println!("No baseline at {}, run with --update to create one", BASELINE_PATH);
+        return;
+    };
+    let baseline: Value = serde_json::from_str(&baseline).expect("Invalid baseline JSON");
+
+    let regressions = find_regressions(&baseline, &results);
+    if !regressions.is_empty() {
+        eprintln!("Compute unit regressions:");
+        for regression in &regressions {
+            eprintln!("  {}", regression);
         }
-        
-        report.push_str("\n## Compute Unit Usage\n\n");
-        report.push_str("| Operation | Compute Units | Efficiency Score |\n");
-        report.push_str("|-----------|---------------|------------------|\n");
-        
-        for (operation, metrics) in &self.results {
-            let efficiency = 100.0 - (metrics.compute_units_used as f64 / 1000.0);
-            report.push_str(&format!(
-                "| {} | {} | {:.1}% |\n",
-                operation,
-                metrics.compute_units_used,
-                efficiency.max(0.0)
-            ));
-        }
-        
-        report.push_str("\n## Recommendations\n\n");
-        report.push_str("1. **Optimization Opportunities**: Focus on operations with >50k compute units\n");
-        report.push_str("2. **Scaling Considerations**: Monitor P95 latency under increased load\n");
-        report.push_str("3. **Error Handling**: Investigate operations with <99% success rate\n");
-        
-        report
+        std::process::exit(1);
     }
+    println!("No compute unit regressions against {}", BASELINE_PATH);
 }
-
-// Criterion benchmark functions
-fn bench_all_operations(c: &mut Criterion) {
-    let rt = tokio::runtime::Runtime::new().unwrap();
-    let mut suite = BenchmarkSuite::new(BenchmarkConfig::default());
-    
-    rt.block_on(async {
-        suite.benchmark_nft_minting(c).await;
-        suite.benchmark_cross_chain_transfers(c).await;
-        suite.benchmark_signature_verification(c).await;
-        suite.benchmark_fraud_detection(c).await;
-        suite.benchmark_circuit_breaker(c).await;
-        suite.benchmark_memory_usage(c).await;
-        suite.benchmark_concurrency(c).await;
-        suite.benchmark_compute_optimization(c).await;
-        suite.benchmark_stress_test(c).await;
-    });
-    
-    // Generate and save performance report
-    let report = suite.generate_performance_report();
-    std::fs::write("benchmark_report.md", report).expect("Failed to write benchmark report");
-}
-
-criterion_group!(benches, bench_all_operations);
-criterion_main!(benches);
\ No newline at end of file
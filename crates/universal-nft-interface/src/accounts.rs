@@ -0,0 +1,570 @@
+//! Instruction accounts in the order the program expects them. Optional
+//! accounts left as `None` are passed as the Universal NFT program id, which
+//! is how Anchor encodes an absent optional account.
+
+use anchor_lang::prelude::*;
+
+fn meta(account: &AccountInfo, is_writable: bool, is_signer: bool) -> AccountMeta {
+    if is_writable {
+        AccountMeta::new(account.key(), is_signer)
+    } else {
+        AccountMeta::new_readonly(account.key(), is_signer)
+    }
+}
+
+fn optional_meta(account: &Option<AccountInfo>, is_writable: bool) -> AccountMeta {
+    match account {
+        Some(account) => meta(account, is_writable, false),
+        None => AccountMeta::new_readonly(crate::ID, false),
+    }
+}
+
+fn signer_flag(is_signer: Option<bool>, default: bool) -> bool {
+    is_signer.unwrap_or(default)
+}
+
+/// Accounts of `mint_nft`. `mint` is a fresh keypair and signs alongside the
+/// payer.
+pub struct MintNft<'info> {
+    /// `pda::config()`
+    pub config: AccountInfo<'info>,
+    /// `pda::universal_nft(mint)`, created
+    pub universal_nft: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    /// `pda::metadata(mint)`
+    pub metadata: AccountInfo<'info>,
+    /// `pda::master_edition(mint)`
+    pub master_edition: AccountInfo<'info>,
+    /// Owner's associated token account, created if needed
+    pub token_account: AccountInfo<'info>,
+    /// `pda::mint_authority()`
+    pub mint_authority: AccountInfo<'info>,
+    pub owner: AccountInfo<'info>,
+    pub payer: AccountInfo<'info>,
+    pub collection_hooks: Option<AccountInfo<'info>>,
+    pub collection_config: Option<AccountInfo<'info>>,
+    pub mint_phase: Option<AccountInfo<'info>>,
+    pub phase_funds_recipient: Option<AccountInfo<'info>>,
+    /// `pda::fee_schedule()`, whether or not governance has set fees
+    pub fee_schedule: AccountInfo<'info>,
+    /// `pda::incident_mode()`, whether or not it was set up
+    pub incident_mode: AccountInfo<'info>,
+    /// `pda::wallet_rate_limit(payer)`, whether or not it was set up
+    pub wallet_rate_limit: AccountInfo<'info>,
+    /// `pda::collection_rate_limit(collection_mint)`, whether or not it was
+    /// set up
+    pub collection_rate_limit: AccountInfo<'info>,
+    /// Fee schedule's fee recipient, required while a fee is charged
+    pub fee_recipient: Option<AccountInfo<'info>>,
+    /// Payer's governance stake, for a stake-tier fee discount, with
+    /// `pda::deployment_registry()` naming the governance program
+    pub governance_stake: Option<AccountInfo<'info>>,
+    pub deployment_registry: Option<AccountInfo<'info>>,
+    /// Payer's token account holding a membership collection NFT, with
+    /// `pda::universal_nft` of its mint, for a membership fee discount
+    pub membership_token_account: Option<AccountInfo<'info>>,
+    pub membership_nft: Option<AccountInfo<'info>>,
+    /// `pda::rebate_campaign()`, refunding part of the fee while it runs
+    pub rebate_campaign: Option<AccountInfo<'info>>,
+    /// `pda::referral(referrer)`, required when a referrer is named
+    pub referral: Option<AccountInfo<'info>>,
+    /// `pda::user_metrics(user)` of the signer, to earn activity points
+    pub user_metrics: Option<AccountInfo<'info>>,
+    /// `pda::points_config()`, required for the activity to earn points
+    pub points_config: Option<AccountInfo<'info>>,
+    /// `pda::usage_segment(collection_mint, integration_id)`, to attribute
+    /// the operation to a collection and integration
+    pub usage_segment: Option<AccountInfo<'info>>,
+    /// `pda::leaderboard(0)`, to rank the minter
+    pub leaderboard: Option<AccountInfo<'info>>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+impl ToAccountMetas for MintNft<'_> {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            meta(&self.config, false, false),
+            meta(&self.universal_nft, true, false),
+            meta(&self.mint, true, signer_flag(is_signer, true)),
+            meta(&self.metadata, true, false),
+            meta(&self.master_edition, true, false),
+            meta(&self.token_account, true, false),
+            meta(&self.mint_authority, false, false),
+            meta(&self.owner, true, false),
+            meta(&self.payer, true, signer_flag(is_signer, true)),
+            optional_meta(&self.collection_hooks, false),
+            optional_meta(&self.collection_config, true),
+            optional_meta(&self.mint_phase, true),
+            optional_meta(&self.phase_funds_recipient, true),
+            meta(&self.fee_schedule, false, false),
+            meta(&self.incident_mode, false, false),
+            meta(&self.wallet_rate_limit, true, false),
+            meta(&self.collection_rate_limit, true, false),
+            optional_meta(&self.fee_recipient, true),
+            optional_meta(&self.governance_stake, false),
+            optional_meta(&self.deployment_registry, false),
+            optional_meta(&self.membership_token_account, false),
+            optional_meta(&self.membership_nft, false),
+            optional_meta(&self.rebate_campaign, true),
+            optional_meta(&self.referral, true),
+            optional_meta(&self.user_metrics, true),
+            optional_meta(&self.points_config, false),
+            optional_meta(&self.usage_segment, true),
+            optional_meta(&self.leaderboard, true),
+            meta(&self.token_program, false, false),
+            meta(&self.associated_token_program, false, false),
+            meta(&self.system_program, false, false),
+            meta(&self.rent, false, false),
+        ]
+    }
+}
+
+impl<'info> ToAccountInfos<'info> for MintNft<'info> {
+    fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+        let mut infos = vec![
+            self.config.clone(),
+            self.universal_nft.clone(),
+            self.mint.clone(),
+            self.metadata.clone(),
+            self.master_edition.clone(),
+            self.token_account.clone(),
+            self.mint_authority.clone(),
+            self.owner.clone(),
+            self.payer.clone(),
+            self.fee_schedule.clone(),
+            self.incident_mode.clone(),
+            self.wallet_rate_limit.clone(),
+            self.collection_rate_limit.clone(),
+        ];
+        infos.extend(
+            [
+                &self.collection_hooks,
+                &self.collection_config,
+                &self.mint_phase,
+                &self.phase_funds_recipient,
+                &self.fee_recipient,
+                &self.governance_stake,
+                &self.deployment_registry,
+                &self.membership_token_account,
+                &self.membership_nft,
+                &self.rebate_campaign,
+                &self.referral,
+                &self.user_metrics,
+                &self.points_config,
+                &self.usage_segment,
+                &self.leaderboard,
+            ]
+            .into_iter()
+            .flatten()
+            .cloned(),
+        );
+        infos.extend([
+            self.token_program.clone(),
+            self.associated_token_program.clone(),
+            self.system_program.clone(),
+            self.rent.clone(),
+        ]);
+        infos
+    }
+}
+
+/// Accounts of `transfer_nft`. `current_owner` signs and pays for the
+/// recipient's token account if it doesn't exist yet.
+pub struct TransferNft<'info> {
+    /// `pda::config()`
+    pub config: AccountInfo<'info>,
+    /// `pda::universal_nft(mint)`
+    pub universal_nft: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    /// Current owner's associated token account
+    pub from_token_account: AccountInfo<'info>,
+    /// New owner's associated token account, created if needed
+    pub to_token_account: AccountInfo<'info>,
+    pub current_owner: AccountInfo<'info>,
+    /// `pda::wallet_lock(current_owner)`
+    pub wallet_lock: AccountInfo<'info>,
+    /// `pda::incident_mode()`, whether or not it was set up
+    pub incident_mode: AccountInfo<'info>,
+    pub new_owner: AccountInfo<'info>,
+    pub collection_hooks: Option<AccountInfo<'info>>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl ToAccountMetas for TransferNft<'_> {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            meta(&self.config, false, false),
+            meta(&self.universal_nft, true, false),
+            meta(&self.mint, true, false),
+            meta(&self.from_token_account, true, false),
+            meta(&self.to_token_account, true, false),
+            meta(&self.current_owner, true, signer_flag(is_signer, true)),
+            meta(&self.wallet_lock, false, false),
+            meta(&self.incident_mode, false, false),
+            meta(&self.new_owner, false, false),
+            optional_meta(&self.collection_hooks, false),
+            meta(&self.token_program, false, false),
+            meta(&self.associated_token_program, false, false),
+            meta(&self.system_program, false, false),
+        ]
+    }
+}
+
+impl<'info> ToAccountInfos<'info> for TransferNft<'info> {
+    fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+        let mut infos = vec![
+            self.config.clone(),
+            self.universal_nft.clone(),
+            self.mint.clone(),
+            self.from_token_account.clone(),
+            self.to_token_account.clone(),
+            self.current_owner.clone(),
+            self.wallet_lock.clone(),
+            self.incident_mode.clone(),
+            self.new_owner.clone(),
+        ];
+        infos.extend(self.collection_hooks.iter().cloned());
+        infos.extend([
+            self.token_program.clone(),
+            self.associated_token_program.clone(),
+            self.system_program.clone(),
+        ]);
+        infos
+    }
+}
+
+/// Accounts of `burn_and_transfer`. `owner` is the NFT owner or an operator
+/// with a bridge-scoped session, and pays for the transfer record and receipt.
+/// Collection hook accounts follow as remaining accounts, then the accounts
+/// the message layer's send instruction takes.
+pub struct BurnAndTransfer<'info> {
+    /// `pda::config()`
+    pub config: AccountInfo<'info>,
+    /// `pda::chain(destination_chain_id)`
+    pub destination_chain: AccountInfo<'info>,
+    /// `pda::universal_nft(mint)`
+    pub universal_nft: AccountInfo<'info>,
+    /// `pda::transfer(mint, config.nonce)`, created
+    pub transfer: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    /// `pda::receipt_mint(transfer)`, created
+    pub receipt_mint: AccountInfo<'info>,
+    /// Owner's associated token account for the receipt, created
+    pub receipt_token_account: AccountInfo<'info>,
+    /// `pda::receipt_authority()`
+    pub receipt_authority: AccountInfo<'info>,
+    pub owner: AccountInfo<'info>,
+    /// `pda::wallet_lock(nft owner)`
+    pub wallet_lock: AccountInfo<'info>,
+    /// `pda::operator_session(nft owner, owner)`
+    pub operator_session: AccountInfo<'info>,
+    /// Gateway, or the program of `message_layer`
+    pub gateway_program: AccountInfo<'info>,
+    pub gas_token_config: Option<AccountInfo<'info>>,
+    pub gas_token_account: Option<AccountInfo<'info>>,
+    pub gas_token_vault: Option<AccountInfo<'info>>,
+    pub collection_hooks: Option<AccountInfo<'info>>,
+    pub collection_floor: Option<AccountInfo<'info>>,
+    pub collection_config: Option<AccountInfo<'info>>,
+    pub custody_token_account: Option<AccountInfo<'info>>,
+    pub scheduled_transfer: Option<AccountInfo<'info>>,
+    /// `pda::message_layer(layer)`; `None` sends through the gateway
+    pub message_layer: Option<AccountInfo<'info>>,
+    /// `pda::metadata_rules(destination_chain_id)`, applied when present
+    pub metadata_rules: Option<AccountInfo<'info>>,
+    /// `pda::supply_ledger(destination_chain_id)`; pass it for lock-mode
+    /// collections so the parity audit counts the locked original
+    pub supply_ledger: Option<AccountInfo<'info>>,
+    /// `pda::nft_attributes(mint)`, whether or not the NFT has attributes
+    pub nft_attributes: AccountInfo<'info>,
+    /// `pda::nft_children(mint)`, whether or not anything is equipped into it
+    pub nft_children: AccountInfo<'info>,
+    /// `pda::vesting_position(mint)`, whether or not the NFT wraps a position
+    pub vesting_position: AccountInfo<'info>,
+    /// `pda::fee_schedule()`, whether or not governance has set fees
+    pub fee_schedule: AccountInfo<'info>,
+    /// `pda::corridor_pause(destination_chain_id)`, whether or not the
+    /// corridor was ever paused
+    pub corridor_pause: AccountInfo<'info>,
+    /// `pda::circuit_breaker(destination_chain_id)`, whether or not the chain
+    /// has a breaker
+    pub circuit_breaker: AccountInfo<'info>,
+    /// `pda::incident_mode()`, whether or not it was set up
+    pub incident_mode: AccountInfo<'info>,
+    /// `pda::wallet_rate_limit(owner)`, `pda::chain_rate_limit(destination_chain_id)`
+    /// and `pda::collection_rate_limit(collection_mint)`, whether or not they
+    /// were set up
+    pub wallet_rate_limit: AccountInfo<'info>,
+    pub chain_rate_limit: AccountInfo<'info>,
+    pub collection_rate_limit: AccountInfo<'info>,
+    /// `pda::fraud_engine()` and `pda::chain_risk_registry()`, whether or not
+    /// they were set up
+    pub fraud_engine: AccountInfo<'info>,
+    pub chain_risk_registry: AccountInfo<'info>,
+    /// Fee schedule's fee recipient, required while a fee is charged
+    pub fee_recipient: Option<AccountInfo<'info>>,
+    /// Payer's governance stake, for a stake-tier fee discount, with
+    /// `pda::deployment_registry()` naming the governance program
+    pub governance_stake: Option<AccountInfo<'info>>,
+    pub deployment_registry: Option<AccountInfo<'info>>,
+    /// Payer's token account holding a membership collection NFT, with
+    /// `pda::universal_nft` of its mint, for a membership fee discount
+    pub membership_token_account: Option<AccountInfo<'info>>,
+    pub membership_nft: Option<AccountInfo<'info>>,
+    /// `pda::rebate_campaign()`, refunding part of the fee while it runs
+    pub rebate_campaign: Option<AccountInfo<'info>>,
+    /// `pda::referral(referrer)`, required when a referrer is named
+    pub referral: Option<AccountInfo<'info>>,
+    /// `pda::user_metrics(user)` of the signer, to earn activity points
+    pub user_metrics: Option<AccountInfo<'info>>,
+    /// `pda::points_config()`, required for the activity to earn points
+    pub points_config: Option<AccountInfo<'info>>,
+    /// `pda::usage_segment(collection_mint, integration_id)`, to attribute
+    /// the operation to a collection and integration
+    pub usage_segment: Option<AccountInfo<'info>>,
+    /// `pda::leaderboard(1)`, to rank the sender
+    pub leaderboard: Option<AccountInfo<'info>>,
+    /// `pda::leaderboard(2)`, to rank the NFT's collection
+    pub collection_leaderboard: Option<AccountInfo<'info>>,
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl ToAccountMetas for BurnAndTransfer<'_> {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            meta(&self.config, true, false),
+            meta(&self.destination_chain, true, false),
+            meta(&self.universal_nft, true, false),
+            meta(&self.transfer, true, false),
+            meta(&self.mint, true, false),
+            meta(&self.token_account, true, false),
+            meta(&self.receipt_mint, true, false),
+            meta(&self.receipt_token_account, true, false),
+            meta(&self.receipt_authority, false, false),
+            meta(&self.owner, true, signer_flag(is_signer, true)),
+            meta(&self.wallet_lock, false, false),
+            meta(&self.operator_session, false, false),
+            meta(&self.gateway_program, false, false),
+            optional_meta(&self.gas_token_config, true),
+            optional_meta(&self.gas_token_account, true),
+            optional_meta(&self.gas_token_vault, true),
+            optional_meta(&self.collection_hooks, false),
+            optional_meta(&self.collection_floor, false),
+            optional_meta(&self.collection_config, false),
+            optional_meta(&self.custody_token_account, true),
+            optional_meta(&self.scheduled_transfer, true),
+            optional_meta(&self.message_layer, false),
+            optional_meta(&self.metadata_rules, false),
+            optional_meta(&self.supply_ledger, true),
+            meta(&self.nft_attributes, false, false),
+            meta(&self.nft_children, false, false),
+            meta(&self.vesting_position, false, false),
+            meta(&self.fee_schedule, false, false),
+            meta(&self.corridor_pause, false, false),
+            meta(&self.circuit_breaker, true, false),
+            meta(&self.incident_mode, false, false),
+            meta(&self.wallet_rate_limit, true, false),
+            meta(&self.chain_rate_limit, true, false),
+            meta(&self.collection_rate_limit, true, false),
+            meta(&self.fraud_engine, true, false),
+            meta(&self.chain_risk_registry, false, false),
+            optional_meta(&self.fee_recipient, true),
+            optional_meta(&self.governance_stake, false),
+            optional_meta(&self.deployment_registry, false),
+            optional_meta(&self.membership_token_account, false),
+            optional_meta(&self.membership_nft, false),
+            optional_meta(&self.rebate_campaign, true),
+            optional_meta(&self.referral, true),
+            optional_meta(&self.user_metrics, true),
+            optional_meta(&self.points_config, false),
+            optional_meta(&self.usage_segment, true),
+            optional_meta(&self.leaderboard, true),
+            optional_meta(&self.collection_leaderboard, true),
+            meta(&self.token_program, false, false),
+            meta(&self.associated_token_program, false, false),
+            meta(&self.system_program, false, false),
+        ]
+    }
+}
+
+impl<'info> ToAccountInfos<'info> for BurnAndTransfer<'info> {
+    fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+        let mut infos = vec![
+            self.config.clone(),
+            self.destination_chain.clone(),
+            self.universal_nft.clone(),
+            self.transfer.clone(),
+            self.mint.clone(),
+            self.token_account.clone(),
+            self.receipt_mint.clone(),
+            self.receipt_token_account.clone(),
+            self.receipt_authority.clone(),
+            self.owner.clone(),
+            self.wallet_lock.clone(),
+            self.operator_session.clone(),
+            self.gateway_program.clone(),
+            self.nft_attributes.clone(),
+            self.nft_children.clone(),
+            self.vesting_position.clone(),
+            self.fee_schedule.clone(),
+            self.corridor_pause.clone(),
+            self.fraud_engine.clone(),
+            self.chain_risk_registry.clone(),
+            self.circuit_breaker.clone(),
+            self.incident_mode.clone(),
+            self.wallet_rate_limit.clone(),
+            self.chain_rate_limit.clone(),
+            self.collection_rate_limit.clone(),
+            self.fraud_engine.clone(),
+            self.chain_risk_registry.clone(),
+        ];
+        infos.extend(
+            [
+                &self.gas_token_config,
+                &self.gas_token_account,
+                &self.gas_token_vault,
+                &self.collection_hooks,
+                &self.collection_floor,
+                &self.collection_config,
+                &self.custody_token_account,
+                &self.scheduled_transfer,
+                &self.message_layer,
+                &self.metadata_rules,
+                &self.supply_ledger,
+                &self.fee_recipient,
+                &self.governance_stake,
+                &self.deployment_registry,
+                &self.membership_token_account,
+                &self.membership_nft,
+                &self.rebate_campaign,
+                &self.referral,
+                &self.user_metrics,
+                &self.points_config,
+                &self.usage_segment,
+                &self.leaderboard,
+                &self.collection_leaderboard,
+            ]
+            .into_iter()
+            .flatten()
+            .cloned(),
+        );
+        infos.extend([
+            self.token_program.clone(),
+            self.associated_token_program.clone(),
+            self.system_program.clone(),
+        ]);
+        infos
+    }
+}
+
+/// Accounts of `get_transfer_status`
+pub struct GetTransferStatus<'info> {
+    /// `pda::transfer(mint, nonce)`
+    pub transfer: AccountInfo<'info>,
+}
+
+impl ToAccountMetas for GetTransferStatus<'_> {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![meta(&self.transfer, false, false)]
+    }
+}
+
+impl<'info> ToAccountInfos<'info> for GetTransferStatus<'info> {
+    fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+        vec![self.transfer.clone()]
+    }
+}
+
+/// Accounts of `get_nft_origin`
+pub struct GetNftOrigin<'info> {
+    /// `pda::nft_origin(mint)`
+    pub nft_origin: AccountInfo<'info>,
+}
+
+impl ToAccountMetas for GetNftOrigin<'_> {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![meta(&self.nft_origin, false, false)]
+    }
+}
+
+impl<'info> ToAccountInfos<'info> for GetNftOrigin<'info> {
+    fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+        vec![self.nft_origin.clone()]
+    }
+}
+
+/// Accounts of `get_chain_config`
+pub struct GetChainConfig<'info> {
+    /// `pda::chain(chain_id)`
+    pub chain: AccountInfo<'info>,
+}
+
+impl ToAccountMetas for GetChainConfig<'_> {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![meta(&self.chain, false, false)]
+    }
+}
+
+impl<'info> ToAccountInfos<'info> for GetChainConfig<'info> {
+    fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+        vec![self.chain.clone()]
+    }
+}
+
+/// Accounts of `deposit_royalty`. The escrow is created on the first deposit.
+pub struct DepositRoyalty<'info> {
+    /// `pda::royalty_config(collection_mint)`
+    pub royalty_config: AccountInfo<'info>,
+    /// `pda::royalty_escrow(collection_mint)`
+    pub royalty_escrow: AccountInfo<'info>,
+    pub depositor: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl ToAccountMetas for DepositRoyalty<'_> {
+    fn to_account_metas(&self, is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![
+            meta(&self.royalty_config, false, false),
+            meta(&self.royalty_escrow, true, false),
+            meta(&self.depositor, true, signer_flag(is_signer, true)),
+            meta(&self.system_program, false, false),
+        ]
+    }
+}
+
+impl<'info> ToAccountInfos<'info> for DepositRoyalty<'info> {
+    fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+        vec![
+            self.royalty_config.clone(),
+            self.royalty_escrow.clone(),
+            self.depositor.clone(),
+            self.system_program.clone(),
+        ]
+    }
+}
+
+/// Accounts of `get_royalty_accounting`
+pub struct GetRoyaltyAccounting<'info> {
+    /// `pda::royalty_escrow(collection_mint)`
+    pub royalty_escrow: AccountInfo<'info>,
+}
+
+impl ToAccountMetas for GetRoyaltyAccounting<'_> {
+    fn to_account_metas(&self, _is_signer: Option<bool>) -> Vec<AccountMeta> {
+        vec![meta(&self.royalty_escrow, false, false)]
+    }
+}
+
+impl<'info> ToAccountInfos<'info> for GetRoyaltyAccounting<'info> {
+    fn to_account_infos(&self) -> Vec<AccountInfo<'info>> {
+        vec![self.royalty_escrow.clone()]
+    }
+}
@@ -0,0 +1,128 @@
+//! Typed CPI client, called like the client Anchor generates with the
+//! program's `cpi` feature. Pass PDA signer seeds with
+//! `CpiContext::new_with_signer` when the owner or payer is a PDA of the
+//! calling program.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::Instruction,
+    program::{get_return_data, invoke_signed},
+};
+use anchor_lang::InstructionData;
+
+use crate::view::{ChainConfigView, NftOriginView, RoyaltyAccountingView, TransferStatusView};
+use crate::{accounts, instruction, GasPayment};
+
+fn invoke<'info, T: ToAccountMetas + ToAccountInfos<'info>>(
+    ctx: CpiContext<'_, '_, '_, 'info, T>,
+    data: Vec<u8>,
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: crate::ID,
+        accounts: ctx.to_account_metas(None),
+        data,
+    };
+    invoke_signed(&ix, &ctx.to_account_infos(), ctx.signer_seeds).map_err(Into::into)
+}
+
+/// Invoke a view instruction and decode what it left in return data
+fn invoke_view<'info, T, V>(ctx: CpiContext<'_, '_, '_, 'info, T>, data: Vec<u8>) -> Result<V>
+where
+    T: ToAccountMetas + ToAccountInfos<'info>,
+    V: AnchorDeserialize,
+{
+    invoke(ctx, data)?;
+    match get_return_data() {
+        Some((program_id, data)) if program_id == crate::ID => {
+            V::try_from_slice(&data).map_err(|_| ErrorCode::InstructionDidNotDeserialize.into())
+        }
+        _ => err!(ErrorCode::InstructionDidNotDeserialize),
+    }
+}
+
+/// Mint a universal NFT to `accounts.owner`
+pub fn mint_nft<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::MintNft<'info>>,
+    name: String,
+    symbol: String,
+    uri: String,
+    collection_mint: Option<Pubkey>,
+    allowlist_proof: Vec<[u8; 32]>,
+    referrer: Option<Pubkey>,
+) -> Result<()> {
+    let data = instruction::MintNft {
+        name,
+        symbol,
+        uri,
+        collection_mint,
+        allowlist_proof,
+        referrer,
+    }
+    .data();
+    invoke(ctx, data)
+}
+
+/// Transfer a universal NFT to `accounts.new_owner` on Solana
+pub fn transfer_nft<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::TransferNft<'info>>,
+) -> Result<()> {
+    invoke(ctx, instruction::TransferNft {}.data())
+}
+
+/// Burn a universal NFT and bridge it to `recipient` on another chain
+pub fn burn_and_transfer<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::BurnAndTransfer<'info>>,
+    destination_chain_id: u64,
+    recipient: Vec<u8>,
+    gas_limit: u64,
+    gas_payment: GasPayment,
+    payload: Vec<u8>,
+    referrer: Option<Pubkey>,
+) -> Result<()> {
+    let data = instruction::BurnAndTransfer {
+        destination_chain_id,
+        recipient,
+        gas_limit,
+        gas_payment,
+        payload,
+        referrer,
+    }
+    .data();
+    invoke(ctx, data)
+}
+
+/// Read an outbound transfer's status
+pub fn get_transfer_status<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::GetTransferStatus<'info>>,
+) -> Result<TransferStatusView> {
+    invoke_view(ctx, instruction::GetTransferStatus {}.data())
+}
+
+/// Read where an NFT originally came from
+pub fn get_nft_origin<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::GetNftOrigin<'info>>,
+) -> Result<NftOriginView> {
+    invoke_view(ctx, instruction::GetNftOrigin {}.data())
+}
+
+/// Read a registered chain's settings and 24h outbound volume
+pub fn get_chain_config<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::GetChainConfig<'info>>,
+) -> Result<ChainConfigView> {
+    invoke_view(ctx, instruction::GetChainConfig {}.data())
+}
+
+/// Deposit a sale's royalty into the collection's escrow for its creators
+pub fn deposit_royalty<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::DepositRoyalty<'info>>,
+    amount: u64,
+) -> Result<()> {
+    invoke(ctx, instruction::DepositRoyalty { amount }.data())
+}
+
+/// Read a collection's lifetime royalty accounting
+pub fn get_royalty_accounting<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, accounts::GetRoyaltyAccounting<'info>>,
+) -> Result<RoyaltyAccountingView> {
+    invoke_view(ctx, instruction::GetRoyaltyAccounting {}.data())
+}
@@ -0,0 +1,152 @@
+//! Instruction arguments, laid out as the program decodes them: the 8-byte
+//! Anchor discriminator (`sha256("global:<name>")[..8]`) followed by the
+//! Borsh-encoded arguments in declaration order.
+
+use anchor_lang::prelude::*;
+use anchor_lang::{Discriminator, InstructionData};
+
+use crate::GasPayment;
+
+/// Arguments of `mint_nft`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MintNft {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub collection_mint: Option<Pubkey>,
+    pub allowlist_proof: Vec<[u8; 32]>,
+    /// Registered referrer credited a share of the mint fee
+    pub referrer: Option<Pubkey>,
+}
+
+impl Discriminator for MintNft {
+    const DISCRIMINATOR: [u8; 8] = [211, 57, 6, 167, 15, 219, 35, 251];
+}
+
+impl InstructionData for MintNft {}
+
+/// Arguments of `transfer_nft`, which takes none
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TransferNft {}
+
+impl Discriminator for TransferNft {
+    const DISCRIMINATOR: [u8; 8] = [190, 28, 194, 8, 194, 218, 78, 78];
+}
+
+impl InstructionData for TransferNft {}
+
+/// Arguments of `burn_and_transfer`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BurnAndTransfer {
+    pub destination_chain_id: u64,
+    /// Recipient address on the destination chain
+    pub recipient: Vec<u8>,
+    pub gas_limit: u64,
+    pub gas_payment: GasPayment,
+    /// Application payload forwarded with the NFT
+    pub payload: Vec<u8>,
+    /// Registered referrer credited a share of the bridge fee
+    pub referrer: Option<Pubkey>,
+}
+
+impl Discriminator for BurnAndTransfer {
+    const DISCRIMINATOR: [u8; 8] = [172, 155, 34, 23, 142, 9, 171, 68];
+}
+
+impl InstructionData for BurnAndTransfer {}
+
+/// Arguments of `get_transfer_status`, which takes none
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GetTransferStatus {}
+
+impl Discriminator for GetTransferStatus {
+    const DISCRIMINATOR: [u8; 8] = [35, 246, 197, 18, 156, 66, 203, 253];
+}
+
+impl InstructionData for GetTransferStatus {}
+
+/// Arguments of `get_nft_origin`, which takes none
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GetNftOrigin {}
+
+impl Discriminator for GetNftOrigin {
+    const DISCRIMINATOR: [u8; 8] = [244, 80, 40, 146, 251, 107, 27, 20];
+}
+
+impl InstructionData for GetNftOrigin {}
+
+/// Arguments of `get_chain_config`, which takes none
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GetChainConfig {}
+
+impl Discriminator for GetChainConfig {
+    const DISCRIMINATOR: [u8; 8] = [170, 157, 36, 63, 128, 41, 235, 211];
+}
+
+impl InstructionData for GetChainConfig {}
+
+/// Arguments of `deposit_royalty`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DepositRoyalty {
+    /// Royalty for the sale, in lamports
+    pub amount: u64,
+}
+
+impl Discriminator for DepositRoyalty {
+    const DISCRIMINATOR: [u8; 8] = [234, 6, 85, 217, 36, 30, 33, 127];
+}
+
+impl InstructionData for DepositRoyalty {}
+
+/// Arguments of `get_royalty_accounting`, which takes none
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GetRoyaltyAccounting {}
+
+impl Discriminator for GetRoyaltyAccounting {
+    const DISCRIMINATOR: [u8; 8] = [244, 112, 189, 109, 28, 1, 69, 162];
+}
+
+impl InstructionData for GetRoyaltyAccounting {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::hash::hash;
+
+    fn sighash(name: &str) -> [u8; 8] {
+        let mut discriminator = [0u8; 8];
+        discriminator.copy_from_slice(&hash(format!("global:{}", name).as_bytes()).to_bytes()[..8]);
+        discriminator
+    }
+
+    #[test]
+    fn test_discriminators_match_instruction_names() {
+        assert_eq!(MintNft::DISCRIMINATOR, sighash("mint_nft"));
+        assert_eq!(TransferNft::DISCRIMINATOR, sighash("transfer_nft"));
+        assert_eq!(BurnAndTransfer::DISCRIMINATOR, sighash("burn_and_transfer"));
+        assert_eq!(GetTransferStatus::DISCRIMINATOR, sighash("get_transfer_status"));
+        assert_eq!(GetNftOrigin::DISCRIMINATOR, sighash("get_nft_origin"));
+        assert_eq!(GetChainConfig::DISCRIMINATOR, sighash("get_chain_config"));
+        assert_eq!(DepositRoyalty::DISCRIMINATOR, sighash("deposit_royalty"));
+        assert_eq!(GetRoyaltyAccounting::DISCRIMINATOR, sighash("get_royalty_accounting"));
+    }
+
+    #[test]
+    fn test_instruction_data_layout() {
+        let args = BurnAndTransfer {
+            destination_chain_id: 7001,
+            recipient: vec![0xab; 20],
+            gas_limit: 200_000,
+            gas_payment: GasPayment::Sol,
+            payload: vec![],
+            referrer: None,
+        };
+        let data = args.data();
+
+        assert_eq!(&data[..8], &BurnAndTransfer::DISCRIMINATOR);
+        assert_eq!(&data[8..16], &7001u64.to_le_bytes());
+        assert_eq!(&data[16..20], &20u32.to_le_bytes());
+        assert_eq!(data.len(), 8 + 8 + 4 + 20 + 8 + 1 + 4 + 1);
+        assert_eq!(TransferNft {}.data(), TransferNft::DISCRIMINATOR.to_vec());
+    }
+}
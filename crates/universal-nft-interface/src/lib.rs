@@ -0,0 +1,50 @@
+//! Interface to the Universal NFT program for other on-chain programs.
+//!
+//! Games, marketplaces and other Anchor programs can depend on this crate
+//! instead of the full program crate to mint, transfer and bridge universal
+//! NFTs by CPI. It carries the instruction discriminators, argument layouts
+//! and account orders, plus PDA helpers, and pulls in nothing but
+//! `anchor-lang`.
+//!
+//! ```ignore
+//! use universal_nft_interface::{accounts::TransferNft, cpi};
+//!
+//! let ctx = CpiContext::new(universal_nft_program.to_account_info(), TransferNft { .. });
+//! cpi::transfer_nft(ctx)?;
+//! ```
+//!
+//! The `get_*` view instructions return typed state through return data, so
+//! callers can read transfers, origins and chain settings without decoding
+//! the program's accounts.
+
+use anchor_lang::prelude::*;
+
+pub mod accounts;
+pub mod cpi;
+pub mod instruction;
+pub mod pda;
+pub mod view;
+
+declare_id!("EiGgwyFXtqcNEutPaUe94J9c9sPaPnDWj64sFcD7W9sz");
+
+/// Metaplex Token Metadata program, which owns NFT metadata and editions
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// Destination gas payment method for outbound calls. Mirrors the program's
+/// `GasPayment`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GasPayment {
+    /// Pay in SOL through the gateway
+    Sol,
+    /// Pay in an SPL token representing a ZRC-20 gas token
+    Zrc20,
+}
+
+/// Messaging network a transfer is sent through. Mirrors the program's
+/// `MessageLayer`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MessageLayer {
+    ZetaGateway,
+    Wormhole,
+    LayerZero,
+}
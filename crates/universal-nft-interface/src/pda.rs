@@ -0,0 +1,289 @@
+use anchor_lang::prelude::*;
+
+use crate::{MessageLayer, TOKEN_METADATA_PROGRAM_ID};
+
+/// Program configuration
+pub fn config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config"], &crate::ID)
+}
+
+/// Universal NFT record for a mint
+pub fn universal_nft(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"universal_nft", mint.as_ref()], &crate::ID)
+}
+
+/// Program-wide mint and freeze authority of universal NFT mints
+pub fn mint_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"mint_authority"], &crate::ID)
+}
+
+/// Registry entry for a destination chain
+pub fn chain(chain_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"chain", chain_id.to_le_bytes().as_ref()], &crate::ID)
+}
+
+/// Config of a message layer outbound transfers can be sent through
+pub fn message_layer(layer: MessageLayer) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"message_layer", &[layer as u8]], &crate::ID)
+}
+
+/// Metadata translation rules of a destination chain
+pub fn metadata_rules(chain_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"metadata_rules", chain_id.to_le_bytes().as_ref()], &crate::ID)
+}
+
+/// Lock/wrap supply counters of a destination chain
+pub fn supply_ledger(chain_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"supply_ledger", chain_id.to_le_bytes().as_ref()], &crate::ID)
+}
+
+/// Attribute entries of an NFT, written by its collection's trait authorities
+pub fn nft_attributes(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"nft_attributes", mint.as_ref()], &crate::ID)
+}
+
+/// Children equipped into an NFT and its bridge-out mode
+pub fn nft_children(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"nft_children", mint.as_ref()], &crate::ID)
+}
+
+/// Link from an equipped NFT to its parent
+pub fn nft_parent(child_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"nft_parent", child_mint.as_ref()], &crate::ID)
+}
+
+/// Evolution recipe of a collection
+pub fn evolution_recipe(collection_mint: &Pubkey, recipe_id: u32) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"evolution_recipe", collection_mint.as_ref(), recipe_id.to_le_bytes().as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Vesting position an NFT wraps, whose token vault is this PDA's associated
+/// token account
+pub fn vesting_position(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"vesting", mint.as_ref()], &crate::ID)
+}
+
+/// Proof-of-attendance drop of a collection's event, the delegate of the
+/// drop's Bubblegum tree
+pub fn attendance_drop(collection_mint: &Pubkey, event_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"attendance_drop", collection_mint.as_ref(), &event_id.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+/// Credential profile of a collection
+pub fn credential_profile(collection_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"credential_profile", collection_mint.as_ref()], &crate::ID)
+}
+
+/// Credential `credential_id` of a collection
+pub fn credential(collection_mint: &Pubkey, credential_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"credential", collection_mint.as_ref(), &credential_id.to_le_bytes()],
+        &crate::ID,
+    )
+}
+
+/// Revocation registry entry of a credential, which exists once it is
+/// revoked
+pub fn credential_revocation(credential: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"credential_revocation", credential.as_ref()], &crate::ID)
+}
+
+/// Swap `swap_id` offered by `maker`, whose vault is this PDA's associated
+/// token account for the maker's mint
+pub fn swap(maker: &Pubkey, swap_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"swap", maker.as_ref(), &swap_id.to_le_bytes()], &crate::ID)
+}
+
+/// Cross-chain swap `swap_id` of `maker`, whose vault is this PDA's
+/// associated token account for the escrowed NFT
+pub fn cross_chain_swap(maker: &Pubkey, swap_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"xswap", maker.as_ref(), &swap_id.to_le_bytes()], &crate::ID)
+}
+
+/// Loan against `nft_mint`, whose vault is this PDA's associated token
+/// account for the NFT
+pub fn loan(nft_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"loan", nft_mint.as_ref()], &crate::ID)
+}
+
+/// Ticketing profile of a collection
+pub fn ticketing_profile(collection_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"ticketing", collection_mint.as_ref()], &crate::ID)
+}
+
+/// Event and check-in state of a ticket NFT
+pub fn ticket(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"ticket", mint.as_ref()], &crate::ID)
+}
+
+/// Protocol fee schedule set by governance
+pub fn fee_schedule() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"fee_schedule"], &crate::ID)
+}
+
+/// Satellite program IDs and versions approved by governance
+pub fn deployment_registry() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"deployment_registry"], &crate::ID)
+}
+
+/// Promotional campaign refunding part of protocol fees
+pub fn rebate_campaign() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"rebate_campaign"], &crate::ID)
+}
+
+/// Referral earnings of a registered referrer
+pub fn referral(referrer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"referral", referrer.as_ref()], &crate::ID)
+}
+
+/// Activity metrics and points balance of a user
+pub fn user_metrics(user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"user_metrics", user.as_ref()], &crate::ID)
+}
+
+/// Activity points weights and redeemers
+pub fn points_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"points_config"], &crate::ID)
+}
+
+/// Usage counters for a collection and integration. NFTs outside a
+/// collection use the default key; direct use is integration zero.
+pub fn usage_segment(collection_mint: &Pubkey, integration_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"usage_segment", collection_mint.as_ref(), integration_id.to_le_bytes().as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Top-N leaderboard: 0 minters, 1 bridgers, 2 bridged collections
+pub fn leaderboard(kind: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"leaderboard", &[kind]], &crate::ID)
+}
+
+/// Metrics and corridor statistics of a registered chain
+pub fn chain_metrics(chain_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"chain_metrics", chain_id.to_le_bytes().as_ref()], &crate::ID)
+}
+
+/// Pause state of the corridors between Solana and a chain
+pub fn corridor_pause(chain_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"corridor_pause", chain_id.to_le_bytes().as_ref()], &crate::ID)
+}
+
+/// Fraud detection engine scoring outbound transfers
+pub fn fraud_engine() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"fraud_engine"], &crate::ID)
+}
+
+/// Governance's chain risk registry the fraud engine scores chain pairs with
+pub fn chain_risk_registry() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"chain_risk_registry"], &crate::ID)
+}
+
+/// Destination and sequence counter of exported security events
+pub fn security_export() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"security_export"], &crate::ID)
+}
+
+/// Outbound transfer record. `nonce` is the config nonce before the transfer
+/// is created, which the record stores as its own `nonce`.
+pub fn transfer(mint: &Pubkey, nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"transfer", mint.as_ref(), nonce.to_le_bytes().as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Non-transferable receipt mint for an outbound transfer
+pub fn receipt_mint(transfer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"receipt_mint", transfer.as_ref()], &crate::ID)
+}
+
+/// Authority that mints, freezes and burns transfer receipts
+pub fn receipt_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"receipt_authority"], &crate::ID)
+}
+
+/// Origin record of a Solana representation of a foreign NFT
+pub fn nft_origin(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"nft_origin", mint.as_ref()], &crate::ID)
+}
+
+/// Royalty split among a collection's creators
+pub fn royalty_config(collection_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"royalty_config", collection_mint.as_ref()], &crate::ID)
+}
+
+/// Escrow holding a collection's deposited royalties
+pub fn royalty_escrow(collection_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"royalty_escrow", collection_mint.as_ref()], &crate::ID)
+}
+
+/// Floor price of a collection, for value caps, loans and insurance
+pub fn collection_floor(collection_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"collection_floor", collection_mint.as_ref()], &crate::ID)
+}
+
+/// A wallet's transfer lock
+pub fn wallet_lock(wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"wallet_lock", wallet.as_ref()], &crate::ID)
+}
+
+/// Operator session of `operator` over `owner`'s NFTs
+pub fn operator_session(owner: &Pubkey, operator: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"operator_session", owner.as_ref(), operator.as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Metaplex metadata account of a mint
+pub fn metadata(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &TOKEN_METADATA_PROGRAM_ID,
+    )
+}
+
+/// Metaplex master edition account of a mint
+pub fn master_edition(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"metadata", TOKEN_METADATA_PROGRAM_ID.as_ref(), mint.as_ref(), b"edition"],
+        &TOKEN_METADATA_PROGRAM_ID,
+    )
+}
+
+/// Circuit breaker guarding the traffic between Solana and a chain
+pub fn circuit_breaker(chain_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"circuit_breaker", chain_id.to_le_bytes().as_ref()], &crate::ID)
+}
+
+/// Protocol-wide incident mode
+pub fn incident_mode() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"incident_mode"], &crate::ID)
+}
+
+/// Token bucket rate limiting a wallet's mints and outbound transfers
+pub fn wallet_rate_limit(wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"rate_limit", b"wallet", wallet.as_ref()], &crate::ID)
+}
+
+/// Token bucket rate limiting outbound transfers to a chain
+pub fn chain_rate_limit(chain_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"rate_limit", b"chain", chain_id.to_le_bytes().as_ref()],
+        &crate::ID,
+    )
+}
+
+/// Token bucket rate limiting a collection's mints and outbound transfers;
+/// NFTs outside a collection use `Pubkey::default()`
+pub fn collection_rate_limit(collection_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"rate_limit", b"collection", collection_mint.as_ref()], &crate::ID)
+}
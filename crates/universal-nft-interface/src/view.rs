@@ -0,0 +1,117 @@
+//! Data returned by the program's read-only view instructions, mirroring the
+//! program's `*View` types and the state enums they carry. Variant order is
+//! the Borsh encoding, so it must stay in sync with the program.
+
+use anchor_lang::prelude::*;
+
+/// Lifecycle of an outbound transfer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransferStatus {
+    Initiated,
+    Processing,
+    Completed,
+    Reverted,
+    Cancelled,
+}
+
+/// Kind of a registered chain
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChainKind {
+    Evm,
+    ZetaChain,
+    Bitcoin,
+    Ton,
+    Sui,
+}
+
+/// Recipient address encoding a registered chain accepts
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressFormat {
+    Evm,
+    Bitcoin,
+    Ton,
+    Sui,
+}
+
+/// Bitcoin ordinal inscription (`<txid>i<index>`)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InscriptionRef {
+    pub txid: [u8; 32],
+    pub index: u32,
+}
+
+/// Outbound caps for one chain; zero disables a cap
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChainTransferLimits {
+    pub max_value_per_transfer: u64,
+    pub daily_value_cap: u64,
+    pub daily_transfer_cap: u32,
+    pub enforcement: RuleEnforcement,
+}
+
+/// Whether a security rule rejects breaches or only reports them
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RuleEnforcement {
+    Off,
+    Shadow,
+    Enforce,
+}
+
+/// Returned by `get_transfer_status`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TransferStatusView {
+    pub transfer: Pubkey,
+    pub nft_mint: Pubkey,
+    pub destination_chain_id: u64,
+    pub nonce: u64,
+    pub status: TransferStatus,
+    pub timestamp: i64,
+    pub gas_fee: u64,
+}
+
+/// Returned by `get_nft_origin`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct NftOriginView {
+    pub mint: Pubkey,
+    pub origin_chain_id: u64,
+    pub chain_kind: ChainKind,
+    /// Sender on the origin chain, in its native encoding
+    pub origin_sender: Vec<u8>,
+    pub inscription: Option<InscriptionRef>,
+    pub recorded_at: i64,
+}
+
+/// Returned by `get_chain_config`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ChainConfigView {
+    pub chain_id: u64,
+    pub chain_kind: ChainKind,
+    pub address_format: AddressFormat,
+    pub counterpart_contract: [u8; 20],
+    pub enabled: bool,
+    pub limits: ChainTransferLimits,
+    /// Outbound floor value over the last 24h, in lamports
+    pub rolling_value: u64,
+    /// Outbound transfers over the last 24h
+    pub rolling_count: u64,
+}
+
+/// A creator's lifetime royalties from a collection
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CreatorRoyalty {
+    pub creator: Pubkey,
+    pub earned: u64,
+    pub claimed: u64,
+}
+
+/// Returned by `get_royalty_accounting`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RoyaltyAccountingView {
+    pub collection_mint: Pubkey,
+    /// Lamports deposited by marketplaces over the escrow's lifetime
+    pub total_deposited: u64,
+    /// Lamports claimed by creators over the escrow's lifetime
+    pub total_claimed: u64,
+    pub deposit_count: u64,
+    pub creators: Vec<CreatorRoyalty>,
+}
@@ -110,7 +110,7 @@ class CrossChainDemo {
 
     // Mint the NFT
     const signature = await this.program.methods
-      .mintNft(name, symbol, uri, collection || null)
+      .mintNft(name, symbol, uri, collection || null, [])
       .accounts({
         config: configPda,
         universalNft: universalNftPda,
@@ -118,7 +118,10 @@ class CrossChainDemo {
         metadata: metadataPda,
         masterEdition: masterEditionPda,
         tokenAccount: tokenAccount,
-        mintAuthority: universalNftPda,
+        mintAuthority: PublicKey.findProgramAddressSync(
+          [Buffer.from("mint_authority")],
+          this.program.programId
+        )[0],
         owner: owner,
         payer: this.provider.wallet.publicKey,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -178,7 +181,7 @@ class CrossChainDemo {
 
     // Get current nonce for transfer PDA
     const config = await this.program.account.programConfig.fetch(configPda);
-    const nonce = config.nonce.add(new anchor.BN(1));
+    const nonce = config.nonce;
 
     const [transferPda] = PublicKey.findProgramAddressSync(
       [
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::errors::UniversalNftError;
+use crate::analytics::DowntimeWindow;
 
 /// Enterprise Solutions Module for Universal NFT Protocol
 /// Provides enterprise-grade features for institutional adoption
@@ -51,6 +52,22 @@ pub struct EnterpriseClient {
     pub bump: u8,
 }
 
+/// Client billing account outage credits accrue into
+#[account]
+#[derive(InitSpace)]
+pub struct EnterpriseBilling {
+    /// Client the account bills
+    pub client_id: u64,
+    /// Credits waiting to be applied to the next invoice (lamports)
+    pub outage_credits: u64,
+    /// Credits applied to invoices so far (lamports)
+    pub credits_claimed: u64,
+    /// End of the last downtime window credited, so windows accrue once
+    pub credited_through: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct EnterpriseTier {
     /// Tier name
@@ -404,6 +421,58 @@ impl EnterpriseManager {
         Ok(())
     }
 
+    /// Accrue SLA outage credits for a downtime window the monitor recorded.
+    /// Only the part of the window inside the client's contract counts; each
+    /// started hour of it earns `outage_credits_bps` of the monthly fee, up
+    /// to one month's fee per window. Windows already credited earn nothing.
+    pub fn accrue_outage_credits(
+        &self,
+        client: &EnterpriseClient,
+        billing: &mut EnterpriseBilling,
+        window: DowntimeWindow,
+    ) -> Result<u64> {
+        require!(billing.client_id == client.client_id, UniversalNftError::InvalidBillingAccount);
+        if window.end <= billing.credited_through {
+            return Ok(0);
+        }
+
+        let start = window.start.max(billing.credited_through);
+        let overlap = DowntimeWindow { start, end: window.end }
+            .overlap(client.created_at, client.contract_end_date);
+        billing.credited_through = window.end;
+        if overlap == 0 {
+            return Ok(0);
+        }
+
+        let monthly_fee = self.tier_configs[Self::tier_index(&client.tier)].monthly_fee;
+        let hours = (overlap as u64).div_ceil(3600);
+        let credit = (monthly_fee as u128)
+            .checked_mul(client.sla.outage_credits_bps as u128)
+            .and_then(|v| v.checked_mul(hours as u128))
+            .ok_or(UniversalNftError::ArithmeticOverflow)?
+            / 10_000;
+        let credit = credit.min(monthly_fee as u128) as u64;
+
+        billing.outage_credits = billing.outage_credits.checked_add(credit)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+        msg!("Outage credit of {} accrued for client {} ({}s of downtime)",
+             credit, client.client_id, overlap);
+        Ok(credit)
+    }
+
+    /// Apply accrued outage credits to an invoice, returning what is still
+    /// due. Credits beyond the invoice carry over to the next one.
+    pub fn claim_outage_credits(billing: &mut EnterpriseBilling, invoice_amount: u64) -> Result<u64> {
+        let applied = billing.outage_credits.min(invoice_amount);
+        billing.outage_credits -= applied;
+        billing.credits_claimed = billing.credits_claimed.checked_add(applied)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+        msg!("Outage credits applied: {} of {}", applied, invoice_amount);
+        Ok(invoice_amount - applied)
+    }
+
     /// Generate enterprise report
     pub fn generate_enterprise_report(&self, client: &EnterpriseClient) -> EnterpriseReport {
         let now = Clock::get().unwrap().unix_timestamp;
@@ -488,6 +557,15 @@ impl EnterpriseManager {
         ]
     }
 
+    fn tier_index(tier: &EnterpriseClientTier) -> usize {
+        match tier {
+            EnterpriseClientTier::Startup => 0,
+            EnterpriseClientTier::Growth => 1,
+            EnterpriseClientTier::Enterprise => 2,
+            EnterpriseClientTier::Fortune500 => 3,
+        }
+    }
+
     fn create_tier_config(tier: &EnterpriseClientTier) -> EnterpriseConfig {
         match tier {
             EnterpriseClientTier::Startup => EnterpriseConfig {
@@ -0,0 +1,330 @@
+use anchor_lang::prelude::*;
+use crate::errors::GovernanceError;
+
+/// Bug Bounty Program for Universal NFT Protocol
+/// Holds treasury-funded lamports for security reports. Whitehats register a
+/// report by its hash, and the security council pays out the tier matching the
+/// accepted severity.
+#[account]
+#[derive(InitSpace)]
+pub struct BountyProgram {
+    /// Program authority (treasury authority)
+    pub authority: Pubkey,
+    /// Treasury account allowed to fund this program
+    pub treasury: Pubkey,
+    /// Council that accepts or rejects reports
+    pub security_council: Pubkey,
+    /// Lamports paid per severity, indexed by `BountySeverity`
+    pub tier_payouts: [u64; 4],
+    /// Total lamports funded by the treasury
+    pub total_funded: u64,
+    /// Total lamports paid to reporters
+    pub total_paid: u64,
+    /// Reports submitted
+    pub reports_submitted: u64,
+    /// Reports paid
+    pub reports_paid: u64,
+    /// Whether new reports are accepted
+    pub accepting_reports: bool,
+    /// Program creation timestamp
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// A submitted security report, identified by the hash of its off-chain contents
+#[account]
+#[derive(InitSpace)]
+pub struct BountyReport {
+    /// Hash of the report contents
+    pub report_hash: [u8; 32],
+    /// Whitehat who submitted the report and receives the payout
+    pub reporter: Pubkey,
+    /// Review status
+    pub status: BountyReportStatus,
+    /// Severity assigned on acceptance
+    pub severity: Option<BountySeverity>,
+    /// Lamports paid
+    pub payout: u64,
+    /// Submission timestamp
+    pub submitted_at: i64,
+    /// Resolution timestamp
+    pub resolved_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum BountySeverity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum BountyReportStatus {
+    /// Awaiting security council review
+    Pending,
+    /// Accepted and paid
+    Paid,
+    /// Rejected by the security council
+    Rejected,
+}
+
+impl BountyProgram {
+    pub const INIT_SPACE: usize =
+        32 +    // authority
+        32 +    // treasury
+        32 +    // security_council
+        8 * 4 + // tier_payouts
+        8 +     // total_funded
+        8 +     // total_paid
+        8 +     // reports_submitted
+        8 +     // reports_paid
+        1 +     // accepting_reports
+        8 +     // created_at
+        1;      // bump
+
+    /// Initialize the bounty program
+    pub fn initialize(
+        &mut self,
+        authority: Pubkey,
+        treasury: Pubkey,
+        security_council: Pubkey,
+        tier_payouts: [u64; 4],
+        bump: u8,
+    ) -> Result<()> {
+        Self::validate_tiers(&tier_payouts)?;
+
+        self.authority = authority;
+        self.treasury = treasury;
+        self.security_council = security_council;
+        self.tier_payouts = tier_payouts;
+        self.total_funded = 0;
+        self.total_paid = 0;
+        self.reports_submitted = 0;
+        self.reports_paid = 0;
+        self.accepting_reports = true;
+        self.created_at = Clock::get()?.unix_timestamp;
+        self.bump = bump;
+
+        log!("Bug bounty program initialized");
+        log!("Security council: {}", security_council);
+
+        Ok(())
+    }
+
+    /// Update the council, payout tiers and intake status
+    pub fn update(
+        &mut self,
+        security_council: Pubkey,
+        tier_payouts: [u64; 4],
+        accepting_reports: bool,
+    ) -> Result<()> {
+        Self::validate_tiers(&tier_payouts)?;
+
+        self.security_council = security_council;
+        self.tier_payouts = tier_payouts;
+        self.accepting_reports = accepting_reports;
+
+        log!("Bug bounty program updated");
+        Ok(())
+    }
+
+    /// Record lamports moved into the program from the treasury
+    pub fn record_funding(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, GovernanceError::InvalidBountyParameters);
+
+        self.total_funded = self.total_funded.checked_add(amount)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+
+        log!("Bug bounty program funded: {} lamports", amount);
+        Ok(())
+    }
+
+    /// Record a newly submitted report
+    pub fn record_submission(&mut self) -> Result<()> {
+        require!(self.accepting_reports, GovernanceError::BountyProgramClosed);
+
+        self.reports_submitted = self.reports_submitted.checked_add(1)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Lamports owed for an accepted report of `severity`
+    pub fn payout_for(&self, severity: BountySeverity) -> u64 {
+        self.tier_payouts[severity as usize]
+    }
+
+    /// Record a payout to a reporter
+    pub fn record_payout(&mut self, amount: u64) -> Result<()> {
+        self.total_paid = self.total_paid.checked_add(amount)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        self.reports_paid = self.reports_paid.checked_add(1)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Resolve a pending report, accepting it at `severity` or rejecting it
+    /// with `None`, and return the payout owed to the reporter. `available` is
+    /// what the program can pay while staying rent exempt.
+    pub fn resolve_report(
+        &mut self,
+        report: &mut BountyReport,
+        severity: Option<BountySeverity>,
+        available: u64,
+        now: i64,
+    ) -> Result<u64> {
+        require!(
+            report.status == BountyReportStatus::Pending,
+            GovernanceError::BountyReportAlreadyResolved
+        );
+
+        let payout = match severity {
+            Some(severity) => {
+                let amount = self.payout_for(severity);
+                require!(amount <= available, GovernanceError::InsufficientBountyFunds);
+                self.record_payout(amount)?;
+                report.status = BountyReportStatus::Paid;
+                amount
+            }
+            None => {
+                report.status = BountyReportStatus::Rejected;
+                0
+            }
+        };
+
+        report.severity = severity;
+        report.payout = payout;
+        report.resolved_at = now;
+        Ok(payout)
+    }
+
+    /// Tiers must be non-zero and rise with severity
+    fn validate_tiers(tier_payouts: &[u64; 4]) -> Result<()> {
+        require!(tier_payouts[0] > 0, GovernanceError::InvalidBountyParameters);
+        require!(
+            tier_payouts.windows(2).all(|w| w[0] <= w[1]),
+            GovernanceError::InvalidBountyParameters
+        );
+        Ok(())
+    }
+}
+
+impl BountyReport {
+    pub const INIT_SPACE: usize =
+        32 +    // report_hash
+        32 +    // reporter
+        1 +     // status
+        1 + 1 + // severity
+        8 +     // payout
+        8 +     // submitted_at
+        8 +     // resolved_at
+        1;      // bump
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounty_program(tier_payouts: [u64; 4]) -> BountyProgram {
+        BountyProgram {
+            authority: Pubkey::new_unique(),
+            treasury: Pubkey::new_unique(),
+            security_council: Pubkey::new_unique(),
+            tier_payouts,
+            total_funded: 0,
+            total_paid: 0,
+            reports_submitted: 0,
+            reports_paid: 0,
+            accepting_reports: true,
+            created_at: 0,
+            bump: 255,
+        }
+    }
+
+    fn pending_report() -> BountyReport {
+        BountyReport {
+            report_hash: [1u8; 32],
+            reporter: Pubkey::new_unique(),
+            status: BountyReportStatus::Pending,
+            severity: None,
+            payout: 0,
+            submitted_at: 0,
+            resolved_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_tiers_rise_with_severity() {
+        let mut program = bounty_program([1, 2, 3, 4]);
+        let council = program.security_council;
+
+        for tiers in [[0, 2, 3, 4], [1, 3, 2, 4]] {
+            assert_eq!(
+                program.update(council, tiers, true).unwrap_err(),
+                GovernanceError::InvalidBountyParameters.into()
+            );
+        }
+        program.update(council, [10, 10, 50, 100], true).unwrap();
+        assert_eq!(program.payout_for(BountySeverity::Low), 10);
+        assert_eq!(program.payout_for(BountySeverity::Critical), 100);
+    }
+
+    #[test]
+    fn test_funding_and_submissions() {
+        let mut program = bounty_program([1, 2, 3, 4]);
+
+        assert_eq!(
+            program.record_funding(0).unwrap_err(),
+            GovernanceError::InvalidBountyParameters.into()
+        );
+        program.record_funding(1_000).unwrap();
+        assert_eq!(program.total_funded, 1_000);
+
+        program.record_submission().unwrap();
+        assert_eq!(program.reports_submitted, 1);
+
+        // A closed program takes no new reports
+        program.accepting_reports = false;
+        assert_eq!(
+            program.record_submission().unwrap_err(),
+            GovernanceError::BountyProgramClosed.into()
+        );
+    }
+
+    #[test]
+    fn test_resolve_report() {
+        let mut program = bounty_program([100, 200, 500, 1_000]);
+
+        // An accepted report pays its severity's tier once
+        let mut report = pending_report();
+        assert_eq!(program.resolve_report(&mut report, Some(BountySeverity::High), 500, 7).unwrap(), 500);
+        assert!(report.status == BountyReportStatus::Paid);
+        assert!(report.severity == Some(BountySeverity::High));
+        assert_eq!((report.payout, report.resolved_at), (500, 7));
+        assert_eq!((program.total_paid, program.reports_paid), (500, 1));
+        assert_eq!(
+            program.resolve_report(&mut report, Some(BountySeverity::High), 500, 8).unwrap_err(),
+            GovernanceError::BountyReportAlreadyResolved.into()
+        );
+
+        // A payout the program can't cover leaves the report pending
+        let mut report = pending_report();
+        assert_eq!(
+            program.resolve_report(&mut report, Some(BountySeverity::Critical), 999, 9).unwrap_err(),
+            GovernanceError::InsufficientBountyFunds.into()
+        );
+        assert!(report.status == BountyReportStatus::Pending);
+
+        // A rejected report pays nothing and stays rejected
+        assert_eq!(program.resolve_report(&mut report, None, 999, 9).unwrap(), 0);
+        assert!(report.status == BountyReportStatus::Rejected);
+        assert_eq!((program.total_paid, program.reports_paid), (500, 1));
+        assert_eq!(
+            program.resolve_report(&mut report, Some(BountySeverity::Low), 999, 10).unwrap_err(),
+            GovernanceError::BountyReportAlreadyResolved.into()
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::errors::UniversalNftError;
+use crate::errors::GovernanceError;
 
 /// Decentralized Autonomous Organization for Universal NFT Protocol Governance
 /// Implements a sophisticated governance system with timelock, delegation, and treasury management
@@ -134,10 +134,10 @@ impl UniversalNftDAO {
         self.is_paused = false;
         self.bump = bump;
 
-        msg!("Universal NFT DAO initialized");
-        msg!("Governance token: {}", governance_token);
-        msg!("Proposal threshold: {}", config.proposal_threshold);
-        msg!("Quorum threshold: {}%", config.quorum_threshold);
+        log!("Universal NFT DAO initialized");
+        log!("Governance token: {}", governance_token);
+        log!("Proposal threshold: {}", config.proposal_threshold);
+        log!("Quorum threshold: {}%", config.quorum_threshold);
 
         Ok(())
     }
@@ -149,7 +149,7 @@ impl UniversalNftDAO {
         amount: u64,
         lock_duration: i64,
     ) -> Result<()> {
-        require!(!self.is_paused, UniversalNftError::ProgramPaused);
+        require!(!self.is_paused, GovernanceError::ProgramPaused);
         
         let now = Clock::get()?.unix_timestamp;
         
@@ -159,18 +159,18 @@ impl UniversalNftDAO {
 
         // Update stake account
         stake_account.amount = stake_account.amount.checked_add(amount)
-            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
         stake_account.staked_at = now;
         stake_account.lock_duration = lock_duration;
         stake_account.power_multiplier = power_multiplier;
         stake_account.voting_power = stake_account.voting_power.checked_add(voting_power)
-            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
 
         // Update DAO total
         self.total_staked = self.total_staked.checked_add(amount)
-            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
 
-        msg!("Tokens staked: {} with {}x multiplier", amount, power_multiplier);
+        log!("Tokens staked: {} with {}x multiplier", amount, power_multiplier);
         Ok(())
     }
 
@@ -180,20 +180,20 @@ impl UniversalNftDAO {
         stake_account: &mut GovernanceStake,
         amount: u64,
     ) -> Result<()> {
-        require!(!self.is_paused, UniversalNftError::ProgramPaused);
+        require!(!self.is_paused, GovernanceError::ProgramPaused);
         
         let now = Clock::get()?.unix_timestamp;
         
         // Check if lock period has expired
         require!(
             now >= stake_account.staked_at + stake_account.lock_duration,
-            UniversalNftError::InvalidTransferStatus // Reusing error for lock period
+            GovernanceError::InvalidStatus // Reusing error for lock period
         );
 
         // Check sufficient staked amount
         require!(
             stake_account.amount >= amount,
-            UniversalNftError::ArithmeticOverflow
+            GovernanceError::ArithmeticOverflow
         );
 
         // Calculate voting power reduction
@@ -201,15 +201,15 @@ impl UniversalNftDAO {
 
         // Update stake account
         stake_account.amount = stake_account.amount.checked_sub(amount)
-            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
         stake_account.voting_power = stake_account.voting_power.checked_sub(power_reduction)
-            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
 
         // Update DAO total
         self.total_staked = self.total_staked.checked_sub(amount)
-            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
 
-        msg!("Tokens unstaked: {}", amount);
+        log!("Tokens unstaked: {}", amount);
         Ok(())
     }
 
@@ -222,12 +222,12 @@ impl UniversalNftDAO {
         amount: u64,
         duration: Option<i64>,
     ) -> Result<()> {
-        require!(!self.is_paused, UniversalNftError::ProgramPaused);
+        require!(!self.is_paused, GovernanceError::ProgramPaused);
         
         // Check delegator has sufficient voting power
         require!(
             delegator_stake.voting_power >= amount,
-            UniversalNftError::ArithmeticOverflow
+            GovernanceError::ArithmeticOverflow
         );
 
         let now = Clock::get()?.unix_timestamp;
@@ -242,10 +242,10 @@ impl UniversalNftDAO {
 
         // Update delegator's available voting power
         delegator_stake.voting_power = delegator_stake.voting_power.checked_sub(amount)
-            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
         delegator_stake.delegated_to = Some(delegate);
 
-        msg!("Voting power delegated: {} to {}", amount, delegate);
+        log!("Voting power delegated: {} to {}", amount, delegate);
         Ok(())
     }
 
@@ -255,19 +255,19 @@ impl UniversalNftDAO {
         delegator_stake: &mut GovernanceStake,
         delegation: &mut VotingDelegation,
     ) -> Result<()> {
-        require!(!self.is_paused, UniversalNftError::ProgramPaused);
-        require!(delegation.is_active, UniversalNftError::InvalidTransferStatus);
+        require!(!self.is_paused, GovernanceError::ProgramPaused);
+        require!(delegation.is_active, GovernanceError::InvalidStatus);
 
         // Return voting power to delegator
         delegator_stake.voting_power = delegator_stake.voting_power
             .checked_add(delegation.voting_power)
-            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
         delegator_stake.delegated_to = None;
 
         // Deactivate delegation
         delegation.is_active = false;
 
-        msg!("Delegation revoked: {}", delegation.voting_power);
+        log!("Delegation revoked: {}", delegation.voting_power);
         Ok(())
     }
 
@@ -280,14 +280,14 @@ impl UniversalNftDAO {
         self.quorum_threshold = config.quorum_threshold;
         self.execution_delay = config.execution_delay;
 
-        msg!("Governance parameters updated");
+        log!("Governance parameters updated");
         Ok(())
     }
 
     /// Emergency pause (emergency council only)
     pub fn emergency_pause(&mut self, paused: bool) -> Result<()> {
         self.is_paused = paused;
-        msg!("DAO emergency pause: {}", paused);
+        log!("DAO emergency pause: {}", paused);
         Ok(())
     }
 
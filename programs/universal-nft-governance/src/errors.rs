@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+/// Governance program error codes. As in the core program, variants are only
+/// ever appended so client-side codes stay stable.
+#[error_code(offset = 6000)]
+pub enum GovernanceError {
+    #[msg("Unauthorized access - caller is not the treasury authority")]
+    Unauthorized,
+    
+    #[msg("Governance is currently paused. Hint: retry once the DAO is unpaused")]
+    ProgramPaused,
+    
+    #[msg("Account is not in a valid state for this operation")]
+    InvalidStatus,
+    
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+    
+    #[msg("Invalid bug bounty parameters")]
+    InvalidBountyParameters,
+    
+    #[msg("Bug bounty program is not accepting reports")]
+    BountyProgramClosed,
+    
+    #[msg("Bug bounty report has already been resolved")]
+    BountyReportAlreadyResolved,
+    
+    #[msg("Insufficient funds in the bug bounty program")]
+    InsufficientBountyFunds,
+    
+    #[msg("Proposal is still timelocked. Hint: execute it once executable_at has passed")]
+    ProposalTimelocked,
+    
+    #[msg("Upgrade buffer is not the approved one or its contents changed since the vote")]
+    BufferHashMismatch,
+    
+    #[msg("Account is not the program's upgradeable loader ProgramData")]
+    InvalidProgramData,
+    
+    #[msg("Upgrade authority does not control this program")]
+    ProgramMismatch,
+    
+    #[msg("Spill account must be the upgrade's proposer or the governance authority")]
+    InvalidSpillAccount,
+}
\ No newline at end of file
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+use crate::bug_bounty::BountySeverity;
+use crate::proposals::ProposalStatus;
+
+/// Emitted when revenue is deposited into the treasury SOL vault
+#[event]
+pub struct TreasuryRevenueDeposited {
+    pub treasury: Pubkey,
+    pub depositor: Pubkey,
+    pub amount: u64,
+    pub sol_balance: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the treasury authority books a spend, directly or through a
+/// CPI from the core program
+#[event]
+pub struct TreasurySpendRecorded {
+    pub treasury: Pubkey,
+    pub amount: u64,
+    pub sol_balance: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the treasury moves lamports into the bug bounty program
+#[event]
+pub struct BountyProgramFunded {
+    pub bounty_program: Pubkey,
+    pub treasury: Pubkey,
+    pub amount: u64,
+    pub total_funded: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a whitehat submits a security report
+#[event]
+pub struct BountyReportSubmitted {
+    pub report_hash: [u8; 32],
+    pub reporter: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the security council accepts or rejects a report
+#[event]
+pub struct BountyReportResolved {
+    pub report_hash: [u8; 32],
+    pub reporter: Pubkey,
+    pub severity: Option<BountySeverity>,
+    pub payout: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a crank finalizes or expires a proposal
+#[event]
+pub struct ProposalCranked {
+    pub proposal: Pubkey,
+    pub status: ProposalStatus,
+    pub caller: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a passed proposal is executed after its timelock
+#[event]
+pub struct ProposalExecuted {
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub caller: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when anyone attests the hash of a program's deployed bytes
+#[event]
+pub struct ProgramHashAttested {
+    pub program_id: Pubkey,
+    pub program_data_hash: [u8; 32],
+    pub deployed_slot: u64,
+    pub attested_slot: u64,
+    pub matched_upgrade: Option<u32>,
+    pub attested_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when an approved upgrade is deployed through the loader
+#[event]
+pub struct UpgradeExecuted {
+    pub program_id: Pubkey,
+    pub upgrade_id: u32,
+    pub previous_hash: [u8; 32],
+    pub new_hash: [u8; 32],
+    pub executed_by: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+
+use crate::errors::*;
+use crate::events::*;
+use crate::upgrade_authority::*;
+
+/// Hash a program's deployed bytes and store it with the current slot. When
+/// the program's upgrade authority and an upgrade record are passed, the
+/// attestation notes whether the live binary is the one that upgrade approved.
+pub fn attest_program_hash(ctx: Context<AttestProgramHash>) -> Result<()> {
+    let (program_data_hash, deployed_slot) =
+        ProgramHashAttestation::hash_program_data(&ctx.accounts.program_data.try_borrow_data()?)?;
+
+    let matched_upgrade = ProgramHashAttestation::matched_upgrade(
+        &program_data_hash,
+        ctx.accounts.upgrade_authority.as_deref(),
+        ctx.accounts.upgrade_history.as_deref(),
+    )?;
+
+    let clock = Clock::get()?;
+    let attestation = &mut ctx.accounts.attestation;
+    attestation.program_id = ctx.accounts.program.key();
+    attestation.program_data_hash = program_data_hash;
+    attestation.deployed_slot = deployed_slot;
+    attestation.attested_slot = clock.slot;
+    attestation.matched_upgrade = matched_upgrade;
+    attestation.attested_by = ctx.accounts.payer.key();
+    attestation.attested_at = clock.unix_timestamp;
+    attestation.bump = ctx.bumps.attestation;
+
+    emit!(ProgramHashAttested {
+        program_id: attestation.program_id,
+        program_data_hash,
+        deployed_slot,
+        attested_slot: clock.slot,
+        matched_upgrade,
+        attested_by: attestation.attested_by,
+        timestamp: clock.unix_timestamp,
+    });
+
+    log!("Program {} attested at slot {}", attestation.program_id, clock.slot);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AttestProgramHash<'info> {
+    /// CHECK: Upgradeable program being attested
+    #[account(executable)]
+    pub program: UncheckedAccount<'info>,
+
+    /// CHECK: The program's ProgramData account, parsed by the handler
+    #[account(
+        seeds = [program.key().as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID
+    )]
+    pub program_data: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = upgrade_authority.program_id == program.key() @ GovernanceError::InvalidStatus
+    )]
+    pub upgrade_authority: Option<Account<'info, UpgradeAuthority>>,
+
+    /// Upgrade record of the program whose approved hash the deployed bytes
+    /// are checked against
+    #[account(
+        seeds = [
+            b"upgrade_history",
+            program.key().as_ref(),
+            upgrade_history.id.to_le_bytes().as_ref()
+        ],
+        bump = upgrade_history.bump
+    )]
+    pub upgrade_history: Option<Account<'info, UpgradeHistory>>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ProgramHashAttestation::INIT_SPACE,
+        seeds = [b"program_attestation", program.key().as_ref()],
+        bump
+    )]
+    pub attestation: Account<'info, ProgramHashAttestation>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
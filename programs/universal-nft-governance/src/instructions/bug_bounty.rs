@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::bug_bounty::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::treasury::Treasury;
+use crate::{TREASURY_SEED, BOUNTY_PROGRAM_SEED, BOUNTY_REPORT_SEED};
+
+/// Create the bug bounty program (treasury authority only)
+pub fn initialize_bounty_program(
+    ctx: Context<InitializeBountyProgram>,
+    security_council: Pubkey,
+    tier_payouts: [u64; 4],
+) -> Result<()> {
+    let treasury = &ctx.accounts.treasury;
+
+    require!(
+        ctx.accounts.authority.key() == treasury.authority,
+        GovernanceError::Unauthorized
+    );
+
+    ctx.accounts.bounty_program.initialize(
+        ctx.accounts.authority.key(),
+        treasury.key(),
+        security_council,
+        tier_payouts,
+        ctx.bumps.bounty_program,
+    )
+}
+
+#[derive(Accounts)]
+pub struct InitializeBountyProgram<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + BountyProgram::INIT_SPACE,
+        seeds = [BOUNTY_PROGRAM_SEED],
+        bump
+    )]
+    pub bounty_program: Account<'info, BountyProgram>,
+
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Change the security council, payout tiers or intake status (bounty authority only)
+pub fn update_bounty_program(
+    ctx: Context<UpdateBountyProgram>,
+    security_council: Pubkey,
+    tier_payouts: [u64; 4],
+    accepting_reports: bool,
+) -> Result<()> {
+    ctx.accounts.bounty_program.update(security_council, tier_payouts, accepting_reports)
+}
+
+#[derive(Accounts)]
+pub struct UpdateBountyProgram<'info> {
+    #[account(
+        mut,
+        seeds = [BOUNTY_PROGRAM_SEED],
+        bump = bounty_program.bump,
+        has_one = authority
+    )]
+    pub bounty_program: Account<'info, BountyProgram>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Move lamports from the treasury SOL vault into the bounty program
+pub fn fund_bounty_program(ctx: Context<FundBountyProgram>, amount: u64) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+
+    require!(
+        ctx.accounts.authority.key() == treasury.authority,
+        GovernanceError::Unauthorized
+    );
+
+    // Treasury bookkeeping enforces the emergency reserve
+    treasury.execute_spend(amount, true)?;
+
+    let cpi_accounts = system_program::Transfer {
+        from: ctx.accounts.sol_vault.to_account_info(),
+        to: ctx.accounts.bounty_program.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, amount)?;
+
+    let bounty_program = &mut ctx.accounts.bounty_program;
+    bounty_program.record_funding(amount)?;
+
+    emit!(BountyProgramFunded {
+        bounty_program: bounty_program.key(),
+        treasury: treasury.key(),
+        amount,
+        total_funded: bounty_program.total_funded,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundBountyProgram<'info> {
+    #[account(
+        mut,
+        seeds = [BOUNTY_PROGRAM_SEED],
+        bump = bounty_program.bump,
+        has_one = treasury
+    )]
+    pub bounty_program: Account<'info, BountyProgram>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// Treasury SOL wallet the funds are drawn from
+    #[account(mut, address = treasury.sol_vault)]
+    pub sol_vault: Signer<'info>,
+
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Register a security report by the hash of its contents
+pub fn submit_bounty_report(ctx: Context<SubmitBountyReport>, report_hash: [u8; 32]) -> Result<()> {
+    ctx.accounts.bounty_program.record_submission()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let report = &mut ctx.accounts.report;
+    report.report_hash = report_hash;
+    report.reporter = ctx.accounts.reporter.key();
+    report.status = BountyReportStatus::Pending;
+    report.severity = None;
+    report.payout = 0;
+    report.submitted_at = now;
+    report.resolved_at = 0;
+    report.bump = ctx.bumps.report;
+
+    emit!(BountyReportSubmitted {
+        report_hash,
+        reporter: report.reporter,
+        timestamp: now,
+    });
+
+    log!("Bug bounty report submitted");
+    log!("Reporter: {}", report.reporter);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(report_hash: [u8; 32])]
+pub struct SubmitBountyReport<'info> {
+    #[account(
+        mut,
+        seeds = [BOUNTY_PROGRAM_SEED],
+        bump = bounty_program.bump
+    )]
+    pub bounty_program: Account<'info, BountyProgram>,
+
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + BountyReport::INIT_SPACE,
+        seeds = [BOUNTY_REPORT_SEED, report_hash.as_ref()],
+        bump
+    )]
+    pub report: Account<'info, BountyReport>,
+
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Accept a report at `severity` and pay its tier, or reject it with `None`
+/// (security council only)
+pub fn resolve_bounty_report(
+    ctx: Context<ResolveBountyReport>,
+    severity: Option<BountySeverity>,
+) -> Result<()> {
+    let bounty_program = &mut ctx.accounts.bounty_program;
+    let report = &mut ctx.accounts.report;
+
+    // Keep the program rent exempt after the payout
+    let program_info = bounty_program.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(program_info.data_len());
+    let available = program_info.lamports().saturating_sub(rent_exempt_minimum);
+
+    let now = Clock::get()?.unix_timestamp;
+    let payout = bounty_program.resolve_report(report, severity, available, now)?;
+    if payout > 0 {
+        **program_info.try_borrow_mut_lamports()? -= payout;
+        **ctx.accounts.reporter.to_account_info().try_borrow_mut_lamports()? += payout;
+    }
+
+    emit!(BountyReportResolved {
+        report_hash: report.report_hash,
+        reporter: report.reporter,
+        severity,
+        payout,
+        timestamp: now,
+    });
+
+    log!("Bug bounty report resolved");
+    log!("Payout: {} lamports", payout);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResolveBountyReport<'info> {
+    #[account(
+        mut,
+        seeds = [BOUNTY_PROGRAM_SEED],
+        bump = bounty_program.bump,
+        has_one = security_council
+    )]
+    pub bounty_program: Account<'info, BountyProgram>,
+
+    #[account(
+        mut,
+        seeds = [BOUNTY_REPORT_SEED, report.report_hash.as_ref()],
+        bump = report.bump,
+        has_one = reporter
+    )]
+    pub report: Account<'info, BountyReport>,
+
+    /// CHECK: Reporter receiving the payout, matched by has_one
+    #[account(mut)]
+    pub reporter: UncheckedAccount<'info>,
+
+    pub security_council: Signer<'info>,
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+pub mod treasury;
+pub mod bug_bounty;
+pub mod proposal;
+pub mod attestation;
+pub mod upgrade;
+
+pub use treasury::*;
+pub use bug_bounty::*;
+pub use proposal::*;
+pub use attestation::*;
+pub use upgrade::*;
\ No newline at end of file
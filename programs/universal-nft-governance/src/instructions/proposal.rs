@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::events::*;
+use crate::proposals::*;
+
+/// Finalize a proposal whose voting period has ended, or expire a passed
+/// proposal that missed its execution deadline
+pub fn crank_proposal(ctx: Context<CrankProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+
+    match proposal.status {
+        ProposalStatus::Active if now > proposal.voting_end => proposal.finalize()?,
+        ProposalStatus::Passed if now > proposal.execution_deadline => proposal.check_expiry()?,
+        _ => return err!(GovernanceError::InvalidStatus),
+    }
+
+    emit!(ProposalCranked {
+        proposal: proposal.key(),
+        status: proposal.status.clone(),
+        caller: ctx.accounts.caller.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Execute a passed proposal once its timelock has ended. Programs acting on
+/// proposals apply only executed ones, so every change waits out the
+/// timelock. Permissionless.
+pub fn execute_proposal(ctx: Context<CrankProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.execute()?;
+
+    emit!(ProposalExecuted {
+        proposal: proposal.key(),
+        proposal_id: proposal.id,
+        caller: ctx.accounts.caller.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CrankProposal<'info> {
+    #[account(mut)]
+    pub proposal: Account<'info, Proposal>,
+
+    pub caller: Signer<'info>,
+}
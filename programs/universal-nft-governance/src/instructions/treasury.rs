@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::*;
+use crate::events::*;
+use crate::treasury::*;
+use crate::TREASURY_SEED;
+
+/// Create the treasury with the signer as its authority
+pub fn initialize_treasury(
+    ctx: Context<InitializeTreasury>,
+    sol_vault: Pubkey,
+    governance_vault: Pubkey,
+    config: TreasuryConfig,
+) -> Result<()> {
+    ctx.accounts.treasury.initialize(
+        ctx.accounts.authority.key(),
+        sol_vault,
+        governance_vault,
+        config,
+        ctx.bumps.treasury,
+    )
+}
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Treasury::INIT_SPACE,
+        seeds = [TREASURY_SEED],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Move lamports into the treasury SOL vault and book them as revenue
+pub fn deposit_treasury_revenue(ctx: Context<DepositTreasuryRevenue>, amount: u64) -> Result<()> {
+    let cpi_accounts = system_program::Transfer {
+        from: ctx.accounts.depositor.to_account_info(),
+        to: ctx.accounts.sol_vault.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, amount)?;
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.deposit_revenue(amount, true)?;
+
+    emit!(TreasuryRevenueDeposited {
+        treasury: treasury.key(),
+        depositor: ctx.accounts.depositor.key(),
+        amount,
+        sol_balance: treasury.sol_balance,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DepositTreasuryRevenue<'info> {
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// CHECK: Treasury SOL wallet, matched against the treasury
+    #[account(mut, address = treasury.sol_vault)]
+    pub sol_vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Book a SOL spend against the treasury. The caller moves the lamports out
+/// of the SOL vault itself; this only enforces the emergency reserve and
+/// keeps the balances straight. The core program calls it by CPI before
+/// funding its compensation vault.
+pub fn record_treasury_spend(ctx: Context<RecordTreasurySpend>, amount: u64) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+
+    require!(
+        ctx.accounts.authority.key() == treasury.authority,
+        GovernanceError::Unauthorized
+    );
+
+    // Treasury bookkeeping enforces the emergency reserve
+    treasury.execute_spend(amount, true)?;
+
+    emit!(TreasurySpendRecorded {
+        treasury: treasury.key(),
+        amount,
+        sol_balance: treasury.sol_balance,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RecordTreasurySpend<'info> {
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::sysvar;
+
+use crate::errors::*;
+use crate::events::*;
+use crate::upgrade_authority::*;
+
+/// Deploy an approved upgrade from its buffer. The buffer is re-hashed and
+/// checked against the approved hash in this instruction, right before the
+/// loader CPI, so it can't be swapped in between. Permissionless, but the
+/// buffer's rent goes back to the proposer or the governance authority.
+pub fn execute_upgrade(ctx: Context<ExecuteUpgrade>) -> Result<()> {
+    ctx.accounts.upgrade_authority.validate_spill(&ctx.accounts.spill.key())?;
+
+    let (previous_hash, _) =
+        ProgramHashAttestation::hash_program_data(&ctx.accounts.program_data.try_borrow_data()?)?;
+
+    let history = &mut ctx.accounts.upgrade_history;
+    history.initialize(ctx.bumps.upgrade_history);
+    ctx.accounts.upgrade_authority.execute_upgrade(
+        history,
+        &ctx.accounts.buffer.to_account_info(),
+        previous_hash,
+        ctx.accounts.executor.key(),
+        0,
+    )?;
+
+    let program_id = ctx.accounts.program.key();
+    let upgrade_authority = ctx.accounts.upgrade_authority.key();
+    let seeds: &[&[u8]] = &[
+        b"upgrade_authority",
+        program_id.as_ref(),
+        &[ctx.accounts.upgrade_authority.bump],
+    ];
+    invoke_signed(
+        &bpf_loader_upgradeable::upgrade(
+            &program_id,
+            &ctx.accounts.buffer.key(),
+            &upgrade_authority,
+            &ctx.accounts.spill.key(),
+        ),
+        &[
+            ctx.accounts.program_data.to_account_info(),
+            ctx.accounts.program.to_account_info(),
+            ctx.accounts.buffer.to_account_info(),
+            ctx.accounts.spill.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+            ctx.accounts.clock.to_account_info(),
+            ctx.accounts.upgrade_authority.to_account_info(),
+        ],
+        &[seeds],
+    )?;
+
+    let history = &ctx.accounts.upgrade_history;
+    emit!(UpgradeExecuted {
+        program_id,
+        upgrade_id: history.id,
+        previous_hash,
+        new_hash: history.new_hash,
+        executed_by: history.executed_by,
+        timestamp: history.executed_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteUpgrade<'info> {
+    /// Loader upgrade authority of the program, and of the buffer
+    #[account(
+        mut,
+        seeds = [b"upgrade_authority", program.key().as_ref()],
+        bump = upgrade_authority.bump,
+        constraint = upgrade_authority.program_id == program.key() @ GovernanceError::ProgramMismatch
+    )]
+    pub upgrade_authority: Account<'info, UpgradeAuthority>,
+
+    #[account(
+        init,
+        payer = executor,
+        space = 8 + UpgradeHistory::INIT_SPACE,
+        seeds = [
+            b"upgrade_history",
+            program.key().as_ref(),
+            upgrade_authority.upgrade_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub upgrade_history: Account<'info, UpgradeHistory>,
+
+    /// CHECK: Upgradeable program being upgraded
+    #[account(mut, executable)]
+    pub program: UncheckedAccount<'info>,
+
+    /// CHECK: The program's ProgramData account, parsed by the handler
+    #[account(
+        mut,
+        seeds = [program.key().as_ref()],
+        bump,
+        seeds::program = bpf_loader_upgradeable::ID
+    )]
+    pub program_data: UncheckedAccount<'info>,
+
+    /// CHECK: Buffer holding the new program bytes, checked against the
+    /// approved proposal by `UpgradeAuthority::execute_upgrade`
+    #[account(mut)]
+    pub buffer: UncheckedAccount<'info>,
+
+    /// CHECK: Receives the buffer's lamports once the loader closes it;
+    /// checked by `UpgradeAuthority::validate_spill`
+    #[account(mut)]
+    pub spill: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    /// CHECK: Rent sysvar, required by the loader
+    #[account(address = sysvar::rent::ID)]
+    pub rent: UncheckedAccount<'info>,
+
+    /// CHECK: Clock sysvar, required by the loader
+    #[account(address = sysvar::clock::ID)]
+    pub clock: UncheckedAccount<'info>,
+
+    /// CHECK: BPF upgradeable loader
+    #[account(address = bpf_loader_upgradeable::ID)]
+    pub bpf_loader_upgradeable: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+
+declare_id!("GKD5qxcYGQUfXFareioEtjZyvzmrdMJ57ep9w9gv8Zan");
+
+/// Program logging, compiled out by the `lean` feature as in the core program
+#[cfg(not(feature = "lean"))]
+macro_rules! log {
+    ($($arg:tt)*) => {
+        msg!($($arg)*)
+    };
+}
+
+#[cfg(feature = "lean")]
+macro_rules! log {
+    ($($arg:tt)*) => {{
+        if false {
+            let _ = format_args!($($arg)*);
+        }
+    }};
+}
+
+pub mod bug_bounty;
+pub mod dao;
+pub mod errors;
+pub mod events;
+pub mod instructions;
+pub mod proposals;
+pub mod treasury;
+pub mod upgrade_authority;
+pub mod voting;
+
+pub use bug_bounty::*;
+pub use dao::*;
+pub use proposals::*;
+pub use treasury::*;
+pub use upgrade_authority::*;
+pub use voting::*;
+
+use instructions::*;
+
+/// PDA seeds of the governance accounts. The core program derives them only
+/// through these constants (with `seeds::program` set to this program), so a
+/// core build without the `governance` feature fails to compile wherever one
+/// is still referenced.
+pub const TREASURY_SEED: &[u8] = b"treasury";
+pub const BOUNTY_PROGRAM_SEED: &[u8] = b"bounty_program";
+pub const BOUNTY_REPORT_SEED: &[u8] = b"bounty_report";
+
+/// Periphery program holding the protocol treasury, DAO proposals and the bug
+/// bounty. It never touches NFTs or bridge state; the core program reads the
+/// treasury to authorize governance actions and books treasury spends through
+/// `record_treasury_spend`, so this program can be upgraded without re-auditing
+/// the asset-custody core.
+#[program]
+pub mod universal_nft_governance {
+    use super::*;
+
+    /// Create the treasury. The first caller becomes its authority, so run it
+    /// straight after deployment.
+    pub fn initialize_treasury(
+        ctx: Context<InitializeTreasury>,
+        sol_vault: Pubkey,
+        governance_vault: Pubkey,
+        config: TreasuryConfig,
+    ) -> Result<()> {
+        instructions::initialize_treasury(ctx, sol_vault, governance_vault, config)
+    }
+
+    /// Deposit protocol revenue into the treasury SOL vault
+    pub fn deposit_treasury_revenue(ctx: Context<DepositTreasuryRevenue>, amount: u64) -> Result<()> {
+        instructions::deposit_treasury_revenue(ctx, amount)
+    }
+
+    /// Book a SOL spend against the treasury (treasury authority only)
+    pub fn record_treasury_spend(ctx: Context<RecordTreasurySpend>, amount: u64) -> Result<()> {
+        instructions::record_treasury_spend(ctx, amount)
+    }
+
+    /// Create the bug bounty program (treasury authority only)
+    pub fn initialize_bounty_program(
+        ctx: Context<InitializeBountyProgram>,
+        security_council: Pubkey,
+        tier_payouts: [u64; 4],
+    ) -> Result<()> {
+        instructions::initialize_bounty_program(ctx, security_council, tier_payouts)
+    }
+
+    /// Change the bounty security council, payout tiers or intake status
+    pub fn update_bounty_program(
+        ctx: Context<UpdateBountyProgram>,
+        security_council: Pubkey,
+        tier_payouts: [u64; 4],
+        accepting_reports: bool,
+    ) -> Result<()> {
+        instructions::update_bounty_program(ctx, security_council, tier_payouts, accepting_reports)
+    }
+
+    /// Move treasury lamports into the bug bounty program
+    pub fn fund_bounty_program(ctx: Context<FundBountyProgram>, amount: u64) -> Result<()> {
+        instructions::fund_bounty_program(ctx, amount)
+    }
+
+    /// Submit a security report by its content hash
+    pub fn submit_bounty_report(ctx: Context<SubmitBountyReport>, report_hash: [u8; 32]) -> Result<()> {
+        instructions::submit_bounty_report(ctx, report_hash)
+    }
+
+    /// Accept and pay, or reject, a security report (security council)
+    pub fn resolve_bounty_report(
+        ctx: Context<ResolveBountyReport>,
+        severity: Option<BountySeverity>,
+    ) -> Result<()> {
+        instructions::resolve_bounty_report(ctx, severity)
+    }
+
+    /// Finalize or expire a proposal once it is due. Permissionless.
+    pub fn crank_proposal(ctx: Context<CrankProposal>) -> Result<()> {
+        instructions::crank_proposal(ctx)
+    }
+
+    /// Execute a passed proposal after its timelock. Permissionless.
+    pub fn execute_proposal(ctx: Context<CrankProposal>) -> Result<()> {
+        instructions::execute_proposal(ctx)
+    }
+
+    /// Record the hash of a program's deployed bytes and whether it matches
+    /// an approved upgrade. Permissionless.
+    pub fn attest_program_hash(ctx: Context<AttestProgramHash>) -> Result<()> {
+        instructions::attest_program_hash(ctx)
+    }
+
+    /// Deploy an approved upgrade after checking its buffer still hashes to
+    /// what voters approved. Permissionless.
+    pub fn execute_upgrade(ctx: Context<ExecuteUpgrade>) -> Result<()> {
+        instructions::execute_upgrade(ctx)
+    }
+}
\ No newline at end of file
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::errors::UniversalNftError;
-use crate::governance::dao::{UniversalNftDAO, GovernanceStake};
+use crate::errors::GovernanceError;
+use crate::dao::UniversalNftDAO;
 
 /// Governance Proposal System for Universal NFT Protocol
 /// Enables token holders to propose and vote on protocol changes
@@ -12,21 +12,29 @@ pub struct Proposal {
     /// Proposer's public key
     pub proposer: Pubkey,
     /// Proposal title (max 128 chars)
+    #[max_len(128)]
     pub title: String,
     /// Proposal description (max 512 chars)
+    #[max_len(512)]
     pub description: String,
     /// Proposal type
     pub proposal_type: ProposalType,
     /// Target for execution (program ID, account, etc.)
     pub target: Option<Pubkey>,
     /// Encoded instruction data for execution
+    #[max_len(1024)]
     pub instruction_data: Vec<u8>,
     /// Voting starts at this timestamp
     pub voting_start: i64,
     /// Voting ends at this timestamp
     pub voting_end: i64,
-    /// Execution deadline (after voting passes)
+    /// Last moment a passed proposal can execute: `executable_at` plus
+    /// `EXECUTION_WINDOW`. It used to be the end of the timelock itself, with
+    /// execution allowed any time before it.
     pub execution_deadline: i64,
+    /// End of the timelock: a passed proposal executes from here until
+    /// `execution_deadline`
+    pub executable_at: i64,
     /// Current proposal status
     pub status: ProposalStatus,
     /// Total votes cast
@@ -49,7 +57,7 @@ pub struct Proposal {
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum ProposalType {
     /// Change governance parameters
     GovernanceUpdate,
@@ -69,7 +77,7 @@ pub enum ProposalType {
     FeeUpdate,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum ProposalStatus {
     /// Proposal is active and can be voted on
     Active,
@@ -104,7 +112,7 @@ pub struct Vote {
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum VoteType {
     For,
     Against,
@@ -112,6 +120,9 @@ pub enum VoteType {
 }
 
 impl Proposal {
+    /// How long a proposal stays executable once its timelock ends
+    pub const EXECUTION_WINDOW: i64 = 7 * 24 * 60 * 60;
+
     pub const INIT_SPACE: usize = 
         8 +     // id
         32 +    // proposer
@@ -123,6 +134,7 @@ impl Proposal {
         8 +     // voting_start
         8 +     // voting_end
         8 +     // execution_deadline
+        8 +     // executable_at
         1 +     // status (enum)
         8 +     // total_votes
         8 +     // votes_for
@@ -135,6 +147,7 @@ impl Proposal {
         1;      // bump
 
     /// Initialize a new proposal
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize(
         &mut self,
         id: u64,
@@ -148,9 +161,9 @@ impl Proposal {
         is_emergency: bool,
         bump: u8,
     ) -> Result<()> {
-        require!(title.len() <= 128, UniversalNftError::InvalidTransferStatus);
-        require!(description.len() <= 512, UniversalNftError::InvalidTransferStatus);
-        require!(instruction_data.len() <= 1024, UniversalNftError::InvalidTransferStatus);
+        require!(title.len() <= 128, GovernanceError::InvalidStatus);
+        require!(description.len() <= 512, GovernanceError::InvalidStatus);
+        require!(instruction_data.len() <= 1024, GovernanceError::InvalidStatus);
 
         let now = Clock::get()?.unix_timestamp;
         
@@ -170,7 +183,8 @@ impl Proposal {
         self.instruction_data = instruction_data;
         self.voting_start = now;
         self.voting_end = now + voting_duration;
-        self.execution_deadline = self.voting_end + dao.execution_delay;
+        self.executable_at = self.voting_end + dao.execution_delay;
+        self.execution_deadline = self.executable_at + Self::EXECUTION_WINDOW;
         self.status = ProposalStatus::Active;
         self.total_votes = 0;
         self.votes_for = 0;
@@ -182,9 +196,9 @@ impl Proposal {
         self.is_emergency = is_emergency;
         self.bump = bump;
 
-        msg!("Proposal {} created: {}", id, self.title);
-        msg!("Voting period: {} to {}", self.voting_start, self.voting_end);
-        msg!("Quorum required: {}", self.quorum_threshold);
+        log!("Proposal {} created: {}", id, self.title);
+        log!("Voting period: {} to {}", self.voting_start, self.voting_end);
+        log!("Quorum required: {}", self.quorum_threshold);
 
         Ok(())
     }
@@ -203,13 +217,13 @@ impl Proposal {
         // Validate voting period
         require!(
             now >= self.voting_start && now <= self.voting_end,
-            UniversalNftError::InvalidTransferStatus
+            GovernanceError::InvalidStatus
         );
 
         // Validate proposal is active
         require!(
             self.status == ProposalStatus::Active,
-            UniversalNftError::InvalidTransferStatus
+            GovernanceError::InvalidStatus
         );
 
         // Initialize vote account
@@ -222,24 +236,24 @@ impl Proposal {
 
         // Update proposal vote counts
         self.total_votes = self.total_votes.checked_add(voting_power)
-            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
 
         match vote_type {
             VoteType::For => {
                 self.votes_for = self.votes_for.checked_add(voting_power)
-                    .ok_or(UniversalNftError::ArithmeticOverflow)?;
+                    .ok_or(GovernanceError::ArithmeticOverflow)?;
             }
             VoteType::Against => {
                 self.votes_against = self.votes_against.checked_add(voting_power)
-                    .ok_or(UniversalNftError::ArithmeticOverflow)?;
+                    .ok_or(GovernanceError::ArithmeticOverflow)?;
             }
             VoteType::Abstain => {
                 self.votes_abstain = self.votes_abstain.checked_add(voting_power)
-                    .ok_or(UniversalNftError::ArithmeticOverflow)?;
+                    .ok_or(GovernanceError::ArithmeticOverflow)?;
             }
         }
 
-        msg!("Vote cast: {} with {} voting power", 
+        log!("Vote cast: {} with {} voting power", 
              match vote_type { VoteType::For => "FOR", VoteType::Against => "AGAINST", VoteType::Abstain => "ABSTAIN" },
              voting_power);
 
@@ -251,13 +265,13 @@ impl Proposal {
         let now = Clock::get()?.unix_timestamp;
 
         // Can only finalize after voting period
-        require!(now > self.voting_end, UniversalNftError::InvalidTransferStatus);
-        require!(self.status == ProposalStatus::Active, UniversalNftError::InvalidTransferStatus);
+        require!(now > self.voting_end, GovernanceError::InvalidStatus);
+        require!(self.status == ProposalStatus::Active, GovernanceError::InvalidStatus);
 
         // Check if quorum was met
         if self.total_votes < self.quorum_threshold {
             self.status = ProposalStatus::Failed;
-            msg!("Proposal {} failed: insufficient quorum ({} < {})", 
+            log!("Proposal {} failed: insufficient quorum ({} < {})", 
                  self.id, self.total_votes, self.quorum_threshold);
             return Ok(());
         }
@@ -265,11 +279,11 @@ impl Proposal {
         // Check if majority voted in favor
         if self.votes_for > self.votes_against {
             self.status = ProposalStatus::Passed;
-            msg!("Proposal {} passed: {} for, {} against", 
+            log!("Proposal {} passed: {} for, {} against", 
                  self.id, self.votes_for, self.votes_against);
         } else {
             self.status = ProposalStatus::Failed;
-            msg!("Proposal {} failed: {} for, {} against", 
+            log!("Proposal {} failed: {} for, {} against", 
                  self.id, self.votes_for, self.votes_against);
         }
 
@@ -281,13 +295,14 @@ impl Proposal {
         let now = Clock::get()?.unix_timestamp;
 
         // Validate proposal can be executed
-        require!(self.status == ProposalStatus::Passed, UniversalNftError::InvalidTransferStatus);
-        require!(now <= self.execution_deadline, UniversalNftError::InvalidTransferStatus);
+        require!(self.status == ProposalStatus::Passed, GovernanceError::InvalidStatus);
+        require!(now >= self.executable_at, GovernanceError::ProposalTimelocked);
+        require!(now <= self.execution_deadline, GovernanceError::InvalidStatus);
 
         self.status = ProposalStatus::Executed;
         self.executed_at = Some(now);
 
-        msg!("Proposal {} executed successfully", self.id);
+        log!("Proposal {} executed successfully", self.id);
         Ok(())
     }
 
@@ -295,11 +310,11 @@ impl Proposal {
     pub fn cancel(&mut self) -> Result<()> {
         require!(
             self.status == ProposalStatus::Active || self.status == ProposalStatus::Passed,
-            UniversalNftError::InvalidTransferStatus
+            GovernanceError::InvalidStatus
         );
 
         self.status = ProposalStatus::Cancelled;
-        msg!("Proposal {} cancelled", self.id);
+        log!("Proposal {} cancelled", self.id);
         Ok(())
     }
 
@@ -309,7 +324,7 @@ impl Proposal {
 
         if self.status == ProposalStatus::Passed && now > self.execution_deadline {
             self.status = ProposalStatus::Expired;
-            msg!("Proposal {} expired", self.id);
+            log!("Proposal {} expired", self.id);
         }
 
         Ok(())
@@ -318,11 +333,7 @@ impl Proposal {
     /// Get voting statistics
     pub fn get_voting_stats(&self) -> VotingStats {
         let total_possible = self.quorum_threshold; // Conservative estimate
-        let participation_rate = if total_possible > 0 {
-            (self.total_votes * 100) / total_possible
-        } else {
-            0
-        };
+        let participation_rate = (self.total_votes * 100).checked_div(total_possible).unwrap_or(0);
 
         VotingStats {
             total_votes: self.total_votes,
@@ -330,22 +341,14 @@ impl Proposal {
             votes_against: self.votes_against,
             votes_abstain: self.votes_abstain,
             participation_rate: participation_rate.min(100),
-            for_percentage: if self.total_votes > 0 {
-                (self.votes_for * 100) / self.total_votes
-            } else {
-                0
-            },
-            against_percentage: if self.total_votes > 0 {
-                (self.votes_against * 100) / self.total_votes
-            } else {
-                0
-            },
+            for_percentage: (self.votes_for * 100).checked_div(self.total_votes).unwrap_or(0),
+            against_percentage: (self.votes_against * 100).checked_div(self.total_votes).unwrap_or(0),
             quorum_met: self.total_votes >= self.quorum_threshold,
         }
     }
 
     /// Check if user can vote (not already voted)
-    pub fn can_vote(&self, voter: Pubkey) -> bool {
+    pub fn can_vote(&self, _voter: Pubkey) -> bool {
         let now = Clock::get().unwrap().unix_timestamp;
         now >= self.voting_start && 
         now <= self.voting_end && 
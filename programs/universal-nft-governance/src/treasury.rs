@@ -1,6 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Token, TokenAccount, Transfer};
-use crate::errors::UniversalNftError;
+use crate::errors::GovernanceError;
 
 /// Treasury Management System for Universal NFT Protocol
 /// Handles protocol funds, revenue distribution, and treasury operations
@@ -49,6 +48,7 @@ pub struct TreasuryProposal {
     /// Token mint (None for SOL)
     pub token_mint: Option<Pubkey>,
     /// Proposal description
+    #[max_len(256)]
     pub description: String,
     /// Proposer
     pub proposer: Pubkey,
@@ -68,7 +68,7 @@ pub struct TreasuryProposal {
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum TreasuryProposalType {
     /// General spending proposal
     Spend,
@@ -84,7 +84,7 @@ pub enum TreasuryProposalType {
     FeeChange,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum TreasuryProposalStatus {
     Active,
     Passed,
@@ -112,7 +112,7 @@ pub struct RevenueDistribution {
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum DistributionType {
     /// Equal distribution to all token holders
     Equal,
@@ -165,9 +165,9 @@ impl Treasury {
         self.created_at = now;
         self.bump = bump;
 
-        msg!("Treasury initialized with authority: {}", authority);
-        msg!("Distribution frequency: {} seconds", self.distribution_frequency);
-        msg!("Treasury fee: {}bps", self.treasury_fee_bps);
+        log!("Treasury initialized with authority: {}", authority);
+        log!("Distribution frequency: {} seconds", self.distribution_frequency);
+        log!("Treasury fee: {}bps", self.treasury_fee_bps);
 
         Ok(())
     }
@@ -175,17 +175,17 @@ impl Treasury {
     /// Deposit revenue into treasury
     pub fn deposit_revenue(&mut self, amount: u64, is_sol: bool) -> Result<()> {
         self.total_revenue = self.total_revenue.checked_add(amount)
-            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
 
         if is_sol {
             self.sol_balance = self.sol_balance.checked_add(amount)
-                .ok_or(UniversalNftError::ArithmeticOverflow)?;
+                .ok_or(GovernanceError::ArithmeticOverflow)?;
         } else {
             self.governance_balance = self.governance_balance.checked_add(amount)
-                .ok_or(UniversalNftError::ArithmeticOverflow)?;
+                .ok_or(GovernanceError::ArithmeticOverflow)?;
         }
 
-        msg!("Revenue deposited: {} {}", amount, if is_sol { "SOL" } else { "tokens" });
+        log!("Revenue deposited: {} {}", amount, if is_sol { "SOL" } else { "tokens" });
         Ok(())
     }
 
@@ -203,20 +203,20 @@ impl Treasury {
         is_sol: bool,
     ) -> Result<()> {
         let available = self.calculate_available_for_distribution(is_sol);
-        require!(amount <= available, UniversalNftError::ArithmeticOverflow);
+        require!(amount <= available, GovernanceError::ArithmeticOverflow);
 
         if is_sol {
             self.sol_balance = self.sol_balance.checked_sub(amount)
-                .ok_or(UniversalNftError::ArithmeticOverflow)?;
+                .ok_or(GovernanceError::ArithmeticOverflow)?;
         } else {
             self.governance_balance = self.governance_balance.checked_sub(amount)
-                .ok_or(UniversalNftError::ArithmeticOverflow)?;
+                .ok_or(GovernanceError::ArithmeticOverflow)?;
         }
 
         self.total_distributed = self.total_distributed.checked_add(amount)
-            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
 
-        msg!("Treasury spend executed: {} {}", amount, if is_sol { "SOL" } else { "tokens" });
+        log!("Treasury spend executed: {} {}", amount, if is_sol { "SOL" } else { "tokens" });
         Ok(())
     }
 
@@ -235,13 +235,13 @@ impl Treasury {
         distribution_type: DistributionType,
     ) -> Result<()> {
         let available = self.calculate_available_for_distribution(true); // SOL for now
-        require!(amount <= available, UniversalNftError::ArithmeticOverflow);
+        require!(amount <= available, GovernanceError::ArithmeticOverflow);
 
         // Update treasury
         self.sol_balance = self.sol_balance.checked_sub(amount)
-            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
         self.total_distributed = self.total_distributed.checked_add(amount)
-            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
         self.last_distribution = Clock::get()?.unix_timestamp;
 
         // Update distribution record
@@ -255,7 +255,7 @@ impl Treasury {
             0
         };
 
-        msg!("Revenue distribution executed: {} SOL to {} beneficiaries", 
+        log!("Revenue distribution executed: {} SOL to {} beneficiaries", 
              amount, beneficiary_count);
         Ok(())
     }
@@ -266,18 +266,14 @@ impl Treasury {
         self.treasury_fee_bps = config.treasury_fee_bps;
         self.emergency_reserve_bps = config.emergency_reserve_bps;
 
-        msg!("Treasury configuration updated");
+        log!("Treasury configuration updated");
         Ok(())
     }
 
     /// Get treasury statistics
     pub fn get_treasury_stats(&self) -> TreasuryStats {
         let total_balance = self.sol_balance + self.governance_balance;
-        let utilization_rate = if self.total_revenue > 0 {
-            (self.total_distributed * 100) / self.total_revenue
-        } else {
-            0
-        };
+        let utilization_rate = (self.total_distributed * 100).checked_div(self.total_revenue).unwrap_or(0);
 
         TreasuryStats {
             total_revenue: self.total_revenue,
@@ -312,6 +308,7 @@ impl TreasuryProposal {
         8 +     // created_at
         1;      // bump
 
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize(
         &mut self,
         id: u64,
@@ -323,7 +320,7 @@ impl TreasuryProposal {
         proposer: Pubkey,
         bump: u8,
     ) -> Result<()> {
-        require!(description.len() <= 256, UniversalNftError::InvalidTransferStatus);
+        require!(description.len() <= 256, GovernanceError::InvalidStatus);
 
         let now = Clock::get()?.unix_timestamp;
         
@@ -342,7 +339,7 @@ impl TreasuryProposal {
         self.created_at = now;
         self.bump = bump;
 
-        msg!("Treasury proposal {} created: {} {} to {}", 
+        log!("Treasury proposal {} created: {} {} to {}", 
              id, amount, 
              if token_mint.is_some() { "tokens" } else { "SOL" },
              recipient);
@@ -351,15 +348,15 @@ impl TreasuryProposal {
 
     pub fn cast_vote(&mut self, vote_for: bool, voting_power: u64) -> Result<()> {
         let now = Clock::get()?.unix_timestamp;
-        require!(now <= self.voting_deadline, UniversalNftError::InvalidTransferStatus);
-        require!(self.status == TreasuryProposalStatus::Active, UniversalNftError::InvalidTransferStatus);
+        require!(now <= self.voting_deadline, GovernanceError::InvalidStatus);
+        require!(self.status == TreasuryProposalStatus::Active, GovernanceError::InvalidStatus);
 
         if vote_for {
             self.votes_for = self.votes_for.checked_add(voting_power)
-                .ok_or(UniversalNftError::ArithmeticOverflow)?;
+                .ok_or(GovernanceError::ArithmeticOverflow)?;
         } else {
             self.votes_against = self.votes_against.checked_add(voting_power)
-                .ok_or(UniversalNftError::ArithmeticOverflow)?;
+                .ok_or(GovernanceError::ArithmeticOverflow)?;
         }
 
         Ok(())
@@ -367,8 +364,8 @@ impl TreasuryProposal {
 
     pub fn finalize(&mut self, quorum_threshold: u64) -> Result<()> {
         let now = Clock::get()?.unix_timestamp;
-        require!(now > self.voting_deadline, UniversalNftError::InvalidTransferStatus);
-        require!(self.status == TreasuryProposalStatus::Active, UniversalNftError::InvalidTransferStatus);
+        require!(now > self.voting_deadline, GovernanceError::InvalidStatus);
+        require!(self.status == TreasuryProposalStatus::Active, GovernanceError::InvalidStatus);
 
         let total_votes = self.votes_for + self.votes_against;
         
@@ -383,8 +380,8 @@ impl TreasuryProposal {
 
     pub fn execute(&mut self) -> Result<()> {
         let now = Clock::get()?.unix_timestamp;
-        require!(self.status == TreasuryProposalStatus::Passed, UniversalNftError::InvalidTransferStatus);
-        require!(now <= self.execution_deadline, UniversalNftError::InvalidTransferStatus);
+        require!(self.status == TreasuryProposalStatus::Passed, GovernanceError::InvalidStatus);
+        require!(now <= self.execution_deadline, GovernanceError::InvalidStatus);
 
         self.status = TreasuryProposalStatus::Executed;
         Ok(())
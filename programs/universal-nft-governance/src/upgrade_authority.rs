@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::errors::UniversalNftError;
+use anchor_lang::solana_program::bpf_loader_upgradeable::UpgradeableLoaderState;
+use anchor_lang::solana_program::hash::hash;
+use crate::errors::GovernanceError;
 
 /// Upgrade Authority System for Universal NFT Protocol
 /// Manages program upgrades through decentralized governance
@@ -30,13 +32,16 @@ pub struct UpgradeAuthority {
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct UpgradeProposal {
     /// Proposal ID
     pub id: u64,
     /// New program data account
     pub new_program_data: Pubkey,
+    /// SHA-256 of the buffer's program bytes voters approved
+    pub buffer_hash: [u8; 32],
     /// Upgrade description
+    #[max_len(256)]
     pub description: String,
     /// Proposer
     pub proposer: Pubkey,
@@ -56,7 +61,7 @@ pub struct UpgradeProposal {
     pub created_at: i64,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum UpgradeType {
     /// Regular feature upgrade
     Feature,
@@ -70,7 +75,7 @@ pub enum UpgradeType {
     BugFix,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum UpgradeStatus {
     Proposed,
     Voting,
@@ -93,6 +98,7 @@ pub struct UpgradeHistory {
     /// Upgrade type
     pub upgrade_type: UpgradeType,
     /// Upgrade description
+    #[max_len(256)]
     pub description: String,
     /// Executed by
     pub executed_by: Pubkey,
@@ -109,7 +115,31 @@ pub struct UpgradeHistory {
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+/// Latest hash of a program's deployed bytes, recorded by anyone through
+/// `attest_program_hash` so wallets and partners can check the live binary
+/// against the approved hash in `UpgradeHistory`
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramHashAttestation {
+    /// Program whose ProgramData was hashed
+    pub program_id: Pubkey,
+    /// Hash of the deployed program bytes (see `UpgradeAuthority::executable_hash`)
+    pub program_data_hash: [u8; 32],
+    /// Slot the program was last deployed at, from its ProgramData header
+    pub deployed_slot: u64,
+    /// Slot the attestation was made at
+    pub attested_slot: u64,
+    /// Upgrade whose approved hash matched the deployed bytes, if any
+    pub matched_upgrade: Option<u32>,
+    /// Attesting caller
+    pub attested_by: Pubkey,
+    /// Attestation timestamp
+    pub attested_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct RollbackData {
     /// Previous program data account
     pub previous_program_data: Pubkey,
@@ -123,7 +153,7 @@ impl UpgradeAuthority {
     pub const INIT_SPACE: usize = 
         32 +    // authority
         32 +    // program_id
-        1 + 256 + // pending_upgrade (Option<UpgradeProposal>)
+        1 + UpgradeProposal::INIT_SPACE + // pending_upgrade (Option<UpgradeProposal>)
         4 +     // upgrade_count
         8 +     // last_upgrade
         8 +     // upgrade_cooldown
@@ -156,24 +186,26 @@ impl UpgradeAuthority {
         self.created_at = now;
         self.bump = bump;
 
-        msg!("Upgrade authority initialized for program: {}", program_id);
-        msg!("Upgrade cooldown: {} seconds", self.upgrade_cooldown);
-        msg!("Emergency upgrades enabled: {}", self.emergency_enabled);
+        log!("Upgrade authority initialized for program: {}", program_id);
+        log!("Upgrade cooldown: {} seconds", self.upgrade_cooldown);
+        log!("Emergency upgrades enabled: {}", self.emergency_enabled);
 
         Ok(())
     }
 
-    /// Propose a program upgrade
+    /// Propose a program upgrade. `buffer_hash` is `buffer_hash()` of the
+    /// buffer's data, which voters can check against a verified build.
     pub fn propose_upgrade(
         &mut self,
         proposal_id: u64,
         new_program_data: Pubkey,
+        buffer_hash: [u8; 32],
         description: String,
         proposer: Pubkey,
         upgrade_type: UpgradeType,
     ) -> Result<()> {
-        require!(self.pending_upgrade.is_none(), UniversalNftError::InvalidTransferStatus);
-        require!(description.len() <= 256, UniversalNftError::InvalidTransferStatus);
+        require!(self.pending_upgrade.is_none(), GovernanceError::InvalidStatus);
+        require!(description.len() <= 256, GovernanceError::InvalidStatus);
 
         let now = Clock::get()?.unix_timestamp;
 
@@ -181,7 +213,7 @@ impl UpgradeAuthority {
         if upgrade_type != UpgradeType::Emergency {
             require!(
                 now >= self.last_upgrade + self.upgrade_cooldown,
-                UniversalNftError::InvalidTransferStatus
+                GovernanceError::InvalidStatus
             );
         }
 
@@ -195,6 +227,7 @@ impl UpgradeAuthority {
         let proposal = UpgradeProposal {
             id: proposal_id,
             new_program_data,
+            buffer_hash,
             description,
             proposer,
             votes_for: 0,
@@ -208,8 +241,8 @@ impl UpgradeAuthority {
 
         self.pending_upgrade = Some(proposal);
 
-        msg!("Upgrade proposal {} created by {}", proposal_id, proposer);
-        msg!("Voting deadline: {}", now + voting_period);
+        log!("Upgrade proposal {} created by {}", proposal_id, proposer);
+        log!("Voting deadline: {}", now + voting_period);
 
         Ok(())
     }
@@ -221,21 +254,21 @@ impl UpgradeAuthority {
         voting_power: u64,
     ) -> Result<()> {
         let proposal = self.pending_upgrade.as_mut()
-            .ok_or(UniversalNftError::InvalidTransferStatus)?;
+            .ok_or(GovernanceError::InvalidStatus)?;
 
         let now = Clock::get()?.unix_timestamp;
-        require!(now <= proposal.voting_deadline, UniversalNftError::InvalidTransferStatus);
-        require!(proposal.status == UpgradeStatus::Voting, UniversalNftError::InvalidTransferStatus);
+        require!(now <= proposal.voting_deadline, GovernanceError::InvalidStatus);
+        require!(proposal.status == UpgradeStatus::Voting, GovernanceError::InvalidStatus);
 
         if vote_for {
             proposal.votes_for = proposal.votes_for.checked_add(voting_power)
-                .ok_or(UniversalNftError::ArithmeticOverflow)?;
+                .ok_or(GovernanceError::ArithmeticOverflow)?;
         } else {
             proposal.votes_against = proposal.votes_against.checked_add(voting_power)
-                .ok_or(UniversalNftError::ArithmeticOverflow)?;
+                .ok_or(GovernanceError::ArithmeticOverflow)?;
         }
 
-        msg!("Upgrade vote cast: {} with {} voting power", 
+        log!("Upgrade vote cast: {} with {} voting power", 
              if vote_for { "FOR" } else { "AGAINST" }, voting_power);
 
         Ok(())
@@ -244,43 +277,53 @@ impl UpgradeAuthority {
     /// Finalize upgrade proposal voting
     pub fn finalize_upgrade_vote(&mut self) -> Result<()> {
         let proposal = self.pending_upgrade.as_mut()
-            .ok_or(UniversalNftError::InvalidTransferStatus)?;
+            .ok_or(GovernanceError::InvalidStatus)?;
 
         let now = Clock::get()?.unix_timestamp;
-        require!(now > proposal.voting_deadline, UniversalNftError::InvalidTransferStatus);
-        require!(proposal.status == UpgradeStatus::Voting, UniversalNftError::InvalidTransferStatus);
+        require!(now > proposal.voting_deadline, GovernanceError::InvalidStatus);
+        require!(proposal.status == UpgradeStatus::Voting, GovernanceError::InvalidStatus);
 
         let total_votes = proposal.votes_for + proposal.votes_against;
         
         // Check if threshold is met and majority approves
         if total_votes >= self.upgrade_threshold && proposal.votes_for > proposal.votes_against {
             proposal.status = UpgradeStatus::Approved;
-            msg!("Upgrade proposal {} approved: {} for, {} against", 
+            log!("Upgrade proposal {} approved: {} for, {} against", 
                  proposal.id, proposal.votes_for, proposal.votes_against);
         } else {
             proposal.status = UpgradeStatus::Rejected;
-            msg!("Upgrade proposal {} rejected: {} for, {} against", 
+            log!("Upgrade proposal {} rejected: {} for, {} against", 
                  proposal.id, proposal.votes_for, proposal.votes_against);
         }
 
         Ok(())
     }
 
-    /// Execute approved upgrade
+    /// Execute approved upgrade. The buffer must be the proposal's and still
+    /// hash to what voters approved, so it can't be swapped after the vote;
+    /// the `execute_upgrade` instruction makes the loader CPI only once this
+    /// returns Ok.
     pub fn execute_upgrade(
         &mut self,
         history: &mut UpgradeHistory,
+        buffer: &AccountInfo,
         program_data_hash: [u8; 32],
-        new_program_data_hash: [u8; 32],
         executor: Pubkey,
         gas_used: u64,
     ) -> Result<()> {
         let proposal = self.pending_upgrade.as_mut()
-            .ok_or(UniversalNftError::InvalidTransferStatus)?;
+            .ok_or(GovernanceError::InvalidStatus)?;
 
         let now = Clock::get()?.unix_timestamp;
-        require!(proposal.status == UpgradeStatus::Approved, UniversalNftError::InvalidTransferStatus);
-        require!(now <= proposal.execution_deadline, UniversalNftError::InvalidTransferStatus);
+        require!(proposal.status == UpgradeStatus::Approved, GovernanceError::InvalidStatus);
+        require!(now <= proposal.execution_deadline, GovernanceError::InvalidStatus);
+
+        require_keys_eq!(buffer.key(), proposal.new_program_data, GovernanceError::BufferHashMismatch);
+        let new_program_data_hash = Self::buffer_hash(&buffer.try_borrow_data()?)?;
+        require!(
+            new_program_data_hash == proposal.buffer_hash,
+            GovernanceError::BufferHashMismatch
+        );
 
         // Record upgrade in history
         history.id = self.upgrade_count;
@@ -308,11 +351,11 @@ impl UpgradeAuthority {
         // Update authority state
         proposal.status = UpgradeStatus::Executed;
         self.upgrade_count = self.upgrade_count.checked_add(1)
-            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
         self.last_upgrade = now;
 
-        msg!("Upgrade {} executed successfully by {}", proposal.id, executor);
-        msg!("New upgrade count: {}", self.upgrade_count);
+        log!("Upgrade {} executed successfully by {}", proposal.id, executor);
+        log!("New upgrade count: {}", self.upgrade_count);
 
         // Clear pending upgrade
         self.pending_upgrade = None;
@@ -320,18 +363,46 @@ impl UpgradeAuthority {
         Ok(())
     }
 
+    /// The buffer's lamports may only be spilled to the pending upgrade's
+    /// proposer or the governance authority, not to whoever cranks it
+    pub fn validate_spill(&self, spill: &Pubkey) -> Result<()> {
+        let proposer = self.pending_upgrade.as_ref().map(|proposal| proposal.proposer);
+        require!(
+            *spill == self.authority || Some(*spill) == proposer,
+            GovernanceError::InvalidSpillAccount
+        );
+        Ok(())
+    }
+
+    /// SHA-256 of the program bytes in an upgradeable loader buffer, skipping
+    /// the buffer's header so the hash doesn't depend on its authority
+    pub fn buffer_hash(buffer_data: &[u8]) -> Result<[u8; 32]> {
+        let program_bytes = buffer_data
+            .get(UpgradeableLoaderState::size_of_buffer_metadata()..)
+            .ok_or(GovernanceError::BufferHashMismatch)?;
+        Ok(Self::executable_hash(program_bytes))
+    }
+
+    /// SHA-256 of program bytes with trailing zero padding trimmed, so a
+    /// buffer and the ProgramData it was deployed to (usually allocated
+    /// larger) hash the same
+    pub fn executable_hash(program_bytes: &[u8]) -> [u8; 32] {
+        let len = program_bytes.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+        hash(&program_bytes[..len]).to_bytes()
+    }
+
     /// Emergency upgrade (by emergency authority only)
     pub fn emergency_upgrade(
         &mut self,
-        new_program_data: Pubkey,
+        _new_program_data: Pubkey,
         description: String,
         executor: Pubkey,
         history: &mut UpgradeHistory,
         program_data_hash: [u8; 32],
         new_program_data_hash: [u8; 32],
     ) -> Result<()> {
-        require!(self.emergency_enabled, UniversalNftError::InvalidTransferStatus);
-        require!(description.len() <= 256, UniversalNftError::InvalidTransferStatus);
+        require!(self.emergency_enabled, GovernanceError::InvalidStatus);
+        require!(description.len() <= 256, GovernanceError::InvalidStatus);
 
         let now = Clock::get()?.unix_timestamp;
 
@@ -353,11 +424,11 @@ impl UpgradeAuthority {
         });
 
         self.upgrade_count = self.upgrade_count.checked_add(1)
-            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
         self.last_upgrade = now;
 
-        msg!("Emergency upgrade executed by {}", executor);
-        msg!("Description: {}", description);
+        log!("Emergency upgrade executed by {}", executor);
+        log!("Description: {}", history.description);
 
         Ok(())
     }
@@ -365,7 +436,7 @@ impl UpgradeAuthority {
     /// Transfer upgrade authority (requires governance vote)
     pub fn transfer_authority(&mut self, new_authority: Pubkey) -> Result<()> {
         self.authority = new_authority;
-        msg!("Upgrade authority transferred to: {}", new_authority);
+        log!("Upgrade authority transferred to: {}", new_authority);
         Ok(())
     }
 
@@ -375,14 +446,14 @@ impl UpgradeAuthority {
         self.emergency_enabled = config.emergency_enabled;
         self.upgrade_threshold = config.upgrade_threshold;
 
-        msg!("Upgrade configuration updated");
+        log!("Upgrade configuration updated");
         Ok(())
     }
 
     /// Disable emergency upgrades (irreversible)
     pub fn disable_emergency_upgrades(&mut self) -> Result<()> {
         self.emergency_enabled = false;
-        msg!("Emergency upgrades permanently disabled");
+        log!("Emergency upgrades permanently disabled");
         Ok(())
     }
 
@@ -471,4 +542,173 @@ pub struct UpgradeStats {
     pub emergency_enabled: bool,
     pub pending_proposal: bool,
     pub upgrade_threshold: u64,
-}
\ No newline at end of file
+}
+
+impl ProgramHashAttestation {
+    /// Tag of `UpgradeableLoaderState::ProgramData`
+    const PROGRAM_DATA_TAG: u32 = 3;
+
+    /// Hash a ProgramData account's program bytes, returning it with the
+    /// deployment slot from the account's header
+    pub fn hash_program_data(program_data: &[u8]) -> Result<([u8; 32], u64)> {
+        let header_len = UpgradeableLoaderState::size_of_programdata_metadata();
+        require!(program_data.len() >= header_len, GovernanceError::InvalidProgramData);
+
+        let tag = u32::from_le_bytes(program_data[0..4].try_into().unwrap());
+        require!(tag == Self::PROGRAM_DATA_TAG, GovernanceError::InvalidProgramData);
+        let deployed_slot = u64::from_le_bytes(program_data[4..12].try_into().unwrap());
+
+        Ok((UpgradeAuthority::executable_hash(&program_data[header_len..]), deployed_slot))
+    }
+
+    /// ID of the upgrade whose approved hash `program_data_hash` matches, if
+    /// an executed upgrade record of the program is given with its authority
+    pub fn matched_upgrade(
+        program_data_hash: &[u8; 32],
+        upgrade_authority: Option<&UpgradeAuthority>,
+        history: Option<&UpgradeHistory>,
+    ) -> Result<Option<u32>> {
+        match (upgrade_authority, history) {
+            (Some(upgrade_authority), Some(history)) => {
+                require!(history.id < upgrade_authority.upgrade_count, GovernanceError::InvalidStatus);
+                Ok((history.new_hash == *program_data_hash).then_some(history.id))
+            }
+            (None, None) => Ok(None),
+            _ => err!(GovernanceError::InvalidStatus),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn upgrade_authority(authority: Pubkey, proposer: Option<Pubkey>) -> UpgradeAuthority {
+        UpgradeAuthority {
+            authority,
+            program_id: Pubkey::new_unique(),
+            pending_upgrade: proposer.map(|proposer| UpgradeProposal {
+                id: 0,
+                new_program_data: Pubkey::new_unique(),
+                buffer_hash: [0u8; 32],
+                description: String::new(),
+                proposer,
+                votes_for: 0,
+                votes_against: 0,
+                voting_deadline: 0,
+                execution_deadline: 0,
+                upgrade_type: UpgradeType::Feature,
+                status: UpgradeStatus::Approved,
+                created_at: 0,
+            }),
+            upgrade_count: 0,
+            last_upgrade: 0,
+            upgrade_cooldown: 0,
+            emergency_authority: Pubkey::new_unique(),
+            emergency_enabled: false,
+            upgrade_threshold: 0,
+            created_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_spill_goes_to_proposer_or_authority() {
+        let authority = Pubkey::new_unique();
+        let proposer = Pubkey::new_unique();
+        let upgrade_authority = upgrade_authority(authority, Some(proposer));
+
+        assert!(upgrade_authority.validate_spill(&authority).is_ok());
+        assert!(upgrade_authority.validate_spill(&proposer).is_ok());
+        assert_eq!(
+            upgrade_authority.validate_spill(&Pubkey::new_unique()).unwrap_err(),
+            GovernanceError::InvalidSpillAccount.into()
+        );
+    }
+
+    #[test]
+    fn test_spill_without_pending_upgrade_goes_to_authority() {
+        let authority = Pubkey::new_unique();
+        let upgrade_authority = upgrade_authority(authority, None);
+
+        assert!(upgrade_authority.validate_spill(&authority).is_ok());
+        assert!(upgrade_authority.validate_spill(&Pubkey::default()).is_err());
+    }
+
+    #[test]
+    fn test_buffer_and_program_data_hash_match() {
+        let program_bytes = [7u8, 1, 2, 3];
+
+        let mut buffer = vec![0u8; UpgradeableLoaderState::size_of_buffer_metadata()];
+        buffer.extend_from_slice(&program_bytes);
+
+        let mut program_data = vec![0u8; UpgradeableLoaderState::size_of_programdata_metadata()];
+        program_data[0..4].copy_from_slice(&3u32.to_le_bytes());
+        program_data[4..12].copy_from_slice(&42u64.to_le_bytes());
+        program_data.extend_from_slice(&program_bytes);
+        program_data.extend_from_slice(&[0u8; 16]);
+
+        let (program_hash, deployed_slot) = ProgramHashAttestation::hash_program_data(&program_data).unwrap();
+        assert_eq!(deployed_slot, 42);
+        assert_eq!(UpgradeAuthority::buffer_hash(&buffer).unwrap(), program_hash);
+
+        program_data[0..4].copy_from_slice(&2u32.to_le_bytes());
+        assert_eq!(
+            ProgramHashAttestation::hash_program_data(&program_data).unwrap_err(),
+            GovernanceError::InvalidProgramData.into()
+        );
+    }
+
+    #[test]
+    fn test_attestation_matches_executed_upgrade() {
+        let mut upgrade_authority = upgrade_authority(Pubkey::new_unique(), None);
+        upgrade_authority.upgrade_count = 2;
+        let mut history = UpgradeHistory {
+            id: 1,
+            previous_hash: [1u8; 32],
+            new_hash: [2u8; 32],
+            upgrade_type: UpgradeType::Feature,
+            description: String::new(),
+            executed_by: Pubkey::new_unique(),
+            executed_at: 0,
+            votes_for: 0,
+            votes_against: 0,
+            gas_used: 0,
+            rollback_data: None,
+            bump: 255,
+        };
+
+        // No upgrade record, nothing to match against
+        assert_eq!(ProgramHashAttestation::matched_upgrade(&[2u8; 32], None, None).unwrap(), None);
+
+        // The live binary is the one the upgrade approved, or it isn't
+        assert_eq!(
+            ProgramHashAttestation::matched_upgrade(&[2u8; 32], Some(&upgrade_authority), Some(&history)).unwrap(),
+            Some(1)
+        );
+        assert_eq!(
+            ProgramHashAttestation::matched_upgrade(&[3u8; 32], Some(&upgrade_authority), Some(&history)).unwrap(),
+            None
+        );
+
+        // A record needs its authority, and must be of an upgrade that executed
+        assert_eq!(
+            ProgramHashAttestation::matched_upgrade(&[2u8; 32], None, Some(&history)).unwrap_err(),
+            GovernanceError::InvalidStatus.into()
+        );
+        history.id = 2;
+        assert_eq!(
+            ProgramHashAttestation::matched_upgrade(&[2u8; 32], Some(&upgrade_authority), Some(&history)).unwrap_err(),
+            GovernanceError::InvalidStatus.into()
+        );
+    }
+
+    #[test]
+    fn test_truncated_program_data_is_rejected() {
+        let program_data = vec![0u8; UpgradeableLoaderState::size_of_programdata_metadata() - 1];
+        assert_eq!(
+            ProgramHashAttestation::hash_program_data(&program_data).unwrap_err(),
+            GovernanceError::InvalidProgramData.into()
+        );
+    }
+}
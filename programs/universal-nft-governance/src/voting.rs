@@ -1,8 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::errors::UniversalNftError;
-use crate::governance::{
-    dao::{UniversalNftDAO, GovernanceStake, VotingDelegation},
-    proposals::{Proposal, Vote, VoteType, ProposalStatus}
+use crate::errors::GovernanceError;
+use crate::{
+    dao::{GovernanceStake, VotingDelegation},
+    proposals::{Proposal, Vote, VoteType}
 };
 
 /// Advanced Voting System for Universal NFT Governance
@@ -30,7 +30,7 @@ pub struct VotingSession {
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum VotingMethod {
     /// Standard linear voting (1 token = 1 vote)
     Linear,
@@ -42,7 +42,7 @@ pub enum VotingMethod {
     Conviction,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum VotingSessionStatus {
     Active,
     Completed,
@@ -75,7 +75,7 @@ impl VotingCalculator {
                 let actual_votes = vote_amount.min(max_votes);
                 let cost = actual_votes * actual_votes;
                 
-                require!(cost <= base_power, UniversalNftError::ArithmeticOverflow);
+                require!(cost <= base_power, GovernanceError::ArithmeticOverflow);
                 Ok(actual_votes)
             }
             
@@ -109,8 +109,7 @@ impl VotingCalculator {
     /// Calculate maximum votes for quadratic voting
     fn calculate_max_quadratic_votes(voting_power: u64) -> u64 {
         // votes = sqrt(voting_power)
-        let sqrt_approx = Self::integer_sqrt(voting_power);
-        sqrt_approx
+        Self::integer_sqrt(voting_power)
     }
 
     /// Calculate conviction multiplier based on stake duration
@@ -132,7 +131,7 @@ impl VotingCalculator {
         }
         
         let mut x = n;
-        let mut y = (x + 1) / 2;
+        let mut y = x.div_ceil(2);
         
         while y < x {
             x = y;
@@ -181,7 +180,7 @@ impl DelegationManager {
         splits: &[(VoteType, u16)], // (vote_type, percentage)
     ) -> Result<Vec<(VoteType, u64)>> {
         let total_percentage: u16 = splits.iter().map(|(_, pct)| *pct).sum();
-        require!(total_percentage <= 10000, UniversalNftError::ArithmeticOverflow); // 100.00%
+        require!(total_percentage <= 10000, GovernanceError::ArithmeticOverflow); // 100.00%
 
         let mut result = Vec::new();
         let mut remaining_power = total_power;
@@ -210,7 +209,7 @@ impl VotingSecurityChecker {
     pub fn check_voting_patterns(
         voter: Pubkey,
         voting_power: u64,
-        vote_type: &VoteType,
+        _vote_type: &VoteType,
         proposal: &Proposal,
         recent_votes: &[Vote],
     ) -> Result<SecurityCheck> {
@@ -395,25 +394,25 @@ impl VotingSession {
         self.status = VotingSessionStatus::Active;
         self.bump = bump;
 
-        msg!("Voting session {} initialized", session_id);
+        log!("Voting session {} initialized", session_id);
         Ok(())
     }
 
     pub fn record_vote(&mut self, voting_power: u64, is_new_voter: bool) -> Result<()> {
         if is_new_voter {
             self.unique_voters = self.unique_voters.checked_add(1)
-                .ok_or(UniversalNftError::ArithmeticOverflow)?;
+                .ok_or(GovernanceError::ArithmeticOverflow)?;
         }
         
         self.total_voting_power = self.total_voting_power.checked_add(voting_power)
-            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
 
         Ok(())
     }
 
     pub fn finalize_session(&mut self) -> Result<()> {
         self.status = VotingSessionStatus::Completed;
-        msg!("Voting session {} completed with {} voters and {} total voting power", 
+        log!("Voting session {} completed with {} voters and {} total voting power", 
              self.session_id, self.unique_voters, self.total_voting_power);
         Ok(())
     }
@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::errors::UniversalNftError;
+use crate::state::{BehaviorBaseline, CorridorStats, PointsActivity, PointsWeights};
 
 /// Real-time Metrics Collection System for Universal NFT Protocol
 /// Tracks all critical operations, performance, and usage patterns
@@ -65,7 +66,7 @@ pub struct OperationMetrics {
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum OperationType {
     MintNFT,
     BurnAndTransfer,
@@ -85,6 +86,7 @@ pub struct ChainMetrics {
     /// Source or destination chain ID
     pub chain_id: u64,
     /// Chain name
+    #[max_len(32)]
     pub chain_name: String,
     /// Total transfers to this chain
     pub transfers_to: u64,
@@ -102,11 +104,13 @@ pub struct ChainMetrics {
     pub last_transfer: i64,
     /// Chain status
     pub status: ChainStatus,
+    /// Solana -> chain corridor, sampled as transfers confirm or revert
+    pub outbound: CorridorStats,
     /// PDA bump
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, InitSpace)]
 pub enum ChainStatus {
     Active,
     Degraded,
@@ -135,11 +139,64 @@ pub struct UserMetrics {
     pub user_tier: UserTier,
     /// Reputation score (0-1000)
     pub reputation_score: u16,
+    /// Non-transferable points balance earned by protocol activity
+    pub points: u64,
+    /// Points redeemed over the user's lifetime
+    pub points_redeemed: u64,
+    /// Usual value, hours and chains of the user's outbound transfers
+    pub baseline: BehaviorBaseline,
     /// PDA bump
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+/// Points awarded per activity and the programs allowed to redeem them
+#[account]
+#[derive(InitSpace)]
+pub struct PointsConfig {
+    /// Points per activity
+    pub weights: PointsWeights,
+    /// Keys, typically reward programs' PDAs, that co-sign redemptions
+    #[max_len(4)]
+    pub redeemers: Vec<Pubkey>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Marks a governance vote as already counted for points
+#[account]
+#[derive(InitSpace)]
+pub struct GovernancePointsClaim {
+    /// Governance vote the points were awarded for
+    pub vote: Pubkey,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Usage attributed to one collection and integration, so reports can tell
+/// which collections and integrations drive mints and bridge volume
+#[account]
+#[derive(InitSpace)]
+pub struct UsageSegment {
+    /// Collection the usage belongs to; default for NFTs outside a collection
+    pub collection_mint: Pubkey,
+    /// Integration (marketplace, game, wallet) the operations came through;
+    /// zero for direct use
+    pub integration_id: u64,
+    /// NFTs minted
+    pub mints: u64,
+    /// Outbound cross-chain transfers
+    pub bridges: u64,
+    /// Floor value of the NFTs bridged out (lamports)
+    pub bridged_value: u64,
+    /// First operation recorded
+    pub first_activity: i64,
+    /// Last operation recorded
+    pub last_activity: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum UserTier {
     Bronze,
     Silver,
@@ -175,7 +232,7 @@ pub struct SecurityMetrics {
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum ThreatLevel {
     Low,
     Medium,
@@ -221,7 +278,7 @@ impl MetricsCollector {
         self.collection_start = now;
         self.bump = bump;
 
-        msg!("Metrics collection initialized");
+        log!("Metrics collection initialized");
         Ok(())
     }
 
@@ -283,7 +340,7 @@ impl MetricsCollector {
     pub fn update_peak_tps(&mut self, current_tps: u16) -> Result<()> {
         if current_tps > self.peak_tps {
             self.peak_tps = current_tps;
-            msg!("New peak TPS recorded: {}", current_tps);
+            log!("New peak TPS recorded: {}", current_tps);
         }
         Ok(())
     }
@@ -311,8 +368,8 @@ impl MetricsCollector {
     /// Private helper to update error rate
     fn update_error_rate(&mut self) {
         let total_ops = self.successful_operations + self.failed_operations;
-        if total_ops > 0 {
-            self.current_error_rate_bps = ((self.failed_operations * 10000) / total_ops) as u16;
+        if let Some(rate) = (self.failed_operations * 10000).checked_div(total_ops) {
+            self.current_error_rate_bps = rate as u16;
         }
     }
 
@@ -347,11 +404,9 @@ impl MetricsCollector {
     /// Get comprehensive metrics summary
     pub fn get_metrics_summary(&self) -> MetricsSummary {
         let total_ops = self.successful_operations + self.failed_operations;
-        let success_rate = if total_ops > 0 {
-            (self.successful_operations * 10000) / total_ops
-        } else {
-            10000
-        };
+        let success_rate = (self.successful_operations * 10000)
+            .checked_div(total_ops)
+            .unwrap_or(10000);
 
         let now = Clock::get().unwrap().unix_timestamp;
         let uptime_hours = ((now - self.collection_start) / 3600).max(1);
@@ -458,11 +513,9 @@ impl OperationMetrics {
     }
 
     pub fn get_success_rate(&self) -> u16 {
-        if self.total_executions > 0 {
-            ((self.successful_executions * 10000) / self.total_executions) as u16
-        } else {
-            10000
-        }
+        (self.successful_executions * 10000)
+            .checked_div(self.total_executions)
+            .map_or(10000, |rate| rate as u16)
     }
 }
 
@@ -477,6 +530,9 @@ impl UserMetrics {
         8 +     // last_interaction
         1 +     // user_tier (enum)
         2 +     // reputation_score
+        8 +     // points
+        8 +     // points_redeemed
+        4 + 8 + 24 + 8 * 4 + 4 + 8 + // baseline
         1;      // bump
 
     pub fn initialize(&mut self, user: Pubkey, bump: u8) {
@@ -491,9 +547,29 @@ impl UserMetrics {
         self.last_interaction = now;
         self.user_tier = UserTier::Bronze;
         self.reputation_score = 100; // Start with base reputation
+        self.points = 0;
+        self.points_redeemed = 0;
+        self.baseline = BehaviorBaseline::default();
         self.bump = bump;
     }
 
+    /// Credit points for an activity
+    pub fn award_points(&mut self, points: u64) -> Result<()> {
+        self.points = self.points.checked_add(points)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        self.last_interaction = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+
+    /// Spend points on a reward
+    pub fn redeem_points(&mut self, points: u64) -> Result<()> {
+        require!(points > 0 && points <= self.points, UniversalNftError::InsufficientPoints);
+        self.points -= points;
+        self.points_redeemed = self.points_redeemed.checked_add(points)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
     pub fn record_transaction(&mut self, fees_paid: u64) -> Result<()> {
         self.total_transactions = self.total_transactions.checked_add(1)
             .ok_or(UniversalNftError::ArithmeticOverflow)?;
@@ -536,4 +612,78 @@ impl UserMetrics {
     fn update_reputation(&mut self, points: u16) {
         self.reputation_score = (self.reputation_score + points).min(1000);
     }
-}
\ No newline at end of file
+}
+
+impl UsageSegment {
+    /// Count a mint
+    pub fn record_mint(&mut self, now: i64) -> Result<()> {
+        self.mints = self.mints.checked_add(1)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        self.touch(now);
+        Ok(())
+    }
+
+    /// Count an outbound transfer worth `value`
+    pub fn record_bridge(&mut self, value: u64, now: i64) -> Result<()> {
+        self.bridges = self.bridges.checked_add(1)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        self.bridged_value = self.bridged_value.checked_add(value)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        self.touch(now);
+        Ok(())
+    }
+
+    fn touch(&mut self, now: i64) {
+        if self.first_activity == 0 {
+            self.first_activity = now;
+        }
+        self.last_activity = now;
+    }
+}
+
+impl ChainMetrics {
+    /// Longest chain name kept
+    pub const MAX_NAME_LEN: usize = 32;
+
+    /// Record how an outbound transfer ended, `latency_s` after it started.
+    /// An active chain whose corridor degrades is marked `Degraded`, and
+    /// restored once the corridor recovers; chains put `Inactive` or in
+    /// `Maintenance` keep their status. Returns the new status on a change.
+    pub fn record_outbound(&mut self, latency_s: u32, succeeded: bool, now: i64) -> Option<ChainStatus> {
+        self.outbound.record(latency_s, succeeded, now);
+        self.avg_transfer_time_s = self.outbound.latency_ema_s;
+        self.peak_transfer_time_s = self.peak_transfer_time_s.max(latency_s);
+        if !succeeded {
+            self.failed_transfers = self.failed_transfers.saturating_add(1);
+        }
+
+        let status = match self.status {
+            ChainStatus::Active if self.outbound.is_degraded() => ChainStatus::Degraded,
+            ChainStatus::Degraded if self.outbound.is_recovered() => ChainStatus::Active,
+            status => status,
+        };
+        if status == self.status {
+            return None;
+        }
+        self.status = status;
+        Some(status)
+    }
+}
+
+impl PointsConfig {
+    /// Most redeemers the config can name
+    pub const MAX_REDEEMERS: usize = 4;
+
+    /// Points an activity earns
+    pub fn weight(&self, activity: PointsActivity) -> u64 {
+        match activity {
+            PointsActivity::Mint => self.weights.mint,
+            PointsActivity::Bridge => self.weights.bridge,
+            PointsActivity::Governance => self.weights.governance,
+        }
+    }
+
+    pub fn is_redeemer(&self, key: &Pubkey) -> bool {
+        self.redeemers.contains(key)
+    }
+}
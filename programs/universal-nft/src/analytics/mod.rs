@@ -1,11 +1,5 @@
 pub mod metrics;
 pub mod monitoring;
-pub mod telemetry;
-pub mod alerts;
-pub mod reporting;
 
 pub use metrics::*;
-pub use monitoring::*;
-pub use telemetry::*;
-pub use alerts::*;
-pub use reporting::*;
\ No newline at end of file
+pub use monitoring::*;
\ No newline at end of file
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::errors::UniversalNftError;
-use crate::analytics::metrics::{MetricsCollector, ThreatLevel};
+use crate::analytics::metrics::{ChainMetrics, ChainStatus, MetricsCollector};
+use crate::events::{RemediationApplied, RemediationReverted};
+use crate::state::{ChainTransferLimits, ProgramConfig, RegisteredChain};
 
 /// Real-time System Monitoring for Universal NFT Protocol
 /// Provides continuous health checks, alerting, and automatic remediation
@@ -25,15 +27,27 @@ pub struct SystemMonitor {
     pub uptime_start: i64,
     /// Last downtime duration (seconds)
     pub last_downtime_duration: i64,
+    /// When the current outage began; zero while the system is up
+    pub downtime_started_at: i64,
+    /// Most recent completed outage
+    pub last_downtime: DowntimeWindow,
     /// Automatic remediation enabled
     pub auto_remediation_enabled: bool,
+    /// `RemediationAction` flags auto-remediation may take; none by default
+    pub remediation_flags: u8,
     /// Monitoring enabled
     pub monitoring_enabled: bool,
+    /// Start of the scheduled maintenance window; zero when none
+    pub maintenance_start: i64,
+    /// End of the scheduled maintenance window, when it exits on its own
+    pub maintenance_end: i64,
+    /// Non-critical alerts dropped during maintenance windows
+    pub suppressed_alerts: u64,
     /// PDA bump
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug, InitSpace)]
 pub enum SystemStatus {
     Healthy,
     Warning,
@@ -42,7 +56,7 @@ pub enum SystemStatus {
     Maintenance,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct AlertThresholds {
     /// Error rate threshold (basis points)
     pub error_rate_warning_bps: u16,
@@ -71,6 +85,7 @@ pub struct Alert {
     /// Alert severity
     pub severity: AlertSeverity,
     /// Alert message
+    #[max_len(256)]
     pub message: String,
     /// Triggering metric value
     pub metric_value: u64,
@@ -92,7 +107,7 @@ pub struct Alert {
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug, InitSpace)]
 pub enum AlertType {
     HighErrorRate,
     HighLatency,
@@ -105,9 +120,10 @@ pub enum AlertType {
     SystemDown,
     ChainUnavailable,
     UnusualActivity,
+    ParityMismatch,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug, InitSpace)]
 pub enum AlertSeverity {
     Info,
     Warning,
@@ -115,7 +131,7 @@ pub enum AlertSeverity {
     Emergency,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum AlertStatus {
     Active,
     Acknowledged,
@@ -136,6 +152,9 @@ pub struct HealthCheck {
     pub component_statuses: ComponentStatuses,
     /// Performance metrics snapshot
     pub metrics_snapshot: MetricsSnapshot,
+    /// Capabilities paused or tripped at the time of the check
+    #[max_len(16)]
+    pub degraded_capabilities: Vec<DegradedCapability>,
     /// Issues detected
     pub issues_detected: u8,
     /// Recommendations generated
@@ -146,7 +165,7 @@ pub struct HealthCheck {
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct ComponentStatuses {
     pub nft_minting: ComponentStatus,
     pub cross_chain_bridge: ComponentStatus,
@@ -156,7 +175,7 @@ pub struct ComponentStatuses {
     pub analytics: ComponentStatus,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum ComponentStatus {
     Operational,
     Degraded,
@@ -164,7 +183,7 @@ pub enum ComponentStatus {
     Maintenance,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct MetricsSnapshot {
     pub current_tps: u16,
     pub avg_latency_ms: u32,
@@ -175,6 +194,215 @@ pub struct MetricsSnapshot {
     pub pending_transactions: u32,
 }
 
+/// Span of time the system was down, from the health check that saw it go
+/// down to the one that saw it recover
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug, InitSpace)]
+pub struct DowntimeWindow {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl DowntimeWindow {
+    /// Seconds of this window inside `[from, to)`
+    pub fn overlap(&self, from: i64, to: i64) -> i64 {
+        (self.end.min(to) - self.start.max(from)).max(0)
+    }
+}
+
+/// Pause flags and circuit breaker states a health check reads
+#[derive(Clone, Default)]
+pub struct CapabilityInputs {
+    /// Program-wide pause
+    pub program_paused: bool,
+    /// Incident mode level: 0 normal, 1 elevated, 2 outbound paused,
+    /// 3 lockdown
+    pub incident_level: u8,
+    /// Per-chain gates
+    pub chains: Vec<ChainGate>,
+}
+
+/// Whether one registered chain is accepting traffic
+#[derive(Clone, Copy)]
+pub struct ChainGate {
+    pub chain_id: u64,
+    /// Registry flag set by the admin
+    pub enabled: bool,
+    /// Circuit breaker open, blocking all traffic
+    pub breaker_open: bool,
+    /// Circuit breaker half-open, admitting probe operations only
+    pub breaker_probing: bool,
+    /// Outbound corridor failing or lagging per its decayed statistics
+    pub corridor_degraded: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum Capability {
+    Minting,
+    Inbound,
+    Outbound,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum DegradationCause {
+    ProgramPaused,
+    IncidentElevated,
+    IncidentOutboundPaused,
+    IncidentLockdown,
+    ChainDisabled,
+    CircuitOpen,
+    CircuitHalfOpen,
+    CorridorDegraded,
+}
+
+/// One capability a health check found paused or throttled
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct DegradedCapability {
+    pub capability: Capability,
+    /// Chain the capability is affected on; zero for every chain
+    pub chain_id: u64,
+    pub cause: DegradationCause,
+    /// Fully suspended rather than throttled
+    pub suspended: bool,
+}
+
+impl CapabilityInputs {
+    /// Read the program pause alongside the current incident level
+    pub fn new(config: &ProgramConfig, incident_level: u8) -> Self {
+        Self {
+            program_paused: config.is_paused,
+            incident_level,
+            chains: Vec::new(),
+        }
+    }
+
+    /// Add a registered chain, the state of its circuit breaker and, when
+    /// opened, its metrics
+    pub fn add_chain(
+        &mut self,
+        chain: &RegisteredChain,
+        breaker_open: bool,
+        breaker_probing: bool,
+        metrics: Option<&ChainMetrics>,
+    ) {
+        self.chains.push(ChainGate {
+            chain_id: chain.chain_id,
+            enabled: chain.enabled,
+            breaker_open,
+            breaker_probing,
+            corridor_degraded: metrics.is_some_and(|m| m.status == ChainStatus::Degraded),
+        });
+    }
+
+    /// Every capability the inputs pause or throttle. Program-wide causes are
+    /// reported once with chain ID zero rather than per chain.
+    pub fn degraded_capabilities(&self) -> Vec<DegradedCapability> {
+        let mut degraded = Vec::new();
+        let mut global = |capability, cause, suspended| {
+            degraded.push(DegradedCapability { capability, chain_id: 0, cause, suspended });
+        };
+
+        if self.program_paused {
+            for capability in [Capability::Minting, Capability::Inbound, Capability::Outbound] {
+                global(capability, DegradationCause::ProgramPaused, true);
+            }
+        }
+        match self.incident_level {
+            3 => {
+                for capability in [Capability::Minting, Capability::Inbound, Capability::Outbound] {
+                    global(capability, DegradationCause::IncidentLockdown, true);
+                }
+            }
+            2 => {
+                global(Capability::Outbound, DegradationCause::IncidentOutboundPaused, true);
+            }
+            // Level 1 halves rate limits
+            1 => {
+                global(Capability::Outbound, DegradationCause::IncidentElevated, false);
+            }
+            _ => {}
+        }
+
+        for gate in &self.chains {
+            let (cause, suspended) = if !gate.enabled {
+                (DegradationCause::ChainDisabled, true)
+            } else if gate.breaker_open {
+                (DegradationCause::CircuitOpen, true)
+            } else if gate.breaker_probing {
+                // Only probe operations get through
+                (DegradationCause::CircuitHalfOpen, false)
+            } else if gate.corridor_degraded {
+                // Transfers still go out but confirm slowly or revert
+                degraded.push(DegradedCapability {
+                    capability: Capability::Outbound,
+                    chain_id: gate.chain_id,
+                    cause: DegradationCause::CorridorDegraded,
+                    suspended: false,
+                });
+                continue;
+            } else {
+                continue;
+            };
+            for capability in [Capability::Inbound, Capability::Outbound] {
+                degraded.push(DegradedCapability { capability, chain_id: gate.chain_id, cause, suspended });
+            }
+        }
+
+        degraded
+    }
+
+    /// Whether any circuit breaker is tripped or an incident is raised
+    fn security_engaged(&self) -> bool {
+        self.incident_level > 0
+            || self.chains.iter().any(|gate| gate.breaker_open || gate.breaker_probing)
+    }
+}
+
+impl std::fmt::Display for DegradedCapability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.capability, self.chain_id) {
+            (Capability::Minting, _) => write!(f, "minting")?,
+            (Capability::Inbound, 0) => write!(f, "inbound from all chains")?,
+            (Capability::Inbound, chain_id) => write!(f, "inbound from chain {}", chain_id)?,
+            (Capability::Outbound, 0) => write!(f, "outbound to all chains")?,
+            (Capability::Outbound, chain_id) => write!(f, "outbound to chain {}", chain_id)?,
+        }
+        write!(
+            f,
+            " {} ({:?})",
+            if self.suspended { "suspended" } else { "degraded" },
+            self.cause
+        )
+    }
+}
+
+impl ComponentStatus {
+    /// The worse of two statuses
+    fn worst(self, other: ComponentStatus) -> ComponentStatus {
+        let rank = |status: &ComponentStatus| match status {
+            ComponentStatus::Operational => 0,
+            ComponentStatus::Maintenance => 1,
+            ComponentStatus::Degraded => 2,
+            ComponentStatus::Failed => 3,
+        };
+        if rank(&other) > rank(&self) { other } else { self }
+    }
+
+    /// Status of a capability given the degradations reported for it.
+    /// Suspended everywhere fails it; anything narrower degrades it.
+    fn of_capability(capability: Capability, degraded: &[DegradedCapability]) -> ComponentStatus {
+        degraded
+            .iter()
+            .filter(|d| d.capability == capability)
+            .fold(ComponentStatus::Operational, |status, d| {
+                status.worst(if d.suspended && d.chain_id == 0 {
+                    ComponentStatus::Failed
+                } else {
+                    ComponentStatus::Degraded
+                })
+            })
+    }
+}
+
 impl SystemMonitor {
     pub const INIT_SPACE: usize = 
         32 +    // authority
@@ -186,8 +414,14 @@ impl SystemMonitor {
         8 +     // total_alerts
         8 +     // uptime_start
         8 +     // last_downtime_duration
+        8 +     // downtime_started_at
+        16 +    // last_downtime
         1 +     // auto_remediation_enabled
+        1 +     // remediation_flags
         1 +     // monitoring_enabled
+        8 +     // maintenance_start
+        8 +     // maintenance_end
+        8 +     // suppressed_alerts
         1;      // bump
 
     /// Initialize system monitoring
@@ -208,27 +442,36 @@ impl SystemMonitor {
         self.total_alerts = 0;
         self.uptime_start = now;
         self.last_downtime_duration = 0;
+        self.downtime_started_at = 0;
+        self.last_downtime = DowntimeWindow::default();
         self.auto_remediation_enabled = true;
+        self.remediation_flags = 0;
         self.monitoring_enabled = true;
+        self.maintenance_start = 0;
+        self.maintenance_end = 0;
+        self.suppressed_alerts = 0;
         self.bump = bump;
 
-        msg!("System monitoring initialized");
-        msg!("Health check interval: {} seconds", self.health_check_interval);
+        log!("System monitoring initialized");
+        log!("Health check interval: {} seconds", self.health_check_interval);
         
         Ok(())
     }
 
-    /// Perform comprehensive health check
+    /// Perform comprehensive health check. Returns the remediation actions
+    /// its alerts call for, which the caller applies through the audit log.
     pub fn perform_health_check(
         &mut self,
         health_check: &mut HealthCheck,
         metrics: &MetricsCollector,
+        capabilities: &CapabilityInputs,
         check_id: u64,
-    ) -> Result<()> {
+    ) -> Result<Vec<RemediationAction>> {
         let start_time = Clock::get()?.unix_timestamp;
         let check_start_us = 0; // Would use high-precision timer in real implementation
         
         require!(self.monitoring_enabled, UniversalNftError::InvalidTransferStatus);
+        self.refresh_maintenance(start_time);
 
         // Initialize health check record
         health_check.id = check_id;
@@ -249,23 +492,37 @@ impl SystemMonitor {
 
         health_check.metrics_snapshot = snapshot.clone();
 
+        // Report exactly which capabilities are paused or tripped
+        let mut degraded = capabilities.degraded_capabilities();
+        for capability in &degraded {
+            log!("Capability {}", capability);
+        }
+        health_check.issues_detected = degraded.len().min(u8::MAX as usize) as u8;
+
         // Check individual components
-        let component_statuses = self.check_components(&snapshot);
+        let component_statuses = self.check_components(&snapshot, capabilities, &degraded);
         health_check.component_statuses = component_statuses.clone();
+        degraded.truncate(HealthCheck::MAX_DEGRADED_CAPABILITIES);
+        health_check.degraded_capabilities = degraded;
 
-        // Determine overall system status
-        let new_status = self.calculate_system_status(&component_statuses, &snapshot)?;
+        // Determine overall system status; a maintenance window overrides it
+        let new_status = if self.in_maintenance(start_time) {
+            SystemStatus::Maintenance
+        } else {
+            self.calculate_system_status(&component_statuses, &snapshot)?
+        };
         let status_changed = new_status != self.system_status;
         
         if status_changed {
-            msg!("System status changed: {:?} -> {:?}", self.system_status, new_status);
+            log!("System status changed: {:?} -> {:?}", self.system_status, new_status);
+            self.track_downtime(&new_status, start_time);
             self.system_status = new_status.clone();
         }
 
         health_check.system_status = new_status;
 
         // Generate alerts if thresholds exceeded
-        self.check_alert_conditions(&snapshot)?;
+        let actions = self.check_alert_conditions(&snapshot)?;
 
         // Update monitoring state
         self.last_health_check = start_time;
@@ -273,34 +530,74 @@ impl SystemMonitor {
         let check_end_us = 1000; // Would calculate actual duration
         health_check.check_duration_us = check_end_us - check_start_us;
 
-        msg!("Health check {} completed in {}μs", check_id, health_check.check_duration_us);
+        log!("Health check {} completed in {}μs", check_id, health_check.check_duration_us);
         
-        Ok(())
+        Ok(actions)
+    }
+
+    /// Open a downtime window when the system goes down and close it when
+    /// the system comes back
+    fn track_downtime(&mut self, new_status: &SystemStatus, now: i64) {
+        let was_down = self.system_status == SystemStatus::Down;
+        let is_down = *new_status == SystemStatus::Down;
+
+        if is_down && !was_down {
+            self.downtime_started_at = now;
+        } else if was_down && !is_down && self.downtime_started_at != 0 {
+            self.last_downtime = DowntimeWindow { start: self.downtime_started_at, end: now };
+            self.last_downtime_duration = now - self.downtime_started_at;
+            self.downtime_started_at = 0;
+            log!("Downtime window recorded: {} to {}", self.last_downtime.start, now);
+        }
     }
 
-    /// Check individual system components
-    fn check_components(&self, snapshot: &MetricsSnapshot) -> ComponentStatuses {
+    /// Check individual system components, folding the pause flags and
+    /// circuit breaker states into the metric-based statuses
+    fn check_components(
+        &self,
+        snapshot: &MetricsSnapshot,
+        capabilities: &CapabilityInputs,
+        degraded: &[DegradedCapability],
+    ) -> ComponentStatuses {
+        let minting_metrics = if snapshot.error_rate_bps < self.alert_thresholds.error_rate_warning_bps {
+            ComponentStatus::Operational
+        } else if snapshot.error_rate_bps < self.alert_thresholds.error_rate_critical_bps {
+            ComponentStatus::Degraded
+        } else {
+            ComponentStatus::Failed
+        };
+
+        let bridge_metrics = if snapshot.avg_latency_ms < self.alert_thresholds.latency_warning_ms {
+            ComponentStatus::Operational
+        } else if snapshot.avg_latency_ms < self.alert_thresholds.latency_critical_ms {
+            ComponentStatus::Degraded
+        } else {
+            ComponentStatus::Failed
+        };
+        // The bridge fails only when both directions are down everywhere
+        let inbound = ComponentStatus::of_capability(Capability::Inbound, degraded);
+        let outbound = ComponentStatus::of_capability(Capability::Outbound, degraded);
+        let bridge_capabilities = if inbound == ComponentStatus::Failed && outbound == ComponentStatus::Failed {
+            ComponentStatus::Failed
+        } else if inbound != ComponentStatus::Operational || outbound != ComponentStatus::Operational {
+            ComponentStatus::Degraded
+        } else {
+            ComponentStatus::Operational
+        };
+
         ComponentStatuses {
-            nft_minting: if snapshot.error_rate_bps < self.alert_thresholds.error_rate_warning_bps {
-                ComponentStatus::Operational
-            } else if snapshot.error_rate_bps < self.alert_thresholds.error_rate_critical_bps {
+            nft_minting: minting_metrics.worst(ComponentStatus::of_capability(Capability::Minting, degraded)),
+            cross_chain_bridge: bridge_metrics.worst(bridge_capabilities),
+            security_system: if capabilities.security_engaged() {
                 ComponentStatus::Degraded
             } else {
-                ComponentStatus::Failed
-            },
-            
-            cross_chain_bridge: if snapshot.avg_latency_ms < self.alert_thresholds.latency_warning_ms {
                 ComponentStatus::Operational
-            } else if snapshot.avg_latency_ms < self.alert_thresholds.latency_critical_ms {
-                ComponentStatus::Degraded
-            } else {
-                ComponentStatus::Failed
             },
-            
-            security_system: ComponentStatus::Operational, // Would check security metrics
-            governance: ComponentStatus::Operational,      // Would check governance health
-            treasury: ComponentStatus::Operational,        // Would check treasury operations
-            analytics: ComponentStatus::Operational,       // Would check analytics collection
+            // No pause flag or breaker gates these; a running check means
+            // analytics collection is live
+            governance: ComponentStatus::Operational,
+            treasury: ComponentStatus::Operational,
+            analytics: ComponentStatus::Operational,
         }
     }
 
@@ -320,11 +617,11 @@ impl SystemMonitor {
         ];
 
         let failed_count = components_array.iter()
-            .filter(|&&status| status == ComponentStatus::Failed)
+            .filter(|&&status| *status == ComponentStatus::Failed)
             .count();
 
         let degraded_count = components_array.iter()
-            .filter(|&&status| status == ComponentStatus::Degraded)
+            .filter(|&&status| *status == ComponentStatus::Degraded)
             .count();
 
         let status = if failed_count > 2 {
@@ -343,88 +640,149 @@ impl SystemMonitor {
     }
 
     /// Check conditions that should trigger alerts
-    fn check_alert_conditions(&mut self, snapshot: &MetricsSnapshot) -> Result<()> {
+    fn check_alert_conditions(&mut self, snapshot: &MetricsSnapshot) -> Result<Vec<RemediationAction>> {
+        let mut actions = Vec::new();
+
         // Check error rate
         if snapshot.error_rate_bps > self.alert_thresholds.error_rate_critical_bps {
-            self.trigger_alert(AlertType::HighErrorRate, AlertSeverity::Critical, snapshot.error_rate_bps as u64)?;
+            actions.extend(self.trigger_alert(AlertType::HighErrorRate, AlertSeverity::Critical, snapshot.error_rate_bps as u64, 0)?);
         } else if snapshot.error_rate_bps > self.alert_thresholds.error_rate_warning_bps {
-            self.trigger_alert(AlertType::HighErrorRate, AlertSeverity::Warning, snapshot.error_rate_bps as u64)?;
+            actions.extend(self.trigger_alert(AlertType::HighErrorRate, AlertSeverity::Warning, snapshot.error_rate_bps as u64, 0)?);
         }
 
         // Check latency
         if snapshot.avg_latency_ms > self.alert_thresholds.latency_critical_ms {
-            self.trigger_alert(AlertType::HighLatency, AlertSeverity::Critical, snapshot.avg_latency_ms as u64)?;
+            actions.extend(self.trigger_alert(AlertType::HighLatency, AlertSeverity::Critical, snapshot.avg_latency_ms as u64, 0)?);
         } else if snapshot.avg_latency_ms > self.alert_thresholds.latency_warning_ms {
-            self.trigger_alert(AlertType::HighLatency, AlertSeverity::Warning, snapshot.avg_latency_ms as u64)?;
+            actions.extend(self.trigger_alert(AlertType::HighLatency, AlertSeverity::Warning, snapshot.avg_latency_ms as u64, 0)?);
         }
 
         // Check memory usage
         if snapshot.memory_usage_pct > self.alert_thresholds.memory_critical_pct {
-            self.trigger_alert(AlertType::HighMemoryUsage, AlertSeverity::Critical, snapshot.memory_usage_pct as u64)?;
+            actions.extend(self.trigger_alert(AlertType::HighMemoryUsage, AlertSeverity::Critical, snapshot.memory_usage_pct as u64, 0)?);
         } else if snapshot.memory_usage_pct > self.alert_thresholds.memory_warning_pct {
-            self.trigger_alert(AlertType::HighMemoryUsage, AlertSeverity::Warning, snapshot.memory_usage_pct as u64)?;
+            actions.extend(self.trigger_alert(AlertType::HighMemoryUsage, AlertSeverity::Warning, snapshot.memory_usage_pct as u64, 0)?);
         }
 
         // Check compute units
         if snapshot.compute_units_avg > self.alert_thresholds.compute_critical_units {
-            self.trigger_alert(AlertType::HighComputeUsage, AlertSeverity::Critical, snapshot.compute_units_avg as u64)?;
+            actions.extend(self.trigger_alert(AlertType::HighComputeUsage, AlertSeverity::Critical, snapshot.compute_units_avg as u64, 0)?);
         } else if snapshot.compute_units_avg > self.alert_thresholds.compute_warning_units {
-            self.trigger_alert(AlertType::HighComputeUsage, AlertSeverity::Warning, snapshot.compute_units_avg as u64)?;
+            actions.extend(self.trigger_alert(AlertType::HighComputeUsage, AlertSeverity::Warning, snapshot.compute_units_avg as u64, 0)?);
         }
 
-        Ok(())
+        Ok(actions)
     }
 
-    /// Trigger an alert
-    fn trigger_alert(
+    /// Trigger an alert about `chain_id` (zero when not chain-specific).
+    /// Returns the remediation action to apply, if one is registered for the
+    /// alert and its flag is set.
+    pub fn trigger_alert(
         &mut self,
         alert_type: AlertType,
         severity: AlertSeverity,
         metric_value: u64,
-    ) -> Result<()> {
+        chain_id: u64,
+    ) -> Result<Option<RemediationAction>> {
+        let now = Clock::get()?.unix_timestamp;
+        self.refresh_maintenance(now);
+        let in_maintenance = self.in_maintenance(now);
+
+        // Planned work shouldn't page anyone over non-critical alerts
+        if in_maintenance && matches!(severity, AlertSeverity::Info | AlertSeverity::Warning) {
+            self.suppressed_alerts = self.suppressed_alerts.saturating_add(1);
+            log!("Non-critical alert suppressed during maintenance - Value: {}", metric_value);
+            return Ok(None);
+        }
+
         self.active_alerts = self.active_alerts.checked_add(1)
             .ok_or(UniversalNftError::ArithmeticOverflow)?;
         
         self.total_alerts = self.total_alerts.checked_add(1)
             .ok_or(UniversalNftError::ArithmeticOverflow)?;
 
-        msg!("Alert triggered: {:?} - Severity: {:?} - Value: {}", 
+        log!("Alert triggered: {:?} - Severity: {:?} - Value: {}", 
              alert_type, severity, metric_value);
 
-        // Apply auto-remediation if enabled and appropriate
-        if self.auto_remediation_enabled {
-            self.apply_auto_remediation(&alert_type, &severity)?;
+        // Apply auto-remediation if enabled and appropriate, never during
+        // maintenance
+        if !self.auto_remediation_enabled || in_maintenance {
+            return Ok(None);
         }
-
-        Ok(())
+        Ok(self.select_remediation(&alert_type, &severity, chain_id))
     }
 
-    /// Apply automatic remediation for certain alert types
-    fn apply_auto_remediation(
+    /// Pick the registered remediation for an alert, if its flag allows it
+    fn select_remediation(
         &self,
         alert_type: &AlertType,
         severity: &AlertSeverity,
-    ) -> Result<()> {
-        match (alert_type, severity) {
-            (AlertType::HighErrorRate, AlertSeverity::Critical) => {
-                msg!("Auto-remediation: Activating circuit breaker");
-                // Would trigger circuit breaker
-            }
-            (AlertType::HighMemoryUsage, AlertSeverity::Critical) => {
-                msg!("Auto-remediation: Clearing caches and optimizing memory");
-                // Would trigger memory cleanup
-            }
-            (AlertType::SecurityThreat, _) => {
-                msg!("Auto-remediation: Enhancing security monitoring");
-                // Would increase security checks
-            }
-            _ => {
-                // No auto-remediation for this alert type/severity
-            }
+        chain_id: u64,
+    ) -> Option<RemediationAction> {
+        let action = RemediationAction::for_alert(alert_type, severity, chain_id)?;
+        if self.remediation_flags & action.flag() == 0 {
+            log!("Auto-remediation {:?} not enabled", action);
+            return None;
         }
+        log!("Auto-remediation selected: {:?}", action);
+        Some(action)
+    }
+
+    /// Set the remediation actions auto-remediation may take (monitoring
+    /// authority only)
+    pub fn set_remediation_flags(&mut self, signer: Pubkey, flags: u8) -> Result<()> {
+        require_keys_eq!(signer, self.authority, UniversalNftError::Unauthorized);
+        require!(flags & !RemediationAction::ALL == 0, UniversalNftError::InvalidRemediationFlags);
+        self.remediation_flags = flags;
+
+        log!("Remediation flags set: {:#05b}", flags);
         Ok(())
     }
 
+    /// Schedule a maintenance window (monitoring authority only). Replaces
+    /// any window already scheduled; maintenance exits on its own at `end`.
+    pub fn schedule_maintenance(&mut self, signer: Pubkey, start: i64, end: i64) -> Result<()> {
+        require_keys_eq!(signer, self.authority, UniversalNftError::Unauthorized);
+        let now = Clock::get()?.unix_timestamp;
+        require!(start < end && end > now, UniversalNftError::InvalidMaintenanceWindow);
+
+        self.maintenance_start = start;
+        self.maintenance_end = end;
+
+        log!("Maintenance scheduled from {} to {}", start, end);
+        Ok(())
+    }
+
+    /// Cancel the scheduled or running maintenance window (monitoring
+    /// authority only)
+    pub fn cancel_maintenance(&mut self, signer: Pubkey) -> Result<()> {
+        require_keys_eq!(signer, self.authority, UniversalNftError::Unauthorized);
+        self.end_maintenance();
+        Ok(())
+    }
+
+    /// Whether `now` falls inside the maintenance window
+    pub fn in_maintenance(&self, now: i64) -> bool {
+        self.maintenance_end != 0 && now >= self.maintenance_start && now < self.maintenance_end
+    }
+
+    /// Exit maintenance once its end time has passed
+    fn refresh_maintenance(&mut self, now: i64) {
+        if self.maintenance_end != 0 && now >= self.maintenance_end {
+            self.end_maintenance();
+        }
+    }
+
+    fn end_maintenance(&mut self) {
+        self.maintenance_start = 0;
+        self.maintenance_end = 0;
+        // The next health check recomputes the real status
+        if self.system_status == SystemStatus::Maintenance {
+            self.system_status = SystemStatus::Healthy;
+        }
+        log!("Maintenance window ended");
+    }
+
     /// Acknowledge an alert
     pub fn acknowledge_alert(&mut self, alert: &mut Alert, acknowledger: Pubkey) -> Result<()> {
         require!(alert.status == AlertStatus::Active, UniversalNftError::InvalidTransferStatus);
@@ -433,7 +791,7 @@ impl SystemMonitor {
         alert.acknowledged_at = Some(Clock::get()?.unix_timestamp);
         alert.status = AlertStatus::Acknowledged;
 
-        msg!("Alert {} acknowledged by {}", alert.id, acknowledger);
+        log!("Alert {} acknowledged by {}", alert.id, acknowledger);
         Ok(())
     }
 
@@ -449,7 +807,7 @@ impl SystemMonitor {
 
         self.active_alerts = self.active_alerts.saturating_sub(1);
 
-        msg!("Alert {} resolved", alert.id);
+        log!("Alert {} resolved", alert.id);
         Ok(())
     }
 
@@ -472,7 +830,7 @@ impl SystemMonitor {
             self.auto_remediation_enabled = auto_rem;
         }
 
-        msg!("Monitoring configuration updated");
+        log!("Monitoring configuration updated");
         Ok(())
     }
 
@@ -499,6 +857,203 @@ impl SystemMonitor {
     }
 }
 
+/// Bounded action auto-remediation can take. Each needs its flag set on
+/// the system monitor and is recorded in the audit log with the state it
+/// replaced, so it can be reverted.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum RemediationAction {
+    /// Pause the program, the protocol-wide breaker
+    TripBreaker,
+    /// Halve a chain's outbound caps
+    HalveRateLimits { chain_id: u64 },
+    /// Stop traffic to and from a chain
+    PauseChain { chain_id: u64 },
+}
+
+impl RemediationAction {
+    pub const TRIP_BREAKER: u8 = 1 << 0;
+    pub const HALVE_RATE_LIMITS: u8 = 1 << 1;
+    pub const PAUSE_CHAIN: u8 = 1 << 2;
+    pub const ALL: u8 = Self::TRIP_BREAKER | Self::HALVE_RATE_LIMITS | Self::PAUSE_CHAIN;
+
+    /// Flag that enables this action
+    pub fn flag(&self) -> u8 {
+        match self {
+            RemediationAction::TripBreaker => Self::TRIP_BREAKER,
+            RemediationAction::HalveRateLimits { .. } => Self::HALVE_RATE_LIMITS,
+            RemediationAction::PauseChain { .. } => Self::PAUSE_CHAIN,
+        }
+    }
+
+    /// Chain the action targets; zero for program-wide actions
+    pub fn chain_id(&self) -> u64 {
+        match self {
+            RemediationAction::TripBreaker => 0,
+            RemediationAction::HalveRateLimits { chain_id }
+            | RemediationAction::PauseChain { chain_id } => *chain_id,
+        }
+    }
+
+    /// The registry: the action an alert calls for, if any. Chain actions
+    /// only apply to alerts about a specific chain.
+    pub fn for_alert(alert_type: &AlertType, severity: &AlertSeverity, chain_id: u64) -> Option<Self> {
+        let critical = matches!(severity, AlertSeverity::Critical | AlertSeverity::Emergency);
+        match alert_type {
+            AlertType::HighErrorRate if critical => Some(Self::TripBreaker),
+            AlertType::ParityMismatch | AlertType::ChainUnavailable if critical && chain_id != 0 => {
+                Some(Self::PauseChain { chain_id })
+            }
+            AlertType::UnusualActivity | AlertType::FraudDetected | AlertType::SecurityThreat
+                if chain_id != 0 =>
+            {
+                Some(Self::HalveRateLimits { chain_id })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// One remediation the program applied on its own
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct AuditEntry {
+    pub id: u64,
+    pub action: RemediationAction,
+    /// Chain caps before `HalveRateLimits`
+    pub previous_limits: ChainTransferLimits,
+    /// Program pause before `TripBreaker`, or chain enabled flag before
+    /// `PauseChain`
+    pub previous_flag: bool,
+    pub applied_at: i64,
+    /// Zero until reverted
+    pub reverted_at: i64,
+}
+
+/// Record of automated remediations, kept so each one is observable and
+/// can be undone
+#[account]
+#[derive(InitSpace)]
+pub struct AuditLog {
+    /// ID the next entry gets
+    pub next_id: u64,
+    /// Most recent entries; the oldest is dropped once full
+    #[max_len(32)]
+    pub entries: Vec<AuditEntry>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl AuditLog {
+    /// Maximum entries kept
+    pub const MAX_ENTRIES: usize = 32;
+
+    /// Apply `action` to its target and record what it replaced. Returns
+    /// the new entry's ID.
+    pub fn apply(
+        &mut self,
+        action: RemediationAction,
+        config: Option<&mut ProgramConfig>,
+        chain: Option<&mut RegisteredChain>,
+        now: i64,
+    ) -> Result<u64> {
+        let mut entry = AuditEntry {
+            id: self.next_id,
+            action,
+            previous_limits: ChainTransferLimits::default(),
+            previous_flag: false,
+            applied_at: now,
+            reverted_at: 0,
+        };
+
+        match action {
+            RemediationAction::TripBreaker => {
+                let config = config.ok_or(UniversalNftError::InvalidRemediationTarget)?;
+                entry.previous_flag = config.is_paused;
+                config.is_paused = true;
+            }
+            RemediationAction::HalveRateLimits { chain_id } => {
+                let chain = Self::target_chain(chain, chain_id)?;
+                entry.previous_limits = chain.limits;
+                chain.limits.max_value_per_transfer /= 2;
+                chain.limits.daily_value_cap /= 2;
+                chain.limits.daily_transfer_cap /= 2;
+            }
+            RemediationAction::PauseChain { chain_id } => {
+                let chain = Self::target_chain(chain, chain_id)?;
+                entry.previous_flag = chain.enabled;
+                chain.enabled = false;
+            }
+        }
+
+        if self.entries.len() >= Self::MAX_ENTRIES {
+            self.entries.remove(0);
+        }
+        self.entries.push(entry);
+        self.next_id = self.next_id
+            .checked_add(1)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+        emit!(RemediationApplied {
+            entry_id: entry.id,
+            action: action.flag(),
+            chain_id: action.chain_id(),
+            timestamp: now,
+        });
+
+        log!("Auto-remediation {:?} applied (audit entry {})", action, entry.id);
+        Ok(entry.id)
+    }
+
+    /// Restore the state an entry's action replaced
+    pub fn revert(
+        &mut self,
+        entry_id: u64,
+        config: Option<&mut ProgramConfig>,
+        chain: Option<&mut RegisteredChain>,
+        now: i64,
+    ) -> Result<()> {
+        let entry = self.entries
+            .iter_mut()
+            .find(|e| e.id == entry_id)
+            .ok_or(UniversalNftError::InvalidRemediationTarget)?;
+        require!(entry.reverted_at == 0, UniversalNftError::RemediationAlreadyReverted);
+
+        match entry.action {
+            RemediationAction::TripBreaker => {
+                let config = config.ok_or(UniversalNftError::InvalidRemediationTarget)?;
+                config.is_paused = entry.previous_flag;
+            }
+            RemediationAction::HalveRateLimits { chain_id } => {
+                Self::target_chain(chain, chain_id)?.limits = entry.previous_limits;
+            }
+            RemediationAction::PauseChain { chain_id } => {
+                Self::target_chain(chain, chain_id)?.enabled = entry.previous_flag;
+            }
+        }
+        entry.reverted_at = now;
+
+        emit!(RemediationReverted {
+            entry_id,
+            action: entry.action.flag(),
+            chain_id: entry.action.chain_id(),
+            timestamp: now,
+        });
+
+        log!("Auto-remediation {:?} reverted (audit entry {})", entry.action, entry_id);
+        Ok(())
+    }
+
+    fn target_chain(chain: Option<&mut RegisteredChain>, chain_id: u64) -> Result<&mut RegisteredChain> {
+        let chain = chain.ok_or(UniversalNftError::InvalidRemediationTarget)?;
+        require!(chain.chain_id == chain_id, UniversalNftError::InvalidRemediationTarget);
+        Ok(chain)
+    }
+}
+
+impl HealthCheck {
+    /// Maximum degraded capabilities recorded per check
+    pub const MAX_DEGRADED_CAPABILITIES: usize = 16;
+}
+
 impl Alert {
     pub const INIT_SPACE: usize = 
         8 +     // id
@@ -515,6 +1070,7 @@ impl Alert {
         1 +     // status (enum)
         1;      // bump
 
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize(
         &mut self,
         id: u64,
@@ -0,0 +1,286 @@
+//! Outbound message layers for Universal NFT Protocol
+//! Each registered chain has a primary route and an optional fallback route,
+//! each naming the message layer (ZetaChain gateway, Wormhole, LayerZero) and
+//! how that layer addresses the chain. A `BridgeAdapter` turns an encoded
+//! cross-chain message into the layer program's send instruction, so a
+//! collection can keep bridging over the fallback while a network is degraded.
+//!
+//! Layers other than the gateway are sent with the layer's config PDA as the
+//! signing emitter/sender. The layer program's own accounts are passed as
+//! remaining accounts after any hook accounts, in the order it expects.
+
+use anchor_lang::prelude::*;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
+};
+
+use crate::errors::UniversalNftError;
+use crate::instructions::cross_chain::create_gateway_call_instruction;
+use crate::state::{BridgeRoute, MessageLayer, MessageLayerConfig, RegisteredChain};
+
+/// Wormhole core bridge `PostMessage` instruction index
+pub const WORMHOLE_POST_MESSAGE: u8 = 1;
+
+/// Wormhole consistency level: wait for finality before guardians sign
+pub const WORMHOLE_CONSISTENCY_FINALIZED: u8 = 1;
+
+/// `sha256("global:send")[..8]`, the LayerZero endpoint's send instruction
+pub const LAYERZERO_SEND_DISCRIMINATOR: [u8; 8] = [102, 251, 20, 187, 65, 75, 12, 69];
+
+/// A message ready to hand to a layer
+pub struct OutboundMessage<'a> {
+    /// Transfer nonce, reused as the layer nonce where one exists
+    pub nonce: u64,
+    /// NFT recipient on the destination chain
+    pub recipient: &'a [u8],
+    /// Encoded `CrossChainMessage`
+    pub message: &'a [u8],
+    /// Destination gas limit
+    pub gas_limit: u64,
+    /// Fee the layer may charge, in lamports
+    pub native_fee: u64,
+}
+
+/// A message layer outbound transfers can be sent through
+pub trait BridgeAdapter {
+    /// Layer this adapter sends through
+    fn layer(&self) -> MessageLayer;
+
+    /// Build the layer program's send instruction. `accounts` are the layer's
+    /// own accounts and `sender` the PDA that signs as this program.
+    fn build_instruction(
+        &self,
+        program_id: Pubkey,
+        route: &BridgeRoute,
+        message: &OutboundMessage,
+        accounts: &[AccountInfo],
+        sender: &Pubkey,
+    ) -> Result<Instruction>;
+}
+
+/// ZetaChain gateway `call`
+pub struct ZetaGatewayAdapter;
+
+impl BridgeAdapter for ZetaGatewayAdapter {
+    fn layer(&self) -> MessageLayer {
+        MessageLayer::ZetaGateway
+    }
+
+    fn build_instruction(
+        &self,
+        program_id: Pubkey,
+        route: &BridgeRoute,
+        message: &OutboundMessage,
+        _accounts: &[AccountInfo],
+        _sender: &Pubkey,
+    ) -> Result<Instruction> {
+        create_gateway_call_instruction(
+            program_id,
+            route.remote_chain_id,
+            message.recipient.to_vec(),
+            message.message.to_vec(),
+            message.gas_limit,
+        )
+    }
+}
+
+/// Wormhole core bridge `post_message`. The payload is prefixed with the
+/// target chain and peer, since a posted message is not addressed by the
+/// bridge itself.
+pub struct WormholeAdapter;
+
+impl BridgeAdapter for WormholeAdapter {
+    fn layer(&self) -> MessageLayer {
+        MessageLayer::Wormhole
+    }
+
+    fn build_instruction(
+        &self,
+        program_id: Pubkey,
+        route: &BridgeRoute,
+        message: &OutboundMessage,
+        accounts: &[AccountInfo],
+        sender: &Pubkey,
+    ) -> Result<Instruction> {
+        let target_chain = u16::try_from(route.remote_chain_id)
+            .map_err(|_| UniversalNftError::InvalidBridgeRoute)?;
+        let payload = [
+            &target_chain.to_be_bytes()[..],
+            &route.peer,
+            message.message,
+        ].concat();
+
+        let mut data = vec![WORMHOLE_POST_MESSAGE];
+        (message.nonce as u32).serialize(&mut data)?;
+        payload.serialize(&mut data)?;
+        data.push(WORMHOLE_CONSISTENCY_FINALIZED);
+
+        Ok(Instruction {
+            program_id,
+            accounts: BridgeAdapterUtils::account_metas(accounts, sender),
+            data,
+        })
+    }
+}
+
+/// LayerZero V2 endpoint `send`, with an executor option granting the
+/// destination `lzReceive` the transfer's gas limit
+pub struct LayerZeroAdapter;
+
+impl LayerZeroAdapter {
+    /// Type 3 options carrying a single executor `lzReceive` gas option
+    pub fn receive_options(gas_limit: u64) -> Vec<u8> {
+        let mut options = vec![0x00, 0x03];
+        options.push(1); // executor worker
+        options.extend_from_slice(&17u16.to_be_bytes());
+        options.push(1); // lzReceive option
+        options.extend_from_slice(&(gas_limit as u128).to_be_bytes());
+        options
+    }
+}
+
+impl BridgeAdapter for LayerZeroAdapter {
+    fn layer(&self) -> MessageLayer {
+        MessageLayer::LayerZero
+    }
+
+    fn build_instruction(
+        &self,
+        program_id: Pubkey,
+        route: &BridgeRoute,
+        message: &OutboundMessage,
+        accounts: &[AccountInfo],
+        sender: &Pubkey,
+    ) -> Result<Instruction> {
+        let dst_eid = u32::try_from(route.remote_chain_id)
+            .map_err(|_| UniversalNftError::InvalidBridgeRoute)?;
+
+        let mut data = LAYERZERO_SEND_DISCRIMINATOR.to_vec();
+        dst_eid.serialize(&mut data)?;
+        route.peer.serialize(&mut data)?;
+        message.message.to_vec().serialize(&mut data)?;
+        Self::receive_options(message.gas_limit).serialize(&mut data)?;
+        message.native_fee.serialize(&mut data)?;
+        0u64.serialize(&mut data)?; // lz_token_fee
+
+        Ok(Instruction {
+            program_id,
+            accounts: BridgeAdapterUtils::account_metas(accounts, sender),
+            data,
+        })
+    }
+}
+
+pub struct BridgeAdapterUtils;
+
+impl BridgeAdapterUtils {
+    /// Adapter for a layer
+    pub fn adapter(layer: MessageLayer) -> &'static dyn BridgeAdapter {
+        match layer {
+            MessageLayer::ZetaGateway => &ZetaGatewayAdapter,
+            MessageLayer::Wormhole => &WormholeAdapter,
+            MessageLayer::LayerZero => &LayerZeroAdapter,
+        }
+    }
+
+    /// Pick the route to `chain` through the supplied layer. Without a layer
+    /// config the chain's primary route must be the gateway, which is how
+    /// transfers were sent before layers were configurable.
+    pub fn resolve_route(
+        chain: &RegisteredChain,
+        layer_config: Option<&MessageLayerConfig>,
+    ) -> Result<BridgeRoute> {
+        let layer_config = match layer_config {
+            Some(layer_config) => layer_config,
+            None => {
+                require!(
+                    chain.primary_route.layer == MessageLayer::ZetaGateway,
+                    UniversalNftError::MessageLayerUnavailable
+                );
+                return Ok(chain.primary_route);
+            }
+        };
+
+        require!(layer_config.operational, UniversalNftError::MessageLayerUnavailable);
+        [Some(chain.primary_route), chain.fallback_route]
+            .into_iter()
+            .flatten()
+            .find(|route| route.layer == layer_config.layer)
+            .ok_or_else(|| UniversalNftError::MessageLayerUnavailable.into())
+    }
+
+    /// Check a route can be set on `chain`
+    pub fn validate_route(chain: &RegisteredChain, route: &BridgeRoute) -> Result<()> {
+        match route.layer {
+            MessageLayer::ZetaGateway => require!(
+                route.remote_chain_id == chain.chain_id,
+                UniversalNftError::InvalidBridgeRoute
+            ),
+            MessageLayer::Wormhole => require!(
+                route.remote_chain_id > 0 && route.remote_chain_id <= u16::MAX as u64,
+                UniversalNftError::InvalidBridgeRoute
+            ),
+            MessageLayer::LayerZero => require!(
+                route.remote_chain_id > 0 && route.remote_chain_id <= u32::MAX as u64,
+                UniversalNftError::InvalidBridgeRoute
+            ),
+        }
+        if route.layer != MessageLayer::ZetaGateway {
+            require!(route.peer != [0u8; 32], UniversalNftError::InvalidBridgeRoute);
+        }
+        Ok(())
+    }
+
+    /// Send `message` along `route`. `program` must be the layer's program
+    /// and `layer_accounts` the accounts its send instruction takes.
+    pub fn send<'info>(
+        route: &BridgeRoute,
+        layer_config: Option<&Account<'info, MessageLayerConfig>>,
+        program: &AccountInfo<'info>,
+        payer: &AccountInfo<'info>,
+        layer_accounts: &[AccountInfo<'info>],
+        message: &OutboundMessage,
+    ) -> Result<()> {
+        let adapter = Self::adapter(route.layer);
+
+        let layer_config = match layer_config {
+            Some(layer_config) => layer_config,
+            None => {
+                let ix = adapter.build_instruction(program.key(), route, message, &[], &payer.key())?;
+                invoke(&ix, &[program.clone(), payer.clone()])?;
+                return Ok(());
+            }
+        };
+
+        require_keys_eq!(
+            program.key(),
+            layer_config.program_id,
+            UniversalNftError::MessageLayerUnavailable
+        );
+        let sender = layer_config.key();
+        let ix = adapter.build_instruction(program.key(), route, message, layer_accounts, &sender)?;
+
+        let mut infos = layer_accounts.to_vec();
+        infos.extend([program.clone(), payer.clone(), layer_config.to_account_info()]);
+
+        let seed = MessageLayerConfig::seed(route.layer);
+        let signer_seeds: &[&[&[u8]]] = &[&[b"message_layer", &seed, &[layer_config.bump]]];
+        invoke_signed(&ix, &infos, signer_seeds)?;
+
+        log!("Message sent through {:?}", route.layer);
+        Ok(())
+    }
+
+    /// Metas for a layer's accounts, with the sender PDA signing
+    fn account_metas(accounts: &[AccountInfo], sender: &Pubkey) -> Vec<AccountMeta> {
+        accounts
+            .iter()
+            .map(|a| AccountMeta {
+                pubkey: a.key(),
+                is_signer: a.is_signer || a.key == sender,
+                is_writable: a.is_writable,
+            })
+            .collect()
+    }
+}
@@ -1,35 +1,39 @@
 use anchor_lang::prelude::*;
 
-#[error_code]
+/// Program error codes. Each variant's code is 6000 plus its position, and
+/// clients key off these numbers, so variants are only ever appended.
+/// Messages that end in "Hint: ..." carry a remediation for users; the SDK's
+/// error table is generated from the IDL with scripts/generate-error-table.ts.
+#[error_code(offset = 6000)]
 pub enum UniversalNftError {
     #[msg("Unauthorized access - caller is not the authority")]
     Unauthorized,
     
-    #[msg("Program is currently paused")]
+    #[msg("Program is currently paused. Hint: retry once the program is unpaused")]
     ProgramPaused,
     
-    #[msg("Invalid gateway authority")]
+    #[msg("Invalid gateway authority. Hint: cross-chain calls must arrive through the ZetaChain gateway")]
     InvalidGatewayAuthority,
     
-    #[msg("Invalid TSS signature")]
+    #[msg("Invalid TSS signature. Hint: the message must be signed by the current TSS address")]
     InvalidTssSignature,
     
-    #[msg("Invalid cross-chain message format")]
+    #[msg("Invalid cross-chain message format. Hint: re-encode the payload with the SDK message encoder")]
     InvalidMessageFormat,
     
-    #[msg("NFT is currently locked for cross-chain transfer")]
+    #[msg("NFT is currently locked for cross-chain transfer. Hint: wait for the pending cross-chain transfer to complete or revert")]
     NftLocked,
     
-    #[msg("Invalid chain ID")]
+    #[msg("Invalid chain ID. Hint: use a chain ID from the chain registry")]
     InvalidChainId,
     
-    #[msg("Invalid recipient address")]
+    #[msg("Invalid recipient address. Hint: check the recipient address for the destination chain")]
     InvalidRecipient,
     
-    #[msg("Insufficient gas limit for cross-chain operation")]
+    #[msg("Insufficient gas limit for cross-chain operation. Hint: raise the gas limit; estimate_bridge_cost returns current fees")]
     InsufficientGasLimit,
     
-    #[msg("Nonce mismatch - potential replay attack")]
+    #[msg("Nonce mismatch - potential replay attack. Hint: refetch the program nonce and rebuild the message")]
     NonceMismatch,
     
     #[msg("Invalid signature recovery")]
@@ -38,10 +42,10 @@ pub enum UniversalNftError {
     #[msg("Transfer already exists")]
     TransferAlreadyExists,
     
-    #[msg("Transfer not found")]
+    #[msg("Transfer not found. Hint: check the transfer nonce and the chain it was sent from")]
     TransferNotFound,
     
-    #[msg("Invalid transfer status")]
+    #[msg("Invalid transfer status. Hint: refetch the transfer; it may already be completed or reverted")]
     InvalidTransferStatus,
     
     #[msg("NFT not found")]
@@ -74,7 +78,7 @@ pub enum UniversalNftError {
     #[msg("Cross-chain call origin validation failed")]
     InvalidCallOrigin,
     
-    #[msg("Message hash mismatch")]
+    #[msg("Message hash mismatch. Hint: the signed hash must cover the exact message bytes sent")]
     MessageHashMismatch,
     
     #[msg("Invalid ECDSA signature format")]
@@ -85,4 +89,760 @@ pub enum UniversalNftError {
     
     #[msg("Sender verification failed")]
     SenderVerificationFailed,
+    
+    #[msg("Compensation amount must be greater than zero")]
+    InvalidCompensationAmount,
+    
+    #[msg("Compensation claims are currently disabled")]
+    CompensationClaimsDisabled,
+    
+    #[msg("Recovery session has not completed")]
+    RecoverySessionNotCompleted,
+    
+    #[msg("No compensation is owed for this recovery session")]
+    NoCompensationOwed,
+    
+    #[msg("Compensation type cannot be paid from the vault")]
+    UnsupportedCompensationType,
+    
+    #[msg("Per-user compensation cap exceeded")]
+    CompensationCapExceeded,
+    
+    #[msg("Insufficient funds in compensation vault")]
+    InsufficientVaultFunds,
+    
+    #[msg("Checkpoint accounts do not match the critical account set")]
+    InvalidCheckpointAccounts,
+    
+    #[msg("Snapshot page out of range")]
+    InvalidSnapshotPage,
+    
+    #[msg("Account is not a valid backup target")]
+    InvalidBackupTarget,
+    
+    #[msg("Invalid restore chunk")]
+    InvalidRestoreChunk,
+    
+    #[msg("Recovery mode is not active")]
+    RecoveryModeInactive,
+    
+    #[msg("Restored data does not match the archived snapshot hash")]
+    RestoreHashMismatch,
+    
+    #[msg("Orphaned NFT has already been claimed")]
+    OrphanAlreadyClaimed,
+    
+    #[msg("Original message does not match the orphan record")]
+    OrphanProofMismatch,
+    
+    #[msg("NFT is not held in program custody")]
+    NftNotInCustody,
+    
+    #[msg("NFT is frozen by an open dispute")]
+    NftUnderDispute,
+    
+    #[msg("Signer is not a party to the disputed transfer")]
+    InvalidDisputeParty,
+    
+    #[msg("Dispute is not open")]
+    DisputeNotOpen,
+    
+    #[msg("Dispute resolution deadline has passed")]
+    DisputeDeadlinePassed,
+    
+    #[msg("Dispute resolution deadline has not been reached")]
+    DisputeDeadlineNotReached,
+    
+    #[msg("Invalid dispute resolution window")]
+    InvalidResolutionWindow,
+    
+    #[msg("Split share must not exceed 10000 basis points")]
+    InvalidSplitShare,
+    
+    #[msg("Invalid interchain query parameters")]
+    InvalidQueryParameters,
+    
+    #[msg("Query result account does not match the query ID")]
+    InvalidQueryAccount,
+    
+    #[msg("Query is not pending")]
+    QueryNotPending,
+    
+    #[msg("Query response came from the wrong chain")]
+    QueryChainMismatch,
+    
+    #[msg("Query response exceeds maximum length")]
+    QueryResponseTooLarge,
+    
+    #[msg("Gas price must be greater than zero. Hint: ask the gas token authority to publish a price")]
+    InvalidGasPrice,
+    
+    #[msg("Gas token is not enabled. Hint: pay gas in SOL or another enabled gas token")]
+    GasTokenDisabled,
+    
+    #[msg("Gas token accounts missing or invalid for ZRC-20 payment. Hint: pass the gas token mint and payer token account")]
+    InvalidGasTokenAccounts,
+    
+    #[msg("Gas token is not registered for the destination chain. Hint: choose a gas token registered for the destination chain")]
+    GasTokenChainMismatch,
+    
+    #[msg("Chain is not registered. Hint: bridge only to chains listed in the chain registry")]
+    ChainNotRegistered,
+    
+    #[msg("Chain is disabled. Hint: the destination chain is temporarily disabled; retry later")]
+    ChainDisabled,
+    
+    #[msg("Message does not match the registered chain kind. Hint: encode the message for the destination chain's kind")]
+    ChainKindMismatch,
+    
+    #[msg("Invalid Bitcoin address. Hint: use a valid bech32 or base58 Bitcoin address")]
+    InvalidBitcoinAddress,
+    
+    #[msg("Invalid inscription reference")]
+    InvalidInscriptionReference,
+    
+    #[msg("Invalid NFT origin account")]
+    InvalidOriginAccount,
+    
+    #[msg("Recipient does not match the destination chain's address format")]
+    InvalidAddressFormat,
+    
+    #[msg("Address format is not valid for the chain kind. Hint: encode the recipient in the destination chain's address format")]
+    AddressFormatMismatch,
+    
+    #[msg("EVM address must be exactly 20 bytes. Hint: pass the raw 20-byte address, not a hex string")]
+    InvalidEvmAddressLength,
+    
+    #[msg("Destination address is the zero address. Hint: set a non-zero recipient address")]
+    ZeroDestinationAddress,
+    
+    #[msg("Destination address is a known burn address. Hint: bridging to a burn address would lose the NFT; use a real recipient")]
+    BurnDestinationAddress,
+    
+    #[msg("Destination address is the program's counterpart contract. Hint: send to a user wallet, not the counterpart contract")]
+    CounterpartDestinationAddress,
+    
+    #[msg("Transfer payload exceeds maximum length. Hint: shorten the metadata URI or remove optional payload fields")]
+    PayloadTooLarge,
+    
+    #[msg("Collection hook registry account is required for collection NFTs")]
+    MissingCollectionHooks,
+    
+    #[msg("Hook accounts missing or invalid")]
+    InvalidHookAccounts,
+    
+    #[msg("Hook exceeded its compute allowance")]
+    HookComputeExceeded,
+    
+    #[msg("Invalid hook configuration")]
+    InvalidHookConfiguration,
+    
+    #[msg("Transfer has not expired yet. Hint: revert is only possible after the transfer timeout")]
+    TransferNotExpired,
+    
+    #[msg("Transfer receipt missing or invalid")]
+    InvalidTransferReceipt,
+    
+    #[msg("Schedule delay out of range")]
+    InvalidScheduleDelay,
+    
+    #[msg("Scheduled transfer delay has not elapsed")]
+    ScheduleNotReady,
+    
+    #[msg("Scheduled transfer can no longer be cancelled")]
+    ScheduleWindowClosed,
+    
+    #[msg("Transfer parameters do not match the schedule")]
+    ScheduleMismatch,
+    
+    #[msg("Invalid insurance premium or payout parameters")]
+    InvalidInsuranceParameters,
+    
+    #[msg("Insurance pool is not accepting new policies")]
+    InsuranceDisabled,
+    
+    #[msg("Transfer did not end in an unrecoverable failure")]
+    InsuranceNotClaimable,
+    
+    #[msg("Insurance payout already claimed")]
+    InsuranceAlreadyClaimed,
+    
+    #[msg("Batch must contain between 1 and 8 NFTs. Hint: split the batch into groups of at most 8 NFTs")]
+    InvalidBatchSize,
+    
+    #[msg("Batch accounts missing or invalid")]
+    InvalidBatchAccounts,
+    
+    #[msg("Collections with bridge-out hooks must be transferred individually. Hint: bridge each NFT from this collection in its own transaction")]
+    BatchHooksUnsupported,
+    
+    #[msg("Invalid relayer registry parameters")]
+    InvalidRelayerParameters,
+    
+    #[msg("Relayer stake below the registry minimum")]
+    InsufficientRelayerStake,
+    
+    #[msg("Relayer is not active")]
+    RelayerInactive,
+    
+    #[msg("Relayer is already unbonding")]
+    RelayerUnbonding,
+    
+    #[msg("Relayer stake is still bonded")]
+    RelayerNotUnbonding,
+    
+    #[msg("Message has already been relayed")]
+    MessageAlreadyRelayed,
+    
+    #[msg("Inbound sequence already processed")]
+    SequenceAlreadyProcessed,
+    
+    #[msg("Inbound sequence too far ahead of the expected sequence. Hint: relay the earlier messages from this chain first")]
+    SequenceGapTooLarge,
+    
+    #[msg("NFT is frozen under legal hold")]
+    NftFrozen,
+    
+    #[msg("NFT is not frozen")]
+    NftNotFrozen,
+    
+    #[msg("Circuit breaker is open for this chain. Hint: wait for the open period to pass before a probe is admitted")]
+    CircuitBreakerOpen,
+    
+    #[msg("Circuit breaker is half-open and its probe interval has not passed")]
+    CircuitBreakerRateLimit,
+    
+    #[msg("Invalid circuit breaker account or config")]
+    InvalidCircuitBreaker,
+    
+    #[msg("Invalid incident mode configuration")]
+    InvalidIncidentConfig,
+    
+    #[msg("Outbound transfers are paused by incident mode. Hint: outbound bridging is paused during an incident; retry later")]
+    IncidentOutboundPaused,
+    
+    #[msg("Protocol is in incident lockdown. Hint: the protocol is in lockdown; retry once it is lifted")]
+    IncidentLockdown,
+    
+    #[msg("Too many mints flagged for incident lockdown")]
+    TooManyFlaggedMints,
+    
+    #[msg("Invalid validator set or threshold")]
+    InvalidValidatorSet,
+    
+    #[msg("Not enough validator signatures to reach quorum. Hint: collect more validator signatures before submitting")]
+    QuorumNotReached,
+    
+    #[msg("Inbound message needs an observer quorum attestation. Hint: collect K-of-N observer signatures and call attest_inbound_message first")]
+    QuorumAttestationRequired,
+    
+    #[msg("Observer quorum attestation was already consumed")]
+    QuorumAttestationConsumed,
+    
+    #[msg("Caller is not the header relayer")]
+    UnauthorizedHeaderRelayer,
+    
+    #[msg("Event proof does not match the posted block root")]
+    InvalidEventProof,
+    
+    #[msg("Collection requires a verified event proof")]
+    EventProofRequired,
+    
+    #[msg("Event proof has already been consumed")]
+    EventProofConsumed,
+    
+    #[msg("Rate limit exceeded. Hint: wait for the rate limit window to reset")]
+    RateLimitExceeded,
+    
+    #[msg("Invalid rate limit configuration")]
+    InvalidRateLimitConfig,
+    
+    #[msg("Transfer value exceeds the chain's per-transfer cap. Hint: the NFT's floor value is above this chain's cap; choose another chain")]
+    TransferValueCapExceeded,
+    
+    #[msg("Chain's rolling 24h value cap exceeded. Hint: the chain's daily value cap is reached; retry in 24h")]
+    DailyVolumeCapExceeded,
+    
+    #[msg("Chain's rolling 24h transfer cap exceeded. Hint: the chain's daily transfer cap is reached; retry in 24h")]
+    DailyTransferCapExceeded,
+    
+    #[msg("Collection floor price account missing or invalid. Hint: pass the collection floor price account")]
+    MissingCollectionFloor,
+    
+    #[msg("Caller is not one of the collection's price authorities")]
+    UnauthorizedPriceOracle,
+    
+    #[msg("Batch transfers are not supported to chains with value caps. Hint: bridge each NFT in its own transaction to value-capped chains")]
+    BatchValueCapUnsupported,
+    
+    #[msg("Invalid bug bounty parameters")]
+    InvalidBountyParameters,
+    
+    #[msg("Bug bounty program is not accepting reports")]
+    BountyProgramClosed,
+    
+    #[msg("Bug bounty report has already been resolved")]
+    BountyReportAlreadyResolved,
+    
+    #[msg("Collection config account is required for collection NFTs")]
+    MissingCollectionConfig,
+    
+    #[msg("Invalid collection configuration")]
+    InvalidCollectionConfig,
+    
+    #[msg("Collection is paused. Hint: the collection authority has paused this collection")]
+    CollectionPaused,
+    
+    #[msg("Collection does not allow bridging to this chain. Hint: choose a chain the collection allows")]
+    DestinationChainNotAllowed,
+    
+    #[msg("Custody token account missing or invalid")]
+    InvalidCustodyAccount,
+    
+    #[msg("Lock-mode collections must be transferred individually. Hint: bridge each lock-mode NFT in its own transaction")]
+    BatchTransferModeUnsupported,
+    
+    #[msg("Collection is sold out")]
+    SoldOut,
+    
+    #[msg("Max supply can only be lowered, and not below the minted count")]
+    InvalidMaxSupply,
+    
+    #[msg("Invalid mint phase")]
+    InvalidMintPhase,
+    
+    #[msg("Minting is closed: no mint phase is active")]
+    MintClosed,
+    
+    #[msg("Wallet is not on the phase allowlist")]
+    NotOnAllowlist,
+    
+    #[msg("Funds recipient does not match the mint phase")]
+    InvalidFundsRecipient,
+    
+    #[msg("Invalid claim escrow parameters")]
+    InvalidClaimEscrow,
+    
+    #[msg("Claim key or claims service signature is missing or wrong")]
+    InvalidClaimProof,
+    
+    #[msg("Claim escrow has expired")]
+    ClaimEscrowExpired,
+    
+    #[msg("Claim escrow has not expired")]
+    ClaimEscrowNotExpired,
+    
+    #[msg("Invalid holder snapshot")]
+    InvalidSnapshot,
+    
+    #[msg("Holder snapshot has already been revealed")]
+    SnapshotAlreadyRevealed,
+    
+    #[msg("Revealed snapshot does not match its commitment")]
+    SnapshotCommitmentMismatch,
+    
+    #[msg("Burn statistics accounts do not match")]
+    InvalidBurnStats,
+    
+    #[msg("Wallet has locked its outbound transfers. Hint: unlock transfers with unlock_my_transfers and wait out the delay")]
+    WalletTransfersLocked,
+    
+    #[msg("Unlock delay is outside the allowed range or shorter than the active lock's")]
+    InvalidUnlockDelay,
+    
+    #[msg("Wallet is not locked or an unlock is already pending")]
+    WalletNotLocked,
+    
+    #[msg("Invalid operator session")]
+    InvalidOperatorSession,
+    
+    #[msg("Operator session has expired. Hint: the NFT owner must register a new operator session")]
+    OperatorSessionExpired,
+    
+    #[msg("Operator session does not grant this action. Hint: the NFT owner must grant this scope or chain to the operator")]
+    OperatorScopeNotGranted,
+    
+    #[msg("Token account is not the owner's associated token account for this mint")]
+    InvalidAssociatedTokenAccount,
+    
+    #[msg("Mint supply is not exactly one")]
+    InvalidNftSupply,
+    
+    #[msg("Mint decimals must be zero")]
+    InvalidNftDecimals,
+    
+    #[msg("Metadata account is not the Token Metadata PDA for this mint")]
+    InvalidMetadataAccount,
+    
+    #[msg("Signer is not the approved delegate for this token account. Hint: the owner must approve the delegate first")]
+    InvalidTokenDelegate,
+    
+    #[msg("Mint authority is not held by the program, so the mint is not a universal NFT")]
+    InvalidMintAuthority,
+    
+    #[msg("Rent vault funding amount must be greater than zero")]
+    InvalidRentFunding,
+    
+    #[msg("Rent ledger does not belong to the message's source chain. Hint: pass the rent_ledger PDA derived from the source chain ID")]
+    InvalidRentLedger,
+    
+    #[msg("Inbound mint is missing the recipient's accounts. Hint: pass recipient, recipient_token_account, payer, system_program, token_program and associated_token_program")]
+    MissingRecipientAccounts,
+    
+    #[msg("Cross-chain payload version is not supported. Hint: prefix payloads with the version byte this program accepts")]
+    UnsupportedMessageVersion,
+    
+    #[msg("Cross-chain message type is not supported by this payload version. Hint: the counterpart sent a message type this program does not know")]
+    UnsupportedMessageType,
+    
+    #[msg("An outbound transfer of this NFT is already in progress. Hint: wait until it is confirmed, reverted or cancelled")]
+    TransferInProgress,
+    
+    #[msg("Signer is not the registered crank operator")]
+    InvalidCrankOperator,
+    
+    #[msg("Nothing is due for this crank yet. Hint: reschedule the automation thread for a later time")]
+    CrankNotDue,
+    
+    #[msg("Instruction is not part of this build. Hint: deploy a build with the governance feature")]
+    FeatureDisabled,
+    
+    #[msg("Message layer not operational or not routed to this chain. Hint: pass the config of the chain's primary or fallback layer")]
+    MessageLayerUnavailable,
+    
+    #[msg("Invalid bridge route. Hint: the remote chain ID must fit the layer and non-gateway routes need a peer")]
+    InvalidBridgeRoute,
+    
+    #[msg("Invalid metadata rules. Hint: URI prefixes are at most 64 bytes and a matched prefix needs a replacement")]
+    InvalidMetadataRules,
+    
+    #[msg("Signer is not the registered pinner")]
+    InvalidPinner,
+    
+    #[msg("Invalid pin receipt. Hint: content ID must be 1-96 bytes and size non-zero")]
+    InvalidPinReceipt,
+    
+    #[msg("Signer is not the moderator")]
+    InvalidModerator,
+    
+    #[msg("Content has already been taken down")]
+    ContentAlreadyTakenDown,
+    
+    #[msg("Content has not been taken down")]
+    ContentNotTakenDown,
+    
+    #[msg("Royalty shares must name up to 5 distinct creators summing to 10000 basis points")]
+    InvalidRoyaltyShares,
+    
+    #[msg("Royalty deposit must be greater than zero")]
+    InvalidRoyaltyAmount,
+    
+    #[msg("Royalty escrow ledger is full")]
+    RoyaltyLedgerFull,
+    
+    #[msg("No royalties to claim")]
+    NoRoyaltiesToClaim,
+    
+    #[msg("Account is not the system monitor")]
+    InvalidSystemMonitor,
+    
+    #[msg("Message must carry its source block number for this chain")]
+    SourceBlockRequired,
+    
+    #[msg("Source block has not reached the chain's required confirmations")]
+    SourceBlockNotFinal,
+    
+    #[msg("Invalid recipient preferences")]
+    InvalidRecipientPreferences,
+    
+    #[msg("Recipient does not accept mints from this sender")]
+    UnsolicitedDeliveryDeclined,
+    
+    #[msg("Recipient must already hold a token account for this NFT")]
+    RecipientTokenAccountRequired,
+    
+    #[msg("Invalid quarantine account")]
+    InvalidQuarantineAccount,
+    
+    #[msg("Quarantined NFT has not expired yet")]
+    QuarantineNotExpired,
+    
+    #[msg("Invalid fee schedule")]
+    InvalidFeeSchedule,
+    
+    #[msg("Fee schedule update is still timelocked")]
+    FeeScheduleTimelocked,
+    
+    #[msg("No fee schedule update is pending")]
+    NoPendingFeeSchedule,
+    
+    #[msg("Fee recipient does not match the fee schedule")]
+    InvalidFeeRecipient,
+    
+    #[msg("Fee discount accounts do not qualify the payer")]
+    InvalidFeeDiscount,
+    
+    #[msg("Referrer cannot be the payer or NFT owner")]
+    SelfReferral,
+    
+    #[msg("Referral account does not match the referrer")]
+    InvalidReferral,
+    
+    #[msg("No referral rewards to claim")]
+    NoReferralRewards,
+    
+    #[msg("Invalid rebate campaign")]
+    InvalidRebateCampaign,
+    
+    #[msg("Points account does not match the user or program")]
+    InvalidPointsAccount,
+    
+    #[msg("Not enough points to redeem")]
+    InsufficientPoints,
+    
+    #[msg("Too many points redeemers")]
+    TooManyPointsRedeemers,
+    
+    #[msg("Invalid NFT attribute")]
+    InvalidNftAttribute,
+    
+    #[msg("NFT has too many attributes")]
+    TooManyNftAttributes,
+    
+    #[msg("NFT attribute not found")]
+    NftAttributeNotFound,
+    
+    #[msg("Attributes cannot be carried on this route")]
+    AttributesUnsupportedOnRoute,
+    
+    #[msg("Too many trait authorities")]
+    TooManyTraitAuthorities,
+    
+    #[msg("Invalid reveal list")]
+    InvalidRevealList,
+    
+    #[msg("Randomness account is not a fresh Switchboard commitment")]
+    InvalidRandomnessAccount,
+    
+    #[msg("Randomness has not been revealed by the oracle")]
+    RandomnessNotResolved,
+    
+    #[msg("Collection minting must be closed before the reveal. Hint: sell out or reduce the max supply to the minted count")]
+    RevealMintingOpen,
+    
+    #[msg("Collection has not been revealed")]
+    CollectionNotRevealed,
+    
+    #[msg("Every reveal list entry is assigned")]
+    RevealListExhausted,
+    
+    #[msg("Reveal list proof does not match the committed root")]
+    InvalidRevealProof,
+    
+    #[msg("Collection has already been revealed")]
+    CollectionAlreadyRevealed,
+    
+    #[msg("NFT has children equipped and its bridge mode blocks transfer")]
+    ChildrenEquipped,
+    
+    #[msg("Parent NFT has the maximum number of children")]
+    TooManyChildren,
+    
+    #[msg("NFT is not equipped into this parent")]
+    ChildNotEquipped,
+    
+    #[msg("NFT can't be equipped into this parent")]
+    InvalidNestedNft,
+    
+    #[msg("Destination route can't carry equipped children")]
+    ChildrenUnsupportedOnRoute,
+    
+    #[msg("Evolution recipe settings are invalid")]
+    InvalidEvolutionRecipe,
+    
+    #[msg("NFT does not meet the recipe's attribute requirements")]
+    EvolutionRequirementsNotMet,
+    
+    #[msg("Resource accounts do not match the recipe inputs")]
+    InvalidResourceAccounts,
+    
+    #[msg("Ticket has already been checked in")]
+    TicketAlreadyUsed,
+    
+    #[msg("Ticket is for a different event")]
+    TicketEventMismatch,
+    
+    #[msg("Too many ticket validators")]
+    TooManyTicketValidators,
+    
+    #[msg("Ticketing proof destination is invalid")]
+    InvalidTicketProofDestination,
+    
+    #[msg("Vesting schedule is invalid")]
+    InvalidVestingSchedule,
+    
+    #[msg("No vested tokens to claim")]
+    NothingVested,
+    
+    #[msg("Vesting terms do not match the position on Solana")]
+    VestingPositionMismatch,
+    
+    #[msg("Destination route can't carry a vesting position")]
+    VestingUnsupportedOnRoute,
+    
+    #[msg("Attendance drop parameters are invalid")]
+    InvalidAttendanceDrop,
+    
+    #[msg("Allowlist entry has already claimed its attendance NFT")]
+    AttendanceAlreadyClaimed,
+    
+    #[msg("Attendance drop is not open for claims")]
+    AttendanceDropClosed,
+    
+    #[msg("Bubblegum tree is not delegated to the attendance drop")]
+    InvalidAttendanceTree,
+    
+    #[msg("Credential profile names too many issuers")]
+    TooManyCredentialIssuers,
+    
+    #[msg("Credential parameters are invalid")]
+    InvalidCredential,
+    
+    #[msg("Revocation destination is invalid")]
+    InvalidRevocationDestination,
+    
+    #[msg("Swap terms are invalid")]
+    InvalidSwapTerms,
+    
+    #[msg("Signer is not a party to the swap")]
+    NotSwapParty,
+    
+    #[msg("Swap is not in a state that allows this")]
+    InvalidSwapState,
+    
+    #[msg("Secret does not match the swap's hashlock")]
+    InvalidSwapSecret,
+    
+    #[msg("Loan terms are invalid")]
+    InvalidLoanTerms,
+    
+    #[msg("Loan is not in a state that allows this")]
+    InvalidLoanState,
+    
+    #[msg("Loan is not overdue")]
+    LoanNotDefaulted,
+    
+    #[msg("Too many price authorities for a collection floor")]
+    TooManyPriceAuthorities,
+    
+    #[msg("Collection floor price is stale")]
+    StaleFloorPrice,
+    
+    #[msg("Price feed account is invalid or not trading")]
+    InvalidPriceFeed,
+    
+    #[msg("Maintenance window must end after it starts and in the future")]
+    InvalidMaintenanceWindow,
+    
+    #[msg("Remediation flags name an unknown action")]
+    InvalidRemediationFlags,
+    
+    #[msg("Remediation target account missing or does not match the action")]
+    InvalidRemediationTarget,
+    
+    #[msg("Remediation has already been reverted")]
+    RemediationAlreadyReverted,
+    
+    #[msg("Billing account belongs to a different client")]
+    InvalidBillingAccount,
+    
+    #[msg("Usage segment account is invalid or belongs to another collection")]
+    InvalidUsageSegment,
+    
+    #[msg("Leaderboard account is invalid or of the wrong kind")]
+    InvalidLeaderboard,
+    
+    #[msg("Chain metrics account does not match the chain or its name is too long")]
+    InvalidChainMetrics,
+    
+    #[msg("Chain risk entries exceed the registry's capacity, exceed the maximum risk or repeat a chain or pair")]
+    InvalidChainRisk,
+    
+    #[msg("Fraud config parameters are out of bounds, their weights don't sum to 100, or the proposal data is malformed")]
+    InvalidFraudConfig,
+    
+    #[msg("Proposal is not an executed, unapplied fraud config update")]
+    InvalidFraudConfigProposal,
+    
+    #[msg("Operation's fraud risk score exceeds the configured threshold")]
+    FraudRiskTooHigh,
+    
+    #[msg("Security monitor must be a nonzero contract on ZetaChain")]
+    InvalidSecurityMonitor,
+    
+    #[msg("Corridor is paused. Hint: wait for the corridor to be resumed on both sides")]
+    CorridorPaused,
+    
+    #[msg("Corridor pause message is malformed, for another chain or not newer than the last one applied, or the chain has no counterpart contract")]
+    InvalidCorridorPause,
+    
+    #[msg("Deployment registry lists a satellite kind twice, a default program ID, or too many satellites")]
+    InvalidDeploymentRegistry,
+    
+    #[msg("Program is not the approved deployment of this satellite. Hint: check the deployment registry")]
+    UnapprovedSatelliteProgram,
+    
+    #[msg("Retry queue is full. Hint: process due retries before scheduling more")]
+    RetryQueueFull,
+    
+    #[msg("Retry outcome report is for another attempt or is missing its signature or failure reason")]
+    InvalidRetryReport,
+    
+    #[msg("Dispute revert needs the original owner's token account. Hint: pass the custody token account for locked NFTs")]
+    InvalidDisputeRevertAccounts,
+    
+    #[msg("Message from a relayed chain needs its relayed delivery record. Hint: submit it with submit_relayed_message first")]
+    RelayedMessageRequired,
+    
+    #[msg("Relayed message is buffered behind a missing sequence. Hint: deliver the earlier sequences first")]
+    MessageNotReleased,
+    
+    #[msg("Relayed message was already executed")]
+    RelayedMessageConsumed,
+    
+    #[msg("Relayed message has not been executed yet. Hint: rewards are paid once on_call executes the message")]
+    RelayedMessageNotExecuted,
+    
+    #[msg("Relayer reward for this message was already claimed")]
+    RelayerRewardClaimed,
+    
+    #[msg("Query response came from a contract other than the one queried")]
+    QuerySenderMismatch,
+    
+    #[msg("Loan terms differ from the terms the lender expected. Hint: re-read the loan and fund it with its current terms")]
+    LoanTermsMismatch,
+    
+    #[msg("Invalid retry session parameters")]
+    InvalidRetrySession,
+    
+    #[msg("Universal NFT account does not belong to the transfer's mint")]
+    TransferNftMismatch,
+    
+    #[msg("Loan principal exceeds the maximum loan-to-value of the collateral's floor")]
+    LoanToValueExceeded,
+    
+    #[msg("NFT already has a live token on Solana, so an inbound message can't deliver it again")]
+    NftAlreadyOnSolana,
+    
+    #[msg("Inbound message's token ID does not match the universal NFT it is delivered into")]
+    InboundTokenMismatch,
+    
+    #[msg("Token ID must be 1 to 64 bytes")]
+    InvalidTokenId,
+    
+    #[msg("Relayed message was not delivered, or on_call already executed it")]
+    DeliveryNotFailed,
+    
+    #[msg("NFT is held in custody for an escrow, dispute, quarantine or nesting")]
+    NftHeldForAnotherFlow,
 }
\ No newline at end of file
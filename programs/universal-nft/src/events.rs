@@ -0,0 +1,1462 @@
+use anchor_lang::prelude::*;
+
+use crate::recovery::error_recovery::{ErrorType, RecoveryResult};
+use crate::recovery::transaction_retry::{AttemptResult, RetryFailureReason, RetrySessionStatus};
+use crate::state::{
+    ChainKind, ChainRisk, ChildBridgeMode, CollectionTransferMode, CorridorDirection, CrankKind, DisputeStatus,
+    FeeKind, FeeRates, InscriptionRef, MessageLayer, MintPhaseKind, PairRiskOverride, PinStorage, PointsActivity,
+    QueryType, RemoteAsset, RoyaltyShare, RuleEnforcement, SatelliteProgram, SecurityEventKind, SecurityRule,
+    SwapAsset, TransferStatus, UnsolicitedPolicy,
+};
+
+/// Emitted when the treasury moves lamports into the compensation vault
+#[cfg(feature = "governance")]
+#[event]
+pub struct CompensationVaultFunded {
+    pub vault: Pubkey,
+    pub treasury: Pubkey,
+    pub amount: u64,
+    pub total_funded: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a user claims compensation for a reverted transfer
+#[cfg(feature = "governance")]
+#[event]
+pub struct CompensationClaimed {
+    pub vault: Pubkey,
+    pub user: Pubkey,
+    pub transfer: Pubkey,
+    pub amount: u64,
+    pub user_total_claimed: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a state checkpoint commits to the critical account set
+#[event]
+pub struct StateCheckpointCreated {
+    pub checkpoint_id: u64,
+    pub state_root: [u8; 32],
+    pub accounts_committed: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when an auditor verifies live state against a checkpoint
+#[event]
+pub struct StateCheckpointVerified {
+    pub checkpoint_id: u64,
+    pub checkpoint_root: [u8; 32],
+    pub matches: bool,
+    pub verifier: Pubkey,
+    pub timestamp: i64,
+}
+
+/// One page of an exported critical account snapshot
+#[event]
+pub struct StateSnapshotPage {
+    pub account: Pubkey,
+    pub owner: Pubkey,
+    pub slot: u64,
+    pub page: u32,
+    pub total_pages: u32,
+    pub data_len: u32,
+    pub data_hash: [u8; 32],
+    pub data: Vec<u8>,
+}
+
+/// Emitted when an archived chunk is staged for restore
+#[event]
+pub struct StateRestoreChunkApplied {
+    pub account: Pubkey,
+    pub offset: u32,
+    pub len: u32,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a staged restore matches its checkpoint and is written back
+#[event]
+pub struct StateRestoreApplied {
+    pub account: Pubkey,
+    pub checkpoint_id: u64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when an NFT stranded in program custody is registered as orphaned
+#[event]
+pub struct NftOrphaned {
+    pub mint: Pubkey,
+    pub source_chain_id: u64,
+    pub message_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Emitted when the original recipient claims an orphaned NFT
+#[event]
+pub struct OrphanedNftClaimed {
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a party opens a dispute against a transfer record
+#[event]
+pub struct DisputeOpened {
+    pub dispute: Pubkey,
+    pub transfer: Pubkey,
+    pub mint: Pubkey,
+    pub opened_by: Pubkey,
+    pub deadline: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the arbiter resolves a dispute or its deadline expires
+#[event]
+pub struct DisputeClosed {
+    pub dispute: Pubkey,
+    pub mint: Pubkey,
+    pub status: DisputeStatus,
+    pub opener_share_bps: u16,
+    pub opener_payout: u64,
+    pub counterparty_payout: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an interchain query is sent through the gateway
+#[event]
+pub struct InterchainQueryRequested {
+    pub query_id: u64,
+    pub requester: Pubkey,
+    pub target_chain_id: u64,
+    pub query_type: QueryType,
+    pub timestamp: i64,
+}
+
+/// Emitted when a query response is stored
+#[event]
+pub struct InterchainQueryAnswered {
+    pub query_id: u64,
+    pub success: bool,
+    pub response_len: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when a chain is added to the registry or its status changes
+#[event]
+pub struct ChainRegistryUpdated {
+    pub chain_id: u64,
+    pub chain_kind: ChainKind,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when the origin of an inbound NFT is recorded
+#[event]
+pub struct NftOriginRecorded {
+    pub mint: Pubkey,
+    pub origin_chain_id: u64,
+    pub chain_kind: ChainKind,
+    pub inscription: Option<InscriptionRef>,
+    pub timestamp: i64,
+}
+
+/// Emitted when an inbound transfer carries a payload for the recipient
+#[event]
+pub struct TransferPayloadDelivered {
+    pub token_id: String,
+    pub recipient: Pubkey,
+    pub source_chain_id: u64,
+    pub payload: Vec<u8>,
+    pub timestamp: i64,
+}
+
+/// Emitted when an outbound transfer is reverted, with its stored payload
+#[event]
+pub struct CrossChainTransferReverted {
+    pub transfer: Pubkey,
+    pub nonce: u64,
+    pub payload: Vec<u8>,
+    pub timestamp: i64,
+}
+
+/// Emitted when a hook is added to or removed from a collection
+#[event]
+pub struct CollectionHookUpdated {
+    pub collection_mint: Pubkey,
+    pub program_id: Pubkey,
+    pub event_mask: u8,
+    pub compute_allowance: u32,
+    pub added: bool,
+}
+
+/// Emitted when a transfer receipt is burned on confirmation, revert or cancel
+#[event]
+pub struct TransferReceiptBurned {
+    pub transfer: Pubkey,
+    pub receipt_mint: Pubkey,
+    pub status: TransferStatus,
+    pub timestamp: i64,
+}
+
+/// Emitted when an outbound transfer is scheduled
+#[event]
+pub struct TransferScheduled {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub destination_chain_id: u64,
+    pub execute_after: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a scheduled transfer is cancelled during its window
+#[event]
+pub struct ScheduledTransferCancelled {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when governance changes the insurance parameters
+#[cfg(feature = "governance")]
+#[event]
+pub struct InsuranceParametersUpdated {
+    pub premium_lamports: u64,
+    pub payout_lamports: u64,
+    pub enabled: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when a premium is paid for an outbound transfer
+#[cfg(feature = "governance")]
+#[event]
+pub struct TransferInsured {
+    pub transfer: Pubkey,
+    pub insured: Pubkey,
+    pub premium: u64,
+    pub payout: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an insurance payout is made
+#[cfg(feature = "governance")]
+#[event]
+pub struct InsuranceClaimPaid {
+    pub transfer: Pubkey,
+    pub insured: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when several NFTs leave in one batch transfer
+#[event]
+pub struct BatchTransferInitiated {
+    pub batch: Pubkey,
+    pub owner: Pubkey,
+    pub destination_chain_id: u64,
+    pub count: u8,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a relayer registers or starts unbonding
+#[event]
+pub struct RelayerStakeChanged {
+    pub relayer: Pubkey,
+    pub stake: u64,
+    pub active: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when a relayer delivers a validly signed message
+#[event]
+pub struct RelayedMessageDelivered {
+    pub relayer: Pubkey,
+    pub message_hash: [u8; 32],
+    pub source_chain_id: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a relayer is paid for a message on_call executed
+#[event]
+pub struct RelayerRewardPaid {
+    pub relayer: Pubkey,
+    pub message_hash: [u8; 32],
+    pub reward: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a relayer is slashed for an invalid submission
+#[event]
+pub struct RelayerSlashed {
+    pub relayer: Pubkey,
+    pub message_hash: [u8; 32],
+    pub amount: u64,
+    pub remaining_stake: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when in-order inbound messages are released for a source chain
+#[event]
+pub struct InboundSequenceAdvanced {
+    pub source_chain_id: u64,
+    pub released_from: u64,
+    pub next_sequence: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the compliance role freezes or releases an NFT
+#[event]
+pub struct NftComplianceFreezeChanged {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub officer: Pubkey,
+    pub frozen: bool,
+    pub reason_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Emitted when the incident level changes, manually or by de-escalation timer
+#[event]
+pub struct IncidentLevelChanged {
+    pub previous_level: u8,
+    pub level: u8,
+    pub automatic: bool,
+    pub expires_at: i64,
+    pub flagged_mints: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when governance replaces the observer validator set
+#[event]
+pub struct ValidatorSetUpdated {
+    pub validator_set: Pubkey,
+    pub proposal_id: u64,
+    pub epoch: u64,
+    pub validator_count: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when K of the N observers attest an inbound message
+#[event]
+pub struct QuorumAttested {
+    pub leaf: [u8; 32],
+    pub chain_id: u64,
+    pub epoch: u64,
+    pub approvals: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when the header relayer posts a source-chain block root
+#[event]
+pub struct BlockHeaderPosted {
+    pub chain_id: u64,
+    pub block_number: u64,
+    pub events_root: [u8; 32],
+    pub relayer: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a source-chain event is proven against a posted block root
+#[event]
+pub struct EventProofVerified {
+    pub leaf: [u8; 32],
+    pub chain_id: u64,
+    pub block_number: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a chain's outbound caps change
+#[event]
+pub struct ChainLimitsUpdated {
+    pub chain_id: u64,
+    pub max_value_per_transfer: u64,
+    pub daily_value_cap: u64,
+    pub daily_transfer_cap: u32,
+    pub enforcement: RuleEnforcement,
+    pub timestamp: i64,
+}
+
+/// Emitted when a collection's floor pricing sources change
+#[event]
+pub struct CollectionFloorConfigured {
+    pub collection_mint: Pubkey,
+    pub price_authorities: Vec<Pubkey>,
+    pub price_feed: Pubkey,
+    pub max_staleness: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a collection's floor price is posted or synced from its feed
+#[event]
+pub struct CollectionFloorUpdated {
+    pub collection_mint: Pubkey,
+    pub floor_value: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a collection's mint and bridging policy changes
+#[event]
+pub struct CollectionConfigUpdated {
+    pub collection_mint: Pubkey,
+    pub max_supply: u64,
+    pub transfer_mode: CollectionTransferMode,
+    pub seller_fee_basis_points: u16,
+    pub is_paused: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when the last NFT under a collection's supply cap is minted
+#[event]
+pub struct CollectionSoldOut {
+    pub collection_mint: Pubkey,
+    pub max_supply: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a collection's supply cap is lowered
+#[event]
+pub struct MaxSupplyReduced {
+    pub collection_mint: Pubkey,
+    pub previous_max_supply: u64,
+    pub max_supply: u64,
+    pub minted_count: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a phase is added to a collection's mint schedule
+#[event]
+pub struct MintPhaseAdded {
+    pub collection_mint: Pubkey,
+    pub phase_index: u8,
+    pub kind: MintPhaseKind,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub price: u64,
+}
+
+/// Emitted when an NFT is placed in claim escrow
+#[event]
+pub struct NftEscrowedForClaim {
+    pub mint: Pubkey,
+    pub claim_key: Pubkey,
+    pub depositor: Pubkey,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an escrowed NFT is claimed
+#[event]
+pub struct EscrowedNftClaimed {
+    pub mint: Pubkey,
+    pub claim_key: Pubkey,
+    pub claimant: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when an expired claim escrow returns its NFT to the depositor
+#[event]
+pub struct ClaimEscrowCancelled {
+    pub mint: Pubkey,
+    pub claim_key: Pubkey,
+    pub depositor: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a holder snapshot commitment is posted
+#[event]
+pub struct HolderSnapshotCommitted {
+    pub collection_mint: Pubkey,
+    pub slot: u64,
+    pub commitment: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Emitted when a holder snapshot root is revealed, for airdrops and EVM mirrors
+#[event]
+pub struct HolderSnapshotRevealed {
+    pub collection_mint: Pubkey,
+    pub slot: u64,
+    pub holders_root: [u8; 32],
+    pub holder_count: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when an NFT is permanently burned
+#[event]
+pub struct NftBurned {
+    pub mint: Pubkey,
+    pub collection_mint: Option<Pubkey>,
+    pub owner: Pubkey,
+    pub user_total_burned: u64,
+    pub collection_total_burned: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a wallet locks its outbound transfers
+#[event]
+pub struct WalletTransfersLocked {
+    pub wallet: Pubkey,
+    pub unlock_delay: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a wallet starts its unlock timer
+#[event]
+pub struct WalletUnlockRequested {
+    pub wallet: Pubkey,
+    pub unlocks_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an owner registers or replaces an operator session
+#[event]
+pub struct OperatorSessionRegistered {
+    pub owner: Pubkey,
+    pub operator: Pubkey,
+    pub scopes: u8,
+    pub bridge_chain_id: Option<u64>,
+    pub expires_slot: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an owner revokes an operator session
+#[event]
+pub struct OperatorSessionRevoked {
+    pub owner: Pubkey,
+    pub operator: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when governance hands a mint's authority to a new key
+#[cfg(feature = "governance")]
+#[event]
+pub struct MintAuthorityHandedOver {
+    pub mint: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Lamports deposited to sponsor a source chain's inbound rent
+#[event]
+pub struct RentVaultFunded {
+    pub chain_id: u64,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub chain_remaining: u64,
+    pub timestamp: i64,
+}
+
+/// A mint and universal NFT record were prepared for a token's first arrival
+#[event]
+pub struct InboundNftPrepared {
+    pub mint: Pubkey,
+    pub source_chain_id: u64,
+    pub token_id: String,
+    pub timestamp: i64,
+}
+
+/// An inbound message delivered an NFT, minted or released from custody
+#[event]
+pub struct InboundNftDelivered {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub source_chain_id: u64,
+    pub minted: bool,
+    pub timestamp: i64,
+}
+
+/// The rent vault paid for an account created by an inbound message
+#[event]
+pub struct InboundRentSponsored {
+    pub account: Pubkey,
+    pub source_chain_id: u64,
+    pub lamports: u64,
+    pub chain_remaining: u64,
+    pub timestamp: i64,
+}
+
+/// Automation crank operator registered or replaced
+#[event]
+pub struct CrankOperatorRegistered {
+    pub operator: Pubkey,
+    pub timestamp: i64,
+}
+
+/// A maintenance crank did its work
+#[event]
+pub struct CrankExecuted {
+    pub crank: CrankKind,
+    pub target: Pubkey,
+    pub caller: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a message layer is registered or its health changes
+#[event]
+pub struct MessageLayerUpdated {
+    pub layer: MessageLayer,
+    pub program_id: Pubkey,
+    pub operational: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when a chain's message layer routes change
+#[event]
+pub struct ChainRoutesUpdated {
+    pub chain_id: u64,
+    pub primary_layer: MessageLayer,
+    pub fallback_layer: Option<MessageLayer>,
+    pub timestamp: i64,
+}
+
+/// Emitted when a chain's metadata translation rules are set
+#[event]
+pub struct ChainMetadataRulesUpdated {
+    pub chain_id: u64,
+    pub max_name_len: u8,
+    pub max_symbol_len: u8,
+    pub royalty_receiver: [u8; 20],
+    pub timestamp: i64,
+}
+
+/// Emitted when a universal NFT is minted on Solana
+#[event]
+pub struct NftMinted {
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub uri: String,
+    pub timestamp: i64,
+}
+
+/// Emitted when an NFT's metadata is updated
+#[event]
+pub struct NftMetadataUpdated {
+    pub mint: Pubkey,
+    pub uri: String,
+    pub timestamp: i64,
+}
+
+/// Pinning service registered or replaced
+#[event]
+pub struct PinnerRegistered {
+    pub pinner: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a pin receipt is posted for an NFT's metadata content
+#[event]
+pub struct PinReceiptRecorded {
+    pub mint: Pubkey,
+    pub storage: PinStorage,
+    pub content_id: String,
+    pub size: u64,
+    pub uri_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Emitted when anyone flags an NFT's content for review
+#[event]
+pub struct ContentFlagged {
+    pub mint: Pubkey,
+    pub flagger: Pubkey,
+    pub reason_hash: [u8; 32],
+    pub bond: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the moderator resolves a flag or reinstates content.
+/// `flagged` is the NFT's takedown bit afterwards, for marketplaces to honor.
+#[event]
+pub struct ContentModerationResolved {
+    pub mint: Pubkey,
+    pub flagger: Pubkey,
+    pub moderator: Pubkey,
+    pub flagged: bool,
+    pub decision_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+/// Emitted when a collection's royalty split changes
+#[event]
+pub struct RoyaltyConfigUpdated {
+    pub collection_mint: Pubkey,
+    pub shares: Vec<RoyaltyShare>,
+    pub timestamp: i64,
+}
+
+/// Emitted when a marketplace deposits royalties into a collection's escrow
+#[event]
+pub struct RoyaltyDeposited {
+    pub collection_mint: Pubkey,
+    pub depositor: Pubkey,
+    pub amount: u64,
+    pub total_deposited: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a creator claims royalties from a collection's escrow
+#[event]
+pub struct RoyaltiesClaimed {
+    pub collection_mint: Pubkey,
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub lifetime_earned: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a supply audit finds a chain's locked originals and wrapped
+/// representations out of parity
+#[event]
+pub struct ParityMismatch {
+    pub chain_id: u64,
+    pub originals_locked: u64,
+    pub wrapped_outstanding: u64,
+    pub reported_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a source chain's required confirmation depth changes
+#[event]
+pub struct ChainFinalityUpdated {
+    pub chain_id: u64,
+    pub required_confirmations: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a wallet sets or changes its receiving preferences for a chain
+#[event]
+pub struct RecipientPreferencesUpdated {
+    pub owner: Pubkey,
+    pub source_chain_id: u64,
+    pub preferred_recipient: Option<Pubkey>,
+    pub auto_wrap: bool,
+    pub unsolicited: UnsolicitedPolicy,
+    pub timestamp: i64,
+}
+
+/// Emitted when an unsolicited inbound NFT is held in quarantine
+#[event]
+pub struct NftQuarantined {
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub source_chain_id: u64,
+    pub sender: [u8; 20],
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a recipient claims a quarantined NFT into their wallet
+#[event]
+pub struct QuarantineReleased {
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a quarantined NFT is rejected or expires and is sent back to
+/// its sender
+#[event]
+pub struct QuarantineReturned {
+    pub mint: Pubkey,
+    pub source_chain_id: u64,
+    pub sender: [u8; 20],
+    pub expired: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when governance queues a new fee schedule behind the timelock
+#[event]
+pub struct FeeScheduleQueued {
+    pub rates: FeeRates,
+    pub effective_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when governance withdraws a queued fee schedule
+#[event]
+pub struct FeeScheduleCancelled {
+    pub timestamp: i64,
+}
+
+/// Emitted when a queued fee schedule takes effect
+#[event]
+pub struct FeeScheduleApplied {
+    pub rates: FeeRates,
+    pub timestamp: i64,
+}
+
+/// Emitted when a paying instruction charges a protocol fee
+#[event]
+pub struct ProtocolFeeCharged {
+    pub payer: Pubkey,
+    pub kind: FeeKind,
+    pub amount: u64,
+    pub discount_bps: u16,
+    pub fee_recipient: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a referrer is credited its share of a referred fee
+#[event]
+pub struct ReferralFeeAccrued {
+    pub referrer: Pubkey,
+    pub payer: Pubkey,
+    pub kind: FeeKind,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a referrer claims its accrued referral rewards
+#[event]
+pub struct ReferralRewardsClaimed {
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub lifetime_accrued: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when governance configures the rebate campaign
+#[event]
+pub struct RebateCampaignConfigured {
+    pub budget: u64,
+    pub rebate_per_tx: u64,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub eligible_kinds: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when lamports are deposited into the rebate campaign
+#[event]
+pub struct RebateCampaignFunded {
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub total_funded: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when part of a protocol fee is refunded by the rebate campaign
+#[event]
+pub struct FeeRebated {
+    pub payer: Pubkey,
+    pub kind: FeeKind,
+    pub amount: u64,
+    pub remaining_budget: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when governance sets the activity points weights and redeemers
+#[event]
+pub struct PointsConfigured {
+    pub mint_points: u64,
+    pub bridge_points: u64,
+    pub governance_points: u64,
+    pub redeemers: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when a user earns activity points
+#[event]
+pub struct PointsAwarded {
+    pub user: Pubkey,
+    pub activity: PointsActivity,
+    pub points: u64,
+    pub balance: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a user's points are redeemed through a configured redeemer
+#[event]
+pub struct PointsRedeemed {
+    pub user: Pubkey,
+    pub redeemer: Pubkey,
+    pub amount: u64,
+    pub balance: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a collection authority names its trait authorities
+#[event]
+pub struct TraitAuthoritiesUpdated {
+    pub collection_mint: Pubkey,
+    pub authorities: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+/// Emitted when a trait authority sets an NFT attribute
+#[event]
+pub struct NftAttributeSet {
+    pub mint: Pubkey,
+    pub key: String,
+    pub value: Vec<u8>,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a trait authority removes an NFT attribute
+#[event]
+pub struct NftAttributeRemoved {
+    pub mint: Pubkey,
+    pub key: String,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when an inbound transfer restores an NFT's attributes
+#[event]
+pub struct NftAttributesRestored {
+    pub mint: Pubkey,
+    pub source_chain_id: u64,
+    pub entries: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when a collection commits its reveal list and randomness
+#[event]
+pub struct RevealCommitted {
+    pub collection_mint: Pubkey,
+    pub list_root: [u8; 32],
+    pub item_count: u32,
+    pub randomness_account: Pubkey,
+    pub seed_slot: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a collection's reveal randomness is recorded
+#[event]
+pub struct CollectionRevealed {
+    pub collection_mint: Pubkey,
+    pub randomness: [u8; 32],
+    pub seed_slot: u64,
+    pub reveal_slot: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an NFT is assigned its reveal list entry
+#[event]
+pub struct NftRevealed {
+    pub mint: Pubkey,
+    pub collection_mint: Pubkey,
+    pub item_index: u32,
+    pub uri: String,
+    pub timestamp: i64,
+}
+
+/// Emitted when an NFT is equipped into a parent NFT
+#[event]
+pub struct NftEquipped {
+    pub parent_mint: Pubkey,
+    pub child_mint: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when an NFT is unequipped and returned to its parent's holder
+#[event]
+pub struct NftUnequipped {
+    pub parent_mint: Pubkey,
+    pub child_mint: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a parent's holder changes what its children do on bridge-out
+#[event]
+pub struct ChildBridgeModeSet {
+    pub parent_mint: Pubkey,
+    pub mode: ChildBridgeMode,
+    pub timestamp: i64,
+}
+
+/// Emitted when a parent is bridged out with its children bundled
+#[event]
+pub struct ChildrenBundled {
+    pub parent_mint: Pubkey,
+    pub children: Vec<Pubkey>,
+    pub destination_chain_id: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a collection authority creates or replaces an evolution recipe
+#[event]
+pub struct EvolutionRecipeSet {
+    pub collection_mint: Pubkey,
+    pub recipe_id: u32,
+    pub inputs: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when a collection authority removes an evolution recipe
+#[event]
+pub struct EvolutionRecipeRemoved {
+    pub collection_mint: Pubkey,
+    pub recipe_id: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when an NFT evolves by a recipe
+#[event]
+pub struct NftEvolved {
+    pub mint: Pubkey,
+    pub collection_mint: Pubkey,
+    pub recipe_id: u32,
+    pub owner: Pubkey,
+    pub uri: String,
+    pub timestamp: i64,
+}
+
+/// Emitted when a collection authority configures ticketing
+#[event]
+pub struct TicketingConfigured {
+    pub collection_mint: Pubkey,
+    pub validators: Vec<Pubkey>,
+    pub freeze_after_use: bool,
+    pub proof_chain_id: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an NFT is issued as a ticket for an event
+#[event]
+pub struct TicketIssued {
+    pub mint: Pubkey,
+    pub collection_mint: Pubkey,
+    pub event_id: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a ticket is checked in. Event systems on other chains also
+/// receive a `TicketCheckIn` message when the collection sends proofs.
+#[event]
+pub struct TicketCheckedIn {
+    pub mint: Pubkey,
+    pub event_id: u64,
+    pub holder: Pubkey,
+    pub validator: Pubkey,
+    pub frozen: bool,
+    pub proof_chain_id: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when SPL tokens are escrowed behind an NFT as a vesting position
+#[event]
+pub struct VestingPositionCreated {
+    pub nft_mint: Pubkey,
+    pub token_mint: Pubkey,
+    pub total_amount: u64,
+    pub start_ts: i64,
+    pub cliff_ts: i64,
+    pub end_ts: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an NFT's holder claims vested tokens
+#[event]
+pub struct VestedTokensClaimed {
+    pub nft_mint: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub claimed_amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an NFT returns carrying terms that match its position
+#[event]
+pub struct VestingPositionReturned {
+    pub nft_mint: Pubkey,
+    pub source_chain_id: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a proof-of-attendance drop opens
+#[event]
+pub struct AttendanceDropCreated {
+    pub collection_mint: Pubkey,
+    pub event_id: u64,
+    pub merkle_tree: Pubkey,
+    pub max_claims: u32,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an attendee claims their attendance NFT
+#[event]
+pub struct AttendanceClaimed {
+    pub collection_mint: Pubkey,
+    pub event_id: u64,
+    pub claimant: Pubkey,
+    pub index: u32,
+    pub claim_number: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when a collection's credential profile is set
+#[event]
+pub struct CredentialsConfigured {
+    pub collection_mint: Pubkey,
+    pub issuers: Vec<Pubkey>,
+    pub revocation_chain_id: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an issuer attests a credential
+#[event]
+pub struct CredentialIssued {
+    pub collection_mint: Pubkey,
+    pub credential_id: u64,
+    pub subject: Pubkey,
+    pub issuer: Pubkey,
+    pub schema_hash: [u8; 32],
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a credential enters the revocation registry
+#[event]
+pub struct CredentialRevoked {
+    pub collection_mint: Pubkey,
+    pub credential_id: u64,
+    pub subject: Pubkey,
+    pub revoked_by: Pubkey,
+    pub reason: u16,
+    pub revocation_chain_id: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a maker escrows its leg of a swap
+#[event]
+pub struct SwapCreated {
+    pub swap: Pubkey,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub maker_asset: SwapAsset,
+    pub taker_asset: SwapAsset,
+    pub timestamp: i64,
+}
+
+/// Emitted when both legs of a swap change hands
+#[event]
+pub struct SwapSettled {
+    pub swap: Pubkey,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub maker_asset: SwapAsset,
+    pub taker_asset: SwapAsset,
+    pub timestamp: i64,
+}
+
+/// Emitted when a swap is cancelled and the maker refunded
+#[event]
+pub struct SwapCancelled {
+    pub swap: Pubkey,
+    pub maker: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a maker escrows an NFT under a cross-chain swap's hashlock
+#[event]
+pub struct CrossChainSwapCreated {
+    pub swap: Pubkey,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub nft_mint: Pubkey,
+    pub counterparty_chain_id: u64,
+    pub remote_asset: RemoteAsset,
+    pub hashlock: [u8; 32],
+    pub expires_at: i64,
+    pub transfer: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the counterparty chain relays a cross-chain swap's secret
+#[event]
+pub struct CrossChainSwapSecretRevealed {
+    pub swap: Pubkey,
+    pub secret: [u8; 32],
+    pub source_chain_id: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a cross-chain swap's lock request is reverted, making it refundable
+#[event]
+pub struct CrossChainSwapReverted {
+    pub swap: Pubkey,
+    pub transfer: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a cross-chain swap's NFT goes to the taker or back to the maker
+#[event]
+pub struct CrossChainSwapSettled {
+    pub swap: Pubkey,
+    pub nft_mint: Pubkey,
+    pub recipient: Pubkey,
+    pub redeemed: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when a borrower escrows an NFT to request a loan
+#[event]
+pub struct LoanRequested {
+    pub loan: Pubkey,
+    pub nft_mint: Pubkey,
+    pub borrower: Pubkey,
+    pub principal_mint: Pubkey,
+    pub principal: u64,
+    pub repayment: u64,
+    pub duration: i64,
+    pub collateral_value: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a lender pays out a loan's principal
+#[event]
+pub struct LoanFunded {
+    pub loan: Pubkey,
+    pub nft_mint: Pubkey,
+    pub borrower: Pubkey,
+    pub lender: Pubkey,
+    pub due_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a borrower repays a loan and gets the NFT back
+#[event]
+pub struct LoanRepaid {
+    pub loan: Pubkey,
+    pub nft_mint: Pubkey,
+    pub borrower: Pubkey,
+    pub lender: Pubkey,
+    pub repayment: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a lender takes an overdue loan's NFT
+#[event]
+pub struct LoanLiquidated {
+    pub loan: Pubkey,
+    pub nft_mint: Pubkey,
+    pub borrower: Pubkey,
+    pub lender: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a borrower withdraws an unfunded loan request
+#[event]
+pub struct LoanRequestCancelled {
+    pub loan: Pubkey,
+    pub nft_mint: Pubkey,
+    pub borrower: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Version of the marketplace event schema in docs/API.md. Listings, offers,
+/// sales and cancellations all carry it, keyed by the universal token ID and
+/// origin chain so activity on every chain joins up for the same asset.
+pub const MARKETPLACE_EVENT_SCHEMA_VERSION: u8 = 1;
+
+/// Emitted when a universal NFT is listed for a price
+#[event]
+pub struct NftListed {
+    pub schema_version: u8,
+    pub universal_token_id: String,
+    pub origin_chain_id: u64,
+    pub nft_mint: Pubkey,
+    pub collection_mint: Option<Pubkey>,
+    pub venue: Pubkey,
+    pub seller: Pubkey,
+    pub price_mint: Pubkey,
+    pub price: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a buyer offers a price for a universal NFT
+#[event]
+pub struct NftOfferMade {
+    pub schema_version: u8,
+    pub universal_token_id: String,
+    pub origin_chain_id: u64,
+    pub nft_mint: Pubkey,
+    pub collection_mint: Option<Pubkey>,
+    pub venue: Pubkey,
+    pub buyer: Pubkey,
+    pub owner: Pubkey,
+    pub price_mint: Pubkey,
+    pub price: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a universal NFT changes hands for a price
+#[event]
+pub struct NftSold {
+    pub schema_version: u8,
+    pub universal_token_id: String,
+    pub origin_chain_id: u64,
+    pub nft_mint: Pubkey,
+    pub collection_mint: Option<Pubkey>,
+    pub venue: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price_mint: Pubkey,
+    pub price: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a listing or offer is withdrawn
+#[event]
+pub struct NftListingCancelled {
+    pub schema_version: u8,
+    pub venue: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when auto-remediation changes protocol state on its own
+#[event]
+pub struct RemediationApplied {
+    pub entry_id: u64,
+    /// Flag of the action taken
+    pub action: u8,
+    /// Target chain; zero for program-wide actions
+    pub chain_id: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when an automated remediation is undone
+#[event]
+pub struct RemediationReverted {
+    pub entry_id: u64,
+    pub action: u8,
+    pub chain_id: u64,
+    pub timestamp: i64,
+}
+
+/// A chain corridor crossed its degradation thresholds in either direction
+#[event]
+pub struct CorridorStatusChanged {
+    pub source_chain_id: u64,
+    pub destination_chain_id: u64,
+    pub degraded: bool,
+    pub latency_ema_s: u32,
+    pub failure_rate_bps: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted when governance replaces the chain risk registry
+#[event]
+pub struct ChainRiskUpdated {
+    pub chain_risks: Vec<ChainRisk>,
+    pub pair_overrides: Vec<PairRiskOverride>,
+    pub timestamp: i64,
+}
+
+/// A rule in shadow mode would have rejected an operation. `subject` is the
+/// chain ID for chain-scoped rules and zero otherwise.
+#[event]
+pub struct ShadowRuleTriggered {
+    pub rule: SecurityRule,
+    pub subject: u64,
+    pub error_code: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when the security event export destination changes
+#[event]
+pub struct SecurityExportConfigured {
+    pub monitor_chain_id: u64,
+    pub monitor_contract: [u8; 20],
+    pub reporter: Pubkey,
+    pub timestamp: i64,
+}
+
+/// A critical security event was sent to the monitoring contract
+#[event]
+pub struct SecurityEventExported {
+    pub sequence: u64,
+    pub kind: SecurityEventKind,
+    pub chain_id: u64,
+    pub value: u64,
+    pub occurred_at: i64,
+    pub reporter: Pubkey,
+    pub timestamp: i64,
+}
+
+/// A corridor was paused or resumed. `source_chain_id` is the chain a signed
+/// admin message came from, zero when the program authority requested it.
+#[event]
+pub struct CorridorPauseChanged {
+    pub chain_id: u64,
+    pub direction: CorridorDirection,
+    pub paused: bool,
+    pub inbound_paused: bool,
+    pub outbound_paused: bool,
+    pub source_chain_id: u64,
+    pub nonce: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when governance replaces the satellite deployment registry
+#[event]
+pub struct DeploymentRegistryUpdated {
+    pub satellites: Vec<SatelliteProgram>,
+    pub timestamp: i64,
+}
+
+/// Emitted when a retry attempt starts; the off-chain retrier resubmits the
+/// original transaction with the given compute unit limit and priority fee
+#[event]
+pub struct RetryAttemptStarted {
+    pub session: Pubkey,
+    pub session_id: u64,
+    pub attempt: u8,
+    pub original_tx_signature: String,
+    pub compute_unit_limit: u32,
+    pub priority_fee_micro_lamports: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a failed transaction is scheduled for retry
+#[event]
+pub struct RetrySessionScheduled {
+    pub session: Pubkey,
+    pub session_id: u64,
+    pub original_tx_signature: String,
+    pub next_retry_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the retrier reports how a resubmitted attempt went
+#[event]
+pub struct RetryOutcomeReported {
+    pub session: Pubkey,
+    pub attempt: u8,
+    pub result: AttemptResult,
+    pub tx_signature: String,
+    pub failure_reason: Option<RetryFailureReason>,
+    pub status: RetrySessionStatus,
+    pub next_retry_at: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a recovery session is opened for a transfer that did not land
+#[event]
+pub struct RecoverySessionOpened {
+    pub session: Pubkey,
+    pub session_id: u64,
+    pub transfer: Pubkey,
+    pub error_type: ErrorType,
+    pub timestamp: i64,
+}
+
+/// Emitted when a recovery session reaches its final result
+#[event]
+pub struct RecoverySessionConcluded {
+    pub session: Pubkey,
+    pub session_id: u64,
+    pub transfer: Pubkey,
+    pub result: RecoveryResult,
+    pub timestamp: i64,
+}
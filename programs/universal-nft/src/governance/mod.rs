@@ -1,11 +0,0 @@
-pub mod dao;
-pub mod proposals;
-pub mod voting;
-pub mod treasury;
-pub mod upgrade_authority;
-
-pub use dao::*;
-pub use proposals::*;
-pub use voting::*;
-pub use treasury::*;
-pub use upgrade_authority::*;
\ No newline at end of file
@@ -0,0 +1,308 @@
+//! Collection hooks for Universal NFT Protocol
+//! Whitelisted external programs are CPI'd on NFT lifecycle events with a
+//! bounded compute allowance, so collections can extend behavior (staking,
+//! loyalty, anti-bot) without forking the program.
+//!
+//! Hook programs implement a single instruction whose data is
+//! `HOOK_DISCRIMINATOR || borsh(HookPayload)`. Callers pass, for each
+//! subscribed hook in registry order, the hook program followed by its
+//! `account_count` accounts as remaining accounts.
+
+use anchor_lang::prelude::*;
+use solana_program::{
+    compute_units::sol_remaining_compute_units,
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+
+use crate::errors::UniversalNftError;
+use crate::state::{CollectionHooks, HookEntry, HookEvent, HookProgram};
+
+/// `sha256("global:on_universal_nft_event")[..8]`
+pub const HOOK_DISCRIMINATOR: [u8; 8] = [110, 183, 26, 10, 225, 103, 209, 149];
+
+/// Data passed to hook programs
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct HookPayload {
+    pub event: HookEvent,
+    pub mint: Pubkey,
+    pub collection_mint: Pubkey,
+    pub from: Pubkey,
+    pub to: Option<Pubkey>,
+    pub destination_chain_id: Option<u64>,
+}
+
+pub struct HookUtils;
+
+impl HookUtils {
+    /// Check a hook before it joins a collection's registry: it subscribes to
+    /// known events only, stays within the whitelisted compute cap, and isn't
+    /// registered twice or past the registry's capacity
+    pub fn validate_hook(hooks: &CollectionHooks, program: &HookProgram, entry: &HookEntry) -> Result<()> {
+        let all_events = HookEvent::Mint.mask() | HookEvent::Transfer.mask() | HookEvent::BridgeOut.mask();
+        require!(
+            entry.event_mask != 0 && entry.event_mask & !all_events == 0,
+            UniversalNftError::InvalidHookConfiguration
+        );
+        require!(
+            entry.compute_allowance > 0 && entry.compute_allowance <= program.max_compute_units,
+            UniversalNftError::HookComputeExceeded
+        );
+        require!(
+            hooks.hooks.len() < CollectionHooks::MAX_HOOKS,
+            UniversalNftError::InvalidHookConfiguration
+        );
+        require!(
+            !hooks.hooks.iter().any(|h| h.program_id == entry.program_id),
+            UniversalNftError::InvalidHookConfiguration
+        );
+        Ok(())
+    }
+
+    /// Load the hook registry for an NFT's collection. When the NFT belongs to
+    /// a collection the registry PDA must be supplied so hooks can't be skipped;
+    /// an uninitialized PDA means the collection has no hooks.
+    pub fn load_collection_hooks(
+        collection_mint: Option<Pubkey>,
+        hooks_account: Option<&AccountInfo>,
+    ) -> Result<Option<CollectionHooks>> {
+        let collection_mint = match collection_mint {
+            Some(mint) => mint,
+            None => return Ok(None),
+        };
+
+        let hooks_account = hooks_account.ok_or(UniversalNftError::MissingCollectionHooks)?;
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"collection_hooks", collection_mint.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            hooks_account.key(),
+            expected,
+            UniversalNftError::MissingCollectionHooks
+        );
+
+        if hooks_account.data_is_empty() {
+            return Ok(None);
+        }
+
+        let data = hooks_account.try_borrow_data()?;
+        let hooks = CollectionHooks::try_deserialize(&mut &data[..])?;
+        Ok(Some(hooks))
+    }
+
+    /// Invoke every hook subscribed to the payload's event and return how many
+    /// remaining accounts the hooks used
+    pub fn invoke_hooks<'info>(
+        hooks: &CollectionHooks,
+        payload: &HookPayload,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<usize> {
+        let mut data = HOOK_DISCRIMINATOR.to_vec();
+        payload.serialize(&mut data)?;
+
+        let mut cursor = 0usize;
+        for entry in hooks.hooks.iter().filter(|h| h.event_mask & payload.event.mask() != 0) {
+            let end = cursor
+                .checked_add(1 + entry.account_count as usize)
+                .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            require!(
+                end <= remaining_accounts.len(),
+                UniversalNftError::InvalidHookAccounts
+            );
+
+            let program = &remaining_accounts[cursor];
+            let accounts = &remaining_accounts[cursor + 1..end];
+            require_keys_eq!(
+                program.key(),
+                entry.program_id,
+                UniversalNftError::InvalidHookAccounts
+            );
+            require!(program.executable, UniversalNftError::InvalidHookAccounts);
+
+            let ix = Instruction {
+                program_id: entry.program_id,
+                accounts: accounts
+                    .iter()
+                    .map(|a| AccountMeta {
+                        pubkey: a.key(),
+                        is_signer: a.is_signer,
+                        is_writable: a.is_writable,
+                    })
+                    .collect(),
+                data: data.clone(),
+            };
+
+            let mut infos = accounts.to_vec();
+            infos.push(program.clone());
+
+            let before = sol_remaining_compute_units();
+            require!(
+                before >= entry.compute_allowance as u64,
+                UniversalNftError::HookComputeExceeded
+            );
+            invoke(&ix, &infos)?;
+            let used = before.saturating_sub(sol_remaining_compute_units());
+            require!(
+                used <= entry.compute_allowance as u64,
+                UniversalNftError::HookComputeExceeded
+            );
+
+            log!("Hook {} used {} CU", entry.program_id, used);
+            cursor = end;
+        }
+
+        Ok(cursor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry(collection_mint: Pubkey, hooks: Vec<HookEntry>) -> CollectionHooks {
+        CollectionHooks {
+            collection_mint,
+            authority: Pubkey::new_unique(),
+            hooks,
+            bump: 255,
+        }
+    }
+
+    fn entry(program_id: Pubkey, event_mask: u8, account_count: u8) -> HookEntry {
+        HookEntry {
+            program_id,
+            event_mask,
+            compute_allowance: 10_000,
+            account_count,
+        }
+    }
+
+    #[test]
+    fn test_validate_hook() {
+        let program = HookProgram {
+            program_id: Pubkey::new_unique(),
+            max_compute_units: 10_000,
+            approved_at: 0,
+            bump: 255,
+        };
+        let mut hooks = registry(Pubkey::new_unique(), Vec::new());
+        let hook = entry(program.program_id, HookEvent::Mint.mask() | HookEvent::BridgeOut.mask(), 0);
+        assert!(HookUtils::validate_hook(&hooks, &program, &hook).is_ok());
+
+        // Unknown or empty event masks
+        for event_mask in [0, 1 << 3] {
+            assert_eq!(
+                HookUtils::validate_hook(&hooks, &program, &HookEntry { event_mask, ..hook.clone() }).unwrap_err(),
+                UniversalNftError::InvalidHookConfiguration.into()
+            );
+        }
+
+        // Allowance past the whitelisted cap, or none at all
+        for compute_allowance in [0, 10_001] {
+            assert_eq!(
+                HookUtils::validate_hook(&hooks, &program, &HookEntry { compute_allowance, ..hook.clone() })
+                    .unwrap_err(),
+                UniversalNftError::HookComputeExceeded.into()
+            );
+        }
+
+        // A program is registered once
+        hooks.hooks.push(hook.clone());
+        assert_eq!(
+            HookUtils::validate_hook(&hooks, &program, &hook).unwrap_err(),
+            UniversalNftError::InvalidHookConfiguration.into()
+        );
+
+        // A full registry takes no more hooks
+        let mut full = registry(hooks.collection_mint, Vec::new());
+        full.hooks = (0..CollectionHooks::MAX_HOOKS).map(|_| entry(Pubkey::new_unique(), 1, 0)).collect();
+        assert_eq!(
+            HookUtils::validate_hook(&full, &program, &entry(program.program_id, 1, 0)).unwrap_err(),
+            UniversalNftError::InvalidHookConfiguration.into()
+        );
+    }
+
+    #[test]
+    fn test_load_collection_hooks() {
+        let collection_mint = Pubkey::new_unique();
+        let (registry_key, _) = Pubkey::find_program_address(
+            &[b"collection_hooks", collection_mint.as_ref()],
+            &crate::ID,
+        );
+        let mut data = Vec::new();
+        registry(collection_mint, vec![entry(Pubkey::new_unique(), 1, 0)])
+            .try_serialize(&mut data)
+            .unwrap();
+        let mut lamports = 1;
+        let registry_info =
+            AccountInfo::new(&registry_key, false, false, &mut lamports, &mut data, &crate::ID, false, 0);
+
+        // NFTs outside a collection have no hooks
+        assert!(HookUtils::load_collection_hooks(None, None).unwrap().is_none());
+
+        let loaded = HookUtils::load_collection_hooks(Some(collection_mint), Some(&registry_info)).unwrap().unwrap();
+        assert_eq!(loaded.hooks.len(), 1);
+
+        // A collection NFT can't skip its hooks by leaving out or swapping the registry
+        assert_eq!(
+            HookUtils::load_collection_hooks(Some(collection_mint), None).err(),
+            Some(UniversalNftError::MissingCollectionHooks.into())
+        );
+        assert_eq!(
+            HookUtils::load_collection_hooks(Some(Pubkey::new_unique()), Some(&registry_info)).err(),
+            Some(UniversalNftError::MissingCollectionHooks.into())
+        );
+
+        // An uncreated registry means no hooks
+        let mut lamports = 0;
+        let mut empty = Vec::new();
+        let empty_info =
+            AccountInfo::new(&registry_key, false, false, &mut lamports, &mut empty, &crate::ID, false, 0);
+        assert!(HookUtils::load_collection_hooks(Some(collection_mint), Some(&empty_info)).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_invoke_hooks_accounts() {
+        let hook_program = Pubkey::new_unique();
+        let hooks = registry(Pubkey::new_unique(), vec![entry(hook_program, HookEvent::BridgeOut.mask(), 1)]);
+        let payload = |event| HookPayload {
+            event,
+            mint: Pubkey::new_unique(),
+            collection_mint: hooks.collection_mint,
+            from: Pubkey::new_unique(),
+            to: None,
+            destination_chain_id: Some(1),
+        };
+
+        // Events no hook subscribes to invoke nothing and use no accounts
+        assert_eq!(HookUtils::invoke_hooks(&hooks, &payload(HookEvent::Mint), &[]).unwrap(), 0);
+
+        // A subscribed hook needs its program and all its accounts
+        assert_eq!(
+            HookUtils::invoke_hooks(&hooks, &payload(HookEvent::BridgeOut), &[]).unwrap_err(),
+            UniversalNftError::InvalidHookAccounts.into()
+        );
+
+        let other = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let (mut wrong_lamports, mut program_lamports, mut account_lamports) = (1, 1, 1);
+        let (mut wrong_data, mut program_data, mut account_data) = (Vec::new(), Vec::new(), Vec::new());
+        let wrong_program =
+            AccountInfo::new(&other, false, false, &mut wrong_lamports, &mut wrong_data, &owner, true, 0);
+        let not_executable =
+            AccountInfo::new(&hook_program, false, false, &mut program_lamports, &mut program_data, &owner, false, 0);
+        let hook_account =
+            AccountInfo::new(&account, false, true, &mut account_lamports, &mut account_data, &owner, false, 0);
+
+        // The program account must be the registered, executable program
+        for program in [wrong_program, not_executable] {
+            assert_eq!(
+                HookUtils::invoke_hooks(&hooks, &payload(HookEvent::BridgeOut), &[program, hook_account.clone()])
+                    .unwrap_err(),
+                UniversalNftError::InvalidHookAccounts.into()
+            );
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::{LeaderboardKind, LeaderboardView, PointsWeights};
+
+// Anchor 0.30's #[program] ignores #[cfg] on instructions, so a build without
+// the `analytics` feature keeps the points, remediation, usage, leaderboard and
+// chain metrics entry points as these stubs. They take no accounts and always
+// fail with FeatureDisabled.
+
+#[derive(Accounts)]
+pub struct ConfigurePoints {}
+
+#[derive(Accounts)]
+pub struct OpenUserMetrics {}
+
+#[derive(Accounts)]
+pub struct ClaimGovernancePoints {}
+
+#[derive(Accounts)]
+pub struct RedeemPoints {}
+
+#[derive(Accounts)]
+pub struct InitializeAuditLog {}
+
+#[derive(Accounts)]
+pub struct RevertRemediation {}
+
+#[derive(Accounts)]
+pub struct OpenUsageSegment {}
+
+#[derive(Accounts)]
+pub struct OpenLeaderboard {}
+
+#[derive(Accounts)]
+pub struct GetLeaderboard {}
+
+#[derive(Accounts)]
+pub struct OpenChainMetrics {}
+
+pub fn configure_points(
+    _ctx: Context<ConfigurePoints>,
+    _weights: PointsWeights,
+    _redeemers: Vec<Pubkey>,
+) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn open_user_metrics(_ctx: Context<OpenUserMetrics>) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn claim_governance_points(_ctx: Context<ClaimGovernancePoints>) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn redeem_points(_ctx: Context<RedeemPoints>, _amount: u64) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn initialize_audit_log(_ctx: Context<InitializeAuditLog>) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn revert_remediation(_ctx: Context<RevertRemediation>, _entry_id: u64) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn open_usage_segment(
+    _ctx: Context<OpenUsageSegment>,
+    _collection_mint: Pubkey,
+    _integration_id: u64,
+) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn open_leaderboard(_ctx: Context<OpenLeaderboard>, _kind: LeaderboardKind) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn get_leaderboard(_ctx: Context<GetLeaderboard>) -> Result<LeaderboardView> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn open_chain_metrics(
+    _ctx: Context<OpenChainMetrics>,
+    _chain_id: u64,
+    _chain_name: String,
+) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
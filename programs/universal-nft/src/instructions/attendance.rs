@@ -0,0 +1,216 @@
+use anchor_lang::prelude::*;
+use solana_program::program::invoke_signed;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::{AttendanceUtils, MetadataUtils};
+
+/// Open a proof-of-attendance drop for one of a collection's events
+/// (collection authority only). The Bubblegum tree must already be created
+/// with this drop's PDA as its delegate and room for every allowlist entry.
+pub fn create_attendance_drop(
+    ctx: Context<CreateAttendanceDrop>,
+    event_id: u64,
+    params: AttendanceDropParams,
+) -> Result<()> {
+    require!(
+        params.max_claims > 0 && params.max_claims <= AttendanceDrop::MAX_CLAIMS,
+        UniversalNftError::InvalidAttendanceDrop
+    );
+    require!(params.start_time < params.end_time, UniversalNftError::InvalidAttendanceDrop);
+    require!(
+        params.name_template.len() <= 32 && params.uri_template.len() <= 200,
+        UniversalNftError::InvalidAttendanceDrop
+    );
+    // The last claim number renders the longest metadata
+    MetadataUtils::validate_name(&AttendanceDrop::render(&params.name_template, params.max_claims))?;
+    MetadataUtils::validate_symbol(&params.symbol)?;
+    MetadataUtils::validate_uri(&AttendanceDrop::render(&params.uri_template, params.max_claims))?;
+
+    let (delegate, remaining) =
+        AttendanceUtils::parse_tree_config(&ctx.accounts.tree_config.try_borrow_data()?)?;
+    require_keys_eq!(
+        delegate,
+        ctx.accounts.attendance_drop.key(),
+        UniversalNftError::InvalidAttendanceTree
+    );
+    require!(
+        remaining >= params.max_claims as u64,
+        UniversalNftError::InvalidAttendanceTree
+    );
+
+    let attendance = &mut ctx.accounts.attendance_drop;
+    attendance.collection_mint = ctx.accounts.collection.mint;
+    attendance.event_id = event_id;
+    attendance.merkle_tree = params.merkle_tree;
+    attendance.allowlist_root = params.allowlist_root;
+    attendance.name_template = params.name_template;
+    attendance.symbol = params.symbol;
+    attendance.uri_template = params.uri_template;
+    attendance.start_time = params.start_time;
+    attendance.end_time = params.end_time;
+    attendance.max_claims = params.max_claims;
+    attendance.claimed_count = 0;
+    attendance.claimed = vec![0u8; params.max_claims.div_ceil(8) as usize];
+    attendance.bump = ctx.bumps.attendance_drop;
+
+    emit!(AttendanceDropCreated {
+        collection_mint: attendance.collection_mint,
+        event_id,
+        merkle_tree: attendance.merkle_tree,
+        max_claims: attendance.max_claims,
+        start_time: attendance.start_time,
+        end_time: attendance.end_time,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Attendance drop opened for event {}", event_id);
+    Ok(())
+}
+
+/// Claim the signer's attendance NFT, free of charge. The signer proves their
+/// allowlist entry, which claims once, and receives a compressed NFT whose
+/// name and URI are filled in with its claim number.
+pub fn claim_attendance(ctx: Context<ClaimAttendance>, index: u32, proof: Vec<[u8; 32]>) -> Result<()> {
+    require!(!ctx.accounts.config.is_paused, UniversalNftError::ProgramPaused);
+
+    let claimant = ctx.accounts.claimant.key();
+    let now = Clock::get()?.unix_timestamp;
+    let attendance = &mut ctx.accounts.attendance_drop;
+    require!(attendance.is_open(now), UniversalNftError::AttendanceDropClosed);
+    require!(
+        AttendanceUtils::verify_allowlist(&attendance.allowlist_root, index, &claimant, &proof),
+        UniversalNftError::NotOnAllowlist
+    );
+    let claim_number = attendance.claim(index)?;
+
+    let mint_ix = AttendanceUtils::mint_v1_instruction(
+        ctx.accounts.tree_config.key(),
+        attendance.merkle_tree,
+        claimant,
+        claimant,
+        attendance.key(),
+        AttendanceDrop::render(&attendance.name_template, claim_number),
+        attendance.symbol.clone(),
+        AttendanceDrop::render(&attendance.uri_template, claim_number),
+        attendance.collection_mint,
+    )?;
+    let collection_mint = attendance.collection_mint;
+    let event_id_bytes = attendance.event_id.to_le_bytes();
+    let drop_seeds = &[
+        b"attendance_drop".as_ref(),
+        collection_mint.as_ref(),
+        event_id_bytes.as_ref(),
+        &[attendance.bump],
+    ];
+    invoke_signed(
+        &mint_ix,
+        &[
+            ctx.accounts.tree_config.to_account_info(),
+            ctx.accounts.claimant.to_account_info(),
+            ctx.accounts.merkle_tree.to_account_info(),
+            attendance.to_account_info(),
+            ctx.accounts.log_wrapper.to_account_info(),
+            ctx.accounts.compression_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.bubblegum_program.to_account_info(),
+        ],
+        &[&drop_seeds[..]],
+    )?;
+
+    emit!(AttendanceClaimed {
+        collection_mint,
+        event_id: attendance.event_id,
+        claimant,
+        index,
+        claim_number,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(event_id: u64, params: AttendanceDropParams)]
+pub struct CreateAttendanceDrop<'info> {
+    #[account(
+        seeds = [b"collection", collection.mint.as_ref()],
+        bump = collection.bump,
+        has_one = authority
+    )]
+    pub collection: Account<'info, UniversalCollection>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AttendanceDrop::INIT_SPACE,
+        seeds = [b"attendance_drop", collection.mint.as_ref(), event_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub attendance_drop: Account<'info, AttendanceDrop>,
+
+    /// CHECK: Bubblegum tree config, parsed by AttendanceUtils
+    #[account(
+        seeds = [params.merkle_tree.as_ref()],
+        bump,
+        seeds::program = AttendanceUtils::BUBBLEGUM_PROGRAM_ID,
+        owner = AttendanceUtils::BUBBLEGUM_PROGRAM_ID @ UniversalNftError::InvalidAttendanceTree
+    )]
+    pub tree_config: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimAttendance<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"attendance_drop",
+            attendance_drop.collection_mint.as_ref(),
+            attendance_drop.event_id.to_le_bytes().as_ref()
+        ],
+        bump = attendance_drop.bump
+    )]
+    pub attendance_drop: Account<'info, AttendanceDrop>,
+
+    /// CHECK: Bubblegum tree config, checked by Bubblegum
+    #[account(
+        mut,
+        seeds = [attendance_drop.merkle_tree.as_ref()],
+        bump,
+        seeds::program = AttendanceUtils::BUBBLEGUM_PROGRAM_ID
+    )]
+    pub tree_config: UncheckedAccount<'info>,
+
+    /// CHECK: Concurrent Merkle tree the NFT is appended to
+    #[account(mut, address = attendance_drop.merkle_tree)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// CHECK: Bubblegum program
+    #[account(address = AttendanceUtils::BUBBLEGUM_PROGRAM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Noop program
+    #[account(address = AttendanceUtils::NOOP_PROGRAM_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Account Compression program
+    #[account(address = AttendanceUtils::COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
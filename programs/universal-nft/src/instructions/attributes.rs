@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Name the keys allowed to write attributes of the collection's NFTs
+/// (collection authority only). An empty list stops all writes.
+pub fn set_trait_authorities(
+    ctx: Context<SetTraitAuthorities>,
+    authorities: Vec<Pubkey>,
+) -> Result<()> {
+    require!(
+        authorities.len() <= TraitAuthorities::MAX_AUTHORITIES,
+        UniversalNftError::TooManyTraitAuthorities
+    );
+
+    let trait_authorities = &mut ctx.accounts.trait_authorities;
+    trait_authorities.collection_mint = ctx.accounts.collection.mint;
+    trait_authorities.authorities = authorities;
+    trait_authorities.bump = ctx.bumps.trait_authorities;
+
+    emit!(TraitAuthoritiesUpdated {
+        collection_mint: trait_authorities.collection_mint,
+        authorities: trait_authorities.authorities.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Trait authorities updated: {}", trait_authorities.collection_mint);
+    Ok(())
+}
+
+/// Set an attribute of an NFT, replacing any value under the same key
+/// (trait authority of its collection only)
+pub fn set_nft_attribute(ctx: Context<SetNftAttribute>, key: String, value: Vec<u8>) -> Result<()> {
+    let universal_nft = &ctx.accounts.universal_nft;
+    universal_nft.require_no_transfer_in_progress()?;
+    require!(
+        ctx.accounts.trait_authorities.is_authority(&ctx.accounts.authority.key()),
+        UniversalNftError::Unauthorized
+    );
+
+    let attributes = &mut ctx.accounts.nft_attributes;
+    attributes.mint = universal_nft.mint;
+    attributes.collection_mint = ctx.accounts.trait_authorities.collection_mint;
+    attributes.bump = ctx.bumps.nft_attributes;
+    attributes.set(key.clone(), value.clone())?;
+    attributes.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(NftAttributeSet {
+        mint: attributes.mint,
+        key,
+        value,
+        authority: ctx.accounts.authority.key(),
+        timestamp: attributes.updated_at,
+    });
+
+    Ok(())
+}
+
+/// Remove an attribute of an NFT (trait authority of its collection only)
+pub fn remove_nft_attribute(ctx: Context<RemoveNftAttribute>, key: String) -> Result<()> {
+    ctx.accounts.universal_nft.require_no_transfer_in_progress()?;
+    require!(
+        ctx.accounts.trait_authorities.is_authority(&ctx.accounts.authority.key()),
+        UniversalNftError::Unauthorized
+    );
+
+    let attributes = &mut ctx.accounts.nft_attributes;
+    attributes.remove(&key)?;
+    attributes.updated_at = Clock::get()?.unix_timestamp;
+
+    emit!(NftAttributeRemoved {
+        mint: attributes.mint,
+        key,
+        authority: ctx.accounts.authority.key(),
+        timestamp: attributes.updated_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetTraitAuthorities<'info> {
+    #[account(
+        seeds = [b"collection", collection.mint.as_ref()],
+        bump = collection.bump,
+        has_one = authority
+    )]
+    pub collection: Account<'info, UniversalCollection>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + TraitAuthorities::INIT_SPACE,
+        seeds = [b"trait_authorities", collection.mint.as_ref()],
+        bump
+    )]
+    pub trait_authorities: Account<'info, TraitAuthorities>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetNftAttribute<'info> {
+    #[account(
+        seeds = [b"universal_nft", universal_nft.mint.as_ref()],
+        bump = universal_nft.bump,
+        constraint = universal_nft.collection_mint == Some(trait_authorities.collection_mint)
+            @ UniversalNftError::CollectionVerificationFailed
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(
+        seeds = [b"trait_authorities", trait_authorities.collection_mint.as_ref()],
+        bump = trait_authorities.bump
+    )]
+    pub trait_authorities: Account<'info, TraitAuthorities>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + NftAttributes::INIT_SPACE,
+        seeds = [b"nft_attributes", universal_nft.mint.as_ref()],
+        bump
+    )]
+    pub nft_attributes: Account<'info, NftAttributes>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveNftAttribute<'info> {
+    #[account(
+        seeds = [b"universal_nft", universal_nft.mint.as_ref()],
+        bump = universal_nft.bump,
+        constraint = universal_nft.collection_mint == Some(trait_authorities.collection_mint)
+            @ UniversalNftError::CollectionVerificationFailed
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(
+        seeds = [b"trait_authorities", trait_authorities.collection_mint.as_ref()],
+        bump = trait_authorities.bump
+    )]
+    pub trait_authorities: Account<'info, TraitAuthorities>,
+
+    #[account(
+        mut,
+        seeds = [b"nft_attributes", universal_nft.mint.as_ref()],
+        bump = nft_attributes.bump
+    )]
+    pub nft_attributes: Account<'info, NftAttributes>,
+
+    pub authority: Signer<'info>,
+}
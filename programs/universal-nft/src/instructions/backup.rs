@@ -0,0 +1,211 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::events::*;
+use crate::recovery::backup_restore::*;
+use crate::recovery::state_recovery::StateCheckpoint;
+use crate::state::ProgramConfig;
+
+/// Export pages of a critical account as snapshot events for off-chain archival
+pub fn export_state_snapshot(
+    ctx: Context<ExportStateSnapshot>,
+    start_page: u32,
+    max_pages: u8,
+) -> Result<()> {
+    let target = &ctx.accounts.target;
+    BackupRestore::validate_backup_target(target)?;
+
+    require!(
+        max_pages > 0 && max_pages <= MAX_PAGES_PER_EXPORT,
+        UniversalNftError::InvalidSnapshotPage
+    );
+
+    let slot = Clock::get()?.slot;
+    let data = target.try_borrow_data()?;
+    let total_pages = BackupRestore::page_count(data.len());
+    let data_hash = BackupRestore::data_hash(&data);
+    let end_page = start_page.saturating_add(max_pages as u32).min(total_pages);
+
+    for page in start_page..end_page {
+        let (start, end) = BackupRestore::page_bounds(data.len(), page)?;
+        emit!(StateSnapshotPage {
+            account: target.key(),
+            owner: *target.owner,
+            slot,
+            page,
+            total_pages,
+            data_len: data.len() as u32,
+            data_hash,
+            data: data[start..end].to_vec(),
+        });
+    }
+
+    log!("Exported pages {}..{} of {} for {}", start_page, end_page, total_pages, target.key());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExportStateSnapshot<'info> {
+    /// CHECK: Validated against the critical account set
+    pub target: UncheckedAccount<'info>,
+}
+
+/// Open a restore of a critical account from checkpoint `checkpoint_id`
+/// (program authority only). Archived data is staged in a buffer and only
+/// written to the account once it matches the account's checkpoint leaf.
+pub fn begin_state_restore(
+    ctx: Context<BeginStateRestore>,
+    checkpoint_id: u64,
+    leaf_index: u8,
+) -> Result<()> {
+    let target = &ctx.accounts.target;
+    BackupRestore::validate_backup_target(target)?;
+    require!(
+        (leaf_index as usize) < ctx.accounts.checkpoint.account_leaves.len(),
+        UniversalNftError::InvalidCheckpointAccounts
+    );
+
+    let buffer = &mut ctx.accounts.buffer;
+    buffer.target = target.key();
+    buffer.checkpoint_id = checkpoint_id;
+    buffer.leaf_index = leaf_index;
+    buffer.authority = ctx.accounts.authority.key();
+    buffer.bump = ctx.bumps.buffer;
+    buffer.data = vec![0u8; target.data_len()];
+
+    log!("Restore of {} opened from checkpoint {}", target.key(), checkpoint_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(checkpoint_id: u64)]
+pub struct BeginStateRestore<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"state_checkpoint", checkpoint_id.to_le_bytes().as_ref()],
+        bump = checkpoint.bump
+    )]
+    pub checkpoint: Account<'info, StateCheckpoint>,
+
+    /// CHECK: Validated against the critical account set
+    pub target: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = RestoreBuffer::space(target.data_len()),
+        seeds = [b"restore_buffer", target.key().as_ref()],
+        bump
+    )]
+    pub buffer: Account<'info, RestoreBuffer>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Stage an archived chunk in an open restore buffer
+pub fn restore_state_chunk(
+    ctx: Context<RestoreStateChunk>,
+    offset: u32,
+    chunk: Vec<u8>,
+) -> Result<()> {
+    let buffer = &mut ctx.accounts.buffer;
+    BackupRestore::write_restore_chunk(&mut buffer.data, offset, &chunk)?;
+
+    emit!(StateRestoreChunkApplied {
+        account: buffer.target,
+        offset,
+        len: chunk.len() as u32,
+        authority: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RestoreStateChunk<'info> {
+    #[account(
+        mut,
+        seeds = [b"restore_buffer", buffer.target.as_ref()],
+        bump = buffer.bump,
+        has_one = authority
+    )]
+    pub buffer: Account<'info, RestoreBuffer>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Write the staged data into the target once it matches the target's leaf
+/// in the checkpoint, then close the buffer (program authority only)
+pub fn apply_state_restore(ctx: Context<ApplyStateRestore>) -> Result<()> {
+    let buffer = &ctx.accounts.buffer;
+    let target = &ctx.accounts.target;
+
+    require!(
+        buffer.data.len() == target.data_len(),
+        UniversalNftError::InvalidRestoreChunk
+    );
+    BackupRestore::verify_against_checkpoint(
+        target,
+        &buffer.data,
+        &ctx.accounts.checkpoint,
+        buffer.leaf_index,
+    )?;
+    target.try_borrow_mut_data()?.copy_from_slice(&buffer.data);
+
+    emit!(StateRestoreApplied {
+        account: target.key(),
+        checkpoint_id: buffer.checkpoint_id,
+        authority: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Restored {} from checkpoint {}", target.key(), buffer.checkpoint_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApplyStateRestore<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"restore_buffer", target.key().as_ref()],
+        bump = buffer.bump,
+        has_one = authority,
+        has_one = target
+    )]
+    pub buffer: Account<'info, RestoreBuffer>,
+
+    #[account(
+        seeds = [b"state_checkpoint", buffer.checkpoint_id.to_le_bytes().as_ref()],
+        bump = checkpoint.bump
+    )]
+    pub checkpoint: Account<'info, StateCheckpoint>,
+
+    /// CHECK: Bound to the buffer's target; its new data is checked against
+    /// the checkpoint leaf
+    #[account(mut)]
+    pub target: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,332 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use solana_program::program::invoke;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::*;
+use crate::events::*;
+use crate::hooks::HookUtils;
+use crate::instructions::cross_chain::create_gateway_call_instruction;
+
+/// Burn several NFTs and send them to one recipient in a single gateway call.
+///
+/// Each NFT is passed as remaining accounts
+/// `[universal_nft, mint, token_account, nft_children, vesting_position]`,
+/// followed by the collection hook registry, collection config and NFT
+/// attributes PDAs when the NFT belongs to a collection. Collections with
+/// bridge-out hooks or in lock mode, and NFTs with attributes, equipped
+/// children or an open vesting position, must use `burn_and_transfer` so
+/// each gets its own accounts.
+pub fn batch_burn_and_transfer<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchBurnAndTransfer<'info>>,
+    destination_chain_id: u64,
+    recipient: Vec<u8>,
+    gas_limit: u64,
+    referrer: Option<Pubkey>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    // Check if program is paused
+    require!(!config.is_paused, UniversalNftError::ProgramPaused);
+
+    // Validate parameters once for the whole batch
+    CrossChainUtils::validate_chain_id(destination_chain_id, &ctx.accounts.destination_chain)?;
+    ctx.accounts.destination_chain.require_valid_recipient(destination_chain_id, &recipient)?;
+    CrossChainUtils::validate_gas_limit(gas_limit)?;
+    CorridorPauseUtils::require_open(&ctx.accounts.corridor_pause, CorridorDirection::Outbound)?;
+
+    // The owner's own transfer lock
+    WalletLockUtils::require_unlocked(&ctx.accounts.wallet_lock)?;
+
+    // Floor values need one price account per item, so value-capped chains
+    // take single transfers only
+    BatchTransferUtils::require_uncapped(&ctx.accounts.destination_chain.limits)?;
+
+    let owner = ctx.accounts.owner.key();
+    let mut accounts = ctx.remaining_accounts.iter();
+    let mut mints: Vec<Pubkey> = Vec::new();
+    let mut items: Vec<BatchMintItem> = Vec::new();
+
+    while let Some(nft_info) = accounts.next() {
+        require!(
+            items.len() < BatchTransfer::MAX_BATCH_SIZE,
+            UniversalNftError::InvalidBatchSize
+        );
+        let mint_info = accounts.next().ok_or(UniversalNftError::InvalidBatchAccounts)?;
+        let token_info = accounts.next().ok_or(UniversalNftError::InvalidBatchAccounts)?;
+        let children_info = accounts.next().ok_or(UniversalNftError::InvalidBatchAccounts)?;
+        let vesting_info = accounts.next().ok_or(UniversalNftError::InvalidBatchAccounts)?;
+        require!(
+            nft_info.is_writable && mint_info.is_writable && token_info.is_writable,
+            UniversalNftError::InvalidBatchAccounts
+        );
+
+        let mut universal_nft = Account::<UniversalNft>::try_from(nft_info)?;
+        let mint = Account::<Mint>::try_from(mint_info)?;
+        let token_account = Account::<TokenAccount>::try_from(token_info)?;
+
+        BatchTransferUtils::require_transferable(
+            &universal_nft,
+            mint.key(),
+            token_account.mint,
+            token_account.owner,
+            owner,
+            &mints,
+        )?;
+        IncidentUtils::require_outbound(&ctx.accounts.incident_mode, &mint.key())?;
+
+        // Batch messages can't bundle children or carry vesting terms, and
+        // neither can be left behind
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"nft_children", mint.key().as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(children_info.key(), expected, UniversalNftError::InvalidBatchAccounts);
+        if let Some(nft_children) = NestingUtils::load_children(children_info)? {
+            require!(nft_children.children.is_empty(), UniversalNftError::ChildrenUnsupportedOnRoute);
+        }
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"vesting", mint.key().as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(vesting_info.key(), expected, UniversalNftError::InvalidBatchAccounts);
+        if let Some(terms) = VestingUtils::load_terms(vesting_info)? {
+            require!(terms.is_fully_claimed(), UniversalNftError::VestingUnsupportedOnRoute);
+        }
+
+        if universal_nft.collection_mint.is_some() {
+            let hooks_info = accounts.next().ok_or(UniversalNftError::MissingCollectionHooks)?;
+            let hooks = HookUtils::load_collection_hooks(
+                universal_nft.collection_mint,
+                Some(hooks_info),
+            )?;
+            let config_info = accounts.next().ok_or(UniversalNftError::MissingCollectionConfig)?;
+            let collection_config = CollectionConfigUtils::load_collection_config(
+                universal_nft.collection_mint,
+                Some(config_info),
+            )?;
+            BatchTransferUtils::require_batchable_collection(
+                hooks.as_ref(),
+                collection_config.as_ref(),
+                destination_chain_id,
+            )?;
+
+            // Batch messages have no attribute field, so attributes can't be dropped here
+            let attributes_info = accounts.next().ok_or(UniversalNftError::InvalidBatchAccounts)?;
+            let (expected, _) = Pubkey::find_program_address(
+                &[b"nft_attributes", mint.key().as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(attributes_info.key(), expected, UniversalNftError::InvalidBatchAccounts);
+            require!(
+                NftAttributeUtils::load_entries(attributes_info)?.is_empty(),
+                UniversalNftError::AttributesUnsupportedOnRoute
+            );
+        }
+
+        // Lock the NFT and persist before the next item is read
+        universal_nft.is_locked = true;
+        universal_nft.transfer_in_progress = true;
+        universal_nft.exit(ctx.program_id)?;
+
+        // Burn the token
+        let cpi_accounts = Burn {
+            mint: mint_info.clone(),
+            from: token_info.clone(),
+            authority: ctx.accounts.owner.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token::burn(cpi_ctx, 1)?;
+
+        mints.push(mint.key());
+        items.push(BatchTransferUtils::item(&universal_nft));
+    }
+    require!(!items.is_empty(), UniversalNftError::InvalidBatchSize);
+
+    // Every item counts against the chain's transfer cap
+    let destination_chain = &mut ctx.accounts.destination_chain;
+    destination_chain.volume = VolumeCapUtils::record(
+        &destination_chain.limits,
+        destination_chain.chain_id,
+        destination_chain.volume,
+        0,
+        items.len() as u32,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    // One nonce and one gas fee cover the whole batch; the batch keeps the
+    // nonce its PDA was derived from
+    let nonce = config.nonce;
+    config.nonce = config.nonce
+        .checked_add(1)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+    let gas_fee = GasFeeUtils::sol_gas_fee(gas_limit)?;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Protocol bridge fee on the batch's single gas fee, with any referrer's
+    // share
+    let referral = FeeUtils::referral(referrer, ctx.accounts.referral.as_deref(), &[owner])?;
+    if let Some(fee_schedule) = FeeUtils::load(&ctx.accounts.fee_schedule)? {
+        FeeUtils::charge(
+            &fee_schedule,
+            FeeKind::Bridge,
+            fee_schedule.bridge_fee(gas_fee)?,
+            &ctx.accounts.owner.to_account_info(),
+            ctx.accounts.fee_recipient.as_deref(),
+            &FeeDiscountAccounts {
+                governance_stake: ctx.accounts.governance_stake.as_deref(),
+                deployment_registry: ctx.accounts.deployment_registry.as_deref(),
+                membership_token_account: ctx.accounts.membership_token_account.as_deref(),
+                membership_nft: ctx.accounts.membership_nft.as_deref(),
+                rebate_campaign: ctx.accounts.rebate_campaign.as_deref(),
+            },
+            referral.as_ref(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+    }
+
+    let batch = &mut ctx.accounts.batch;
+    batch.owner = owner;
+    batch.destination_chain_id = destination_chain_id;
+    batch.recipient = recipient.clone();
+    batch.mints = mints;
+    batch.gas_limit = gas_limit;
+    batch.gas_fee = gas_fee;
+    batch.nonce = nonce;
+    batch.timestamp = now;
+    batch.status = TransferStatus::Initiated;
+    batch.bump = ctx.bumps.batch;
+
+    let recipient_key = ctx.accounts.destination_chain.encode_recipient(&recipient)?;
+    let cross_chain_msg = CrossChainMessage::MintNftBatch {
+        recipient: recipient_key,
+        items,
+    };
+    let message_data = MessageCodec::encode(&cross_chain_msg)?;
+
+    let gateway_call_ix = create_gateway_call_instruction(
+        ctx.accounts.gateway_program.key(),
+        destination_chain_id,
+        recipient,
+        message_data,
+        gas_limit,
+    )?;
+
+    invoke(
+        &gateway_call_ix,
+        &[
+            ctx.accounts.gateway_program.to_account_info(),
+            ctx.accounts.owner.to_account_info(),
+        ],
+    )?;
+
+    emit!(BatchTransferInitiated {
+        batch: batch.key(),
+        owner,
+        destination_chain_id,
+        count: batch.mints.len() as u8,
+        nonce: batch.nonce,
+        timestamp: now,
+    });
+
+    log!("Batch cross-chain transfer initiated");
+    log!("NFTs: {}", batch.mints.len());
+    log!("Destination chain: {}", destination_chain_id);
+    log!("Transfer nonce: {}", batch.nonce);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(destination_chain_id: u64)]
+pub struct BatchBurnAndTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Registry entry for the destination chain
+    #[account(
+        mut,
+        seeds = [b"chain", destination_chain_id.to_le_bytes().as_ref()],
+        bump = destination_chain.bump
+    )]
+    pub destination_chain: Account<'info, RegisteredChain>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + BatchTransfer::INIT_SPACE,
+        seeds = [b"batch_transfer", owner.key().as_ref(), config.nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub batch: Account<'info, BatchTransfer>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Owner's wallet lock PDA, read by WalletLockUtils
+    #[account(
+        seeds = [b"wallet_lock", owner.key().as_ref()],
+        bump
+    )]
+    pub wallet_lock: UncheckedAccount<'info>,
+
+    /// CHECK: Gateway program for cross-chain calls
+    pub gateway_program: UncheckedAccount<'info>,
+
+    /// CHECK: Protocol fee schedule PDA, read by FeeUtils
+    #[account(
+        seeds = [b"fee_schedule"],
+        bump
+    )]
+    pub fee_schedule: UncheckedAccount<'info>,
+
+    /// CHECK: Destination chain's corridor pause PDA, read by CorridorPauseUtils
+    #[account(
+        seeds = [b"corridor_pause", destination_chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub corridor_pause: UncheckedAccount<'info>,
+
+    /// CHECK: Incident mode PDA, read by IncidentUtils
+    #[account(
+        seeds = [b"incident_mode"],
+        bump
+    )]
+    pub incident_mode: UncheckedAccount<'info>,
+
+    /// CHECK: Must match the fee schedule's fee recipient
+    #[account(mut)]
+    pub fee_recipient: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Payer's governance stake, verified by FeeUtils for a fee discount
+    pub governance_stake: Option<UncheckedAccount<'info>>,
+
+    /// Registry naming the governance deployment, required with `governance_stake`
+    #[account(
+        seeds = [b"deployment_registry"],
+        bump = deployment_registry.bump
+    )]
+    pub deployment_registry: Option<Account<'info, DeploymentRegistry>>,
+
+    /// CHECK: Payer's token account holding a membership NFT, verified by FeeUtils
+    pub membership_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Universal NFT record of the membership NFT, verified by FeeUtils
+    pub membership_nft: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Rebate campaign PDA, verified by FeeUtils
+    #[account(mut)]
+    pub rebate_campaign: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Named referrer's accrual PDA, verified by FeeUtils
+    #[account(mut)]
+    pub referral: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
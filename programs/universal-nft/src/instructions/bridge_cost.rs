@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::*;
+
+/// Estimate what sending an NFT to `destination_chain_id` will cost, without
+/// changing any state. Fails the same way burn_and_transfer would for a
+/// disabled chain, a bad gas limit or an NFT that can't leave.
+pub fn estimate_bridge_cost(
+    ctx: Context<EstimateBridgeCost>,
+    destination_chain_id: u64,
+    gas_limit: u64,
+    gas_payment: GasPayment,
+) -> Result<BridgeCostEstimate> {
+    let destination_chain = &ctx.accounts.destination_chain;
+    require!(destination_chain.enabled, UniversalNftError::ChainDisabled);
+    CrossChainUtils::validate_gas_limit(gas_limit)?;
+
+    let universal_nft = &ctx.accounts.universal_nft;
+    universal_nft.require_no_transfer_in_progress()?;
+    require!(!universal_nft.is_locked, UniversalNftError::NftLocked);
+    require!(!universal_nft.is_disputed, UniversalNftError::NftUnderDispute);
+    require!(!universal_nft.is_frozen, UniversalNftError::NftFrozen);
+
+    let price_per_gas_unit = match (&gas_payment, &ctx.accounts.gas_token_config) {
+        (GasPayment::Zrc20, Some(gas_token)) => {
+            require!(gas_token.enabled, UniversalNftError::GasTokenDisabled);
+            require!(
+                gas_token.destination_chain_id == destination_chain_id,
+                UniversalNftError::GasTokenChainMismatch
+            );
+            Some(gas_token.price_per_gas_unit)
+        }
+        _ => None,
+    };
+
+    BridgeCostUtils::estimate(gas_limit, gas_payment, price_per_gas_unit, &Rent::get()?)
+}
+
+#[derive(Accounts)]
+#[instruction(destination_chain_id: u64)]
+pub struct EstimateBridgeCost<'info> {
+    /// Registry entry for the destination chain
+    #[account(
+        seeds = [b"chain", destination_chain_id.to_le_bytes().as_ref()],
+        bump = destination_chain.bump
+    )]
+    pub destination_chain: Account<'info, RegisteredChain>,
+
+    #[account(
+        seeds = [b"universal_nft", universal_nft.mint.as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    /// Registered ZRC-20 gas token, required when estimating ZRC-20 gas
+    pub gas_token_config: Option<Account<'info, GasTokenConfig>>,
+}
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Permanently burn an NFT and record it in the collection and wallet burn
+/// statistics. The Universal NFT account is closed to the owner.
+pub fn burn_nft(ctx: Context<BurnNft>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    require!(!config.is_paused, UniversalNftError::ProgramPaused);
+
+    let universal_nft = &ctx.accounts.universal_nft;
+    universal_nft.require_releasable_by(&ctx.accounts.owner.key())?;
+
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.mint.to_account_info(),
+        from: ctx.accounts.token_account.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::burn(cpi_ctx, 1)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let collection_mint = universal_nft.collection_mint.unwrap_or_default();
+
+    let user_stats = &mut ctx.accounts.user_burn_stats;
+    let first_burn = user_stats.record(
+        ctx.accounts.owner.key(),
+        collection_mint,
+        now,
+        ctx.bumps.user_burn_stats,
+    )?;
+
+    let collection_stats = &mut ctx.accounts.collection_burn_stats;
+    collection_stats.record(collection_mint, first_burn, now, ctx.bumps.collection_burn_stats)?;
+
+    emit!(NftBurned {
+        mint: ctx.accounts.mint.key(),
+        collection_mint: universal_nft.collection_mint,
+        owner: ctx.accounts.owner.key(),
+        user_total_burned: user_stats.total_burned,
+        collection_total_burned: collection_stats.total_burned,
+        timestamp: now,
+    });
+
+    log!("NFT burned: {}", ctx.accounts.mint.key());
+    log!("Wallet burns: {}", user_stats.total_burned);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BurnNft<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", mint.key().as_ref()],
+        bump = universal_nft.bump,
+        close = owner
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + CollectionBurnStats::INIT_SPACE,
+        seeds = [b"burn_stats", universal_nft.collection_mint.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub collection_burn_stats: Account<'info, CollectionBurnStats>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + UserBurnStats::INIT_SPACE,
+        seeds = [
+            b"user_burns",
+            universal_nft.collection_mint.unwrap_or_default().as_ref(),
+            owner.key().as_ref()
+        ],
+        bump
+    )]
+    pub user_burn_stats: Account<'info, UserBurnStats>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Return a collection's burn totals alongside one wallet's
+pub fn get_burn_stats(ctx: Context<GetBurnStats>) -> Result<BurnStatsView> {
+    let collection_stats = &ctx.accounts.collection_burn_stats;
+    let user_stats = &ctx.accounts.user_burn_stats;
+
+    Ok(BurnStatsView {
+        collection_mint: collection_stats.collection_mint,
+        collection_total_burned: collection_stats.total_burned,
+        unique_burners: collection_stats.unique_burners,
+        user: user_stats.user,
+        user_total_burned: user_stats.total_burned,
+    })
+}
+
+#[derive(Accounts)]
+pub struct GetBurnStats<'info> {
+    #[account(
+        seeds = [b"burn_stats", collection_burn_stats.collection_mint.as_ref()],
+        bump = collection_burn_stats.bump
+    )]
+    pub collection_burn_stats: Account<'info, CollectionBurnStats>,
+
+    #[account(
+        seeds = [b"user_burns", user_burn_stats.collection_mint.as_ref(), user_burn_stats.user.as_ref()],
+        bump = user_burn_stats.bump,
+        constraint = user_burn_stats.collection_mint == collection_burn_stats.collection_mint
+            @ UniversalNftError::InvalidBurnStats
+    )]
+    pub user_burn_stats: Account<'info, UserBurnStats>,
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::analytics::{ChainMetrics, ChainStatus};
+use crate::errors::*;
+use crate::state::*;
+
+/// Open the metrics account of a registered chain (authority only).
+/// Confirmations and reverts of transfers to the chain that pass it are
+/// sampled into its corridor statistics.
+pub fn open_chain_metrics(
+    ctx: Context<OpenChainMetrics>,
+    chain_id: u64,
+    chain_name: String,
+) -> Result<()> {
+    require!(
+        chain_name.len() <= ChainMetrics::MAX_NAME_LEN,
+        UniversalNftError::InvalidChainMetrics
+    );
+
+    let metrics = &mut ctx.accounts.chain_metrics;
+    metrics.chain_id = chain_id;
+    metrics.chain_name = chain_name;
+    metrics.transfers_to = 0;
+    metrics.transfers_from = 0;
+    metrics.avg_transfer_time_s = 0;
+    metrics.peak_transfer_time_s = 0;
+    metrics.failed_transfers = 0;
+    metrics.total_value_transferred = 0;
+    metrics.last_transfer = 0;
+    metrics.status = ChainStatus::Active;
+    metrics.outbound = CorridorStats::default();
+    metrics.bump = ctx.bumps.chain_metrics;
+
+    log!("Chain metrics opened: {}", chain_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct OpenChainMetrics<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"chain", chain_id.to_le_bytes().as_ref()],
+        bump = chain.bump
+    )]
+    pub chain: Account<'info, RegisteredChain>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ChainMetrics::INIT_SPACE,
+        seeds = [b"chain_metrics", chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub chain_metrics: Account<'info, ChainMetrics>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
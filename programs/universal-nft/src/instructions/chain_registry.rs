@@ -0,0 +1,271 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::bridge::BridgeAdapterUtils;
+
+/// Add a chain to the registry (authority only)
+pub fn register_chain(
+    ctx: Context<RegisterChain>,
+    chain_id: u64,
+    chain_kind: ChainKind,
+    address_format: AddressFormat,
+) -> Result<()> {
+    // The registry itself is the list of supported chains
+    require!(chain_id != 0, UniversalNftError::InvalidChainId);
+    require!(
+        AddressFormat::for_chain_kind(&chain_kind) == address_format,
+        UniversalNftError::AddressFormatMismatch
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let chain = &mut ctx.accounts.chain;
+    chain.chain_id = chain_id;
+    chain.chain_kind = chain_kind.clone();
+    chain.address_format = address_format;
+    chain.counterpart_contract = [0u8; 20];
+    chain.enabled = true;
+    chain.registered_at = now;
+    chain.limits = ChainTransferLimits::default();
+    chain.volume = RollingVolume::default();
+    chain.primary_route = BridgeRoute {
+        layer: MessageLayer::ZetaGateway,
+        remote_chain_id: chain_id,
+        peer: [0u8; 32],
+    };
+    chain.fallback_route = None;
+    chain.bump = ctx.bumps.chain;
+
+    emit!(ChainRegistryUpdated {
+        chain_id,
+        chain_kind,
+        enabled: true,
+        timestamp: now,
+    });
+
+    log!("Chain registered: {}", chain_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct RegisterChain<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RegisteredChain::INIT_SPACE,
+        seeds = [b"chain", chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub chain: Account<'info, RegisteredChain>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Enable or disable a registered chain (authority only)
+pub fn set_chain_enabled(ctx: Context<UpdateChain>, enabled: bool) -> Result<()> {
+    let chain = &mut ctx.accounts.chain;
+    chain.enabled = enabled;
+
+    emit!(ChainRegistryUpdated {
+        chain_id: chain.chain_id,
+        chain_kind: chain.chain_kind.clone(),
+        enabled,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Chain {} enabled: {}", chain.chain_id, enabled);
+
+    Ok(())
+}
+
+/// Set the Universal NFT counterpart contract on an EVM chain (authority only)
+pub fn set_chain_counterpart(ctx: Context<UpdateChain>, counterpart_contract: [u8; 20]) -> Result<()> {
+    let chain = &mut ctx.accounts.chain;
+    require!(
+        chain.address_format == AddressFormat::Evm,
+        UniversalNftError::AddressFormatMismatch
+    );
+
+    chain.counterpart_contract = counterpart_contract;
+
+    log!("Chain {} counterpart: {:?}", chain.chain_id, counterpart_contract);
+
+    Ok(())
+}
+
+/// Set the outbound value and count caps for a chain and whether they are
+/// enforced or only reported (authority only)
+pub fn set_chain_limits(ctx: Context<UpdateChain>, limits: ChainTransferLimits) -> Result<()> {
+    let chain = &mut ctx.accounts.chain;
+    chain.limits = limits;
+
+    emit!(ChainLimitsUpdated {
+        chain_id: chain.chain_id,
+        max_value_per_transfer: limits.max_value_per_transfer,
+        daily_value_cap: limits.daily_value_cap,
+        daily_transfer_cap: limits.daily_transfer_cap,
+        enforcement: limits.enforcement,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Chain {} limits updated", chain.chain_id);
+
+    Ok(())
+}
+
+/// Set the message layers a chain is reached through (authority only). The
+/// fallback lets senders keep bridging while the primary layer is degraded.
+pub fn set_chain_routes(
+    ctx: Context<UpdateChain>,
+    primary_route: BridgeRoute,
+    fallback_route: Option<BridgeRoute>,
+) -> Result<()> {
+    let chain = &mut ctx.accounts.chain;
+    BridgeAdapterUtils::validate_route(chain, &primary_route)?;
+    if let Some(fallback) = &fallback_route {
+        BridgeAdapterUtils::validate_route(chain, fallback)?;
+        require!(
+            fallback.layer != primary_route.layer,
+            UniversalNftError::InvalidBridgeRoute
+        );
+    }
+
+    chain.primary_route = primary_route;
+    chain.fallback_route = fallback_route;
+
+    emit!(ChainRoutesUpdated {
+        chain_id: chain.chain_id,
+        primary_layer: primary_route.layer,
+        fallback_layer: fallback_route.map(|r| r.layer),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Chain {} primary layer: {:?}", chain.chain_id, primary_route.layer);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateChain<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"chain", chain.chain_id.to_le_bytes().as_ref()],
+        bump = chain.bump
+    )]
+    pub chain: Account<'info, RegisteredChain>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Register the program a message layer sends through (authority only)
+pub fn register_message_layer(
+    ctx: Context<RegisterMessageLayer>,
+    layer: MessageLayer,
+    program_id: Pubkey,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let layer_config = &mut ctx.accounts.layer_config;
+    layer_config.layer = layer;
+    layer_config.program_id = program_id;
+    layer_config.operational = true;
+    layer_config.updated_at = now;
+    layer_config.bump = ctx.bumps.layer_config;
+
+    emit!(MessageLayerUpdated {
+        layer,
+        program_id,
+        operational: true,
+        timestamp: now,
+    });
+
+    log!("Message layer registered: {:?}", layer);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(layer: MessageLayer)]
+pub struct RegisterMessageLayer<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + MessageLayerConfig::INIT_SPACE,
+        seeds = [b"message_layer", MessageLayerConfig::seed(layer).as_ref()],
+        bump
+    )]
+    pub layer_config: Account<'info, MessageLayerConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Mark a message layer degraded or healthy again (authority only). Senders
+/// can't use a degraded layer and switch to their chain's fallback.
+pub fn set_message_layer_operational(
+    ctx: Context<UpdateMessageLayer>,
+    operational: bool,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let layer_config = &mut ctx.accounts.layer_config;
+    layer_config.operational = operational;
+    layer_config.updated_at = now;
+
+    emit!(MessageLayerUpdated {
+        layer: layer_config.layer,
+        program_id: layer_config.program_id,
+        operational,
+        timestamp: now,
+    });
+
+    log!("Message layer {:?} operational: {}", layer_config.layer, operational);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateMessageLayer<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"message_layer", MessageLayerConfig::seed(layer_config.layer).as_ref()],
+        bump = layer_config.bump
+    )]
+    pub layer_config: Account<'info, MessageLayerConfig>,
+
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::governance::treasury::Treasury;
+use crate::governance::{self, TREASURY_SEED};
+
+/// Replace the chain risk registry the fraud engine scores chain pairs with
+/// (treasury authority only). The first call creates it.
+pub fn configure_chain_risk(ctx: Context<ConfigureChainRisk>, params: ChainRiskParams) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.treasury.authority,
+        UniversalNftError::Unauthorized
+    );
+    ChainRiskRegistry::validate(&params)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let registry = &mut ctx.accounts.chain_risk_registry;
+    registry.chain_risks = params.chain_risks;
+    registry.pair_overrides = params.pair_overrides;
+    registry.updated_at = now;
+    registry.bump = ctx.bumps.chain_risk_registry;
+
+    emit!(ChainRiskUpdated {
+        chain_risks: registry.chain_risks.clone(),
+        pair_overrides: registry.pair_overrides.clone(),
+        timestamp: now,
+    });
+
+    log!(
+        "Chain risk registry updated: {} chains, {} pair overrides",
+        registry.chain_risks.len(),
+        registry.pair_overrides.len()
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ConfigureChainRisk<'info> {
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+        seeds::program = governance::ID
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ChainRiskRegistry::INIT_SPACE,
+        seeds = [b"chain_risk_registry"],
+        bump
+    )]
+    pub chain_risk_registry: Account<'info, ChainRiskRegistry>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::events::*;
+use crate::recovery::state_recovery::*;
+
+/// Create a state checkpoint committing to the critical account set.
+/// The critical accounts are passed as remaining accounts in
+/// `CHECKPOINT_ACCOUNT_SEEDS` order, followed by every registered chain PDA
+/// in ascending chain id order.
+pub fn create_state_checkpoint(
+    ctx: Context<CreateStateCheckpoint>,
+    checkpoint_type: CheckpointType,
+    state_metrics: StateMetrics,
+) -> Result<()> {
+    let manager = &mut ctx.accounts.manager;
+
+    require!(
+        ctx.accounts.authority.key() == manager.authority,
+        UniversalNftError::Unauthorized
+    );
+
+    let checkpoint = &mut ctx.accounts.checkpoint;
+    manager.create_checkpoint(
+        checkpoint,
+        checkpoint_type,
+        state_metrics,
+        ctx.remaining_accounts,
+    )?;
+    checkpoint.bump = ctx.bumps.checkpoint;
+
+    emit!(StateCheckpointCreated {
+        checkpoint_id: checkpoint.checkpoint_id,
+        state_root: checkpoint.state_hash,
+        accounts_committed: checkpoint.accounts_committed,
+        timestamp: checkpoint.created_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateStateCheckpoint<'info> {
+    #[account(
+        mut,
+        seeds = [b"state_recovery"],
+        bump = manager.bump
+    )]
+    pub manager: Account<'info, StateRecoveryManager>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + StateCheckpoint::INIT_SPACE,
+        seeds = [b"state_checkpoint", manager.total_checkpoints.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub checkpoint: Account<'info, StateCheckpoint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Verify the live critical account set against a stored checkpoint.
+/// Permissionless so auditors can check state commitments independently.
+pub fn verify_state_against_checkpoint(
+    ctx: Context<VerifyStateAgainstCheckpoint>,
+    checkpoint_id: u64,
+) -> Result<()> {
+    let checkpoint = &ctx.accounts.checkpoint;
+
+    let matches = StateRecoveryManager::verify_state_against_checkpoint(
+        checkpoint,
+        ctx.remaining_accounts,
+    )?;
+
+    emit!(StateCheckpointVerified {
+        checkpoint_id,
+        checkpoint_root: checkpoint.state_hash,
+        matches,
+        verifier: ctx.accounts.verifier.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Checkpoint {} verification: {}", checkpoint_id, if matches { "MATCH" } else { "MISMATCH" });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(checkpoint_id: u64)]
+pub struct VerifyStateAgainstCheckpoint<'info> {
+    #[account(
+        seeds = [b"state_checkpoint", checkpoint_id.to_le_bytes().as_ref()],
+        bump = checkpoint.bump
+    )]
+    pub checkpoint: Account<'info, StateCheckpoint>,
+
+    pub verifier: Signer<'info>,
+}
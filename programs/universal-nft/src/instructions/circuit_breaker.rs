@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::security::{CircuitBreaker, CircuitConfig};
+
+/// Put a circuit breaker in front of a registered chain (program authority
+/// only). Transfers to and from the chain are then admitted by the breaker;
+/// `None` takes the default thresholds.
+pub fn initialize_circuit_breaker(
+    ctx: Context<InitializeCircuitBreaker>,
+    chain_id: u64,
+    config: Option<CircuitConfig>,
+) -> Result<()> {
+    if let Some(config) = &config {
+        require!(
+            config.failure_threshold > 0
+                && config.failure_window > 0
+                && config.min_open_duration > 0
+                && config.probe_interval > 0,
+            UniversalNftError::InvalidCircuitBreaker
+        );
+    }
+
+    let breaker = &mut ctx.accounts.circuit_breaker;
+    breaker.initialize(
+        chain_id,
+        ctx.accounts.authority.key(),
+        config,
+        ctx.bumps.circuit_breaker,
+    );
+
+    log!("Circuit breaker initialized for chain {}", chain_id);
+    Ok(())
+}
+
+/// Force a chain's breaker to let all traffic through, or clear the override
+/// and close it (breaker authority only)
+pub fn set_circuit_breaker_override(
+    ctx: Context<SetCircuitBreakerOverride>,
+    enabled: bool,
+) -> Result<()> {
+    let breaker = &mut ctx.accounts.circuit_breaker;
+    breaker.set_manual_override(enabled)?;
+
+    log!("Circuit breaker override for chain {}: {}", breaker.chain_id, enabled);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct InitializeCircuitBreaker<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"chain", chain_id.to_le_bytes().as_ref()],
+        bump = chain.bump
+    )]
+    pub chain: Account<'info, RegisteredChain>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CircuitBreaker::INIT_SPACE,
+        seeds = [b"circuit_breaker", chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetCircuitBreakerOverride<'info> {
+    #[account(
+        mut,
+        seeds = [b"circuit_breaker", circuit_breaker.chain_id.to_le_bytes().as_ref()],
+        bump = circuit_breaker.bump,
+        has_one = authority
+    )]
+    pub circuit_breaker: Account<'info, CircuitBreaker>,
+
+    pub authority: Signer<'info>,
+}
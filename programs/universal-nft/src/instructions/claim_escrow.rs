@@ -0,0 +1,318 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::{ClaimEscrowUtils, WalletLockUtils};
+
+/// Move an NFT into custody until its recipient claims it. Minting straight
+/// into escrow is done by calling this after mint_nft in the same transaction.
+/// `claim_key` is the public key of a fresh keypair whose secret is handed to
+/// the recipient as the claim code.
+pub fn escrow_nft_for_claim(
+    ctx: Context<EscrowNftForClaim>,
+    claim_key: Pubkey,
+    claims_service: Option<Pubkey>,
+    expires_at: i64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    require!(!config.is_paused, UniversalNftError::ProgramPaused);
+
+    let now = Clock::get()?.unix_timestamp;
+    ClaimEscrowUtils::validate_expiry(expires_at, now)?;
+
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    universal_nft.require_no_transfer_in_progress()?;
+    require!(!universal_nft.is_locked, UniversalNftError::NftLocked);
+    require!(!universal_nft.is_disputed, UniversalNftError::NftUnderDispute);
+    require!(!universal_nft.is_frozen, UniversalNftError::NftFrozen);
+    require!(
+        universal_nft.owner == ctx.accounts.depositor.key(),
+        UniversalNftError::InvalidNftOwner
+    );
+
+    // The owner's own transfer lock
+    WalletLockUtils::require_unlocked(&ctx.accounts.wallet_lock)?;
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.depositor_token_account.to_account_info(),
+        to: ctx.accounts.custody_token_account.to_account_info(),
+        authority: ctx.accounts.depositor.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, 1)?;
+
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.claim_key = claim_key;
+    escrow.mint = ctx.accounts.mint.key();
+    escrow.depositor = ctx.accounts.depositor.key();
+    escrow.custody_token_account = ctx.accounts.custody_token_account.key();
+    escrow.claims_service = claims_service;
+    escrow.expires_at = expires_at;
+    escrow.created_at = now;
+    escrow.bump = ctx.bumps.escrow;
+
+    // The escrow owns the NFT until it is claimed or cancelled
+    universal_nft.owner = escrow.key();
+
+    emit!(NftEscrowedForClaim {
+        mint: escrow.mint,
+        claim_key,
+        depositor: escrow.depositor,
+        expires_at,
+        timestamp: now,
+    });
+
+    log!("NFT escrowed for claim: {}", escrow.mint);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(claim_key: Pubkey)]
+pub struct EscrowNftForClaim<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", mint.key().as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(
+        init,
+        payer = depositor,
+        space = 8 + ClaimEscrow::INIT_SPACE,
+        seeds = [b"claim_escrow", claim_key.as_ref()],
+        bump
+    )]
+    pub escrow: Account<'info, ClaimEscrow>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = depositor,
+    )]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        associated_token::mint = mint,
+        associated_token::authority = custody,
+    )]
+    pub custody_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that holds NFTs in program custody
+    #[account(
+        seeds = [b"nft_custody"],
+        bump
+    )]
+    pub custody: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    /// CHECK: Owner's wallet lock PDA, read by WalletLockUtils
+    #[account(
+        seeds = [b"wallet_lock", depositor.key().as_ref()],
+        bump
+    )]
+    pub wallet_lock: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Claim an escrowed NFT with the claim key signing, or with the claims
+/// service co-signing for the claimant. The signature covers the claimant, so
+/// a claim seen in flight can't be redirected to another wallet.
+pub fn claim_escrowed_nft(ctx: Context<ClaimEscrowedNft>) -> Result<()> {
+    let escrow = &ctx.accounts.escrow;
+    let claimant = ctx.accounts.claimant.key();
+
+    let now = Clock::get()?.unix_timestamp;
+    ClaimEscrowUtils::require_claimable(
+        escrow,
+        ctx.accounts.claim_signer.as_ref().map(|signer| signer.key()),
+        ctx.accounts.claims_service.as_ref().map(|service| service.key()),
+        now,
+    )?;
+
+    let custody_seeds = &[b"nft_custody".as_ref(), &[ctx.bumps.custody]];
+    let signer_seeds = &[&custody_seeds[..]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.custody_token_account.to_account_info(),
+        to: ctx.accounts.claimant_token_account.to_account_info(),
+        authority: ctx.accounts.custody.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::transfer(cpi_ctx, 1)?;
+
+    ctx.accounts.universal_nft.owner = claimant;
+
+    emit!(EscrowedNftClaimed {
+        mint: escrow.mint,
+        claim_key: escrow.claim_key,
+        claimant,
+        timestamp: now,
+    });
+
+    log!("Escrowed NFT claimed");
+    log!("Mint: {}", escrow.mint);
+    log!("Claimant: {}", claimant);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimEscrowedNft<'info> {
+    #[account(
+        mut,
+        seeds = [b"claim_escrow", escrow.claim_key.as_ref()],
+        bump = escrow.bump,
+        has_one = mint,
+        has_one = depositor,
+        has_one = custody_token_account,
+        close = depositor
+    )]
+    pub escrow: Account<'info, ClaimEscrow>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", mint.key().as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub custody_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that holds NFTs in program custody
+    #[account(
+        seeds = [b"nft_custody"],
+        bump
+    )]
+    pub custody: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        associated_token::mint = mint,
+        associated_token::authority = claimant,
+    )]
+    pub claimant_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Receives the escrow rent back, matched by has_one
+    #[account(mut)]
+    pub depositor: UncheckedAccount<'info>,
+
+    /// Claim keypair, restored from the claim code
+    pub claim_signer: Option<Signer<'info>>,
+
+    /// Claims service co-signing a claim made without the claim code
+    pub claims_service: Option<Signer<'info>>,
+
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Return an expired, unclaimed NFT to its depositor
+pub fn cancel_claim_escrow(ctx: Context<CancelClaimEscrow>) -> Result<()> {
+    let escrow = &ctx.accounts.escrow;
+
+    let now = Clock::get()?.unix_timestamp;
+    ClaimEscrowUtils::require_cancellable(escrow, now)?;
+
+    let custody_seeds = &[b"nft_custody".as_ref(), &[ctx.bumps.custody]];
+    let signer_seeds = &[&custody_seeds[..]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.custody_token_account.to_account_info(),
+        to: ctx.accounts.depositor_token_account.to_account_info(),
+        authority: ctx.accounts.custody.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::transfer(cpi_ctx, 1)?;
+
+    ctx.accounts.universal_nft.owner = escrow.depositor;
+
+    emit!(ClaimEscrowCancelled {
+        mint: escrow.mint,
+        claim_key: escrow.claim_key,
+        depositor: escrow.depositor,
+        timestamp: now,
+    });
+
+    log!("Claim escrow cancelled, NFT returned to {}", escrow.depositor);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelClaimEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [b"claim_escrow", escrow.claim_key.as_ref()],
+        bump = escrow.bump,
+        has_one = mint,
+        has_one = depositor,
+        has_one = custody_token_account,
+        close = depositor
+    )]
+    pub escrow: Account<'info, ClaimEscrow>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", mint.key().as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub custody_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that holds NFTs in program custody
+    #[account(
+        seeds = [b"nft_custody"],
+        bump
+    )]
+    pub custody: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = depositor,
+    )]
+    pub depositor_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Create or replace a collection's mint and bridging policy (collection
+/// authority only). Each allowed destination chain's registry entry is passed
+/// as a remaining account, in the same order.
+pub fn set_collection_config(
+    ctx: Context<SetCollectionConfig>,
+    params: CollectionConfigParams,
+) -> Result<()> {
+    require!(
+        params.allowed_destination_chains.len() <= CollectionConfig::MAX_ALLOWED_CHAINS,
+        UniversalNftError::InvalidCollectionConfig
+    );
+    require!(
+        params.seller_fee_basis_points <= 10_000,
+        UniversalNftError::InvalidCollectionConfig
+    );
+    CrossChainUtils::validate_registered_chain_ids(
+        &params.allowed_destination_chains,
+        ctx.remaining_accounts,
+    )?;
+
+    // The supply cap is fixed once set; reduce_max_supply can only lower it
+    let collection_config = &mut ctx.accounts.collection_config;
+    if collection_config.collection_mint == Pubkey::default() {
+        collection_config.collection_mint = ctx.accounts.collection.mint;
+        collection_config.max_supply = params.max_supply;
+        collection_config.minted_count = 0;
+        collection_config.phase_count = 0;
+        collection_config.phases_end = 0;
+    } else {
+        require!(
+            params.max_supply == collection_config.max_supply,
+            UniversalNftError::InvalidMaxSupply
+        );
+    }
+    collection_config.allowed_destination_chains = params.allowed_destination_chains;
+    collection_config.transfer_mode = params.transfer_mode;
+    collection_config.seller_fee_basis_points = params.seller_fee_basis_points;
+    collection_config.is_paused = params.is_paused;
+    collection_config.bump = ctx.bumps.collection_config;
+
+    emit!(CollectionConfigUpdated {
+        collection_mint: collection_config.collection_mint,
+        max_supply: collection_config.max_supply,
+        transfer_mode: collection_config.transfer_mode,
+        seller_fee_basis_points: collection_config.seller_fee_basis_points,
+        is_paused: collection_config.is_paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Collection config updated: {}", collection_config.collection_mint);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCollectionConfig<'info> {
+    #[account(
+        seeds = [b"collection", collection.mint.as_ref()],
+        bump = collection.bump,
+        has_one = authority
+    )]
+    pub collection: Account<'info, UniversalCollection>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CollectionConfig::INIT_SPACE,
+        seeds = [b"collection_config", collection.mint.as_ref()],
+        bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Lower a collection's supply cap. The cap can never be raised, so creators
+/// can commit to scarcity on-chain (collection authority only).
+pub fn reduce_max_supply(ctx: Context<ReduceMaxSupply>, new_max_supply: u64) -> Result<()> {
+    let collection_config = &mut ctx.accounts.collection_config;
+    let previous_max_supply = collection_config.max_supply;
+
+    // Zero means unlimited, so any cap is a reduction from it
+    require!(new_max_supply > 0, UniversalNftError::InvalidMaxSupply);
+    require!(
+        previous_max_supply == 0 || new_max_supply < previous_max_supply,
+        UniversalNftError::InvalidMaxSupply
+    );
+    require!(
+        new_max_supply >= collection_config.minted_count,
+        UniversalNftError::InvalidMaxSupply
+    );
+
+    collection_config.max_supply = new_max_supply;
+
+    emit!(MaxSupplyReduced {
+        collection_mint: collection_config.collection_mint,
+        previous_max_supply,
+        max_supply: new_max_supply,
+        minted_count: collection_config.minted_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Collection {} max supply: {} -> {}", collection_config.collection_mint, previous_max_supply, new_max_supply);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReduceMaxSupply<'info> {
+    #[account(
+        seeds = [b"collection", collection.mint.as_ref()],
+        bump = collection.bump,
+        has_one = authority
+    )]
+    pub collection: Account<'info, UniversalCollection>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection.mint.as_ref()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    pub authority: Signer<'info>,
+}
\ No newline at end of file
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::FloorPriceUtils;
+
+/// Set who prices a collection's floor value (authority only). Price-feed
+/// authorities post prices directly, and a price feed lets anyone sync the
+/// floor from it.
+pub fn configure_collection_floor(
+    ctx: Context<ConfigureCollectionFloor>,
+    collection_mint: Pubkey,
+    params: CollectionFloorParams,
+) -> Result<()> {
+    require!(
+        params.price_authorities.len() <= CollectionFloor::MAX_PRICE_AUTHORITIES,
+        UniversalNftError::TooManyPriceAuthorities
+    );
+    require!(params.max_staleness >= 0, UniversalNftError::InvalidCollectionConfig);
+
+    let floor = &mut ctx.accounts.collection_floor;
+    floor.collection_mint = collection_mint;
+    floor.price_authorities = params.price_authorities;
+    floor.price_feed = params.price_feed;
+    floor.max_staleness = params.max_staleness;
+    floor.bump = ctx.bumps.collection_floor;
+
+    emit!(CollectionFloorConfigured {
+        collection_mint,
+        price_authorities: floor.price_authorities.clone(),
+        price_feed: floor.price_feed,
+        max_staleness: floor.max_staleness,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Floor pricing configured for collection {}", collection_mint);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(collection_mint: Pubkey)]
+pub struct ConfigureCollectionFloor<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CollectionFloor::INIT_SPACE,
+        seeds = [b"collection_floor", collection_mint.as_ref()],
+        bump
+    )]
+    pub collection_floor: Account<'info, CollectionFloor>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Post a collection's floor value in lamports (price-feed authorities only)
+pub fn post_floor_price(ctx: Context<PostFloorPrice>, floor_value: u64) -> Result<()> {
+    let floor = &mut ctx.accounts.collection_floor;
+    require!(
+        floor.is_price_authority(&ctx.accounts.price_authority.key()),
+        UniversalNftError::UnauthorizedPriceOracle
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    floor.floor_value = floor_value;
+    floor.updated_at = now;
+
+    emit!(CollectionFloorUpdated {
+        collection_mint: floor.collection_mint,
+        floor_value,
+        timestamp: now,
+    });
+
+    log!("Collection {} floor: {} lamports", floor.collection_mint, floor_value);
+
+    Ok(())
+}
+
+/// Copy a collection's floor value from its price feed. Anyone may call
+/// this; feed prices older than the last posted one are ignored.
+pub fn sync_floor_price(ctx: Context<SyncFloorPrice>) -> Result<()> {
+    let (floor_value, published_at) =
+        FloorPriceUtils::parse_pyth_price(&ctx.accounts.price_feed.try_borrow_data()?)?;
+
+    let floor = &mut ctx.accounts.collection_floor;
+    require!(published_at > floor.updated_at, UniversalNftError::StaleFloorPrice);
+    floor.floor_value = floor_value;
+    floor.updated_at = published_at;
+
+    emit!(CollectionFloorUpdated {
+        collection_mint: floor.collection_mint,
+        floor_value,
+        timestamp: published_at,
+    });
+
+    log!("Collection {} floor synced: {} lamports", floor.collection_mint, floor_value);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PostFloorPrice<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection_floor", collection_floor.collection_mint.as_ref()],
+        bump = collection_floor.bump
+    )]
+    pub collection_floor: Account<'info, CollectionFloor>,
+
+    pub price_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SyncFloorPrice<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection_floor", collection_floor.collection_mint.as_ref()],
+        bump = collection_floor.bump
+    )]
+    pub collection_floor: Account<'info, CollectionFloor>,
+
+    /// CHECK: The collection's configured price feed, parsed by FloorPriceUtils
+    #[account(
+        address = collection_floor.price_feed @ UniversalNftError::InvalidPriceFeed,
+        constraint = collection_floor.price_feed != Pubkey::default() @ UniversalNftError::InvalidPriceFeed
+    )]
+    pub price_feed: UncheckedAccount<'info>,
+}
\ No newline at end of file
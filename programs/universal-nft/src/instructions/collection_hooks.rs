@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::hooks::HookUtils;
+
+/// Approve an external program as a lifecycle hook (authority only)
+pub fn whitelist_hook_program(
+    ctx: Context<WhitelistHookProgram>,
+    max_compute_units: u32,
+) -> Result<()> {
+    require!(
+        ctx.accounts.program.executable,
+        UniversalNftError::InvalidHookAccounts
+    );
+    require!(max_compute_units > 0, UniversalNftError::HookComputeExceeded);
+
+    let hook_program = &mut ctx.accounts.hook_program;
+    hook_program.program_id = ctx.accounts.program.key();
+    hook_program.max_compute_units = max_compute_units;
+    hook_program.approved_at = Clock::get()?.unix_timestamp;
+    hook_program.bump = ctx.bumps.hook_program;
+
+    log!("Hook program whitelisted: {}", hook_program.program_id);
+    log!("Max compute units: {}", max_compute_units);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WhitelistHookProgram<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + HookProgram::INIT_SPACE,
+        seeds = [b"hook_program", program.key().as_ref()],
+        bump
+    )]
+    pub hook_program: Account<'info, HookProgram>,
+
+    /// CHECK: Program being whitelisted, must be executable
+    pub program: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Register a whitelisted hook on a collection (collection authority only)
+pub fn add_collection_hook(
+    ctx: Context<AddCollectionHook>,
+    event_mask: u8,
+    compute_allowance: u32,
+    account_count: u8,
+) -> Result<()> {
+    let hook_program = &ctx.accounts.hook_program;
+    let hooks = &mut ctx.accounts.collection_hooks;
+    let entry = HookEntry {
+        program_id: hook_program.program_id,
+        event_mask,
+        compute_allowance,
+        account_count,
+    };
+
+    // Fresh registry created by this call
+    if hooks.collection_mint == Pubkey::default() {
+        hooks.collection_mint = ctx.accounts.collection.mint;
+        hooks.authority = ctx.accounts.authority.key();
+        hooks.hooks = Vec::new();
+        hooks.bump = ctx.bumps.collection_hooks;
+    }
+
+    HookUtils::validate_hook(hooks, hook_program, &entry)?;
+    hooks.hooks.push(entry);
+
+    emit!(CollectionHookUpdated {
+        collection_mint: hooks.collection_mint,
+        program_id: hook_program.program_id,
+        event_mask,
+        compute_allowance,
+        added: true,
+    });
+
+    log!("Hook added to collection {}", hooks.collection_mint);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddCollectionHook<'info> {
+    #[account(
+        seeds = [b"collection", collection.mint.as_ref()],
+        bump = collection.bump,
+        has_one = authority
+    )]
+    pub collection: Account<'info, UniversalCollection>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CollectionHooks::INIT_SPACE,
+        seeds = [b"collection_hooks", collection.mint.as_ref()],
+        bump
+    )]
+    pub collection_hooks: Account<'info, CollectionHooks>,
+
+    #[account(
+        seeds = [b"hook_program", hook_program.program_id.as_ref()],
+        bump = hook_program.bump
+    )]
+    pub hook_program: Account<'info, HookProgram>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Remove a hook from a collection (collection authority only)
+pub fn remove_collection_hook(ctx: Context<RemoveCollectionHook>, program_id: Pubkey) -> Result<()> {
+    let hooks = &mut ctx.accounts.collection_hooks;
+
+    let index = hooks.hooks
+        .iter()
+        .position(|h| h.program_id == program_id)
+        .ok_or(UniversalNftError::InvalidHookConfiguration)?;
+    let removed = hooks.hooks.remove(index);
+
+    emit!(CollectionHookUpdated {
+        collection_mint: hooks.collection_mint,
+        program_id,
+        event_mask: removed.event_mask,
+        compute_allowance: removed.compute_allowance,
+        added: false,
+    });
+
+    log!("Hook removed from collection {}", hooks.collection_mint);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveCollectionHook<'info> {
+    #[account(
+        mut,
+        seeds = [b"collection_hooks", collection_hooks.collection_mint.as_ref()],
+        bump = collection_hooks.bump,
+        has_one = authority
+    )]
+    pub collection_hooks: Account<'info, CollectionHooks>,
+
+    pub authority: Signer<'info>,
+}
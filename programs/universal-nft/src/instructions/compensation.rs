@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::*;
+use crate::events::*;
+use crate::governance::treasury::Treasury;
+use crate::governance::{self, TREASURY_SEED};
+use crate::recovery::compensation::*;
+use crate::recovery::error_recovery::RecoverySession;
+use crate::state::CrossChainTransfer;
+
+/// Create the compensation vault (treasury authority only)
+pub fn initialize_compensation_vault(
+    ctx: Context<InitializeCompensationVault>,
+    per_user_cap: u64,
+) -> Result<()> {
+    let treasury = &ctx.accounts.treasury;
+
+    require!(
+        ctx.accounts.authority.key() == treasury.authority,
+        UniversalNftError::Unauthorized
+    );
+
+    ctx.accounts.vault.initialize(
+        ctx.accounts.authority.key(),
+        treasury.key(),
+        per_user_cap,
+        ctx.bumps.vault,
+    )
+}
+
+#[derive(Accounts)]
+pub struct InitializeCompensationVault<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CompensationVault::INIT_SPACE,
+        seeds = [b"compensation_vault"],
+        bump
+    )]
+    pub vault: Account<'info, CompensationVault>,
+
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+        seeds::program = governance::ID
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Move lamports from the treasury SOL vault into the compensation vault
+pub fn fund_compensation_vault(ctx: Context<FundCompensationVault>, amount: u64) -> Result<()> {
+    // The governance program owns the treasury: it checks the authority and
+    // enforces the emergency reserve while booking the spend
+    let cpi_accounts = governance::cpi::accounts::RecordTreasurySpend {
+        treasury: ctx.accounts.treasury.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.governance_program.to_account_info(), cpi_accounts);
+    governance::cpi::record_treasury_spend(cpi_ctx, amount)?;
+
+    let cpi_accounts = system_program::Transfer {
+        from: ctx.accounts.sol_vault.to_account_info(),
+        to: ctx.accounts.vault.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, amount)?;
+
+    let vault = &mut ctx.accounts.vault;
+    vault.record_funding(amount)?;
+
+    emit!(CompensationVaultFunded {
+        vault: vault.key(),
+        treasury: ctx.accounts.treasury.key(),
+        amount,
+        total_funded: vault.total_funded,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundCompensationVault<'info> {
+    #[account(
+        mut,
+        seeds = [b"compensation_vault"],
+        bump = vault.bump,
+        has_one = treasury
+    )]
+    pub vault: Account<'info, CompensationVault>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+        seeds::program = governance::ID
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    /// Treasury SOL wallet the funds are drawn from
+    #[account(mut, address = treasury.sol_vault)]
+    pub sol_vault: Signer<'info>,
+
+    pub authority: Signer<'info>,
+
+    pub governance_program: Program<'info, governance::program::UniversalNftGovernance>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Refund the destination gas of a transfer whose recovery session failed to
+/// the wallet that paid it
+pub fn claim_compensation(ctx: Context<ClaimCompensation>) -> Result<()> {
+    let claimant = ctx.accounts.claimant.key();
+    let transfer = &ctx.accounts.transfer;
+    let vault = &mut ctx.accounts.vault;
+    let ledger = &mut ctx.accounts.ledger;
+
+    // Fresh ledger created by this claim
+    if ledger.user == Pubkey::default() {
+        ledger.user = claimant;
+        ledger.total_claimed = 0;
+        ledger.claim_count = 0;
+        ledger.last_claim_at = 0;
+        ledger.bump = ctx.bumps.ledger;
+    }
+
+    let (compensation_type, amount) = vault.validate_claim(transfer, &ctx.accounts.recovery_session, ledger, claimant)?;
+
+    // Keep the vault rent exempt after the payout
+    let vault_info = vault.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(vault_info.data_len());
+    let available = vault_info.lamports().saturating_sub(rent_exempt_minimum);
+    require!(amount <= available, UniversalNftError::InsufficientVaultFunds);
+
+    **vault_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.claimant.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    vault.record_claim(ledger, amount)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let receipt = &mut ctx.accounts.receipt;
+    receipt.transfer = transfer.key();
+    receipt.user = claimant;
+    receipt.compensation_type = compensation_type;
+    receipt.amount = amount;
+    receipt.claimed_at = now;
+    receipt.bump = ctx.bumps.receipt;
+
+    emit!(CompensationClaimed {
+        vault: vault.key(),
+        user: claimant,
+        transfer: transfer.key(),
+        amount,
+        user_total_claimed: ledger.total_claimed,
+        timestamp: now,
+    });
+
+    log!("Compensation claimed for transfer {}: {} lamports", transfer.nonce, amount);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimCompensation<'info> {
+    #[account(
+        mut,
+        seeds = [b"compensation_vault"],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, CompensationVault>,
+
+    /// Failed transfer whose gas is refunded
+    pub transfer: Account<'info, CrossChainTransfer>,
+
+    /// Recovery session opened for the transfer
+    #[account(
+        seeds = [b"recovery_session", transfer.key().as_ref()],
+        bump = recovery_session.bump
+    )]
+    pub recovery_session: Account<'info, RecoverySession>,
+
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        space = 8 + UserCompensationLedger::INIT_SPACE,
+        seeds = [b"compensation_ledger", claimant.key().as_ref()],
+        bump
+    )]
+    pub ledger: Account<'info, UserCompensationLedger>,
+
+    /// One receipt per transfer prevents double claims
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + CompensationReceipt::INIT_SPACE,
+        seeds = [b"compensation_receipt", transfer.key().as_ref()],
+        bump
+    )]
+    pub receipt: Account<'info, CompensationReceipt>,
+
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
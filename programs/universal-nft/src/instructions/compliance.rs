@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, FreezeAccount, Mint, ThawAccount, Token, TokenAccount};
+
+use crate::state::*;
+use crate::events::*;
+
+/// Create the compliance config and assign the compliance officer (program authority only)
+pub fn initialize_compliance_config(
+    ctx: Context<InitializeComplianceConfig>,
+    compliance_officer: Pubkey,
+) -> Result<()> {
+    let compliance = &mut ctx.accounts.compliance_config;
+    compliance.authority = ctx.accounts.authority.key();
+    compliance.compliance_officer = compliance_officer;
+    compliance.frozen_count = 0;
+    compliance.bump = ctx.bumps.compliance_config;
+
+    log!("Compliance config initialized");
+    log!("Compliance officer: {}", compliance_officer);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeComplianceConfig<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ComplianceConfig::INIT_SPACE,
+        seeds = [b"compliance_config"],
+        bump
+    )]
+    pub compliance_config: Account<'info, ComplianceConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Replace the compliance officer (program authority only)
+pub fn set_compliance_officer(
+    ctx: Context<SetComplianceOfficer>,
+    compliance_officer: Pubkey,
+) -> Result<()> {
+    ctx.accounts.compliance_config.compliance_officer = compliance_officer;
+
+    log!("Compliance officer updated: {}", compliance_officer);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetComplianceOfficer<'info> {
+    #[account(
+        mut,
+        seeds = [b"compliance_config"],
+        bump = compliance_config.bump,
+        has_one = authority
+    )]
+    pub compliance_config: Account<'info, ComplianceConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Place an NFT under legal hold by freezing its token account
+pub fn freeze_nft(ctx: Context<ComplianceAction>, reason_hash: [u8; 32]) -> Result<()> {
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    ctx.accounts.compliance_config.record_freeze(universal_nft, ctx.accounts.token_account.amount)?;
+
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[b"universal_nft".as_ref(), mint_key.as_ref(), &[universal_nft.bump]];
+    let signer = &[&seeds[..]];
+
+    // The universal NFT PDA is the mint's freeze authority
+    let cpi_accounts = FreezeAccount {
+        account: ctx.accounts.token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        authority: universal_nft.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    token::freeze_account(cpi_ctx)?;
+
+    emit!(NftComplianceFreezeChanged {
+        mint: mint_key,
+        token_account: ctx.accounts.token_account.key(),
+        officer: ctx.accounts.compliance_officer.key(),
+        frozen: true,
+        reason_hash,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("NFT frozen under legal hold");
+    log!("Mint: {}", mint_key);
+
+    Ok(())
+}
+
+/// Release an NFT from legal hold by thawing its token account
+pub fn unfreeze_nft(ctx: Context<ComplianceAction>, reason_hash: [u8; 32]) -> Result<()> {
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    ctx.accounts.compliance_config.record_release(universal_nft, ctx.accounts.token_account.amount)?;
+
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[b"universal_nft".as_ref(), mint_key.as_ref(), &[universal_nft.bump]];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = ThawAccount {
+        account: ctx.accounts.token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        authority: universal_nft.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer,
+    );
+    token::thaw_account(cpi_ctx)?;
+
+    emit!(NftComplianceFreezeChanged {
+        mint: mint_key,
+        token_account: ctx.accounts.token_account.key(),
+        officer: ctx.accounts.compliance_officer.key(),
+        frozen: false,
+        reason_hash,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("NFT released from legal hold");
+    log!("Mint: {}", mint_key);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ComplianceAction<'info> {
+    #[account(
+        mut,
+        seeds = [b"compliance_config"],
+        bump = compliance_config.bump,
+        has_one = compliance_officer
+    )]
+    pub compliance_config: Account<'info, ComplianceConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", mint.key().as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// Token account currently holding the NFT
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    pub compliance_officer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
\ No newline at end of file
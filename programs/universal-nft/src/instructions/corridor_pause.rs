@@ -0,0 +1,192 @@
+use anchor_lang::prelude::*;
+use solana_program::program::invoke;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::{CorridorPauseUtils, CrossChainUtils, MessageCodec, SignatureUtils};
+use crate::instructions::cross_chain::create_gateway_call_instruction;
+use crate::instructions::signature::pubkey_to_eth_address;
+
+/// Apply a TSS-signed `CorridorPause` admin message from `source_chain_id`,
+/// so an incident response on the other side halts the Solana side too.
+/// Anyone may submit it; each message applies once and in nonce order.
+pub fn apply_corridor_pause(
+    ctx: Context<ApplyCorridorPause>,
+    chain_id: u64,
+    source_chain_id: u64,
+    message: Vec<u8>,
+    signature: [u8; 64],
+    recovery_id: u8,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    require!(
+        config.tss_authority != Pubkey::default(),
+        UniversalNftError::InvalidTssSignature
+    );
+    CrossChainUtils::validate_chain_id(source_chain_id, &ctx.accounts.source_chain)?;
+
+    let (nonce, direction, paused) = match MessageCodec::decode(&message)? {
+        CrossChainMessage::CorridorPause { nonce, chain_id: target, direction, paused } if target == chain_id => {
+            (nonce, direction, paused)
+        }
+        _ => return Err(UniversalNftError::InvalidCorridorPause.into()),
+    };
+
+    let is_valid = SignatureUtils::verify_ecdsa_signature(
+        &CorridorPauseUtils::signing_hash(source_chain_id, nonce, &message),
+        &signature,
+        recovery_id,
+        &pubkey_to_eth_address(&config.tss_authority),
+    )?;
+    require!(is_valid, UniversalNftError::InvalidTssSignature);
+
+    let pause = &mut ctx.accounts.corridor_pause;
+    require!(nonce > pause.last_admin_nonce, UniversalNftError::InvalidCorridorPause);
+
+    let now = Clock::get()?.unix_timestamp;
+    pause.chain_id = chain_id;
+    pause.last_admin_nonce = nonce;
+    pause.bump = ctx.bumps.corridor_pause;
+    pause.set(direction, paused, now);
+
+    emit!(CorridorPauseChanged {
+        chain_id,
+        direction,
+        paused,
+        inbound_paused: pause.inbound_paused,
+        outbound_paused: pause.outbound_paused,
+        source_chain_id,
+        nonce,
+        timestamp: now,
+    });
+
+    log!("Corridor {} {:?} paused: {} (admin nonce {})", chain_id, direction, paused, nonce);
+    Ok(())
+}
+
+/// Pause or resume a corridor on Solana and ask the chain's counterpart
+/// contract to do the same on its side (program authority only)
+pub fn request_corridor_pause(
+    ctx: Context<RequestCorridorPause>,
+    chain_id: u64,
+    direction: CorridorDirection,
+    paused: bool,
+) -> Result<()> {
+    let chain = &ctx.accounts.chain;
+    require!(
+        chain.counterpart_contract != [0u8; 20],
+        UniversalNftError::InvalidCorridorPause
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let pause = &mut ctx.accounts.corridor_pause;
+    pause.chain_id = chain_id;
+    pause.bump = ctx.bumps.corridor_pause;
+    pause.set(direction, paused, now);
+
+    let nonce = pause.requests_sent;
+    let message = CrossChainMessage::CorridorPauseRequested {
+        nonce,
+        chain_id,
+        direction,
+        paused,
+    };
+    let gateway_call_ix = create_gateway_call_instruction(
+        ctx.accounts.gateway_program.key(),
+        chain_id,
+        chain.counterpart_contract.to_vec(),
+        MessageCodec::encode(&message)?,
+        CorridorPause::REQUEST_GAS_LIMIT,
+    )?;
+    invoke(
+        &gateway_call_ix,
+        &[
+            ctx.accounts.gateway_program.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+        ],
+    )?;
+    pause.requests_sent = nonce
+        .checked_add(1)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    emit!(CorridorPauseChanged {
+        chain_id,
+        direction,
+        paused,
+        inbound_paused: pause.inbound_paused,
+        outbound_paused: pause.outbound_paused,
+        source_chain_id: 0,
+        nonce,
+        timestamp: now,
+    });
+
+    log!("Corridor {} {:?} paused: {}, request {} sent", chain_id, direction, paused, nonce);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64, source_chain_id: u64)]
+pub struct ApplyCorridorPause<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CorridorPause::INIT_SPACE,
+        seeds = [b"corridor_pause", chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub corridor_pause: Account<'info, CorridorPause>,
+
+    /// Registry entry for the chain that signed the pause
+    #[account(
+        seeds = [b"chain", source_chain_id.to_le_bytes().as_ref()],
+        bump = source_chain.bump
+    )]
+    pub source_chain: Account<'info, RegisteredChain>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct RequestCorridorPause<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"chain", chain_id.to_le_bytes().as_ref()],
+        bump = chain.bump
+    )]
+    pub chain: Account<'info, RegisteredChain>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CorridorPause::INIT_SPACE,
+        seeds = [b"corridor_pause", chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub corridor_pause: Account<'info, CorridorPause>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Gateway program for pause requests
+    #[account(address = config.gateway_authority)]
+    pub gateway_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
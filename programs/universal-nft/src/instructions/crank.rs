@@ -0,0 +1,306 @@
+use anchor_lang::prelude::*;
+use solana_program::compute_units::sol_remaining_compute_units;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::recovery::state_recovery::*;
+use crate::recovery::transaction_retry::{RetryQueue, RetrySession, RetrySessionStatus, TransactionRetryManager};
+
+// Cranks take only fixed PDAs or accounts known when the automation thread is
+// created, plus a payer where rent is needed, so a thread can replay the same
+// instruction on a schedule. A crank that has nothing to do fails with
+// CrankNotDue and changes nothing. expire_dispute is already signerless and
+// can be scheduled as-is; proposals are cranked by the governance program.
+// process_due_retries is permissionless, so any keeper can drain the retry
+// queue.
+
+/// Register or replace the automation thread allowed to run the privileged
+/// cranks (program authority only)
+pub fn register_crank_operator(ctx: Context<RegisterCrankOperator>, operator: Pubkey) -> Result<()> {
+    let crank_config = &mut ctx.accounts.crank_config;
+    crank_config.operator = operator;
+    crank_config.bump = ctx.bumps.crank_config;
+
+    emit!(CrankOperatorRegistered {
+        operator,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Crank operator registered: {}", operator);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterCrankOperator<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CrankConfig::INIT_SPACE,
+        seeds = [b"crank_config"],
+        bump
+    )]
+    pub crank_config: Account<'info, CrankConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Take a periodic checkpoint once the manager's interval has elapsed. The
+/// critical accounts are passed as remaining accounts, as for
+/// create_state_checkpoint.
+pub fn crank_state_checkpoint(
+    ctx: Context<CrankStateCheckpoint>,
+    state_metrics: StateMetrics,
+) -> Result<()> {
+    let manager = &mut ctx.accounts.manager;
+    require!(manager.should_create_checkpoint(), UniversalNftError::CrankNotDue);
+
+    let checkpoint = &mut ctx.accounts.checkpoint;
+    manager.create_checkpoint(
+        checkpoint,
+        CheckpointType::Periodic,
+        state_metrics,
+        ctx.remaining_accounts,
+    )?;
+    checkpoint.bump = ctx.bumps.checkpoint;
+
+    emit!(StateCheckpointCreated {
+        checkpoint_id: checkpoint.checkpoint_id,
+        state_root: checkpoint.state_hash,
+        accounts_committed: checkpoint.accounts_committed,
+        timestamp: checkpoint.created_at,
+    });
+    emit!(CrankExecuted {
+        crank: CrankKind::StateCheckpoint,
+        target: checkpoint.key(),
+        caller: ctx.accounts.operator.key(),
+        timestamp: checkpoint.created_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CrankStateCheckpoint<'info> {
+    #[account(
+        seeds = [b"crank_config"],
+        bump = crank_config.bump,
+        has_one = operator @ UniversalNftError::InvalidCrankOperator
+    )]
+    pub crank_config: Account<'info, CrankConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"state_recovery"],
+        bump = manager.bump
+    )]
+    pub manager: Account<'info, StateRecoveryManager>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + StateCheckpoint::INIT_SPACE,
+        seeds = [b"state_checkpoint", manager.total_checkpoints.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub checkpoint: Account<'info, StateCheckpoint>,
+
+    pub operator: Signer<'info>,
+
+    /// Pays the checkpoint rent; the thread's fee payer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Start a retry session's next attempt once its backoff has elapsed, for the
+/// off-chain retrier to resubmit
+pub fn crank_retry_session(ctx: Context<CrankRetrySession>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let accounts = &mut *ctx.accounts;
+    require!(
+        accounts.retry_session.status == RetrySessionStatus::Scheduled
+            && now >= accounts.retry_session.next_retry_at,
+        UniversalNftError::CrankNotDue
+    );
+
+    let session_key = accounts.retry_session.key();
+    accounts.retry_manager.start_retry_attempt(
+        &mut accounts.retry_session,
+        &session_key,
+        &mut accounts.retry_queue,
+    )?;
+    emit_retry_attempt_started(&accounts.retry_session, session_key, now);
+
+    emit!(CrankExecuted {
+        crank: CrankKind::RetryAttempt,
+        target: accounts.retry_session.key(),
+        caller: accounts.operator.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CrankRetrySession<'info> {
+    #[account(
+        seeds = [b"crank_config"],
+        bump = crank_config.bump,
+        has_one = operator @ UniversalNftError::InvalidCrankOperator
+    )]
+    pub crank_config: Account<'info, CrankConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"retry_manager"],
+        bump = retry_manager.bump
+    )]
+    pub retry_manager: Account<'info, TransactionRetryManager>,
+
+    #[account(
+        mut,
+        seeds = [b"retry_session", retry_session.session_id.to_le_bytes().as_ref()],
+        bump = retry_session.bump,
+        has_one = retry_manager
+    )]
+    pub retry_session: Account<'info, RetrySession>,
+
+    #[account(
+        mut,
+        seeds = [b"retry_queue"],
+        bump = retry_queue.bump
+    )]
+    pub retry_queue: Account<'info, RetryQueue>,
+
+    pub operator: Signer<'info>,
+}
+
+/// Create the queue retry sessions wait in for their next attempt (program
+/// authority only)
+pub fn initialize_retry_queue(ctx: Context<InitializeRetryQueue>) -> Result<()> {
+    let retry_queue = &mut ctx.accounts.retry_queue;
+    retry_queue.entries = Vec::new();
+    retry_queue.bump = ctx.bumps.retry_queue;
+
+    log!("Retry queue initialized");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeRetryQueue<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RetryQueue::INIT_SPACE,
+        seeds = [b"retry_queue"],
+        bump
+    )]
+    pub retry_queue: Account<'info, RetryQueue>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Start due retry sessions' attempts, most overdue first, until none is due,
+/// the next due session isn't among the remaining accounts, or the compute
+/// left wouldn't cover another one. Queue entries for sessions that were
+/// cancelled or already finished are dropped. Permissionless.
+pub fn process_due_retries<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ProcessDueRetries<'info>>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let accounts = &mut *ctx.accounts;
+    let mut progressed = false;
+
+    while sol_remaining_compute_units() >= RetryQueue::COMPUTE_PER_RETRY {
+        let entry = match accounts.retry_queue.peek_due(now) {
+            Some(entry) => entry,
+            None => break,
+        };
+        let session_info = match ctx.remaining_accounts.iter().find(|a| a.key() == entry.session) {
+            Some(info) => info,
+            None => break,
+        };
+
+        let mut session = Account::<RetrySession>::try_from(session_info)?;
+        require_keys_eq!(
+            session.retry_manager,
+            accounts.retry_manager.key(),
+            UniversalNftError::InvalidRetrySession
+        );
+        progressed = true;
+        if session.status != RetrySessionStatus::Scheduled || session.next_retry_at != entry.due_at {
+            accounts.retry_queue.pop();
+            continue;
+        }
+
+        accounts.retry_manager.start_retry_attempt(&mut session, &entry.session, &mut accounts.retry_queue)?;
+        session.exit(ctx.program_id)?;
+        emit_retry_attempt_started(&session, entry.session, now);
+
+        emit!(CrankExecuted {
+            crank: CrankKind::RetryAttempt,
+            target: entry.session,
+            caller: accounts.caller.key(),
+            timestamp: now,
+        });
+    }
+
+    require!(progressed, UniversalNftError::CrankNotDue);
+    Ok(())
+}
+
+/// Tell the retrier which transaction to resubmit, and with what compute
+/// unit limit and priority fee
+fn emit_retry_attempt_started(session: &RetrySession, session_key: Pubkey, now: i64) {
+    emit!(RetryAttemptStarted {
+        session: session_key,
+        session_id: session.session_id,
+        attempt: session.current_attempt,
+        original_tx_signature: session.original_tx_signature.clone(),
+        compute_unit_limit: session.compute_unit_limit,
+        priority_fee_micro_lamports: session.priority_fee_micro_lamports,
+        timestamp: now,
+    });
+}
+
+#[derive(Accounts)]
+pub struct ProcessDueRetries<'info> {
+    #[account(
+        mut,
+        seeds = [b"retry_queue"],
+        bump = retry_queue.bump
+    )]
+    pub retry_queue: Account<'info, RetryQueue>,
+
+    #[account(
+        mut,
+        seeds = [b"retry_manager"],
+        bump = retry_manager.bump
+    )]
+    pub retry_manager: Account<'info, TransactionRetryManager>,
+
+    pub caller: Signer<'info>,
+}
@@ -0,0 +1,277 @@
+use anchor_lang::prelude::*;
+use solana_program::program::invoke;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::{CrossChainUtils, MessageCodec, MetadataUtils};
+use crate::instructions::cross_chain::create_gateway_call_instruction;
+
+/// Set a collection's credential issuers and where revocations are sent
+/// (collection authority only). A revocation chain's registry entry is the
+/// first remaining account.
+pub fn configure_credentials(
+    ctx: Context<ConfigureCredentials>,
+    params: CredentialProfileParams,
+) -> Result<()> {
+    require!(
+        params.issuers.len() <= CredentialProfile::MAX_ISSUERS,
+        UniversalNftError::TooManyCredentialIssuers
+    );
+    if params.revocation_chain_id != 0 {
+        CrossChainUtils::validate_registered_chain_ids(&[params.revocation_chain_id], ctx.remaining_accounts)?;
+        require!(
+            params.revocation_receiver != [0u8; 20],
+            UniversalNftError::InvalidRevocationDestination
+        );
+    }
+
+    let profile = &mut ctx.accounts.credential_profile;
+    profile.collection_mint = ctx.accounts.collection.mint;
+    profile.issuers = params.issuers;
+    profile.revocation_chain_id = params.revocation_chain_id;
+    profile.revocation_receiver = params.revocation_receiver;
+    profile.bump = ctx.bumps.credential_profile;
+
+    emit!(CredentialsConfigured {
+        collection_mint: profile.collection_mint,
+        issuers: profile.issuers.clone(),
+        revocation_chain_id: profile.revocation_chain_id,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Credentials configured: {}", profile.collection_mint);
+    Ok(())
+}
+
+/// Issue a soulbound credential about `subject` (issuers only). Credentials
+/// take the profile's next ID; `expires_at` of 0 never expires.
+pub fn issue_credential(
+    ctx: Context<IssueCredential>,
+    subject: Pubkey,
+    schema_hash: [u8; 32],
+    uri: String,
+    expires_at: i64,
+) -> Result<()> {
+    let issuer = ctx.accounts.issuer.key();
+    let profile = &mut ctx.accounts.credential_profile;
+    require!(profile.is_issuer(&issuer), UniversalNftError::Unauthorized);
+    MetadataUtils::validate_uri(&uri)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(expires_at == 0 || expires_at > now, UniversalNftError::InvalidCredential);
+
+    let credential = &mut ctx.accounts.credential;
+    credential.collection_mint = profile.collection_mint;
+    credential.credential_id = profile.issued_count;
+    credential.subject = subject;
+    credential.issuer = issuer;
+    credential.schema_hash = schema_hash;
+    credential.uri = uri;
+    credential.issued_at = now;
+    credential.expires_at = expires_at;
+    credential.bump = ctx.bumps.credential;
+    profile.issued_count += 1;
+
+    emit!(CredentialIssued {
+        collection_mint: credential.collection_mint,
+        credential_id: credential.credential_id,
+        subject,
+        issuer,
+        schema_hash,
+        expires_at,
+        timestamp: now,
+    });
+
+    log!("Credential {} issued", credential.credential_id);
+    Ok(())
+}
+
+/// Revoke a credential by writing its revocation registry entry (issuers
+/// only). When the profile names a revocation chain, the revocation is sent
+/// there too.
+pub fn revoke_credential(ctx: Context<RevokeCredential>, reason: u16) -> Result<()> {
+    let issuer = ctx.accounts.issuer.key();
+    let profile = &ctx.accounts.credential_profile;
+    require!(profile.is_issuer(&issuer), UniversalNftError::Unauthorized);
+
+    let now = Clock::get()?.unix_timestamp;
+    let credential = &ctx.accounts.credential;
+    let revocation = &mut ctx.accounts.revocation;
+    revocation.credential = credential.key();
+    revocation.revoked_by = issuer;
+    revocation.reason = reason;
+    revocation.revoked_at = now;
+    revocation.bump = ctx.bumps.revocation;
+
+    if profile.revocation_chain_id != 0 {
+        let message = CrossChainMessage::CredentialRevoked {
+            collection_mint: credential.collection_mint,
+            credential_id: credential.credential_id,
+            subject: credential.subject,
+            revoked_at: now,
+        };
+        let gateway_call_ix = create_gateway_call_instruction(
+            ctx.accounts.gateway_program.key(),
+            profile.revocation_chain_id,
+            profile.revocation_receiver.to_vec(),
+            MessageCodec::encode(&message)?,
+            CredentialProfile::REVOCATION_GAS_LIMIT,
+        )?;
+        invoke(
+            &gateway_call_ix,
+            &[
+                ctx.accounts.gateway_program.to_account_info(),
+                ctx.accounts.issuer.to_account_info(),
+            ],
+        )?;
+    }
+
+    emit!(CredentialRevoked {
+        collection_mint: credential.collection_mint,
+        credential_id: credential.credential_id,
+        subject: credential.subject,
+        revoked_by: issuer,
+        reason,
+        revocation_chain_id: profile.revocation_chain_id,
+        timestamp: now,
+    });
+
+    log!("Credential {} revoked", credential.credential_id);
+    Ok(())
+}
+
+/// Return whether a credential is currently valid. Programs read the result
+/// with `get_return_data` after the CPI.
+pub fn verify_credential(ctx: Context<VerifyCredential>) -> Result<CredentialStatusView> {
+    let credential = &ctx.accounts.credential;
+    let expired = credential.is_expired(Clock::get()?.unix_timestamp);
+    let revoked = !ctx.accounts.revocation.data_is_empty();
+
+    Ok(CredentialStatusView {
+        credential: credential.key(),
+        subject: credential.subject,
+        issuer: credential.issuer,
+        schema_hash: credential.schema_hash,
+        issued_at: credential.issued_at,
+        expires_at: credential.expires_at,
+        expired,
+        revoked,
+        valid: !expired && !revoked,
+    })
+}
+
+#[derive(Accounts)]
+pub struct ConfigureCredentials<'info> {
+    #[account(
+        seeds = [b"collection", collection.mint.as_ref()],
+        bump = collection.bump,
+        has_one = authority
+    )]
+    pub collection: Account<'info, UniversalCollection>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CredentialProfile::INIT_SPACE,
+        seeds = [b"credential_profile", collection.mint.as_ref()],
+        bump
+    )]
+    pub credential_profile: Account<'info, CredentialProfile>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct IssueCredential<'info> {
+    #[account(
+        mut,
+        seeds = [b"credential_profile", credential_profile.collection_mint.as_ref()],
+        bump = credential_profile.bump
+    )]
+    pub credential_profile: Account<'info, CredentialProfile>,
+
+    #[account(
+        init,
+        payer = issuer,
+        space = 8 + Credential::INIT_SPACE,
+        seeds = [
+            b"credential",
+            credential_profile.collection_mint.as_ref(),
+            credential_profile.issued_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub credential: Account<'info, Credential>,
+
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeCredential<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"credential_profile", credential.collection_mint.as_ref()],
+        bump = credential_profile.bump
+    )]
+    pub credential_profile: Account<'info, CredentialProfile>,
+
+    #[account(
+        seeds = [
+            b"credential",
+            credential.collection_mint.as_ref(),
+            credential.credential_id.to_le_bytes().as_ref()
+        ],
+        bump = credential.bump
+    )]
+    pub credential: Account<'info, Credential>,
+
+    #[account(
+        init,
+        payer = issuer,
+        space = 8 + CredentialRevocation::INIT_SPACE,
+        seeds = [b"credential_revocation", credential.key().as_ref()],
+        bump
+    )]
+    pub revocation: Account<'info, CredentialRevocation>,
+
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    /// CHECK: Gateway program for revocation messages
+    #[account(address = config.gateway_authority)]
+    pub gateway_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyCredential<'info> {
+    #[account(
+        seeds = [
+            b"credential",
+            credential.collection_mint.as_ref(),
+            credential.credential_id.to_le_bytes().as_ref()
+        ],
+        bump = credential.bump
+    )]
+    pub credential: Account<'info, Credential>,
+
+    /// CHECK: Credential's revocation registry entry, empty unless revoked
+    #[account(
+        seeds = [b"credential_revocation", credential.key().as_ref()],
+        bump
+    )]
+    pub revocation: UncheckedAccount<'info>,
+}
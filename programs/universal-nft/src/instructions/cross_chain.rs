@@ -1,19 +1,32 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Burn, Token, TokenAccount};
+use anchor_spl::associated_token::{self, get_associated_token_address, AssociatedToken};
+use anchor_spl::token::{self, Burn, Mint, MintTo, Token, TokenAccount, Transfer};
+use mpl_token_metadata::{
+    instructions::{CreateMetadataAccountV3, CreateMetadataAccountV3InstructionArgs},
+    types::{Collection, DataV2},
+};
 use solana_program::{
-    instruction::Instruction,
-    program::{invoke, invoke_signed},
+    instruction::{get_stack_height, Instruction, TRANSACTION_LEVEL_STACK_HEIGHT},
+    program::invoke_signed,
     system_instruction,
-    sysvar::{instructions::Instructions as SysvarInstructions, Sysvar},
+    sysvar::{
+        instructions::{load_current_index_checked, load_instruction_at_checked},
+        Sysvar,
+    },
 };
 
 use crate::state::*;
 use crate::errors::*;
 use crate::utils::*;
+use crate::events::*;
+use crate::bridge::{BridgeAdapterUtils, OutboundMessage};
+use crate::hooks::{HookPayload, HookUtils};
+use crate::security::QuorumAttestation;
+use crate::instructions::receipt::{burn_transfer_receipt, mint_transfer_receipt};
 
 /// Handle incoming cross-chain calls from ZetaChain Gateway
-pub fn on_call(
-    ctx: Context<OnCall>,
+pub fn on_call<'info>(
+    ctx: Context<'_, '_, 'info, 'info, OnCall<'info>>,
     sender: [u8; 20],
     source_chain_id: u64,
     message: Vec<u8>,
@@ -24,17 +37,72 @@ pub fn on_call(
     require!(!config.is_paused, UniversalNftError::ProgramPaused);
     
     // Verify the call is coming from the gateway program
-    verify_instruction_origin(&ctx.accounts.instructions_sysvar)?;
-    
-    // Validate chain ID
-    CrossChainUtils::validate_chain_id(source_chain_id)?;
+    verify_instruction_origin(&ctx.accounts.instructions_sysvar, &config.gateway_authority)?;
+
+    // Only registered, enabled chains are heard from
+    CrossChainUtils::validate_chain_id(source_chain_id, &ctx.accounts.source_chain)?;
     
     // Validate message format
     SignatureUtils::validate_message_format(&message)?;
     
     // Parse the cross-chain message
-    let cross_chain_msg: CrossChainMessage = borsh::from_slice(&message)
-        .map_err(|_| UniversalNftError::InvalidMessageFormat)?;
+    let cross_chain_msg = MessageCodec::decode(&message)?;
+
+    // Only act on genuine universal NFTs. Transfers and metadata updates need
+    // the token live on Solana; other messages may deliver into an NFT that
+    // was burned when it left or prepared for its first arrival.
+    match cross_chain_msg {
+        CrossChainMessage::TransferOwnership { .. } | CrossChainMessage::UpdateMetadata { .. } => {
+            validate::universal_nft_mint(&ctx.accounts.mint)?
+        }
+        _ => validate::inbound_universal_nft_mint(&ctx.accounts.mint)?,
+    }
+
+    // Nothing is accepted from a chain whose inbound corridor is paused
+    CorridorPauseUtils::require_open(&ctx.accounts.corridor_pause, CorridorDirection::Inbound)?;
+    IncidentUtils::require_operational(&ctx.accounts.incident_mode, None)?;
+
+    // Fast chains can reorg a message away; wait for the configured depth
+    require_source_finality(
+        source_chain_id,
+        MessageCodec::source_block(&message),
+        &ctx.accounts.chain_finality,
+    )?;
+
+    // Collections that opted into light-client proofs also need a proven event
+    let collections = message_collections(&cross_chain_msg);
+    if proof_required(&collections, ctx.remaining_accounts)? {
+        let verified_event = ctx.accounts.verified_event
+            .as_mut()
+            .ok_or(UniversalNftError::EventProofRequired)?;
+        let leaf = LightClientUtils::event_leaf(source_chain_id, &sender, &message);
+        require!(verified_event.leaf == leaf, UniversalNftError::EventProofRequired);
+        require!(!verified_event.consumed, UniversalNftError::EventProofConsumed);
+        verified_event.consumed = true;
+        log!("Inbound event proof consumed");
+    }
+
+    // Once an observer set is registered, every message needs its quorum
+    QuorumUtils::consume(
+        &ctx.accounts.validator_set,
+        ctx.accounts.quorum_attestation.as_deref_mut(),
+        &LightClientUtils::event_leaf(source_chain_id, &sender, &message),
+    )?;
+
+    // Messages a relayer delivered execute once, in source-chain order
+    SequenceUtils::consume_released(
+        &ctx.accounts.inbound_queue,
+        ctx.accounts.relayed_message.as_deref_mut(),
+        source_chain_id,
+        &sender,
+        &message,
+    )?;
+
+    // A chain whose breaker is open is refused; a message admitted as the
+    // half-open probe closes it. The handlers below either succeed or revert
+    // this whole call, so the success is recorded up front.
+    CircuitBreakerUtils::check(&ctx.accounts.circuit_breaker)?;
+    CircuitBreakerUtils::record(&ctx.accounts.circuit_breaker, true)?;
 
     // Process based on message type
     match cross_chain_msg {
@@ -54,6 +122,105 @@ pub fn on_call(
                 uri,
                 recipient,
                 collection_mint,
+                &sender,
+                source_chain_id,
+            )?;
+        }
+        CrossChainMessage::MintNftWithPayload {
+            token_id,
+            name,
+            symbol,
+            uri,
+            recipient,
+            collection_mint,
+            payload,
+        } => {
+            emit!(TransferPayloadDelivered {
+                token_id: token_id.clone(),
+                recipient,
+                source_chain_id,
+                payload,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+            handle_mint_from_cross_chain(
+                ctx,
+                token_id,
+                name,
+                symbol,
+                uri,
+                recipient,
+                collection_mint,
+                &sender,
+                source_chain_id,
+            )?;
+        }
+        CrossChainMessage::MintNftWithAttributes {
+            token_id,
+            name,
+            symbol,
+            uri,
+            recipient,
+            collection_mint,
+            payload,
+            attributes,
+        } => {
+            if !payload.is_empty() {
+                emit!(TransferPayloadDelivered {
+                    token_id: token_id.clone(),
+                    recipient,
+                    source_chain_id,
+                    payload,
+                    timestamp: Clock::get()?.unix_timestamp,
+                });
+            }
+            restore_inbound_attributes(
+                ctx.accounts,
+                ctx.bumps.nft_attributes,
+                collection_mint,
+                attributes,
+                source_chain_id,
+            )?;
+            handle_mint_from_cross_chain(
+                ctx,
+                token_id,
+                name,
+                symbol,
+                uri,
+                recipient,
+                collection_mint,
+                &sender,
+                source_chain_id,
+            )?;
+        }
+        CrossChainMessage::MintNftWithVesting {
+            token_id,
+            name,
+            symbol,
+            uri,
+            recipient,
+            collection_mint,
+            payload,
+            vesting,
+        } => {
+            if !payload.is_empty() {
+                emit!(TransferPayloadDelivered {
+                    token_id: token_id.clone(),
+                    recipient,
+                    source_chain_id,
+                    payload,
+                    timestamp: Clock::get()?.unix_timestamp,
+                });
+            }
+            verify_returning_vesting(ctx.accounts, &vesting, source_chain_id)?;
+            handle_mint_from_cross_chain(
+                ctx,
+                token_id,
+                name,
+                symbol,
+                uri,
+                recipient,
+                collection_mint,
+                &sender,
                 source_chain_id,
             )?;
         }
@@ -78,11 +245,53 @@ pub fn on_call(
                 source_chain_id,
             )?;
         }
+        CrossChainMessage::QueryResponse { query_id, success, data } => {
+            handle_query_response(ctx, query_id, success, data, &sender, source_chain_id)?;
+        }
+        CrossChainMessage::MintFromBitcoin {
+            inscription,
+            btc_sender,
+            name,
+            symbol,
+            uri,
+            recipient,
+        } => {
+            handle_mint_from_bitcoin(
+                ctx,
+                inscription,
+                btc_sender,
+                name,
+                symbol,
+                uri,
+                recipient,
+                source_chain_id,
+            )?;
+        }
+        CrossChainMessage::MintNftBatch { recipient, items } => {
+            handle_batch_mint_from_cross_chain(ctx, recipient, items, collections.len(), &sender, source_chain_id)?;
+        }
+        CrossChainMessage::MintNftSplit { entries } => {
+            handle_split_mint_from_cross_chain(ctx, entries, collections.len(), source_chain_id)?;
+        }
+        CrossChainMessage::SwapSecretRevealed { swap, secret } => {
+            handle_swap_secret_revealed(ctx.accounts, swap, secret, &sender, source_chain_id)?;
+        }
+        CrossChainMessage::QueryRequest { .. }
+        | CrossChainMessage::MintNftWithRoyalty { .. }
+        | CrossChainMessage::MintNftBundle { .. }
+        | CrossChainMessage::TicketCheckIn { .. }
+        | CrossChainMessage::CredentialRevoked { .. }
+        | CrossChainMessage::SwapLockRequested { .. }
+        | CrossChainMessage::SecurityEvent { .. }
+        | CrossChainMessage::CorridorPause { .. }
+        | CrossChainMessage::CorridorPauseRequested { .. } => {
+            return Err(UniversalNftError::InvalidMessageFormat.into());
+        }
     }
 
-    msg!("Cross-chain call processed successfully");
-    msg!("Source chain: {}", source_chain_id);
-    msg!("Sender: {:?}", sender);
+    log!("Cross-chain call processed successfully");
+    log!("Source chain: {}", source_chain_id);
+    log!("Sender: {:?}", sender);
 
     Ok(())
 }
@@ -90,9 +299,9 @@ pub fn on_call(
 /// Handle revert operations for failed cross-chain transactions
 pub fn on_revert(
     ctx: Context<OnRevert>,
-    sender: [u8; 20],
+    _sender: [u8; 20],
     source_chain_id: u64,
-    message: Vec<u8>,
+    _message: Vec<u8>,
 ) -> Result<()> {
     let config = &ctx.accounts.config;
     
@@ -100,61 +309,200 @@ pub fn on_revert(
     require!(!config.is_paused, UniversalNftError::ProgramPaused);
     
     // Verify the call is coming from the gateway program
-    verify_instruction_origin(&ctx.accounts.instructions_sysvar)?;
+    verify_instruction_origin(&ctx.accounts.instructions_sysvar, &config.gateway_authority)?;
     
     // Find the transfer that needs to be reverted
     let transfer = &mut ctx.accounts.transfer;
     require!(
-        transfer.status == TransferStatus::Processing,
+        transfer.status == TransferStatus::Initiated || transfer.status == TransferStatus::Processing,
         UniversalNftError::InvalidTransferStatus
     );
 
     // Update transfer status to reverted
     transfer.status = TransferStatus::Reverted;
+    CorridorUtils::record_outbound(transfer, false, ctx.accounts.chain_metrics.as_deref())?;
+    CircuitBreakerUtils::record(&ctx.accounts.circuit_breaker, false)?;
 
     // Unlock the NFT if it was locked
     let universal_nft = &mut ctx.accounts.universal_nft;
     universal_nft.is_locked = false;
+    universal_nft.transfer_in_progress = false;
+
+    // The transfer is no longer in flight
+    burn_transfer_receipt(
+        &ctx.accounts.token_program,
+        &ctx.accounts.receipt_mint,
+        &ctx.accounts.receipt_token_account,
+        &ctx.accounts.receipt_authority,
+        ctx.bumps.receipt_authority,
+    )?;
+
+    // A reverted swap lock request leaves the NFT refundable to its maker
+    if let Some(swap) = ctx.accounts.cross_chain_swap.as_mut() {
+        require_keys_eq!(swap.transfer, transfer.key(), UniversalNftError::InvalidSwapState);
+        require!(swap.status == CrossChainSwapStatus::Locked, UniversalNftError::InvalidSwapState);
+        swap.status = CrossChainSwapStatus::Reverted;
+
+        emit!(CrossChainSwapReverted {
+            swap: swap.key(),
+            transfer: transfer.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+
+    // The payload stays on the transfer record for the sender to act on
+    emit!(CrossChainTransferReverted {
+        transfer: transfer.key(),
+        nonce: transfer.nonce,
+        payload: transfer.payload.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
-    msg!("Cross-chain transaction reverted");
-    msg!("Transfer nonce: {}", transfer.nonce);
-    msg!("Source chain: {}", source_chain_id);
+    log!("Cross-chain transaction reverted");
+    log!("Transfer nonce: {}", transfer.nonce);
+    log!("Source chain: {}", source_chain_id);
 
     Ok(())
 }
 
 /// Burn NFT and initiate cross-chain transfer
-pub fn burn_and_transfer(
-    ctx: Context<BurnAndTransfer>,
+pub fn burn_and_transfer<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BurnAndTransfer<'info>>,
     destination_chain_id: u64,
     recipient: Vec<u8>,
     gas_limit: u64,
+    gas_payment: GasPayment,
+    payload: Vec<u8>,
+    referrer: Option<Pubkey>,
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
     
     // Check if program is paused
     require!(!config.is_paused, UniversalNftError::ProgramPaused);
+
+    validate::universal_nft_mint(&ctx.accounts.mint)?;
     
     // Validate parameters
-    CrossChainUtils::validate_chain_id(destination_chain_id)?;
-    CrossChainUtils::validate_recipient(&recipient)?;
+    CrossChainUtils::validate_chain_id(destination_chain_id, &ctx.accounts.destination_chain)?;
+    ctx.accounts.destination_chain.require_valid_recipient(destination_chain_id, &recipient)?;
     CrossChainUtils::validate_gas_limit(gas_limit)?;
+    CorridorPauseUtils::require_open(&ctx.accounts.corridor_pause, CorridorDirection::Outbound)?;
+    IncidentUtils::require_outbound(&ctx.accounts.incident_mode, &ctx.accounts.mint.key())?;
+    for rate_limit in [
+        &ctx.accounts.wallet_rate_limit,
+        &ctx.accounts.chain_rate_limit,
+        &ctx.accounts.collection_rate_limit,
+    ] {
+        RateLimitUtils::consume(rate_limit, &ctx.accounts.incident_mode)?;
+    }
+
+    // A tripped chain only takes the half-open probe; its outcome arrives
+    // with confirm_outbound_transfer or on_revert
+    CircuitBreakerUtils::check(&ctx.accounts.circuit_breaker)?;
+    CrossChainUtils::validate_payload(&payload)?;
+
+    // Message layer this transfer leaves through
+    let route = BridgeAdapterUtils::resolve_route(
+        &ctx.accounts.destination_chain,
+        ctx.accounts.message_layer.as_deref(),
+    )?;
 
     let universal_nft = &mut ctx.accounts.universal_nft;
+
+    // Mark the transfer in flight and persist it now, so a hook or other
+    // nested call into this program sees the flag before this one finishes
+    universal_nft.require_no_transfer_in_progress()?;
+    universal_nft.transfer_in_progress = true;
+    universal_nft.exit(ctx.program_id)?;
     
-    // Check NFT is not locked
-    require!(!universal_nft.is_locked, UniversalNftError::NftLocked);
+    // Check NFT is not locked or frozen by a dispute. A scheduled transfer
+    // holds the lock itself and may execute once its delay has elapsed.
+    match &ctx.accounts.scheduled_transfer {
+        Some(scheduled) => scheduled.require_executable(
+            universal_nft.mint,
+            destination_chain_id,
+            &recipient,
+            gas_limit,
+            Clock::get()?.unix_timestamp,
+        )?,
+        None => require!(!universal_nft.is_locked, UniversalNftError::NftLocked),
+    }
+    require!(!universal_nft.is_disputed, UniversalNftError::NftUnderDispute);
+    require!(!universal_nft.is_frozen, UniversalNftError::NftFrozen);
     
-    // Verify ownership
-    require!(
-        universal_nft.owner == ctx.accounts.owner.key(),
+    // Verify ownership, or a bridge-scoped operator session. Operators burn
+    // through the token delegate the owner approved.
+    OperatorSessionUtils::authorize_signer(
+        &universal_nft.owner,
+        &ctx.accounts.owner.key(),
+        &ctx.accounts.operator_session,
+        SessionScope::Bridge,
+        Some(destination_chain_id),
+    )?;
+    require_keys_eq!(
+        ctx.accounts.token_account.owner,
+        universal_nft.owner,
         UniversalNftError::InvalidNftOwner
     );
 
+    // The owner's own transfer lock
+    WalletLockUtils::require_unlocked(&ctx.accounts.wallet_lock)?;
+
+    // Equipped children either hold the parent here or travel with it
+    let nft_children = NestingUtils::load_children(&ctx.accounts.nft_children)?;
+    if let Some(nft_children) = &nft_children {
+        nft_children.require_bridgeable()?;
+    }
+    let children = nft_children.map_or_else(Vec::new, |record| record.children);
+
+    // An open vesting position travels with the NFT; its escrow stays here
+    let vesting = VestingUtils::load_terms(&ctx.accounts.vesting_position)?
+        .filter(|terms| !terms.is_fully_claimed());
+
+    // Apply the collection's bridging policy, if it has one
+    let config_account = ctx.accounts.collection_config.as_ref().map(|a| a.to_account_info());
+    let collection_config = CollectionConfigUtils::load_collection_config(
+        universal_nft.collection_mint,
+        config_account.as_ref(),
+    )?;
+    if let Some(collection_config) = &collection_config {
+        collection_config.require_bridge_allowed(destination_chain_id)?;
+    }
+    let lock_in_custody = InboundDeliveryUtils::locks_outbound(
+        collection_config
+            .as_ref()
+            .is_some_and(|c| c.transfer_mode == CollectionTransferMode::Lock),
+        validate::reissuable(&ctx.accounts.mint),
+    );
+
+    // Count the transfer against the destination chain's caps
+    let floor_account = ctx.accounts.collection_floor.as_ref().map(|a| a.to_account_info());
+    let value = VolumeCapUtils::load_floor_value(universal_nft.collection_mint, floor_account.as_ref())?;
+    let destination_chain = &mut ctx.accounts.destination_chain;
+    destination_chain.volume = VolumeCapUtils::record(
+        &destination_chain.limits,
+        destination_chain.chain_id,
+        destination_chain.volume,
+        value,
+        1,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    // Score the transfer against recent traffic and governance's chain risks
+    FraudUtils::screen(
+        &ctx.accounts.fraud_engine,
+        &ctx.accounts.chain_risk_registry,
+        destination_chain_id,
+        value,
+        &universal_nft.owner,
+    )?;
+
     // Lock the NFT
     universal_nft.is_locked = true;
 
-    // Increment nonce for replay protection
+    // The transfer keeps the nonce its PDA was derived from; the config moves
+    // on to the next one for replay protection
+    let nonce = config.nonce;
     config.nonce = config.nonce
         .checked_add(1)
         .ok_or(UniversalNftError::ArithmeticOverflow)?;
@@ -167,141 +515,1518 @@ pub fn burn_and_transfer(
     transfer.sender = [0u8; 20]; // Convert Solana address to bytes
     transfer.recipient = recipient.clone();
     transfer.gas_limit = gas_limit;
-    transfer.nonce = config.nonce;
+    transfer.nonce = nonce;
     transfer.timestamp = Clock::get()?.unix_timestamp;
     transfer.status = TransferStatus::Initiated;
     transfer.bump = ctx.bumps.transfer;
 
-    // Burn the token
-    let cpi_accounts = Burn {
-        mint: ctx.accounts.mint.to_account_info(),
-        from: ctx.accounts.token_account.to_account_info(),
-        authority: ctx.accounts.owner.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    token::burn(cpi_ctx, 1)?;
-
-    // Prepare cross-chain message
-    let cross_chain_msg = CrossChainMessage::MintNft {
-        token_id: universal_nft.origin_token_id.clone(),
-        name: universal_nft.name.clone(),
-        symbol: universal_nft.symbol.clone(),
-        uri: universal_nft.uri.clone(),
-        recipient: Pubkey::new_from_array(
-            recipient.try_into().map_err(|_| UniversalNftError::InvalidRecipient)?
-        ),
-        collection_mint: universal_nft.collection_mint,
+    // Charge destination gas. SOL is paid to the gateway as part of the call;
+    // ZRC-20 gas tokens are collected into the registered token vault.
+    let gas_fee = match gas_payment {
+        GasPayment::Sol => GasFeeUtils::sol_gas_fee(gas_limit)?,
+        GasPayment::Zrc20 => {
+            let gas_token = ctx.accounts.gas_token_config
+                .as_mut()
+                .ok_or(UniversalNftError::InvalidGasTokenAccounts)?;
+            let payer_tokens = ctx.accounts.gas_token_account
+                .as_ref()
+                .ok_or(UniversalNftError::InvalidGasTokenAccounts)?;
+            let vault = ctx.accounts.gas_token_vault
+                .as_ref()
+                .ok_or(UniversalNftError::InvalidGasTokenAccounts)?;
+
+            require!(gas_token.enabled, UniversalNftError::GasTokenDisabled);
+            require!(
+                gas_token.destination_chain_id == destination_chain_id,
+                UniversalNftError::GasTokenChainMismatch
+            );
+            require_keys_eq!(vault.key(), gas_token.vault, UniversalNftError::InvalidGasTokenAccounts);
+            require_keys_eq!(
+                payer_tokens.mint,
+                gas_token.zrc20_mint,
+                UniversalNftError::InvalidGasTokenAccounts
+            );
+            require_keys_eq!(
+                payer_tokens.owner,
+                ctx.accounts.owner.key(),
+                UniversalNftError::InvalidGasTokenAccounts
+            );
+
+            let fee = GasFeeUtils::calculate_gas_fee(gas_limit, gas_token.price_per_gas_unit)?;
+
+            let cpi_accounts = Transfer {
+                from: payer_tokens.to_account_info(),
+                to: vault.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+            token::transfer(cpi_ctx, fee)?;
+
+            gas_token.total_collected = gas_token.total_collected
+                .checked_add(fee)
+                .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            fee
+        }
     };
+    transfer.gas_payment = gas_payment;
+    transfer.gas_fee = gas_fee;
+    transfer.initiator = ctx.accounts.owner.key();
 
-    let message_data = borsh::to_vec(&cross_chain_msg)
-        .map_err(|_| UniversalNftError::InvalidMessageFormat)?;
+    // Protocol bridge fee, a share of the destination gas priced in SOL,
+    // with any referrer's share
+    let referral = FeeUtils::referral(
+        referrer,
+        ctx.accounts.referral.as_deref(),
+        &[ctx.accounts.owner.key(), universal_nft.owner],
+    )?;
+    if let Some(fee_schedule) = FeeUtils::load(&ctx.accounts.fee_schedule)? {
+        FeeUtils::charge(
+            &fee_schedule,
+            FeeKind::Bridge,
+            fee_schedule.bridge_fee(GasFeeUtils::sol_gas_fee(gas_limit)?)?,
+            &ctx.accounts.owner.to_account_info(),
+            ctx.accounts.fee_recipient.as_deref(),
+            &FeeDiscountAccounts {
+                governance_stake: ctx.accounts.governance_stake.as_deref(),
+                deployment_registry: ctx.accounts.deployment_registry.as_deref(),
+                membership_token_account: ctx.accounts.membership_token_account.as_deref(),
+                membership_nft: ctx.accounts.membership_nft.as_deref(),
+                rebate_campaign: ctx.accounts.rebate_campaign.as_deref(),
+            },
+            referral.as_ref(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+    }
 
-    // Create instruction for gateway call
-    let gateway_call_ix = create_gateway_call_instruction(
-        ctx.accounts.gateway_program.key(),
+    // Activity points for users who opened their metrics account
+    PointsUtils::record(
+        PointsActivity::Bridge,
+        &ctx.accounts.owner.key(),
+        ctx.accounts.user_metrics.as_deref(),
+        ctx.accounts.points_config.as_deref(),
+    )?;
+    BaselineUtils::record_transfer(
+        &ctx.accounts.owner.key(),
+        value,
         destination_chain_id,
-        recipient,
-        message_data,
-        gas_limit,
+        ctx.accounts.user_metrics.as_deref(),
+    )?;
+    UsageSegmentUtils::record(
+        universal_nft.collection_mint,
+        Some(value),
+        ctx.accounts.usage_segment.as_deref(),
+    )?;
+    LeaderboardUtils::record_user(
+        LeaderboardKind::Bridgers,
+        &ctx.accounts.owner.key(),
+        ctx.accounts.user_metrics.as_deref(),
+        ctx.accounts.leaderboard.as_deref(),
+    )?;
+    LeaderboardUtils::record_collection_bridge(
+        universal_nft.collection_mint,
+        ctx.accounts.collection_leaderboard.as_deref(),
     )?;
+    transfer.payload = payload.clone();
 
-    // Invoke gateway program
-    invoke(
-        &gateway_call_ix,
-        &[
-            ctx.accounts.gateway_program.to_account_info(),
-            ctx.accounts.owner.to_account_info(),
-        ],
+    // Run collection hooks subscribed to this event. Message layer accounts
+    // follow the hooks' accounts.
+    let mut hook_accounts = 0;
+    let hooks_account = ctx.accounts.collection_hooks.as_ref().map(|a| a.to_account_info());
+    if let Some(hooks) = HookUtils::load_collection_hooks(universal_nft.collection_mint, hooks_account.as_ref())? {
+        let payload = HookPayload {
+            event: HookEvent::BridgeOut,
+            mint: universal_nft.mint,
+            collection_mint: hooks.collection_mint,
+            from: ctx.accounts.owner.key(),
+            to: None,
+            destination_chain_id: Some(destination_chain_id),
+        };
+        hook_accounts = HookUtils::invoke_hooks(&hooks, &payload, ctx.remaining_accounts)?;
+    }
+
+    // Give the sender a receipt showing the asset is in flight
+    mint_transfer_receipt(
+        &ctx.accounts.token_program,
+        &ctx.accounts.receipt_mint,
+        &ctx.accounts.receipt_token_account,
+        &ctx.accounts.receipt_authority,
+        &ctx.accounts.owner,
+        ctx.bumps.receipt_authority,
+    )?;
+
+    if lock_in_custody {
+        // Lock-mode collections, and NFTs that couldn't be minted again,
+        // keep the token in program custody
+        let custody_tokens = ctx.accounts.custody_token_account
+            .as_ref()
+            .ok_or(UniversalNftError::InvalidCustodyAccount)?;
+        let (custody, _) = Pubkey::find_program_address(&[b"nft_custody"], &crate::ID);
+        require_keys_eq!(custody_tokens.owner, custody, UniversalNftError::InvalidCustodyAccount);
+        require_keys_eq!(
+            custody_tokens.mint,
+            ctx.accounts.mint.key(),
+            UniversalNftError::InvalidCustodyAccount
+        );
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.token_account.to_account_info(),
+            to: custody_tokens.to_account_info(),
+            authority: ctx.accounts.owner.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token::transfer(cpi_ctx, 1)?;
+
+        // The original now backs a wrapped representation on the destination
+        if let Some(ledger) = ctx.accounts.supply_ledger.as_mut() {
+            ledger.originals_locked = ledger.originals_locked
+                .checked_add(1)
+                .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        }
+    } else {
+        // Burn the token
+        let cpi_accounts = Burn {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.token_account.to_account_info(),
+            authority: ctx.accounts.owner.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::burn(cpi_ctx, 1)?;
+    }
+
+    // Prepare cross-chain message, carrying the payload to the receive hook if
+    // present and translating metadata when the chain has rules
+    let token_id = universal_nft.origin_token_id.clone();
+    let (name, symbol, uri) = match &ctx.accounts.metadata_rules {
+        Some(rules) => MetadataTranslationUtils::translate(
+            rules,
+            &universal_nft.name,
+            &universal_nft.symbol,
+            &universal_nft.uri,
+        )?,
+        None => (
+            universal_nft.name.clone(),
+            universal_nft.symbol.clone(),
+            universal_nft.uri.clone(),
+        ),
+    };
+    let recipient_key = ctx.accounts.destination_chain.encode_recipient(&recipient)?;
+    let collection_mint = universal_nft.collection_mint;
+    let royalty = ctx.accounts.metadata_rules
+        .as_ref()
+        .filter(|rules| rules.royalty_receiver != [0u8; 20])
+        .map(|rules| Erc2981Royalty {
+            receiver: rules.royalty_receiver,
+            basis_points: collection_config.as_ref().map_or(0, |c| c.seller_fee_basis_points),
+        });
+    let attributes = NftAttributeUtils::load_entries(&ctx.accounts.nft_attributes)?;
+    let cross_chain_msg = if let Some(vesting) = vesting {
+        // The vesting message has no royalty, attribute or children field
+        require!(royalty.is_none(), UniversalNftError::VestingUnsupportedOnRoute);
+        require!(attributes.is_empty(), UniversalNftError::AttributesUnsupportedOnRoute);
+        require!(children.is_empty(), UniversalNftError::ChildrenUnsupportedOnRoute);
+        CrossChainMessage::MintNftWithVesting {
+            token_id,
+            name,
+            symbol,
+            uri,
+            recipient: recipient_key,
+            collection_mint,
+            payload,
+            vesting,
+        }
+    } else if !children.is_empty() {
+        // The bundle message has no royalty or attribute field
+        require!(royalty.is_none(), UniversalNftError::ChildrenUnsupportedOnRoute);
+        require!(attributes.is_empty(), UniversalNftError::AttributesUnsupportedOnRoute);
+        emit!(ChildrenBundled {
+            parent_mint: universal_nft.mint,
+            children: children.iter().map(|child| child.mint).collect(),
+            destination_chain_id,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        CrossChainMessage::MintNftBundle {
+            token_id,
+            name,
+            symbol,
+            uri,
+            recipient: recipient_key,
+            collection_mint,
+            payload,
+            children: children.iter().map(EquippedChild::to_batch_item).collect(),
+        }
+    } else if let Some(royalty) = royalty {
+        // The royalty message has no attribute field
+        require!(attributes.is_empty(), UniversalNftError::AttributesUnsupportedOnRoute);
+        CrossChainMessage::MintNftWithRoyalty {
+            token_id,
+            name,
+            symbol,
+            uri,
+            recipient: recipient_key,
+            collection_mint,
+            payload,
+            royalty,
+        }
+    } else if !attributes.is_empty() {
+        CrossChainMessage::MintNftWithAttributes {
+            token_id,
+            name,
+            symbol,
+            uri,
+            recipient: recipient_key,
+            collection_mint,
+            payload,
+            attributes,
+        }
+    } else {
+        CrossChainUtils::attach_payload(
+            CrossChainMessage::MintNft {
+                token_id,
+                name,
+                symbol,
+                uri,
+                recipient: recipient_key,
+                collection_mint,
+            },
+            payload,
+        )
+    };
+
+    let message_data = MessageCodec::encode(&cross_chain_msg)?;
+
+    // Hand the message to the route's layer
+    BridgeAdapterUtils::send(
+        &route,
+        ctx.accounts.message_layer.as_ref(),
+        &ctx.accounts.gateway_program.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.remaining_accounts[hook_accounts..],
+        &OutboundMessage {
+            nonce: transfer.nonce,
+            recipient: &recipient,
+            message: &message_data,
+            gas_limit,
+            native_fee: transfer.gas_fee,
+        },
     )?;
 
-    msg!("Cross-chain transfer initiated");
-    msg!("Token ID: {}", universal_nft.origin_token_id);
-    msg!("Destination chain: {}", destination_chain_id);
-    msg!("Transfer nonce: {}", transfer.nonce);
+    log!("Cross-chain transfer initiated");
+    log!("Token ID: {}", universal_nft.origin_token_id);
+    log!("Destination chain: {}", destination_chain_id);
+    log!("Transfer nonce: {}", transfer.nonce);
+    log!("Gas fee: {}", transfer.gas_fee);
 
     Ok(())
 }
 
 // Helper functions
 
-fn handle_mint_from_cross_chain(
-    ctx: Context<OnCall>,
-    token_id: String,
-    name: String,
-    symbol: String,
-    uri: String,
-    recipient: Pubkey,
-    collection_mint: Option<Pubkey>,
+/// Make sure the recipient can hold the NFT, creating their associated token
+/// account if it is missing. Off-curve recipients such as PDAs get an ATA
+/// like any wallet; only their owning program can move the NFT afterwards.
+fn ensure_recipient_token_account<'info>(
+    accounts: &mut OnCall<'info>,
+    mint: &AccountInfo<'info>,
+    recipient_info: &AccountInfo<'info>,
+    recipient_token_account: &AccountInfo<'info>,
+    recipient: &Pubkey,
+    create: bool,
     source_chain_id: u64,
 ) -> Result<()> {
-    // Implementation for minting NFT from cross-chain
-    msg!("Minting NFT from cross-chain");
-    msg!("Token ID: {}", token_id);
-    msg!("Recipient: {}", recipient);
-    
-    // This would involve creating a new mint and metadata
-    // Similar to the mint_nft instruction but with cross-chain origin
-    
+    let payer = accounts.payer
+        .as_ref()
+        .ok_or(UniversalNftError::MissingRecipientAccounts)?;
+    let system_program = accounts.system_program
+        .as_ref()
+        .ok_or(UniversalNftError::MissingRecipientAccounts)?;
+    let token_program = accounts.token_program
+        .as_ref()
+        .ok_or(UniversalNftError::MissingRecipientAccounts)?;
+    let associated_token_program = accounts.associated_token_program
+        .as_ref()
+        .ok_or(UniversalNftError::MissingRecipientAccounts)?;
+
+    require_keys_eq!(*recipient_info.key, *recipient, UniversalNftError::InvalidRecipient);
+    require_keys_eq!(
+        *recipient_token_account.key,
+        get_associated_token_address(recipient, mint.key),
+        UniversalNftError::InvalidAssociatedTokenAccount
+    );
+
+    if !recipient.is_on_curve() {
+        log!("Recipient is off-curve: {}", recipient);
+    }
+
+    // Recipients who turned off auto-wrap open their token accounts themselves
+    if !create {
+        require!(
+            !recipient_token_account.data_is_empty(),
+            UniversalNftError::RecipientTokenAccountRequired
+        );
+        return Ok(());
+    }
+
+    // A no-op when the account already exists, so replays and relayer
+    // retries deliver instead of failing
+    let cpi_accounts = associated_token::Create {
+        payer: payer.to_account_info(),
+        associated_token: recipient_token_account.clone(),
+        authority: recipient_info.clone(),
+        mint: mint.clone(),
+        system_program: system_program.to_account_info(),
+        token_program: token_program.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(associated_token_program.to_account_info(), cpi_accounts);
+    let created = recipient_token_account.data_is_empty();
+    associated_token::create_idempotent(cpi_ctx)?;
+
+    if created {
+        reimburse_inbound_rent(
+            accounts,
+            *recipient_token_account.key,
+            recipient_token_account.lamports(),
+            source_chain_id,
+        )?;
+    }
+
     Ok(())
 }
 
-fn handle_burn_from_cross_chain(
-    ctx: Context<OnCall>,
-    token_id: String,
-    owner: Pubkey,
+/// Pay the `payer` back from the rent vault for an account it funded for an
+/// inbound NFT. Without the vault accounts, or once the chain's deposit runs
+/// out, the payer keeps the cost.
+fn reimburse_inbound_rent<'info>(
+    accounts: &mut OnCall<'info>,
+    account: Pubkey,
+    lamports: u64,
     source_chain_id: u64,
 ) -> Result<()> {
-    // Implementation for burning NFT from cross-chain
-    msg!("Burning NFT from cross-chain");
-    msg!("Token ID: {}", token_id);
-    msg!("Owner: {}", owner);
-    
+    let Some(payer) = accounts.payer.as_ref().map(|payer| payer.to_account_info()) else {
+        return Ok(());
+    };
+    let (Some(vault), Some(ledger)) = (accounts.rent_vault.as_mut(), accounts.rent_ledger.as_mut()) else {
+        return Ok(());
+    };
+    require!(ledger.chain_id == source_chain_id, UniversalNftError::InvalidRentLedger);
+
+    let vault_info = vault.to_account_info();
+    let spare_lamports = vault_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(vault_info.data_len()));
+    if !RentSponsorUtils::can_sponsor(vault, ledger, spare_lamports, lamports) {
+        return Ok(());
+    }
+
+    // The vault is program-owned, so its lamports move without a CPI
+    **vault_info.try_borrow_mut_lamports()? -= lamports;
+    **payer.try_borrow_mut_lamports()? += lamports;
+    RentSponsorUtils::record(vault, ledger, lamports)?;
+
+    emit!(InboundRentSponsored {
+        account,
+        source_chain_id,
+        lamports,
+        chain_remaining: ledger.remaining(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    log!("Inbound rent sponsored: {} lamports", lamports);
+
     Ok(())
 }
 
-fn handle_transfer_from_cross_chain(
-    ctx: Context<OnCall>,
-    token_id: String,
-    new_owner: Pubkey,
+/// Apply the named recipient's preferences for the source chain and prepare
+/// the token account of whoever the NFT goes to, from the named OnCall
+/// accounts. Quarantined NFTs go to the custody PDA's token account.
+fn ensure_named_recipient_token_account(
+    accounts: &mut OnCall,
+    recipient: &Pubkey,
+    sender: &[u8; 20],
     source_chain_id: u64,
-) -> Result<()> {
-    // Implementation for transferring ownership from cross-chain
-    msg!("Transferring ownership from cross-chain");
-    msg!("Token ID: {}", token_id);
-    msg!("New owner: {}", new_owner);
-    
-    Ok(())
+) -> Result<RecipientDelivery> {
+    let preferences_info = accounts.recipient_preferences
+        .as_ref()
+        .ok_or(UniversalNftError::InvalidRecipientPreferences)?;
+    let preferences = RecipientPreferencesUtils::load(preferences_info, recipient, source_chain_id)?;
+    let delivery = RecipientPreferencesUtils::resolve(preferences.as_ref(), *recipient, sender)?;
+    if delivery.recipient != *recipient {
+        log!("Delivery redirected to preferred recipient: {}", delivery.recipient);
+    }
+
+    let mint = accounts.mint.to_account_info();
+    let recipient_info = accounts.recipient
+        .as_ref()
+        .ok_or(UniversalNftError::MissingRecipientAccounts)?
+        .to_account_info();
+    let recipient_token_account = accounts.recipient_token_account
+        .as_ref()
+        .ok_or(UniversalNftError::MissingRecipientAccounts)?
+        .to_account_info();
+
+    if delivery.quarantine {
+        let (custody, _) = Pubkey::find_program_address(&[b"nft_custody"], &crate::ID);
+        ensure_recipient_token_account(
+            accounts,
+            &mint,
+            &recipient_info,
+            &recipient_token_account,
+            &custody,
+            true,
+            source_chain_id,
+        )?;
+    } else {
+        ensure_recipient_token_account(
+            accounts,
+            &mint,
+            &recipient_info,
+            &recipient_token_account,
+            &delivery.recipient,
+            delivery.create_token_account,
+            source_chain_id,
+        )?;
+    }
+
+    Ok(delivery)
 }
 
-fn handle_metadata_update_from_cross_chain(
-    ctx: Context<OnCall>,
-    token_id: String,
-    new_uri: String,
-    new_name: Option<String>,
-    new_symbol: Option<String>,
+/// Record an unsolicited NFT held in custody for its recipient
+fn quarantine_inbound_nft<'info>(
+    accounts: &mut OnCall<'info>,
+    quarantine_bump: Option<u8>,
+    recipient: Pubkey,
+    sender: &[u8; 20],
     source_chain_id: u64,
 ) -> Result<()> {
-    // Implementation for updating metadata from cross-chain
-    msg!("Updating metadata from cross-chain");
-    msg!("Token ID: {}", token_id);
-    msg!("New URI: {}", new_uri);
-    
-    Ok(())
-}
+    let quarantine_info = accounts.quarantine
+        .as_ref()
+        .ok_or(UniversalNftError::InvalidQuarantineAccount)?
+        .to_account_info();
+    let bump = quarantine_bump.ok_or(UniversalNftError::InvalidQuarantineAccount)?;
+    require!(quarantine_info.data_is_empty(), UniversalNftError::InvalidQuarantineAccount);
 
-fn verify_instruction_origin(instructions_sysvar: &UncheckedAccount) -> Result<()> {
-    // Verify that the current instruction is called by the gateway program
-    let instructions = SysvarInstructions::from_account_info(instructions_sysvar)?;
-    
-    // Check if the calling instruction is from the authorized gateway
-    // This is a simplified version - full implementation would check the instruction stack
-    
+    // The record only stands for a token delivery left in custody
+    let mint = accounts.universal_nft.mint;
+    let (custody, _) = Pubkey::find_program_address(&[b"nft_custody"], &crate::ID);
+    require_keys_eq!(accounts.universal_nft.owner, custody, UniversalNftError::InvalidCustodyAccount);
+    let deposit = {
+        let custody_tokens = accounts.recipient_token_account
+            .as_ref()
+            .ok_or(UniversalNftError::InvalidCustodyAccount)?;
+        let data = custody_tokens.try_borrow_data()?;
+        TokenAccount::try_deserialize(&mut &data[..])?
+    };
+    require!(
+        deposit.owner == custody && deposit.mint == mint && deposit.amount == 1,
+        UniversalNftError::InvalidCustodyAccount
+    );
+
+    create_inbound_account(
+        accounts,
+        &quarantine_info,
+        8 + QuarantinedNft::INIT_SPACE,
+        &[b"quarantine", mint.as_ref(), &[bump]],
+        source_chain_id,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let record = QuarantinedNft {
+        mint,
+        recipient,
+        source_chain_id,
+        sender: *sender,
+        quarantined_at: now,
+        expires_at: now + QuarantinedNft::QUARANTINE_PERIOD,
+        bump,
+    };
+    {
+        let mut data = quarantine_info.try_borrow_mut_data()?;
+        record.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(NftQuarantined {
+        mint,
+        recipient,
+        source_chain_id,
+        sender: *sender,
+        expires_at: record.expires_at,
+        timestamp: now,
+    });
+
+    log!("Unsolicited NFT quarantined for {}", recipient);
     Ok(())
 }
 
-fn create_gateway_call_instruction(
-    gateway_program: Pubkey,
+/// Write the attributes an inbound NFT carries, creating its NftAttributes
+/// PDA or replacing the entries it had when it last left Solana
+fn restore_inbound_attributes<'info>(
+    accounts: &mut OnCall<'info>,
+    attributes_bump: Option<u8>,
+    collection_mint: Option<Pubkey>,
+    entries: Vec<NftAttribute>,
+    source_chain_id: u64,
+) -> Result<()> {
+    NftAttributes::validate_entries(&entries)?;
+    let collection_mint = collection_mint.ok_or(UniversalNftError::InvalidNftAttribute)?;
+    let attributes_info = accounts.nft_attributes
+        .as_ref()
+        .ok_or(UniversalNftError::InvalidNftAttribute)?
+        .to_account_info();
+    let bump = attributes_bump.ok_or(UniversalNftError::InvalidNftAttribute)?;
+
+    let mint = accounts.universal_nft.mint;
+    if attributes_info.data_is_empty() {
+        create_inbound_account(
+            accounts,
+            &attributes_info,
+            8 + NftAttributes::INIT_SPACE,
+            &[b"nft_attributes", mint.as_ref(), &[bump]],
+            source_chain_id,
+        )?;
+    } else {
+        require_keys_eq!(*attributes_info.owner, crate::ID, UniversalNftError::InvalidNftAttribute);
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+    let record = NftAttributes {
+        mint,
+        collection_mint,
+        entries,
+        updated_at: now,
+        bump,
+    };
+    {
+        let mut data = attributes_info.try_borrow_mut_data()?;
+        record.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(NftAttributesRestored {
+        mint,
+        source_chain_id,
+        entries: record.entries.len() as u8,
+        timestamp: now,
+    });
+
+    log!("NFT attributes restored: {} entries", record.entries.len());
+    Ok(())
+}
+
+/// A vesting position's escrow never leaves Solana, so a returning NFT must
+/// carry exactly the terms it left with
+fn verify_returning_vesting(
+    accounts: &OnCall,
+    vesting: &VestingTerms,
+    source_chain_id: u64,
+) -> Result<()> {
+    let position = accounts.vesting_position
+        .as_ref()
+        .ok_or(UniversalNftError::VestingPositionMismatch)?;
+    require!(position.terms == *vesting, UniversalNftError::VestingPositionMismatch);
+
+    emit!(VestingPositionReturned {
+        nft_mint: position.nft_mint,
+        source_chain_id,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Record a cross-chain swap's secret once the taker's leg was claimed with
+/// it on the counterparty chain, letting anyone redeem the Solana leg
+fn handle_swap_secret_revealed(
+    accounts: &mut OnCall,
+    swap_key: Pubkey,
+    secret: [u8; 32],
+    sender: &[u8; 20],
+    source_chain_id: u64,
+) -> Result<()> {
+    let swap = accounts.cross_chain_swap
+        .as_mut()
+        .ok_or(UniversalNftError::InvalidSwapState)?;
+    require_keys_eq!(swap.key(), swap_key, UniversalNftError::InvalidSwapState);
+    require_keys_eq!(swap.nft_mint, accounts.universal_nft.mint, UniversalNftError::InvalidSwapState);
+
+    // Only the counterparty chain's counterpart contract speaks for the remote leg
+    let source_chain = &accounts.source_chain;
+    require!(
+        source_chain.chain_id == source_chain_id && source_chain_id == swap.counterparty_chain_id,
+        UniversalNftError::InvalidSwapState
+    );
+    require!(source_chain.counterpart_contract == *sender, UniversalNftError::Unauthorized);
+
+    SwapUtils::verify_secret(&secret, &swap.hashlock)?;
+    swap.reveal(secret)?;
+
+    emit!(CrossChainSwapSecretRevealed {
+        swap: swap_key,
+        secret,
+        source_chain_id,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Cross-chain swap {} secret revealed", swap.swap_id);
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn handle_mint_from_cross_chain(
+    ctx: Context<OnCall>,
+    token_id: String,
+    name: String,
+    symbol: String,
+    uri: String,
+    recipient: Pubkey,
+    collection_mint: Option<Pubkey>,
+    sender: &[u8; 20],
+    source_chain_id: u64,
+) -> Result<()> {
+    log!("Minting NFT from cross-chain");
+    log!("Token ID: {}", token_id);
+    log!("Recipient: {}", recipient);
+
+    let delivery = ensure_named_recipient_token_account(ctx.accounts, &recipient, sender, source_chain_id)?;
+    let item = BatchMintItem {
+        token_id,
+        name,
+        symbol,
+        uri,
+        collection_mint,
+    };
+    deliver_named_nft(ctx.accounts, &delivery, &item, source_chain_id)?;
+
+    if delivery.quarantine {
+        quarantine_inbound_nft(
+            ctx.accounts,
+            ctx.bumps.quarantine,
+            delivery.recipient,
+            sender,
+            source_chain_id,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Deliver the OnCall universal NFT into the token account prepared by
+/// `ensure_named_recipient_token_account`. Quarantined NFTs are owned by the
+/// custody PDA until the recipient claims them.
+fn deliver_named_nft<'info>(
+    accounts: &mut OnCall<'info>,
+    delivery: &RecipientDelivery,
+    item: &BatchMintItem,
+    source_chain_id: u64,
+) -> Result<()> {
+    let owner = if delivery.quarantine {
+        Pubkey::find_program_address(&[b"nft_custody"], &crate::ID).0
+    } else {
+        delivery.recipient
+    };
+
+    let mut universal_nft = accounts.universal_nft.clone().into_inner();
+    let universal_nft_info = accounts.universal_nft.to_account_info();
+    let mint = accounts.mint.clone();
+    let metadata = accounts.metadata.as_ref().map(|metadata| metadata.to_account_info());
+    let custody_token_account = accounts.custody_token_account.clone();
+    let recipient_token_account = accounts.recipient_token_account
+        .as_ref()
+        .ok_or(UniversalNftError::MissingRecipientAccounts)?
+        .to_account_info();
+
+    deliver_inbound_nft(
+        accounts,
+        &mut universal_nft,
+        &universal_nft_info,
+        &mint,
+        metadata.as_ref(),
+        custody_token_account.as_ref(),
+        &recipient_token_account,
+        owner,
+        item,
+        source_chain_id,
+    )?;
+
+    accounts.universal_nft.set_inner(universal_nft);
+    Ok(())
+}
+
+/// Put an inbound NFT's token into `recipient_token_account` for `owner`.
+/// The token is minted when the NFT is new to Solana or was burned when it
+/// left, or released from custody when it was locked; a first arrival also
+/// gets its Token Metadata account.
+#[allow(clippy::too_many_arguments)]
+fn deliver_inbound_nft<'info>(
+    accounts: &mut OnCall<'info>,
+    universal_nft: &mut UniversalNft,
+    universal_nft_info: &AccountInfo<'info>,
+    mint: &Account<'info, Mint>,
+    metadata: Option<&AccountInfo<'info>>,
+    custody_token_account: Option<&Account<'info, TokenAccount>>,
+    recipient_token_account: &AccountInfo<'info>,
+    owner: Pubkey,
+    item: &BatchMintItem,
+    source_chain_id: u64,
+) -> Result<InboundSource> {
+    require!(
+        universal_nft.origin_token_id == item.token_id,
+        UniversalNftError::InboundTokenMismatch
+    );
+    require_keys_eq!(universal_nft.mint, mint.key(), UniversalNftError::NftNotFound);
+    validate::inbound_universal_nft_mint(mint)?;
+
+    let token_program = accounts.token_program
+        .as_ref()
+        .ok_or(UniversalNftError::MissingRecipientAccounts)?
+        .to_account_info();
+
+    let (custody, custody_bump) = Pubkey::find_program_address(&[b"nft_custody"], &crate::ID);
+    let custody_amount = match custody_token_account {
+        Some(custody_tokens) => {
+            require_keys_eq!(custody_tokens.mint, mint.key(), UniversalNftError::InvalidCustodyAccount);
+            require_keys_eq!(custody_tokens.owner, custody, UniversalNftError::InvalidCustodyAccount);
+            custody_tokens.amount
+        }
+        None => 0,
+    };
+
+    let source = InboundDeliveryUtils::source(
+        mint.supply,
+        validate::reissuable(mint),
+        custody_amount,
+        universal_nft.is_locked,
+    )?;
+    match source {
+        InboundSource::Custody => {
+            let custody_tokens = custody_token_account.ok_or(UniversalNftError::InvalidCustodyAccount)?;
+            let custody_info = accounts.custody
+                .as_ref()
+                .ok_or(UniversalNftError::InvalidCustodyAccount)?;
+
+            // A quarantined NFT stays where it is
+            if custody_tokens.key() != recipient_token_account.key() {
+                let custody_seeds: &[&[u8]] = &[b"nft_custody", &[custody_bump]];
+                let signer_seeds = &[custody_seeds];
+                let cpi_accounts = Transfer {
+                    from: custody_tokens.to_account_info(),
+                    to: recipient_token_account.clone(),
+                    authority: custody_info.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new_with_signer(token_program, cpi_accounts, signer_seeds);
+                token::transfer(cpi_ctx, 1)?;
+            }
+
+            // The original no longer backs a wrapped representation
+            if let Some(ledger) = accounts.supply_ledger.as_mut() {
+                ledger.originals_locked = ledger.originals_locked
+                    .checked_sub(1)
+                    .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            }
+        }
+        InboundSource::Mint => {
+            let mint_authority = accounts.mint_authority
+                .as_ref()
+                .ok_or(UniversalNftError::InvalidMintAuthority)?
+                .to_account_info();
+            let (_, mint_authority_bump) = Pubkey::find_program_address(&[b"mint_authority"], &crate::ID);
+            let mint_authority_seeds: &[&[u8]] = &[b"mint_authority", &[mint_authority_bump]];
+            let mint_signer = &[mint_authority_seeds];
+
+            let cpi_accounts = MintTo {
+                mint: mint.to_account_info(),
+                to: recipient_token_account.clone(),
+                authority: mint_authority.clone(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(token_program, cpi_accounts, mint_signer);
+            token::mint_to(cpi_ctx, 1)?;
+
+            // Prepared records have no owner until their first delivery
+            if universal_nft.owner == Pubkey::default() {
+                create_inbound_metadata(
+                    accounts,
+                    universal_nft,
+                    universal_nft_info,
+                    mint,
+                    metadata,
+                    &mint_authority,
+                    mint_authority_seeds,
+                    item,
+                )?;
+
+                // The mint and record were funded by prepare_inbound_nft
+                // and the metadata just now
+                let metadata = metadata.ok_or(UniversalNftError::InvalidMetadataAccount)?;
+                for funded in [mint.to_account_info(), universal_nft_info.clone(), metadata.clone()] {
+                    reimburse_inbound_rent(accounts, funded.key(), funded.lamports(), source_chain_id)?;
+                }
+            }
+        }
+    }
+
+    universal_nft.owner = owner;
+    universal_nft.is_locked = false;
+    universal_nft.transfer_in_progress = false;
+
+    emit!(InboundNftDelivered {
+        mint: mint.key(),
+        owner,
+        source_chain_id,
+        minted: source == InboundSource::Mint,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Inbound NFT delivered to {}", owner);
+    Ok(source)
+}
+
+/// Create the Token Metadata account of an NFT arriving on Solana for the
+/// first time, and fill its universal NFT record from the message. The
+/// record's PDA is the update authority, as for NFTs minted here.
+#[allow(clippy::too_many_arguments)]
+fn create_inbound_metadata<'info>(
+    accounts: &OnCall<'info>,
+    universal_nft: &mut UniversalNft,
+    universal_nft_info: &AccountInfo<'info>,
+    mint: &Account<'info, Mint>,
+    metadata: Option<&AccountInfo<'info>>,
+    mint_authority: &AccountInfo<'info>,
+    mint_authority_seeds: &[&[u8]],
+    item: &BatchMintItem,
+) -> Result<()> {
+    MetadataUtils::validate_name(&item.name)?;
+    MetadataUtils::validate_symbol(&item.symbol)?;
+    MetadataUtils::validate_uri(&item.uri)?;
+
+    let metadata = metadata.ok_or(UniversalNftError::InvalidMetadataAccount)?;
+    validate::metadata_pda(metadata.key, &mint.key())?;
+    let payer = accounts.payer
+        .as_ref()
+        .ok_or(UniversalNftError::MissingRecipientAccounts)?;
+    let system_program = accounts.system_program
+        .as_ref()
+        .ok_or(UniversalNftError::MissingRecipientAccounts)?;
+    let rent = accounts.rent
+        .as_ref()
+        .ok_or(UniversalNftError::InvalidMetadataAccount)?;
+    require!(
+        accounts.token_metadata_program.is_some(),
+        UniversalNftError::InvalidMetadataAccount
+    );
+
+    let data = DataV2 {
+        name: item.name.clone(),
+        symbol: item.symbol.clone(),
+        uri: item.uri.clone(),
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: item.collection_mint.map(|key| Collection { verified: false, key }),
+        uses: None,
+    };
+    let create_metadata_ix = CreateMetadataAccountV3 {
+        metadata: *metadata.key,
+        mint: mint.key(),
+        mint_authority: *mint_authority.key,
+        payer: payer.key(),
+        update_authority: (*universal_nft_info.key, true),
+        system_program: system_program.key(),
+        rent: Some(rent.key()),
+    };
+
+    let mint_key = mint.key();
+    let update_authority_seeds: &[&[u8]] =
+        &[b"universal_nft", mint_key.as_ref(), &[universal_nft.bump]];
+    invoke_signed(
+        &create_metadata_ix.instruction(CreateMetadataAccountV3InstructionArgs {
+            data,
+            is_mutable: true,
+            collection_details: None,
+        }),
+        &[
+            metadata.clone(),
+            mint.to_account_info(),
+            mint_authority.clone(),
+            payer.to_account_info(),
+            universal_nft_info.clone(),
+            system_program.to_account_info(),
+            rent.to_account_info(),
+        ],
+        &[mint_authority_seeds, update_authority_seeds],
+    )?;
+
+    universal_nft.name = item.name.clone();
+    universal_nft.symbol = item.symbol.clone();
+    universal_nft.uri = item.uri.clone();
+    universal_nft.collection_mint = item.collection_mint;
+
+    Ok(())
+}
+
+/// Deliver several NFTs to one recipient. The first item is the OnCall NFT;
+/// after the proof policy accounts, the remaining accounts carry each later
+/// item's universal NFT record, mint, metadata PDA and recipient token
+/// account, in item order.
+fn handle_batch_mint_from_cross_chain<'info>(
+    ctx: Context<'_, '_, 'info, 'info, OnCall<'info>>,
+    recipient: Pubkey,
+    items: Vec<BatchMintItem>,
+    policy_count: usize,
+    sender: &[u8; 20],
+    source_chain_id: u64,
+) -> Result<()> {
+    require!(
+        !items.is_empty() && items.len() <= BatchTransfer::MAX_BATCH_SIZE,
+        UniversalNftError::InvalidBatchSize
+    );
+
+    let delivery_accounts = ctx.remaining_accounts
+        .get(policy_count..)
+        .ok_or(UniversalNftError::MissingRecipientAccounts)?;
+    require!(
+        delivery_accounts.len() >= (items.len() - 1) * BatchMintItem::DELIVERY_ACCOUNTS,
+        UniversalNftError::MissingRecipientAccounts
+    );
+
+    log!("Minting NFT batch from cross-chain");
+    log!("Recipient: {}", recipient);
+    // One quarantine record covers one NFT, so unsolicited batches are declined
+    let delivery = ensure_named_recipient_token_account(ctx.accounts, &recipient, sender, source_chain_id)?;
+    require!(!delivery.quarantine, UniversalNftError::UnsolicitedDeliveryDeclined);
+
+    log!("Token ID: {}", items[0].token_id);
+    deliver_named_nft(ctx.accounts, &delivery, &items[0], source_chain_id)?;
+
+    let recipient_info = ctx.accounts.recipient
+        .as_ref()
+        .ok_or(UniversalNftError::MissingRecipientAccounts)?
+        .to_account_info();
+    for (item, accounts) in items[1..]
+        .iter()
+        .zip(delivery_accounts.chunks_exact(BatchMintItem::DELIVERY_ACCOUNTS))
+    {
+        log!("Token ID: {}", item.token_id);
+        let [universal_nft, mint, metadata, recipient_token_account] = accounts else {
+            return Err(UniversalNftError::MissingRecipientAccounts.into());
+        };
+        // The token account is only created for an item that is minted into it
+        ensure_recipient_token_account(
+            ctx.accounts,
+            mint,
+            &recipient_info,
+            recipient_token_account,
+            &delivery.recipient,
+            delivery.create_token_account,
+            source_chain_id,
+        )?;
+        deliver_listed_nft(
+            ctx.accounts,
+            universal_nft,
+            mint,
+            metadata,
+            recipient_token_account,
+            delivery.recipient,
+            item,
+            source_chain_id,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Deliver NFTs to several recipients from one message. After the proof
+/// policy accounts, the remaining accounts carry each entry's universal NFT
+/// record, mint, metadata PDA, recipient and recipient token account, in
+/// entry order.
+fn handle_split_mint_from_cross_chain<'info>(
+    ctx: Context<'_, '_, 'info, 'info, OnCall<'info>>,
+    entries: Vec<SplitMintEntry>,
+    policy_count: usize,
+    source_chain_id: u64,
+) -> Result<()> {
+    require!(
+        !entries.is_empty() && entries.len() <= SplitMintEntry::MAX_ENTRIES,
+        UniversalNftError::InvalidBatchSize
+    );
+
+    let delivery_accounts = ctx.remaining_accounts
+        .get(policy_count..)
+        .ok_or(UniversalNftError::MissingRecipientAccounts)?;
+    require!(
+        delivery_accounts.len() >= entries.len() * SplitMintEntry::DELIVERY_ACCOUNTS,
+        UniversalNftError::MissingRecipientAccounts
+    );
+
+    log!("Minting NFT split delivery from cross-chain");
+    for (entry, accounts) in entries
+        .iter()
+        .zip(delivery_accounts.chunks_exact(SplitMintEntry::DELIVERY_ACCOUNTS))
+    {
+        log!("Token ID: {}", entry.item.token_id);
+        log!("Recipient: {}", entry.recipient);
+        let [universal_nft, mint, metadata, recipient, recipient_token_account] = accounts else {
+            return Err(UniversalNftError::MissingRecipientAccounts.into());
+        };
+        ensure_recipient_token_account(
+            ctx.accounts,
+            mint,
+            recipient,
+            recipient_token_account,
+            &entry.recipient,
+            true,
+            source_chain_id,
+        )?;
+        deliver_listed_nft(
+            ctx.accounts,
+            universal_nft,
+            mint,
+            metadata,
+            recipient_token_account,
+            entry.recipient,
+            &entry.item,
+            source_chain_id,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Deliver an NFT other than the OnCall one, passed in remaining accounts.
+/// Such NFTs are minted; one locked in custody returns in its own message.
+#[allow(clippy::too_many_arguments)]
+fn deliver_listed_nft<'info>(
+    accounts: &mut OnCall<'info>,
+    universal_nft_info: &'info AccountInfo<'info>,
+    mint_info: &'info AccountInfo<'info>,
+    metadata: &'info AccountInfo<'info>,
+    recipient_token_account: &AccountInfo<'info>,
+    owner: Pubkey,
+    item: &BatchMintItem,
+    source_chain_id: u64,
+) -> Result<()> {
+    require!(mint_info.is_writable && universal_nft_info.is_writable, UniversalNftError::NftNotFound);
+    let mint = Account::<Mint>::try_from(mint_info)?;
+    let (expected_nft, _) = Pubkey::find_program_address(
+        &[b"universal_nft", mint.key().as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(universal_nft_info.key(), expected_nft, UniversalNftError::NftNotFound);
+    let mut universal_nft = Account::<UniversalNft>::try_from(universal_nft_info)?;
+
+    deliver_inbound_nft(
+        accounts,
+        &mut universal_nft,
+        universal_nft_info,
+        &mint,
+        Some(metadata),
+        None,
+        recipient_token_account,
+        owner,
+        item,
+        source_chain_id,
+    )?;
+
+    universal_nft.exit(&crate::ID)
+}
+
+fn handle_burn_from_cross_chain(
+    _ctx: Context<OnCall>,
+    token_id: String,
+    owner: Pubkey,
+    _source_chain_id: u64,
+) -> Result<()> {
+    // Implementation for burning NFT from cross-chain
+    log!("Burning NFT from cross-chain");
+    log!("Token ID: {}", token_id);
+    log!("Owner: {}", owner);
+    
+    Ok(())
+}
+
+fn handle_transfer_from_cross_chain(
+    _ctx: Context<OnCall>,
+    token_id: String,
+    new_owner: Pubkey,
+    _source_chain_id: u64,
+) -> Result<()> {
+    // Implementation for transferring ownership from cross-chain
+    log!("Transferring ownership from cross-chain");
+    log!("Token ID: {}", token_id);
+    log!("New owner: {}", new_owner);
+    
+    Ok(())
+}
+
+fn handle_metadata_update_from_cross_chain(
+    _ctx: Context<OnCall>,
+    token_id: String,
+    new_uri: String,
+    _new_name: Option<String>,
+    _new_symbol: Option<String>,
+    _source_chain_id: u64,
+) -> Result<()> {
+    // Implementation for updating metadata from cross-chain
+    log!("Updating metadata from cross-chain");
+    log!("Token ID: {}", token_id);
+    log!("New URI: {}", new_uri);
+    
+    Ok(())
+}
+
+/// Create a program-owned PDA for an inbound message. The rent vault pays the
+/// rent when sponsorship is on and the source chain's ledger covers it;
+/// otherwise the payer fronts it.
+fn create_inbound_account<'info>(
+    accounts: &mut OnCall<'info>,
+    new_account: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+    source_chain_id: u64,
+) -> Result<()> {
+    let system_program = accounts.system_program
+        .as_ref()
+        .ok_or(UniversalNftError::InvalidOriginAccount)?;
+    let rent = Rent::get()?;
+    let lamports = rent.minimum_balance(space);
+
+    if let (Some(vault), Some(ledger)) = (accounts.rent_vault.as_mut(), accounts.rent_ledger.as_mut()) {
+        require!(ledger.chain_id == source_chain_id, UniversalNftError::InvalidRentLedger);
+
+        let vault_info = vault.to_account_info();
+        let spare_lamports = vault_info
+            .lamports()
+            .saturating_sub(rent.minimum_balance(vault_info.data_len()));
+
+        if new_account.lamports() == 0
+            && RentSponsorUtils::can_sponsor(vault, ledger, spare_lamports, lamports)
+        {
+            // The vault is program-owned, so its lamports move without a CPI
+            **vault_info.try_borrow_mut_lamports()? -= lamports;
+            **new_account.try_borrow_mut_lamports()? += lamports;
+
+            invoke_signed(
+                &system_instruction::allocate(new_account.key, space as u64),
+                &[new_account.clone(), system_program.to_account_info()],
+                &[signer_seeds],
+            )?;
+            invoke_signed(
+                &system_instruction::assign(new_account.key, &crate::ID),
+                &[new_account.clone(), system_program.to_account_info()],
+                &[signer_seeds],
+            )?;
+
+            RentSponsorUtils::record(vault, ledger, lamports)?;
+
+            emit!(InboundRentSponsored {
+                account: *new_account.key,
+                source_chain_id,
+                lamports,
+                chain_remaining: ledger.remaining(),
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+            log!("Inbound rent sponsored: {} lamports", lamports);
+
+            return Ok(());
+        }
+    }
+
+    let payer = accounts.payer
+        .as_ref()
+        .ok_or(UniversalNftError::InvalidOriginAccount)?;
+    invoke_signed(
+        &system_instruction::create_account(
+            &payer.key(),
+            new_account.key,
+            lamports,
+            space as u64,
+            &crate::ID,
+        ),
+        &[
+            payer.to_account_info(),
+            new_account.clone(),
+            system_program.to_account_info(),
+        ],
+        &[signer_seeds],
+    )?;
+
+    Ok(())
+}
+
+/// Mint a Solana representation of a Bitcoin inscription and record its origin.
+/// The gateway's 20-byte sender does not carry a Bitcoin address, so the full
+/// sender travels in the message and is validated against the BTC formats.
+#[allow(clippy::too_many_arguments)]
+fn handle_mint_from_bitcoin(
+    ctx: Context<OnCall>,
+    inscription: InscriptionRef,
+    btc_sender: Vec<u8>,
+    name: String,
+    symbol: String,
+    uri: String,
+    recipient: Pubkey,
+    source_chain_id: u64,
+) -> Result<()> {
+    ctx.accounts.source_chain.require_kind(source_chain_id, ChainKind::Bitcoin)?;
+
+    CrossChainUtils::validate_bitcoin_address(&btc_sender)?;
+    require!(
+        inscription.txid != [0u8; 32],
+        UniversalNftError::InvalidInscriptionReference
+    );
+    MetadataUtils::validate_name(&name)?;
+    MetadataUtils::validate_symbol(&symbol)?;
+    MetadataUtils::validate_uri(&uri)?;
+
+    log!("Minting NFT from Bitcoin inscription");
+    log!("Inscription index: {}", inscription.index);
+    log!("Recipient: {}", recipient);
+
+    // The mint was prepared under the token ID derived from the inscription
+    let item = BatchMintItem {
+        token_id: inscription.token_id(),
+        name,
+        symbol,
+        uri,
+        collection_mint: None,
+    };
+    let delivery = RecipientDelivery {
+        recipient,
+        create_token_account: true,
+        quarantine: false,
+    };
+    let recipient_info = ctx.accounts.recipient
+        .as_ref()
+        .ok_or(UniversalNftError::MissingRecipientAccounts)?
+        .to_account_info();
+    let recipient_token_account = ctx.accounts.recipient_token_account
+        .as_ref()
+        .ok_or(UniversalNftError::MissingRecipientAccounts)?
+        .to_account_info();
+    let mint = ctx.accounts.mint.to_account_info();
+    ensure_recipient_token_account(
+        ctx.accounts,
+        &mint,
+        &recipient_info,
+        &recipient_token_account,
+        &recipient,
+        true,
+        source_chain_id,
+    )?;
+    deliver_named_nft(ctx.accounts, &delivery, &item, source_chain_id)?;
+
+    // The origin is recorded once, on the NFT's first arrival
+    let origin_info = ctx.accounts.nft_origin
+        .as_ref()
+        .ok_or(UniversalNftError::InvalidOriginAccount)?
+        .to_account_info();
+    if !origin_info.data_is_empty() {
+        return Ok(());
+    }
+
+    let mint = ctx.accounts.universal_nft.mint;
+    let origin_bump = ctx.bumps.nft_origin.ok_or(UniversalNftError::InvalidOriginAccount)?;
+    create_inbound_account(
+        ctx.accounts,
+        &origin_info,
+        8 + NftOrigin::INIT_SPACE,
+        &[b"nft_origin", mint.as_ref(), &[origin_bump]],
+        source_chain_id,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let origin = NftOrigin {
+        mint,
+        origin_chain_id: source_chain_id,
+        chain_kind: ChainKind::Bitcoin,
+        origin_sender: btc_sender,
+        inscription: Some(inscription.clone()),
+        recorded_at: now,
+        bump: origin_bump,
+    };
+    {
+        let mut data = origin_info.try_borrow_mut_data()?;
+        origin.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(NftOriginRecorded {
+        mint,
+        origin_chain_id: source_chain_id,
+        chain_kind: ChainKind::Bitcoin,
+        inscription: Some(inscription),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Store a query answer in its QueryResult PDA, passed as the first remaining account
+fn handle_query_response(
+    ctx: Context<OnCall>,
+    query_id: u64,
+    success: bool,
+    data: Vec<u8>,
+    sender: &[u8; 20],
+    source_chain_id: u64,
+) -> Result<()> {
+    require!(
+        data.len() <= QueryResult::MAX_RESPONSE_LEN,
+        UniversalNftError::QueryResponseTooLarge
+    );
+
+    let query_info = ctx.remaining_accounts
+        .first()
+        .ok_or(UniversalNftError::InvalidQueryAccount)?;
+    let (expected_query, _) = Pubkey::find_program_address(
+        &[b"query", query_id.to_le_bytes().as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(query_info.key(), expected_query, UniversalNftError::InvalidQueryAccount);
+    require_keys_eq!(*query_info.owner, crate::ID, UniversalNftError::InvalidQueryAccount);
+    require!(query_info.is_writable, UniversalNftError::InvalidQueryAccount);
+
+    let mut query = {
+        let data = query_info.try_borrow_data()?;
+        QueryResult::try_deserialize(&mut &data[..])?
+    };
+    require!(query.query_id == query_id, UniversalNftError::InvalidQueryAccount);
+    require!(query.status == QueryStatus::Pending, UniversalNftError::QueryNotPending);
+    require!(
+        query.target_chain_id == source_chain_id,
+        UniversalNftError::QueryChainMismatch
+    );
+    // Only the queried contract may answer
+    require!(
+        query.contract_address == *sender,
+        UniversalNftError::QuerySenderMismatch
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    query.status = if success { QueryStatus::Answered } else { QueryStatus::Failed };
+    query.response = data;
+    query.answered_at = now;
+
+    {
+        let mut account_data = query_info.try_borrow_mut_data()?;
+        query.try_serialize(&mut &mut account_data[..])?;
+    }
+
+    emit!(InterchainQueryAnswered {
+        query_id,
+        success,
+        response_len: query.response.len() as u32,
+        timestamp: now,
+    });
+
+    log!("Interchain query answered");
+    log!("Query ID: {}", query_id);
+
+    Ok(())
+}
+
+/// Distinct collections referenced by an inbound message, in order of appearance
+fn message_collections(message: &CrossChainMessage) -> Vec<Pubkey> {
+    let mut collections: Vec<Pubkey> = Vec::new();
+    let mut add = |collection: &Option<Pubkey>| {
+        if let Some(mint) = collection {
+            if !collections.contains(mint) {
+                collections.push(*mint);
+            }
+        }
+    };
+
+    match message {
+        CrossChainMessage::MintNft { collection_mint, .. }
+        | CrossChainMessage::MintNftWithPayload { collection_mint, .. }
+        | CrossChainMessage::MintNftWithAttributes { collection_mint, .. }
+        | CrossChainMessage::MintNftWithVesting { collection_mint, .. } => add(collection_mint),
+        CrossChainMessage::MintNftBatch { items, .. } => {
+            items.iter().for_each(|item| add(&item.collection_mint))
+        }
+        CrossChainMessage::MintNftSplit { entries } => {
+            entries.iter().for_each(|entry| add(&entry.item.collection_mint))
+        }
+        _ => {}
+    }
+
+    collections
+}
+
+/// Whether any referenced collection requires an event proof. The remaining
+/// accounts carry one `proof_policy` PDA per collection, in the same order;
+/// an uninitialized PDA means the collection has no policy.
+fn proof_required(collections: &[Pubkey], policy_accounts: &[AccountInfo]) -> Result<bool> {
+    require!(
+        policy_accounts.len() >= collections.len(),
+        UniversalNftError::EventProofRequired
+    );
+
+    let mut required = false;
+    for (collection, policy_info) in collections.iter().zip(policy_accounts.iter()) {
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"proof_policy", collection.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(policy_info.key(), expected, UniversalNftError::EventProofRequired);
+
+        if policy_info.data_is_empty() {
+            continue;
+        }
+        require_keys_eq!(*policy_info.owner, crate::ID, UniversalNftError::EventProofRequired);
+        let data = policy_info.try_borrow_data()?;
+        let policy = ProofPolicy::try_deserialize(&mut &data[..])?;
+        required |= policy.required;
+    }
+
+    Ok(required)
+}
+
+/// Check the source chain's confirmation depth, if it has one configured.
+/// The finality PDA must always be passed so a sender can't skip the check by
+/// leaving it out; an uninitialized PDA means the chain has no requirement.
+fn require_source_finality(
+    source_chain_id: u64,
+    source_block: Option<u64>,
+    finality_info: &AccountInfo,
+) -> Result<()> {
+    let (expected, _) = Pubkey::find_program_address(
+        &[b"chain_finality", source_chain_id.to_le_bytes().as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(finality_info.key(), expected, UniversalNftError::SourceBlockNotFinal);
+
+    if finality_info.data_is_empty() {
+        return Ok(());
+    }
+    require_keys_eq!(*finality_info.owner, crate::ID, UniversalNftError::SourceBlockNotFinal);
+    let data = finality_info.try_borrow_data()?;
+    let finality = ChainFinality::try_deserialize(&mut &data[..])?;
+    finality.require_final(source_block)?;
+
+    log!("Source block final: {:?}", source_block);
+    Ok(())
+}
+
+/// Require that this instruction runs as a direct CPI from the gateway
+/// program recorded in the config. The transaction-level instruction being
+/// executed must target the gateway, and the stack height must be exactly one
+/// below it, so neither a direct call nor a call relayed through another
+/// program passes.
+fn verify_instruction_origin(instructions_sysvar: &UncheckedAccount, gateway_program: &Pubkey) -> Result<()> {
+    require!(
+        get_stack_height() == TRANSACTION_LEVEL_STACK_HEIGHT + 1,
+        UniversalNftError::InvalidGatewayAuthority
+    );
+
+    let instructions_sysvar = instructions_sysvar.to_account_info();
+    let current_index = load_current_index_checked(&instructions_sysvar)?;
+    let outer_instruction = load_instruction_at_checked(current_index as usize, &instructions_sysvar)?;
+    require_keys_eq!(
+        outer_instruction.program_id,
+        *gateway_program,
+        UniversalNftError::InvalidGatewayAuthority
+    );
+
+    Ok(())
+}
+
+pub(crate) fn create_gateway_call_instruction(
+    gateway_program: Pubkey,
     destination_chain_id: u64,
     recipient: Vec<u8>,
     message: Vec<u8>,
@@ -311,7 +2036,7 @@ fn create_gateway_call_instruction(
     // This would use the actual gateway program interface
     
     let instruction_data = [
-        &[0u8], // Instruction discriminator for "call"
+        &[0u8][..], // Instruction discriminator for "call"
         &destination_chain_id.to_le_bytes(),
         &(recipient.len() as u32).to_le_bytes(),
         &recipient,
@@ -330,6 +2055,7 @@ fn create_gateway_call_instruction(
 // Account structs
 
 #[derive(Accounts)]
+#[instruction(sender: [u8; 20], source_chain_id: u64)]
 pub struct OnCall<'info> {
     #[account(
         seeds = [b"config"],
@@ -340,9 +2066,204 @@ pub struct OnCall<'info> {
     #[account(mut)]
     pub universal_nft: Account<'info, UniversalNft>,
 
-    /// CHECK: Instructions sysvar for origin verification
-    #[account(address = SysvarInstructions::id())]
+    /// The NFT's mint, checked against the universal NFT invariants
+    #[account(mut, address = universal_nft.mint @ UniversalNftError::NftNotFound)]
+    pub mint: Account<'info, anchor_spl::token::Mint>,
+
+    /// CHECK: Instructions sysvar, used to verify the gateway made this call
+    #[account(address = solana_program::sysvar::instructions::ID)]
     pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Registry entry for the source chain
+    #[account(
+        seeds = [b"chain", source_chain_id.to_le_bytes().as_ref()],
+        bump = source_chain.bump
+    )]
+    pub source_chain: Account<'info, RegisteredChain>,
+
+    /// CHECK: NftOrigin PDA, created by the handler
+    #[account(
+        mut,
+        seeds = [b"nft_origin", universal_nft.mint.as_ref()],
+        bump
+    )]
+    pub nft_origin: Option<UncheckedAccount<'info>>,
+
+    /// Pays for the recipient's token account and a first arrival's
+    /// metadata, and for the NftOrigin account when the rent vault doesn't
+    /// sponsor it
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    pub system_program: Option<Program<'info, System>>,
+
+    /// CHECK: Recipient named in a mint message, its preferred recipient, or
+    /// the custody PDA for quarantined NFTs, matched in the handler. May be a
+    /// PDA or other off-curve address.
+    pub recipient: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Recipient's associated token account, created if missing
+    #[account(mut)]
+    pub recipient_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Named recipient's preferences PDA for the source chain, required
+    /// for single-recipient mints and checked by RecipientPreferencesUtils
+    pub recipient_preferences: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: QuarantinedNft PDA, created by the handler for unsolicited mints
+    #[account(
+        mut,
+        seeds = [b"quarantine", universal_nft.mint.as_ref()],
+        bump
+    )]
+    pub quarantine: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: NftAttributes PDA, created or overwritten by the handler for
+    /// mints carrying attributes
+    #[account(
+        mut,
+        seeds = [b"nft_attributes", universal_nft.mint.as_ref()],
+        bump
+    )]
+    pub nft_attributes: Option<UncheckedAccount<'info>>,
+
+    /// Vesting position of a returning NFT, checked against the message's terms
+    #[account(
+        seeds = [b"vesting", universal_nft.mint.as_ref()],
+        bump = vesting_position.bump
+    )]
+    pub vesting_position: Option<Account<'info, VestingPosition>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    /// CHECK: Program-wide mint authority, mints inbound NFTs
+    #[account(
+        seeds = [b"mint_authority"],
+        bump
+    )]
+    pub mint_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: PDA that holds NFTs in program custody
+    #[account(
+        seeds = [b"nft_custody"],
+        bump
+    )]
+    pub custody: Option<UncheckedAccount<'info>>,
+
+    /// Custody token account of a returning NFT that was locked when it
+    /// left, checked in the handler
+    #[account(mut)]
+    pub custody_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Source chain's supply ledger, counting originals locked for it
+    #[account(
+        mut,
+        seeds = [b"supply_ledger", source_chain_id.to_le_bytes().as_ref()],
+        bump = supply_ledger.bump
+    )]
+    pub supply_ledger: Option<Account<'info, ChainSupplyLedger>>,
+
+    /// CHECK: Token Metadata PDA, created on the NFT's first arrival and
+    /// checked in the handler
+    #[account(mut)]
+    pub metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Token Metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Rent sysvar, required by Token Metadata
+    #[account(address = solana_program::sysvar::rent::ID)]
+    pub rent: Option<UncheckedAccount<'info>>,
+
+    /// Protocol rent vault, sponsoring inbound account rent when funded
+    #[account(
+        mut,
+        seeds = [b"rent_vault"],
+        bump = rent_vault.bump
+    )]
+    pub rent_vault: Option<Account<'info, RentVault>>,
+
+    /// The source chain's rent ledger, checked against the message in the handler
+    #[account(
+        mut,
+        seeds = [b"rent_ledger", rent_ledger.chain_id.to_le_bytes().as_ref()],
+        bump = rent_ledger.bump
+    )]
+    pub rent_ledger: Option<Account<'info, ChainRentLedger>>,
+
+    /// Proven source event, required when a referenced collection has a proof policy
+    #[account(
+        mut,
+        seeds = [b"verified_event", verified_event.leaf.as_ref()],
+        bump = verified_event.bump
+    )]
+    pub verified_event: Option<Account<'info, VerifiedEvent>>,
+
+    /// CHECK: Observer validator set PDA, read by QuorumUtils
+    #[account(
+        seeds = [b"validator_set"],
+        bump
+    )]
+    pub validator_set: UncheckedAccount<'info>,
+
+    /// Observer attestation of this message, required once a set is registered
+    #[account(
+        mut,
+        seeds = [b"quorum_attestation", quorum_attestation.leaf.as_ref()],
+        bump = quorum_attestation.bump
+    )]
+    pub quorum_attestation: Option<Account<'info, QuorumAttestation>>,
+
+    /// CHECK: Source chain's inbound queue PDA, read by SequenceUtils
+    #[account(
+        seeds = [b"inbound_queue", source_chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub inbound_queue: UncheckedAccount<'info>,
+
+    /// Relayed delivery of this message, required once a relayer has
+    /// delivered from the source chain
+    #[account(
+        mut,
+        seeds = [b"relayed_message", relayed_message.message_hash.as_ref()],
+        bump = relayed_message.bump
+    )]
+    pub relayed_message: Option<Account<'info, RelayedMessage>>,
+
+    /// Cross-chain swap whose secret the counterparty chain is relaying
+    #[account(
+        mut,
+        seeds = [b"xswap", cross_chain_swap.maker.as_ref(), cross_chain_swap.swap_id.to_le_bytes().as_ref()],
+        bump = cross_chain_swap.bump
+    )]
+    pub cross_chain_swap: Option<Account<'info, CrossChainSwap>>,
+
+    /// CHECK: Source chain's finality PDA, checked by require_source_finality
+    pub chain_finality: UncheckedAccount<'info>,
+
+    /// CHECK: Source chain's corridor pause PDA, read by CorridorPauseUtils
+    #[account(
+        seeds = [b"corridor_pause", source_chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub corridor_pause: UncheckedAccount<'info>,
+
+    /// CHECK: Source chain's circuit breaker PDA, updated by CircuitBreakerUtils
+    #[account(
+        mut,
+        seeds = [b"circuit_breaker", source_chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_breaker: UncheckedAccount<'info>,
+
+    /// CHECK: Incident mode PDA, read by IncidentUtils
+    #[account(
+        seeds = [b"incident_mode"],
+        bump
+    )]
+    pub incident_mode: UncheckedAccount<'info>,
 }
 
 #[derive(Accounts)]
@@ -353,18 +2274,68 @@ pub struct OnRevert<'info> {
     )]
     pub config: Account<'info, ProgramConfig>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"universal_nft", transfer.nft_mint.as_ref()],
+        bump = universal_nft.bump,
+        constraint = universal_nft.mint == transfer.nft_mint @ UniversalNftError::TransferNftMismatch
+    )]
     pub universal_nft: Account<'info, UniversalNft>,
 
     #[account(mut)]
     pub transfer: Account<'info, CrossChainTransfer>,
 
-    /// CHECK: Instructions sysvar for origin verification
-    #[account(address = SysvarInstructions::id())]
+    /// CHECK: Instructions sysvar, used to verify the gateway made this call
+    #[account(address = solana_program::sysvar::instructions::ID)]
     pub instructions_sysvar: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"receipt_mint", transfer.key().as_ref()],
+        bump
+    )]
+    pub receipt_mint: Account<'info, anchor_spl::token::Mint>,
+
+    #[account(
+        mut,
+        token::mint = receipt_mint,
+    )]
+    pub receipt_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that mints, freezes and burns receipts
+    #[account(
+        seeds = [b"receipt_authority"],
+        bump
+    )]
+    pub receipt_authority: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// Cross-chain swap whose lock request is being reverted
+    #[account(
+        mut,
+        seeds = [b"xswap", cross_chain_swap.maker.as_ref(), cross_chain_swap.swap_id.to_le_bytes().as_ref()],
+        bump = cross_chain_swap.bump
+    )]
+    pub cross_chain_swap: Option<Account<'info, CrossChainSwap>>,
+
+    /// CHECK: Destination chain's metrics PDA the revert is sampled into,
+    /// verified by CorridorUtils
+    #[account(mut)]
+    pub chain_metrics: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Destination chain's circuit breaker PDA, updated by CircuitBreakerUtils
+    #[account(
+        mut,
+        seeds = [b"circuit_breaker", transfer.destination_chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_breaker: UncheckedAccount<'info>,
 }
 
+// Mirrored by crates/universal-nft-interface: keep account order in sync
 #[derive(Accounts)]
+#[instruction(destination_chain_id: u64)]
 pub struct BurnAndTransfer<'info> {
     #[account(
         mut,
@@ -373,6 +2344,14 @@ pub struct BurnAndTransfer<'info> {
     )]
     pub config: Account<'info, ProgramConfig>,
 
+    /// Registry entry for the destination chain
+    #[account(
+        mut,
+        seeds = [b"chain", destination_chain_id.to_le_bytes().as_ref()],
+        bump = destination_chain.bump
+    )]
+    pub destination_chain: Account<'info, RegisteredChain>,
+
     #[account(
         mut,
         seeds = [b"universal_nft", mint.key().as_ref()],
@@ -394,17 +2373,260 @@ pub struct BurnAndTransfer<'info> {
 
     #[account(
         mut,
-        associated_token::mint = mint,
-        associated_token::authority = owner,
+        token::mint = mint,
     )]
     pub token_account: Account<'info, TokenAccount>,
 
+    /// Non-transferable receipt for this transfer
+    #[account(
+        init,
+        payer = owner,
+        mint::decimals = 0,
+        mint::authority = receipt_authority,
+        mint::freeze_authority = receipt_authority,
+        seeds = [b"receipt_mint", transfer.key().as_ref()],
+        bump
+    )]
+    pub receipt_mint: Account<'info, anchor_spl::token::Mint>,
+
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint = receipt_mint,
+        associated_token::authority = owner,
+    )]
+    pub receipt_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that mints, freezes and burns receipts
+    #[account(
+        seeds = [b"receipt_authority"],
+        bump
+    )]
+    pub receipt_authority: UncheckedAccount<'info>,
+
+    /// NFT owner, or an operator holding a bridge-scoped session
     #[account(mut)]
     pub owner: Signer<'info>,
 
-    /// CHECK: Gateway program for cross-chain calls
+    /// CHECK: Owner's wallet lock PDA, read by WalletLockUtils
+    #[account(
+        seeds = [b"wallet_lock", universal_nft.owner.as_ref()],
+        bump
+    )]
+    pub wallet_lock: UncheckedAccount<'info>,
+
+    /// CHECK: Operator session PDA for the signer, read by OperatorSessionUtils
+    #[account(
+        seeds = [b"operator_session", universal_nft.owner.as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub operator_session: UncheckedAccount<'info>,
+
+    /// CHECK: Program of the message layer the transfer is sent through,
+    /// checked against the layer config by BridgeAdapterUtils
     pub gateway_program: UncheckedAccount<'info>,
 
+    /// Registered ZRC-20 gas token, required when paying gas in ZRC-20
+    #[account(mut)]
+    pub gas_token_config: Option<Account<'info, GasTokenConfig>>,
+
+    /// Owner's ZRC-20 token account the gas fee is drawn from
+    #[account(mut)]
+    pub gas_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Vault collecting ZRC-20 gas fees
+    #[account(mut)]
+    pub gas_token_vault: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: Collection hook registry PDA, verified by HookUtils
+    pub collection_hooks: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Collection floor price PDA, verified by VolumeCapUtils
+    pub collection_floor: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Collection config PDA, verified by CollectionConfigUtils
+    pub collection_config: Option<UncheckedAccount<'info>>,
+
+    /// Custody token account, required for lock-mode collections
+    #[account(mut)]
+    pub custody_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Scheduled transfer being executed, closed on execution
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"scheduled_transfer", mint.key().as_ref()],
+        bump = scheduled_transfer.bump,
+        has_one = owner
+    )]
+    pub scheduled_transfer: Option<Account<'info, ScheduledTransfer>>,
+
+    /// Message layer to send through; omit to use the gateway when it is the
+    /// chain's primary route
+    #[account(
+        seeds = [b"message_layer", MessageLayerConfig::seed(message_layer.layer).as_ref()],
+        bump = message_layer.bump
+    )]
+    pub message_layer: Option<Account<'info, MessageLayerConfig>>,
+
+    /// Destination chain's metadata translation rules
+    #[account(
+        seeds = [b"metadata_rules", destination_chain.chain_id.to_le_bytes().as_ref()],
+        bump = metadata_rules.bump
+    )]
+    pub metadata_rules: Option<Account<'info, ChainMetadataRules>>,
+
+    /// Destination chain's supply ledger, counting originals locked for it
+    #[account(
+        mut,
+        seeds = [b"supply_ledger", destination_chain.chain_id.to_le_bytes().as_ref()],
+        bump = supply_ledger.bump
+    )]
+    pub supply_ledger: Option<Account<'info, ChainSupplyLedger>>,
+
+    /// CHECK: NFT's attributes PDA, empty when it has none. Always passed so
+    /// the attributes can't be left behind.
+    #[account(
+        seeds = [b"nft_attributes", mint.key().as_ref()],
+        bump
+    )]
+    pub nft_attributes: UncheckedAccount<'info>,
+
+    /// CHECK: NFT's children PDA, empty when nothing was ever equipped into
+    /// it. Always passed so equipped children can't be left behind.
+    #[account(
+        seeds = [b"nft_children", mint.key().as_ref()],
+        bump
+    )]
+    pub nft_children: UncheckedAccount<'info>,
+
+    /// CHECK: NFT's vesting position PDA, empty when it wraps none. Always
+    /// passed so the position's terms can't be left out of the message.
+    #[account(
+        seeds = [b"vesting", mint.key().as_ref()],
+        bump
+    )]
+    pub vesting_position: UncheckedAccount<'info>,
+
+    /// CHECK: Protocol fee schedule PDA, read by FeeUtils
+    #[account(
+        seeds = [b"fee_schedule"],
+        bump
+    )]
+    pub fee_schedule: UncheckedAccount<'info>,
+
+    /// CHECK: Destination chain's corridor pause PDA, read by CorridorPauseUtils
+    #[account(
+        seeds = [b"corridor_pause", destination_chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub corridor_pause: UncheckedAccount<'info>,
+
+    /// CHECK: Destination chain's circuit breaker PDA, updated by CircuitBreakerUtils
+    #[account(
+        mut,
+        seeds = [b"circuit_breaker", destination_chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_breaker: UncheckedAccount<'info>,
+
+    /// CHECK: Incident mode PDA, read by IncidentUtils
+    #[account(
+        seeds = [b"incident_mode"],
+        bump
+    )]
+    pub incident_mode: UncheckedAccount<'info>,
+
+    /// CHECK: Signer's rate limit bucket PDA, updated by RateLimitUtils
+    #[account(
+        mut,
+        seeds = [b"rate_limit", b"wallet", owner.key().as_ref()],
+        bump
+    )]
+    pub wallet_rate_limit: UncheckedAccount<'info>,
+
+    /// CHECK: Destination chain's rate limit bucket PDA, updated by RateLimitUtils
+    #[account(
+        mut,
+        seeds = [b"rate_limit", b"chain", destination_chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub chain_rate_limit: UncheckedAccount<'info>,
+
+    /// CHECK: Collection's rate limit bucket PDA, updated by RateLimitUtils;
+    /// NFTs outside a collection share the default key's bucket
+    #[account(
+        mut,
+        seeds = [b"rate_limit", b"collection", universal_nft.collection_mint.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub collection_rate_limit: UncheckedAccount<'info>,
+
+    /// CHECK: Fraud detection engine PDA, updated by FraudUtils
+    #[account(
+        mut,
+        seeds = [b"fraud_engine"],
+        bump
+    )]
+    pub fraud_engine: UncheckedAccount<'info>,
+
+    /// CHECK: Chain risk registry PDA, read by FraudUtils
+    #[account(
+        seeds = [b"chain_risk_registry"],
+        bump
+    )]
+    pub chain_risk_registry: UncheckedAccount<'info>,
+
+    /// CHECK: Must match the fee schedule's fee recipient
+    #[account(mut)]
+    pub fee_recipient: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Payer's governance stake, verified by FeeUtils for a fee discount
+    pub governance_stake: Option<UncheckedAccount<'info>>,
+
+    /// Registry naming the governance deployment, required with `governance_stake`
+    #[account(
+        seeds = [b"deployment_registry"],
+        bump = deployment_registry.bump
+    )]
+    pub deployment_registry: Option<Account<'info, DeploymentRegistry>>,
+
+    /// CHECK: Payer's token account holding a membership NFT, verified by FeeUtils
+    pub membership_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Universal NFT record of the membership NFT, verified by FeeUtils
+    pub membership_nft: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Rebate campaign PDA, verified by FeeUtils
+    #[account(mut)]
+    pub rebate_campaign: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Named referrer's accrual PDA, verified by FeeUtils
+    #[account(mut)]
+    pub referral: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Owner's user metrics PDA, credited with points by PointsUtils
+    #[account(mut)]
+    pub user_metrics: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Points config PDA, verified by PointsUtils
+    pub points_config: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Usage segment PDA the transfer is attributed to, verified by
+    /// UsageSegmentUtils
+    #[account(mut)]
+    pub usage_segment: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Bridgers leaderboard PDA, verified by LeaderboardUtils
+    #[account(mut)]
+    pub leaderboard: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Bridged collections leaderboard PDA, verified by
+    /// LeaderboardUtils
+    #[account(mut)]
+    pub collection_leaderboard: Option<UncheckedAccount<'info>>,
+
     pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
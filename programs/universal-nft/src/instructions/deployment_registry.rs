@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::governance::treasury::Treasury;
+use crate::governance::{self, TREASURY_SEED};
+
+/// Replace the list of approved satellite programs and versions (treasury
+/// authority only). The first call creates the registry.
+pub fn configure_deployment_registry(
+    ctx: Context<ConfigureDeploymentRegistry>,
+    satellites: Vec<SatelliteProgram>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.treasury.authority,
+        UniversalNftError::Unauthorized
+    );
+    DeploymentRegistry::validate(&satellites)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let registry = &mut ctx.accounts.deployment_registry;
+    registry.satellites = satellites;
+    registry.updated_at = now;
+    registry.bump = ctx.bumps.deployment_registry;
+
+    emit!(DeploymentRegistryUpdated {
+        satellites: registry.satellites.clone(),
+        timestamp: now,
+    });
+
+    log!("Deployment registry updated: {} satellites", registry.satellites.len());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ConfigureDeploymentRegistry<'info> {
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+        seeds::program = governance::ID
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + DeploymentRegistry::INIT_SPACE,
+        seeds = [b"deployment_registry"],
+        bump
+    )]
+    pub deployment_registry: Account<'info, DeploymentRegistry>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
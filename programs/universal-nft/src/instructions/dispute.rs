@@ -0,0 +1,499 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::{DisputeUtils, InboundSource};
+use crate::instructions::receipt::burn_transfer_receipt;
+
+/// Basis points representing the full escrow
+pub const MAX_SPLIT_BPS: u16 = DisputeUtils::MAX_SPLIT_BPS;
+
+/// Create the dispute config and assign the arbiter (program authority only)
+pub fn initialize_dispute_config(
+    ctx: Context<InitializeDisputeConfig>,
+    arbiter: Pubkey,
+    resolution_window: i64,
+    bond: u64,
+) -> Result<()> {
+    require!(resolution_window > 0, UniversalNftError::InvalidResolutionWindow);
+
+    let dispute_config = &mut ctx.accounts.dispute_config;
+    dispute_config.authority = ctx.accounts.authority.key();
+    dispute_config.arbiter = arbiter;
+    dispute_config.resolution_window = resolution_window;
+    dispute_config.bond = bond;
+    dispute_config.total_opened = 0;
+    dispute_config.total_closed = 0;
+    dispute_config.bump = ctx.bumps.dispute_config;
+
+    log!("Dispute config initialized");
+    log!("Arbiter: {}", arbiter);
+    log!("Resolution window: {}s", resolution_window);
+    log!("Bond: {} lamports", bond);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeDisputeConfig<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + DisputeConfig::INIT_SPACE,
+        seeds = [b"dispute_config"],
+        bump
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open a dispute against a transfer still in flight, freezing the NFT and
+/// escrowing the opener's bond
+pub fn open_dispute(ctx: Context<OpenDispute>, reason_hash: [u8; 32]) -> Result<()> {
+    let transfer = &ctx.accounts.transfer;
+    DisputeUtils::require_disputable(&transfer.status)?;
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    let opener = ctx.accounts.opener.key();
+
+    // Only the NFT owner or the transfer recipient may open a dispute
+    let is_owner = universal_nft.owner == opener;
+    let is_recipient = transfer.recipient.as_slice() == opener.as_ref();
+    require!(is_owner || is_recipient, UniversalNftError::InvalidDisputeParty);
+
+    let dispute_config = &mut ctx.accounts.dispute_config;
+
+    // An owner disputes against the recipient when it is a Solana wallet;
+    // otherwise the operator who relayed the transfer answers for it
+    let counterparty = if is_owner {
+        <[u8; 32]>::try_from(transfer.recipient.as_slice())
+            .map(Pubkey::new_from_array)
+            .unwrap_or(dispute_config.authority)
+    } else {
+        universal_nft.owner
+    };
+
+    universal_nft.is_disputed = true;
+
+    dispute_config.total_opened = dispute_config.total_opened
+        .checked_add(1)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let deadline = now
+        .checked_add(dispute_config.resolution_window)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    let dispute = &mut ctx.accounts.dispute;
+    dispute.transfer = transfer.key();
+    dispute.mint = universal_nft.mint;
+    dispute.opened_by = opener;
+    dispute.original_owner = universal_nft.owner;
+    dispute.counterparty = counterparty;
+    dispute.reason_hash = reason_hash;
+    dispute.status = DisputeStatus::Open;
+    dispute.opener_share_bps = 0;
+    dispute.escrowed = dispute_config.bond;
+    dispute.opened_at = now;
+    dispute.deadline = deadline;
+    dispute.closed_at = 0;
+    dispute.bump = ctx.bumps.dispute;
+
+    if dispute.escrowed > 0 {
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.opener.to_account_info(),
+            to: dispute.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_ctx, dispute.escrowed)?;
+    }
+
+    emit!(DisputeOpened {
+        dispute: dispute.key(),
+        transfer: dispute.transfer,
+        mint: dispute.mint,
+        opened_by: opener,
+        deadline,
+        timestamp: now,
+    });
+
+    log!("Dispute opened");
+    log!("Transfer: {}", dispute.transfer);
+    log!("Deadline: {}", deadline);
+    log!("Escrowed: {} lamports", dispute.escrowed);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"dispute_config"],
+        bump = dispute_config.bump
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+
+    #[account(
+        init,
+        payer = opener,
+        space = 8 + Dispute::INIT_SPACE,
+        seeds = [b"dispute", transfer.key().as_ref()],
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        seeds = [b"transfer", transfer.nft_mint.as_ref(), transfer.nonce.to_le_bytes().as_ref()],
+        bump = transfer.bump
+    )]
+    pub transfer: Account<'info, CrossChainTransfer>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", transfer.nft_mint.as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(mut)]
+    pub opener: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Arbiter upholds the transfer and releases the NFT; the escrow goes to the
+/// counterparty
+pub fn resolve_dispute_release(ctx: Context<ResolveDispute>) -> Result<()> {
+    close_dispute(ctx, DisputeStatus::Released, 0)
+}
+
+/// Arbiter reverts a transfer still in flight, returning the NFT token to its
+/// original owner and the escrow to the opener. A locked NFT leaves custody
+/// and stops backing the destination's supply; a burned one is minted again.
+/// The sender's transfer receipt is burned.
+pub fn resolve_dispute_revert(ctx: Context<ResolveDispute>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    DisputeUtils::require_disputable(&accounts.transfer.status)?;
+    let owner_tokens = accounts.original_owner_token_account
+        .as_ref()
+        .ok_or(UniversalNftError::InvalidDisputeRevertAccounts)?;
+
+    let custody_amount = accounts.custody_token_account.as_ref().map(|custody_tokens| custody_tokens.amount);
+    match DisputeUtils::revert_source(custody_amount, accounts.mint.supply)? {
+        InboundSource::Custody => {
+            let custody_tokens = accounts.custody_token_account
+                .as_ref()
+                .ok_or(UniversalNftError::InvalidDisputeRevertAccounts)?;
+            let custody_seeds = &[b"nft_custody".as_ref(), &[ctx.bumps.custody]];
+            let signer_seeds = &[&custody_seeds[..]];
+            let cpi_accounts = Transfer {
+                from: custody_tokens.to_account_info(),
+                to: owner_tokens.to_account_info(),
+                authority: accounts.custody.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::transfer(cpi_ctx, 1)?;
+        }
+        InboundSource::Mint => {
+            let mint_authority_seeds: &[&[u8]] = &[b"mint_authority", &[ctx.bumps.mint_authority]];
+            let signer_seeds = &[mint_authority_seeds];
+            let cpi_accounts = MintTo {
+                mint: accounts.mint.to_account_info(),
+                to: owner_tokens.to_account_info(),
+                authority: accounts.mint_authority.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::mint_to(cpi_ctx, 1)?;
+        }
+    }
+
+    // The original no longer backs a wrapped representation
+    if accounts.custody_token_account.is_some() {
+        if let Some(ledger) = ctx.accounts.supply_ledger.as_mut() {
+            ledger.originals_locked = ledger.originals_locked
+                .checked_sub(1)
+                .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        }
+    }
+
+    // The transfer is no longer in flight
+    let accounts = &ctx.accounts;
+    burn_transfer_receipt(
+        &accounts.token_program,
+        accounts.receipt_mint.as_ref().ok_or(UniversalNftError::InvalidDisputeRevertAccounts)?,
+        accounts.receipt_token_account.as_ref().ok_or(UniversalNftError::InvalidDisputeRevertAccounts)?,
+        &accounts.receipt_authority,
+        ctx.bumps.receipt_authority,
+    )?;
+
+    let transfer = &mut ctx.accounts.transfer;
+    transfer.status = TransferStatus::Reverted;
+
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    universal_nft.owner = ctx.accounts.dispute.original_owner;
+    universal_nft.is_locked = false;
+    universal_nft.transfer_in_progress = false;
+
+    close_dispute(ctx, DisputeStatus::Reverted, MAX_SPLIT_BPS)
+}
+
+/// Arbiter splits the escrow between the opener and the counterparty
+pub fn resolve_dispute_split(ctx: Context<ResolveDispute>, opener_share_bps: u16) -> Result<()> {
+    require!(opener_share_bps <= MAX_SPLIT_BPS, UniversalNftError::InvalidSplitShare);
+
+    close_dispute(ctx, DisputeStatus::Split, opener_share_bps)
+}
+
+/// Close a dispute whose deadline passed without resolution, releasing the NFT
+/// and refunding the escrow to the opener
+pub fn expire_dispute(ctx: Context<ExpireDispute>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let dispute = &mut ctx.accounts.dispute;
+
+    require!(dispute.status == DisputeStatus::Open, UniversalNftError::DisputeNotOpen);
+    require!(now > dispute.deadline, UniversalNftError::DisputeDeadlineNotReached);
+
+    let refund = dispute.escrowed;
+    pay_out_escrow(dispute, &ctx.accounts.opener, refund)?;
+
+    dispute.status = DisputeStatus::Expired;
+    dispute.escrowed = 0;
+    dispute.closed_at = now;
+    ctx.accounts.universal_nft.is_disputed = false;
+
+    let dispute_config = &mut ctx.accounts.dispute_config;
+    dispute_config.total_closed = dispute_config.total_closed
+        .checked_add(1)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    emit!(DisputeClosed {
+        dispute: dispute.key(),
+        mint: dispute.mint,
+        status: DisputeStatus::Expired,
+        opener_share_bps: 0,
+        opener_payout: refund,
+        counterparty_payout: 0,
+        timestamp: now,
+    });
+
+    log!("Dispute expired without resolution");
+
+    Ok(())
+}
+
+fn close_dispute(
+    ctx: Context<ResolveDispute>,
+    status: DisputeStatus,
+    opener_share_bps: u16,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let dispute = &mut ctx.accounts.dispute;
+
+    require!(dispute.status == DisputeStatus::Open, UniversalNftError::DisputeNotOpen);
+    require!(now <= dispute.deadline, UniversalNftError::DisputeDeadlinePassed);
+
+    let (opener_payout, counterparty_payout) = DisputeUtils::payouts(dispute.escrowed, opener_share_bps)?;
+    pay_out_escrow(dispute, &ctx.accounts.opener, opener_payout)?;
+    pay_out_escrow(dispute, &ctx.accounts.counterparty, counterparty_payout)?;
+
+    dispute.status = status.clone();
+    dispute.opener_share_bps = opener_share_bps;
+    dispute.escrowed = 0;
+    dispute.closed_at = now;
+    ctx.accounts.universal_nft.is_disputed = false;
+
+    let dispute_config = &mut ctx.accounts.dispute_config;
+    dispute_config.total_closed = dispute_config.total_closed
+        .checked_add(1)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    emit!(DisputeClosed {
+        dispute: dispute.key(),
+        mint: dispute.mint,
+        status,
+        opener_share_bps,
+        opener_payout,
+        counterparty_payout,
+        timestamp: now,
+    });
+
+    log!("Dispute resolved");
+    log!("Transfer: {}", dispute.transfer);
+    log!("Opener share: {} bps", opener_share_bps);
+    log!("Paid out: {} lamports to opener, {} to counterparty", opener_payout, counterparty_payout);
+
+    Ok(())
+}
+
+/// Move escrowed lamports out of a dispute. The dispute is program-owned, so
+/// its lamports move without a CPI.
+fn pay_out_escrow<'info>(
+    dispute: &Account<'info, Dispute>,
+    recipient: &UncheckedAccount<'info>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    **dispute.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **recipient.to_account_info().try_borrow_mut_lamports()? += amount;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"dispute_config"],
+        bump = dispute_config.bump,
+        has_one = arbiter
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", transfer.key().as_ref()],
+        bump = dispute.bump,
+        has_one = transfer,
+        has_one = mint
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(mut)]
+    pub transfer: Account<'info, CrossChainTransfer>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", mint.key().as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: Receives the opener's share of the escrow
+    #[account(mut, address = dispute.opened_by)]
+    pub opener: UncheckedAccount<'info>,
+
+    /// CHECK: Receives the counterparty's share of the escrow
+    #[account(mut, address = dispute.counterparty)]
+    pub counterparty: UncheckedAccount<'info>,
+
+    /// Token account the NFT is returned to on revert
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = dispute.original_owner,
+    )]
+    pub original_owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Custody token account holding the NFT of a lock-mode transfer
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = custody,
+    )]
+    pub custody_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: PDA that holds NFTs in program custody
+    #[account(
+        seeds = [b"nft_custody"],
+        bump
+    )]
+    pub custody: UncheckedAccount<'info>,
+
+    /// CHECK: Program-wide mint authority, mints a burned NFT again on revert
+    #[account(
+        seeds = [b"mint_authority"],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// Destination chain's supply ledger, released from a locked NFT on revert
+    #[account(
+        mut,
+        seeds = [b"supply_ledger", transfer.destination_chain_id.to_le_bytes().as_ref()],
+        bump = supply_ledger.bump
+    )]
+    pub supply_ledger: Option<Account<'info, ChainSupplyLedger>>,
+
+    /// Transfer receipt, burned on revert
+    #[account(
+        mut,
+        seeds = [b"receipt_mint", transfer.key().as_ref()],
+        bump
+    )]
+    pub receipt_mint: Option<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = receipt_mint,
+    )]
+    pub receipt_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// CHECK: PDA that mints, freezes and burns receipts
+    #[account(
+        seeds = [b"receipt_authority"],
+        bump
+    )]
+    pub receipt_authority: UncheckedAccount<'info>,
+
+    pub arbiter: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ExpireDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"dispute_config"],
+        bump = dispute_config.bump
+    )]
+    pub dispute_config: Account<'info, DisputeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"dispute", dispute.transfer.as_ref()],
+        bump = dispute.bump,
+        has_one = mint
+    )]
+    pub dispute: Account<'info, Dispute>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", mint.key().as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    /// CHECK: Only used to derive the NFT PDA, matched against the dispute
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: Receives the refunded escrow
+    #[account(mut, address = dispute.opened_by)]
+    pub opener: UncheckedAccount<'info>,
+}
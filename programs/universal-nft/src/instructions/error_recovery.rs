@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::recovery::error_recovery::{
+    ErrorRecoveryManager, ErrorType, OperationContext, RecoveryConfig, RecoveryResult, RecoverySession,
+};
+
+/// Create the error recovery manager that tracks recovery sessions for
+/// failed transfers (program authority only). `None` takes the defaults.
+pub fn initialize_error_recovery(
+    ctx: Context<InitializeErrorRecovery>,
+    config: Option<RecoveryConfig>,
+) -> Result<()> {
+    ctx.accounts.recovery_manager.initialize(
+        ctx.accounts.authority.key(),
+        config.unwrap_or_default(),
+        ctx.bumps.recovery_manager,
+    )
+}
+
+#[derive(Accounts)]
+pub struct InitializeErrorRecovery<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ErrorRecoveryManager::INIT_SPACE,
+        seeds = [b"error_recovery"],
+        bump
+    )]
+    pub recovery_manager: Account<'info, ErrorRecoveryManager>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open a recovery session for an outbound transfer that has not landed
+/// (recovery manager authority only). The session is keyed to the transfer,
+/// so compensation and insurance claims can read its outcome.
+pub fn open_recovery_session(ctx: Context<OpenRecoverySession>, error_type: ErrorType) -> Result<()> {
+    let transfer = &ctx.accounts.transfer;
+    require!(
+        transfer.status != TransferStatus::Completed,
+        UniversalNftError::InvalidTransferStatus
+    );
+
+    let operation_context = OperationContext {
+        operation_type: "burn_and_transfer".to_string(),
+        user: transfer.initiator,
+        nft_mint: Some(transfer.nft_mint),
+        target_chain: Some(transfer.destination_chain_id),
+        failed_signature: None,
+        compute_units_used: 0,
+        fees_paid: transfer.gas_fee,
+    };
+
+    let accounts = &mut *ctx.accounts;
+    let session_id = accounts.recovery_manager.total_recovery_attempts;
+    accounts.recovery_manager.initiate_recovery(
+        &mut accounts.recovery_session,
+        session_id,
+        error_type.clone(),
+        operation_context,
+    )?;
+
+    let session = &mut accounts.recovery_session;
+    session.bump = ctx.bumps.recovery_session;
+
+    emit!(RecoverySessionOpened {
+        session: session.key(),
+        session_id,
+        transfer: accounts.transfer.key(),
+        error_type,
+        timestamp: session.started_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct OpenRecoverySession<'info> {
+    #[account(
+        mut,
+        seeds = [b"error_recovery"],
+        bump = recovery_manager.bump,
+        has_one = authority
+    )]
+    pub recovery_manager: Account<'info, ErrorRecoveryManager>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RecoverySession::INIT_SPACE,
+        seeds = [b"recovery_session", transfer.key().as_ref()],
+        bump
+    )]
+    pub recovery_session: Account<'info, RecoverySession>,
+
+    /// Transfer the session recovers
+    pub transfer: Account<'info, CrossChainTransfer>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Record the final result of a recovery session (recovery manager
+/// authority only)
+pub fn conclude_recovery_session(
+    ctx: Context<ConcludeRecoverySession>,
+    result: RecoveryResult,
+) -> Result<()> {
+    let accounts = &mut *ctx.accounts;
+    accounts.recovery_manager.conclude_recovery_session(&mut accounts.recovery_session, result.clone())?;
+
+    let session = &accounts.recovery_session;
+    emit!(RecoverySessionConcluded {
+        session: session.key(),
+        session_id: session.session_id,
+        transfer: accounts.transfer.key(),
+        result,
+        timestamp: session.completed_at.unwrap_or_default(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ConcludeRecoverySession<'info> {
+    #[account(
+        mut,
+        seeds = [b"error_recovery"],
+        bump = recovery_manager.bump,
+        has_one = authority
+    )]
+    pub recovery_manager: Account<'info, ErrorRecoveryManager>,
+
+    #[account(
+        mut,
+        seeds = [b"recovery_session", transfer.key().as_ref()],
+        bump = recovery_session.bump
+    )]
+    pub recovery_session: Account<'info, RecoverySession>,
+
+    pub transfer: Account<'info, CrossChainTransfer>,
+
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,271 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use mpl_token_metadata::{
+    instructions::{UpdateMetadataAccountV2, UpdateMetadataAccountV2InstructionArgs},
+    types::DataV2,
+};
+use solana_program::program::invoke_signed;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Create or replace a collection's evolution recipe (collection authority
+/// only)
+pub fn set_evolution_recipe(
+    ctx: Context<SetEvolutionRecipe>,
+    recipe_id: u32,
+    params: EvolutionRecipeParams,
+) -> Result<()> {
+    EvolutionRecipe::validate(&params)?;
+    if !params.uri.is_empty() {
+        MetadataUtils::validate_uri(&params.uri)?;
+    }
+
+    let recipe = &mut ctx.accounts.recipe;
+    recipe.collection_mint = ctx.accounts.collection.mint;
+    recipe.recipe_id = recipe_id;
+    recipe.inputs = params.inputs;
+    recipe.requires = params.requires;
+    recipe.sets = params.sets;
+    recipe.uri = params.uri;
+    recipe.bump = ctx.bumps.recipe;
+
+    emit!(EvolutionRecipeSet {
+        collection_mint: recipe.collection_mint,
+        recipe_id,
+        inputs: recipe.inputs.len() as u8,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Evolution recipe {} set", recipe_id);
+    Ok(())
+}
+
+/// Remove an evolution recipe (collection authority only)
+pub fn remove_evolution_recipe(ctx: Context<RemoveEvolutionRecipe>, recipe_id: u32) -> Result<()> {
+    emit!(EvolutionRecipeRemoved {
+        collection_mint: ctx.accounts.collection.mint,
+        recipe_id,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Evolution recipe {} removed", recipe_id);
+    Ok(())
+}
+
+/// Evolve an NFT by a recipe of its collection. The owner's resources are
+/// burned and the NFT's attributes and URI updated in the same instruction.
+///
+/// Each recipe input is passed as remaining accounts
+/// `[resource_mint, owner_token_account]`, in recipe order.
+pub fn evolve_nft<'info>(
+    ctx: Context<'_, '_, 'info, 'info, EvolveNft<'info>>,
+    recipe_id: u32,
+) -> Result<()> {
+    require!(!ctx.accounts.config.is_paused, UniversalNftError::ProgramPaused);
+    validate::metadata_pda(&ctx.accounts.metadata.key(), &ctx.accounts.mint.key())?;
+
+    let owner = ctx.accounts.owner.key();
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    universal_nft.require_no_transfer_in_progress()?;
+    require!(!universal_nft.is_locked, UniversalNftError::NftLocked);
+    require!(!universal_nft.is_disputed, UniversalNftError::NftUnderDispute);
+    require!(!universal_nft.is_frozen, UniversalNftError::NftFrozen);
+    require!(universal_nft.owner == owner, UniversalNftError::InvalidNftOwner);
+
+    let recipe = &ctx.accounts.recipe;
+    let attributes = &mut ctx.accounts.nft_attributes;
+    require!(
+        attributes.contains_all(&recipe.requires),
+        UniversalNftError::EvolutionRequirementsNotMet
+    );
+
+    // Burn the resources
+    require!(
+        ctx.remaining_accounts.len() == recipe.inputs.len() * 2,
+        UniversalNftError::InvalidResourceAccounts
+    );
+    for (input, accounts) in recipe.inputs.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let (mint_info, token_info) = (&accounts[0], &accounts[1]);
+        require_keys_eq!(mint_info.key(), input.mint, UniversalNftError::InvalidResourceAccounts);
+        let token_account = Account::<TokenAccount>::try_from(token_info)?;
+        require_keys_eq!(token_account.mint, input.mint, UniversalNftError::InvalidResourceAccounts);
+        require_keys_eq!(token_account.owner, owner, UniversalNftError::InvalidResourceAccounts);
+
+        let cpi_accounts = Burn {
+            mint: mint_info.clone(),
+            from: token_info.clone(),
+            authority: ctx.accounts.owner.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+        token::burn(cpi_ctx, input.amount)?;
+    }
+
+    // Write the recipe's entries
+    let now = Clock::get()?.unix_timestamp;
+    attributes.mint = universal_nft.mint;
+    attributes.collection_mint = recipe.collection_mint;
+    attributes.bump = ctx.bumps.nft_attributes;
+    for entry in recipe.sets.iter() {
+        attributes.set(entry.key.clone(), entry.value.clone())?;
+    }
+    attributes.updated_at = now;
+
+    if !recipe.uri.is_empty() {
+        universal_nft.uri = recipe.uri.clone();
+
+        // Point the token metadata at the evolved URI
+        let mint_key = ctx.accounts.mint.key();
+        let metadata_seeds = &[b"universal_nft".as_ref(), mint_key.as_ref(), &[universal_nft.bump]];
+        let signer_seeds = &[&metadata_seeds[..]];
+        let data = DataV2 {
+            name: universal_nft.name.clone(),
+            symbol: universal_nft.symbol.clone(),
+            uri: recipe.uri.clone(),
+            seller_fee_basis_points: ctx.accounts.collection_config.seller_fee_basis_points,
+            creators: None,
+            collection: universal_nft.collection_mint.map(|mint| {
+                mpl_token_metadata::types::Collection {
+                    verified: false,
+                    key: mint,
+                }
+            }),
+            uses: None,
+        };
+        let update_metadata_ix = UpdateMetadataAccountV2 {
+            metadata: ctx.accounts.metadata.key(),
+            update_authority: universal_nft.key(),
+        };
+        invoke_signed(
+            &update_metadata_ix.instruction(UpdateMetadataAccountV2InstructionArgs {
+                data: Some(data),
+                new_update_authority: None,
+                primary_sale_happened: None,
+                is_mutable: None,
+            }),
+            &[
+                ctx.accounts.metadata.to_account_info(),
+                universal_nft.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+    }
+
+    emit!(NftEvolved {
+        mint: universal_nft.mint,
+        collection_mint: recipe.collection_mint,
+        recipe_id,
+        owner,
+        uri: universal_nft.uri.clone(),
+        timestamp: now,
+    });
+
+    log!("NFT evolved by recipe {}", recipe_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(recipe_id: u32)]
+pub struct SetEvolutionRecipe<'info> {
+    #[account(
+        seeds = [b"collection", collection.mint.as_ref()],
+        bump = collection.bump,
+        has_one = authority
+    )]
+    pub collection: Account<'info, UniversalCollection>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + EvolutionRecipe::INIT_SPACE,
+        seeds = [b"evolution_recipe", collection.mint.as_ref(), recipe_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub recipe: Account<'info, EvolutionRecipe>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(recipe_id: u32)]
+pub struct RemoveEvolutionRecipe<'info> {
+    #[account(
+        seeds = [b"collection", collection.mint.as_ref()],
+        bump = collection.bump,
+        has_one = authority
+    )]
+    pub collection: Account<'info, UniversalCollection>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"evolution_recipe", collection.mint.as_ref(), recipe_id.to_le_bytes().as_ref()],
+        bump = recipe.bump
+    )]
+    pub recipe: Account<'info, EvolutionRecipe>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(recipe_id: u32)]
+pub struct EvolveNft<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"evolution_recipe", recipe.collection_mint.as_ref(), recipe_id.to_le_bytes().as_ref()],
+        bump = recipe.bump
+    )]
+    pub recipe: Account<'info, EvolutionRecipe>,
+
+    /// Collection's config, for the royalty written back into the metadata
+    #[account(
+        seeds = [b"collection_config", recipe.collection_mint.as_ref()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", mint.key().as_ref()],
+        bump = universal_nft.bump,
+        constraint = universal_nft.collection_mint == Some(recipe.collection_mint)
+            @ UniversalNftError::CollectionVerificationFailed
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: Metadata PDA, checked by validate::metadata_pda
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + NftAttributes::INIT_SPACE,
+        seeds = [b"nft_attributes", mint.key().as_ref()],
+        bump
+    )]
+    pub nft_attributes: Account<'info, NftAttributes>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Token Metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::governance::treasury::Treasury;
+use crate::governance::{self, TREASURY_SEED};
+
+/// Queue new protocol fees (treasury authority only). They replace any
+/// queued schedule and can be applied once `FeeSchedule::TIMELOCK` has
+/// passed; the first call creates the schedule with every fee switched off.
+pub fn queue_fee_schedule(ctx: Context<GovernFeeSchedule>, rates: FeeRates) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.treasury.authority,
+        UniversalNftError::Unauthorized
+    );
+    rates.validate()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let schedule = &mut ctx.accounts.fee_schedule;
+    if schedule.bump == 0 {
+        schedule.active = FeeRates {
+            enabled: false,
+            mint_fee: 0,
+            bridge_fee_bps: 0,
+            metadata_update_fee: 0,
+            marketplace_fee_bps: 0,
+            fee_recipient: ctx.accounts.treasury.key(),
+            stake_tiers: [StakeDiscountTier::default(); 3],
+            membership_collection: None,
+            membership_discount_bps: 0,
+            referral_share_bps: 0,
+        };
+        schedule.updated_at = now;
+        schedule.bump = ctx.bumps.fee_schedule;
+    }
+
+    let effective_at = now + FeeSchedule::TIMELOCK;
+    schedule.pending = Some(rates);
+    schedule.pending_effective_at = effective_at;
+
+    emit!(FeeScheduleQueued {
+        rates,
+        effective_at,
+        timestamp: now,
+    });
+
+    log!("Fee schedule queued, effective at {}", effective_at);
+    Ok(())
+}
+
+/// Withdraw the queued fee schedule (treasury authority only)
+pub fn cancel_fee_schedule(ctx: Context<GovernFeeSchedule>) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.treasury.authority,
+        UniversalNftError::Unauthorized
+    );
+
+    let schedule = &mut ctx.accounts.fee_schedule;
+    require!(schedule.pending.is_some(), UniversalNftError::NoPendingFeeSchedule);
+    schedule.pending = None;
+    schedule.pending_effective_at = 0;
+
+    emit!(FeeScheduleCancelled {
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Queued fee schedule cancelled");
+    Ok(())
+}
+
+/// Put the queued fee schedule into effect once its timelock has passed.
+/// Anyone may call this.
+pub fn apply_fee_schedule(ctx: Context<ApplyFeeSchedule>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let schedule = &mut ctx.accounts.fee_schedule;
+    let rates = schedule.pending.ok_or(UniversalNftError::NoPendingFeeSchedule)?;
+    require!(
+        now >= schedule.pending_effective_at,
+        UniversalNftError::FeeScheduleTimelocked
+    );
+
+    schedule.active = rates;
+    schedule.pending = None;
+    schedule.pending_effective_at = 0;
+    schedule.updated_at = now;
+
+    emit!(FeeScheduleApplied {
+        rates,
+        timestamp: now,
+    });
+
+    log!("Fee schedule applied, fees enabled: {}", rates.enabled);
+    Ok(())
+}
+
+/// Create or reconfigure the fee rebate campaign (treasury authority only).
+/// Lamports already refunded and deposited carry over.
+pub fn configure_rebate_campaign(
+    ctx: Context<ConfigureRebateCampaign>,
+    params: RebateCampaignParams,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.treasury.authority,
+        UniversalNftError::Unauthorized
+    );
+    require!(
+        params.ends_at > params.starts_at
+            && params.rebate_per_tx > 0
+            && params.eligible_kinds != 0
+            && params.eligible_kinds & !RebateCampaign::ALL_KINDS == 0,
+        UniversalNftError::InvalidRebateCampaign
+    );
+
+    let campaign = &mut ctx.accounts.rebate_campaign;
+    require!(params.budget >= campaign.spent, UniversalNftError::InvalidRebateCampaign);
+    campaign.budget = params.budget;
+    campaign.rebate_per_tx = params.rebate_per_tx;
+    campaign.starts_at = params.starts_at;
+    campaign.ends_at = params.ends_at;
+    campaign.eligible_kinds = params.eligible_kinds;
+    campaign.bump = ctx.bumps.rebate_campaign;
+
+    emit!(RebateCampaignConfigured {
+        budget: params.budget,
+        rebate_per_tx: params.rebate_per_tx,
+        starts_at: params.starts_at,
+        ends_at: params.ends_at,
+        eligible_kinds: params.eligible_kinds,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Rebate campaign configured, budget {}", params.budget);
+    Ok(())
+}
+
+/// Return an ended campaign's unspent lamports to the treasury (treasury
+/// authority only)
+pub fn reclaim_rebate_funds(ctx: Context<ReclaimRebateFunds>) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.treasury.authority,
+        UniversalNftError::Unauthorized
+    );
+    let campaign = ctx.accounts.rebate_campaign.to_account_info();
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.rebate_campaign.ends_at,
+        UniversalNftError::InvalidRebateCampaign
+    );
+
+    let reserve = Rent::get()?.minimum_balance(campaign.data_len());
+    let amount = campaign.lamports().saturating_sub(reserve);
+    **campaign.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    log!("Rebate funds reclaimed: {} lamports", amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct GovernFeeSchedule<'info> {
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+        seeds::program = governance::ID
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + FeeSchedule::INIT_SPACE,
+        seeds = [b"fee_schedule"],
+        bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApplyFeeSchedule<'info> {
+    #[account(
+        mut,
+        seeds = [b"fee_schedule"],
+        bump = fee_schedule.bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureRebateCampaign<'info> {
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+        seeds::program = governance::ID
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RebateCampaign::INIT_SPACE,
+        seeds = [b"rebate_campaign"],
+        bump
+    )]
+    pub rebate_campaign: Account<'info, RebateCampaign>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimRebateFunds<'info> {
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+        seeds::program = governance::ID
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        seeds = [b"rebate_campaign"],
+        bump = rebate_campaign.bump
+    )]
+    pub rebate_campaign: Account<'info, RebateCampaign>,
+
+    pub authority: Signer<'info>,
+}
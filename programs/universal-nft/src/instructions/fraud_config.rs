@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::governance::proposals::Proposal;
+use crate::security::FraudDetectionEngine;
+
+/// Apply the fraud config carried by an executed `ProtocolUpdate` proposal.
+/// Anyone may submit it.
+pub fn apply_fraud_config_proposal(ctx: Context<ApplyFraudConfigProposal>) -> Result<()> {
+    ctx.accounts.fraud_engine.apply_config_proposal(&ctx.accounts.proposal)
+}
+
+#[derive(Accounts)]
+pub struct ApplyFraudConfigProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"fraud_engine"],
+        bump = fraud_engine.bump
+    )]
+    pub fraud_engine: Account<'info, FraudDetectionEngine>,
+
+    /// Executed governance proposal carrying the new config
+    pub proposal: Account<'info, Proposal>,
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::security::{FraudConfig, FraudDetectionEngine};
+
+/// Create the fraud detection engine (program authority only). Outbound
+/// transfers are then scored against recent traffic and the chain risk
+/// registry; `None` takes the default thresholds and weights.
+pub fn initialize_fraud_engine(
+    ctx: Context<InitializeFraudEngine>,
+    config: Option<FraudConfig>,
+) -> Result<()> {
+    if let Some(config) = &config {
+        config.validate()?;
+    }
+
+    ctx.accounts.fraud_engine.initialize(
+        ctx.accounts.authority.key(),
+        config,
+        ctx.bumps.fraud_engine,
+    );
+
+    log!("Fraud engine initialized: {}", ctx.accounts.fraud_engine.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeFraudEngine<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + FraudDetectionEngine::INIT_SPACE,
+        seeds = [b"fraud_engine"],
+        bump
+    )]
+    pub fraud_engine: Account<'info, FraudDetectionEngine>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
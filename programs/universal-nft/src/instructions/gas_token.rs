@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::*;
+
+/// Register an SPL token representing a ZRC-20 gas token (authority only)
+pub fn register_gas_token(
+    ctx: Context<RegisterGasToken>,
+    destination_chain_id: u64,
+    price_per_gas_unit: u64,
+) -> Result<()> {
+    CrossChainUtils::validate_chain_id(destination_chain_id, &ctx.accounts.destination_chain)?;
+    require!(price_per_gas_unit > 0, UniversalNftError::InvalidGasPrice);
+
+    let gas_token = &mut ctx.accounts.gas_token_config;
+    gas_token.zrc20_mint = ctx.accounts.zrc20_mint.key();
+    gas_token.destination_chain_id = destination_chain_id;
+    gas_token.vault = ctx.accounts.gas_token_vault.key();
+    gas_token.price_per_gas_unit = price_per_gas_unit;
+    gas_token.enabled = true;
+    gas_token.total_collected = 0;
+    gas_token.bump = ctx.bumps.gas_token_config;
+
+    log!("Gas token registered");
+    log!("Mint: {}", gas_token.zrc20_mint);
+    log!("Destination chain: {}", destination_chain_id);
+    log!("Price per gas unit: {}", price_per_gas_unit);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(destination_chain_id: u64)]
+pub struct RegisterGasToken<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + GasTokenConfig::INIT_SPACE,
+        seeds = [b"gas_token", zrc20_mint.key().as_ref()],
+        bump
+    )]
+    pub gas_token_config: Account<'info, GasTokenConfig>,
+
+    pub zrc20_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        token::mint = zrc20_mint,
+        token::authority = gas_token_config,
+        seeds = [b"gas_vault", zrc20_mint.key().as_ref()],
+        bump
+    )]
+    pub gas_token_vault: Account<'info, TokenAccount>,
+
+    /// Registry entry for the chain the gas token pays for
+    #[account(
+        seeds = [b"chain", destination_chain_id.to_le_bytes().as_ref()],
+        bump = destination_chain.bump
+    )]
+    pub destination_chain: Account<'info, RegisteredChain>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Update a registered gas token's price and status (authority only)
+pub fn update_gas_token(
+    ctx: Context<UpdateGasToken>,
+    price_per_gas_unit: u64,
+    enabled: bool,
+) -> Result<()> {
+    require!(price_per_gas_unit > 0, UniversalNftError::InvalidGasPrice);
+
+    let gas_token = &mut ctx.accounts.gas_token_config;
+    gas_token.price_per_gas_unit = price_per_gas_unit;
+    gas_token.enabled = enabled;
+
+    log!("Gas token updated");
+    log!("Price per gas unit: {}", price_per_gas_unit);
+    log!("Enabled: {}", enabled);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateGasToken<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"gas_token", gas_token_config.zrc20_mint.as_ref()],
+        bump = gas_token_config.bump
+    )]
+    pub gas_token_config: Account<'info, GasTokenConfig>,
+
+    pub authority: Signer<'info>,
+}
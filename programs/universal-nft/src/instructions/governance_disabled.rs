@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::{ChainRiskParams, FeeRates, RebateCampaignParams, SatelliteProgram};
+
+// Anchor 0.30's #[program] ignores #[cfg] on instructions, so a build without
+// the `governance` feature keeps the treasury-gated entry points as these
+// stubs. They take no accounts and always fail with FeatureDisabled; none of
+// the compensation, insurance, mint authority, fee schedule, chain risk,
+// deployment registry, validator set or fraud config proposal logic is
+// compiled in.
+
+#[derive(Accounts)]
+pub struct InitializeCompensationVault {}
+
+#[derive(Accounts)]
+pub struct FundCompensationVault {}
+
+#[derive(Accounts)]
+pub struct ClaimCompensation {}
+
+#[derive(Accounts)]
+pub struct InitializeInsurancePool {}
+
+#[derive(Accounts)]
+pub struct UpdateInsuranceParameters {}
+
+#[derive(Accounts)]
+pub struct InsureTransfer {}
+
+#[derive(Accounts)]
+pub struct ClaimInsurance {}
+
+#[derive(Accounts)]
+pub struct HandOverMintAuthority {}
+
+#[derive(Accounts)]
+pub struct GovernFeeSchedule {}
+
+#[derive(Accounts)]
+pub struct ApplyFeeSchedule {}
+
+#[derive(Accounts)]
+pub struct InitializeValidatorSet {}
+
+#[derive(Accounts)]
+pub struct ApplyValidatorSetProposal {}
+
+#[derive(Accounts)]
+pub struct ConfigureRebateCampaign {}
+
+#[derive(Accounts)]
+pub struct ReclaimRebateFunds {}
+
+#[derive(Accounts)]
+pub struct ConfigureChainRisk {}
+
+#[derive(Accounts)]
+pub struct ConfigureDeploymentRegistry {}
+
+#[derive(Accounts)]
+pub struct ApplyFraudConfigProposal {}
+
+pub fn initialize_compensation_vault(
+    _ctx: Context<InitializeCompensationVault>,
+    _per_user_cap: u64,
+) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn fund_compensation_vault(_ctx: Context<FundCompensationVault>, _amount: u64) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn claim_compensation(_ctx: Context<ClaimCompensation>) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn initialize_insurance_pool(
+    _ctx: Context<InitializeInsurancePool>,
+    _premium_lamports: u64,
+    _payout_lamports: u64,
+) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn update_insurance_parameters(
+    _ctx: Context<UpdateInsuranceParameters>,
+    _premium_lamports: u64,
+    _payout_lamports: u64,
+    _enabled: bool,
+) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn insure_transfer(_ctx: Context<InsureTransfer>) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn claim_insurance(_ctx: Context<ClaimInsurance>) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn hand_over_mint_authority(
+    _ctx: Context<HandOverMintAuthority>,
+    _new_authority: Pubkey,
+) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn queue_fee_schedule(_ctx: Context<GovernFeeSchedule>, _rates: FeeRates) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn cancel_fee_schedule(_ctx: Context<GovernFeeSchedule>) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn apply_fee_schedule(_ctx: Context<ApplyFeeSchedule>) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn initialize_validator_set(
+    _ctx: Context<InitializeValidatorSet>,
+    _validators: Vec<[u8; 20]>,
+    _threshold: u8,
+) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn apply_validator_set_proposal(_ctx: Context<ApplyValidatorSetProposal>) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn configure_rebate_campaign(
+    _ctx: Context<ConfigureRebateCampaign>,
+    _params: RebateCampaignParams,
+) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn reclaim_rebate_funds(_ctx: Context<ReclaimRebateFunds>) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn configure_chain_risk(_ctx: Context<ConfigureChainRisk>, _params: ChainRiskParams) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn configure_deployment_registry(
+    _ctx: Context<ConfigureDeploymentRegistry>,
+    _satellites: Vec<SatelliteProgram>,
+) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+pub fn apply_fraud_config_proposal(_ctx: Context<ApplyFraudConfigProposal>) -> Result<()> {
+    err!(UniversalNftError::FeatureDisabled)
+}
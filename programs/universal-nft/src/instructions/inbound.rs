@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::InboundDeliveryUtils;
+
+/// Create the mint and universal NFT record a token from another chain is
+/// delivered into on its first arrival. Gateway calls carry a fixed account
+/// list, so the relayer prepares them in the delivery transaction; `on_call`
+/// mints the token and creates its metadata. Permissionless.
+pub fn prepare_inbound_nft(
+    ctx: Context<PrepareInboundNft>,
+    source_chain_id: u64,
+    token_id: String,
+) -> Result<()> {
+    require!(ctx.accounts.source_chain.enabled, UniversalNftError::ChainDisabled);
+    require!(
+        !token_id.is_empty() && token_id.len() <= 64,
+        UniversalNftError::InvalidTokenId
+    );
+
+    let clock = Clock::get()?;
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    universal_nft.mint = ctx.accounts.mint.key();
+    universal_nft.origin_chain_id = source_chain_id;
+    universal_nft.origin_token_id = token_id;
+    // No owner until on_call delivers the first token
+    universal_nft.owner = Pubkey::default();
+    universal_nft.uri = String::new();
+    universal_nft.name = String::new();
+    universal_nft.symbol = String::new();
+    universal_nft.collection_mint = None;
+    universal_nft.creation_block = clock.slot;
+    universal_nft.creation_timestamp = clock.unix_timestamp;
+    universal_nft.bump = ctx.bumps.universal_nft;
+    universal_nft.is_locked = false;
+    universal_nft.is_disputed = false;
+    universal_nft.is_frozen = false;
+    universal_nft.transfer_in_progress = false;
+    universal_nft.is_flagged = false;
+
+    emit!(InboundNftPrepared {
+        mint: universal_nft.mint,
+        source_chain_id,
+        token_id: universal_nft.origin_token_id.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    log!("Inbound NFT prepared");
+    log!("Mint: {}", universal_nft.mint);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(source_chain_id: u64, token_id: String)]
+pub struct PrepareInboundNft<'info> {
+    /// Registry entry for the chain the NFT arrives from
+    #[account(
+        seeds = [b"chain", source_chain_id.to_le_bytes().as_ref()],
+        bump = source_chain.bump
+    )]
+    pub source_chain: Account<'info, RegisteredChain>,
+
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority,
+        seeds = [
+            b"inbound_mint",
+            source_chain_id.to_le_bytes().as_ref(),
+            InboundDeliveryUtils::token_id_seed(&token_id).as_ref()
+        ],
+        bump
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + UniversalNft::INIT_SPACE,
+        seeds = [b"universal_nft", mint.key().as_ref()],
+        bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    /// CHECK: Program-wide mint authority, which keeps the mint so the NFT
+    /// can be minted again each time it returns
+    #[account(
+        seeds = [b"mint_authority"],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
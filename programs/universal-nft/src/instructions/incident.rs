@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::security::{IncidentConfig, IncidentLevel, IncidentMode};
+
+/// Create the protocol's incident mode at the normal level and name the
+/// emergency role that may raise it (program authority only)
+pub fn initialize_incident_mode(
+    ctx: Context<InitializeIncidentMode>,
+    emergency_authority: Pubkey,
+    config: Option<IncidentConfig>,
+) -> Result<()> {
+    ctx.accounts.incident_mode.initialize(
+        emergency_authority,
+        config,
+        ctx.bumps.incident_mode,
+    )?;
+
+    log!("Incident mode initialized, emergency authority {}", emergency_authority);
+    Ok(())
+}
+
+/// Switch to `level` and restart its de-escalation timer (emergency role
+/// only). `Normal` ends the incident at once.
+pub fn set_incident_level(ctx: Context<ManageIncidentMode>, level: IncidentLevel) -> Result<()> {
+    let signer = ctx.accounts.emergency_authority.key();
+    ctx.accounts.incident_mode.set_level(signer, level)
+}
+
+/// Flag a mint to be frozen while the protocol is in lockdown (emergency
+/// role only)
+pub fn flag_incident_mint(ctx: Context<ManageIncidentMode>, mint: Pubkey) -> Result<()> {
+    let signer = ctx.accounts.emergency_authority.key();
+    ctx.accounts.incident_mode.flag_mint(signer, mint)
+}
+
+/// Take a mint off the lockdown list (emergency role only)
+pub fn unflag_incident_mint(ctx: Context<ManageIncidentMode>, mint: Pubkey) -> Result<()> {
+    let signer = ctx.accounts.emergency_authority.key();
+    ctx.accounts.incident_mode.unflag_mint(signer, mint)
+}
+
+/// Record the de-escalation steps whose timers have run out, emitting an
+/// event for each. Anyone may call it; the checks on transfers already apply
+/// expired timers without it.
+pub fn refresh_incident_mode(ctx: Context<RefreshIncidentMode>) -> Result<()> {
+    ctx.accounts.incident_mode.refresh(Clock::get()?.unix_timestamp)
+}
+
+#[derive(Accounts)]
+pub struct InitializeIncidentMode<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + IncidentMode::INIT_SPACE,
+        seeds = [b"incident_mode"],
+        bump
+    )]
+    pub incident_mode: Account<'info, IncidentMode>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageIncidentMode<'info> {
+    #[account(
+        mut,
+        seeds = [b"incident_mode"],
+        bump = incident_mode.bump,
+        has_one = emergency_authority
+    )]
+    pub incident_mode: Account<'info, IncidentMode>,
+
+    pub emergency_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RefreshIncidentMode<'info> {
+    #[account(
+        mut,
+        seeds = [b"incident_mode"],
+        bump = incident_mode.bump
+    )]
+    pub incident_mode: Account<'info, IncidentMode>,
+}
@@ -19,9 +19,9 @@ pub fn initialize(ctx: Context<Initialize>, gateway_authority: Pubkey) -> Result
     config.bump = ctx.bumps.config;
     config.is_paused = false;
 
-    msg!("Universal NFT program initialized");
-    msg!("Authority: {}", config.authority);
-    msg!("Gateway Authority: {}", config.gateway_authority);
+    log!("Universal NFT program initialized");
+    log!("Authority: {}", config.authority);
+    log!("Gateway Authority: {}", config.gateway_authority);
 
     Ok(())
 }
@@ -64,17 +64,17 @@ pub fn update_config(
             UniversalNftError::InvalidGatewayAuthority
         );
         config.gateway_authority = gateway_auth;
-        msg!("Gateway authority updated to: {}", gateway_auth);
+        log!("Gateway authority updated to: {}", gateway_auth);
     }
 
     if let Some(tss_auth) = new_tss_authority {
         config.tss_authority = tss_auth;
-        msg!("TSS authority updated to: {}", tss_auth);
+        log!("TSS authority updated to: {}", tss_auth);
     }
 
     if let Some(is_paused) = paused {
         config.is_paused = is_paused;
-        msg!("Program paused status updated to: {}", is_paused);
+        log!("Program paused status updated to: {}", is_paused);
     }
 
     Ok(())
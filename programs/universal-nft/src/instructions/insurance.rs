@@ -0,0 +1,245 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::errors::*;
+use crate::events::*;
+use crate::governance::treasury::Treasury;
+use crate::governance::{self, TREASURY_SEED};
+use crate::recovery::error_recovery::RecoverySession;
+use crate::recovery::insurance::*;
+use crate::state::*;
+use crate::utils::FloorPriceUtils;
+
+/// Create the insurance pool (treasury authority only)
+pub fn initialize_insurance_pool(
+    ctx: Context<InitializeInsurancePool>,
+    premium_lamports: u64,
+    payout_lamports: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.treasury.authority,
+        UniversalNftError::Unauthorized
+    );
+
+    ctx.accounts.pool.initialize(
+        ctx.accounts.authority.key(),
+        premium_lamports,
+        payout_lamports,
+        ctx.bumps.pool,
+    )
+}
+
+#[derive(Accounts)]
+pub struct InitializeInsurancePool<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + InsurancePool::INIT_SPACE,
+        seeds = [b"insurance_pool"],
+        bump
+    )]
+    pub pool: Account<'info, InsurancePool>,
+
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+        seeds::program = governance::ID
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Update premium and payout parameters (pool authority only)
+pub fn update_insurance_parameters(
+    ctx: Context<UpdateInsuranceParameters>,
+    premium_lamports: u64,
+    payout_lamports: u64,
+    enabled: bool,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    pool.update_parameters(premium_lamports, payout_lamports, enabled)?;
+
+    emit!(InsuranceParametersUpdated {
+        premium_lamports,
+        payout_lamports,
+        enabled,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateInsuranceParameters<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool"],
+        bump = pool.bump,
+        has_one = authority
+    )]
+    pub pool: Account<'info, InsurancePool>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Pay the premium for an in-flight transfer, proven by holding its receipt
+pub fn insure_transfer(ctx: Context<InsureTransfer>) -> Result<()> {
+    let transfer = &ctx.accounts.transfer;
+
+    InsurancePool::require_insurable(transfer, ctx.accounts.receipt_token_account.amount)?;
+
+    let premium = ctx.accounts.pool.premium_lamports;
+    let cpi_accounts = system_program::Transfer {
+        from: ctx.accounts.insured.to_account_info(),
+        to: ctx.accounts.pool.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, premium)?;
+
+    // Priced collections pay out no more than the NFT's floor
+    let now = Clock::get()?.unix_timestamp;
+    let floor_account = ctx.accounts.collection_floor.as_ref().map(|a| a.to_account_info());
+    let floor_value = FloorPriceUtils::fresh_value(
+        ctx.accounts.universal_nft.collection_mint,
+        floor_account.as_ref(),
+        now,
+    )?;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.write_policy(
+        &mut ctx.accounts.policy,
+        transfer.key(),
+        transfer.nft_mint,
+        ctx.accounts.insured.key(),
+        floor_value,
+        now,
+        ctx.bumps.policy,
+    )?;
+
+    emit!(TransferInsured {
+        transfer: transfer.key(),
+        insured: ctx.accounts.insured.key(),
+        premium,
+        payout: ctx.accounts.policy.payout_amount,
+        timestamp: ctx.accounts.policy.purchased_at,
+    });
+
+    log!("Transfer insured: premium {} lamports", premium);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InsureTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, InsurancePool>,
+
+    #[account(
+        init,
+        payer = insured,
+        space = 8 + InsurancePolicy::INIT_SPACE,
+        seeds = [b"insurance_policy", transfer.key().as_ref()],
+        bump
+    )]
+    pub policy: Account<'info, InsurancePolicy>,
+
+    pub transfer: Account<'info, CrossChainTransfer>,
+
+    #[account(
+        seeds = [b"universal_nft", transfer.nft_mint.as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    /// CHECK: Collection floor PDA, required for collection NFTs and checked
+    /// by FloorPriceUtils
+    pub collection_floor: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        seeds = [b"receipt_mint", transfer.key().as_ref()],
+        bump
+    )]
+    pub receipt_mint: Account<'info, Mint>,
+
+    #[account(
+        token::mint = receipt_mint,
+        token::authority = insured,
+    )]
+    pub receipt_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub insured: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Claim the policy payout once the insured transfer's recovery session ends
+/// in an unrecoverable failure
+pub fn claim_insurance(ctx: Context<ClaimInsurance>) -> Result<()> {
+    let claimant = ctx.accounts.claimant.key();
+    let transfer = &ctx.accounts.transfer;
+    let pool = &mut ctx.accounts.pool;
+    let policy = &mut ctx.accounts.policy;
+
+    let amount = pool.validate_claim(policy, transfer, &ctx.accounts.recovery_session, claimant)?;
+
+    // Keep the pool rent exempt after the payout
+    let pool_info = pool.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(pool_info.data_len());
+    InsurancePool::require_payable(amount, pool_info.lamports(), rent_exempt_minimum)?;
+
+    **pool_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.claimant.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    pool.record_claim(policy, amount)?;
+
+    emit!(InsuranceClaimPaid {
+        transfer: policy.transfer,
+        insured: claimant,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Insurance claim paid for transfer {}: {} lamports", transfer.nonce, amount);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimInsurance<'info> {
+    #[account(
+        mut,
+        seeds = [b"insurance_pool"],
+        bump = pool.bump
+    )]
+    pub pool: Account<'info, InsurancePool>,
+
+    #[account(
+        mut,
+        seeds = [b"insurance_policy", transfer.key().as_ref()],
+        bump = policy.bump
+    )]
+    pub policy: Account<'info, InsurancePolicy>,
+
+    /// Insured transfer
+    pub transfer: Account<'info, CrossChainTransfer>,
+
+    /// Recovery session opened for the transfer
+    #[account(
+        seeds = [b"recovery_session", transfer.key().as_ref()],
+        bump = recovery_session.bump
+    )]
+    pub recovery_session: Account<'info, RecoverySession>,
+
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+
+/// Create the leaderboard of `kind` (program authority only). Mints and
+/// bridges passing it keep it current from then on.
+pub fn open_leaderboard(ctx: Context<OpenLeaderboard>, kind: LeaderboardKind) -> Result<()> {
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.kind = kind;
+    leaderboard.entries = Vec::new();
+    leaderboard.updated_at = Clock::get()?.unix_timestamp;
+    leaderboard.bump = ctx.bumps.leaderboard;
+
+    log!("Leaderboard opened: {:?}", kind);
+    Ok(())
+}
+
+/// Return a leaderboard's ranking. Programs read the result with
+/// `get_return_data` after the CPI.
+pub fn get_leaderboard(ctx: Context<GetLeaderboard>) -> Result<LeaderboardView> {
+    let leaderboard = &ctx.accounts.leaderboard;
+
+    Ok(LeaderboardView {
+        kind: leaderboard.kind,
+        entries: leaderboard.entries.clone(),
+        updated_at: leaderboard.updated_at,
+    })
+}
+
+#[derive(Accounts)]
+#[instruction(kind: LeaderboardKind)]
+pub struct OpenLeaderboard<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Leaderboard::INIT_SPACE,
+        seeds = [b"leaderboard", [kind.seed()].as_ref()],
+        bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct GetLeaderboard<'info> {
+    #[account(
+        seeds = [b"leaderboard", [leaderboard.kind.seed()].as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+}
@@ -0,0 +1,499 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::{validate, FloorPriceUtils, WalletLockUtils};
+
+/// Request a loan against a universal NFT, escrowing it in the loan PDA's
+/// vault. While collateralized, the NFT's record points at the loan, so it
+/// can't be bridged or sold.
+pub fn request_loan(ctx: Context<RequestLoan>, terms: LoanTerms) -> Result<()> {
+    require!(!ctx.accounts.config.is_paused, UniversalNftError::ProgramPaused);
+    validate::universal_nft_mint(&ctx.accounts.mint)?;
+    terms.validate()?;
+
+    let borrower = ctx.accounts.borrower.key();
+    let loan_key = ctx.accounts.loan.key();
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    universal_nft.require_no_transfer_in_progress()?;
+    require!(!universal_nft.is_locked, UniversalNftError::NftLocked);
+    require!(!universal_nft.is_disputed, UniversalNftError::NftUnderDispute);
+    require!(!universal_nft.is_frozen, UniversalNftError::NftFrozen);
+    require!(universal_nft.owner == borrower, UniversalNftError::InvalidNftOwner);
+    WalletLockUtils::require_unlocked(&ctx.accounts.wallet_lock)?;
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.borrower_token_account.to_account_info(),
+        to: ctx.accounts.vault.to_account_info(),
+        authority: ctx.accounts.borrower.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, 1)?;
+    universal_nft.owner = loan_key;
+
+    // Lenders price the request against the collection's current floor
+    let now = Clock::get()?.unix_timestamp;
+    let floor_account = ctx.accounts.collection_floor.as_ref().map(|a| a.to_account_info());
+    let collateral_value =
+        FloorPriceUtils::fresh_value(universal_nft.collection_mint, floor_account.as_ref(), now)?;
+    terms.require_within_ltv(collateral_value)?;
+
+    let loan = &mut ctx.accounts.loan;
+    loan.nft_mint = universal_nft.mint;
+    loan.borrower = borrower;
+    loan.lender = Pubkey::default();
+    loan.terms = terms;
+    loan.collateral_value = collateral_value;
+    loan.funded_at = 0;
+    loan.due_at = 0;
+    loan.bump = ctx.bumps.loan;
+
+    emit!(LoanRequested {
+        loan: loan_key,
+        nft_mint: loan.nft_mint,
+        borrower,
+        principal_mint: terms.principal_mint,
+        principal: terms.principal,
+        repayment: terms.repayment,
+        duration: terms.duration,
+        collateral_value,
+        timestamp: now,
+    });
+
+    log!("Loan requested against {}", loan.nft_mint);
+    Ok(())
+}
+
+/// Fund a loan request, paying the principal to the borrower. The loan is
+/// due `duration` seconds from now. The lender passes the terms it agreed to,
+/// so a request can't be swapped for worse terms before this lands.
+pub fn fund_loan(ctx: Context<FundLoan>, expected_terms: LoanTerms) -> Result<()> {
+    require!(!ctx.accounts.config.is_paused, UniversalNftError::ProgramPaused);
+
+    let lender = ctx.accounts.lender.key();
+    let loan = &mut ctx.accounts.loan;
+    require!(!loan.is_active(), UniversalNftError::InvalidLoanState);
+    require_keys_neq!(lender, loan.borrower, UniversalNftError::InvalidLoanState);
+    require!(loan.terms == expected_terms, UniversalNftError::LoanTermsMismatch);
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.lender_token_account.to_account_info(),
+        to: ctx.accounts.borrower_token_account.to_account_info(),
+        authority: ctx.accounts.lender.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, loan.terms.principal)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    loan.lender = lender;
+    loan.funded_at = now;
+    loan.due_at = now
+        .checked_add(loan.terms.duration)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    emit!(LoanFunded {
+        loan: loan.key(),
+        nft_mint: loan.nft_mint,
+        borrower: loan.borrower,
+        lender,
+        due_at: loan.due_at,
+        timestamp: now,
+    });
+
+    log!("Loan against {} funded", loan.nft_mint);
+    Ok(())
+}
+
+/// Repay a loan to its lender and take the NFT back. Borrowers can repay
+/// after the due date until the lender liquidates.
+pub fn repay_loan(ctx: Context<RepayLoan>) -> Result<()> {
+    let borrower = ctx.accounts.borrower.key();
+    let loan = &ctx.accounts.loan;
+    require!(loan.is_active(), UniversalNftError::InvalidLoanState);
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.borrower_token_account.to_account_info(),
+        to: ctx.accounts.lender_token_account.to_account_info(),
+        authority: ctx.accounts.borrower.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, loan.terms.repayment)?;
+
+    release_collateral(
+        &ctx.accounts.token_program,
+        loan,
+        &ctx.accounts.vault,
+        &ctx.accounts.borrower_nft_account,
+        &ctx.accounts.borrower.to_account_info(),
+    )?;
+    ctx.accounts.universal_nft.owner = borrower;
+
+    emit!(LoanRepaid {
+        loan: loan.key(),
+        nft_mint: loan.nft_mint,
+        borrower,
+        lender: loan.lender,
+        repayment: loan.terms.repayment,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Loan against {} repaid", loan.nft_mint);
+    Ok(())
+}
+
+/// Take an overdue loan's NFT as the lender
+pub fn liquidate_loan(ctx: Context<LiquidateLoan>) -> Result<()> {
+    let lender = ctx.accounts.lender.key();
+    let loan = &ctx.accounts.loan;
+    require!(loan.is_active(), UniversalNftError::InvalidLoanState);
+    let now = Clock::get()?.unix_timestamp;
+    require!(loan.is_defaulted(now), UniversalNftError::LoanNotDefaulted);
+
+    release_collateral(
+        &ctx.accounts.token_program,
+        loan,
+        &ctx.accounts.vault,
+        &ctx.accounts.lender_nft_account,
+        &ctx.accounts.borrower.to_account_info(),
+    )?;
+    ctx.accounts.universal_nft.owner = lender;
+
+    emit!(LoanLiquidated {
+        loan: loan.key(),
+        nft_mint: loan.nft_mint,
+        borrower: loan.borrower,
+        lender,
+        timestamp: now,
+    });
+
+    log!("Loan against {} liquidated", loan.nft_mint);
+    Ok(())
+}
+
+/// Withdraw an unfunded loan request and take the NFT back
+pub fn cancel_loan_request(ctx: Context<CancelLoanRequest>) -> Result<()> {
+    let borrower = ctx.accounts.borrower.key();
+    let loan = &ctx.accounts.loan;
+    require!(!loan.is_active(), UniversalNftError::InvalidLoanState);
+
+    release_collateral(
+        &ctx.accounts.token_program,
+        loan,
+        &ctx.accounts.vault,
+        &ctx.accounts.borrower_nft_account,
+        &ctx.accounts.borrower.to_account_info(),
+    )?;
+    ctx.accounts.universal_nft.owner = borrower;
+
+    emit!(LoanRequestCancelled {
+        loan: loan.key(),
+        nft_mint: loan.nft_mint,
+        borrower,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Loan request against {} cancelled", loan.nft_mint);
+    Ok(())
+}
+
+/// Move the collateral out of the vault and close the vault, refunding its
+/// rent to the borrower who paid it
+fn release_collateral<'info>(
+    token_program: &Program<'info, Token>,
+    loan: &Account<'info, Loan>,
+    vault: &Account<'info, TokenAccount>,
+    destination: &Account<'info, TokenAccount>,
+    borrower: &AccountInfo<'info>,
+) -> Result<()> {
+    let nft_mint = loan.nft_mint;
+    let loan_seeds = &[b"loan".as_ref(), nft_mint.as_ref(), &[loan.bump]];
+    let signer_seeds = &[&loan_seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: vault.to_account_info(),
+        to: destination.to_account_info(),
+        authority: loan.to_account_info(),
+    };
+    token::transfer(
+        CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer_seeds),
+        1,
+    )?;
+
+    let cpi_accounts = CloseAccount {
+        account: vault.to_account_info(),
+        destination: borrower.clone(),
+        authority: loan.to_account_info(),
+    };
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    ))
+}
+
+#[derive(Accounts)]
+pub struct RequestLoan<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = borrower,
+        space = 8 + Loan::INIT_SPACE,
+        seeds = [b"loan", mint.key().as_ref()],
+        bump
+    )]
+    pub loan: Account<'info, Loan>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", mint.key().as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: Collection floor PDA, required for collection NFTs and checked
+    /// by FloorPriceUtils
+    pub collection_floor: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = borrower
+    )]
+    pub borrower_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = borrower,
+        associated_token::mint = mint,
+        associated_token::authority = loan,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Borrower's wallet lock PDA, read by WalletLockUtils
+    #[account(
+        seeds = [b"wallet_lock", borrower.key().as_ref()],
+        bump
+    )]
+    pub wallet_lock: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundLoan<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"loan", loan.nft_mint.as_ref()],
+        bump = loan.bump
+    )]
+    pub loan: Account<'info, Loan>,
+
+    #[account(address = loan.terms.principal_mint)]
+    pub principal_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = principal_mint,
+        token::authority = lender
+    )]
+    pub lender_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Loan's borrower, receiving the principal
+    #[account(address = loan.borrower)]
+    pub borrower: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = lender,
+        associated_token::mint = principal_mint,
+        associated_token::authority = borrower,
+    )]
+    pub borrower_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub lender: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RepayLoan<'info> {
+    #[account(
+        mut,
+        close = borrower,
+        seeds = [b"loan", loan.nft_mint.as_ref()],
+        bump = loan.bump,
+        has_one = borrower
+    )]
+    pub loan: Account<'info, Loan>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", loan.nft_mint.as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(address = loan.nft_mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = loan,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = borrower,
+        associated_token::mint = mint,
+        associated_token::authority = borrower,
+    )]
+    pub borrower_nft_account: Account<'info, TokenAccount>,
+
+    #[account(address = loan.terms.principal_mint)]
+    pub principal_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = principal_mint,
+        token::authority = borrower
+    )]
+    pub borrower_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Loan's lender, receiving the repayment
+    #[account(address = loan.lender)]
+    pub lender: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = borrower,
+        associated_token::mint = principal_mint,
+        associated_token::authority = lender,
+    )]
+    pub lender_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct LiquidateLoan<'info> {
+    #[account(
+        mut,
+        close = borrower,
+        seeds = [b"loan", loan.nft_mint.as_ref()],
+        bump = loan.bump,
+        has_one = lender,
+        has_one = borrower
+    )]
+    pub loan: Account<'info, Loan>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", loan.nft_mint.as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(address = loan.nft_mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = loan,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = lender,
+        associated_token::mint = mint,
+        associated_token::authority = lender,
+    )]
+    pub lender_nft_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Loan's borrower, receiving the loan and vault rent
+    #[account(mut)]
+    pub borrower: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub lender: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelLoanRequest<'info> {
+    #[account(
+        mut,
+        close = borrower,
+        seeds = [b"loan", loan.nft_mint.as_ref()],
+        bump = loan.bump,
+        has_one = borrower
+    )]
+    pub loan: Account<'info, Loan>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", loan.nft_mint.as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(address = loan.nft_mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = loan,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = borrower,
+        associated_token::mint = mint,
+        associated_token::authority = borrower,
+    )]
+    pub borrower_nft_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,308 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::*;
+use crate::events::*;
+
+/// Create the light client config and assign the header relayer (program authority only)
+pub fn initialize_light_client(
+    ctx: Context<InitializeLightClient>,
+    header_relayer: Pubkey,
+) -> Result<()> {
+    let light_client = &mut ctx.accounts.light_client;
+    light_client.authority = ctx.accounts.authority.key();
+    light_client.header_relayer = header_relayer;
+    light_client.bump = ctx.bumps.light_client;
+
+    log!("Light client initialized");
+    log!("Header relayer: {}", header_relayer);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeLightClient<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + LightClientConfig::INIT_SPACE,
+        seeds = [b"light_client"],
+        bump
+    )]
+    pub light_client: Account<'info, LightClientConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Replace the header relayer (program authority only)
+pub fn set_header_relayer(ctx: Context<SetHeaderRelayer>, header_relayer: Pubkey) -> Result<()> {
+    ctx.accounts.light_client.header_relayer = header_relayer;
+
+    log!("Header relayer updated: {}", header_relayer);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetHeaderRelayer<'info> {
+    #[account(
+        mut,
+        seeds = [b"light_client"],
+        bump = light_client.bump,
+        has_one = authority
+    )]
+    pub light_client: Account<'info, LightClientConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Post the bridge event root of a source-chain block (header relayer only)
+pub fn post_block_header(
+    ctx: Context<PostBlockHeader>,
+    chain_id: u64,
+    block_number: u64,
+    events_root: [u8; 32],
+) -> Result<()> {
+    require!(
+        ctx.accounts.header_relayer.key() == ctx.accounts.light_client.header_relayer,
+        UniversalNftError::UnauthorizedHeaderRelayer
+    );
+    CrossChainUtils::validate_chain_id(chain_id, &ctx.accounts.chain)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let header = &mut ctx.accounts.block_header;
+    header.chain_id = chain_id;
+    header.block_number = block_number;
+    header.events_root = events_root;
+    header.relayer = ctx.accounts.header_relayer.key();
+    header.posted_at = now;
+    header.bump = ctx.bumps.block_header;
+
+    // Advance the attested head confirmation depths are measured against
+    if let Some(finality) = ctx.accounts.chain_finality.as_mut() {
+        if block_number > finality.attested_head {
+            finality.attested_head = block_number;
+            finality.head_updated_at = now;
+        }
+    }
+
+    emit!(BlockHeaderPosted {
+        chain_id,
+        block_number,
+        events_root,
+        relayer: header.relayer,
+        timestamp: now,
+    });
+
+    log!("Block header posted");
+    log!("Chain: {}, block: {}", chain_id, block_number);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64, block_number: u64)]
+pub struct PostBlockHeader<'info> {
+    #[account(
+        seeds = [b"light_client"],
+        bump = light_client.bump
+    )]
+    pub light_client: Account<'info, LightClientConfig>,
+
+    #[account(
+        init,
+        payer = header_relayer,
+        space = 8 + BlockHeaderRoot::INIT_SPACE,
+        seeds = [b"block_header", chain_id.to_le_bytes().as_ref(), block_number.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub block_header: Account<'info, BlockHeaderRoot>,
+
+    /// Chain's finality config, whose attested head the block advances
+    #[account(
+        mut,
+        seeds = [b"chain_finality", chain_id.to_le_bytes().as_ref()],
+        bump = chain_finality.bump
+    )]
+    pub chain_finality: Option<Account<'info, ChainFinality>>,
+
+    /// Registry entry for the chain the block belongs to
+    #[account(
+        seeds = [b"chain", chain_id.to_le_bytes().as_ref()],
+        bump = chain.bump
+    )]
+    pub chain: Account<'info, RegisteredChain>,
+
+    #[account(mut)]
+    pub header_relayer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Set how many confirmations inbound messages from a chain need before
+/// on_call accepts them (program authority only)
+pub fn set_chain_finality(ctx: Context<SetChainFinality>, required_confirmations: u64) -> Result<()> {
+    let finality = &mut ctx.accounts.chain_finality;
+    finality.chain_id = ctx.accounts.chain.chain_id;
+    finality.required_confirmations = required_confirmations;
+    finality.bump = ctx.bumps.chain_finality;
+
+    emit!(ChainFinalityUpdated {
+        chain_id: finality.chain_id,
+        required_confirmations,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Chain {} requires {} confirmations", finality.chain_id, required_confirmations);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetChainFinality<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"chain", chain.chain_id.to_le_bytes().as_ref()],
+        bump = chain.bump
+    )]
+    pub chain: Account<'info, RegisteredChain>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ChainFinality::INIT_SPACE,
+        seeds = [b"chain_finality", chain.chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub chain_finality: Account<'info, ChainFinality>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Require (or stop requiring) event proofs for a collection's inbound messages
+pub fn set_proof_policy(
+    ctx: Context<SetProofPolicy>,
+    collection_mint: Pubkey,
+    required: bool,
+) -> Result<()> {
+    let policy = &mut ctx.accounts.proof_policy;
+    policy.collection_mint = collection_mint;
+    policy.required = required;
+    policy.bump = ctx.bumps.proof_policy;
+
+    log!("Proof policy for collection {}: required = {}", collection_mint, required);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(collection_mint: Pubkey)]
+pub struct SetProofPolicy<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProofPolicy::INIT_SPACE,
+        seeds = [b"proof_policy", collection_mint.as_ref()],
+        bump
+    )]
+    pub proof_policy: Account<'info, ProofPolicy>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Prove an inbound bridge event against a posted block root. The resulting
+/// record is consumed by on_call when the message's collection requires proofs.
+pub fn verify_event_proof(
+    ctx: Context<VerifyEventProof>,
+    chain_id: u64,
+    block_number: u64,
+    sender: [u8; 20],
+    message: Vec<u8>,
+    proof: Vec<[u8; 32]>,
+    leaf_index: u64,
+) -> Result<()> {
+    let leaf = LightClientUtils::event_leaf(chain_id, &sender, &message);
+    let root = LightClientUtils::compute_root(&leaf, &proof, leaf_index)?;
+    require!(
+        root == ctx.accounts.block_header.events_root,
+        UniversalNftError::InvalidEventProof
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let verified = &mut ctx.accounts.verified_event;
+    verified.leaf = leaf;
+    verified.chain_id = chain_id;
+    verified.block_number = block_number;
+    verified.consumed = false;
+    verified.verified_at = now;
+    verified.bump = ctx.bumps.verified_event;
+
+    emit!(EventProofVerified {
+        leaf,
+        chain_id,
+        block_number,
+        timestamp: now,
+    });
+
+    log!("Event proof verified");
+    log!("Chain: {}, block: {}", chain_id, block_number);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64, block_number: u64, sender: [u8; 20], message: Vec<u8>)]
+pub struct VerifyEventProof<'info> {
+    #[account(
+        seeds = [b"block_header", chain_id.to_le_bytes().as_ref(), block_number.to_le_bytes().as_ref()],
+        bump = block_header.bump
+    )]
+    pub block_header: Account<'info, BlockHeaderRoot>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + VerifiedEvent::INIT_SPACE,
+        seeds = [
+            b"verified_event",
+            LightClientUtils::event_leaf(chain_id, &sender, &message).as_ref()
+        ],
+        bump
+    )]
+    pub verified_event: Account<'info, VerifiedEvent>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
@@ -1,13 +1,16 @@
 use anchor_lang::prelude::*;
 use mpl_token_metadata::{
-    accounts::Metadata,
-    instructions::UpdateMetadataAccountV2,
-    types::DataV2,
+    instructions::{
+        CreateMetadataAccountV3, CreateMetadataAccountV3InstructionArgs, UpdateMetadataAccountV2,
+        UpdateMetadataAccountV2InstructionArgs,
+    },
+    types::{CollectionDetails, Creator, DataV2},
 };
 use solana_program::program::invoke_signed;
 
 use crate::state::*;
 use crate::errors::*;
+use crate::events::*;
 use crate::utils::*;
 
 /// Update NFT metadata (owner only)
@@ -21,17 +24,23 @@ pub fn update_metadata(
     
     // Check if program is paused
     require!(!config.is_paused, UniversalNftError::ProgramPaused);
+
+    validate::metadata_pda(&ctx.accounts.metadata.key(), &ctx.accounts.mint.key())?;
     
     let universal_nft = &mut ctx.accounts.universal_nft;
     
     // Check NFT is not locked
+    universal_nft.require_no_transfer_in_progress()?;
     require!(!universal_nft.is_locked, UniversalNftError::NftLocked);
     
-    // Verify ownership
-    require!(
-        universal_nft.owner == ctx.accounts.owner.key(),
-        UniversalNftError::InvalidNftOwner
-    );
+    // Verify ownership, or a metadata-scoped operator session
+    OperatorSessionUtils::authorize_signer(
+        &universal_nft.owner,
+        &ctx.accounts.owner.key(),
+        &ctx.accounts.operator_session,
+        SessionScope::Metadata,
+        None,
+    )?;
 
     // Validate new metadata
     MetadataUtils::validate_uri(&new_uri)?;
@@ -44,6 +53,26 @@ pub fn update_metadata(
         MetadataUtils::validate_symbol(symbol)?;
     }
 
+    // Protocol metadata update fee, paid by the signer
+    if let Some(fee_schedule) = FeeUtils::load(&ctx.accounts.fee_schedule)? {
+        FeeUtils::charge(
+            &fee_schedule,
+            FeeKind::MetadataUpdate,
+            fee_schedule.metadata_update_fee(),
+            &ctx.accounts.owner.to_account_info(),
+            ctx.accounts.fee_recipient.as_deref(),
+            &FeeDiscountAccounts {
+                governance_stake: ctx.accounts.governance_stake.as_deref(),
+                deployment_registry: ctx.accounts.deployment_registry.as_deref(),
+                membership_token_account: ctx.accounts.membership_token_account.as_deref(),
+                membership_nft: ctx.accounts.membership_nft.as_deref(),
+                rebate_campaign: ctx.accounts.rebate_campaign.as_deref(),
+            },
+            None,
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+    }
+
     // Update Universal NFT account
     universal_nft.uri = new_uri.clone();
     
@@ -56,9 +85,10 @@ pub fn update_metadata(
     }
 
     // Update on-chain metadata
+    let mint_key = ctx.accounts.mint.key();
     let metadata_seeds = &[
         b"universal_nft",
-        ctx.accounts.mint.key().as_ref(),
+        mint_key.as_ref(),
         &[universal_nft.bump],
     ];
     let signer_seeds = &[&metadata_seeds[..]];
@@ -86,9 +116,9 @@ pub fn update_metadata(
     };
 
     invoke_signed(
-        &update_metadata_ix.instruction(mpl_token_metadata::types::UpdateMetadataAccountArgsV2 {
+        &update_metadata_ix.instruction(UpdateMetadataAccountV2InstructionArgs {
             data: Some(data),
-            update_authority: Some(ctx.accounts.update_authority.key()),
+            new_update_authority: Some(ctx.accounts.update_authority.key()),
             primary_sale_happened: None,
             is_mutable: Some(true),
         }),
@@ -99,9 +129,15 @@ pub fn update_metadata(
         signer_seeds,
     )?;
 
-    msg!("NFT metadata updated successfully");
-    msg!("Token ID: {}", universal_nft.origin_token_id);
-    msg!("New URI: {}", universal_nft.uri);
+    emit!(NftMetadataUpdated {
+        mint: universal_nft.mint,
+        uri: universal_nft.uri.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("NFT metadata updated successfully");
+    log!("Token ID: {}", universal_nft.origin_token_id);
+    log!("New URI: {}", universal_nft.uri);
 
     Ok(())
 }
@@ -124,17 +160,8 @@ pub struct UpdateMetadata<'info> {
     #[account(mut)]
     pub mint: Account<'info, anchor_spl::token::Mint>,
 
-    /// CHECK: Metadata account validated by seeds
-    #[account(
-        mut,
-        seeds = [
-            b"metadata",
-            mpl_token_metadata::ID.as_ref(),
-            mint.key().as_ref(),
-        ],
-        bump,
-        seeds::program = mpl_token_metadata::ID,
-    )]
+    /// CHECK: Metadata PDA, checked by validate::metadata_pda
+    #[account(mut)]
     pub metadata: UncheckedAccount<'info>,
 
     /// CHECK: Update authority PDA
@@ -144,8 +171,49 @@ pub struct UpdateMetadata<'info> {
     )]
     pub update_authority: UncheckedAccount<'info>,
 
+    /// NFT owner, or an operator holding a metadata-scoped session
     #[account(mut)]
     pub owner: Signer<'info>,
+
+    /// CHECK: Operator session PDA for the signer, read by OperatorSessionUtils
+    #[account(
+        seeds = [b"operator_session", universal_nft.owner.as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub operator_session: UncheckedAccount<'info>,
+
+    /// CHECK: Protocol fee schedule PDA, read by FeeUtils
+    #[account(
+        seeds = [b"fee_schedule"],
+        bump
+    )]
+    pub fee_schedule: UncheckedAccount<'info>,
+
+    /// CHECK: Must match the fee schedule's fee recipient
+    #[account(mut)]
+    pub fee_recipient: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Payer's governance stake, verified by FeeUtils for a fee discount
+    pub governance_stake: Option<UncheckedAccount<'info>>,
+
+    /// Registry naming the governance deployment, required with `governance_stake`
+    #[account(
+        seeds = [b"deployment_registry"],
+        bump = deployment_registry.bump
+    )]
+    pub deployment_registry: Option<Account<'info, DeploymentRegistry>>,
+
+    /// CHECK: Payer's token account holding a membership NFT, verified by FeeUtils
+    pub membership_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Universal NFT record of the membership NFT, verified by FeeUtils
+    pub membership_nft: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Rebate campaign PDA, verified by FeeUtils
+    #[account(mut)]
+    pub rebate_campaign: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
 }
 
 /// Verify collection membership for an NFT
@@ -167,9 +235,9 @@ pub fn verify_collection(ctx: Context<VerifyCollection>) -> Result<()> {
     // Update NFT to reference the collection
     universal_nft.collection_mint = Some(collection.mint);
 
-    msg!("Collection verified for NFT");
-    msg!("Token ID: {}", universal_nft.origin_token_id);
-    msg!("Collection: {}", collection.mint);
+    log!("Collection verified for NFT");
+    log!("Token ID: {}", universal_nft.origin_token_id);
+    log!("Collection: {}", collection.mint);
 
     Ok(())
 }
@@ -233,6 +301,15 @@ pub fn create_collection(
     collection.is_verified = true;
     collection.bump = ctx.bumps.collection;
 
+    // The program-wide mint authority signs; the collection PDA becomes the
+    // metadata update authority
+    let mint_key = ctx.accounts.mint.key();
+    let mint_authority_seeds: &[&[u8]] = &[b"mint_authority", &[ctx.bumps.mint_authority]];
+    let update_authority_seeds: &[&[u8]] =
+        &[b"collection", mint_key.as_ref(), &[ctx.bumps.collection]];
+    let mint_signer = &[mint_authority_seeds];
+    let signer_seeds = &[mint_authority_seeds, update_authority_seeds];
+
     // Mint collection token
     let cpi_accounts = anchor_spl::token::MintTo {
         mint: ctx.accounts.mint.to_account_info(),
@@ -240,23 +317,15 @@ pub fn create_collection(
         authority: ctx.accounts.mint_authority.to_account_info(),
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, mint_signer);
     anchor_spl::token::mint_to(cpi_ctx, 1)?;
 
-    // Create collection metadata
-    let metadata_seeds = &[
-        b"collection",
-        ctx.accounts.mint.key().as_ref(),
-        &[collection.bump],
-    ];
-    let signer_seeds = &[&metadata_seeds[..]];
-
     let data = DataV2 {
         name: name.clone(),
         symbol: symbol.clone(),
         uri: uri.clone(),
         seller_fee_basis_points: 0,
-        creators: Some(vec![mpl_token_metadata::types::CreatorV2 {
+        creators: Some(vec![Creator {
             address: ctx.accounts.authority.key(),
             verified: true,
             share: 100,
@@ -266,21 +335,21 @@ pub fn create_collection(
     };
 
     // Create metadata account instruction
-    let create_metadata_ix = mpl_token_metadata::instructions::CreateMetadataAccountV3 {
+    let create_metadata_ix = CreateMetadataAccountV3 {
         metadata: ctx.accounts.metadata.key(),
         mint: ctx.accounts.mint.key(),
         mint_authority: ctx.accounts.mint_authority.key(),
         payer: ctx.accounts.payer.key(),
-        update_authority: ctx.accounts.mint_authority.key(),
+        update_authority: (ctx.accounts.collection.key(), true),
         system_program: ctx.accounts.system_program.key(),
-        rent: ctx.accounts.rent.key(),
+        rent: Some(ctx.accounts.rent.key()),
     };
 
     invoke_signed(
-        &create_metadata_ix.instruction(mpl_token_metadata::types::CreateMetadataAccountArgsV3 {
+        &create_metadata_ix.instruction(CreateMetadataAccountV3InstructionArgs {
             data,
             is_mutable: true,
-            collection_details: Some(mpl_token_metadata::types::CollectionDetailsValue::V1 {
+            collection_details: Some(CollectionDetails::V1 {
                 size: max_supply,
             }),
         }),
@@ -289,17 +358,17 @@ pub fn create_collection(
             ctx.accounts.mint.to_account_info(),
             ctx.accounts.mint_authority.to_account_info(),
             ctx.accounts.payer.to_account_info(),
-            ctx.accounts.mint_authority.to_account_info(),
+            ctx.accounts.collection.to_account_info(),
             ctx.accounts.system_program.to_account_info(),
             ctx.accounts.rent.to_account_info(),
         ],
         signer_seeds,
     )?;
 
-    msg!("Universal collection created successfully");
-    msg!("Collection: {}", ctx.accounts.mint.key());
-    msg!("Name: {}", name);
-    msg!("Max Supply: {}", max_supply);
+    log!("Universal collection created successfully");
+    log!("Collection: {}", ctx.accounts.mint.key());
+    log!("Name: {}", name);
+    log!("Max Supply: {}", max_supply);
 
     Ok(())
 }
@@ -352,9 +421,9 @@ pub struct CreateCollection<'info> {
     )]
     pub token_account: Account<'info, anchor_spl::token::TokenAccount>,
 
-    /// CHECK: PDA authority for collection
+    /// CHECK: Program-wide mint and freeze authority for universal NFT mints
     #[account(
-        seeds = [b"collection", mint.key().as_ref()],
+        seeds = [b"mint_authority"],
         bump
     )]
     pub mint_authority: UncheckedAccount<'info>,
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Set how NFT metadata is rewritten for a registered chain (authority only).
+/// burn_and_transfer applies the rules when it is given the chain's rules PDA.
+pub fn set_chain_metadata_rules(
+    ctx: Context<SetChainMetadataRules>,
+    params: ChainMetadataRulesParams,
+) -> Result<()> {
+    require!(
+        params.uri_match_prefix.len() <= ChainMetadataRules::MAX_PREFIX_LEN
+            && params.uri_replace_prefix.len() <= ChainMetadataRules::MAX_PREFIX_LEN,
+        UniversalNftError::InvalidMetadataRules
+    );
+    require!(
+        params.uri_match_prefix.is_empty() || !params.uri_replace_prefix.is_empty(),
+        UniversalNftError::InvalidMetadataRules
+    );
+
+    let chain_id = ctx.accounts.chain.chain_id;
+    let rules = &mut ctx.accounts.metadata_rules;
+    rules.chain_id = chain_id;
+    rules.max_name_len = params.max_name_len;
+    rules.max_symbol_len = params.max_symbol_len;
+    rules.uri_match_prefix = params.uri_match_prefix;
+    rules.uri_replace_prefix = params.uri_replace_prefix;
+    rules.royalty_receiver = params.royalty_receiver;
+    rules.bump = ctx.bumps.metadata_rules;
+
+    emit!(ChainMetadataRulesUpdated {
+        chain_id,
+        max_name_len: rules.max_name_len,
+        max_symbol_len: rules.max_symbol_len,
+        royalty_receiver: rules.royalty_receiver,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Metadata rules set for chain {}", chain_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetChainMetadataRules<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"chain", chain.chain_id.to_le_bytes().as_ref()],
+        bump = chain.bump
+    )]
+    pub chain: Account<'info, RegisteredChain>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ChainMetadataRules::INIT_SPACE,
+        seeds = [b"metadata_rules", chain.chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub metadata_rules: Account<'info, ChainMetadataRules>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token::{self, spl_token::instruction::AuthorityType, Mint, SetAuthority, Token};
+
+use crate::errors::*;
+use crate::events::*;
+use crate::governance::treasury::Treasury;
+use crate::governance::{self, TREASURY_SEED};
+
+/// Hand a mint's mint and freeze authority from the program's mint authority
+/// PDA (seeds = [b"mint_authority"]) to a new authority, such as a successor
+/// program (treasury authority only). NFTs whose authority already moved to
+/// their master edition are not affected.
+pub fn hand_over_mint_authority(
+    ctx: Context<HandOverMintAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.treasury.authority,
+        UniversalNftError::Unauthorized
+    );
+    require!(
+        new_authority != Pubkey::default() && new_authority != ctx.accounts.mint_authority.key(),
+        UniversalNftError::InvalidMintAuthority
+    );
+
+    let mint_authority_seeds: &[&[u8]] = &[b"mint_authority", &[ctx.bumps.mint_authority]];
+    let signer_seeds = &[mint_authority_seeds];
+
+    let mut handed_over = vec![AuthorityType::MintTokens];
+    if ctx.accounts.mint.freeze_authority == COption::Some(ctx.accounts.mint_authority.key()) {
+        handed_over.push(AuthorityType::FreezeAccount);
+    }
+
+    for authority_type in handed_over {
+        let cpi_accounts = SetAuthority {
+            current_authority: ctx.accounts.mint_authority.to_account_info(),
+            account_or_mint: ctx.accounts.mint.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::set_authority(cpi_ctx, authority_type, Some(new_authority))?;
+    }
+
+    emit!(MintAuthorityHandedOver {
+        mint: ctx.accounts.mint.key(),
+        new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Mint authority handed over for {}", ctx.accounts.mint.key());
+    log!("New authority: {}", new_authority);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct HandOverMintAuthority<'info> {
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+        seeds::program = governance::ID
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        mut,
+        constraint = mint.mint_authority == COption::Some(mint_authority.key())
+            @ UniversalNftError::InvalidMintAuthority
+    )]
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: Program-wide mint and freeze authority for universal NFT mints
+    #[account(
+        seeds = [b"mint_authority"],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
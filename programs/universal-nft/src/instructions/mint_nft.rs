@@ -1,19 +1,21 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo};
 use anchor_spl::associated_token::AssociatedToken;
 use mpl_token_metadata::{
-    accounts::{Metadata, MasterEdition},
-    instructions::{CreateMetadataAccountV3, CreateMasterEditionV3},
-    types::{DataV2, CreatorV2, CollectionDetails},
-};
-use solana_program::{
-    program::invoke_signed,
-    system_instruction,
+    instructions::{
+        CreateMasterEditionV3, CreateMasterEditionV3InstructionArgs, CreateMetadataAccountV3,
+        CreateMetadataAccountV3InstructionArgs,
+    },
+    types::{Creator, DataV2},
 };
+use solana_program::program::invoke_signed;
 
 use crate::state::*;
 use crate::errors::*;
 use crate::utils::*;
+use crate::events::*;
+use crate::hooks::{HookPayload, HookUtils};
 
 pub fn mint_nft(
     ctx: Context<MintNft>,
@@ -21,17 +23,124 @@ pub fn mint_nft(
     symbol: String,
     uri: String,
     collection_mint: Option<Pubkey>,
+    allowlist_proof: Vec<[u8; 32]>,
+    referrer: Option<Pubkey>,
 ) -> Result<()> {
     let config = &ctx.accounts.config;
     
     // Check if program is paused
     require!(!config.is_paused, UniversalNftError::ProgramPaused);
+    IncidentUtils::require_operational(&ctx.accounts.incident_mode, None)?;
+    for rate_limit in [&ctx.accounts.wallet_rate_limit, &ctx.accounts.collection_rate_limit] {
+        RateLimitUtils::consume(rate_limit, &ctx.accounts.incident_mode)?;
+    }
     
     // Validate metadata
     MetadataUtils::validate_name(&name)?;
     MetadataUtils::validate_symbol(&symbol)?;
     MetadataUtils::validate_uri(&uri)?;
 
+    // Apply the collection's policy, if it has one
+    let config_account = ctx.accounts.collection_config.as_ref().map(|a| a.to_account_info());
+    let mut collection_config = CollectionConfigUtils::load_collection_config(
+        collection_mint,
+        config_account.as_ref(),
+    )?;
+    if let (Some(collection_config), Some(config_account)) =
+        (collection_config.as_mut(), config_account.as_ref())
+    {
+        let sold_out = collection_config.record_mint()?;
+        CollectionConfigUtils::store_collection_config(collection_config, config_account)?;
+
+        if sold_out {
+            emit!(CollectionSoldOut {
+                collection_mint: collection_config.collection_mint,
+                max_supply: collection_config.max_supply,
+                timestamp: Clock::get()?.unix_timestamp,
+            });
+            log!("Collection sold out: {}", collection_config.collection_mint);
+        }
+    }
+
+    // Resolve the scheduled phase, if the collection has a mint schedule
+    let mut in_phase = false;
+    if let Some(collection_config) = &collection_config {
+        let now = Clock::get()?.unix_timestamp;
+        let mint_phase = ctx.accounts.mint_phase.as_deref();
+        if let Some(phase) = MintPhaseUtils::resolve_phase(collection_config, mint_phase, now)? {
+            MintPhaseUtils::check_eligibility(phase, &ctx.accounts.payer.key(), &allowlist_proof)?;
+
+            if phase.price > 0 {
+                let recipient = ctx.accounts.phase_funds_recipient
+                    .as_ref()
+                    .ok_or(UniversalNftError::InvalidFundsRecipient)?;
+                require_keys_eq!(
+                    recipient.key(),
+                    phase.funds_recipient,
+                    UniversalNftError::InvalidFundsRecipient
+                );
+
+                let cpi_accounts = system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: recipient.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+                system_program::transfer(cpi_ctx, phase.price)?;
+            }
+            in_phase = true;
+        }
+    }
+    if let (true, Some(mint_phase)) = (in_phase, ctx.accounts.mint_phase.as_mut()) {
+        mint_phase.minted_count = mint_phase.minted_count
+            .checked_add(1)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+    }
+
+    // Protocol mint fee, when governance has switched fees on, with any
+    // referrer's share
+    let referral = FeeUtils::referral(
+        referrer,
+        ctx.accounts.referral.as_deref(),
+        &[ctx.accounts.payer.key(), ctx.accounts.owner.key()],
+    )?;
+    if let Some(fee_schedule) = FeeUtils::load(&ctx.accounts.fee_schedule)? {
+        FeeUtils::charge(
+            &fee_schedule,
+            FeeKind::Mint,
+            fee_schedule.mint_fee(),
+            &ctx.accounts.payer.to_account_info(),
+            ctx.accounts.fee_recipient.as_deref(),
+            &FeeDiscountAccounts {
+                governance_stake: ctx.accounts.governance_stake.as_deref(),
+                deployment_registry: ctx.accounts.deployment_registry.as_deref(),
+                membership_token_account: ctx.accounts.membership_token_account.as_deref(),
+                membership_nft: ctx.accounts.membership_nft.as_deref(),
+                rebate_campaign: ctx.accounts.rebate_campaign.as_deref(),
+            },
+            referral.as_ref(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+    }
+
+    // Activity points for users who opened their metrics account
+    PointsUtils::record(
+        PointsActivity::Mint,
+        &ctx.accounts.payer.key(),
+        ctx.accounts.user_metrics.as_deref(),
+        ctx.accounts.points_config.as_deref(),
+    )?;
+    UsageSegmentUtils::record(collection_mint, None, ctx.accounts.usage_segment.as_deref())?;
+    LeaderboardUtils::record_user(
+        LeaderboardKind::Minters,
+        &ctx.accounts.payer.key(),
+        ctx.accounts.user_metrics.as_deref(),
+        ctx.accounts.leaderboard.as_deref(),
+    )?;
+
+    let seller_fee_basis_points = collection_config
+        .as_ref()
+        .map_or(0, |c| c.seller_fee_basis_points);
+
     // Get current slot and timestamp for token ID generation
     let clock = Clock::get()?;
     let slot = clock.slot;
@@ -58,6 +167,19 @@ pub fn mint_nft(
     universal_nft.creation_timestamp = timestamp;
     universal_nft.bump = ctx.bumps.universal_nft;
     universal_nft.is_locked = false;
+    universal_nft.is_disputed = false;
+    universal_nft.is_frozen = false;
+    universal_nft.transfer_in_progress = false;
+    universal_nft.is_flagged = false;
+
+    // The program-wide mint authority signs the mint and both Metaplex CPIs;
+    // the NFT's own PDA becomes the metadata update authority
+    let mint_key = ctx.accounts.mint.key();
+    let mint_authority_seeds: &[&[u8]] = &[b"mint_authority", &[ctx.bumps.mint_authority]];
+    let update_authority_seeds: &[&[u8]] =
+        &[b"universal_nft", mint_key.as_ref(), &[ctx.bumps.universal_nft]];
+    let mint_signer = &[mint_authority_seeds];
+    let signer_seeds = &[mint_authority_seeds, update_authority_seeds];
 
     // Mint token to owner
     let cpi_accounts = MintTo {
@@ -66,24 +188,16 @@ pub fn mint_nft(
         authority: ctx.accounts.mint_authority.to_account_info(),
     };
     let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, mint_signer);
     token::mint_to(cpi_ctx, 1)?;
 
-    // Create metadata account
-    let metadata_seeds = &[
-        b"universal_nft",
-        ctx.accounts.mint.key().as_ref(),
-        &[universal_nft.bump],
-    ];
-    let signer_seeds = &[&metadata_seeds[..]];
-
     // Prepare metadata
     let data = DataV2 {
         name: name.clone(),
         symbol: symbol.clone(),
         uri: uri.clone(),
-        seller_fee_basis_points: 0,
-        creators: Some(vec![CreatorV2 {
+        seller_fee_basis_points,
+        creators: Some(vec![Creator {
             address: ctx.accounts.owner.key(),
             verified: true,
             share: 100,
@@ -101,13 +215,13 @@ pub fn mint_nft(
         mint: ctx.accounts.mint.key(),
         mint_authority: ctx.accounts.mint_authority.key(),
         payer: ctx.accounts.payer.key(),
-        update_authority: ctx.accounts.mint_authority.key(),
+        update_authority: (ctx.accounts.universal_nft.key(), true),
         system_program: ctx.accounts.system_program.key(),
-        rent: ctx.accounts.rent.key(),
+        rent: Some(ctx.accounts.rent.key()),
     };
 
     invoke_signed(
-        &create_metadata_ix.instruction(mpl_token_metadata::types::CreateMetadataAccountArgsV3 {
+        &create_metadata_ix.instruction(CreateMetadataAccountV3InstructionArgs {
             data,
             is_mutable: true,
             collection_details: None,
@@ -117,7 +231,7 @@ pub fn mint_nft(
             ctx.accounts.mint.to_account_info(),
             ctx.accounts.mint_authority.to_account_info(),
             ctx.accounts.payer.to_account_info(),
-            ctx.accounts.mint_authority.to_account_info(),
+            ctx.accounts.universal_nft.to_account_info(),
             ctx.accounts.system_program.to_account_info(),
             ctx.accounts.rent.to_account_info(),
         ],
@@ -128,23 +242,23 @@ pub fn mint_nft(
     let create_master_edition_ix = CreateMasterEditionV3 {
         edition: ctx.accounts.master_edition.key(),
         mint: ctx.accounts.mint.key(),
-        update_authority: ctx.accounts.mint_authority.key(),
+        update_authority: ctx.accounts.universal_nft.key(),
         mint_authority: ctx.accounts.mint_authority.key(),
         payer: ctx.accounts.payer.key(),
         metadata: ctx.accounts.metadata.key(),
         token_program: ctx.accounts.token_program.key(),
         system_program: ctx.accounts.system_program.key(),
-        rent: ctx.accounts.rent.key(),
+        rent: Some(ctx.accounts.rent.key()),
     };
 
     invoke_signed(
-        &create_master_edition_ix.instruction(mpl_token_metadata::types::CreateMasterEditionArgs {
+        &create_master_edition_ix.instruction(CreateMasterEditionV3InstructionArgs {
             max_supply: Some(0), // Unique NFT
         }),
         &[
             ctx.accounts.master_edition.to_account_info(),
             ctx.accounts.mint.to_account_info(),
-            ctx.accounts.mint_authority.to_account_info(),
+            ctx.accounts.universal_nft.to_account_info(),
             ctx.accounts.mint_authority.to_account_info(),
             ctx.accounts.payer.to_account_info(),
             ctx.accounts.metadata.to_account_info(),
@@ -155,16 +269,42 @@ pub fn mint_nft(
         signer_seeds,
     )?;
 
-    msg!("Universal NFT minted successfully");
-    msg!("Token ID: {}", token_id);
-    msg!("Mint: {}", ctx.accounts.mint.key());
-    msg!("Owner: {}", ctx.accounts.owner.key());
+    // The finished mint must satisfy the same invariants transfers check
+    ctx.accounts.mint.reload()?;
+    validate::universal_nft_mint(&ctx.accounts.mint)?;
+
+    // Run collection hooks subscribed to this event
+    let hooks_account = ctx.accounts.collection_hooks.as_ref().map(|a| a.to_account_info());
+    if let Some(hooks) = HookUtils::load_collection_hooks(collection_mint, hooks_account.as_ref())? {
+        let payload = HookPayload {
+            event: HookEvent::Mint,
+            mint: ctx.accounts.mint.key(),
+            collection_mint: hooks.collection_mint,
+            from: ctx.accounts.owner.key(),
+            to: None,
+            destination_chain_id: None,
+        };
+        HookUtils::invoke_hooks(&hooks, &payload, ctx.remaining_accounts)?;
+    }
+
+    emit!(NftMinted {
+        mint: ctx.accounts.mint.key(),
+        owner: ctx.accounts.owner.key(),
+        uri: ctx.accounts.universal_nft.uri.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Universal NFT minted successfully");
+    log!("Token ID: {}", token_id);
+    log!("Mint: {}", ctx.accounts.mint.key());
+    log!("Owner: {}", ctx.accounts.owner.key());
 
     Ok(())
 }
 
+// Mirrored by crates/universal-nft-interface: keep account order in sync
 #[derive(Accounts)]
-#[instruction(name: String)]
+#[instruction(name: String, symbol: String, uri: String, collection_mint: Option<Pubkey>)]
 pub struct MintNft<'info> {
     #[account(
         seeds = [b"config"],
@@ -225,9 +365,9 @@ pub struct MintNft<'info> {
     )]
     pub token_account: Account<'info, TokenAccount>,
 
-    /// CHECK: PDA authority for minting
+    /// CHECK: Program-wide mint and freeze authority for universal NFT mints
     #[account(
-        seeds = [b"universal_nft", mint.key().as_ref()],
+        seeds = [b"mint_authority"],
         bump
     )]
     pub mint_authority: UncheckedAccount<'info>,
@@ -238,6 +378,96 @@ pub struct MintNft<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    /// CHECK: Collection hook registry PDA, verified by HookUtils
+    pub collection_hooks: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Collection config PDA, verified by CollectionConfigUtils
+    #[account(mut)]
+    pub collection_config: Option<UncheckedAccount<'info>>,
+
+    /// Active phase of the collection's mint schedule, checked by MintPhaseUtils
+    #[account(mut)]
+    pub mint_phase: Option<Account<'info, MintPhase>>,
+
+    /// CHECK: Must match the active phase's funds recipient
+    #[account(mut)]
+    pub phase_funds_recipient: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Protocol fee schedule PDA, read by FeeUtils
+    #[account(
+        seeds = [b"fee_schedule"],
+        bump
+    )]
+    pub fee_schedule: UncheckedAccount<'info>,
+
+    /// CHECK: Incident mode PDA, read by IncidentUtils
+    #[account(
+        seeds = [b"incident_mode"],
+        bump
+    )]
+    pub incident_mode: UncheckedAccount<'info>,
+
+    /// CHECK: Payer's rate limit bucket PDA, updated by RateLimitUtils
+    #[account(
+        mut,
+        seeds = [b"rate_limit", b"wallet", payer.key().as_ref()],
+        bump
+    )]
+    pub wallet_rate_limit: UncheckedAccount<'info>,
+
+    /// CHECK: Collection's rate limit bucket PDA, updated by RateLimitUtils;
+    /// mints outside a collection share the default key's bucket
+    #[account(
+        mut,
+        seeds = [b"rate_limit", b"collection", collection_mint.unwrap_or_default().as_ref()],
+        bump
+    )]
+    pub collection_rate_limit: UncheckedAccount<'info>,
+
+    /// CHECK: Must match the fee schedule's fee recipient
+    #[account(mut)]
+    pub fee_recipient: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Payer's governance stake, verified by FeeUtils for a fee discount
+    pub governance_stake: Option<UncheckedAccount<'info>>,
+
+    /// Registry naming the governance deployment, required with `governance_stake`
+    #[account(
+        seeds = [b"deployment_registry"],
+        bump = deployment_registry.bump
+    )]
+    pub deployment_registry: Option<Account<'info, DeploymentRegistry>>,
+
+    /// CHECK: Payer's token account holding a membership NFT, verified by FeeUtils
+    pub membership_token_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Universal NFT record of the membership NFT, verified by FeeUtils
+    pub membership_nft: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Rebate campaign PDA, verified by FeeUtils
+    #[account(mut)]
+    pub rebate_campaign: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Named referrer's accrual PDA, verified by FeeUtils
+    #[account(mut)]
+    pub referral: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Payer's user metrics PDA, credited with points by PointsUtils
+    #[account(mut)]
+    pub user_metrics: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Points config PDA, verified by PointsUtils
+    pub points_config: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Usage segment PDA the mint is attributed to, verified by
+    /// UsageSegmentUtils
+    #[account(mut)]
+    pub usage_segment: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Minters leaderboard PDA, verified by LeaderboardUtils
+    #[account(mut)]
+    pub leaderboard: Option<UncheckedAccount<'info>>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
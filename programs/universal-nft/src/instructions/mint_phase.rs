@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Append a phase to a collection's mint schedule. Phases are added in order
+/// and may not overlap (collection authority only).
+pub fn add_mint_phase(ctx: Context<AddMintPhase>, params: MintPhaseParams) -> Result<()> {
+    let collection_config = &mut ctx.accounts.collection_config;
+
+    require!(
+        collection_config.phase_count < MintPhase::MAX_PHASES,
+        UniversalNftError::InvalidMintPhase
+    );
+    require!(params.start_time < params.end_time, UniversalNftError::InvalidMintPhase);
+    require!(
+        params.start_time >= collection_config.phases_end,
+        UniversalNftError::InvalidMintPhase
+    );
+    require!(
+        params.end_time > Clock::get()?.unix_timestamp,
+        UniversalNftError::InvalidMintPhase
+    );
+    if params.kind == MintPhaseKind::Presale {
+        require!(params.allowlist_root.is_some(), UniversalNftError::InvalidMintPhase);
+    }
+
+    let mint_phase = &mut ctx.accounts.mint_phase;
+    mint_phase.collection_mint = collection_config.collection_mint;
+    mint_phase.phase_index = collection_config.phase_count;
+    mint_phase.kind = params.kind;
+    mint_phase.start_time = params.start_time;
+    mint_phase.end_time = params.end_time;
+    mint_phase.price = params.price;
+    mint_phase.funds_recipient = params.funds_recipient;
+    mint_phase.allowlist_root = params.allowlist_root;
+    mint_phase.minted_count = 0;
+    mint_phase.bump = ctx.bumps.mint_phase;
+
+    collection_config.phase_count += 1;
+    collection_config.phases_end = params.end_time;
+
+    emit!(MintPhaseAdded {
+        collection_mint: mint_phase.collection_mint,
+        phase_index: mint_phase.phase_index,
+        kind: mint_phase.kind,
+        start_time: mint_phase.start_time,
+        end_time: mint_phase.end_time,
+        price: mint_phase.price,
+    });
+
+    log!("Mint phase {} added to collection {}", mint_phase.phase_index, mint_phase.collection_mint);
+    log!("Window: {} - {}", mint_phase.start_time, mint_phase.end_time);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddMintPhase<'info> {
+    #[account(
+        seeds = [b"collection", collection.mint.as_ref()],
+        bump = collection.bump,
+        has_one = authority
+    )]
+    pub collection: Account<'info, UniversalCollection>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection.mint.as_ref()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + MintPhase::INIT_SPACE,
+        seeds = [
+            b"mint_phase",
+            collection.mint.as_ref(),
+            &[collection_config.phase_count]
+        ],
+        bump
+    )]
+    pub mint_phase: Account<'info, MintPhase>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
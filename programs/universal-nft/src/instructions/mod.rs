@@ -1,13 +1,183 @@
 pub mod initialize;
 pub mod mint_nft;
 pub mod cross_chain;
+pub mod inbound;
 pub mod transfer;
 pub mod metadata;
 pub mod signature;
+#[cfg(feature = "governance")]
+pub mod compensation;
+pub mod error_recovery;
+pub mod checkpoint;
+pub mod backup;
+pub mod orphan;
+pub mod dispute;
+pub mod query;
+pub mod gas_token;
+pub mod chain_registry;
+pub mod collection_hooks;
+pub mod receipt;
+pub mod schedule;
+#[cfg(feature = "governance")]
+pub mod insurance;
+pub mod batch_transfer;
+pub mod relayer;
+pub mod compliance;
+pub mod light_client;
+pub mod collection_floor;
+pub mod collection_config;
+pub mod mint_phase;
+pub mod claim_escrow;
+pub mod snapshot;
+pub mod burn;
+pub mod wallet_lock;
+pub mod operator_session;
+pub mod bridge_cost;
+#[cfg(feature = "governance")]
+pub mod mint_authority;
+pub mod rent_vault;
+pub mod crank;
+pub mod view;
+pub mod metadata_rules;
+pub mod pin_receipt;
+pub mod moderation;
+pub mod royalty;
+pub mod supply_audit;
+pub mod recipient_preferences;
+pub mod quarantine;
+#[cfg(feature = "governance")]
+pub mod fee_schedule;
+#[cfg(feature = "governance")]
+pub mod chain_risk;
+pub mod referral;
+pub mod rebate;
+pub mod attributes;
+pub mod reveal;
+pub mod nesting;
+pub mod evolution;
+pub mod ticketing;
+pub mod vesting;
+pub mod attendance;
+pub mod credentials;
+pub mod swap;
+pub mod lending;
+pub mod security_export;
+pub mod corridor_pause;
+pub mod retry;
+#[cfg(feature = "governance")]
+pub mod deployment_registry;
+#[cfg(feature = "analytics")]
+pub mod points;
+#[cfg(feature = "analytics")]
+pub mod remediation;
+#[cfg(feature = "analytics")]
+pub mod usage;
+#[cfg(feature = "analytics")]
+pub mod leaderboard;
+#[cfg(feature = "analytics")]
+pub mod chain_metrics;
+#[cfg(not(feature = "governance"))]
+pub mod governance_disabled;
+#[cfg(not(feature = "analytics"))]
+pub mod analytics_disabled;
+pub mod circuit_breaker;
+pub mod incident;
+pub mod observer_quorum;
+pub mod rate_limit;
+pub mod fraud_engine;
+#[cfg(feature = "governance")]
+pub mod validator_set;
+#[cfg(feature = "governance")]
+pub mod fraud_config;
 
 pub use initialize::*;
 pub use mint_nft::*;
 pub use cross_chain::*;
+pub use inbound::*;
 pub use transfer::*;
 pub use metadata::*;
-pub use signature::*;
\ No newline at end of file
+pub use signature::*;
+#[cfg(feature = "governance")]
+pub use compensation::*;
+pub use error_recovery::*;
+pub use checkpoint::*;
+pub use backup::*;
+pub use orphan::*;
+pub use dispute::*;
+pub use query::*;
+pub use gas_token::*;
+pub use chain_registry::*;
+pub use collection_hooks::*;
+pub use receipt::*;
+pub use schedule::*;
+#[cfg(feature = "governance")]
+pub use insurance::*;
+pub use batch_transfer::*;
+pub use relayer::*;
+pub use compliance::*;
+pub use light_client::*;
+pub use collection_floor::*;
+pub use collection_config::*;
+pub use mint_phase::*;
+pub use claim_escrow::*;
+pub use snapshot::*;
+pub use burn::*;
+pub use wallet_lock::*;
+pub use operator_session::*;
+pub use bridge_cost::*;
+#[cfg(feature = "governance")]
+pub use mint_authority::*;
+pub use rent_vault::*;
+pub use crank::*;
+pub use view::*;
+pub use metadata_rules::*;
+pub use pin_receipt::*;
+pub use moderation::*;
+pub use royalty::*;
+pub use supply_audit::*;
+pub use recipient_preferences::*;
+pub use quarantine::*;
+#[cfg(feature = "governance")]
+pub use fee_schedule::*;
+#[cfg(feature = "governance")]
+pub use chain_risk::*;
+pub use referral::*;
+pub use rebate::*;
+pub use attributes::*;
+pub use reveal::*;
+pub use nesting::*;
+pub use evolution::*;
+pub use ticketing::*;
+pub use vesting::*;
+pub use attendance::*;
+pub use credentials::*;
+pub use swap::*;
+pub use lending::*;
+pub use security_export::*;
+pub use corridor_pause::*;
+pub use retry::*;
+#[cfg(feature = "governance")]
+pub use deployment_registry::*;
+#[cfg(feature = "analytics")]
+pub use points::*;
+#[cfg(feature = "analytics")]
+pub use remediation::*;
+#[cfg(feature = "analytics")]
+pub use usage::*;
+#[cfg(feature = "analytics")]
+pub use leaderboard::*;
+#[cfg(feature = "analytics")]
+pub use chain_metrics::*;
+#[cfg(not(feature = "governance"))]
+pub use governance_disabled::*;
+#[cfg(not(feature = "analytics"))]
+pub use analytics_disabled::*;
+pub use circuit_breaker::*;
+pub use incident::*;
+pub use observer_quorum::*;
+pub use rate_limit::*;
+pub use fraud_engine::*;
+#[cfg(feature = "governance")]
+pub use validator_set::*;
+#[cfg(feature = "governance")]
+pub use fraud_config::*;
@@ -0,0 +1,272 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Create the moderation config, assign the moderator and set the bond
+/// required to flag content (program authority only)
+pub fn initialize_moderation_config(
+    ctx: Context<InitializeModerationConfig>,
+    moderator: Pubkey,
+    flag_bond: u64,
+) -> Result<()> {
+    let moderation = &mut ctx.accounts.moderation_config;
+    moderation.authority = ctx.accounts.authority.key();
+    moderation.moderator = moderator;
+    moderation.flag_bond = flag_bond;
+    moderation.total_flags = 0;
+    moderation.total_takedowns = 0;
+    moderation.bump = ctx.bumps.moderation_config;
+
+    log!("Moderation config initialized");
+    log!("Moderator: {}", moderator);
+    log!("Flag bond: {} lamports", flag_bond);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeModerationConfig<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ModerationConfig::INIT_SPACE,
+        seeds = [b"moderation_config"],
+        bump
+    )]
+    pub moderation_config: Account<'info, ModerationConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Replace the moderator or change the flag bond (program authority only)
+pub fn update_moderation_config(
+    ctx: Context<UpdateModerationConfig>,
+    moderator: Option<Pubkey>,
+    flag_bond: Option<u64>,
+) -> Result<()> {
+    let moderation = &mut ctx.accounts.moderation_config;
+    if let Some(moderator) = moderator {
+        moderation.moderator = moderator;
+        log!("Moderator updated: {}", moderator);
+    }
+    if let Some(flag_bond) = flag_bond {
+        moderation.flag_bond = flag_bond;
+        log!("Flag bond updated: {} lamports", flag_bond);
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateModerationConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"moderation_config"],
+        bump = moderation_config.bump,
+        has_one = authority
+    )]
+    pub moderation_config: Account<'info, ModerationConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Flag an NFT's content for review. Anyone may flag by posting the bond,
+/// which is returned if the moderator takes the content down and paid to the
+/// NFT owner if the flag is dismissed. One flag per NFT is open at a time.
+pub fn flag_content(ctx: Context<FlagContent>, reason_hash: [u8; 32]) -> Result<()> {
+    let universal_nft = &ctx.accounts.universal_nft;
+    require!(!universal_nft.is_flagged, UniversalNftError::ContentAlreadyTakenDown);
+
+    let bond = ctx.accounts.moderation_config.flag_bond;
+    let cpi_accounts = system_program::Transfer {
+        from: ctx.accounts.flagger.to_account_info(),
+        to: ctx.accounts.content_flag.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, bond)?;
+
+    let moderation = &mut ctx.accounts.moderation_config;
+    moderation.total_flags = moderation.total_flags
+        .checked_add(1)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let flag = &mut ctx.accounts.content_flag;
+    flag.mint = universal_nft.mint;
+    flag.flagger = ctx.accounts.flagger.key();
+    flag.reason_hash = reason_hash;
+    flag.bond = bond;
+    flag.flagged_at = now;
+    flag.bump = ctx.bumps.content_flag;
+
+    emit!(ContentFlagged {
+        mint: flag.mint,
+        flagger: flag.flagger,
+        reason_hash,
+        bond,
+        timestamp: now,
+    });
+
+    log!("Content flagged: {}", flag.mint);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FlagContent<'info> {
+    #[account(
+        mut,
+        seeds = [b"moderation_config"],
+        bump = moderation_config.bump
+    )]
+    pub moderation_config: Account<'info, ModerationConfig>,
+
+    #[account(
+        seeds = [b"universal_nft", universal_nft.mint.as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(
+        init,
+        payer = flagger,
+        space = 8 + ContentFlag::INIT_SPACE,
+        seeds = [b"content_flag", universal_nft.mint.as_ref()],
+        bump
+    )]
+    pub content_flag: Account<'info, ContentFlag>,
+
+    #[account(mut)]
+    pub flagger: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Resolve an open flag (moderator only). Upholding it takes the content down
+/// by setting the NFT's flagged bit and returns the bond to the flagger;
+/// dismissing it pays the bond to the NFT owner. The flag account is closed
+/// to the flagger either way.
+pub fn resolve_content_flag(
+    ctx: Context<ResolveContentFlag>,
+    take_down: bool,
+    decision_hash: [u8; 32],
+) -> Result<()> {
+    let bond = ctx.accounts.content_flag.bond;
+    if !take_down {
+        **ctx.accounts.content_flag.to_account_info().try_borrow_mut_lamports()? -= bond;
+        **ctx.accounts.owner.to_account_info().try_borrow_mut_lamports()? += bond;
+    }
+
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    if take_down {
+        universal_nft.is_flagged = true;
+
+        let moderation = &mut ctx.accounts.moderation_config;
+        moderation.total_takedowns = moderation.total_takedowns
+            .checked_add(1)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+    }
+
+    emit!(ContentModerationResolved {
+        mint: universal_nft.mint,
+        flagger: ctx.accounts.flagger.key(),
+        moderator: ctx.accounts.moderator.key(),
+        flagged: universal_nft.is_flagged,
+        decision_hash,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Content flag resolved: {}", universal_nft.mint);
+    log!("Taken down: {}", take_down);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResolveContentFlag<'info> {
+    #[account(
+        mut,
+        seeds = [b"moderation_config"],
+        bump = moderation_config.bump,
+        has_one = moderator @ UniversalNftError::InvalidModerator
+    )]
+    pub moderation_config: Account<'info, ModerationConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", universal_nft.mint.as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(
+        mut,
+        close = flagger,
+        seeds = [b"content_flag", universal_nft.mint.as_ref()],
+        bump = content_flag.bump,
+        has_one = flagger
+    )]
+    pub content_flag: Account<'info, ContentFlag>,
+
+    /// CHECK: Flagger receiving the flag account's rent, checked by has_one
+    #[account(mut)]
+    pub flagger: UncheckedAccount<'info>,
+
+    /// CHECK: NFT owner receiving a dismissed flag's bond
+    #[account(mut, address = universal_nft.owner @ UniversalNftError::InvalidNftOwner)]
+    pub owner: UncheckedAccount<'info>,
+
+    pub moderator: Signer<'info>,
+}
+
+/// Reinstate content taken down by moderation (moderator only)
+pub fn reinstate_content(ctx: Context<ReinstateContent>, decision_hash: [u8; 32]) -> Result<()> {
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    require!(universal_nft.is_flagged, UniversalNftError::ContentNotTakenDown);
+    universal_nft.is_flagged = false;
+
+    emit!(ContentModerationResolved {
+        mint: universal_nft.mint,
+        flagger: Pubkey::default(),
+        moderator: ctx.accounts.moderator.key(),
+        flagged: false,
+        decision_hash,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Content reinstated: {}", universal_nft.mint);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReinstateContent<'info> {
+    #[account(
+        seeds = [b"moderation_config"],
+        bump = moderation_config.bump,
+        has_one = moderator @ UniversalNftError::InvalidModerator
+    )]
+    pub moderation_config: Account<'info, ModerationConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", universal_nft.mint.as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    pub moderator: Signer<'info>,
+}
@@ -0,0 +1,340 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::{NestingUtils, WalletLockUtils};
+
+/// Equip one of the signer's NFTs into another NFT they hold. The child moves
+/// into program custody and belongs to whoever holds the parent. Nesting is
+/// one level deep: a parent can't be equipped, nor can an NFT with children.
+pub fn equip_nft(ctx: Context<EquipNft>) -> Result<()> {
+    require!(!ctx.accounts.config.is_paused, UniversalNftError::ProgramPaused);
+
+    let owner = ctx.accounts.owner.key();
+    let parent = &ctx.accounts.parent_nft;
+    parent.require_no_transfer_in_progress()?;
+    require!(parent.owner == owner, UniversalNftError::InvalidNftOwner);
+
+    let child = &mut ctx.accounts.child_nft;
+    require_keys_neq!(child.mint, parent.mint, UniversalNftError::InvalidNestedNft);
+    child.require_no_transfer_in_progress()?;
+    require!(!child.is_locked, UniversalNftError::NftLocked);
+    require!(!child.is_disputed, UniversalNftError::NftUnderDispute);
+    require!(!child.is_frozen, UniversalNftError::NftFrozen);
+    require!(child.owner == owner, UniversalNftError::InvalidNftOwner);
+    if let Some(grandchildren) = NestingUtils::load_children(&ctx.accounts.child_children)? {
+        require!(grandchildren.children.is_empty(), UniversalNftError::InvalidNestedNft);
+    }
+
+    // Equipping moves the child out of the wallet, like a transfer
+    WalletLockUtils::require_unlocked(&ctx.accounts.wallet_lock)?;
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.child_token_account.to_account_info(),
+        to: ctx.accounts.custody_token_account.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, 1)?;
+
+    // Custody owns the child until it is unequipped, so it can't be moved
+    // or bridged on its own
+    child.owner = ctx.accounts.custody.key();
+
+    let children = &mut ctx.accounts.nft_children;
+    if children.parent_mint == Pubkey::default() {
+        children.parent_mint = parent.mint;
+        children.bridge_mode = ChildBridgeMode::Block;
+        children.bump = ctx.bumps.nft_children;
+    }
+    children.equip(EquippedChild {
+        mint: child.mint,
+        token_id: child.origin_token_id.clone(),
+        name: child.name.clone(),
+        symbol: child.symbol.clone(),
+        uri: child.uri.clone(),
+        collection_mint: child.collection_mint,
+    })?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let link = &mut ctx.accounts.nft_parent;
+    link.child_mint = child.mint;
+    link.parent_mint = parent.mint;
+    link.equipped_at = now;
+    link.bump = ctx.bumps.nft_parent;
+
+    emit!(NftEquipped {
+        parent_mint: parent.mint,
+        child_mint: child.mint,
+        owner,
+        timestamp: now,
+    });
+
+    log!("NFT {} equipped into {}", child.mint, parent.mint);
+    Ok(())
+}
+
+/// Take a child out of its parent and give it to the parent's holder
+pub fn unequip_nft(ctx: Context<UnequipNft>) -> Result<()> {
+    require!(!ctx.accounts.config.is_paused, UniversalNftError::ProgramPaused);
+
+    let owner = ctx.accounts.owner.key();
+    let parent = &ctx.accounts.parent_nft;
+    parent.require_no_transfer_in_progress()?;
+    require!(parent.owner == owner, UniversalNftError::InvalidNftOwner);
+
+    let child_mint = ctx.accounts.child_mint.key();
+    ctx.accounts.nft_children.unequip(&child_mint)?;
+
+    let custody_seeds = &[b"nft_custody".as_ref(), &[ctx.bumps.custody]];
+    let signer_seeds = &[&custody_seeds[..]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.custody_token_account.to_account_info(),
+        to: ctx.accounts.owner_token_account.to_account_info(),
+        authority: ctx.accounts.custody.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::transfer(cpi_ctx, 1)?;
+
+    ctx.accounts.child_nft.owner = owner;
+
+    emit!(NftUnequipped {
+        parent_mint: parent.mint,
+        child_mint,
+        owner,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("NFT {} unequipped from {}", child_mint, parent.mint);
+    Ok(())
+}
+
+/// Choose whether the parent's children block its bridge-out or travel with
+/// it (parent holder only)
+pub fn set_child_bridge_mode(ctx: Context<SetChildBridgeMode>, mode: ChildBridgeMode) -> Result<()> {
+    let parent = &ctx.accounts.parent_nft;
+    parent.require_no_transfer_in_progress()?;
+    require!(
+        parent.owner == ctx.accounts.owner.key(),
+        UniversalNftError::InvalidNftOwner
+    );
+
+    let children = &mut ctx.accounts.nft_children;
+    children.parent_mint = parent.mint;
+    children.bridge_mode = mode;
+    children.bump = ctx.bumps.nft_children;
+
+    emit!(ChildBridgeModeSet {
+        parent_mint: parent.mint,
+        mode,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct EquipNft<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"universal_nft", parent_nft.mint.as_ref()],
+        bump = parent_nft.bump
+    )]
+    pub parent_nft: Account<'info, UniversalNft>,
+
+    #[account(
+        constraint = parent_token_account.mint == parent_nft.mint @ UniversalNftError::InvalidNftOwner,
+        constraint = parent_token_account.owner == owner.key() @ UniversalNftError::InvalidNftOwner,
+        constraint = parent_token_account.amount == 1 @ UniversalNftError::InvalidNftOwner
+    )]
+    pub parent_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + NftChildren::INIT_SPACE,
+        seeds = [b"nft_children", parent_nft.mint.as_ref()],
+        bump
+    )]
+    pub nft_children: Account<'info, NftChildren>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", child_mint.key().as_ref()],
+        bump = child_nft.bump
+    )]
+    pub child_nft: Account<'info, UniversalNft>,
+
+    pub child_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = child_mint,
+        token::authority = owner
+    )]
+    pub child_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Child's own children PDA, empty when it has none. Always passed
+    /// so a parent can't be nested.
+    #[account(
+        seeds = [b"nft_children", child_mint.key().as_ref()],
+        bump
+    )]
+    pub child_children: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + NftParent::INIT_SPACE,
+        seeds = [b"nft_parent", child_mint.key().as_ref()],
+        bump
+    )]
+    pub nft_parent: Account<'info, NftParent>,
+
+    /// CHECK: PDA that holds NFTs in program custody
+    #[account(
+        seeds = [b"nft_custody"],
+        bump
+    )]
+    pub custody: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = child_mint,
+        associated_token::authority = custody,
+    )]
+    pub custody_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Owner's wallet lock PDA, read by WalletLockUtils
+    #[account(
+        seeds = [b"wallet_lock", owner.key().as_ref()],
+        bump
+    )]
+    pub wallet_lock: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnequipNft<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"universal_nft", parent_nft.mint.as_ref()],
+        bump = parent_nft.bump
+    )]
+    pub parent_nft: Account<'info, UniversalNft>,
+
+    #[account(
+        constraint = parent_token_account.mint == parent_nft.mint @ UniversalNftError::InvalidNftOwner,
+        constraint = parent_token_account.owner == owner.key() @ UniversalNftError::InvalidNftOwner,
+        constraint = parent_token_account.amount == 1 @ UniversalNftError::InvalidNftOwner
+    )]
+    pub parent_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"nft_children", parent_nft.mint.as_ref()],
+        bump = nft_children.bump
+    )]
+    pub nft_children: Account<'info, NftChildren>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", child_mint.key().as_ref()],
+        bump = child_nft.bump
+    )]
+    pub child_nft: Account<'info, UniversalNft>,
+
+    pub child_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"nft_parent", child_mint.key().as_ref()],
+        bump = nft_parent.bump,
+        constraint = nft_parent.parent_mint == parent_nft.mint @ UniversalNftError::ChildNotEquipped
+    )]
+    pub nft_parent: Account<'info, NftParent>,
+
+    /// CHECK: PDA that holds NFTs in program custody
+    #[account(
+        seeds = [b"nft_custody"],
+        bump
+    )]
+    pub custody: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = child_mint,
+        associated_token::authority = custody,
+    )]
+    pub custody_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = child_mint,
+        associated_token::authority = owner,
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetChildBridgeMode<'info> {
+    #[account(
+        seeds = [b"universal_nft", parent_nft.mint.as_ref()],
+        bump = parent_nft.bump
+    )]
+    pub parent_nft: Account<'info, UniversalNft>,
+
+    #[account(
+        constraint = parent_token_account.mint == parent_nft.mint @ UniversalNftError::InvalidNftOwner,
+        constraint = parent_token_account.owner == owner.key() @ UniversalNftError::InvalidNftOwner,
+        constraint = parent_token_account.amount == 1 @ UniversalNftError::InvalidNftOwner
+    )]
+    pub parent_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + NftChildren::INIT_SPACE,
+        seeds = [b"nft_children", parent_nft.mint.as_ref()],
+        bump
+    )]
+    pub nft_children: Account<'info, NftChildren>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
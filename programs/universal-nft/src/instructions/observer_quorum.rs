@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::events::*;
+use crate::security::{QuorumAttestation, QuorumSignature, ValidatorSet};
+use crate::utils::LightClientUtils;
+
+/// Record that K of the N registered observers signed an inbound message.
+/// Anyone may submit the signatures; on_call consumes the attestation once.
+/// After a validator set rotation, an unconsumed attestation from the old
+/// epoch can be replaced with one from the current set.
+pub fn attest_inbound_message(
+    ctx: Context<AttestInboundMessage>,
+    source_chain_id: u64,
+    sender: [u8; 20],
+    message: Vec<u8>,
+    signatures: Vec<QuorumSignature>,
+) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let leaf = LightClientUtils::event_leaf(source_chain_id, &sender, &message);
+    let approvals = validator_set.verify_quorum(
+        &ValidatorSet::signing_hash(source_chain_id, &leaf),
+        &signatures,
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.quorum_attestation.record(
+        leaf,
+        source_chain_id,
+        validator_set.epoch,
+        approvals,
+        now,
+        ctx.bumps.quorum_attestation,
+    )?;
+
+    emit!(QuorumAttested {
+        leaf,
+        chain_id: source_chain_id,
+        epoch: validator_set.epoch,
+        approvals,
+        timestamp: now,
+    });
+
+    log!("Inbound message from chain {} attested by {} observers", source_chain_id, approvals);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(source_chain_id: u64, sender: [u8; 20], message: Vec<u8>)]
+pub struct AttestInboundMessage<'info> {
+    #[account(
+        seeds = [b"validator_set"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, ValidatorSet>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + QuorumAttestation::INIT_SPACE,
+        seeds = [
+            b"quorum_attestation",
+            LightClientUtils::event_leaf(source_chain_id, &sender, &message).as_ref()
+        ],
+        bump
+    )]
+    pub quorum_attestation: Account<'info, QuorumAttestation>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
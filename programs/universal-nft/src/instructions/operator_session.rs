@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Register or replace a scoped operator key for the signer's NFTs. Transfer
+/// and bridge scopes act through the token delegate the owner approves. A
+/// bridge chain's registry entry is the first remaining account.
+pub fn register_operator(
+    ctx: Context<RegisterOperator>,
+    operator: Pubkey,
+    scopes: u8,
+    bridge_chain_id: Option<u64>,
+    expires_slot: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    OperatorSession::validate_terms(&ctx.accounts.owner.key(), &operator, scopes, expires_slot, clock.slot)?;
+    if let Some(chain_id) = bridge_chain_id {
+        CrossChainUtils::validate_registered_chain_ids(&[chain_id], ctx.remaining_accounts)?;
+    }
+
+    let session = &mut ctx.accounts.operator_session;
+    session.owner = ctx.accounts.owner.key();
+    session.operator = operator;
+    session.scopes = scopes;
+    session.bridge_chain_id = bridge_chain_id;
+    session.expires_slot = expires_slot;
+    session.created_at = clock.unix_timestamp;
+    session.bump = ctx.bumps.operator_session;
+
+    emit!(OperatorSessionRegistered {
+        owner: session.owner,
+        operator,
+        scopes,
+        bridge_chain_id,
+        expires_slot,
+        timestamp: clock.unix_timestamp,
+    });
+
+    log!("Operator session registered: {}", operator);
+    log!("Expires at slot: {}", expires_slot);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(operator: Pubkey)]
+pub struct RegisterOperator<'info> {
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + OperatorSession::INIT_SPACE,
+        seeds = [b"operator_session", owner.key().as_ref(), operator.as_ref()],
+        bump
+    )]
+    pub operator_session: Account<'info, OperatorSession>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// End an operator session immediately. The account is kept, expired, so a
+/// token delegate registered as the operator stays bound by it.
+pub fn revoke_operator(ctx: Context<RevokeOperator>) -> Result<()> {
+    let session = &mut ctx.accounts.operator_session;
+    session.scopes = 0;
+    session.expires_slot = 0;
+
+    emit!(OperatorSessionRevoked {
+        owner: session.owner,
+        operator: session.operator,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Operator session revoked: {}", session.operator);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeOperator<'info> {
+    #[account(
+        mut,
+        seeds = [b"operator_session", owner.key().as_ref(), operator_session.operator.as_ref()],
+        bump = operator_session.bump,
+        has_one = owner
+    )]
+    pub operator_session: Account<'info, OperatorSession>,
+
+    pub owner: Signer<'info>,
+}
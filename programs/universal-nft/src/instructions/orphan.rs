@@ -0,0 +1,277 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::{OrphanUtils, RelayerUtils};
+
+/// Register an NFT left in program custody by an inbound mint message that
+/// was relayed but never executed (program or gateway authority only). The
+/// message, its sequence and sender must match the relayed message record,
+/// whose hash is kept so that only the recipient the message names can
+/// later claim the NFT. NFTs held for an escrow, dispute, quarantine or
+/// nesting are refused.
+pub fn register_orphaned_nft(
+    ctx: Context<RegisterOrphanedNft>,
+    sequence: u64,
+    sender: [u8; 20],
+    message: Vec<u8>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let signer = ctx.accounts.authority.key();
+
+    require!(
+        signer == config.authority || signer == config.gateway_authority,
+        UniversalNftError::Unauthorized
+    );
+    require!(
+        ctx.accounts.custody_token_account.amount == 1,
+        UniversalNftError::NftNotInCustody
+    );
+
+    let record = &ctx.accounts.relayed_message;
+    OrphanUtils::require_failed_delivery(record, sequence, &sender, &message)?;
+    OrphanUtils::recipient(&message, &ctx.accounts.universal_nft.origin_token_id)?;
+
+    // Only an NFT that left through the bridge lock waits in custody for a
+    // returning message; the other custody flows keep their own records
+    let universal_nft = &ctx.accounts.universal_nft;
+    require!(
+        universal_nft.is_locked
+            && !universal_nft.is_disputed
+            && ctx.accounts.nft_owner.owner != &crate::ID
+            && ctx.accounts.quarantine.data_is_empty()
+            && ctx.accounts.nft_parent.data_is_empty(),
+        UniversalNftError::NftHeldForAnotherFlow
+    );
+    let source_chain_id = record.source_chain_id;
+    let message_hash = record.message_hash;
+
+    let registry = &mut ctx.accounts.registry;
+    if registry.bump == 0 {
+        registry.bump = ctx.bumps.registry;
+    }
+    registry.total_orphaned = registry.total_orphaned
+        .checked_add(1)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let orphan = &mut ctx.accounts.orphan;
+    orphan.mint = ctx.accounts.mint.key();
+    orphan.custody_token_account = ctx.accounts.custody_token_account.key();
+    orphan.source_chain_id = source_chain_id;
+    orphan.message_hash = message_hash;
+    orphan.registered_at = now;
+    orphan.claimed_by = None;
+    orphan.claimed_at = 0;
+    orphan.bump = ctx.bumps.orphan;
+
+    emit!(NftOrphaned {
+        mint: orphan.mint,
+        source_chain_id,
+        message_hash,
+        timestamp: now,
+    });
+
+    log!("NFT registered as orphaned: {}", orphan.mint);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterOrphanedNft<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + OrphanRegistry::INIT_SPACE,
+        seeds = [b"orphan_registry"],
+        bump
+    )]
+    pub registry: Account<'info, OrphanRegistry>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + OrphanedNft::INIT_SPACE,
+        seeds = [b"orphan", mint.key().as_ref()],
+        bump
+    )]
+    pub orphan: Account<'info, OrphanedNft>,
+
+    /// Record of the relayed message that failed to deliver the NFT
+    #[account(
+        seeds = [b"relayed_message", relayed_message.message_hash.as_ref()],
+        bump = relayed_message.bump
+    )]
+    pub relayed_message: Account<'info, RelayedMessage>,
+
+    #[account(
+        seeds = [b"universal_nft", mint.key().as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    /// CHECK: The NFT's recorded owner, which is a program PDA while the NFT
+    /// is held for a claim escrow
+    #[account(address = universal_nft.owner)]
+    pub nft_owner: UncheckedAccount<'info>,
+
+    /// CHECK: The NFT's quarantine PDA, which must not exist
+    #[account(
+        seeds = [b"quarantine", mint.key().as_ref()],
+        bump
+    )]
+    pub quarantine: UncheckedAccount<'info>,
+
+    /// CHECK: The NFT's nesting parent PDA, which must not exist
+    #[account(
+        seeds = [b"nft_parent", mint.key().as_ref()],
+        bump
+    )]
+    pub nft_parent: UncheckedAccount<'info>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        token::mint = mint,
+        token::authority = custody,
+    )]
+    pub custody_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that holds NFTs in program custody
+    #[account(
+        seeds = [b"nft_custody"],
+        bump
+    )]
+    pub custody: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Claim an orphaned NFT by presenting the original inbound message, with
+/// its sequence and sender, naming the claimant as recipient
+pub fn claim_orphaned_nft(
+    ctx: Context<ClaimOrphanedNft>,
+    sequence: u64,
+    sender: [u8; 20],
+    original_message: Vec<u8>,
+) -> Result<()> {
+    let orphan = &mut ctx.accounts.orphan;
+    let claimant = ctx.accounts.recipient.key();
+
+    require!(orphan.claimed_by.is_none(), UniversalNftError::OrphanAlreadyClaimed);
+
+    // Proof of original recipient: the message must hash to the recorded
+    // value and name the claimant as the NFT's mint recipient
+    require!(
+        RelayerUtils::relayed_message_hash(orphan.source_chain_id, sequence, &sender, &original_message)
+            == orphan.message_hash,
+        UniversalNftError::OrphanProofMismatch
+    );
+    let recipient = OrphanUtils::recipient(&original_message, &ctx.accounts.universal_nft.origin_token_id)?;
+    require_keys_eq!(recipient, claimant, UniversalNftError::OrphanProofMismatch);
+
+    // Release the NFT from custody
+    let custody_seeds = &[b"nft_custody".as_ref(), &[ctx.bumps.custody]];
+    let signer_seeds = &[&custody_seeds[..]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.custody_token_account.to_account_info(),
+        to: ctx.accounts.recipient_token_account.to_account_info(),
+        authority: ctx.accounts.custody.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::transfer(cpi_ctx, 1)?;
+
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    universal_nft.owner = claimant;
+    universal_nft.is_locked = false;
+
+    let now = Clock::get()?.unix_timestamp;
+    orphan.claimed_by = Some(claimant);
+    orphan.claimed_at = now;
+
+    let registry = &mut ctx.accounts.registry;
+    registry.total_claimed = registry.total_claimed
+        .checked_add(1)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    emit!(OrphanedNftClaimed {
+        mint: orphan.mint,
+        recipient: claimant,
+        timestamp: now,
+    });
+
+    log!("Orphaned NFT claimed");
+    log!("Mint: {}", orphan.mint);
+    log!("Recipient: {}", claimant);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimOrphanedNft<'info> {
+    #[account(
+        mut,
+        seeds = [b"orphan_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, OrphanRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"orphan", mint.key().as_ref()],
+        bump = orphan.bump,
+        has_one = mint,
+        has_one = custody_token_account
+    )]
+    pub orphan: Account<'info, OrphanedNft>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", mint.key().as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub custody_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that holds NFTs in program custody
+    #[account(
+        seeds = [b"nft_custody"],
+        bump
+    )]
+    pub custody: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = recipient,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use sha2::{Digest, Sha256};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Register or replace the pinning service allowed to post pin receipts
+/// (program authority only)
+pub fn register_pinner(ctx: Context<RegisterPinner>, pinner: Pubkey) -> Result<()> {
+    let pin_config = &mut ctx.accounts.pin_config;
+    pin_config.pinner = pinner;
+    pin_config.bump = ctx.bumps.pin_config;
+
+    emit!(PinnerRegistered {
+        pinner,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Pinner registered: {}", pinner);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterPinner<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PinConfig::INIT_SPACE,
+        seeds = [b"pin_config"],
+        bump
+    )]
+    pub pin_config: Account<'info, PinConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Record that the content behind an NFT's current metadata URI is pinned
+/// (registered pinner only). The receipt is bound to the URI by hash, so a
+/// metadata update leaves it stale until the pinner posts a new one.
+pub fn record_pin_receipt(
+    ctx: Context<RecordPinReceipt>,
+    storage: PinStorage,
+    content_id: String,
+    size: u64,
+) -> Result<()> {
+    require!(
+        !content_id.is_empty() && content_id.len() <= PinReceipt::MAX_CONTENT_ID_LEN,
+        UniversalNftError::InvalidPinReceipt
+    );
+    require!(size > 0, UniversalNftError::InvalidPinReceipt);
+
+    let universal_nft = &ctx.accounts.universal_nft;
+    let uri_hash: [u8; 32] = Sha256::digest(universal_nft.uri.as_bytes()).into();
+    let now = Clock::get()?.unix_timestamp;
+
+    let receipt = &mut ctx.accounts.pin_receipt;
+    receipt.mint = universal_nft.mint;
+    receipt.storage = storage;
+    receipt.content_id = content_id.clone();
+    receipt.size = size;
+    receipt.uri_hash = uri_hash;
+    receipt.pinner = ctx.accounts.pinner.key();
+    receipt.pinned_at = now;
+    receipt.bump = ctx.bumps.pin_receipt;
+
+    emit!(PinReceiptRecorded {
+        mint: universal_nft.mint,
+        storage,
+        content_id,
+        size,
+        uri_hash,
+        timestamp: now,
+    });
+
+    log!("Pin receipt recorded for {}", universal_nft.mint);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RecordPinReceipt<'info> {
+    #[account(
+        seeds = [b"pin_config"],
+        bump = pin_config.bump,
+        has_one = pinner @ UniversalNftError::InvalidPinner
+    )]
+    pub pin_config: Account<'info, PinConfig>,
+
+    #[account(
+        seeds = [b"universal_nft", universal_nft.mint.as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(
+        init_if_needed,
+        payer = pinner,
+        space = 8 + PinReceipt::INIT_SPACE,
+        seeds = [b"pin_receipt", universal_nft.mint.as_ref()],
+        bump
+    )]
+    pub pin_receipt: Account<'info, PinReceipt>,
+
+    #[account(mut)]
+    pub pinner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
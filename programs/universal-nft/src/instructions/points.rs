@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+
+use crate::analytics::{GovernancePointsClaim, PointsConfig, UserMetrics};
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Set the points each activity earns and the keys allowed to co-sign
+/// redemptions (program authority only)
+pub fn configure_points(
+    ctx: Context<ConfigurePoints>,
+    weights: PointsWeights,
+    redeemers: Vec<Pubkey>,
+) -> Result<()> {
+    require!(
+        redeemers.len() <= PointsConfig::MAX_REDEEMERS,
+        UniversalNftError::TooManyPointsRedeemers
+    );
+
+    let config = &mut ctx.accounts.points_config;
+    config.weights = weights;
+    config.redeemers = redeemers;
+    config.bump = ctx.bumps.points_config;
+
+    emit!(PointsConfigured {
+        mint_points: weights.mint,
+        bridge_points: weights.bridge,
+        governance_points: weights.governance,
+        redeemers: config.redeemers.len() as u8,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Points configured");
+    Ok(())
+}
+
+/// Open the signer's metrics account so their mints, bridges and votes
+/// start earning points
+pub fn open_user_metrics(ctx: Context<OpenUserMetrics>) -> Result<()> {
+    let user = ctx.accounts.user.key();
+    ctx.accounts.user_metrics.initialize(user, ctx.bumps.user_metrics);
+
+    log!("User metrics opened: {}", user);
+    Ok(())
+}
+
+/// Credit the signer's points for a governance vote they cast. Each vote
+/// counts once.
+pub fn claim_governance_points(ctx: Context<ClaimGovernancePoints>) -> Result<()> {
+    let user = ctx.accounts.user.key();
+    require_keys_eq!(
+        governance_voter(&ctx.accounts.vote, &ctx.accounts.deployment_registry)?,
+        user,
+        UniversalNftError::InvalidPointsAccount
+    );
+
+    let claim = &mut ctx.accounts.claim;
+    claim.vote = ctx.accounts.vote.key();
+    claim.bump = ctx.bumps.claim;
+
+    let points = ctx.accounts.points_config.weight(PointsActivity::Governance);
+    let metrics = &mut ctx.accounts.user_metrics;
+    metrics.award_points(points)?;
+
+    emit!(PointsAwarded {
+        user,
+        activity: PointsActivity::Governance,
+        points,
+        balance: metrics.points,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Governance points awarded: {}", points);
+    Ok(())
+}
+
+/// Spend the user's points. Both the user and one of the configured
+/// redeemers sign, so a reward program grants whatever the points buy in the
+/// same transaction.
+pub fn redeem_points(ctx: Context<RedeemPoints>, amount: u64) -> Result<()> {
+    let redeemer = ctx.accounts.redeemer.key();
+    require!(
+        ctx.accounts.points_config.is_redeemer(&redeemer),
+        UniversalNftError::Unauthorized
+    );
+
+    let metrics = &mut ctx.accounts.user_metrics;
+    metrics.redeem_points(amount)?;
+
+    emit!(PointsRedeemed {
+        user: metrics.user,
+        redeemer,
+        amount,
+        balance: metrics.points,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Points redeemed: {}", amount);
+    Ok(())
+}
+
+/// Voter recorded on a vote account of the approved governance program
+#[cfg(feature = "governance")]
+fn governance_voter(vote: &AccountInfo, registry: &DeploymentRegistry) -> Result<Pubkey> {
+    use crate::governance::proposals::Vote;
+
+    registry.require_program(SatelliteKind::Governance, vote.owner)?;
+    let data = vote.try_borrow_data()?;
+    Ok(Vote::try_deserialize(&mut &data[..])?.voter)
+}
+
+#[cfg(not(feature = "governance"))]
+fn governance_voter(_vote: &AccountInfo, _registry: &DeploymentRegistry) -> Result<Pubkey> {
+    err!(UniversalNftError::FeatureDisabled)
+}
+
+#[derive(Accounts)]
+pub struct ConfigurePoints<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PointsConfig::INIT_SPACE,
+        seeds = [b"points_config"],
+        bump
+    )]
+    pub points_config: Account<'info, PointsConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct OpenUserMetrics<'info> {
+    #[account(
+        init,
+        payer = user,
+        space = 8 + UserMetrics::INIT_SPACE,
+        seeds = [b"user_metrics", user.key().as_ref()],
+        bump
+    )]
+    pub user_metrics: Account<'info, UserMetrics>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimGovernancePoints<'info> {
+    #[account(
+        seeds = [b"points_config"],
+        bump = points_config.bump
+    )]
+    pub points_config: Account<'info, PointsConfig>,
+
+    #[account(
+        seeds = [b"deployment_registry"],
+        bump = deployment_registry.bump
+    )]
+    pub deployment_registry: Account<'info, DeploymentRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"user_metrics", user.key().as_ref()],
+        bump = user_metrics.bump
+    )]
+    pub user_metrics: Account<'info, UserMetrics>,
+
+    /// CHECK: Governance program vote account, deserialized in the handler
+    pub vote: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + GovernancePointsClaim::INIT_SPACE,
+        seeds = [b"vote_points", vote.key().as_ref()],
+        bump
+    )]
+    pub claim: Account<'info, GovernancePointsClaim>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RedeemPoints<'info> {
+    #[account(
+        seeds = [b"points_config"],
+        bump = points_config.bump
+    )]
+    pub points_config: Account<'info, PointsConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"user_metrics", user.key().as_ref()],
+        bump = user_metrics.bump
+    )]
+    pub user_metrics: Account<'info, UserMetrics>,
+
+    pub user: Signer<'info>,
+
+    pub redeemer: Signer<'info>,
+}
@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use solana_program::program::invoke;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::{validate, CrossChainUtils, MessageCodec};
+use crate::instructions::cross_chain::create_gateway_call_instruction;
+
+/// Accept a quarantined NFT, moving it from program custody to the recipient
+pub fn claim_quarantined_nft(ctx: Context<ClaimQuarantinedNft>) -> Result<()> {
+    let custody_seeds = &[b"nft_custody".as_ref(), &[ctx.bumps.custody]];
+    let signer_seeds = &[&custody_seeds[..]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.custody_token_account.to_account_info(),
+        to: ctx.accounts.recipient_token_account.to_account_info(),
+        authority: ctx.accounts.custody.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::transfer(cpi_ctx, 1)?;
+
+    let recipient = ctx.accounts.recipient.key();
+    ctx.accounts.universal_nft.owner = recipient;
+
+    emit!(QuarantineReleased {
+        mint: ctx.accounts.mint.key(),
+        recipient,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Quarantined NFT claimed by {}", recipient);
+    Ok(())
+}
+
+/// Refuse a quarantined NFT, returning it to the sender on its source chain
+pub fn reject_quarantined_nft(ctx: Context<ReturnQuarantinedNft>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.caller.key(),
+        ctx.accounts.quarantine.recipient,
+        UniversalNftError::Unauthorized
+    );
+    return_quarantined_nft(ctx, false)
+}
+
+/// Return a quarantined NFT its recipient left unclaimed past the quarantine
+/// period. Anyone may call this once the NFT has expired.
+pub fn expire_quarantined_nft(ctx: Context<ReturnQuarantinedNft>) -> Result<()> {
+    require!(
+        Clock::get()?.unix_timestamp >= ctx.accounts.quarantine.expires_at,
+        UniversalNftError::QuarantineNotExpired
+    );
+    return_quarantined_nft(ctx, true)
+}
+
+fn return_quarantined_nft(ctx: Context<ReturnQuarantinedNft>, expired: bool) -> Result<()> {
+    require!(!ctx.accounts.config.is_paused, UniversalNftError::ProgramPaused);
+
+    if validate::reissuable(&ctx.accounts.mint) {
+        let custody_seeds = &[b"nft_custody".as_ref(), &[ctx.bumps.custody]];
+        let signer_seeds = &[&custody_seeds[..]];
+        let cpi_accounts = Burn {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.custody_token_account.to_account_info(),
+            authority: ctx.accounts.custody.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token::burn(cpi_ctx, 1)?;
+    } else {
+        // A mint that can't issue the token again keeps it in custody,
+        // released when the NFT next comes back
+        ctx.accounts.universal_nft.is_locked = true;
+        if let Some(ledger) = ctx.accounts.supply_ledger.as_mut() {
+            ledger.originals_locked = ledger.originals_locked
+                .checked_add(1)
+                .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        }
+    }
+
+    // The sender is an EVM address, encoded like any outbound EVM recipient
+    let quarantine = &ctx.accounts.quarantine;
+    let sender_key = CrossChainUtils::encode_recipient(&AddressFormat::Evm, &quarantine.sender)?;
+
+    let nft = &ctx.accounts.universal_nft;
+    let return_msg = CrossChainMessage::MintNft {
+        token_id: nft.origin_token_id.clone(),
+        name: nft.name.clone(),
+        symbol: nft.symbol.clone(),
+        uri: nft.uri.clone(),
+        recipient: sender_key,
+        collection_mint: nft.collection_mint,
+    };
+    let message_data = MessageCodec::encode(&return_msg)?;
+
+    let gateway_call_ix = create_gateway_call_instruction(
+        ctx.accounts.gateway_program.key(),
+        quarantine.source_chain_id,
+        quarantine.sender.to_vec(),
+        message_data,
+        QuarantinedNft::RETURN_GAS_LIMIT,
+    )?;
+
+    invoke(
+        &gateway_call_ix,
+        &[
+            ctx.accounts.gateway_program.to_account_info(),
+            ctx.accounts.caller.to_account_info(),
+        ],
+    )?;
+
+    emit!(QuarantineReturned {
+        mint: quarantine.mint,
+        source_chain_id: quarantine.source_chain_id,
+        sender: quarantine.sender,
+        expired,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Quarantined NFT returned to chain {}", quarantine.source_chain_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimQuarantinedNft<'info> {
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [b"quarantine", mint.key().as_ref()],
+        bump = quarantine.bump,
+        has_one = mint,
+        has_one = recipient
+    )]
+    pub quarantine: Account<'info, QuarantinedNft>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", mint.key().as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = custody,
+    )]
+    pub custody_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that holds NFTs in program custody
+    #[account(
+        seeds = [b"nft_custody"],
+        bump
+    )]
+    pub custody: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = recipient,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReturnQuarantinedNft<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [b"quarantine", mint.key().as_ref()],
+        bump = quarantine.bump,
+        has_one = mint,
+        has_one = recipient
+    )]
+    pub quarantine: Account<'info, QuarantinedNft>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", mint.key().as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = custody,
+    )]
+    pub custody_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that holds NFTs in program custody
+    #[account(
+        seeds = [b"nft_custody"],
+        bump
+    )]
+    pub custody: UncheckedAccount<'info>,
+
+    /// CHECK: Wallet the NFT was quarantined for, refunded the record's rent
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// Recipient when rejecting, anyone once the quarantine has expired
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// Source chain's supply ledger, counting an NFT kept in custody as
+    /// backing the returned token
+    #[account(
+        mut,
+        seeds = [b"supply_ledger", quarantine.source_chain_id.to_le_bytes().as_ref()],
+        bump = supply_ledger.bump
+    )]
+    pub supply_ledger: Option<Account<'info, ChainSupplyLedger>>,
+
+    /// CHECK: Gateway program for cross-chain calls
+    #[account(address = config.gateway_authority)]
+    pub gateway_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
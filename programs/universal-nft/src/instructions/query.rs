@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use solana_program::program::invoke;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+use crate::instructions::cross_chain::create_gateway_call_instruction;
+
+/// Send a read-only query for EVM-chain state through the ZetaChain gateway.
+/// The answer arrives through on_call and is stored in the QueryResult PDA.
+pub fn request_interchain_query(
+    ctx: Context<RequestInterchainQuery>,
+    target_chain_id: u64,
+    query_type: QueryType,
+    contract_address: [u8; 20],
+    token_id: String,
+    gas_limit: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    // Check if program is paused
+    require!(!config.is_paused, UniversalNftError::ProgramPaused);
+
+    // Validate parameters
+    CrossChainUtils::validate_chain_id(target_chain_id, &ctx.accounts.target_chain)?;
+    CrossChainUtils::validate_gas_limit(gas_limit)?;
+    require!(
+        !token_id.is_empty() && token_id.len() <= 64,
+        UniversalNftError::InvalidQueryParameters
+    );
+    require!(
+        contract_address != [0u8; 20],
+        UniversalNftError::InvalidQueryParameters
+    );
+
+    let query_id = config.nonce;
+    config.nonce = config.nonce
+        .checked_add(1)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let query = &mut ctx.accounts.query;
+    query.query_id = query_id;
+    query.requester = ctx.accounts.requester.key();
+    query.target_chain_id = target_chain_id;
+    query.query_type = query_type.clone();
+    query.contract_address = contract_address;
+    query.token_id = token_id.clone();
+    query.status = QueryStatus::Pending;
+    query.response = Vec::new();
+    query.requested_at = now;
+    query.answered_at = 0;
+    query.bump = ctx.bumps.query;
+
+    let query_msg = CrossChainMessage::QueryRequest {
+        query_id,
+        query_type: query_type.clone(),
+        contract_address,
+        token_id,
+        reply_to: query.key(),
+    };
+    let message_data = MessageCodec::encode(&query_msg)?;
+
+    let gateway_call_ix = create_gateway_call_instruction(
+        ctx.accounts.gateway_program.key(),
+        target_chain_id,
+        contract_address.to_vec(),
+        message_data,
+        gas_limit,
+    )?;
+
+    invoke(
+        &gateway_call_ix,
+        &[
+            ctx.accounts.gateway_program.to_account_info(),
+            ctx.accounts.requester.to_account_info(),
+        ],
+    )?;
+
+    emit!(InterchainQueryRequested {
+        query_id,
+        requester: ctx.accounts.requester.key(),
+        target_chain_id,
+        query_type,
+        timestamp: now,
+    });
+
+    log!("Interchain query sent");
+    log!("Query ID: {}", query_id);
+    log!("Target chain: {}", target_chain_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(target_chain_id: u64)]
+pub struct RequestInterchainQuery<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = requester,
+        space = 8 + QueryResult::INIT_SPACE,
+        seeds = [b"query", config.nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub query: Account<'info, QueryResult>,
+
+    /// Registry entry for the chain queried
+    #[account(
+        seeds = [b"chain", target_chain_id.to_le_bytes().as_ref()],
+        bump = target_chain.bump
+    )]
+    pub target_chain: Account<'info, RegisteredChain>,
+
+    #[account(mut)]
+    pub requester: Signer<'info>,
+
+    /// CHECK: Gateway program for cross-chain calls
+    #[account(address = config.gateway_authority)]
+    pub gateway_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
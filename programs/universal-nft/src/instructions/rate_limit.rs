@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::security::{RateLimitConfig, RateLimitSubject, TokenBucket};
+
+/// Create a full token bucket for a wallet, chain or collection (program
+/// authority only). Mints and outbound transfers by the subject then spend
+/// its credits; `None` takes the default capacity and refill rate.
+pub fn initialize_rate_limit(
+    ctx: Context<InitializeRateLimit>,
+    subject: RateLimitSubject,
+    config: Option<RateLimitConfig>,
+) -> Result<()> {
+    ctx.accounts.token_bucket.initialize(
+        subject,
+        config,
+        ctx.accounts.authority.key(),
+        ctx.bumps.token_bucket,
+    )?;
+
+    log!("Rate limit initialized: bucket {}", ctx.accounts.token_bucket.key());
+    Ok(())
+}
+
+/// Change a bucket's capacity, refill rate or enforcement (bucket authority
+/// only)
+pub fn update_rate_limit(ctx: Context<UpdateRateLimit>, config: RateLimitConfig) -> Result<()> {
+    let signer = ctx.accounts.authority.key();
+    ctx.accounts.token_bucket.update_config(signer, config)
+}
+
+#[derive(Accounts)]
+#[instruction(subject: RateLimitSubject)]
+pub struct InitializeRateLimit<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + TokenBucket::INIT_SPACE,
+        seeds = [b"rate_limit", subject.seed_tag(), &subject.seed_key()],
+        bump
+    )]
+    pub token_bucket: Account<'info, TokenBucket>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRateLimit<'info> {
+    #[account(
+        mut,
+        seeds = [b"rate_limit", token_bucket.subject.seed_tag(), &token_bucket.subject.seed_key()],
+        bump = token_bucket.bump,
+        has_one = authority
+    )]
+    pub token_bucket: Account<'info, TokenBucket>,
+
+    pub authority: Signer<'info>,
+}
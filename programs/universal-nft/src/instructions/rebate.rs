@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Deposit lamports the rebate campaign refunds fees from. The treasury
+/// funds campaigns this way, but anyone may top one up.
+pub fn fund_rebate_campaign(ctx: Context<FundRebateCampaign>, amount: u64) -> Result<()> {
+    require!(amount > 0, UniversalNftError::InvalidRebateCampaign);
+
+    let cpi_accounts = system_program::Transfer {
+        from: ctx.accounts.funder.to_account_info(),
+        to: ctx.accounts.rebate_campaign.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, amount)?;
+
+    let campaign = &mut ctx.accounts.rebate_campaign;
+    campaign.funded = campaign.funded
+        .checked_add(amount)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    emit!(RebateCampaignFunded {
+        funder: ctx.accounts.funder.key(),
+        amount,
+        total_funded: campaign.funded,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Rebate campaign funded: {} lamports", amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundRebateCampaign<'info> {
+    #[account(
+        mut,
+        seeds = [b"rebate_campaign"],
+        bump = rebate_campaign.bump
+    )]
+    pub rebate_campaign: Account<'info, RebateCampaign>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,268 @@
+//! Receipt tokens for in-flight cross-chain transfers.
+//! A receipt is minted to the sender when an outbound transfer starts and is
+//! frozen so it can't be moved. The receipt authority PDA is approved as
+//! delegate, letting the program burn it on confirmation, revert or cancel.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{
+    self, Approve, Burn, FreezeAccount, Mint, MintTo, ThawAccount, Token, TokenAccount,
+};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::{CircuitBreakerUtils, CorridorUtils, ReceiptUtils};
+
+/// Mint a frozen, program-burnable receipt to the transfer sender
+pub(crate) fn mint_transfer_receipt<'info>(
+    token_program: &Program<'info, Token>,
+    receipt_mint: &Account<'info, Mint>,
+    receipt_token_account: &Account<'info, TokenAccount>,
+    receipt_authority: &UncheckedAccount<'info>,
+    owner: &Signer<'info>,
+    receipt_authority_bump: u8,
+) -> Result<()> {
+    let authority_seeds = &[b"receipt_authority".as_ref(), &[receipt_authority_bump]];
+    let signer_seeds = &[&authority_seeds[..]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: receipt_mint.to_account_info(),
+                to: receipt_token_account.to_account_info(),
+                authority: receipt_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    token::approve(
+        CpiContext::new(
+            token_program.to_account_info(),
+            Approve {
+                to: receipt_token_account.to_account_info(),
+                delegate: receipt_authority.to_account_info(),
+                authority: owner.to_account_info(),
+            },
+        ),
+        1,
+    )?;
+
+    token::freeze_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        FreezeAccount {
+            account: receipt_token_account.to_account_info(),
+            mint: receipt_mint.to_account_info(),
+            authority: receipt_authority.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    Ok(())
+}
+
+/// Thaw and burn a transfer receipt
+pub(crate) fn burn_transfer_receipt<'info>(
+    token_program: &Program<'info, Token>,
+    receipt_mint: &Account<'info, Mint>,
+    receipt_token_account: &Account<'info, TokenAccount>,
+    receipt_authority: &UncheckedAccount<'info>,
+    receipt_authority_bump: u8,
+) -> Result<()> {
+    // Already burned, e.g. by an earlier confirmation
+    if receipt_token_account.amount == 0 {
+        return Ok(());
+    }
+
+    let authority_seeds = &[b"receipt_authority".as_ref(), &[receipt_authority_bump]];
+    let signer_seeds = &[&authority_seeds[..]];
+
+    token::thaw_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        ThawAccount {
+            account: receipt_token_account.to_account_info(),
+            mint: receipt_mint.to_account_info(),
+            authority: receipt_authority.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+
+    token::burn(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Burn {
+                mint: receipt_mint.to_account_info(),
+                from: receipt_token_account.to_account_info(),
+                authority: receipt_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    Ok(())
+}
+
+/// Confirm an outbound transfer arrived and burn its receipt (gateway authority only)
+pub fn confirm_outbound_transfer(ctx: Context<ConfirmOutboundTransfer>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    require!(
+        ctx.accounts.gateway_authority.key() == config.gateway_authority,
+        UniversalNftError::Unauthorized
+    );
+
+    let transfer = &mut ctx.accounts.transfer;
+    ReceiptUtils::require_in_flight(&transfer.status)?;
+    transfer.status = TransferStatus::Completed;
+    ctx.accounts.universal_nft.transfer_in_progress = false;
+    CorridorUtils::record_outbound(transfer, true, ctx.accounts.chain_metrics.as_deref())?;
+    CircuitBreakerUtils::record(&ctx.accounts.circuit_breaker, true)?;
+
+    burn_transfer_receipt(
+        &ctx.accounts.token_program,
+        &ctx.accounts.receipt_mint,
+        &ctx.accounts.receipt_token_account,
+        &ctx.accounts.receipt_authority,
+        ctx.bumps.receipt_authority,
+    )?;
+
+    emit!(TransferReceiptBurned {
+        transfer: transfer.key(),
+        receipt_mint: ctx.accounts.receipt_mint.key(),
+        status: TransferStatus::Completed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Outbound transfer confirmed");
+    log!("Transfer nonce: {}", transfer.nonce);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ConfirmOutboundTransfer<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(mut)]
+    pub transfer: Account<'info, CrossChainTransfer>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", transfer.nft_mint.as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(
+        mut,
+        seeds = [b"receipt_mint", transfer.key().as_ref()],
+        bump
+    )]
+    pub receipt_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = receipt_mint,
+    )]
+    pub receipt_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that mints, freezes and burns receipts
+    #[account(
+        seeds = [b"receipt_authority"],
+        bump
+    )]
+    pub receipt_authority: UncheckedAccount<'info>,
+
+    pub gateway_authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: Destination chain's metrics PDA the outcome is sampled into,
+    /// verified by CorridorUtils
+    #[account(mut)]
+    pub chain_metrics: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Destination chain's circuit breaker PDA, updated by CircuitBreakerUtils
+    #[account(
+        mut,
+        seeds = [b"circuit_breaker", transfer.destination_chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub circuit_breaker: UncheckedAccount<'info>,
+}
+
+/// Cancel a transfer that was never confirmed, using the receipt as proof of sender
+pub fn cancel_expired_transfer(ctx: Context<CancelExpiredTransfer>) -> Result<()> {
+    let transfer = &mut ctx.accounts.transfer;
+    let now = Clock::get()?.unix_timestamp;
+
+    ReceiptUtils::require_cancellable(transfer, ctx.accounts.receipt_token_account.amount, now)?;
+
+    transfer.status = TransferStatus::Cancelled;
+    ctx.accounts.universal_nft.is_locked = false;
+    ctx.accounts.universal_nft.transfer_in_progress = false;
+
+    burn_transfer_receipt(
+        &ctx.accounts.token_program,
+        &ctx.accounts.receipt_mint,
+        &ctx.accounts.receipt_token_account,
+        &ctx.accounts.receipt_authority,
+        ctx.bumps.receipt_authority,
+    )?;
+
+    emit!(TransferReceiptBurned {
+        transfer: transfer.key(),
+        receipt_mint: ctx.accounts.receipt_mint.key(),
+        status: TransferStatus::Cancelled,
+        timestamp: now,
+    });
+
+    log!("Expired transfer cancelled");
+    log!("Transfer nonce: {}", transfer.nonce);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelExpiredTransfer<'info> {
+    #[account(mut)]
+    pub transfer: Account<'info, CrossChainTransfer>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", transfer.nft_mint.as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(
+        mut,
+        seeds = [b"receipt_mint", transfer.key().as_ref()],
+        bump
+    )]
+    pub receipt_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = receipt_mint,
+        token::authority = holder,
+    )]
+    pub receipt_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that mints, freezes and burns receipts
+    #[account(
+        seeds = [b"receipt_authority"],
+        bump
+    )]
+    pub receipt_authority: UncheckedAccount<'info>,
+
+    pub holder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Set how NFTs arriving from a source chain are delivered to the signer:
+/// a preferred address to redirect them to, whether token accounts are
+/// created automatically, and how mints from untrusted senders are handled
+pub fn set_recipient_preferences(
+    ctx: Context<SetRecipientPreferences>,
+    source_chain_id: u64,
+    params: RecipientPreferencesParams,
+) -> Result<()> {
+    CrossChainUtils::validate_chain_id(source_chain_id, &ctx.accounts.source_chain)?;
+    require!(
+        params.trusted_senders.len() <= RecipientPreferences::MAX_TRUSTED_SENDERS,
+        UniversalNftError::InvalidRecipientPreferences
+    );
+    require!(
+        params.preferred_recipient != Some(Pubkey::default()),
+        UniversalNftError::InvalidRecipientPreferences
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let preferences = &mut ctx.accounts.recipient_preferences;
+    preferences.owner = ctx.accounts.owner.key();
+    preferences.source_chain_id = source_chain_id;
+    preferences.preferred_recipient = params.preferred_recipient;
+    preferences.auto_wrap = params.auto_wrap;
+    preferences.unsolicited = params.unsolicited;
+    preferences.trusted_senders = params.trusted_senders;
+    preferences.updated_at = now;
+    preferences.bump = ctx.bumps.recipient_preferences;
+
+    emit!(RecipientPreferencesUpdated {
+        owner: preferences.owner,
+        source_chain_id,
+        preferred_recipient: preferences.preferred_recipient,
+        auto_wrap: preferences.auto_wrap,
+        unsolicited: preferences.unsolicited,
+        timestamp: now,
+    });
+
+    log!("Recipient preferences set for chain {}", source_chain_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(source_chain_id: u64)]
+pub struct SetRecipientPreferences<'info> {
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + RecipientPreferences::INIT_SPACE,
+        seeds = [b"recipient_preferences", owner.key().as_ref(), source_chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub recipient_preferences: Account<'info, RecipientPreferences>,
+
+    /// Registry entry for the chain the preferences apply to
+    #[account(
+        seeds = [b"chain", source_chain_id.to_le_bytes().as_ref()],
+        bump = source_chain.bump
+    )]
+    pub source_chain: Account<'info, RegisteredChain>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Drop the signer's preferences for a source chain, restoring default
+/// delivery, and reclaim the account's rent
+pub fn close_recipient_preferences(ctx: Context<CloseRecipientPreferences>) -> Result<()> {
+    log!(
+        "Recipient preferences closed for chain {}",
+        ctx.accounts.recipient_preferences.source_chain_id
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseRecipientPreferences<'info> {
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            b"recipient_preferences",
+            owner.key().as_ref(),
+            recipient_preferences.source_chain_id.to_le_bytes().as_ref()
+        ],
+        bump = recipient_preferences.bump,
+        has_one = owner
+    )]
+    pub recipient_preferences: Account<'info, RecipientPreferences>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Open the signer's referral accrual PDA so mints and bridges can name it
+/// as referrer
+pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+    let accrual = &mut ctx.accounts.referral;
+    accrual.referrer = ctx.accounts.referrer.key();
+    accrual.accrued = 0;
+    accrual.claimed = 0;
+    accrual.referral_count = 0;
+    accrual.bump = ctx.bumps.referral;
+
+    log!("Referrer registered: {}", accrual.referrer);
+    Ok(())
+}
+
+/// Claim everything the signing referrer has accrued
+pub fn claim_referral_rewards(ctx: Context<ClaimReferralRewards>) -> Result<()> {
+    let accrual = &mut ctx.accounts.referral;
+    let amount = accrual.unclaimed();
+    require!(amount > 0, UniversalNftError::NoReferralRewards);
+    accrual.claimed = accrual.accrued;
+
+    **accrual.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.referrer.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    emit!(ReferralRewardsClaimed {
+        referrer: accrual.referrer,
+        amount,
+        lifetime_accrued: accrual.accrued,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Referral rewards claimed: {} lamports", amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(
+        init,
+        payer = referrer,
+        space = 8 + ReferrerAccrual::INIT_SPACE,
+        seeds = [b"referral", referrer.key().as_ref()],
+        bump
+    )]
+    pub referral: Account<'info, ReferrerAccrual>,
+
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimReferralRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"referral", referrer.key().as_ref()],
+        bump = referral.bump,
+        has_one = referrer
+    )]
+    pub referral: Account<'info, ReferrerAccrual>,
+
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+}
@@ -0,0 +1,611 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::utils::*;
+use crate::events::*;
+use crate::instructions::signature::pubkey_to_eth_address;
+
+/// Create the relayer registry (program authority only)
+pub fn initialize_relayer_registry(
+    ctx: Context<InitializeRelayerRegistry>,
+    min_stake: u64,
+    reward_per_message: u64,
+    slash_bps: u16,
+    unbonding_period: i64,
+) -> Result<()> {
+    require!(
+        min_stake > 0 && slash_bps > 0 && slash_bps <= RelayerUtils::MAX_SLASH_BPS && unbonding_period > 0,
+        UniversalNftError::InvalidRelayerParameters
+    );
+
+    let registry = &mut ctx.accounts.registry;
+    registry.authority = ctx.accounts.authority.key();
+    registry.min_stake = min_stake;
+    registry.reward_per_message = reward_per_message;
+    registry.slash_bps = slash_bps;
+    registry.unbonding_period = unbonding_period;
+    registry.total_staked = 0;
+    registry.total_slashed = 0;
+    registry.relayer_count = 0;
+    registry.bump = ctx.bumps.registry;
+
+    log!("Relayer registry initialized");
+    log!("Minimum stake: {} lamports", min_stake);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeRelayerRegistry<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RelayerRegistry::INIT_SPACE,
+        seeds = [b"relayer_registry"],
+        bump
+    )]
+    pub registry: Account<'info, RelayerRegistry>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Add lamports to the relayer reward pool
+pub fn fund_relayer_rewards(ctx: Context<FundRelayerRewards>, amount: u64) -> Result<()> {
+    require!(amount > 0, UniversalNftError::InvalidRelayerParameters);
+
+    let cpi_accounts = system_program::Transfer {
+        from: ctx.accounts.funder.to_account_info(),
+        to: ctx.accounts.registry.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, amount)?;
+
+    log!("Relayer reward pool funded: {} lamports", amount);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundRelayerRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"relayer_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RelayerRegistry>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Stake SOL to become a relayer
+pub fn register_relayer(ctx: Context<RegisterRelayer>, stake: u64) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    require!(stake >= registry.min_stake, UniversalNftError::InsufficientRelayerStake);
+
+    let cpi_accounts = system_program::Transfer {
+        from: ctx.accounts.authority.to_account_info(),
+        to: ctx.accounts.relayer.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, stake)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let relayer = &mut ctx.accounts.relayer;
+    relayer.authority = ctx.accounts.authority.key();
+    relayer.stake = stake;
+    relayer.messages_delivered = 0;
+    relayer.invalid_submissions = 0;
+    relayer.rewards_earned = 0;
+    relayer.active = true;
+    relayer.unbonding_at = 0;
+    relayer.registered_at = now;
+    relayer.bump = ctx.bumps.relayer;
+
+    registry.total_staked = registry.total_staked
+        .checked_add(stake)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+    registry.relayer_count = registry.relayer_count
+        .checked_add(1)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    emit!(RelayerStakeChanged {
+        relayer: relayer.authority,
+        stake,
+        active: true,
+        timestamp: now,
+    });
+
+    log!("Relayer registered with {} lamports stake", stake);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterRelayer<'info> {
+    #[account(
+        mut,
+        seeds = [b"relayer_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RelayerRegistry>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Relayer::INIT_SPACE,
+        seeds = [b"relayer", authority.key().as_ref()],
+        bump
+    )]
+    pub relayer: Account<'info, Relayer>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Add stake to a relayer. A relayer slashed below the minimum stake is
+/// reactivated once its stake is back at the minimum, unless it is unbonding.
+pub fn top_up_relayer_stake(ctx: Context<TopUpRelayerStake>, amount: u64) -> Result<()> {
+    require!(amount > 0, UniversalNftError::InvalidRelayerParameters);
+
+    let cpi_accounts = system_program::Transfer {
+        from: ctx.accounts.authority.to_account_info(),
+        to: ctx.accounts.relayer.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, amount)?;
+
+    let registry = &mut ctx.accounts.registry;
+    let relayer = &mut ctx.accounts.relayer;
+    relayer.stake = relayer.stake
+        .checked_add(amount)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+    if relayer.unbonding_at == 0 && relayer.stake >= registry.min_stake {
+        relayer.active = true;
+    }
+    registry.total_staked = registry.total_staked
+        .checked_add(amount)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    emit!(RelayerStakeChanged {
+        relayer: relayer.authority,
+        stake: relayer.stake,
+        active: relayer.active,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Relayer stake topped up to {} lamports", relayer.stake);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TopUpRelayerStake<'info> {
+    #[account(
+        mut,
+        seeds = [b"relayer_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RelayerRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"relayer", authority.key().as_ref()],
+        bump = relayer.bump,
+        has_one = authority
+    )]
+    pub relayer: Account<'info, Relayer>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Stop relaying and start the unbonding period
+pub fn begin_relayer_unbonding(ctx: Context<BeginRelayerUnbonding>) -> Result<()> {
+    let registry = &ctx.accounts.registry;
+    let relayer = &mut ctx.accounts.relayer;
+    require!(relayer.unbonding_at == 0, UniversalNftError::RelayerUnbonding);
+
+    let now = Clock::get()?.unix_timestamp;
+    relayer.active = false;
+    relayer.unbonding_at = now
+        .checked_add(registry.unbonding_period)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    emit!(RelayerStakeChanged {
+        relayer: relayer.authority,
+        stake: relayer.stake,
+        active: false,
+        timestamp: now,
+    });
+
+    log!("Relayer unbonding until {}", relayer.unbonding_at);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BeginRelayerUnbonding<'info> {
+    #[account(
+        seeds = [b"relayer_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RelayerRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"relayer", authority.key().as_ref()],
+        bump = relayer.bump,
+        has_one = authority
+    )]
+    pub relayer: Account<'info, Relayer>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Withdraw remaining stake after unbonding; closes the relayer account
+pub fn withdraw_relayer_stake(ctx: Context<WithdrawRelayerStake>) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let relayer = &ctx.accounts.relayer;
+
+    require!(relayer.unbonding_at != 0, UniversalNftError::RelayerNotUnbonding);
+    require!(
+        Clock::get()?.unix_timestamp >= relayer.unbonding_at,
+        UniversalNftError::RelayerNotUnbonding
+    );
+
+    registry.total_staked = registry.total_staked.saturating_sub(relayer.stake);
+    registry.relayer_count = registry.relayer_count.saturating_sub(1);
+
+    log!("Relayer stake withdrawn: {} lamports", relayer.stake);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawRelayerStake<'info> {
+    #[account(
+        mut,
+        seeds = [b"relayer_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RelayerRegistry>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"relayer", authority.key().as_ref()],
+        bump = relayer.bump,
+        has_one = authority
+    )]
+    pub relayer: Account<'info, Relayer>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+/// Create a source chain's inbound queue, starting at the chain's next
+/// unrelayed sequence (program authority only). Relayed submissions for the
+/// chain need the queue, and once it exists on_call executes only released
+/// messages.
+pub fn initialize_inbound_queue(
+    ctx: Context<InitializeInboundQueue>,
+    source_chain_id: u64,
+    start_sequence: u64,
+) -> Result<()> {
+    CrossChainUtils::validate_chain_id(source_chain_id, &ctx.accounts.source_chain)?;
+
+    let queue = &mut ctx.accounts.inbound_queue;
+    queue.source_chain_id = source_chain_id;
+    queue.next_sequence = start_sequence;
+    queue.pending_bitmap = 0;
+    queue.bump = ctx.bumps.inbound_queue;
+
+    log!("Inbound queue for chain {} starts at sequence {}", source_chain_id, start_sequence);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(source_chain_id: u64)]
+pub struct InitializeInboundQueue<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + InboundQueue::INIT_SPACE,
+        seeds = [b"inbound_queue", source_chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub inbound_queue: Account<'info, InboundQueue>,
+
+    /// Registry entry for the chain the messages come from
+    #[account(
+        seeds = [b"chain", source_chain_id.to_le_bytes().as_ref()],
+        bump = source_chain.bump
+    )]
+    pub source_chain: Account<'info, RegisteredChain>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Submit a TSS-signed inbound message. A valid signature records the message
+/// as delivered, and the relayer claims its reward once on_call executes it;
+/// an invalid one slashes its stake. The slash is committed, so this returns
+/// Ok in both cases.
+///
+/// Messages carry a per-source-chain sequence. Ones arriving ahead of the next
+/// expected sequence are buffered within the reorder window and released in
+/// order once the gap is filled. Once a chain has a queue, on_call executes
+/// only released messages, each once.
+pub fn submit_relayed_message(
+    ctx: Context<SubmitRelayedMessage>,
+    source_chain_id: u64,
+    sequence: u64,
+    sender: [u8; 20],
+    message: Vec<u8>,
+    signature: [u8; 64],
+    recovery_id: u8,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+
+    // Check if program is paused
+    require!(!config.is_paused, UniversalNftError::ProgramPaused);
+    require!(
+        config.tss_authority != Pubkey::default(),
+        UniversalNftError::InvalidTssSignature
+    );
+
+    let registry = &mut ctx.accounts.registry;
+    let relayer = &mut ctx.accounts.relayer;
+    require!(relayer.active, UniversalNftError::RelayerInactive);
+    require!(relayer.stake >= registry.min_stake, UniversalNftError::InsufficientRelayerStake);
+
+    CrossChainUtils::validate_chain_id(source_chain_id, &ctx.accounts.source_chain)?;
+    SignatureUtils::validate_message_format(&message)?;
+
+    let message_hash = RelayerUtils::relayed_message_hash(source_chain_id, sequence, &sender, &message);
+    let record = &mut ctx.accounts.relayed_message;
+    require!(!record.delivered, UniversalNftError::MessageAlreadyRelayed);
+
+    let now = Clock::get()?.unix_timestamp;
+    record.message_hash = message_hash;
+    record.relayer = relayer.authority;
+    record.source_chain_id = source_chain_id;
+    record.sequence = sequence;
+    record.submitted_at = now;
+    record.bump = ctx.bumps.relayed_message;
+
+    // A signature that fails to recover counts as invalid
+    let tss_eth_address = pubkey_to_eth_address(&config.tss_authority);
+    let signing_hash = DomainUtils::domain_message_hash(
+        &DomainUtils::inbound_domain(source_chain_id),
+        sequence,
+        &message_hash,
+    );
+    let is_valid = SignatureUtils::verify_ecdsa_signature(
+        &signing_hash,
+        &signature,
+        recovery_id,
+        &tss_eth_address,
+    )
+    .unwrap_or(false);
+
+    let relayer_info = relayer.to_account_info();
+    let registry_info = registry.to_account_info();
+
+    if !is_valid {
+        let slashed = RelayerUtils::slash_amount(relayer.stake, registry.slash_bps)?;
+
+        **relayer_info.try_borrow_mut_lamports()? -= slashed;
+        **registry_info.try_borrow_mut_lamports()? += slashed;
+
+        relayer.stake -= slashed;
+        relayer.invalid_submissions = relayer.invalid_submissions
+            .checked_add(1)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        if relayer.stake < registry.min_stake {
+            relayer.active = false;
+        }
+        registry.total_staked = registry.total_staked.saturating_sub(slashed);
+        registry.total_slashed = registry.total_slashed
+            .checked_add(slashed)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+        emit!(RelayerSlashed {
+            relayer: relayer.authority,
+            message_hash,
+            amount: slashed,
+            remaining_stake: relayer.stake,
+            timestamp: now,
+        });
+
+        log!("Invalid relayed message, relayer slashed {} lamports", slashed);
+        return Ok(());
+    }
+
+    // Enforce ordering before the message counts as delivered
+    let queue = &mut ctx.accounts.inbound_queue;
+    let released_from = queue.next_sequence;
+    let (next_sequence, pending_bitmap) =
+        SequenceUtils::accept(queue.next_sequence, queue.pending_bitmap, sequence)?;
+    queue.next_sequence = next_sequence;
+    queue.pending_bitmap = pending_bitmap;
+
+    if next_sequence > released_from {
+        emit!(InboundSequenceAdvanced {
+            source_chain_id,
+            released_from,
+            next_sequence,
+            timestamp: now,
+        });
+    } else {
+        log!("Sequence {} buffered until {} arrives", sequence, released_from);
+    }
+
+    record.delivered = true;
+
+    relayer.messages_delivered = relayer.messages_delivered
+        .checked_add(1)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    emit!(RelayedMessageDelivered {
+        relayer: relayer.authority,
+        message_hash,
+        source_chain_id,
+        timestamp: now,
+    });
+
+    log!("Relayed message delivered");
+    log!("Source chain: {}", source_chain_id);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(source_chain_id: u64, sequence: u64, sender: [u8; 20], message: Vec<u8>)]
+pub struct SubmitRelayedMessage<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"relayer_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RelayerRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"relayer", authority.key().as_ref()],
+        bump = relayer.bump,
+        has_one = authority
+    )]
+    pub relayer: Account<'info, Relayer>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RelayedMessage::INIT_SPACE,
+        seeds = [
+            b"relayed_message",
+            RelayerUtils::relayed_message_hash(source_chain_id, sequence, &sender, &message).as_ref()
+        ],
+        bump
+    )]
+    pub relayed_message: Account<'info, RelayedMessage>,
+
+    /// Queue the authority created with initialize_inbound_queue
+    #[account(
+        mut,
+        seeds = [b"inbound_queue", source_chain_id.to_le_bytes().as_ref()],
+        bump = inbound_queue.bump
+    )]
+    pub inbound_queue: Account<'info, InboundQueue>,
+
+    /// Registry entry for the chain the messages come from
+    #[account(
+        seeds = [b"chain", source_chain_id.to_le_bytes().as_ref()],
+        bump = source_chain.bump
+    )]
+    pub source_chain: Account<'info, RegisteredChain>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Pay the relayer of a delivered message once on_call has executed it.
+/// Deliveries that never execute earn nothing.
+pub fn claim_relayer_reward(ctx: Context<ClaimRelayerReward>) -> Result<()> {
+    let record = &mut ctx.accounts.relayed_message;
+    require!(record.executed, UniversalNftError::RelayedMessageNotExecuted);
+    require!(!record.rewarded, UniversalNftError::RelayerRewardClaimed);
+    record.rewarded = true;
+
+    // Pay what the pool can afford while keeping it rent exempt
+    let registry_info = ctx.accounts.registry.to_account_info();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(registry_info.data_len());
+    let available = registry_info.lamports().saturating_sub(rent_exempt_minimum);
+    let reward = ctx.accounts.registry.reward_per_message.min(available);
+    if reward > 0 {
+        **registry_info.try_borrow_mut_lamports()? -= reward;
+        **ctx.accounts.authority.to_account_info().try_borrow_mut_lamports()? += reward;
+    }
+
+    let relayer = &mut ctx.accounts.relayer;
+    relayer.rewards_earned = relayer.rewards_earned
+        .checked_add(reward)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    emit!(RelayerRewardPaid {
+        relayer: relayer.authority,
+        message_hash: record.message_hash,
+        reward,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Relayer reward: {} lamports", reward);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimRelayerReward<'info> {
+    #[account(
+        mut,
+        seeds = [b"relayer_registry"],
+        bump = registry.bump
+    )]
+    pub registry: Account<'info, RelayerRegistry>,
+
+    #[account(
+        mut,
+        seeds = [b"relayer", authority.key().as_ref()],
+        bump = relayer.bump,
+        has_one = authority
+    )]
+    pub relayer: Account<'info, Relayer>,
+
+    #[account(
+        mut,
+        seeds = [b"relayed_message", relayed_message.message_hash.as_ref()],
+        bump = relayed_message.bump,
+        constraint = relayed_message.relayer == authority.key() @ UniversalNftError::Unauthorized
+    )]
+    pub relayed_message: Account<'info, RelayedMessage>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
\ No newline at end of file
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::analytics::AuditLog;
+use crate::state::*;
+
+/// Create the audit log automated remediations are recorded in (program
+/// authority only)
+pub fn initialize_audit_log(ctx: Context<InitializeAuditLog>) -> Result<()> {
+    let audit_log = &mut ctx.accounts.audit_log;
+    audit_log.next_id = 0;
+    audit_log.entries = Vec::new();
+    audit_log.bump = ctx.bumps.audit_log;
+
+    log!("Remediation audit log initialized");
+    Ok(())
+}
+
+/// Undo an automated remediation, restoring the state recorded in its audit
+/// entry (program authority only). Chain actions need the targeted chain.
+pub fn revert_remediation(ctx: Context<RevertRemediation>, entry_id: u64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.audit_log.revert(
+        entry_id,
+        Some(&mut ctx.accounts.config),
+        ctx.accounts.chain.as_deref_mut(),
+        now,
+    )
+}
+
+#[derive(Accounts)]
+pub struct InitializeAuditLog<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AuditLog::INIT_SPACE,
+        seeds = [b"audit_log"],
+        bump
+    )]
+    pub audit_log: Account<'info, AuditLog>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevertRemediation<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"audit_log"],
+        bump = audit_log.bump
+    )]
+    pub audit_log: Account<'info, AuditLog>,
+
+    #[account(
+        mut,
+        seeds = [b"chain", chain.chain_id.to_le_bytes().as_ref()],
+        bump = chain.bump
+    )]
+    pub chain: Option<Account<'info, RegisteredChain>>,
+
+    pub authority: Signer<'info>,
+}
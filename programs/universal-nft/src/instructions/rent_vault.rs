@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Create the rent vault that sponsors inbound account rent (program authority only)
+pub fn initialize_rent_vault(ctx: Context<InitializeRentVault>) -> Result<()> {
+    let vault = &mut ctx.accounts.rent_vault;
+    vault.enabled = true;
+    vault.total_funded = 0;
+    vault.total_sponsored = 0;
+    vault.bump = ctx.bumps.rent_vault;
+
+    log!("Rent vault initialized: {}", vault.key());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeRentVault<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RentVault::INIT_SPACE,
+        seeds = [b"rent_vault"],
+        bump
+    )]
+    pub rent_vault: Account<'info, RentVault>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Deposit lamports that sponsor rent for one source chain's inbound mints.
+/// Chains can only draw what was deposited for them.
+pub fn fund_rent_vault(ctx: Context<FundRentVault>, chain_id: u64, amount: u64) -> Result<()> {
+    CrossChainUtils::validate_chain_id(chain_id, &ctx.accounts.chain)?;
+    require!(amount > 0, UniversalNftError::InvalidRentFunding);
+
+    let cpi_accounts = system_program::Transfer {
+        from: ctx.accounts.funder.to_account_info(),
+        to: ctx.accounts.rent_vault.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, amount)?;
+
+    let ledger = &mut ctx.accounts.rent_ledger;
+    if ledger.funded_lamports == 0 {
+        ledger.chain_id = chain_id;
+        ledger.bump = ctx.bumps.rent_ledger;
+    }
+    ledger.funded_lamports = ledger.funded_lamports
+        .checked_add(amount)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    let vault = &mut ctx.accounts.rent_vault;
+    vault.total_funded = vault.total_funded
+        .checked_add(amount)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    emit!(RentVaultFunded {
+        chain_id,
+        funder: ctx.accounts.funder.key(),
+        amount,
+        chain_remaining: ledger.remaining(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Rent vault funded for chain {}: {} lamports", chain_id, amount);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64)]
+pub struct FundRentVault<'info> {
+    #[account(
+        mut,
+        seeds = [b"rent_vault"],
+        bump = rent_vault.bump
+    )]
+    pub rent_vault: Account<'info, RentVault>,
+
+    #[account(
+        init_if_needed,
+        payer = funder,
+        space = 8 + ChainRentLedger::INIT_SPACE,
+        seeds = [b"rent_ledger", chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub rent_ledger: Account<'info, ChainRentLedger>,
+
+    /// Registry entry for the chain the deposit sponsors
+    #[account(
+        seeds = [b"chain", chain_id.to_le_bytes().as_ref()],
+        bump = chain.bump
+    )]
+    pub chain: Account<'info, RegisteredChain>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Turn rent sponsorship on or off without touching deposited funds
+pub fn set_rent_sponsorship(ctx: Context<SetRentSponsorship>, enabled: bool) -> Result<()> {
+    ctx.accounts.rent_vault.enabled = enabled;
+
+    log!("Rent sponsorship enabled: {}", enabled);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRentSponsorship<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"rent_vault"],
+        bump = rent_vault.bump
+    )]
+    pub rent_vault: Account<'info, RentVault>,
+
+    pub authority: Signer<'info>,
+}
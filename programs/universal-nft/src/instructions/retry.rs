@@ -0,0 +1,210 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::recovery::transaction_retry::{
+    RetryConfig, RetryFailureReason, RetryOutcomeReport, RetryQueue, RetrySession, TransactionRetryManager,
+};
+
+/// Create the retry manager that schedules and tracks retry sessions
+/// (program authority only). `None` takes the default backoff.
+pub fn initialize_retry_manager(ctx: Context<InitializeRetryManager>, config: Option<RetryConfig>) -> Result<()> {
+    let config = config.unwrap_or_default();
+    require!(config.max_attempts > 0, UniversalNftError::InvalidRetrySession);
+
+    ctx.accounts.retry_manager.initialize(
+        ctx.accounts.authority.key(),
+        config,
+        ctx.bumps.retry_manager,
+    )
+}
+
+#[derive(Accounts)]
+pub struct InitializeRetryManager<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + TransactionRetryManager::INIT_SPACE,
+        seeds = [b"retry_manager"],
+        bump
+    )]
+    pub retry_manager: Account<'info, TransactionRetryManager>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Schedule a failed transaction for retry (registered retrier only). The
+/// session joins the retry queue, due once the config's initial delay has
+/// passed; `None` takes the manager's default config.
+pub fn schedule_retry_session(
+    ctx: Context<ScheduleRetrySession>,
+    original_tx_signature: String,
+    failure_reason: RetryFailureReason,
+    compute_unit_limit: u32,
+    priority_fee_micro_lamports: u64,
+    config: Option<RetryConfig>,
+) -> Result<()> {
+    require!(
+        !original_tx_signature.is_empty()
+            && original_tx_signature.len() <= RetrySession::MAX_SIGNATURE_LEN
+            && compute_unit_limit > 0
+            && compute_unit_limit <= RetrySession::MAX_COMPUTE_UNIT_LIMIT
+            && config.as_ref().is_none_or(|c| c.max_attempts > 0),
+        UniversalNftError::InvalidRetrySession
+    );
+
+    let accounts = &mut *ctx.accounts;
+    let session_key = accounts.retry_session.key();
+    let manager_key = accounts.retry_manager.key();
+    let session_id = accounts.retry_manager.next_session_id;
+    accounts.retry_manager.schedule_retry(
+        &mut accounts.retry_session,
+        session_key,
+        manager_key,
+        &mut accounts.retry_queue,
+        session_id,
+        original_tx_signature,
+        failure_reason,
+        compute_unit_limit,
+        priority_fee_micro_lamports,
+        config,
+    )?;
+    accounts.retry_manager.next_session_id = session_id
+        .checked_add(1)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    let session = &mut accounts.retry_session;
+    session.bump = ctx.bumps.retry_session;
+
+    emit!(RetrySessionScheduled {
+        session: session_key,
+        session_id,
+        original_tx_signature: session.original_tx_signature.clone(),
+        next_retry_at: session.next_retry_at,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ScheduleRetrySession<'info> {
+    #[account(
+        mut,
+        seeds = [b"retry_manager"],
+        bump = retry_manager.bump,
+        has_one = reporter @ UniversalNftError::Unauthorized
+    )]
+    pub retry_manager: Account<'info, TransactionRetryManager>,
+
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + RetrySession::INIT_SPACE,
+        seeds = [b"retry_session", retry_manager.next_session_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub retry_session: Account<'info, RetrySession>,
+
+    #[account(
+        mut,
+        seeds = [b"retry_queue"],
+        bump = retry_queue.bump
+    )]
+    pub retry_queue: Account<'info, RetryQueue>,
+
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Set the off-chain retrier allowed to report attempt outcomes (retry
+/// manager authority only)
+pub fn set_retry_reporter(ctx: Context<SetRetryReporter>, reporter: Pubkey) -> Result<()> {
+    ctx.accounts.retry_manager.reporter = reporter;
+
+    log!("Retry reporter set: {}", reporter);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRetryReporter<'info> {
+    #[account(
+        mut,
+        seeds = [b"retry_manager"],
+        bump = retry_manager.bump,
+        has_one = authority
+    )]
+    pub retry_manager: Account<'info, TransactionRetryManager>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Record how a resubmitted retry attempt went (registered retrier only). A
+/// failure reschedules the session with adjusted compute and fees until it
+/// runs out of attempts.
+pub fn report_retry_outcome(ctx: Context<ReportRetryOutcome>, report: RetryOutcomeReport) -> Result<()> {
+    let accounts = &mut *ctx.accounts;
+    let session_key = accounts.retry_session.key();
+    accounts.retry_manager.record_retry_outcome(
+        &mut accounts.retry_session,
+        session_key,
+        &mut accounts.retry_queue,
+        &report,
+    )?;
+
+    let session = &accounts.retry_session;
+    emit!(RetryOutcomeReported {
+        session: session_key,
+        attempt: report.attempt,
+        result: report.result,
+        tx_signature: report.tx_signature,
+        failure_reason: report.failure_reason,
+        status: session.status.clone(),
+        next_retry_at: session.next_retry_at,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Retry session {} attempt {} reported", session.session_id, report.attempt);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReportRetryOutcome<'info> {
+    #[account(
+        mut,
+        seeds = [b"retry_manager"],
+        bump = retry_manager.bump,
+        has_one = reporter @ UniversalNftError::Unauthorized
+    )]
+    pub retry_manager: Account<'info, TransactionRetryManager>,
+
+    #[account(
+        mut,
+        seeds = [b"retry_session", retry_session.session_id.to_le_bytes().as_ref()],
+        bump = retry_session.bump,
+        has_one = retry_manager
+    )]
+    pub retry_session: Account<'info, RetrySession>,
+
+    #[account(
+        mut,
+        seeds = [b"retry_queue"],
+        bump = retry_queue.bump
+    )]
+    pub retry_queue: Account<'info, RetryQueue>,
+
+    pub reporter: Signer<'info>,
+}
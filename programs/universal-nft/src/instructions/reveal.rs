@@ -0,0 +1,286 @@
+use anchor_lang::prelude::*;
+use mpl_token_metadata::{
+    instructions::{UpdateMetadataAccountV2, UpdateMetadataAccountV2InstructionArgs},
+    types::DataV2,
+};
+use solana_program::program::invoke_signed;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Commit a collection's metadata list and the Switchboard randomness that
+/// will shuffle it (collection authority only). The randomness must be
+/// committed in the same transaction, seeded by the previous slot, so the
+/// list is fixed before anyone can know the value. A collection commits once.
+pub fn commit_reveal(
+    ctx: Context<CommitReveal>,
+    list_root: [u8; 32],
+    item_count: u32,
+) -> Result<()> {
+    require!(
+        item_count > 0 && item_count <= RevealConfig::MAX_ITEMS,
+        UniversalNftError::InvalidRevealList
+    );
+    // Every NFT the collection can mint needs an entry
+    require!(
+        ctx.accounts.collection_config.max_supply > 0
+            && ctx.accounts.collection_config.max_supply <= item_count as u64,
+        UniversalNftError::InvalidRevealList
+    );
+
+    let clock = Clock::get()?;
+    let randomness = RevealUtils::read_randomness(&ctx.accounts.randomness_account)?;
+    require!(
+        randomness.seed_slot == clock.slot.saturating_sub(1) && randomness.reveal_slot == 0,
+        UniversalNftError::InvalidRandomnessAccount
+    );
+
+    let reveal = &mut ctx.accounts.reveal_config;
+    reveal.collection_mint = ctx.accounts.collection.mint;
+    reveal.list_root = list_root;
+    reveal.item_count = item_count;
+    reveal.randomness_account = ctx.accounts.randomness_account.key();
+    reveal.seed_slot = randomness.seed_slot;
+    reveal.randomness = None;
+    reveal.reveal_slot = 0;
+    reveal.revealed_count = 0;
+    reveal.assigned = vec![0u8; item_count.div_ceil(8) as usize];
+    reveal.bump = ctx.bumps.reveal_config;
+
+    emit!(RevealCommitted {
+        collection_mint: reveal.collection_mint,
+        list_root,
+        item_count,
+        randomness_account: reveal.randomness_account,
+        seed_slot: reveal.seed_slot,
+        timestamp: clock.unix_timestamp,
+    });
+
+    log!("Reveal committed for collection {}", reveal.collection_mint);
+    Ok(())
+}
+
+/// Record the oracle's randomness for a collection once its minting has
+/// closed. Anyone may call this.
+pub fn reveal_collection(ctx: Context<RevealCollection>) -> Result<()> {
+    let collection_config = &ctx.accounts.collection_config;
+    require!(
+        collection_config.minted_count == collection_config.max_supply,
+        UniversalNftError::RevealMintingOpen
+    );
+
+    let reveal = &mut ctx.accounts.reveal_config;
+    require!(reveal.randomness.is_none(), UniversalNftError::CollectionAlreadyRevealed);
+    let randomness = RevealUtils::read_randomness(&ctx.accounts.randomness_account)?;
+    require!(
+        randomness.seed_slot == reveal.seed_slot,
+        UniversalNftError::InvalidRandomnessAccount
+    );
+    require!(
+        randomness.reveal_slot > randomness.seed_slot,
+        UniversalNftError::RandomnessNotResolved
+    );
+
+    reveal.randomness = Some(randomness.value);
+    reveal.reveal_slot = randomness.reveal_slot;
+
+    emit!(CollectionRevealed {
+        collection_mint: reveal.collection_mint,
+        randomness: randomness.value,
+        seed_slot: randomness.seed_slot,
+        reveal_slot: randomness.reveal_slot,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Collection revealed: {}", reveal.collection_mint);
+    Ok(())
+}
+
+/// Assign an NFT its list entry and write the entry's URI into its metadata.
+/// The randomness and mint pick the entry, so anyone may call this with the
+/// entry's proof.
+pub fn reveal_nft(ctx: Context<RevealNft>, uri: String, proof: Vec<[u8; 32]>) -> Result<()> {
+    require!(!ctx.accounts.config.is_paused, UniversalNftError::ProgramPaused);
+    validate::metadata_pda(&ctx.accounts.metadata.key(), &ctx.accounts.mint.key())?;
+    MetadataUtils::validate_uri(&uri)?;
+
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    universal_nft.require_no_transfer_in_progress()?;
+
+    let reveal = &mut ctx.accounts.reveal_config;
+    let randomness = reveal.randomness.ok_or(UniversalNftError::CollectionNotRevealed)?;
+    let target_index = RevealUtils::target_index(&randomness, &universal_nft.mint, reveal.item_count);
+    let item_index = reveal.assign(target_index)?;
+    require!(
+        RevealUtils::verify_item(&reveal.list_root, item_index, &uri, &proof),
+        UniversalNftError::InvalidRevealProof
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let revealed = &mut ctx.accounts.revealed_nft;
+    revealed.mint = universal_nft.mint;
+    revealed.collection_mint = reveal.collection_mint;
+    revealed.target_index = target_index;
+    revealed.item_index = item_index;
+    revealed.revealed_at = now;
+    revealed.bump = ctx.bumps.revealed_nft;
+
+    universal_nft.uri = uri.clone();
+
+    // Point the token metadata at the revealed entry
+    let mint_key = ctx.accounts.mint.key();
+    let metadata_seeds = &[b"universal_nft".as_ref(), mint_key.as_ref(), &[universal_nft.bump]];
+    let signer_seeds = &[&metadata_seeds[..]];
+    let data = DataV2 {
+        name: universal_nft.name.clone(),
+        symbol: universal_nft.symbol.clone(),
+        uri: uri.clone(),
+        seller_fee_basis_points: ctx.accounts.collection_config.seller_fee_basis_points,
+        creators: None,
+        collection: universal_nft.collection_mint.map(|mint| {
+            mpl_token_metadata::types::Collection {
+                verified: false,
+                key: mint,
+            }
+        }),
+        uses: None,
+    };
+    let update_metadata_ix = UpdateMetadataAccountV2 {
+        metadata: ctx.accounts.metadata.key(),
+        update_authority: universal_nft.key(),
+    };
+    invoke_signed(
+        &update_metadata_ix.instruction(UpdateMetadataAccountV2InstructionArgs {
+            data: Some(data),
+            new_update_authority: None,
+            primary_sale_happened: None,
+            is_mutable: None,
+        }),
+        &[
+            ctx.accounts.metadata.to_account_info(),
+            universal_nft.to_account_info(),
+        ],
+        signer_seeds,
+    )?;
+
+    emit!(NftRevealed {
+        mint: revealed.mint,
+        collection_mint: revealed.collection_mint,
+        item_index,
+        uri,
+        timestamp: now,
+    });
+
+    log!("NFT revealed as entry {}", item_index);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CommitReveal<'info> {
+    #[account(
+        seeds = [b"collection", collection.mint.as_ref()],
+        bump = collection.bump,
+        has_one = authority
+    )]
+    pub collection: Account<'info, UniversalCollection>,
+
+    #[account(
+        seeds = [b"collection_config", collection.mint.as_ref()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RevealConfig::INIT_SPACE,
+        seeds = [b"reveal_config", collection.mint.as_ref()],
+        bump
+    )]
+    pub reveal_config: Account<'info, RevealConfig>,
+
+    /// CHECK: Switchboard randomness account, read by RevealUtils
+    pub randomness_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevealCollection<'info> {
+    #[account(
+        seeds = [b"collection_config", reveal_config.collection_mint.as_ref()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reveal_config", reveal_config.collection_mint.as_ref()],
+        bump = reveal_config.bump,
+        has_one = randomness_account
+    )]
+    pub reveal_config: Account<'info, RevealConfig>,
+
+    /// CHECK: The committed Switchboard randomness account, read by RevealUtils
+    pub randomness_account: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RevealNft<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"collection_config", reveal_config.collection_mint.as_ref()],
+        bump = collection_config.bump
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"reveal_config", reveal_config.collection_mint.as_ref()],
+        bump = reveal_config.bump
+    )]
+    pub reveal_config: Account<'info, RevealConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", mint.key().as_ref()],
+        bump = universal_nft.bump,
+        constraint = universal_nft.collection_mint == Some(reveal_config.collection_mint)
+            @ UniversalNftError::CollectionVerificationFailed
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    pub mint: Account<'info, anchor_spl::token::Mint>,
+
+    /// CHECK: Metadata PDA, checked by validate::metadata_pda
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + RevealedNft::INIT_SPACE,
+        seeds = [b"revealed_nft", mint.key().as_ref()],
+        bump
+    )]
+    pub revealed_nft: Account<'info, RevealedNft>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Token Metadata program
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Set how a collection's royalties are split among its creators (collection
+/// authority only). Applies to deposits made from now on.
+pub fn set_royalty_config(ctx: Context<SetRoyaltyConfig>, shares: Vec<RoyaltyShare>) -> Result<()> {
+    RoyaltyUtils::validate_shares(&shares)?;
+
+    let royalty_config = &mut ctx.accounts.royalty_config;
+    royalty_config.collection_mint = ctx.accounts.collection.mint;
+    royalty_config.shares = shares;
+    royalty_config.bump = ctx.bumps.royalty_config;
+
+    emit!(RoyaltyConfigUpdated {
+        collection_mint: royalty_config.collection_mint,
+        shares: royalty_config.shares.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Royalty config updated: {}", royalty_config.collection_mint);
+    log!("Creators: {}", royalty_config.shares.len());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRoyaltyConfig<'info> {
+    #[account(
+        seeds = [b"collection", collection.mint.as_ref()],
+        bump = collection.bump,
+        has_one = authority
+    )]
+    pub collection: Account<'info, UniversalCollection>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RoyaltyConfig::INIT_SPACE,
+        seeds = [b"royalty_config", collection.mint.as_ref()],
+        bump
+    )]
+    pub royalty_config: Account<'info, RoyaltyConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Deposit royalties from a secondary sale into the collection's escrow.
+/// Called by marketplaces on any venue; the amount is credited to the
+/// creators per the collection's royalty config.
+pub fn deposit_royalty(ctx: Context<DepositRoyalty>, amount: u64) -> Result<()> {
+    require!(amount > 0, UniversalNftError::InvalidRoyaltyAmount);
+
+    let cpi_accounts = system_program::Transfer {
+        from: ctx.accounts.depositor.to_account_info(),
+        to: ctx.accounts.royalty_escrow.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, amount)?;
+
+    let royalty_config = &ctx.accounts.royalty_config;
+    let escrow = &mut ctx.accounts.royalty_escrow;
+    escrow.collection_mint = royalty_config.collection_mint;
+    escrow.bump = ctx.bumps.royalty_escrow;
+    RoyaltyUtils::credit(escrow, &royalty_config.shares, amount)?;
+
+    emit!(RoyaltyDeposited {
+        collection_mint: escrow.collection_mint,
+        depositor: ctx.accounts.depositor.key(),
+        amount,
+        total_deposited: escrow.total_deposited,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Royalty deposited: {} lamports", amount);
+    log!("Collection: {}", escrow.collection_mint);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DepositRoyalty<'info> {
+    #[account(
+        seeds = [b"royalty_config", royalty_config.collection_mint.as_ref()],
+        bump = royalty_config.bump
+    )]
+    pub royalty_config: Account<'info, RoyaltyConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        space = 8 + RoyaltyEscrow::INIT_SPACE,
+        seeds = [b"royalty_escrow", royalty_config.collection_mint.as_ref()],
+        bump
+    )]
+    pub royalty_escrow: Account<'info, RoyaltyEscrow>,
+
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Claim everything a creator has earned from a collection's escrow
+pub fn claim_royalties(ctx: Context<ClaimRoyalties>) -> Result<()> {
+    let creator = ctx.accounts.creator.key();
+    let escrow = &mut ctx.accounts.royalty_escrow;
+
+    let entry = escrow.ledger
+        .iter_mut()
+        .find(|entry| entry.creator == creator)
+        .ok_or(UniversalNftError::NoRoyaltiesToClaim)?;
+    let amount = entry.unclaimed();
+    require!(amount > 0, UniversalNftError::NoRoyaltiesToClaim);
+    entry.claimed = entry.earned;
+    let lifetime_earned = entry.earned;
+
+    escrow.total_claimed = escrow.total_claimed
+        .checked_add(amount)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    **escrow.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.creator.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    emit!(RoyaltiesClaimed {
+        collection_mint: escrow.collection_mint,
+        creator,
+        amount,
+        lifetime_earned,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Royalties claimed: {} lamports", amount);
+    log!("Creator: {}", creator);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimRoyalties<'info> {
+    #[account(
+        mut,
+        seeds = [b"royalty_escrow", royalty_escrow.collection_mint.as_ref()],
+        bump = royalty_escrow.bump
+    )]
+    pub royalty_escrow: Account<'info, RoyaltyEscrow>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+/// Return a collection's lifetime royalty accounting. Programs read the
+/// result with `get_return_data` after the CPI.
+pub fn get_royalty_accounting(ctx: Context<GetRoyaltyAccounting>) -> Result<RoyaltyAccountingView> {
+    let escrow = &ctx.accounts.royalty_escrow;
+
+    Ok(RoyaltyAccountingView {
+        collection_mint: escrow.collection_mint,
+        total_deposited: escrow.total_deposited,
+        total_claimed: escrow.total_claimed,
+        deposit_count: escrow.deposit_count,
+        creators: escrow.ledger.clone(),
+    })
+}
+
+#[derive(Accounts)]
+pub struct GetRoyaltyAccounting<'info> {
+    #[account(
+        seeds = [b"royalty_escrow", royalty_escrow.collection_mint.as_ref()],
+        bump = royalty_escrow.bump
+    )]
+    pub royalty_escrow: Account<'info, RoyaltyEscrow>,
+}
@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Lock an NFT and schedule its outbound transfer after a review delay.
+/// The owner executes it through burn_and_transfer once the delay elapses.
+pub fn schedule_transfer(
+    ctx: Context<ScheduleTransfer>,
+    destination_chain_id: u64,
+    recipient: Vec<u8>,
+    gas_limit: u64,
+    delay_seconds: i64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+
+    // Check if program is paused
+    require!(!config.is_paused, UniversalNftError::ProgramPaused);
+
+    // Validate parameters
+    CrossChainUtils::validate_chain_id(destination_chain_id, &ctx.accounts.destination_chain)?;
+    ctx.accounts.destination_chain.require_valid_recipient(destination_chain_id, &recipient)?;
+    CrossChainUtils::validate_gas_limit(gas_limit)?;
+    let now = Clock::get()?.unix_timestamp;
+    let execute_after = ScheduledTransfer::execute_after(now, delay_seconds)?;
+
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    universal_nft.require_no_transfer_in_progress()?;
+    require!(!universal_nft.is_locked, UniversalNftError::NftLocked);
+    require!(!universal_nft.is_disputed, UniversalNftError::NftUnderDispute);
+    require!(!universal_nft.is_frozen, UniversalNftError::NftFrozen);
+    require!(
+        universal_nft.owner == ctx.accounts.owner.key(),
+        UniversalNftError::InvalidNftOwner
+    );
+
+    // The owner's own transfer lock
+    WalletLockUtils::require_unlocked(&ctx.accounts.wallet_lock)?;
+
+    // Lock the NFT for the review window
+    universal_nft.is_locked = true;
+
+    let scheduled = &mut ctx.accounts.scheduled_transfer;
+    scheduled.owner = ctx.accounts.owner.key();
+    scheduled.mint = universal_nft.mint;
+    scheduled.destination_chain_id = destination_chain_id;
+    scheduled.recipient = recipient;
+    scheduled.gas_limit = gas_limit;
+    scheduled.execute_after = execute_after;
+    scheduled.created_at = now;
+    scheduled.bump = ctx.bumps.scheduled_transfer;
+
+    emit!(TransferScheduled {
+        mint: scheduled.mint,
+        owner: scheduled.owner,
+        destination_chain_id,
+        execute_after,
+        timestamp: now,
+    });
+
+    log!("Transfer scheduled");
+    log!("Token ID: {}", universal_nft.origin_token_id);
+    log!("Executes after: {}", execute_after);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(destination_chain_id: u64)]
+pub struct ScheduleTransfer<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Registry entry for the destination chain
+    #[account(
+        seeds = [b"chain", destination_chain_id.to_le_bytes().as_ref()],
+        bump = destination_chain.bump
+    )]
+    pub destination_chain: Account<'info, RegisteredChain>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", mint.key().as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + ScheduledTransfer::INIT_SPACE,
+        seeds = [b"scheduled_transfer", mint.key().as_ref()],
+        bump
+    )]
+    pub scheduled_transfer: Account<'info, ScheduledTransfer>,
+
+    pub mint: Account<'info, anchor_spl::token::Mint>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Owner's wallet lock PDA, read by WalletLockUtils
+    #[account(
+        seeds = [b"wallet_lock", owner.key().as_ref()],
+        bump
+    )]
+    pub wallet_lock: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Cancel a scheduled transfer during its review window, unlocking the NFT
+pub fn cancel_scheduled_transfer(ctx: Context<CancelScheduledTransfer>) -> Result<()> {
+    let scheduled = &ctx.accounts.scheduled_transfer;
+    let now = Clock::get()?.unix_timestamp;
+
+    scheduled.require_cancellable(now)?;
+
+    ctx.accounts.universal_nft.is_locked = false;
+
+    emit!(ScheduledTransferCancelled {
+        mint: scheduled.mint,
+        owner: scheduled.owner,
+        timestamp: now,
+    });
+
+    log!("Scheduled transfer cancelled");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelScheduledTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [b"universal_nft", mint.key().as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"scheduled_transfer", mint.key().as_ref()],
+        bump = scheduled_transfer.bump,
+        has_one = owner,
+        has_one = mint
+    )]
+    pub scheduled_transfer: Account<'info, ScheduledTransfer>,
+
+    pub mint: Account<'info, anchor_spl::token::Mint>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use solana_program::program::invoke;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::MessageCodec;
+use crate::instructions::cross_chain::create_gateway_call_instruction;
+
+/// Set the ZetaChain monitoring contract critical security events are sent
+/// to, and the keeper allowed to report them (program authority only)
+pub fn configure_security_export(
+    ctx: Context<ConfigureSecurityExport>,
+    params: SecurityExportParams,
+) -> Result<()> {
+    require!(
+        SecurityExport::MONITOR_CHAINS.contains(&params.monitor_chain_id)
+            && params.monitor_contract != [0u8; 20],
+        UniversalNftError::InvalidSecurityMonitor
+    );
+
+    let export = &mut ctx.accounts.security_export;
+    export.monitor_chain_id = params.monitor_chain_id;
+    export.monitor_contract = params.monitor_contract;
+    export.reporter = params.reporter;
+    export.bump = ctx.bumps.security_export;
+
+    emit!(SecurityExportConfigured {
+        monitor_chain_id: export.monitor_chain_id,
+        monitor_contract: export.monitor_contract,
+        reporter: export.reporter,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Security export configured: chain {}", export.monitor_chain_id);
+    Ok(())
+}
+
+/// Send a critical security event to the monitoring contract (reporter or
+/// program authority only). Events are numbered so the contract can detect
+/// gaps and replays.
+pub fn export_security_event(
+    ctx: Context<ExportSecurityEvent>,
+    kind: SecurityEventKind,
+    chain_id: u64,
+    value: u64,
+    occurred_at: i64,
+) -> Result<()> {
+    let reporter = ctx.accounts.reporter.key();
+    let export = &mut ctx.accounts.security_export;
+    require!(
+        export.can_report(&reporter, &ctx.accounts.config.authority),
+        UniversalNftError::Unauthorized
+    );
+
+    let sequence = export.next_sequence;
+    let message = CrossChainMessage::SecurityEvent {
+        sequence,
+        kind,
+        chain_id,
+        value,
+        occurred_at,
+    };
+    let gateway_call_ix = create_gateway_call_instruction(
+        ctx.accounts.gateway_program.key(),
+        export.monitor_chain_id,
+        export.monitor_contract.to_vec(),
+        MessageCodec::encode(&message)?,
+        SecurityExport::GAS_LIMIT,
+    )?;
+    invoke(
+        &gateway_call_ix,
+        &[
+            ctx.accounts.gateway_program.to_account_info(),
+            ctx.accounts.reporter.to_account_info(),
+        ],
+    )?;
+    export.next_sequence = sequence
+        .checked_add(1)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    emit!(SecurityEventExported {
+        sequence,
+        kind,
+        chain_id,
+        value,
+        occurred_at,
+        reporter,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Security event {} exported: {:?}", sequence, kind);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ConfigureSecurityExport<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + SecurityExport::INIT_SPACE,
+        seeds = [b"security_export"],
+        bump
+    )]
+    pub security_export: Account<'info, SecurityExport>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExportSecurityEvent<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"security_export"],
+        bump = security_export.bump
+    )]
+    pub security_export: Account<'info, SecurityExport>,
+
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    /// CHECK: Gateway program for security event messages
+    #[account(address = config.gateway_authority)]
+    pub gateway_program: UncheckedAccount<'info>,
+}
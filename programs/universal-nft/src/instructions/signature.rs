@@ -34,14 +34,15 @@ pub fn verify_signature(
 
     require!(is_valid, UniversalNftError::InvalidTssSignature);
 
-    msg!("TSS signature verified successfully");
-    msg!("Message hash: {:?}", message_hash);
-    msg!("TSS authority: {}", config.tss_authority);
+    log!("TSS signature verified successfully");
+    log!("Message hash: {:?}", message_hash);
+    log!("TSS authority: {}", config.tss_authority);
 
     Ok(())
 }
 
 /// Verify a cross-chain message with nonce validation
+#[allow(clippy::too_many_arguments)]
 pub fn verify_cross_chain_message(
     ctx: Context<VerifyCrossChainMessage>,
     nonce: u64,
@@ -61,19 +62,24 @@ pub fn verify_cross_chain_message(
     require!(nonce > config.nonce, UniversalNftError::NonceMismatch);
     
     // Validate chain ID
-    CrossChainUtils::validate_chain_id(chain_id)?;
+    CrossChainUtils::validate_chain_id(chain_id, &ctx.accounts.chain)?;
     
     // Validate recipient
     CrossChainUtils::validate_recipient(&recipient)?;
 
-    // Hash the message components
-    let message_hash = SignatureUtils::hash_message(
+    // Hash the message components, bound to this deployment's signing domain
+    let payload_hash = SignatureUtils::hash_message(
         nonce,
         chain_id,
         &recipient,
         amount,
         &data,
     );
+    let message_hash = DomainUtils::domain_message_hash(
+        &DomainUtils::inbound_domain(chain_id),
+        nonce,
+        &payload_hash,
+    );
 
     // Convert TSS authority to Ethereum address format
     let tss_eth_address = pubkey_to_eth_address(&config.tss_authority);
@@ -91,10 +97,10 @@ pub fn verify_cross_chain_message(
     // Update nonce to prevent replay
     config.nonce = nonce;
 
-    msg!("Cross-chain message verified successfully");
-    msg!("Nonce: {}", nonce);
-    msg!("Chain ID: {}", chain_id);
-    msg!("Amount: {}", amount);
+    log!("Cross-chain message verified successfully");
+    log!("Nonce: {}", nonce);
+    log!("Chain ID: {}", chain_id);
+    log!("Amount: {}", amount);
 
     Ok(())
 }
@@ -140,11 +146,11 @@ pub fn batch_verify_signatures(
 
         require!(is_valid, UniversalNftError::InvalidTssSignature);
         
-        msg!("Signature {} verified", i);
+        log!("Signature {} verified", i);
     }
 
-    msg!("Batch signature verification completed");
-    msg!("Verified {} signatures", messages.len());
+    log!("Batch signature verification completed");
+    log!("Verified {} signatures", messages.len());
 
     Ok(())
 }
@@ -161,7 +167,7 @@ pub fn recover_public_key(
     // Check if program is paused (allow if authority for debugging)
     if config.is_paused {
         require!(
-            ctx.accounts.authority.key() == config.authority,
+            ctx.accounts.authority.as_ref().map(|a| a.key()) == Some(config.authority),
             UniversalNftError::ProgramPaused
         );
     }
@@ -177,16 +183,16 @@ pub fn recover_public_key(
     // Convert to Ethereum address
     let ethereum_address = SignatureUtils::pubkey_to_ethereum_address(&recovered_pubkey.to_bytes());
 
-    msg!("Public key recovery successful");
-    msg!("Message hash: {:?}", message_hash);
-    msg!("Recovered Ethereum address: {:?}", ethereum_address);
-    msg!("Recovery ID: {}", recovery_id);
+    log!("Public key recovery successful");
+    log!("Message hash: {:?}", message_hash);
+    log!("Recovered Ethereum address: {:?}", ethereum_address);
+    log!("Recovery ID: {}", recovery_id);
 
     Ok(())
 }
 
 // Helper function to convert Solana pubkey to Ethereum address
-fn pubkey_to_eth_address(pubkey: &Pubkey) -> [u8; 20] {
+pub(crate) fn pubkey_to_eth_address(pubkey: &Pubkey) -> [u8; 20] {
     // This is a simplified conversion - in practice, you'd need to handle
     // the proper conversion between Solana and Ethereum address formats
     let pubkey_bytes = pubkey.to_bytes();
@@ -207,6 +213,7 @@ pub struct VerifySignature<'info> {
 }
 
 #[derive(Accounts)]
+#[instruction(nonce: u64, chain_id: u64)]
 pub struct VerifyCrossChainMessage<'info> {
     #[account(
         mut,
@@ -214,6 +221,13 @@ pub struct VerifyCrossChainMessage<'info> {
         bump = config.bump
     )]
     pub config: Account<'info, ProgramConfig>,
+
+    /// Registry entry for the chain the message names
+    #[account(
+        seeds = [b"chain", chain_id.to_le_bytes().as_ref()],
+        bump = chain.bump
+    )]
+    pub chain: Account<'info, RegisteredChain>,
 }
 
 #[derive(Accounts)]
@@ -260,8 +274,8 @@ pub fn verify_sender_authority(
 
     require!(is_valid, UniversalNftError::SenderVerificationFailed);
 
-    msg!("Sender authority verified");
-    msg!("Sender address: {:?}", sender_address);
+    log!("Sender authority verified");
+    log!("Sender address: {:?}", sender_address);
 
     Ok(())
 }
@@ -278,7 +292,6 @@ pub struct VerifySenderAuthority<'info> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use solana_program::hash::hash;
 
     #[test]
     fn test_pubkey_to_eth_address() {
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::*;
+
+/// Commit to an indexer-built holder snapshot of a collection at a past slot
+/// (collection authority only)
+pub fn commit_holder_snapshot(
+    ctx: Context<CommitHolderSnapshot>,
+    slot: u64,
+    commitment: [u8; 32],
+) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(slot <= clock.slot, UniversalNftError::InvalidSnapshot);
+
+    let snapshot = &mut ctx.accounts.snapshot;
+    snapshot.collection_mint = ctx.accounts.collection.mint;
+    snapshot.slot = slot;
+    snapshot.commitment = commitment;
+    snapshot.committed_slot = clock.slot;
+    snapshot.holders_root = None;
+    snapshot.holder_count = 0;
+    snapshot.revealed_at = 0;
+    snapshot.bump = ctx.bumps.snapshot;
+
+    emit!(HolderSnapshotCommitted {
+        collection_mint: snapshot.collection_mint,
+        slot,
+        commitment,
+        timestamp: clock.unix_timestamp,
+    });
+
+    log!("Holder snapshot committed for collection {}", snapshot.collection_mint);
+    log!("Snapshot slot: {}", slot);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(slot: u64)]
+pub struct CommitHolderSnapshot<'info> {
+    #[account(
+        seeds = [b"collection", collection.mint.as_ref()],
+        bump = collection.bump,
+        has_one = authority
+    )]
+    pub collection: Account<'info, UniversalCollection>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + HolderSnapshot::INIT_SPACE,
+        seeds = [b"holder_snapshot", collection.mint.as_ref(), slot.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub snapshot: Account<'info, HolderSnapshot>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Reveal a committed snapshot's holders root. The reveal must land in a
+/// later slot than the commitment (collection authority only).
+pub fn reveal_holder_snapshot(
+    ctx: Context<RevealHolderSnapshot>,
+    holders_root: [u8; 32],
+    holder_count: u32,
+    salt: [u8; 32],
+) -> Result<()> {
+    let snapshot = &mut ctx.accounts.snapshot;
+    let clock = Clock::get()?;
+
+    require!(snapshot.holders_root.is_none(), UniversalNftError::SnapshotAlreadyRevealed);
+    require!(clock.slot > snapshot.committed_slot, UniversalNftError::InvalidSnapshot);
+    require!(
+        SnapshotUtils::commitment(&holders_root, holder_count, &salt) == snapshot.commitment,
+        UniversalNftError::SnapshotCommitmentMismatch
+    );
+
+    snapshot.holders_root = Some(holders_root);
+    snapshot.holder_count = holder_count;
+    snapshot.revealed_at = clock.unix_timestamp;
+
+    emit!(HolderSnapshotRevealed {
+        collection_mint: snapshot.collection_mint,
+        slot: snapshot.slot,
+        holders_root,
+        holder_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    log!("Holder snapshot revealed for collection {}", snapshot.collection_mint);
+    log!("Holders: {}", holder_count);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevealHolderSnapshot<'info> {
+    #[account(
+        seeds = [b"collection", collection.mint.as_ref()],
+        bump = collection.bump,
+        has_one = authority
+    )]
+    pub collection: Account<'info, UniversalCollection>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"holder_snapshot",
+            collection.mint.as_ref(),
+            snapshot.slot.to_le_bytes().as_ref()
+        ],
+        bump = snapshot.bump
+    )]
+    pub snapshot: Account<'info, HolderSnapshot>,
+
+    pub authority: Signer<'info>,
+}
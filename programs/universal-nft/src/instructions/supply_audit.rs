@@ -0,0 +1,213 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+#[cfg(feature = "analytics")]
+use crate::analytics::{AlertSeverity, AlertType, AuditLog, RemediationAction, SystemMonitor};
+
+/// Start tracking a chain's lock/wrap parity (authority only). Chains with
+/// transfers already in flight are seeded with their current counts.
+pub fn initialize_supply_ledger(
+    ctx: Context<InitializeSupplyLedger>,
+    originals_locked: u64,
+    wrapped_outstanding: u64,
+) -> Result<()> {
+    let ledger = &mut ctx.accounts.supply_ledger;
+    ledger.chain_id = ctx.accounts.chain.chain_id;
+    ledger.originals_locked = originals_locked;
+    ledger.wrapped_outstanding = wrapped_outstanding;
+    ledger.reported_at = Clock::get()?.unix_timestamp;
+    ledger.last_audit_at = 0;
+    ledger.mismatch_count = 0;
+    ledger.bump = ctx.bumps.supply_ledger;
+
+    log!("Supply ledger initialized for chain {}", ledger.chain_id);
+    log!("Originals locked: {}", originals_locked);
+    log!("Wrapped outstanding: {}", wrapped_outstanding);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeSupplyLedger<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"chain", chain.chain_id.to_le_bytes().as_ref()],
+        bump = chain.bump
+    )]
+    pub chain: Account<'info, RegisteredChain>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ChainSupplyLedger::INIT_SPACE,
+        seeds = [b"supply_ledger", chain.chain_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub supply_ledger: Account<'info, ChainSupplyLedger>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Record the wrapped representations outstanding on a chain, as reported by
+/// its counterpart contract (gateway authority only)
+pub fn report_wrapped_supply(ctx: Context<ReportWrappedSupply>, wrapped_outstanding: u64) -> Result<()> {
+    require!(
+        ctx.accounts.gateway_authority.key() == ctx.accounts.config.gateway_authority,
+        UniversalNftError::Unauthorized
+    );
+
+    let ledger = &mut ctx.accounts.supply_ledger;
+    ledger.wrapped_outstanding = wrapped_outstanding;
+    ledger.reported_at = Clock::get()?.unix_timestamp;
+
+    log!("Wrapped supply reported for chain {}: {}", ledger.chain_id, wrapped_outstanding);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReportWrappedSupply<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"supply_ledger", supply_ledger.chain_id.to_le_bytes().as_ref()],
+        bump = supply_ledger.bump
+    )]
+    pub supply_ledger: Account<'info, ChainSupplyLedger>,
+
+    pub gateway_authority: Signer<'info>,
+}
+
+/// Check that the originals locked in custody for a chain match the wrapped
+/// representations outstanding there. Anyone may run the audit; a mismatch
+/// emits `ParityMismatch` and, when the system monitor is passed to a build
+/// with the `analytics` feature, raises a critical alert. The audit itself never fails on a mismatch so the alert
+/// is always recorded. If the alert calls for pausing the chain and the chain
+/// and audit log are passed, the pause is applied and logged.
+pub fn audit_supply_parity(ctx: Context<AuditSupplyParity>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let ledger = &mut ctx.accounts.supply_ledger;
+    ledger.last_audit_at = now;
+
+    if ledger.in_parity() {
+        log!("Supply parity holds for chain {}", ledger.chain_id);
+        return Ok(());
+    }
+
+    ledger.mismatch_count = ledger.mismatch_count
+        .checked_add(1)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    emit!(ParityMismatch {
+        chain_id: ledger.chain_id,
+        originals_locked: ledger.originals_locked,
+        wrapped_outstanding: ledger.wrapped_outstanding,
+        reported_at: ledger.reported_at,
+        timestamp: now,
+    });
+
+    #[cfg(feature = "analytics")]
+    if let Some(monitor) = ctx.accounts.system_monitor.as_ref() {
+        let discrepancy = ledger.originals_locked.abs_diff(ledger.wrapped_outstanding);
+        if let Some(action) = raise_parity_alert(monitor, discrepancy, ledger.chain_id)? {
+            match ctx.accounts.audit_log.as_ref() {
+                Some(audit_log) => {
+                    apply_remediation(audit_log, action, ctx.accounts.chain.as_deref_mut(), now)?
+                }
+                None => log!("Remediation skipped: audit log not passed"),
+            }
+        }
+    }
+
+    log!("Supply parity broken for chain {}", ledger.chain_id);
+    log!("Originals locked: {}", ledger.originals_locked);
+    log!("Wrapped outstanding: {}", ledger.wrapped_outstanding);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AuditSupplyParity<'info> {
+    #[account(
+        mut,
+        seeds = [b"supply_ledger", supply_ledger.chain_id.to_le_bytes().as_ref()],
+        bump = supply_ledger.bump
+    )]
+    pub supply_ledger: Account<'info, ChainSupplyLedger>,
+
+    /// CHECK: System monitor PDA the mismatch alert is raised on, verified
+    /// by raise_parity_alert
+    #[account(mut)]
+    pub system_monitor: Option<UncheckedAccount<'info>>,
+
+    /// Audited chain, paused if auto-remediation calls for it
+    #[account(
+        mut,
+        seeds = [b"chain", supply_ledger.chain_id.to_le_bytes().as_ref()],
+        bump = chain.bump
+    )]
+    pub chain: Option<Account<'info, RegisteredChain>>,
+
+    /// CHECK: Audit log PDA remediations are recorded in, verified by
+    /// apply_remediation
+    #[account(mut)]
+    pub audit_log: Option<UncheckedAccount<'info>>,
+}
+
+/// Raise a critical parity alert on the system monitor, returning the
+/// remediation it calls for
+#[cfg(feature = "analytics")]
+fn raise_parity_alert(
+    monitor: &UncheckedAccount,
+    discrepancy: u64,
+    chain_id: u64,
+) -> Result<Option<RemediationAction>> {
+    let (expected, _) = Pubkey::find_program_address(&[b"system_monitor"], &crate::ID);
+    require_keys_eq!(monitor.key(), expected, UniversalNftError::InvalidSystemMonitor);
+    require_keys_eq!(*monitor.owner, crate::ID, UniversalNftError::InvalidSystemMonitor);
+
+    let mut data = monitor.try_borrow_mut_data()?;
+    let mut system_monitor = SystemMonitor::try_deserialize(&mut &data[..])?;
+    let action = system_monitor.trigger_alert(
+        AlertType::ParityMismatch,
+        AlertSeverity::Critical,
+        discrepancy,
+        chain_id,
+    )?;
+    system_monitor.try_serialize(&mut &mut data[..])?;
+    Ok(action)
+}
+
+/// Apply a remediation and record it in the audit log
+#[cfg(feature = "analytics")]
+fn apply_remediation(
+    audit_log: &UncheckedAccount,
+    action: RemediationAction,
+    chain: Option<&mut RegisteredChain>,
+    now: i64,
+) -> Result<()> {
+    let (expected, _) = Pubkey::find_program_address(&[b"audit_log"], &crate::ID);
+    require_keys_eq!(audit_log.key(), expected, UniversalNftError::InvalidRemediationTarget);
+    require_keys_eq!(*audit_log.owner, crate::ID, UniversalNftError::InvalidRemediationTarget);
+
+    let mut data = audit_log.try_borrow_mut_data()?;
+    let mut log = AuditLog::try_deserialize(&mut &data[..])?;
+    log.apply(action, None, chain, now)?;
+    log.try_serialize(&mut &mut data[..])
+}
@@ -0,0 +1,846 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+use solana_program::program::invoke;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::{validate, MessageCodec, SwapUtils, WalletLockUtils};
+use crate::instructions::cross_chain::create_gateway_call_instruction;
+use crate::instructions::receipt::{burn_transfer_receipt, mint_transfer_receipt};
+
+/// Offer a swap, escrowing the maker's leg in the swap PDA's vault. Naming
+/// no taker (the default key) lets anyone accept.
+pub fn create_swap(
+    ctx: Context<CreateSwap>,
+    swap_id: u64,
+    taker: Pubkey,
+    maker_amount: u64,
+    taker_asset: SwapAsset,
+) -> Result<()> {
+    require!(!ctx.accounts.config.is_paused, UniversalNftError::ProgramPaused);
+
+    let maker = ctx.accounts.maker.key();
+    let maker_asset = SwapAsset {
+        mint: ctx.accounts.maker_mint.key(),
+        amount: maker_amount,
+    };
+    Swap::validate(&maker_asset, &taker_asset)?;
+    require_keys_neq!(taker, maker, UniversalNftError::InvalidSwapTerms);
+
+    WalletLockUtils::require_unlocked(&ctx.accounts.wallet_lock)?;
+    let maker_nft = SwapUtils::read_record(&ctx.accounts.maker_nft_record)?;
+    let taker_nft = SwapUtils::read_record(&ctx.accounts.taker_nft_record)?;
+    SwapUtils::escrow_record(
+        &ctx.accounts.maker_nft_record,
+        &maker,
+        &ctx.accounts.swap.key(),
+        maker_amount,
+    )?;
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.maker_token_account.to_account_info(),
+        to: ctx.accounts.vault.to_account_info(),
+        authority: ctx.accounts.maker.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, maker_amount)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let swap = &mut ctx.accounts.swap;
+    swap.maker = maker;
+    swap.swap_id = swap_id;
+    swap.taker = taker;
+    swap.maker_asset = maker_asset;
+    swap.taker_asset = taker_asset;
+    swap.created_at = now;
+    swap.bump = ctx.bumps.swap;
+
+    emit!(SwapCreated {
+        swap: swap.key(),
+        maker,
+        taker,
+        maker_asset,
+        taker_asset,
+        timestamp: now,
+    });
+
+    // A universal NFT priced in tokens is a listing, tokens bid on one an offer
+    match (maker_nft, taker_nft) {
+        (Some(nft), None) => emit!(NftListed {
+            schema_version: MARKETPLACE_EVENT_SCHEMA_VERSION,
+            universal_token_id: nft.origin_token_id,
+            origin_chain_id: nft.origin_chain_id,
+            nft_mint: nft.mint,
+            collection_mint: nft.collection_mint,
+            venue: swap.key(),
+            seller: maker,
+            price_mint: taker_asset.mint,
+            price: taker_asset.amount,
+            timestamp: now,
+        }),
+        (None, Some(nft)) => emit!(NftOfferMade {
+            schema_version: MARKETPLACE_EVENT_SCHEMA_VERSION,
+            universal_token_id: nft.origin_token_id,
+            origin_chain_id: nft.origin_chain_id,
+            nft_mint: nft.mint,
+            collection_mint: nft.collection_mint,
+            venue: swap.key(),
+            buyer: maker,
+            owner: nft.owner,
+            price_mint: maker_asset.mint,
+            price: maker_asset.amount,
+            timestamp: now,
+        }),
+        _ => {}
+    }
+
+    log!("Swap {} offered", swap_id);
+    Ok(())
+}
+
+/// Accept a swap. The taker's leg goes to the maker and the escrowed leg to
+/// the taker in one instruction, so either both move or neither does.
+pub fn accept_swap(ctx: Context<AcceptSwap>) -> Result<()> {
+    require!(!ctx.accounts.config.is_paused, UniversalNftError::ProgramPaused);
+
+    let taker = ctx.accounts.taker.key();
+    let swap = &ctx.accounts.swap;
+    require!(swap.can_accept(&taker), UniversalNftError::NotSwapParty);
+    require_keys_neq!(taker, swap.maker, UniversalNftError::NotSwapParty);
+
+    // Taker's leg, straight to the maker
+    WalletLockUtils::require_unlocked(&ctx.accounts.wallet_lock)?;
+    let maker_nft = SwapUtils::read_record(&ctx.accounts.maker_nft_record)?;
+    let taker_nft = SwapUtils::read_record(&ctx.accounts.taker_nft_record)?;
+    SwapUtils::escrow_record(
+        &ctx.accounts.taker_nft_record,
+        &taker,
+        &swap.maker,
+        swap.taker_asset.amount,
+    )?;
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.taker_token_account.to_account_info(),
+        to: ctx.accounts.maker_receive_account.to_account_info(),
+        authority: ctx.accounts.taker.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, swap.taker_asset.amount)?;
+
+    // Escrowed leg, out of the vault to the taker
+    let maker_key = swap.maker;
+    let swap_id_bytes = swap.swap_id.to_le_bytes();
+    let swap_seeds = &[b"swap".as_ref(), maker_key.as_ref(), swap_id_bytes.as_ref(), &[swap.bump]];
+    let signer_seeds = &[&swap_seeds[..]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.vault.to_account_info(),
+        to: ctx.accounts.taker_receive_account.to_account_info(),
+        authority: swap.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::transfer(cpi_ctx, swap.maker_asset.amount)?;
+    SwapUtils::release_record(&ctx.accounts.maker_nft_record, &taker)?;
+
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.vault.to_account_info(),
+        destination: ctx.accounts.maker.to_account_info(),
+        authority: swap.to_account_info(),
+    };
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    ))?;
+
+    let now = Clock::get()?.unix_timestamp;
+    emit!(SwapSettled {
+        swap: swap.key(),
+        maker: maker_key,
+        taker,
+        maker_asset: swap.maker_asset,
+        taker_asset: swap.taker_asset,
+        timestamp: now,
+    });
+
+    // The universal NFT leg was sold for the other leg
+    let sale = match (maker_nft, taker_nft) {
+        (Some(nft), None) => Some((nft, maker_key, taker, swap.taker_asset)),
+        (None, Some(nft)) => Some((nft, taker, maker_key, swap.maker_asset)),
+        _ => None,
+    };
+    if let Some((nft, seller, buyer, price)) = sale {
+        emit!(NftSold {
+            schema_version: MARKETPLACE_EVENT_SCHEMA_VERSION,
+            universal_token_id: nft.origin_token_id,
+            origin_chain_id: nft.origin_chain_id,
+            nft_mint: nft.mint,
+            collection_mint: nft.collection_mint,
+            venue: swap.key(),
+            seller,
+            buyer,
+            price_mint: price.mint,
+            price: price.amount,
+            timestamp: now,
+        });
+    }
+
+    log!("Swap {} settled", swap.swap_id);
+    Ok(())
+}
+
+/// Cancel an unaccepted swap and refund the maker's leg. The maker or the
+/// named taker may cancel.
+pub fn cancel_swap(ctx: Context<CancelSwap>) -> Result<()> {
+    let swap = &ctx.accounts.swap;
+    let party = ctx.accounts.party.key();
+    require!(swap.is_party(&party), UniversalNftError::NotSwapParty);
+
+    let maker_key = swap.maker;
+    let swap_id_bytes = swap.swap_id.to_le_bytes();
+    let swap_seeds = &[b"swap".as_ref(), maker_key.as_ref(), swap_id_bytes.as_ref(), &[swap.bump]];
+    let signer_seeds = &[&swap_seeds[..]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.vault.to_account_info(),
+        to: ctx.accounts.maker_token_account.to_account_info(),
+        authority: swap.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::transfer(cpi_ctx, swap.maker_asset.amount)?;
+    SwapUtils::release_record(&ctx.accounts.maker_nft_record, &maker_key)?;
+
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.vault.to_account_info(),
+        destination: ctx.accounts.maker.to_account_info(),
+        authority: swap.to_account_info(),
+    };
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    ))?;
+
+    let now = Clock::get()?.unix_timestamp;
+    emit!(SwapCancelled {
+        swap: swap.key(),
+        maker: maker_key,
+        cancelled_by: party,
+        timestamp: now,
+    });
+    emit!(NftListingCancelled {
+        schema_version: MARKETPLACE_EVENT_SCHEMA_VERSION,
+        venue: swap.key(),
+        cancelled_by: party,
+        timestamp: now,
+    });
+
+    log!("Swap {} cancelled", swap.swap_id);
+    Ok(())
+}
+
+/// Escrow a universal NFT against an NFT on an EVM chain under a hashlock
+/// only the maker can open. The lock request goes to the chain's counterpart
+/// contract as a transfer, so a reverted request comes back through
+/// `on_revert`.
+#[allow(clippy::too_many_arguments)]
+pub fn create_cross_chain_swap(
+    ctx: Context<CreateCrossChainSwap>,
+    swap_id: u64,
+    counterparty_chain_id: u64,
+    taker: Pubkey,
+    maker_receiver: [u8; 20],
+    remote_asset: RemoteAsset,
+    hashlock: [u8; 32],
+    expires_at: i64,
+) -> Result<()> {
+    require!(!ctx.accounts.config.is_paused, UniversalNftError::ProgramPaused);
+    validate::universal_nft_mint(&ctx.accounts.mint)?;
+
+    let chain = &ctx.accounts.counterparty_chain;
+    require!(chain.address_format == AddressFormat::Evm, UniversalNftError::InvalidSwapTerms);
+    chain.require_valid_recipient(counterparty_chain_id, &maker_receiver)?;
+    require!(taker != Pubkey::default(), UniversalNftError::InvalidSwapTerms);
+    let now = Clock::get()?.unix_timestamp;
+    CrossChainSwap::validate_timelock(expires_at, now)?;
+
+    let maker = ctx.accounts.maker.key();
+    let swap_key = ctx.accounts.swap.key();
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    universal_nft.require_no_transfer_in_progress()?;
+    require!(!universal_nft.is_locked, UniversalNftError::NftLocked);
+    require!(!universal_nft.is_disputed, UniversalNftError::NftUnderDispute);
+    require!(!universal_nft.is_frozen, UniversalNftError::NftFrozen);
+    require!(universal_nft.owner == maker, UniversalNftError::InvalidNftOwner);
+    WalletLockUtils::require_unlocked(&ctx.accounts.wallet_lock)?;
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.maker_token_account.to_account_info(),
+        to: ctx.accounts.vault.to_account_info(),
+        authority: ctx.accounts.maker.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, 1)?;
+
+    // The swap holds the NFT until it is redeemed or refunded
+    universal_nft.owner = swap_key;
+    universal_nft.transfer_in_progress = true;
+
+    let config = &mut ctx.accounts.config;
+    let nonce = config.nonce;
+    config.nonce = config.nonce
+        .checked_add(1)
+        .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+    let transfer = &mut ctx.accounts.transfer;
+    transfer.nft_mint = universal_nft.mint;
+    transfer.source_chain_id = 900; // Solana chain ID
+    transfer.destination_chain_id = counterparty_chain_id;
+    transfer.sender = [0u8; 20];
+    transfer.recipient = chain.counterpart_contract.to_vec();
+    transfer.gas_limit = CrossChainSwap::LOCK_GAS_LIMIT;
+    transfer.nonce = nonce;
+    transfer.timestamp = now;
+    transfer.status = TransferStatus::Processing;
+    transfer.bump = ctx.bumps.transfer;
+    transfer.gas_payment = GasPayment::Sol;
+    transfer.gas_fee = 0;
+    transfer.payload = Vec::new();
+    transfer.initiator = ctx.accounts.maker.key();
+
+    // The maker's receipt shows the NFT is tied up in the swap
+    mint_transfer_receipt(
+        &ctx.accounts.token_program,
+        &ctx.accounts.receipt_mint,
+        &ctx.accounts.receipt_token_account,
+        &ctx.accounts.receipt_authority,
+        &ctx.accounts.maker,
+        ctx.bumps.receipt_authority,
+    )?;
+
+    let swap = &mut ctx.accounts.swap;
+    swap.maker = maker;
+    swap.swap_id = swap_id;
+    swap.nft_mint = universal_nft.mint;
+    swap.taker = taker;
+    swap.counterparty_chain_id = counterparty_chain_id;
+    swap.maker_receiver = maker_receiver;
+    swap.remote_asset = remote_asset;
+    swap.hashlock = hashlock;
+    swap.expires_at = expires_at;
+    swap.transfer = transfer.key();
+    swap.status = CrossChainSwapStatus::Locked;
+    swap.secret = [0u8; 32];
+    swap.created_at = now;
+    swap.bump = ctx.bumps.swap;
+
+    let message = CrossChainMessage::SwapLockRequested {
+        swap: swap_key,
+        token_id: universal_nft.origin_token_id.clone(),
+        hashlock,
+        expires_at,
+        maker_receiver,
+        remote_asset,
+    };
+    let gateway_call_ix = create_gateway_call_instruction(
+        ctx.accounts.gateway_program.key(),
+        counterparty_chain_id,
+        chain.counterpart_contract.to_vec(),
+        MessageCodec::encode(&message)?,
+        CrossChainSwap::LOCK_GAS_LIMIT,
+    )?;
+    invoke(
+        &gateway_call_ix,
+        &[
+            ctx.accounts.gateway_program.to_account_info(),
+            ctx.accounts.maker.to_account_info(),
+        ],
+    )?;
+
+    emit!(CrossChainSwapCreated {
+        swap: swap_key,
+        maker,
+        taker,
+        nft_mint: swap.nft_mint,
+        counterparty_chain_id,
+        remote_asset,
+        hashlock,
+        expires_at,
+        transfer: swap.transfer,
+        timestamp: now,
+    });
+
+    log!("Cross-chain swap {} locked", swap_id);
+    Ok(())
+}
+
+/// Release a cross-chain swap's NFT to its taker. Anyone may call this with
+/// the secret before expiry, or after the counterparty chain relayed it.
+pub fn redeem_cross_chain_swap(ctx: Context<SettleCrossChainSwap>, secret: [u8; 32]) -> Result<()> {
+    let swap = &ctx.accounts.swap;
+    SwapUtils::verify_secret(&secret, &swap.hashlock)?;
+    swap.require_redeemable(Clock::get()?.unix_timestamp)?;
+
+    settle_cross_chain_swap(ctx, true)
+}
+
+/// Return an expired or reverted cross-chain swap's NFT to its maker.
+/// Anyone may call this.
+pub fn refund_cross_chain_swap(ctx: Context<SettleCrossChainSwap>) -> Result<()> {
+    ctx.accounts.swap.require_refundable(Clock::get()?.unix_timestamp)?;
+
+    settle_cross_chain_swap(ctx, false)
+}
+
+/// Move the escrowed NFT to the taker or back to the maker, and close out
+/// the lock request's transfer and receipt
+fn settle_cross_chain_swap(ctx: Context<SettleCrossChainSwap>, redeemed: bool) -> Result<()> {
+    let swap = &ctx.accounts.swap;
+    let recipient = if redeemed { swap.taker } else { swap.maker };
+    require_keys_eq!(
+        ctx.accounts.recipient.key(),
+        recipient,
+        UniversalNftError::InvalidRecipient
+    );
+
+    let maker_key = swap.maker;
+    let swap_id_bytes = swap.swap_id.to_le_bytes();
+    let swap_seeds = &[b"xswap".as_ref(), maker_key.as_ref(), swap_id_bytes.as_ref(), &[swap.bump]];
+    let signer_seeds = &[&swap_seeds[..]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.vault.to_account_info(),
+        to: ctx.accounts.recipient_token_account.to_account_info(),
+        authority: swap.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::transfer(cpi_ctx, 1)?;
+
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.vault.to_account_info(),
+        destination: ctx.accounts.maker.to_account_info(),
+        authority: swap.to_account_info(),
+    };
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    ))?;
+
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    universal_nft.owner = recipient;
+    universal_nft.transfer_in_progress = false;
+
+    // A reverted lock request already closed its transfer
+    let transfer = &mut ctx.accounts.transfer;
+    if transfer.status == TransferStatus::Processing {
+        transfer.status = if redeemed { TransferStatus::Completed } else { TransferStatus::Cancelled };
+    }
+    burn_transfer_receipt(
+        &ctx.accounts.token_program,
+        &ctx.accounts.receipt_mint,
+        &ctx.accounts.receipt_token_account,
+        &ctx.accounts.receipt_authority,
+        ctx.bumps.receipt_authority,
+    )?;
+
+    emit!(CrossChainSwapSettled {
+        swap: swap.key(),
+        nft_mint: swap.nft_mint,
+        recipient,
+        redeemed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Cross-chain swap {} settled", swap.swap_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(swap_id: u64, taker: Pubkey, maker_amount: u64, taker_asset: SwapAsset)]
+pub struct CreateSwap<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        init,
+        payer = maker,
+        space = 8 + Swap::INIT_SPACE,
+        seeds = [b"swap", maker.key().as_ref(), swap_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub swap: Account<'info, Swap>,
+
+    pub maker_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = maker_mint,
+        token::authority = maker
+    )]
+    pub maker_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = maker,
+        associated_token::mint = maker_mint,
+        associated_token::authority = swap,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Universal NFT record of the maker's leg, empty for plain SPL
+    /// tokens. Always passed so a universal NFT's owner stays in sync.
+    #[account(
+        mut,
+        seeds = [b"universal_nft", maker_mint.key().as_ref()],
+        bump
+    )]
+    pub maker_nft_record: UncheckedAccount<'info>,
+
+    /// CHECK: Universal NFT record of the taker's leg, empty for plain SPL
+    /// tokens. Read for marketplace events only.
+    #[account(
+        seeds = [b"universal_nft", taker_asset.mint.as_ref()],
+        bump
+    )]
+    pub taker_nft_record: UncheckedAccount<'info>,
+
+    /// CHECK: Maker's wallet lock PDA, read by WalletLockUtils
+    #[account(
+        seeds = [b"wallet_lock", maker.key().as_ref()],
+        bump
+    )]
+    pub wallet_lock: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptSwap<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        mut,
+        close = maker,
+        seeds = [b"swap", swap.maker.as_ref(), swap.swap_id.to_le_bytes().as_ref()],
+        bump = swap.bump
+    )]
+    pub swap: Account<'info, Swap>,
+
+    /// CHECK: Swap's maker, receiving the taker's leg and the rent
+    #[account(mut, address = swap.maker)]
+    pub maker: UncheckedAccount<'info>,
+
+    #[account(address = swap.maker_asset.mint)]
+    pub maker_mint: Account<'info, Mint>,
+
+    #[account(address = swap.taker_asset.mint)]
+    pub taker_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = maker_mint,
+        associated_token::authority = swap,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = taker_mint,
+        token::authority = taker
+    )]
+    pub taker_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = taker_mint,
+        associated_token::authority = maker,
+    )]
+    pub maker_receive_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = taker,
+        associated_token::mint = maker_mint,
+        associated_token::authority = taker,
+    )]
+    pub taker_receive_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Universal NFT record of the maker's leg, empty for plain SPL
+    /// tokens
+    #[account(
+        mut,
+        seeds = [b"universal_nft", maker_mint.key().as_ref()],
+        bump
+    )]
+    pub maker_nft_record: UncheckedAccount<'info>,
+
+    /// CHECK: Universal NFT record of the taker's leg, empty for plain SPL
+    /// tokens
+    #[account(
+        mut,
+        seeds = [b"universal_nft", taker_mint.key().as_ref()],
+        bump
+    )]
+    pub taker_nft_record: UncheckedAccount<'info>,
+
+    /// CHECK: Taker's wallet lock PDA, read by WalletLockUtils
+    #[account(
+        seeds = [b"wallet_lock", taker.key().as_ref()],
+        bump
+    )]
+    pub wallet_lock: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub taker: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelSwap<'info> {
+    #[account(
+        mut,
+        close = maker,
+        seeds = [b"swap", swap.maker.as_ref(), swap.swap_id.to_le_bytes().as_ref()],
+        bump = swap.bump
+    )]
+    pub swap: Account<'info, Swap>,
+
+    /// CHECK: Swap's maker, receiving the refund and the rent
+    #[account(mut, address = swap.maker)]
+    pub maker: UncheckedAccount<'info>,
+
+    #[account(address = swap.maker_asset.mint)]
+    pub maker_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = maker_mint,
+        associated_token::authority = swap,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = maker_mint,
+        token::authority = maker
+    )]
+    pub maker_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Universal NFT record of the maker's leg, empty for plain SPL
+    /// tokens
+    #[account(
+        mut,
+        seeds = [b"universal_nft", maker_mint.key().as_ref()],
+        bump
+    )]
+    pub maker_nft_record: UncheckedAccount<'info>,
+
+    pub party: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(swap_id: u64, counterparty_chain_id: u64)]
+pub struct CreateCrossChainSwap<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    /// Chain the remote leg is locked on
+    #[account(
+        seeds = [b"chain", counterparty_chain_id.to_le_bytes().as_ref()],
+        bump = counterparty_chain.bump
+    )]
+    pub counterparty_chain: Account<'info, RegisteredChain>,
+
+    #[account(
+        init,
+        payer = maker,
+        space = 8 + CrossChainSwap::INIT_SPACE,
+        seeds = [b"xswap", maker.key().as_ref(), swap_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub swap: Account<'info, CrossChainSwap>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", mint.key().as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = maker
+    )]
+    pub maker_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = maker,
+        associated_token::mint = mint,
+        associated_token::authority = swap,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = maker,
+        space = 8 + CrossChainTransfer::INIT_SPACE,
+        seeds = [b"transfer", mint.key().as_ref(), &config.nonce.to_le_bytes()],
+        bump
+    )]
+    pub transfer: Account<'info, CrossChainTransfer>,
+
+    /// Non-transferable receipt for the lock request
+    #[account(
+        init,
+        payer = maker,
+        mint::decimals = 0,
+        mint::authority = receipt_authority,
+        mint::freeze_authority = receipt_authority,
+        seeds = [b"receipt_mint", transfer.key().as_ref()],
+        bump
+    )]
+    pub receipt_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = maker,
+        associated_token::mint = receipt_mint,
+        associated_token::authority = maker,
+    )]
+    pub receipt_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that mints, freezes and burns receipts
+    #[account(
+        seeds = [b"receipt_authority"],
+        bump
+    )]
+    pub receipt_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Maker's wallet lock PDA, read by WalletLockUtils
+    #[account(
+        seeds = [b"wallet_lock", maker.key().as_ref()],
+        bump
+    )]
+    pub wallet_lock: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    /// CHECK: Gateway program for the lock request
+    #[account(address = config.gateway_authority)]
+    pub gateway_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SettleCrossChainSwap<'info> {
+    #[account(
+        mut,
+        close = maker,
+        seeds = [b"xswap", swap.maker.as_ref(), swap.swap_id.to_le_bytes().as_ref()],
+        bump = swap.bump
+    )]
+    pub swap: Account<'info, CrossChainSwap>,
+
+    /// CHECK: Swap's maker, receiving the rent
+    #[account(mut, address = swap.maker)]
+    pub maker: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", mint.key().as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(address = swap.nft_mint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = swap,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Taker on redeem, maker on refund; checked by the handler
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut, address = swap.transfer)]
+    pub transfer: Account<'info, CrossChainTransfer>,
+
+    #[account(
+        mut,
+        seeds = [b"receipt_mint", transfer.key().as_ref()],
+        bump
+    )]
+    pub receipt_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = receipt_mint,
+    )]
+    pub receipt_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: PDA that mints, freezes and burns receipts
+    #[account(
+        seeds = [b"receipt_authority"],
+        bump
+    )]
+    pub receipt_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
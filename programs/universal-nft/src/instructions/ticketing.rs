@@ -0,0 +1,228 @@
+use anchor_lang::prelude::*;
+use solana_program::program::invoke;
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::utils::{CrossChainUtils, MessageCodec};
+use crate::instructions::cross_chain::create_gateway_call_instruction;
+
+/// Make a collection's NFTs event tickets and name the validators allowed to
+/// check them in (collection authority only). A proof chain's registry entry
+/// is the first remaining account.
+pub fn configure_ticketing(ctx: Context<ConfigureTicketing>, params: TicketingParams) -> Result<()> {
+    require!(
+        params.validators.len() <= TicketingProfile::MAX_VALIDATORS,
+        UniversalNftError::TooManyTicketValidators
+    );
+    if params.proof_chain_id != 0 {
+        CrossChainUtils::validate_registered_chain_ids(&[params.proof_chain_id], ctx.remaining_accounts)?;
+        require!(
+            params.proof_receiver != [0u8; 20],
+            UniversalNftError::InvalidTicketProofDestination
+        );
+    }
+
+    let profile = &mut ctx.accounts.ticketing_profile;
+    profile.collection_mint = ctx.accounts.collection.mint;
+    profile.validators = params.validators;
+    profile.freeze_after_use = params.freeze_after_use;
+    profile.proof_chain_id = params.proof_chain_id;
+    profile.proof_receiver = params.proof_receiver;
+    profile.bump = ctx.bumps.ticketing_profile;
+
+    emit!(TicketingConfigured {
+        collection_mint: profile.collection_mint,
+        validators: profile.validators.clone(),
+        freeze_after_use: profile.freeze_after_use,
+        proof_chain_id: profile.proof_chain_id,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Ticketing configured: {}", profile.collection_mint);
+    Ok(())
+}
+
+/// Set the event an NFT of the collection admits to (collection authority
+/// only). Unused tickets can be moved to another event.
+pub fn issue_ticket(ctx: Context<IssueTicket>, event_id: u64) -> Result<()> {
+    let ticket = &mut ctx.accounts.ticket;
+    require!(!ticket.used, UniversalNftError::TicketAlreadyUsed);
+
+    ticket.mint = ctx.accounts.universal_nft.mint;
+    ticket.collection_mint = ctx.accounts.collection.mint;
+    ticket.event_id = event_id;
+    ticket.bump = ctx.bumps.ticket;
+
+    emit!(TicketIssued {
+        mint: ticket.mint,
+        collection_mint: ticket.collection_mint,
+        event_id,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Check a ticket in at the door. The holder and a validator both sign, so a
+/// ticket is only used in its holder's presence, and it checks in once.
+pub fn check_in(ctx: Context<CheckIn>, event_id: u64) -> Result<()> {
+    require!(!ctx.accounts.config.is_paused, UniversalNftError::ProgramPaused);
+
+    let profile = &ctx.accounts.ticketing_profile;
+    let validator = ctx.accounts.validator.key();
+    require!(profile.is_validator(&validator), UniversalNftError::Unauthorized);
+
+    let holder = ctx.accounts.holder.key();
+    let universal_nft = &mut ctx.accounts.universal_nft;
+    universal_nft.require_no_transfer_in_progress()?;
+    require!(!universal_nft.is_locked, UniversalNftError::NftLocked);
+    require!(!universal_nft.is_disputed, UniversalNftError::NftUnderDispute);
+    require!(!universal_nft.is_frozen, UniversalNftError::NftFrozen);
+    require!(universal_nft.owner == holder, UniversalNftError::InvalidNftOwner);
+
+    let now = Clock::get()?.unix_timestamp;
+    let ticket = &mut ctx.accounts.ticket;
+    ticket.check_in(event_id, validator, now)?;
+
+    // A used ticket stays locked, so it can't be resold or bridged
+    if profile.freeze_after_use {
+        universal_nft.is_locked = true;
+    }
+
+    if profile.proof_chain_id != 0 {
+        let proof = CrossChainMessage::TicketCheckIn {
+            token_id: universal_nft.origin_token_id.clone(),
+            event_id,
+            holder,
+            checked_in_at: now,
+        };
+        let gateway_call_ix = create_gateway_call_instruction(
+            ctx.accounts.gateway_program.key(),
+            profile.proof_chain_id,
+            profile.proof_receiver.to_vec(),
+            MessageCodec::encode(&proof)?,
+            TicketingProfile::PROOF_GAS_LIMIT,
+        )?;
+        invoke(
+            &gateway_call_ix,
+            &[
+                ctx.accounts.gateway_program.to_account_info(),
+                ctx.accounts.validator.to_account_info(),
+            ],
+        )?;
+    }
+
+    emit!(TicketCheckedIn {
+        mint: ticket.mint,
+        event_id,
+        holder,
+        validator,
+        frozen: profile.freeze_after_use,
+        proof_chain_id: profile.proof_chain_id,
+        timestamp: now,
+    });
+
+    log!("Ticket checked in for event {}", event_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ConfigureTicketing<'info> {
+    #[account(
+        seeds = [b"collection", collection.mint.as_ref()],
+        bump = collection.bump,
+        has_one = authority
+    )]
+    pub collection: Account<'info, UniversalCollection>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + TicketingProfile::INIT_SPACE,
+        seeds = [b"ticketing", collection.mint.as_ref()],
+        bump
+    )]
+    pub ticketing_profile: Account<'info, TicketingProfile>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct IssueTicket<'info> {
+    #[account(
+        seeds = [b"collection", collection.mint.as_ref()],
+        bump = collection.bump,
+        has_one = authority
+    )]
+    pub collection: Account<'info, UniversalCollection>,
+
+    #[account(
+        seeds = [b"ticketing", collection.mint.as_ref()],
+        bump = ticketing_profile.bump
+    )]
+    pub ticketing_profile: Account<'info, TicketingProfile>,
+
+    #[account(
+        seeds = [b"universal_nft", universal_nft.mint.as_ref()],
+        bump = universal_nft.bump,
+        constraint = universal_nft.collection_mint == Some(collection.mint)
+            @ UniversalNftError::CollectionVerificationFailed
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + Ticket::INIT_SPACE,
+        seeds = [b"ticket", universal_nft.mint.as_ref()],
+        bump
+    )]
+    pub ticket: Account<'info, Ticket>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CheckIn<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"ticketing", ticket.collection_mint.as_ref()],
+        bump = ticketing_profile.bump
+    )]
+    pub ticketing_profile: Account<'info, TicketingProfile>,
+
+    #[account(
+        mut,
+        seeds = [b"ticket", universal_nft.mint.as_ref()],
+        bump = ticket.bump
+    )]
+    pub ticket: Account<'info, Ticket>,
+
+    #[account(
+        mut,
+        seeds = [b"universal_nft", universal_nft.mint.as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    pub holder: Signer<'info>,
+
+    #[account(mut)]
+    pub validator: Signer<'info>,
+
+    /// CHECK: Gateway program for check-in proofs
+    #[account(address = config.gateway_authority)]
+    pub gateway_program: UncheckedAccount<'info>,
+}
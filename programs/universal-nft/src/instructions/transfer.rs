@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::state::*;
 use crate::errors::*;
+use crate::utils::{validate, IncidentUtils, OperatorSessionUtils, WalletLockUtils};
+use crate::hooks::{HookPayload, HookUtils};
 
 /// Transfer NFT to another address on Solana
 pub fn transfer_nft(ctx: Context<TransferNft>) -> Result<()> {
@@ -9,11 +11,22 @@ pub fn transfer_nft(ctx: Context<TransferNft>) -> Result<()> {
     
     // Check if program is paused
     require!(!config.is_paused, UniversalNftError::ProgramPaused);
+    IncidentUtils::require_operational(&ctx.accounts.incident_mode, Some(&ctx.accounts.mint.key()))?;
+
+    validate::universal_nft_mint(&ctx.accounts.mint)?;
+    validate::associated_token_account(
+        &ctx.accounts.from_token_account,
+        &ctx.accounts.mint.key(),
+        &ctx.accounts.current_owner.key(),
+    )?;
     
     let universal_nft = &mut ctx.accounts.universal_nft;
     
     // Check NFT is not locked for cross-chain transfer
+    universal_nft.require_no_transfer_in_progress()?;
     require!(!universal_nft.is_locked, UniversalNftError::NftLocked);
+    require!(!universal_nft.is_disputed, UniversalNftError::NftUnderDispute);
+    require!(!universal_nft.is_frozen, UniversalNftError::NftFrozen);
     
     // Verify current ownership
     require!(
@@ -21,6 +34,9 @@ pub fn transfer_nft(ctx: Context<TransferNft>) -> Result<()> {
         UniversalNftError::InvalidNftOwner
     );
 
+    // The owner's own transfer lock
+    WalletLockUtils::require_unlocked(&ctx.accounts.wallet_lock)?;
+
     // Perform the token transfer
     let cpi_accounts = Transfer {
         from: ctx.accounts.from_token_account.to_account_info(),
@@ -34,14 +50,29 @@ pub fn transfer_nft(ctx: Context<TransferNft>) -> Result<()> {
     // Update ownership in Universal NFT account
     universal_nft.owner = ctx.accounts.new_owner.key();
 
-    msg!("NFT transferred successfully");
-    msg!("Token ID: {}", universal_nft.origin_token_id);
-    msg!("From: {}", ctx.accounts.current_owner.key());
-    msg!("To: {}", ctx.accounts.new_owner.key());
+    // Run collection hooks subscribed to this event
+    let hooks_account = ctx.accounts.collection_hooks.as_ref().map(|a| a.to_account_info());
+    if let Some(hooks) = HookUtils::load_collection_hooks(universal_nft.collection_mint, hooks_account.as_ref())? {
+        let payload = HookPayload {
+            event: HookEvent::Transfer,
+            mint: universal_nft.mint,
+            collection_mint: hooks.collection_mint,
+            from: ctx.accounts.current_owner.key(),
+            to: Some(ctx.accounts.new_owner.key()),
+            destination_chain_id: None,
+        };
+        HookUtils::invoke_hooks(&hooks, &payload, ctx.remaining_accounts)?;
+    }
+
+    log!("NFT transferred successfully");
+    log!("Token ID: {}", universal_nft.origin_token_id);
+    log!("From: {}", ctx.accounts.current_owner.key());
+    log!("To: {}", ctx.accounts.new_owner.key());
 
     Ok(())
 }
 
+// Mirrored by crates/universal-nft-interface: keep account order in sync
 #[derive(Accounts)]
 pub struct TransferNft<'info> {
     #[account(
@@ -60,11 +91,8 @@ pub struct TransferNft<'info> {
     #[account(mut)]
     pub mint: Account<'info, anchor_spl::token::Mint>,
 
-    #[account(
-        mut,
-        associated_token::mint = mint,
-        associated_token::authority = current_owner,
-    )]
+    /// Owner's associated token account, checked by validate::associated_token_account
+    #[account(mut)]
     pub from_token_account: Account<'info, TokenAccount>,
 
     #[account(
@@ -78,9 +106,26 @@ pub struct TransferNft<'info> {
     #[account(mut)]
     pub current_owner: Signer<'info>,
 
+    /// CHECK: Owner's wallet lock PDA, read by WalletLockUtils
+    #[account(
+        seeds = [b"wallet_lock", current_owner.key().as_ref()],
+        bump
+    )]
+    pub wallet_lock: UncheckedAccount<'info>,
+
+    /// CHECK: Incident mode PDA, read by IncidentUtils
+    #[account(
+        seeds = [b"incident_mode"],
+        bump
+    )]
+    pub incident_mode: UncheckedAccount<'info>,
+
     /// CHECK: New owner account
     pub new_owner: SystemAccount<'info>,
 
+    /// CHECK: Collection hook registry PDA, verified by HookUtils
+    pub collection_hooks: Option<UncheckedAccount<'info>>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -96,6 +141,7 @@ pub fn approve_transfer(ctx: Context<ApproveTransfer>) -> Result<()> {
     let universal_nft = &ctx.accounts.universal_nft;
     
     // Check NFT is not locked
+    universal_nft.require_no_transfer_in_progress()?;
     require!(!universal_nft.is_locked, UniversalNftError::NftLocked);
     
     // Verify ownership
@@ -104,6 +150,9 @@ pub fn approve_transfer(ctx: Context<ApproveTransfer>) -> Result<()> {
         UniversalNftError::InvalidNftOwner
     );
 
+    // The owner's own transfer lock
+    WalletLockUtils::require_unlocked(&ctx.accounts.wallet_lock)?;
+
     // Approve the delegate
     let cpi_accounts = anchor_spl::token::Approve {
         to: ctx.accounts.token_account.to_account_info(),
@@ -114,9 +163,9 @@ pub fn approve_transfer(ctx: Context<ApproveTransfer>) -> Result<()> {
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
     anchor_spl::token::approve(cpi_ctx, 1)?;
 
-    msg!("Transfer approval granted");
-    msg!("Token ID: {}", universal_nft.origin_token_id);
-    msg!("Delegate: {}", ctx.accounts.delegate.key());
+    log!("Transfer approval granted");
+    log!("Token ID: {}", universal_nft.origin_token_id);
+    log!("Delegate: {}", ctx.accounts.delegate.key());
 
     Ok(())
 }
@@ -148,6 +197,13 @@ pub struct ApproveTransfer<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
 
+    /// CHECK: Owner's wallet lock PDA, read by WalletLockUtils
+    #[account(
+        seeds = [b"wallet_lock", owner.key().as_ref()],
+        bump
+    )]
+    pub wallet_lock: UncheckedAccount<'info>,
+
     /// CHECK: Delegate account to approve
     pub delegate: SystemAccount<'info>,
 
@@ -160,11 +216,30 @@ pub fn transfer_from(ctx: Context<TransferFrom>) -> Result<()> {
     
     // Check if program is paused
     require!(!config.is_paused, UniversalNftError::ProgramPaused);
+
+    validate::universal_nft_mint(&ctx.accounts.mint)?;
+    validate::associated_token_account(
+        &ctx.accounts.from_token_account,
+        &ctx.accounts.mint.key(),
+        &ctx.accounts.current_owner.key(),
+    )?;
+    validate::delegated_to(&ctx.accounts.from_token_account, &ctx.accounts.delegate.key())?;
     
     let universal_nft = &mut ctx.accounts.universal_nft;
     
     // Check NFT is not locked
+    universal_nft.require_no_transfer_in_progress()?;
     require!(!universal_nft.is_locked, UniversalNftError::NftLocked);
+    require!(!universal_nft.is_disputed, UniversalNftError::NftUnderDispute);
+    require!(!universal_nft.is_frozen, UniversalNftError::NftFrozen);
+
+    // The owner's own transfer lock
+    WalletLockUtils::require_unlocked(&ctx.accounts.wallet_lock)?;
+
+    // A delegate registered as an operator is held to its session
+    if let Some(session) = OperatorSessionUtils::load_session(&ctx.accounts.operator_session)? {
+        session.authorize(SessionScope::Transfer, None, Clock::get()?.slot)?;
+    }
 
     // Transfer using delegate authority
     let cpi_accounts = Transfer {
@@ -179,10 +254,24 @@ pub fn transfer_from(ctx: Context<TransferFrom>) -> Result<()> {
     // Update ownership in Universal NFT account
     universal_nft.owner = ctx.accounts.new_owner.key();
 
-    msg!("NFT transferred by delegate");
-    msg!("Token ID: {}", universal_nft.origin_token_id);
-    msg!("Delegate: {}", ctx.accounts.delegate.key());
-    msg!("To: {}", ctx.accounts.new_owner.key());
+    // Run collection hooks subscribed to this event
+    let hooks_account = ctx.accounts.collection_hooks.as_ref().map(|a| a.to_account_info());
+    if let Some(hooks) = HookUtils::load_collection_hooks(universal_nft.collection_mint, hooks_account.as_ref())? {
+        let payload = HookPayload {
+            event: HookEvent::Transfer,
+            mint: universal_nft.mint,
+            collection_mint: hooks.collection_mint,
+            from: ctx.accounts.current_owner.key(),
+            to: Some(ctx.accounts.new_owner.key()),
+            destination_chain_id: None,
+        };
+        HookUtils::invoke_hooks(&hooks, &payload, ctx.remaining_accounts)?;
+    }
+
+    log!("NFT transferred by delegate");
+    log!("Token ID: {}", universal_nft.origin_token_id);
+    log!("Delegate: {}", ctx.accounts.delegate.key());
+    log!("To: {}", ctx.accounts.new_owner.key());
 
     Ok(())
 }
@@ -205,11 +294,8 @@ pub struct TransferFrom<'info> {
     #[account(mut)]
     pub mint: Account<'info, anchor_spl::token::Mint>,
 
-    #[account(
-        mut,
-        associated_token::mint = mint,
-        associated_token::authority = current_owner,
-    )]
+    /// Owner's associated token account, checked by validate::associated_token_account
+    #[account(mut)]
     pub from_token_account: Account<'info, TokenAccount>,
 
     #[account(
@@ -223,12 +309,29 @@ pub struct TransferFrom<'info> {
     /// CHECK: Current owner (not signer since delegate is transferring)
     pub current_owner: SystemAccount<'info>,
 
+    /// CHECK: Owner's wallet lock PDA, read by WalletLockUtils
+    #[account(
+        seeds = [b"wallet_lock", current_owner.key().as_ref()],
+        bump
+    )]
+    pub wallet_lock: UncheckedAccount<'info>,
+
     /// CHECK: New owner account
     pub new_owner: SystemAccount<'info>,
 
+    /// CHECK: Collection hook registry PDA, verified by HookUtils
+    pub collection_hooks: Option<UncheckedAccount<'info>>,
+
     #[account(mut)]
     pub delegate: Signer<'info>,
 
+    /// CHECK: Operator session PDA for the signer, read by OperatorSessionUtils
+    #[account(
+        seeds = [b"operator_session", universal_nft.owner.as_ref(), delegate.key().as_ref()],
+        bump
+    )]
+    pub operator_session: UncheckedAccount<'info>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, anchor_spl::associated_token::AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -258,8 +361,8 @@ pub fn revoke_approval(ctx: Context<RevokeApproval>) -> Result<()> {
     let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
     anchor_spl::token::revoke(cpi_ctx)?;
 
-    msg!("Transfer approval revoked");
-    msg!("Token ID: {}", universal_nft.origin_token_id);
+    log!("Transfer approval revoked");
+    log!("Token ID: {}", universal_nft.origin_token_id);
 
     Ok(())
 }
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::analytics::UsageSegment;
+
+/// Open the usage segment for a collection and integration (anyone may pay).
+/// Mints and bridges passing the segment are attributed to it; use the
+/// default key for NFTs outside a collection and integration ID zero for
+/// direct use.
+pub fn open_usage_segment(
+    ctx: Context<OpenUsageSegment>,
+    collection_mint: Pubkey,
+    integration_id: u64,
+) -> Result<()> {
+    let segment = &mut ctx.accounts.usage_segment;
+    segment.collection_mint = collection_mint;
+    segment.integration_id = integration_id;
+    segment.mints = 0;
+    segment.bridges = 0;
+    segment.bridged_value = 0;
+    segment.first_activity = 0;
+    segment.last_activity = 0;
+    segment.bump = ctx.bumps.usage_segment;
+
+    log!("Usage segment opened: {} / {}", collection_mint, integration_id);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(collection_mint: Pubkey, integration_id: u64)]
+pub struct OpenUsageSegment<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + UsageSegment::INIT_SPACE,
+        seeds = [b"usage_segment", collection_mint.as_ref(), integration_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub usage_segment: Account<'info, UsageSegment>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::governance::proposals::Proposal;
+use crate::governance::treasury::Treasury;
+use crate::governance::{self, TREASURY_SEED};
+use crate::security::ValidatorSet;
+
+/// Register the first observer validator set (treasury authority only). Once
+/// it exists, on_call requires a K-of-N attestation for every message, and
+/// only governance proposals change the set.
+pub fn initialize_validator_set(
+    ctx: Context<InitializeValidatorSet>,
+    validators: Vec<[u8; 20]>,
+    threshold: u8,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.treasury.authority,
+        UniversalNftError::Unauthorized
+    );
+    ctx.accounts.validator_set.initialize(validators, threshold, ctx.bumps.validator_set)
+}
+
+/// Replace the observer validator set from an executed `SecurityUpdate`
+/// proposal targeting it. Anyone may submit it.
+pub fn apply_validator_set_proposal(ctx: Context<ApplyValidatorSetProposal>) -> Result<()> {
+    let set_key = ctx.accounts.validator_set.key();
+    ctx.accounts.validator_set.apply_proposal(set_key, &ctx.accounts.proposal)
+}
+
+#[derive(Accounts)]
+pub struct InitializeValidatorSet<'info> {
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump = treasury.bump,
+        seeds::program = governance::ID
+    )]
+    pub treasury: Account<'info, Treasury>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ValidatorSet::INIT_SPACE,
+        seeds = [b"validator_set"],
+        bump
+    )]
+    pub validator_set: Account<'info, ValidatorSet>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApplyValidatorSetProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"validator_set"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, ValidatorSet>,
+
+    /// Executed governance proposal carrying the new set
+    pub proposal: Account<'info, Proposal>,
+}
@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::state::*;
+use crate::errors::*;
+use crate::events::*;
+
+/// Escrow SPL tokens behind an NFT the signer owns, vesting to whoever holds
+/// the NFT. Each NFT wraps at most one position.
+pub fn create_vesting_position(
+    ctx: Context<CreateVestingPosition>,
+    total_amount: u64,
+    start_ts: i64,
+    cliff_ts: i64,
+    end_ts: i64,
+) -> Result<()> {
+    let universal_nft = &ctx.accounts.universal_nft;
+    universal_nft.require_no_transfer_in_progress()?;
+    require!(!universal_nft.is_locked, UniversalNftError::NftLocked);
+    require!(
+        universal_nft.owner == ctx.accounts.funder.key(),
+        UniversalNftError::InvalidNftOwner
+    );
+
+    let terms = VestingTerms {
+        token_mint: ctx.accounts.token_mint.key(),
+        total_amount,
+        claimed_amount: 0,
+        start_ts,
+        cliff_ts,
+        end_ts,
+    };
+    terms.validate()?;
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.funder_token_account.to_account_info(),
+        to: ctx.accounts.vault.to_account_info(),
+        authority: ctx.accounts.funder.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token::transfer(cpi_ctx, total_amount)?;
+
+    let position = &mut ctx.accounts.vesting_position;
+    position.nft_mint = universal_nft.mint;
+    position.terms = terms;
+    position.funder = ctx.accounts.funder.key();
+    position.bump = ctx.bumps.vesting_position;
+
+    emit!(VestingPositionCreated {
+        nft_mint: position.nft_mint,
+        token_mint: terms.token_mint,
+        total_amount,
+        start_ts,
+        cliff_ts,
+        end_ts,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    log!("Vesting position created for {}", position.nft_mint);
+    Ok(())
+}
+
+/// Claim the tokens vested so far to the NFT's current holder. Claims wait
+/// while the NFT is away on another chain.
+pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+    require!(!ctx.accounts.config.is_paused, UniversalNftError::ProgramPaused);
+
+    let holder = ctx.accounts.holder.key();
+    let universal_nft = &ctx.accounts.universal_nft;
+    universal_nft.require_no_transfer_in_progress()?;
+    require!(!universal_nft.is_locked, UniversalNftError::NftLocked);
+    require!(!universal_nft.is_disputed, UniversalNftError::NftUnderDispute);
+    require!(!universal_nft.is_frozen, UniversalNftError::NftFrozen);
+    require!(universal_nft.owner == holder, UniversalNftError::InvalidNftOwner);
+
+    let now = Clock::get()?.unix_timestamp;
+    let position = &mut ctx.accounts.vesting_position;
+    let amount = position.terms.claimable(now);
+    require!(amount > 0, UniversalNftError::NothingVested);
+    position.terms.claimed_amount += amount;
+
+    let nft_mint = position.nft_mint;
+    let position_seeds = &[b"vesting".as_ref(), nft_mint.as_ref(), &[position.bump]];
+    let signer_seeds = &[&position_seeds[..]];
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.vault.to_account_info(),
+        to: ctx.accounts.holder_token_account.to_account_info(),
+        authority: position.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::transfer(cpi_ctx, amount)?;
+
+    emit!(VestedTokensClaimed {
+        nft_mint,
+        holder,
+        amount,
+        claimed_amount: position.terms.claimed_amount,
+        timestamp: now,
+    });
+
+    log!("Claimed {} vested tokens", amount);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateVestingPosition<'info> {
+    #[account(
+        seeds = [b"universal_nft", universal_nft.mint.as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(
+        init,
+        payer = funder,
+        space = 8 + VestingPosition::INIT_SPACE,
+        seeds = [b"vesting", universal_nft.mint.as_ref()],
+        bump
+    )]
+    pub vesting_position: Account<'info, VestingPosition>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = funder,
+        associated_token::mint = token_mint,
+        associated_token::authority = vesting_position,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = funder
+    )]
+    pub funder_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump
+    )]
+    pub config: Account<'info, ProgramConfig>,
+
+    #[account(
+        seeds = [b"universal_nft", universal_nft.mint.as_ref()],
+        bump = universal_nft.bump
+    )]
+    pub universal_nft: Account<'info, UniversalNft>,
+
+    #[account(
+        mut,
+        seeds = [b"vesting", universal_nft.mint.as_ref()],
+        bump = vesting_position.bump
+    )]
+    pub vesting_position: Account<'info, VestingPosition>,
+
+    #[account(address = vesting_position.terms.token_mint)]
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = vesting_position,
+    )]
+    pub vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = holder,
+        associated_token::mint = token_mint,
+        associated_token::authority = holder,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
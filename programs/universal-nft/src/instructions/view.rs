@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::utils::*;
+
+/// Return an outbound transfer's status. Programs read the result with
+/// `get_return_data` after the CPI.
+pub fn get_transfer_status(ctx: Context<GetTransferStatus>) -> Result<TransferStatusView> {
+    let transfer = &ctx.accounts.transfer;
+
+    Ok(TransferStatusView {
+        transfer: transfer.key(),
+        nft_mint: transfer.nft_mint,
+        destination_chain_id: transfer.destination_chain_id,
+        nonce: transfer.nonce,
+        status: transfer.status.clone(),
+        timestamp: transfer.timestamp,
+        gas_fee: transfer.gas_fee,
+    })
+}
+
+/// Return where a Solana NFT representation originally came from
+pub fn get_nft_origin(ctx: Context<GetNftOrigin>) -> Result<NftOriginView> {
+    let origin = &ctx.accounts.nft_origin;
+
+    Ok(NftOriginView {
+        mint: origin.mint,
+        origin_chain_id: origin.origin_chain_id,
+        chain_kind: origin.chain_kind.clone(),
+        origin_sender: origin.origin_sender.clone(),
+        inscription: origin.inscription.clone(),
+        recorded_at: origin.recorded_at,
+    })
+}
+
+/// Return a registered chain's settings and its outbound volume over the last
+/// 24h, as burn_and_transfer would count it now
+pub fn get_chain_config(ctx: Context<GetChainConfig>) -> Result<ChainConfigView> {
+    let chain = &ctx.accounts.chain;
+    let now = Clock::get()?.unix_timestamp;
+
+    let volume = VolumeCapUtils::roll(chain.volume, now);
+    let elapsed = now - volume.window_start;
+
+    Ok(ChainConfigView {
+        chain_id: chain.chain_id,
+        chain_kind: chain.chain_kind.clone(),
+        address_format: chain.address_format.clone(),
+        counterpart_contract: chain.counterpart_contract,
+        enabled: chain.enabled,
+        limits: chain.limits,
+        rolling_value: VolumeCapUtils::rolling_total(volume.previous_value, volume.current_value, elapsed),
+        rolling_count: VolumeCapUtils::rolling_total(
+            volume.previous_count as u64,
+            volume.current_count as u64,
+            elapsed,
+        ),
+    })
+}
+
+#[derive(Accounts)]
+pub struct GetTransferStatus<'info> {
+    pub transfer: Account<'info, CrossChainTransfer>,
+}
+
+#[derive(Accounts)]
+pub struct GetNftOrigin<'info> {
+    #[account(
+        seeds = [b"nft_origin", nft_origin.mint.as_ref()],
+        bump = nft_origin.bump
+    )]
+    pub nft_origin: Account<'info, NftOrigin>,
+}
+
+#[derive(Accounts)]
+pub struct GetChainConfig<'info> {
+    #[account(
+        seeds = [b"chain", chain.chain_id.to_le_bytes().as_ref()],
+        bump = chain.bump
+    )]
+    pub chain: Account<'info, RegisteredChain>,
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::events::*;
+
+/// Block every outbound transfer and bridge from the signer's wallet. Locking
+/// again cancels a pending unlock; the delay can't be shortened while locked.
+pub fn lock_my_transfers(ctx: Context<LockMyTransfers>, unlock_delay: i64) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let wallet_lock = &mut ctx.accounts.wallet_lock;
+    wallet_lock.lock(ctx.accounts.wallet.key(), unlock_delay, now, ctx.bumps.wallet_lock)?;
+
+    emit!(WalletTransfersLocked {
+        wallet: wallet_lock.wallet,
+        unlock_delay,
+        timestamp: now,
+    });
+
+    log!("Transfers locked for wallet {}", wallet_lock.wallet);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct LockMyTransfers<'info> {
+    #[account(
+        init_if_needed,
+        payer = wallet,
+        space = 8 + WalletLock::INIT_SPACE,
+        seeds = [b"wallet_lock", wallet.key().as_ref()],
+        bump
+    )]
+    pub wallet_lock: Account<'info, WalletLock>,
+
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Start the unlock timer. Transfers stay blocked until the wallet's unlock
+/// delay has passed.
+pub fn unlock_my_transfers(ctx: Context<UnlockMyTransfers>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let wallet_lock = &mut ctx.accounts.wallet_lock;
+
+    let unlocks_at = wallet_lock.request_unlock(now)?;
+
+    emit!(WalletUnlockRequested {
+        wallet: wallet_lock.wallet,
+        unlocks_at,
+        timestamp: now,
+    });
+
+    log!("Unlock requested for wallet {}", wallet_lock.wallet);
+    log!("Transfers unlock at: {}", unlocks_at);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnlockMyTransfers<'info> {
+    #[account(
+        mut,
+        seeds = [b"wallet_lock", wallet.key().as_ref()],
+        bump = wallet_lock.bump,
+        has_one = wallet
+    )]
+    pub wallet_lock: Account<'info, WalletLock>,
+
+    pub wallet: Signer<'info>,
+}
@@ -1,27 +1,48 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
-use anchor_spl::associated_token::AssociatedToken;
-use mpl_token_metadata::instructions::{CreateMetadataAccountV3, UpdateMetadataAccountV2};
-use mpl_token_metadata::types::{DataV2, CreatorV2, CollectionDetails};
-use solana_program::{
-    system_instruction,
-    sysvar::{Sysvar, SysvarId},
-    program::{invoke, invoke_signed},
-    pubkey::Pubkey,
-    instruction::Instruction,
-};
 
 declare_id!("EiGgwyFXtqcNEutPaUe94J9c9sPaPnDWj64sFcD7W9sz");
 
+/// Program logging. Lean builds compile it out: arguments are still
+/// type-checked, but no log strings or syscalls end up in the binary.
+#[cfg(not(feature = "lean"))]
+macro_rules! log {
+    ($($arg:tt)*) => {
+        msg!($($arg)*)
+    };
+}
+
+#[cfg(feature = "lean")]
+macro_rules! log {
+    ($($arg:tt)*) => {{
+        if false {
+            let _ = format_args!($($arg)*);
+        }
+    }};
+}
+
+#[cfg(feature = "analytics")]
+pub mod analytics;
+pub mod bridge;
 pub mod errors;
+pub mod events;
+pub mod hooks;
 pub mod instructions;
+pub mod recovery;
+pub mod security;
 pub mod state;
 pub mod utils;
 
-use errors::*;
+/// Treasury, DAO and bug bounty live in the separate governance program; the
+/// core only reads its accounts and books treasury spends through its CPI client
+#[cfg(feature = "governance")]
+pub use universal_nft_governance as governance;
+
 use instructions::*;
 use state::*;
-use utils::*;
+use security::{
+    CircuitConfig, FraudConfig, IncidentConfig, IncidentLevel, QuorumSignature, RateLimitConfig,
+    RateLimitSubject,
+};
 
 #[program]
 pub mod universal_nft {
@@ -32,6 +53,16 @@ pub mod universal_nft {
         instructions::initialize(ctx, gateway_authority)
     }
 
+    /// Update program configuration (authority only)
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        new_gateway_authority: Option<Pubkey>,
+        new_tss_authority: Option<Pubkey>,
+        paused: Option<bool>,
+    ) -> Result<()> {
+        instructions::update_config(ctx, new_gateway_authority, new_tss_authority, paused)
+    }
+
     /// Mint a new universal NFT with Solana compute optimization
     pub fn mint_nft(
         ctx: Context<MintNft>,
@@ -39,14 +70,16 @@ pub mod universal_nft {
         symbol: String,
         uri: String,
         collection_mint: Option<Pubkey>,
+        allowlist_proof: Vec<[u8; 32]>,
+        referrer: Option<Pubkey>,
     ) -> Result<()> {
         // Solana compute budget optimization - rent exemption handled in instructions
-        instructions::mint_nft(ctx, name, symbol, uri, collection_mint)
+        instructions::mint_nft(ctx, name, symbol, uri, collection_mint, allowlist_proof, referrer)
     }
 
     /// Handle incoming cross-chain calls from ZetaChain Gateway
-    pub fn on_call(
-        ctx: Context<OnCall>,
+    pub fn on_call<'info>(
+        ctx: Context<'_, '_, 'info, 'info, OnCall<'info>>,
         sender: [u8; 20],
         source_chain_id: u64,
         message: Vec<u8>,
@@ -64,14 +97,46 @@ pub mod universal_nft {
         instructions::on_revert(ctx, sender, source_chain_id, message)
     }
 
+    /// Create the mint and record a token from another chain is delivered
+    /// into on its first arrival (permissionless)
+    pub fn prepare_inbound_nft(
+        ctx: Context<PrepareInboundNft>,
+        source_chain_id: u64,
+        token_id: String,
+    ) -> Result<()> {
+        instructions::prepare_inbound_nft(ctx, source_chain_id, token_id)
+    }
+
     /// Burn NFT and initiate cross-chain transfer
-    pub fn burn_and_transfer(
-        ctx: Context<BurnAndTransfer>,
+    pub fn burn_and_transfer<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BurnAndTransfer<'info>>,
+        destination_chain_id: u64,
+        recipient: Vec<u8>,
+        gas_limit: u64,
+        gas_payment: GasPayment,
+        payload: Vec<u8>,
+        referrer: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::burn_and_transfer(
+            ctx,
+            destination_chain_id,
+            recipient,
+            gas_limit,
+            gas_payment,
+            payload,
+            referrer,
+        )
+    }
+
+    /// Burn several NFTs and send them to one recipient in a single gateway call
+    pub fn batch_burn_and_transfer<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchBurnAndTransfer<'info>>,
         destination_chain_id: u64,
         recipient: Vec<u8>,
         gas_limit: u64,
+        referrer: Option<Pubkey>,
     ) -> Result<()> {
-        instructions::burn_and_transfer(ctx, destination_chain_id, recipient, gas_limit)
+        instructions::batch_burn_and_transfer(ctx, destination_chain_id, recipient, gas_limit, referrer)
     }
 
     /// Transfer NFT to another address on Solana
@@ -79,6 +144,21 @@ pub mod universal_nft {
         instructions::transfer_nft(ctx)
     }
 
+    /// Approve another account to transfer the NFT
+    pub fn approve_transfer(ctx: Context<ApproveTransfer>) -> Result<()> {
+        instructions::approve_transfer(ctx)
+    }
+
+    /// Transfer NFT using delegate authority
+    pub fn transfer_from(ctx: Context<TransferFrom>) -> Result<()> {
+        instructions::transfer_from(ctx)
+    }
+
+    /// Revoke transfer approval
+    pub fn revoke_approval(ctx: Context<RevokeApproval>) -> Result<()> {
+        instructions::revoke_approval(ctx)
+    }
+
     /// Update NFT metadata (owner only)
     pub fn update_metadata(
         ctx: Context<UpdateMetadata>,
@@ -89,6 +169,22 @@ pub mod universal_nft {
         instructions::update_metadata(ctx, new_uri, new_name, new_symbol)
     }
 
+    /// Create a new universal collection
+    pub fn create_collection(
+        ctx: Context<CreateCollection>,
+        name: String,
+        symbol: String,
+        uri: String,
+        max_supply: u64,
+    ) -> Result<()> {
+        instructions::create_collection(ctx, name, symbol, uri, max_supply)
+    }
+
+    /// Verify collection membership for an NFT
+    pub fn verify_collection(ctx: Context<VerifyCollection>) -> Result<()> {
+        instructions::verify_collection(ctx)
+    }
+
     /// Verify cross-chain signature from ZetaChain TSS
     pub fn verify_signature(
         ctx: Context<VerifySignature>,
@@ -98,21 +194,1372 @@ pub mod universal_nft {
     ) -> Result<()> {
         instructions::verify_signature(ctx, message_hash, signature, recovery_id)
     }
-}
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + ProgramConfig::INIT_SPACE,
-        seeds = [b"config"],
-        bump
-    )]
-    pub config: Account<'info, ProgramConfig>,
-    
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    
-    pub system_program: Program<'info, System>,
-}
\ No newline at end of file
+    /// Verify a cross-chain message with nonce validation
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_cross_chain_message(
+        ctx: Context<VerifyCrossChainMessage>,
+        nonce: u64,
+        chain_id: u64,
+        recipient: Vec<u8>,
+        amount: u64,
+        data: Vec<u8>,
+        signature: [u8; 64],
+        recovery_id: u8,
+    ) -> Result<()> {
+        instructions::verify_cross_chain_message(
+            ctx, nonce, chain_id, recipient, amount, data, signature, recovery_id,
+        )
+    }
+
+    /// Batch verify multiple TSS signatures
+    pub fn batch_verify_signatures(
+        ctx: Context<BatchVerifySignatures>,
+        messages: Vec<[u8; 32]>,
+        signatures: Vec<[u8; 64]>,
+        recovery_ids: Vec<u8>,
+    ) -> Result<()> {
+        instructions::batch_verify_signatures(ctx, messages, signatures, recovery_ids)
+    }
+
+    /// Recover the signer's public key from a signature
+    pub fn recover_public_key(
+        ctx: Context<RecoverPublicKey>,
+        message_hash: [u8; 32],
+        signature: [u8; 64],
+        recovery_id: u8,
+    ) -> Result<()> {
+        instructions::recover_public_key(ctx, message_hash, signature, recovery_id)
+    }
+
+    /// Verify that a cross-chain sender signed a message
+    pub fn verify_sender_authority(
+        ctx: Context<VerifySenderAuthority>,
+        sender_address: [u8; 20],
+        message_hash: [u8; 32],
+        signature: [u8; 64],
+        recovery_id: u8,
+    ) -> Result<()> {
+        instructions::verify_sender_authority(ctx, sender_address, message_hash, signature, recovery_id)
+    }
+
+    /// Create the treasury-funded compensation vault
+    pub fn initialize_compensation_vault(
+        ctx: Context<InitializeCompensationVault>,
+        per_user_cap: u64,
+    ) -> Result<()> {
+        instructions::initialize_compensation_vault(ctx, per_user_cap)
+    }
+
+    /// Fund the compensation vault from the treasury
+    pub fn fund_compensation_vault(ctx: Context<FundCompensationVault>, amount: u64) -> Result<()> {
+        instructions::fund_compensation_vault(ctx, amount)
+    }
+
+    /// Refund the destination gas of a failed transfer from the compensation vault
+    pub fn claim_compensation(ctx: Context<ClaimCompensation>) -> Result<()> {
+        instructions::claim_compensation(ctx)
+    }
+
+    /// Create the error recovery manager (program authority only)
+    pub fn initialize_error_recovery(
+        ctx: Context<InitializeErrorRecovery>,
+        config: Option<recovery::RecoveryConfig>,
+    ) -> Result<()> {
+        instructions::initialize_error_recovery(ctx, config)
+    }
+
+    /// Open a recovery session for a transfer that has not landed
+    /// (recovery manager authority only)
+    pub fn open_recovery_session(
+        ctx: Context<OpenRecoverySession>,
+        error_type: recovery::ErrorType,
+    ) -> Result<()> {
+        instructions::open_recovery_session(ctx, error_type)
+    }
+
+    /// Record a recovery session's final result (recovery manager authority only)
+    pub fn conclude_recovery_session(
+        ctx: Context<ConcludeRecoverySession>,
+        result: recovery::RecoveryResult,
+    ) -> Result<()> {
+        instructions::conclude_recovery_session(ctx, result)
+    }
+
+    /// Create a Merkle state checkpoint over the critical accounts
+    pub fn create_state_checkpoint(
+        ctx: Context<CreateStateCheckpoint>,
+        checkpoint_type: recovery::CheckpointType,
+        state_metrics: recovery::StateMetrics,
+    ) -> Result<()> {
+        instructions::create_state_checkpoint(ctx, checkpoint_type, state_metrics)
+    }
+
+    /// Verify live critical account state against a checkpoint
+    pub fn verify_state_against_checkpoint(
+        ctx: Context<VerifyStateAgainstCheckpoint>,
+        checkpoint_id: u64,
+    ) -> Result<()> {
+        instructions::verify_state_against_checkpoint(ctx, checkpoint_id)
+    }
+
+    /// Export critical account pages as snapshot events
+    pub fn export_state_snapshot(
+        ctx: Context<ExportStateSnapshot>,
+        start_page: u32,
+        max_pages: u8,
+    ) -> Result<()> {
+        instructions::export_state_snapshot(ctx, start_page, max_pages)
+    }
+
+    /// Open a checkpoint-verified restore of a critical account (authority only)
+    pub fn begin_state_restore(
+        ctx: Context<BeginStateRestore>,
+        checkpoint_id: u64,
+        leaf_index: u8,
+    ) -> Result<()> {
+        instructions::begin_state_restore(ctx, checkpoint_id, leaf_index)
+    }
+
+    /// Stage an archived chunk in an open restore buffer
+    pub fn restore_state_chunk(
+        ctx: Context<RestoreStateChunk>,
+        offset: u32,
+        chunk: Vec<u8>,
+    ) -> Result<()> {
+        instructions::restore_state_chunk(ctx, offset, chunk)
+    }
+
+    /// Write a staged restore into its account once it matches the checkpoint
+    pub fn apply_state_restore(ctx: Context<ApplyStateRestore>) -> Result<()> {
+        instructions::apply_state_restore(ctx)
+    }
+
+    /// Register an NFT left in custody by a relayed inbound message that
+    /// never executed
+    pub fn register_orphaned_nft(
+        ctx: Context<RegisterOrphanedNft>,
+        sequence: u64,
+        sender: [u8; 20],
+        message: Vec<u8>,
+    ) -> Result<()> {
+        instructions::register_orphaned_nft(ctx, sequence, sender, message)
+    }
+
+    /// Claim an orphaned NFT with the original inbound message as proof
+    pub fn claim_orphaned_nft(
+        ctx: Context<ClaimOrphanedNft>,
+        sequence: u64,
+        sender: [u8; 20],
+        original_message: Vec<u8>,
+    ) -> Result<()> {
+        instructions::claim_orphaned_nft(ctx, sequence, sender, original_message)
+    }
+
+    /// Create the dispute config and assign the arbiter
+    pub fn initialize_dispute_config(
+        ctx: Context<InitializeDisputeConfig>,
+        arbiter: Pubkey,
+        resolution_window: i64,
+        bond: u64,
+    ) -> Result<()> {
+        instructions::initialize_dispute_config(ctx, arbiter, resolution_window, bond)
+    }
+
+    /// Open a dispute against a transfer record
+    pub fn open_dispute(ctx: Context<OpenDispute>, reason_hash: [u8; 32]) -> Result<()> {
+        instructions::open_dispute(ctx, reason_hash)
+    }
+
+    /// Arbiter upholds a disputed transfer
+    pub fn resolve_dispute_release(ctx: Context<ResolveDispute>) -> Result<()> {
+        instructions::resolve_dispute_release(ctx)
+    }
+
+    /// Arbiter reverts a disputed transfer
+    pub fn resolve_dispute_revert(ctx: Context<ResolveDispute>) -> Result<()> {
+        instructions::resolve_dispute_revert(ctx)
+    }
+
+    /// Arbiter splits the escrow of a disputed transfer
+    pub fn resolve_dispute_split(ctx: Context<ResolveDispute>, opener_share_bps: u16) -> Result<()> {
+        instructions::resolve_dispute_split(ctx, opener_share_bps)
+    }
+
+    /// Close a dispute whose resolution deadline has passed
+    pub fn expire_dispute(ctx: Context<ExpireDispute>) -> Result<()> {
+        instructions::expire_dispute(ctx)
+    }
+
+    /// Query EVM-chain state through ZetaChain; the answer arrives via on_call
+    pub fn request_interchain_query(
+        ctx: Context<RequestInterchainQuery>,
+        target_chain_id: u64,
+        query_type: QueryType,
+        contract_address: [u8; 20],
+        token_id: String,
+        gas_limit: u64,
+    ) -> Result<()> {
+        instructions::request_interchain_query(
+            ctx,
+            target_chain_id,
+            query_type,
+            contract_address,
+            token_id,
+            gas_limit,
+        )
+    }
+
+    /// Register an SPL token representing a ZRC-20 gas token
+    pub fn register_gas_token(
+        ctx: Context<RegisterGasToken>,
+        destination_chain_id: u64,
+        price_per_gas_unit: u64,
+    ) -> Result<()> {
+        instructions::register_gas_token(ctx, destination_chain_id, price_per_gas_unit)
+    }
+
+    /// Update a registered gas token's price and status
+    pub fn update_gas_token(
+        ctx: Context<UpdateGasToken>,
+        price_per_gas_unit: u64,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::update_gas_token(ctx, price_per_gas_unit, enabled)
+    }
+
+    /// Add a chain to the registry
+    pub fn register_chain(
+        ctx: Context<RegisterChain>,
+        chain_id: u64,
+        chain_kind: ChainKind,
+        address_format: AddressFormat,
+    ) -> Result<()> {
+        instructions::register_chain(ctx, chain_id, chain_kind, address_format)
+    }
+
+    /// Enable or disable a registered chain
+    pub fn set_chain_enabled(ctx: Context<UpdateChain>, enabled: bool) -> Result<()> {
+        instructions::set_chain_enabled(ctx, enabled)
+    }
+
+    /// Set the counterpart contract address for an EVM chain
+    pub fn set_chain_counterpart(
+        ctx: Context<UpdateChain>,
+        counterpart_contract: [u8; 20],
+    ) -> Result<()> {
+        instructions::set_chain_counterpart(ctx, counterpart_contract)
+    }
+
+    /// Set the outbound value and count caps for a chain and their enforcement mode
+    pub fn set_chain_limits(ctx: Context<UpdateChain>, limits: ChainTransferLimits) -> Result<()> {
+        instructions::set_chain_limits(ctx, limits)
+    }
+
+    /// Set the primary and fallback message layer routes to a chain (authority only)
+    pub fn set_chain_routes(
+        ctx: Context<UpdateChain>,
+        primary_route: BridgeRoute,
+        fallback_route: Option<BridgeRoute>,
+    ) -> Result<()> {
+        instructions::set_chain_routes(ctx, primary_route, fallback_route)
+    }
+
+    /// Register a message layer program (authority only)
+    pub fn register_message_layer(
+        ctx: Context<RegisterMessageLayer>,
+        layer: MessageLayer,
+        program_id: Pubkey,
+    ) -> Result<()> {
+        instructions::register_message_layer(ctx, layer, program_id)
+    }
+
+    /// Mark a message layer degraded or operational (authority only)
+    pub fn set_message_layer_operational(
+        ctx: Context<UpdateMessageLayer>,
+        operational: bool,
+    ) -> Result<()> {
+        instructions::set_message_layer_operational(ctx, operational)
+    }
+
+    /// Set how outbound metadata is rewritten for a chain (authority only)
+    pub fn set_chain_metadata_rules(
+        ctx: Context<SetChainMetadataRules>,
+        params: ChainMetadataRulesParams,
+    ) -> Result<()> {
+        instructions::set_chain_metadata_rules(ctx, params)
+    }
+
+    /// Register the pinning service allowed to post pin receipts (program authority only)
+    pub fn register_pinner(ctx: Context<RegisterPinner>, pinner: Pubkey) -> Result<()> {
+        instructions::register_pinner(ctx, pinner)
+    }
+
+    /// Record where an NFT's metadata content is pinned (registered pinner only)
+    pub fn record_pin_receipt(
+        ctx: Context<RecordPinReceipt>,
+        storage: PinStorage,
+        content_id: String,
+        size: u64,
+    ) -> Result<()> {
+        instructions::record_pin_receipt(ctx, storage, content_id, size)
+    }
+
+    /// Create the moderation config (program authority only)
+    pub fn initialize_moderation_config(
+        ctx: Context<InitializeModerationConfig>,
+        moderator: Pubkey,
+        flag_bond: u64,
+    ) -> Result<()> {
+        instructions::initialize_moderation_config(ctx, moderator, flag_bond)
+    }
+
+    /// Replace the moderator or change the flag bond (program authority only)
+    pub fn update_moderation_config(
+        ctx: Context<UpdateModerationConfig>,
+        moderator: Option<Pubkey>,
+        flag_bond: Option<u64>,
+    ) -> Result<()> {
+        instructions::update_moderation_config(ctx, moderator, flag_bond)
+    }
+
+    /// Flag an NFT's content for moderation, posting the flag bond
+    pub fn flag_content(ctx: Context<FlagContent>, reason_hash: [u8; 32]) -> Result<()> {
+        instructions::flag_content(ctx, reason_hash)
+    }
+
+    /// Uphold a flag with a takedown or dismiss it (moderator only)
+    pub fn resolve_content_flag(
+        ctx: Context<ResolveContentFlag>,
+        take_down: bool,
+        decision_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::resolve_content_flag(ctx, take_down, decision_hash)
+    }
+
+    /// Clear a takedown (moderator only)
+    pub fn reinstate_content(ctx: Context<ReinstateContent>, decision_hash: [u8; 32]) -> Result<()> {
+        instructions::reinstate_content(ctx, decision_hash)
+    }
+
+    /// Set a collection's royalty split among creators (collection authority only)
+    pub fn set_royalty_config(ctx: Context<SetRoyaltyConfig>, shares: Vec<RoyaltyShare>) -> Result<()> {
+        instructions::set_royalty_config(ctx, shares)
+    }
+
+    /// Deposit secondary-sale royalties into a collection's escrow
+    pub fn deposit_royalty(ctx: Context<DepositRoyalty>, amount: u64) -> Result<()> {
+        instructions::deposit_royalty(ctx, amount)
+    }
+
+    /// Claim a creator's royalties from a collection's escrow
+    pub fn claim_royalties(ctx: Context<ClaimRoyalties>) -> Result<()> {
+        instructions::claim_royalties(ctx)
+    }
+
+    /// Start tracking a chain's lock/wrap supply parity (authority only)
+    pub fn initialize_supply_ledger(
+        ctx: Context<InitializeSupplyLedger>,
+        originals_locked: u64,
+        wrapped_outstanding: u64,
+    ) -> Result<()> {
+        instructions::initialize_supply_ledger(ctx, originals_locked, wrapped_outstanding)
+    }
+
+    /// Record a chain's outstanding wrapped supply (gateway authority only)
+    pub fn report_wrapped_supply(ctx: Context<ReportWrappedSupply>, wrapped_outstanding: u64) -> Result<()> {
+        instructions::report_wrapped_supply(ctx, wrapped_outstanding)
+    }
+
+    /// Compare a chain's locked originals against its wrapped supply
+    pub fn audit_supply_parity(ctx: Context<AuditSupplyParity>) -> Result<()> {
+        instructions::audit_supply_parity(ctx)
+    }
+
+    /// Set a source chain's required confirmation depth (authority only)
+    pub fn set_chain_finality(ctx: Context<SetChainFinality>, required_confirmations: u64) -> Result<()> {
+        instructions::set_chain_finality(ctx, required_confirmations)
+    }
+
+    /// Set how NFTs from a source chain are delivered to the signer
+    pub fn set_recipient_preferences(
+        ctx: Context<SetRecipientPreferences>,
+        source_chain_id: u64,
+        params: RecipientPreferencesParams,
+    ) -> Result<()> {
+        instructions::set_recipient_preferences(ctx, source_chain_id, params)
+    }
+
+    /// Drop the signer's receiving preferences for a source chain
+    pub fn close_recipient_preferences(ctx: Context<CloseRecipientPreferences>) -> Result<()> {
+        instructions::close_recipient_preferences(ctx)
+    }
+
+    /// Accept an unsolicited NFT held in quarantine
+    pub fn claim_quarantined_nft(ctx: Context<ClaimQuarantinedNft>) -> Result<()> {
+        instructions::claim_quarantined_nft(ctx)
+    }
+
+    /// Refuse a quarantined NFT and send it back to its source chain
+    pub fn reject_quarantined_nft(ctx: Context<ReturnQuarantinedNft>) -> Result<()> {
+        instructions::reject_quarantined_nft(ctx)
+    }
+
+    /// Send back a quarantined NFT left unclaimed past the quarantine period
+    pub fn expire_quarantined_nft(ctx: Context<ReturnQuarantinedNft>) -> Result<()> {
+        instructions::expire_quarantined_nft(ctx)
+    }
+
+    /// Queue new protocol fees behind the timelock (governance only)
+    pub fn queue_fee_schedule(ctx: Context<GovernFeeSchedule>, rates: FeeRates) -> Result<()> {
+        instructions::queue_fee_schedule(ctx, rates)
+    }
+
+    /// Withdraw queued protocol fees (governance only)
+    pub fn cancel_fee_schedule(ctx: Context<GovernFeeSchedule>) -> Result<()> {
+        instructions::cancel_fee_schedule(ctx)
+    }
+
+    /// Put queued protocol fees into effect after the timelock
+    pub fn apply_fee_schedule(ctx: Context<ApplyFeeSchedule>) -> Result<()> {
+        instructions::apply_fee_schedule(ctx)
+    }
+
+    /// Open the signer's referral accrual account
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        instructions::register_referrer(ctx)
+    }
+
+    /// Claim the signer's accrued referral rewards
+    pub fn claim_referral_rewards(ctx: Context<ClaimReferralRewards>) -> Result<()> {
+        instructions::claim_referral_rewards(ctx)
+    }
+
+    /// Create or reconfigure the fee rebate campaign (governance only)
+    pub fn configure_rebate_campaign(
+        ctx: Context<ConfigureRebateCampaign>,
+        params: RebateCampaignParams,
+    ) -> Result<()> {
+        instructions::configure_rebate_campaign(ctx, params)
+    }
+
+    /// Deposit lamports for the rebate campaign to refund fees from
+    pub fn fund_rebate_campaign(ctx: Context<FundRebateCampaign>, amount: u64) -> Result<()> {
+        instructions::fund_rebate_campaign(ctx, amount)
+    }
+
+    /// Return an ended rebate campaign's unspent lamports to the treasury
+    pub fn reclaim_rebate_funds(ctx: Context<ReclaimRebateFunds>) -> Result<()> {
+        instructions::reclaim_rebate_funds(ctx)
+    }
+
+    /// Replace the chain risk registry used to score chain pairs (governance only)
+    pub fn configure_chain_risk(ctx: Context<ConfigureChainRisk>, params: ChainRiskParams) -> Result<()> {
+        instructions::configure_chain_risk(ctx, params)
+    }
+
+    /// Replace the approved satellite program IDs and versions (governance only)
+    pub fn configure_deployment_registry(
+        ctx: Context<ConfigureDeploymentRegistry>,
+        satellites: Vec<SatelliteProgram>,
+    ) -> Result<()> {
+        instructions::configure_deployment_registry(ctx, satellites)
+    }
+
+    /// Set activity points weights and redeemers
+    pub fn configure_points(
+        ctx: Context<ConfigurePoints>,
+        weights: PointsWeights,
+        redeemers: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::configure_points(ctx, weights, redeemers)
+    }
+
+    /// Open the signer's user metrics account to start earning points
+    pub fn open_user_metrics(ctx: Context<OpenUserMetrics>) -> Result<()> {
+        instructions::open_user_metrics(ctx)
+    }
+
+    /// Credit points for a governance vote the signer cast
+    pub fn claim_governance_points(ctx: Context<ClaimGovernancePoints>) -> Result<()> {
+        instructions::claim_governance_points(ctx)
+    }
+
+    /// Redeem a user's points through a configured redeemer
+    pub fn redeem_points(ctx: Context<RedeemPoints>, amount: u64) -> Result<()> {
+        instructions::redeem_points(ctx, amount)
+    }
+
+    /// Create the audit log automated remediations are recorded in
+    pub fn initialize_audit_log(ctx: Context<InitializeAuditLog>) -> Result<()> {
+        instructions::initialize_audit_log(ctx)
+    }
+
+    /// Undo an automated remediation recorded in the audit log
+    pub fn revert_remediation(ctx: Context<RevertRemediation>, entry_id: u64) -> Result<()> {
+        instructions::revert_remediation(ctx, entry_id)
+    }
+
+    /// Open the usage segment mints and bridges can be attributed to
+    pub fn open_usage_segment(
+        ctx: Context<OpenUsageSegment>,
+        collection_mint: Pubkey,
+        integration_id: u64,
+    ) -> Result<()> {
+        instructions::open_usage_segment(ctx, collection_mint, integration_id)
+    }
+
+    /// Create a top-N leaderboard of minters, bridgers or bridged collections
+    pub fn open_leaderboard(ctx: Context<OpenLeaderboard>, kind: LeaderboardKind) -> Result<()> {
+        instructions::open_leaderboard(ctx, kind)
+    }
+
+    /// Read a leaderboard's ranking through return data
+    pub fn get_leaderboard(ctx: Context<GetLeaderboard>) -> Result<LeaderboardView> {
+        instructions::get_leaderboard(ctx)
+    }
+
+    /// Open a registered chain's metrics and corridor statistics
+    pub fn open_chain_metrics(
+        ctx: Context<OpenChainMetrics>,
+        chain_id: u64,
+        chain_name: String,
+    ) -> Result<()> {
+        instructions::open_chain_metrics(ctx, chain_id, chain_name)
+    }
+
+    /// Name the keys allowed to write a collection's NFT attributes
+    pub fn set_trait_authorities(
+        ctx: Context<SetTraitAuthorities>,
+        authorities: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_trait_authorities(ctx, authorities)
+    }
+
+    /// Set an NFT attribute as one of its collection's trait authorities
+    pub fn set_nft_attribute(ctx: Context<SetNftAttribute>, key: String, value: Vec<u8>) -> Result<()> {
+        instructions::set_nft_attribute(ctx, key, value)
+    }
+
+    /// Remove an NFT attribute as one of its collection's trait authorities
+    pub fn remove_nft_attribute(ctx: Context<RemoveNftAttribute>, key: String) -> Result<()> {
+        instructions::remove_nft_attribute(ctx, key)
+    }
+
+    /// Commit a collection's reveal list and Switchboard randomness
+    pub fn commit_reveal(ctx: Context<CommitReveal>, list_root: [u8; 32], item_count: u32) -> Result<()> {
+        instructions::commit_reveal(ctx, list_root, item_count)
+    }
+
+    /// Record a collection's revealed randomness once minting has closed
+    pub fn reveal_collection(ctx: Context<RevealCollection>) -> Result<()> {
+        instructions::reveal_collection(ctx)
+    }
+
+    /// Assign an NFT its reveal list entry and update its metadata
+    pub fn reveal_nft(ctx: Context<RevealNft>, uri: String, proof: Vec<[u8; 32]>) -> Result<()> {
+        instructions::reveal_nft(ctx, uri, proof)
+    }
+
+    /// Equip an NFT into another NFT the signer holds
+    pub fn equip_nft(ctx: Context<EquipNft>) -> Result<()> {
+        instructions::equip_nft(ctx)
+    }
+
+    /// Return an equipped NFT to its parent's holder
+    pub fn unequip_nft(ctx: Context<UnequipNft>) -> Result<()> {
+        instructions::unequip_nft(ctx)
+    }
+
+    /// Set whether a parent's children block or join its bridge-out
+    pub fn set_child_bridge_mode(ctx: Context<SetChildBridgeMode>, mode: ChildBridgeMode) -> Result<()> {
+        instructions::set_child_bridge_mode(ctx, mode)
+    }
+
+    /// Create or replace a collection's evolution recipe
+    pub fn set_evolution_recipe(
+        ctx: Context<SetEvolutionRecipe>,
+        recipe_id: u32,
+        params: EvolutionRecipeParams,
+    ) -> Result<()> {
+        instructions::set_evolution_recipe(ctx, recipe_id, params)
+    }
+
+    /// Remove a collection's evolution recipe
+    pub fn remove_evolution_recipe(ctx: Context<RemoveEvolutionRecipe>, recipe_id: u32) -> Result<()> {
+        instructions::remove_evolution_recipe(ctx, recipe_id)
+    }
+
+    /// Burn a recipe's resources to evolve an NFT
+    pub fn evolve_nft<'info>(
+        ctx: Context<'_, '_, 'info, 'info, EvolveNft<'info>>,
+        recipe_id: u32,
+    ) -> Result<()> {
+        instructions::evolve_nft(ctx, recipe_id)
+    }
+
+    /// Make a collection's NFTs event tickets and name their validators
+    pub fn configure_ticketing(ctx: Context<ConfigureTicketing>, params: TicketingParams) -> Result<()> {
+        instructions::configure_ticketing(ctx, params)
+    }
+
+    /// Set the event a ticket NFT admits to
+    pub fn issue_ticket(ctx: Context<IssueTicket>, event_id: u64) -> Result<()> {
+        instructions::issue_ticket(ctx, event_id)
+    }
+
+    /// Check a ticket in, signed by its holder and a validator
+    pub fn check_in(ctx: Context<CheckIn>, event_id: u64) -> Result<()> {
+        instructions::check_in(ctx, event_id)
+    }
+
+    /// Escrow SPL tokens behind an NFT, vesting to its holder
+    pub fn create_vesting_position(
+        ctx: Context<CreateVestingPosition>,
+        total_amount: u64,
+        start_ts: i64,
+        cliff_ts: i64,
+        end_ts: i64,
+    ) -> Result<()> {
+        instructions::create_vesting_position(ctx, total_amount, start_ts, cliff_ts, end_ts)
+    }
+
+    /// Claim an NFT's vested tokens to its holder
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        instructions::claim_vested(ctx)
+    }
+
+    /// Open a proof-of-attendance drop of compressed NFTs for an event
+    pub fn create_attendance_drop(
+        ctx: Context<CreateAttendanceDrop>,
+        event_id: u64,
+        params: AttendanceDropParams,
+    ) -> Result<()> {
+        instructions::create_attendance_drop(ctx, event_id, params)
+    }
+
+    /// Claim a free attendance NFT with an allowlist proof
+    pub fn claim_attendance(ctx: Context<ClaimAttendance>, index: u32, proof: Vec<[u8; 32]>) -> Result<()> {
+        instructions::claim_attendance(ctx, index, proof)
+    }
+
+    /// Set a collection's credential issuers and revocation destination
+    pub fn configure_credentials(
+        ctx: Context<ConfigureCredentials>,
+        params: CredentialProfileParams,
+    ) -> Result<()> {
+        instructions::configure_credentials(ctx, params)
+    }
+
+    /// Issue a soulbound credential about a wallet
+    pub fn issue_credential(
+        ctx: Context<IssueCredential>,
+        subject: Pubkey,
+        schema_hash: [u8; 32],
+        uri: String,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::issue_credential(ctx, subject, schema_hash, uri, expires_at)
+    }
+
+    /// Revoke a credential in the revocation registry
+    pub fn revoke_credential(ctx: Context<RevokeCredential>, reason: u16) -> Result<()> {
+        instructions::revoke_credential(ctx, reason)
+    }
+
+    /// Return whether a credential is valid
+    pub fn verify_credential(ctx: Context<VerifyCredential>) -> Result<CredentialStatusView> {
+        instructions::verify_credential(ctx)
+    }
+
+    /// Offer a swap, escrowing the maker's NFT or tokens
+    pub fn create_swap(
+        ctx: Context<CreateSwap>,
+        swap_id: u64,
+        taker: Pubkey,
+        maker_amount: u64,
+        taker_asset: SwapAsset,
+    ) -> Result<()> {
+        instructions::create_swap(ctx, swap_id, taker, maker_amount, taker_asset)
+    }
+
+    /// Accept a swap, settling both legs atomically
+    pub fn accept_swap(ctx: Context<AcceptSwap>) -> Result<()> {
+        instructions::accept_swap(ctx)
+    }
+
+    /// Cancel an unaccepted swap and refund the maker
+    pub fn cancel_swap(ctx: Context<CancelSwap>) -> Result<()> {
+        instructions::cancel_swap(ctx)
+    }
+
+    /// Escrow an NFT against an EVM NFT under a hashed timelock
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_cross_chain_swap(
+        ctx: Context<CreateCrossChainSwap>,
+        swap_id: u64,
+        counterparty_chain_id: u64,
+        taker: Pubkey,
+        maker_receiver: [u8; 20],
+        remote_asset: RemoteAsset,
+        hashlock: [u8; 32],
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::create_cross_chain_swap(
+            ctx,
+            swap_id,
+            counterparty_chain_id,
+            taker,
+            maker_receiver,
+            remote_asset,
+            hashlock,
+            expires_at,
+        )
+    }
+
+    /// Release a cross-chain swap's NFT to its taker with the secret
+    pub fn redeem_cross_chain_swap(ctx: Context<SettleCrossChainSwap>, secret: [u8; 32]) -> Result<()> {
+        instructions::redeem_cross_chain_swap(ctx, secret)
+    }
+
+    /// Return an expired or reverted cross-chain swap's NFT to its maker
+    pub fn refund_cross_chain_swap(ctx: Context<SettleCrossChainSwap>) -> Result<()> {
+        instructions::refund_cross_chain_swap(ctx)
+    }
+
+    /// Escrow an NFT and request a loan against it
+    pub fn request_loan(ctx: Context<RequestLoan>, terms: LoanTerms) -> Result<()> {
+        instructions::request_loan(ctx, terms)
+    }
+
+    /// Fund a loan request, paying the principal to the borrower
+    pub fn fund_loan(ctx: Context<FundLoan>, expected_terms: LoanTerms) -> Result<()> {
+        instructions::fund_loan(ctx, expected_terms)
+    }
+
+    /// Repay a loan and take the collateral back
+    pub fn repay_loan(ctx: Context<RepayLoan>) -> Result<()> {
+        instructions::repay_loan(ctx)
+    }
+
+    /// Take an overdue loan's collateral as the lender
+    pub fn liquidate_loan(ctx: Context<LiquidateLoan>) -> Result<()> {
+        instructions::liquidate_loan(ctx)
+    }
+
+    /// Withdraw an unfunded loan request
+    pub fn cancel_loan_request(ctx: Context<CancelLoanRequest>) -> Result<()> {
+        instructions::cancel_loan_request(ctx)
+    }
+
+    /// Set the ZetaChain monitoring contract security events are exported to
+    pub fn configure_security_export(
+        ctx: Context<ConfigureSecurityExport>,
+        params: SecurityExportParams,
+    ) -> Result<()> {
+        instructions::configure_security_export(ctx, params)
+    }
+
+    /// Send a critical security event to the ZetaChain monitoring contract
+    pub fn export_security_event(
+        ctx: Context<ExportSecurityEvent>,
+        kind: SecurityEventKind,
+        chain_id: u64,
+        value: u64,
+        occurred_at: i64,
+    ) -> Result<()> {
+        instructions::export_security_event(ctx, kind, chain_id, value, occurred_at)
+    }
+
+    /// Apply a TSS-signed corridor pause or resume from another chain
+    pub fn apply_corridor_pause(
+        ctx: Context<ApplyCorridorPause>,
+        chain_id: u64,
+        source_chain_id: u64,
+        message: Vec<u8>,
+        signature: [u8; 64],
+        recovery_id: u8,
+    ) -> Result<()> {
+        instructions::apply_corridor_pause(ctx, chain_id, source_chain_id, message, signature, recovery_id)
+    }
+
+    /// Pause or resume a corridor and ask the chain's counterpart to match
+    pub fn request_corridor_pause(
+        ctx: Context<RequestCorridorPause>,
+        chain_id: u64,
+        direction: CorridorDirection,
+        paused: bool,
+    ) -> Result<()> {
+        instructions::request_corridor_pause(ctx, chain_id, direction, paused)
+    }
+
+    /// Put a circuit breaker in front of a registered chain
+    pub fn initialize_circuit_breaker(
+        ctx: Context<InitializeCircuitBreaker>,
+        chain_id: u64,
+        config: Option<CircuitConfig>,
+    ) -> Result<()> {
+        instructions::initialize_circuit_breaker(ctx, chain_id, config)
+    }
+
+    /// Force a chain's circuit breaker open to traffic, or clear the override
+    pub fn set_circuit_breaker_override(
+        ctx: Context<SetCircuitBreakerOverride>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_circuit_breaker_override(ctx, enabled)
+    }
+
+    /// Create the protocol's incident mode and name its emergency role
+    pub fn initialize_incident_mode(
+        ctx: Context<InitializeIncidentMode>,
+        emergency_authority: Pubkey,
+        config: Option<IncidentConfig>,
+    ) -> Result<()> {
+        instructions::initialize_incident_mode(ctx, emergency_authority, config)
+    }
+
+    /// Raise or lower the incident level
+    pub fn set_incident_level(ctx: Context<ManageIncidentMode>, level: IncidentLevel) -> Result<()> {
+        instructions::set_incident_level(ctx, level)
+    }
+
+    /// Flag a mint to be frozen during lockdown
+    pub fn flag_incident_mint(ctx: Context<ManageIncidentMode>, mint: Pubkey) -> Result<()> {
+        instructions::flag_incident_mint(ctx, mint)
+    }
+
+    /// Take a mint off the lockdown list
+    pub fn unflag_incident_mint(ctx: Context<ManageIncidentMode>, mint: Pubkey) -> Result<()> {
+        instructions::unflag_incident_mint(ctx, mint)
+    }
+
+    /// Record incident de-escalation steps whose timers have run out
+    pub fn refresh_incident_mode(ctx: Context<RefreshIncidentMode>) -> Result<()> {
+        instructions::refresh_incident_mode(ctx)
+    }
+
+    /// Register the first observer validator set (governance only)
+    pub fn initialize_validator_set(
+        ctx: Context<InitializeValidatorSet>,
+        validators: Vec<[u8; 20]>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::initialize_validator_set(ctx, validators, threshold)
+    }
+
+    /// Replace the observer validator set from an executed proposal
+    pub fn apply_validator_set_proposal(ctx: Context<ApplyValidatorSetProposal>) -> Result<()> {
+        instructions::apply_validator_set_proposal(ctx)
+    }
+
+    /// Record a K-of-N observer attestation of an inbound message
+    pub fn attest_inbound_message(
+        ctx: Context<AttestInboundMessage>,
+        source_chain_id: u64,
+        sender: [u8; 20],
+        message: Vec<u8>,
+        signatures: Vec<QuorumSignature>,
+    ) -> Result<()> {
+        instructions::attest_inbound_message(ctx, source_chain_id, sender, message, signatures)
+    }
+
+    /// Create a token bucket rate limiting a wallet, chain or collection
+    pub fn initialize_rate_limit(
+        ctx: Context<InitializeRateLimit>,
+        subject: RateLimitSubject,
+        config: Option<RateLimitConfig>,
+    ) -> Result<()> {
+        instructions::initialize_rate_limit(ctx, subject, config)
+    }
+
+    /// Change a token bucket's capacity, refill rate or enforcement
+    pub fn update_rate_limit(ctx: Context<UpdateRateLimit>, config: RateLimitConfig) -> Result<()> {
+        instructions::update_rate_limit(ctx, config)
+    }
+
+    /// Create the fraud engine that scores outbound transfers
+    pub fn initialize_fraud_engine(
+        ctx: Context<InitializeFraudEngine>,
+        config: Option<FraudConfig>,
+    ) -> Result<()> {
+        instructions::initialize_fraud_engine(ctx, config)
+    }
+
+    /// Apply the fraud config of an executed governance proposal
+    pub fn apply_fraud_config_proposal(ctx: Context<ApplyFraudConfigProposal>) -> Result<()> {
+        instructions::apply_fraud_config_proposal(ctx)
+    }
+
+    /// Approve an external program as a lifecycle hook
+    pub fn whitelist_hook_program(
+        ctx: Context<WhitelistHookProgram>,
+        max_compute_units: u32,
+    ) -> Result<()> {
+        instructions::whitelist_hook_program(ctx, max_compute_units)
+    }
+
+    /// Register a whitelisted hook on a collection
+    pub fn add_collection_hook(
+        ctx: Context<AddCollectionHook>,
+        event_mask: u8,
+        compute_allowance: u32,
+        account_count: u8,
+    ) -> Result<()> {
+        instructions::add_collection_hook(ctx, event_mask, compute_allowance, account_count)
+    }
+
+    /// Remove a hook from a collection
+    pub fn remove_collection_hook(
+        ctx: Context<RemoveCollectionHook>,
+        program_id: Pubkey,
+    ) -> Result<()> {
+        instructions::remove_collection_hook(ctx, program_id)
+    }
+
+    /// Confirm an outbound transfer arrived and burn its receipt
+    pub fn confirm_outbound_transfer(ctx: Context<ConfirmOutboundTransfer>) -> Result<()> {
+        instructions::confirm_outbound_transfer(ctx)
+    }
+
+    /// Cancel an unconfirmed transfer after expiry using its receipt
+    pub fn cancel_expired_transfer(ctx: Context<CancelExpiredTransfer>) -> Result<()> {
+        instructions::cancel_expired_transfer(ctx)
+    }
+
+    /// Lock an NFT and schedule its outbound transfer after a review delay
+    pub fn schedule_transfer(
+        ctx: Context<ScheduleTransfer>,
+        destination_chain_id: u64,
+        recipient: Vec<u8>,
+        gas_limit: u64,
+        delay_seconds: i64,
+    ) -> Result<()> {
+        instructions::schedule_transfer(ctx, destination_chain_id, recipient, gas_limit, delay_seconds)
+    }
+
+    /// Cancel a scheduled transfer during its review window
+    pub fn cancel_scheduled_transfer(ctx: Context<CancelScheduledTransfer>) -> Result<()> {
+        instructions::cancel_scheduled_transfer(ctx)
+    }
+
+    /// Create the transfer insurance pool
+    pub fn initialize_insurance_pool(
+        ctx: Context<InitializeInsurancePool>,
+        premium_lamports: u64,
+        payout_lamports: u64,
+    ) -> Result<()> {
+        instructions::initialize_insurance_pool(ctx, premium_lamports, payout_lamports)
+    }
+
+    /// Update insurance premium and payout parameters
+    pub fn update_insurance_parameters(
+        ctx: Context<UpdateInsuranceParameters>,
+        premium_lamports: u64,
+        payout_lamports: u64,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::update_insurance_parameters(ctx, premium_lamports, payout_lamports, enabled)
+    }
+
+    /// Pay the insurance premium for an in-flight transfer
+    pub fn insure_transfer(ctx: Context<InsureTransfer>) -> Result<()> {
+        instructions::insure_transfer(ctx)
+    }
+
+    /// Claim the insurance payout for a transfer recovery gave up on
+    pub fn claim_insurance(ctx: Context<ClaimInsurance>) -> Result<()> {
+        instructions::claim_insurance(ctx)
+    }
+
+    /// Create the relayer registry
+    pub fn initialize_relayer_registry(
+        ctx: Context<InitializeRelayerRegistry>,
+        min_stake: u64,
+        reward_per_message: u64,
+        slash_bps: u16,
+        unbonding_period: i64,
+    ) -> Result<()> {
+        instructions::initialize_relayer_registry(
+            ctx,
+            min_stake,
+            reward_per_message,
+            slash_bps,
+            unbonding_period,
+        )
+    }
+
+    /// Add lamports to the relayer reward pool
+    pub fn fund_relayer_rewards(ctx: Context<FundRelayerRewards>, amount: u64) -> Result<()> {
+        instructions::fund_relayer_rewards(ctx, amount)
+    }
+
+    /// Stake SOL to become a relayer
+    pub fn register_relayer(ctx: Context<RegisterRelayer>, stake: u64) -> Result<()> {
+        instructions::register_relayer(ctx, stake)
+    }
+
+    /// Add stake to a relayer, reactivating one slashed below the minimum
+    pub fn top_up_relayer_stake(ctx: Context<TopUpRelayerStake>, amount: u64) -> Result<()> {
+        instructions::top_up_relayer_stake(ctx, amount)
+    }
+
+    /// Stop relaying and start the unbonding period
+    pub fn begin_relayer_unbonding(ctx: Context<BeginRelayerUnbonding>) -> Result<()> {
+        instructions::begin_relayer_unbonding(ctx)
+    }
+
+    /// Withdraw relayer stake after unbonding
+    pub fn withdraw_relayer_stake(ctx: Context<WithdrawRelayerStake>) -> Result<()> {
+        instructions::withdraw_relayer_stake(ctx)
+    }
+
+    /// Create a source chain's inbound queue at its starting sequence
+    /// (program authority only)
+    pub fn initialize_inbound_queue(
+        ctx: Context<InitializeInboundQueue>,
+        source_chain_id: u64,
+        start_sequence: u64,
+    ) -> Result<()> {
+        instructions::initialize_inbound_queue(ctx, source_chain_id, start_sequence)
+    }
+
+    /// Submit a TSS-signed inbound message as a staked relayer
+    pub fn submit_relayed_message(
+        ctx: Context<SubmitRelayedMessage>,
+        source_chain_id: u64,
+        sequence: u64,
+        sender: [u8; 20],
+        message: Vec<u8>,
+        signature: [u8; 64],
+        recovery_id: u8,
+    ) -> Result<()> {
+        instructions::submit_relayed_message(
+            ctx,
+            source_chain_id,
+            sequence,
+            sender,
+            message,
+            signature,
+            recovery_id,
+        )
+    }
+
+    /// Claim the relayer reward for a message on_call executed
+    pub fn claim_relayer_reward(ctx: Context<ClaimRelayerReward>) -> Result<()> {
+        instructions::claim_relayer_reward(ctx)
+    }
+
+    /// Create the compliance config and assign the compliance officer
+    pub fn initialize_compliance_config(
+        ctx: Context<InitializeComplianceConfig>,
+        compliance_officer: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize_compliance_config(ctx, compliance_officer)
+    }
+
+    /// Replace the compliance officer
+    pub fn set_compliance_officer(
+        ctx: Context<SetComplianceOfficer>,
+        compliance_officer: Pubkey,
+    ) -> Result<()> {
+        instructions::set_compliance_officer(ctx, compliance_officer)
+    }
+
+    /// Freeze an NFT under legal hold (compliance officer)
+    pub fn freeze_nft(ctx: Context<ComplianceAction>, reason_hash: [u8; 32]) -> Result<()> {
+        instructions::freeze_nft(ctx, reason_hash)
+    }
+
+    /// Release an NFT from legal hold (compliance officer)
+    pub fn unfreeze_nft(ctx: Context<ComplianceAction>, reason_hash: [u8; 32]) -> Result<()> {
+        instructions::unfreeze_nft(ctx, reason_hash)
+    }
+
+    /// Create the light client config and assign the header relayer
+    pub fn initialize_light_client(
+        ctx: Context<InitializeLightClient>,
+        header_relayer: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize_light_client(ctx, header_relayer)
+    }
+
+    /// Replace the header relayer
+    pub fn set_header_relayer(ctx: Context<SetHeaderRelayer>, header_relayer: Pubkey) -> Result<()> {
+        instructions::set_header_relayer(ctx, header_relayer)
+    }
+
+    /// Post a source-chain block's bridge event root (header relayer)
+    pub fn post_block_header(
+        ctx: Context<PostBlockHeader>,
+        chain_id: u64,
+        block_number: u64,
+        events_root: [u8; 32],
+    ) -> Result<()> {
+        instructions::post_block_header(ctx, chain_id, block_number, events_root)
+    }
+
+    /// Require event proofs for a collection's inbound messages
+    pub fn set_proof_policy(
+        ctx: Context<SetProofPolicy>,
+        collection_mint: Pubkey,
+        required: bool,
+    ) -> Result<()> {
+        instructions::set_proof_policy(ctx, collection_mint, required)
+    }
+
+    /// Prove an inbound bridge event against a posted block root
+    pub fn verify_event_proof(
+        ctx: Context<VerifyEventProof>,
+        chain_id: u64,
+        block_number: u64,
+        sender: [u8; 20],
+        message: Vec<u8>,
+        proof: Vec<[u8; 32]>,
+        leaf_index: u64,
+    ) -> Result<()> {
+        instructions::verify_event_proof(ctx, chain_id, block_number, sender, message, proof, leaf_index)
+    }
+
+    /// Set the price-feed authorities and price feed of a collection's floor
+    pub fn configure_collection_floor(
+        ctx: Context<ConfigureCollectionFloor>,
+        collection_mint: Pubkey,
+        params: CollectionFloorParams,
+    ) -> Result<()> {
+        instructions::configure_collection_floor(ctx, collection_mint, params)
+    }
+
+    /// Post a collection's floor value (price-feed authority)
+    pub fn post_floor_price(ctx: Context<PostFloorPrice>, floor_value: u64) -> Result<()> {
+        instructions::post_floor_price(ctx, floor_value)
+    }
+
+    /// Sync a collection's floor value from its price feed
+    pub fn sync_floor_price(ctx: Context<SyncFloorPrice>) -> Result<()> {
+        instructions::sync_floor_price(ctx)
+    }
+
+
+
+
+
+
+    /// Create or replace a collection's mint and bridging policy
+    pub fn set_collection_config(
+        ctx: Context<SetCollectionConfig>,
+        params: CollectionConfigParams,
+    ) -> Result<()> {
+        instructions::set_collection_config(ctx, params)
+    }
+
+    /// Lower a collection's supply cap (never raises it)
+    pub fn reduce_max_supply(ctx: Context<ReduceMaxSupply>, new_max_supply: u64) -> Result<()> {
+        instructions::reduce_max_supply(ctx, new_max_supply)
+    }
+
+    /// Append a phase to a collection's mint schedule
+    pub fn add_mint_phase(ctx: Context<AddMintPhase>, params: MintPhaseParams) -> Result<()> {
+        instructions::add_mint_phase(ctx, params)
+    }
+
+    /// Place an NFT in custody for a wallet-less recipient to claim
+    pub fn escrow_nft_for_claim(
+        ctx: Context<EscrowNftForClaim>,
+        claim_key: Pubkey,
+        claims_service: Option<Pubkey>,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::escrow_nft_for_claim(ctx, claim_key, claims_service, expires_at)
+    }
+
+    /// Claim an escrowed NFT signed by its claim key or the claims service
+    pub fn claim_escrowed_nft(ctx: Context<ClaimEscrowedNft>) -> Result<()> {
+        instructions::claim_escrowed_nft(ctx)
+    }
+
+    /// Return an expired claim escrow's NFT to its depositor
+    pub fn cancel_claim_escrow(ctx: Context<CancelClaimEscrow>) -> Result<()> {
+        instructions::cancel_claim_escrow(ctx)
+    }
+
+    /// Commit to a collection's holder snapshot at a past slot
+    pub fn commit_holder_snapshot(
+        ctx: Context<CommitHolderSnapshot>,
+        slot: u64,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::commit_holder_snapshot(ctx, slot, commitment)
+    }
+
+    /// Reveal a committed holder snapshot's Merkle root
+    pub fn reveal_holder_snapshot(
+        ctx: Context<RevealHolderSnapshot>,
+        holders_root: [u8; 32],
+        holder_count: u32,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_holder_snapshot(ctx, holders_root, holder_count, salt)
+    }
+
+    /// Permanently burn an NFT, updating burn statistics
+    pub fn burn_nft(ctx: Context<BurnNft>) -> Result<()> {
+        instructions::burn_nft(ctx)
+    }
+
+    /// Read a collection's and a wallet's burn totals
+    pub fn get_burn_stats(ctx: Context<GetBurnStats>) -> Result<BurnStatsView> {
+        instructions::get_burn_stats(ctx)
+    }
+
+    /// Lock all outbound transfers and bridges from the signer's wallet
+    pub fn lock_my_transfers(ctx: Context<LockMyTransfers>, unlock_delay: i64) -> Result<()> {
+        instructions::lock_my_transfers(ctx, unlock_delay)
+    }
+
+    /// Start the timelocked unlock of the signer's wallet
+    pub fn unlock_my_transfers(ctx: Context<UnlockMyTransfers>) -> Result<()> {
+        instructions::unlock_my_transfers(ctx)
+    }
+
+    /// Register a scoped, expiring operator key for the signer's NFTs
+    pub fn register_operator(
+        ctx: Context<RegisterOperator>,
+        operator: Pubkey,
+        scopes: u8,
+        bridge_chain_id: Option<u64>,
+        expires_slot: u64,
+    ) -> Result<()> {
+        instructions::register_operator(ctx, operator, scopes, bridge_chain_id, expires_slot)
+    }
+
+    /// Revoke an operator session
+    pub fn revoke_operator(ctx: Context<RevokeOperator>) -> Result<()> {
+        instructions::revoke_operator(ctx)
+    }
+
+    /// Estimate the fees and deposits of sending an NFT to another chain
+    pub fn estimate_bridge_cost(
+        ctx: Context<EstimateBridgeCost>,
+        destination_chain_id: u64,
+        gas_limit: u64,
+        gas_payment: GasPayment,
+    ) -> Result<BridgeCostEstimate> {
+        instructions::estimate_bridge_cost(ctx, destination_chain_id, gas_limit, gas_payment)
+    }
+
+    /// Hand a mint's authority from the program PDA to a new key (governance only)
+    pub fn hand_over_mint_authority(
+        ctx: Context<HandOverMintAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::hand_over_mint_authority(ctx, new_authority)
+    }
+
+    /// Create the vault that sponsors inbound account rent (program authority only)
+    pub fn initialize_rent_vault(ctx: Context<InitializeRentVault>) -> Result<()> {
+        instructions::initialize_rent_vault(ctx)
+    }
+
+    /// Deposit lamports sponsoring inbound rent for a source chain
+    pub fn fund_rent_vault(ctx: Context<FundRentVault>, chain_id: u64, amount: u64) -> Result<()> {
+        instructions::fund_rent_vault(ctx, chain_id, amount)
+    }
+
+    /// Turn inbound rent sponsorship on or off (program authority only)
+    pub fn set_rent_sponsorship(ctx: Context<SetRentSponsorship>, enabled: bool) -> Result<()> {
+        instructions::set_rent_sponsorship(ctx, enabled)
+    }
+
+    /// Register the automation thread that runs privileged cranks (program authority only)
+    pub fn register_crank_operator(ctx: Context<RegisterCrankOperator>, operator: Pubkey) -> Result<()> {
+        instructions::register_crank_operator(ctx, operator)
+    }
+
+    /// Take a periodic state checkpoint once it is due (crank operator only)
+    pub fn crank_state_checkpoint(
+        ctx: Context<CrankStateCheckpoint>,
+        state_metrics: recovery::StateMetrics,
+    ) -> Result<()> {
+        instructions::crank_state_checkpoint(ctx, state_metrics)
+    }
+
+
+    /// Start a due retry attempt for the off-chain retrier (crank operator only)
+    pub fn crank_retry_session(ctx: Context<CrankRetrySession>) -> Result<()> {
+        instructions::crank_retry_session(ctx)
+    }
+
+    /// Create the retry queue (program authority only)
+    pub fn initialize_retry_queue(ctx: Context<InitializeRetryQueue>) -> Result<()> {
+        instructions::initialize_retry_queue(ctx)
+    }
+
+    /// Start due retry attempts from the queue within the compute budget.
+    /// Permissionless; due sessions are passed as remaining accounts.
+    pub fn process_due_retries<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProcessDueRetries<'info>>,
+    ) -> Result<()> {
+        instructions::process_due_retries(ctx)
+    }
+
+    /// Create the retry manager (program authority only)
+    pub fn initialize_retry_manager(
+        ctx: Context<InitializeRetryManager>,
+        config: Option<recovery::RetryConfig>,
+    ) -> Result<()> {
+        instructions::initialize_retry_manager(ctx, config)
+    }
+
+    /// Schedule a failed transaction for retry (registered retrier only)
+    pub fn schedule_retry_session(
+        ctx: Context<ScheduleRetrySession>,
+        original_tx_signature: String,
+        failure_reason: recovery::RetryFailureReason,
+        compute_unit_limit: u32,
+        priority_fee_micro_lamports: u64,
+        config: Option<recovery::RetryConfig>,
+    ) -> Result<()> {
+        instructions::schedule_retry_session(
+            ctx,
+            original_tx_signature,
+            failure_reason,
+            compute_unit_limit,
+            priority_fee_micro_lamports,
+            config,
+        )
+    }
+
+    /// Set the retrier allowed to report attempt outcomes (retry manager authority only)
+    pub fn set_retry_reporter(ctx: Context<SetRetryReporter>, reporter: Pubkey) -> Result<()> {
+        instructions::set_retry_reporter(ctx, reporter)
+    }
+
+    /// Record the outcome of a resubmitted retry attempt (registered retrier only)
+    pub fn report_retry_outcome(
+        ctx: Context<ReportRetryOutcome>,
+        report: recovery::RetryOutcomeReport,
+    ) -> Result<()> {
+        instructions::report_retry_outcome(ctx, report)
+    }
+
+    /// Read an outbound transfer's status through return data
+    pub fn get_transfer_status(ctx: Context<GetTransferStatus>) -> Result<TransferStatusView> {
+        instructions::get_transfer_status(ctx)
+    }
+
+    /// Read an NFT's origin chain and sender through return data
+    pub fn get_nft_origin(ctx: Context<GetNftOrigin>) -> Result<NftOriginView> {
+        instructions::get_nft_origin(ctx)
+    }
+
+    /// Read a registered chain's settings and 24h volume through return data
+    pub fn get_chain_config(ctx: Context<GetChainConfig>) -> Result<ChainConfigView> {
+        instructions::get_chain_config(ctx)
+    }
+
+    /// Read a collection's lifetime royalty accounting through return data
+    pub fn get_royalty_accounting(ctx: Context<GetRoyaltyAccounting>) -> Result<RoyaltyAccountingView> {
+        instructions::get_royalty_accounting(ctx)
+    }
+}
@@ -0,0 +1,123 @@
+//! Backup/Restore System for Universal NFT Protocol
+//! Exports critical PDAs as paged snapshot events that an off-chain archiver
+//! stores, and writes archived pages back during a recovery session
+
+use anchor_lang::prelude::*;
+use sha2::{Digest, Sha256};
+use crate::errors::UniversalNftError;
+use crate::recovery::state_recovery::{StateCheckpoint, CHECKPOINT_ACCOUNT_SEEDS};
+use crate::utils::MerkleUtils;
+
+/// Bytes of account data carried by a single snapshot page event
+pub const SNAPSHOT_PAGE_SIZE: usize = 768;
+
+/// Maximum pages exported by a single export instruction
+pub const MAX_PAGES_PER_EXPORT: u8 = 4;
+
+/// Maximum bytes written by a single restore instruction
+pub const MAX_RESTORE_CHUNK: usize = 900;
+
+/// Archived account data staged for a restore. Chunks are written here
+/// first; the target is only overwritten once the whole buffer matches the
+/// target's leaf in a state checkpoint.
+#[account]
+pub struct RestoreBuffer {
+    /// Account being restored
+    pub target: Pubkey,
+    /// Checkpoint the restored data must match
+    pub checkpoint_id: u64,
+    /// Target's leaf position in that checkpoint
+    pub leaf_index: u8,
+    /// Program authority that opened the restore
+    pub authority: Pubkey,
+    /// PDA bump
+    pub bump: u8,
+    /// Staged account data, sized to the target
+    pub data: Vec<u8>,
+}
+
+impl RestoreBuffer {
+    /// Account space for a buffer staging `data_len` bytes
+    pub fn space(data_len: usize) -> usize {
+        8 +     // discriminator
+        32 +    // target
+        8 +     // checkpoint_id
+        1 +     // leaf_index
+        32 +    // authority
+        1 +     // bump
+        4 + data_len // data
+    }
+}
+
+pub struct BackupRestore;
+
+impl BackupRestore {
+    /// Number of pages needed to export `data_len` bytes
+    pub fn page_count(data_len: usize) -> u32 {
+        data_len.div_ceil(SNAPSHOT_PAGE_SIZE).max(1) as u32
+    }
+
+    /// Byte range covered by `page`
+    pub fn page_bounds(data_len: usize, page: u32) -> Result<(usize, usize)> {
+        require!(
+            page < Self::page_count(data_len),
+            UniversalNftError::InvalidSnapshotPage
+        );
+
+        let start = page as usize * SNAPSHOT_PAGE_SIZE;
+        let end = (start + SNAPSHOT_PAGE_SIZE).min(data_len);
+        Ok((start, end))
+    }
+
+    /// Sha256 of the full account data, used to verify reassembled snapshots
+    pub fn data_hash(data: &[u8]) -> [u8; 32] {
+        Sha256::digest(data).into()
+    }
+
+    /// Check that an account is a program-owned member of the critical set
+    pub fn validate_backup_target(account: &AccountInfo) -> Result<()> {
+        require_keys_eq!(
+            *account.owner,
+            crate::ID,
+            UniversalNftError::InvalidBackupTarget
+        );
+
+        let is_critical = CHECKPOINT_ACCOUNT_SEEDS.iter().any(|seed| {
+            Pubkey::find_program_address(&[seed], &crate::ID).0 == account.key()
+        });
+        require!(is_critical, UniversalNftError::InvalidBackupTarget);
+
+        Ok(())
+    }
+
+    /// Write an archived chunk into a restore buffer
+    pub fn write_restore_chunk(data: &mut [u8], offset: u32, chunk: &[u8]) -> Result<()> {
+        require!(
+            !chunk.is_empty() && chunk.len() <= MAX_RESTORE_CHUNK,
+            UniversalNftError::InvalidRestoreChunk
+        );
+
+        let start = offset as usize;
+        let end = start.checked_add(chunk.len())
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        require!(end <= data.len(), UniversalNftError::InvalidRestoreChunk);
+
+        data[start..end].copy_from_slice(chunk);
+        Ok(())
+    }
+
+    /// Check staged data for `target` against the target's leaf in a checkpoint
+    pub fn verify_against_checkpoint(
+        target: &AccountInfo,
+        data: &[u8],
+        checkpoint: &StateCheckpoint,
+        leaf_index: u8,
+    ) -> Result<()> {
+        let expected = checkpoint.account_leaves
+            .get(leaf_index as usize)
+            .ok_or(UniversalNftError::InvalidCheckpointAccounts)?;
+        let leaf = MerkleUtils::hash_account_leaf(target.key, target.owner, data);
+        require!(leaf == *expected, UniversalNftError::RestoreHashMismatch);
+        Ok(())
+    }
+}
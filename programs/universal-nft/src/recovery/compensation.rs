@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+use crate::errors::UniversalNftError;
+use crate::recovery::error_recovery::{CompensationType, RecoveryResult, RecoverySession};
+use crate::state::{CrossChainTransfer, GasPayment, TransferStatus};
+
+/// Compensation Vault for Universal NFT Protocol
+/// Holds treasury-funded lamports that refund the destination gas of
+/// transfers whose recovery session ended in failure
+#[account]
+#[derive(InitSpace)]
+pub struct CompensationVault {
+    /// Vault authority (recovery manager authority)
+    pub authority: Pubkey,
+    /// Treasury account allowed to fund this vault
+    pub treasury: Pubkey,
+    /// Maximum lamports a single user can claim over the vault lifetime
+    pub per_user_cap: u64,
+    /// Total lamports funded by the treasury
+    pub total_funded: u64,
+    /// Total lamports paid out as compensation
+    pub total_paid: u64,
+    /// Number of compensation claims paid
+    pub claims_paid: u64,
+    /// Whether claims are currently accepted
+    pub claims_enabled: bool,
+    /// Vault creation timestamp
+    pub created_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Per-user compensation ledger used to enforce the vault's per-user cap
+#[account]
+#[derive(InitSpace)]
+pub struct UserCompensationLedger {
+    /// User receiving compensation
+    pub user: Pubkey,
+    /// Total lamports claimed by this user
+    pub total_claimed: u64,
+    /// Number of claims made by this user
+    pub claim_count: u32,
+    /// Last claim timestamp
+    pub last_claim_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Receipt proving a failed transfer's compensation has been paid
+#[account]
+#[derive(InitSpace)]
+pub struct CompensationReceipt {
+    /// Failed transfer the compensation refunds
+    pub transfer: Pubkey,
+    /// User who received the compensation
+    pub user: Pubkey,
+    /// Compensation type paid
+    pub compensation_type: CompensationType,
+    /// Lamports paid
+    pub amount: u64,
+    /// Claim timestamp
+    pub claimed_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl CompensationVault {
+    pub const INIT_SPACE: usize =
+        32 +    // authority
+        32 +    // treasury
+        8 +     // per_user_cap
+        8 +     // total_funded
+        8 +     // total_paid
+        8 +     // claims_paid
+        1 +     // claims_enabled
+        8 +     // created_at
+        1;      // bump
+
+    /// Initialize the compensation vault
+    pub fn initialize(
+        &mut self,
+        authority: Pubkey,
+        treasury: Pubkey,
+        per_user_cap: u64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(per_user_cap > 0, UniversalNftError::InvalidCompensationAmount);
+
+        self.authority = authority;
+        self.treasury = treasury;
+        self.per_user_cap = per_user_cap;
+        self.total_funded = 0;
+        self.total_paid = 0;
+        self.claims_paid = 0;
+        self.claims_enabled = true;
+        self.created_at = Clock::get()?.unix_timestamp;
+        self.bump = bump;
+
+        log!("Compensation vault initialized");
+        log!("Per-user cap: {} lamports", per_user_cap);
+
+        Ok(())
+    }
+
+    /// Record lamports moved into the vault from the treasury
+    pub fn record_funding(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, UniversalNftError::InvalidCompensationAmount);
+
+        self.total_funded = self.total_funded.checked_add(amount)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+        log!("Compensation vault funded: {} lamports", amount);
+        Ok(())
+    }
+
+    /// Validate a claim against the transfer's recovery session and the
+    /// user's ledger, returning the lamports to pay out
+    pub fn validate_claim(
+        &self,
+        transfer: &CrossChainTransfer,
+        session: &RecoverySession,
+        ledger: &UserCompensationLedger,
+        claimant: Pubkey,
+    ) -> Result<(CompensationType, u64)> {
+        require!(self.claims_enabled, UniversalNftError::CompensationClaimsDisabled);
+        require!(transfer.initiator == claimant, UniversalNftError::Unauthorized);
+        require!(
+            transfer.status != TransferStatus::Completed,
+            UniversalNftError::InvalidTransferStatus
+        );
+
+        // Only a session that gave up on the transfer owes a refund
+        require!(
+            matches!(
+                session.final_result()?,
+                RecoveryResult::CompensatedFailure | RecoveryResult::UnrecoverableFailure
+            ),
+            UniversalNftError::NoCompensationOwed
+        );
+
+        // The vault only holds lamports; gas paid in ZRC-20 tokens is not refunded here
+        require!(
+            transfer.gas_payment == GasPayment::Sol,
+            UniversalNftError::UnsupportedCompensationType
+        );
+        require!(transfer.gas_fee > 0, UniversalNftError::NoCompensationOwed);
+
+        let claimed_after = ledger.total_claimed.checked_add(transfer.gas_fee)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        require!(
+            claimed_after <= self.per_user_cap,
+            UniversalNftError::CompensationCapExceeded
+        );
+
+        Ok((CompensationType::FeeRefund, transfer.gas_fee))
+    }
+
+    /// Record a paid claim
+    pub fn record_claim(
+        &mut self,
+        ledger: &mut UserCompensationLedger,
+        amount: u64,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        ledger.total_claimed = ledger.total_claimed.checked_add(amount)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        ledger.claim_count = ledger.claim_count.checked_add(1)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        ledger.last_claim_at = now;
+
+        self.total_paid = self.total_paid.checked_add(amount)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        self.claims_paid = self.claims_paid.checked_add(1)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+
+    /// Remaining claimable amount for a user
+    pub fn remaining_for_user(&self, ledger: &UserCompensationLedger) -> u64 {
+        self.per_user_cap.saturating_sub(ledger.total_claimed)
+    }
+}
+
+impl UserCompensationLedger {
+    pub const INIT_SPACE: usize =
+        32 +    // user
+        8 +     // total_claimed
+        4 +     // claim_count
+        8 +     // last_claim_at
+        1;      // bump
+}
+
+impl CompensationReceipt {
+    pub const INIT_SPACE: usize =
+        32 +    // transfer
+        32 +    // user
+        1 +     // compensation_type (enum)
+        8 +     // amount
+        8 +     // claimed_at
+        1;      // bump
+}
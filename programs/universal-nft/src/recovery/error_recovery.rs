@@ -54,6 +54,7 @@ pub struct RecoverySession {
     /// Session completion timestamp
     pub completed_at: Option<i64>,
     /// Recovery actions taken
+    #[max_len(10)]
     pub actions_taken: Vec<RecoveryAction>,
     /// Final outcome
     pub outcome: Option<RecoveryOutcome>,
@@ -63,7 +64,7 @@ pub struct RecoverySession {
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug, InitSpace)]
 pub enum ErrorType {
     TransactionFailed,
     NetworkTimeout,
@@ -79,7 +80,7 @@ pub enum ErrorType {
     SystemOverload,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug, InitSpace)]
 pub enum RecoveryStrategy {
     /// Simple retry with exponential backoff
     ExponentialBackoff,
@@ -99,9 +100,10 @@ pub enum RecoveryStrategy {
     GracefulDegradation,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct OperationContext {
     /// Operation type that failed
+    #[max_len(32)]
     pub operation_type: String,
     /// User who initiated the operation
     pub user: Pubkey,
@@ -110,6 +112,7 @@ pub struct OperationContext {
     /// Target chain (for cross-chain operations)
     pub target_chain: Option<u64>,
     /// Transaction signature that failed
+    #[max_len(88)]
     pub failed_signature: Option<String>,
     /// Compute units consumed before failure
     pub compute_units_used: u32,
@@ -117,7 +120,7 @@ pub struct OperationContext {
     pub fees_paid: u64,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum RecoveryStatus {
     InProgress,
     Successful,
@@ -127,13 +130,14 @@ pub enum RecoveryStatus {
     TimedOut,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct RecoveryAction {
     /// Action type taken
     pub action_type: ActionType,
     /// Action timestamp
     pub timestamp: i64,
     /// Action parameters
+    #[max_len(64)]
     pub parameters: String,
     /// Action result
     pub result: ActionResult,
@@ -141,7 +145,7 @@ pub struct RecoveryAction {
     pub compute_units: u32,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum ActionType {
     RetryTransaction,
     AdjustComputeLimit,
@@ -155,7 +159,7 @@ pub enum ActionType {
     EscalateToManual,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum ActionResult {
     Success,
     PartialSuccess,
@@ -163,19 +167,21 @@ pub enum ActionResult {
     Skipped,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct RecoveryOutcome {
     /// Final result of recovery
     pub result: RecoveryResult,
     /// New transaction signature (if successful)
+    #[max_len(88)]
     pub new_signature: Option<String>,
     /// Compensation provided to user
     pub compensation: Option<Compensation>,
     /// Lessons learned for future improvements
+    #[max_len(128)]
     pub lessons_learned: String,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug, InitSpace)]
 pub enum RecoveryResult {
     FullRecovery,
     PartialRecovery,
@@ -183,7 +189,7 @@ pub enum RecoveryResult {
     UnrecoverableFailure,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct Compensation {
     /// Compensation type
     pub compensation_type: CompensationType,
@@ -192,10 +198,11 @@ pub struct Compensation {
     /// Token mint for compensation (None for SOL)
     pub token_mint: Option<Pubkey>,
     /// Reason for compensation
+    #[max_len(64)]
     pub reason: String,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum CompensationType {
     FeeRefund,
     TokenCompensation,
@@ -203,7 +210,7 @@ pub enum CompensationType {
     PriorityAccess,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct ResourceUsage {
     /// Total compute units consumed
     pub compute_units: u64,
@@ -215,6 +222,16 @@ pub struct ResourceUsage {
     pub network_requests: u32,
 }
 
+impl RecoverySession {
+    /// Final result of the session, once it has completed
+    pub fn final_result(&self) -> Result<&RecoveryResult> {
+        match (&self.status, &self.outcome) {
+            (RecoveryStatus::InProgress, _) | (_, None) => err!(UniversalNftError::RecoverySessionNotCompleted),
+            (_, Some(outcome)) => Ok(&outcome.result),
+        }
+    }
+}
+
 impl ErrorRecoveryManager {
     pub const INIT_SPACE: usize = 
         32 +    // authority
@@ -252,9 +269,9 @@ impl ErrorRecoveryManager {
         self.stats_reset_at = now;
         self.bump = bump;
 
-        msg!("Error recovery manager initialized");
-        msg!("Max concurrent sessions: {}", self.max_concurrent_sessions);
-        msg!("Auto-recovery enabled: {}", self.auto_recovery_enabled);
+        log!("Error recovery manager initialized");
+        log!("Max concurrent sessions: {}", self.max_concurrent_sessions);
+        log!("Auto-recovery enabled: {}", self.auto_recovery_enabled);
 
         Ok(())
     }
@@ -305,8 +322,8 @@ impl ErrorRecoveryManager {
             .ok_or(UniversalNftError::ArithmeticOverflow)?;
         self.last_recovery_attempt = now;
 
-        msg!("Recovery session {} initiated for error: {:?}", session_id, error_type);
-        msg!("Strategy: {:?}, Max attempts: {}", session.recovery_strategy, max_attempts);
+        log!("Recovery session {} initiated for error: {:?}", session_id, error_type);
+        log!("Strategy: {:?}, Max attempts: {}", session.recovery_strategy, max_attempts);
 
         Ok(())
     }
@@ -371,6 +388,20 @@ impl ErrorRecoveryManager {
         Ok(success)
     }
 
+    /// Record the result an operator reached for a session still in progress
+    pub fn conclude_recovery_session(
+        &mut self,
+        session: &mut RecoverySession,
+        result: RecoveryResult,
+    ) -> Result<()> {
+        require!(
+            session.status == RecoveryStatus::InProgress
+                || session.status == RecoveryStatus::RequiresManualIntervention,
+            UniversalNftError::InvalidTransferStatus
+        );
+        self.complete_recovery_session(session, result)
+    }
+
     /// Complete a recovery session
     fn complete_recovery_session(
         &mut self,
@@ -412,7 +443,7 @@ impl ErrorRecoveryManager {
 
         self.update_success_rate();
 
-        msg!("Recovery session {} completed with result: {:?}", session.session_id, result);
+        log!("Recovery session {} completed with result: {:?}", session.session_id, result);
         Ok(())
     }
 
@@ -444,7 +475,7 @@ impl ErrorRecoveryManager {
     }
 
     /// Calculate maximum recovery attempts
-    fn calculate_max_attempts(&self, error_type: &ErrorType, strategy: &RecoveryStrategy) -> u8 {
+    fn calculate_max_attempts(&self, error_type: &ErrorType, _strategy: &RecoveryStrategy) -> u8 {
         let base_attempts = match error_type {
             ErrorType::NetworkTimeout => 5,
             ErrorType::TransactionFailed => 3,
@@ -466,7 +497,7 @@ impl ErrorRecoveryManager {
         // Simulate exponential backoff delay
         let delay_seconds = 2_u64.pow((session.attempts_made - 1) as u32);
         
-        msg!("Exponential backoff recovery: attempt {}, delay {}s", 
+        log!("Exponential backoff recovery: attempt {}, delay {}s", 
              session.attempts_made, delay_seconds);
         
         // In real implementation, would actually retry the operation
@@ -476,7 +507,7 @@ impl ErrorRecoveryManager {
 
     /// Execute parameter adjustment recovery
     fn execute_parameter_adjustment_recovery(&self, session: &RecoverySession) -> Result<bool> {
-        msg!("Parameter adjustment recovery: attempt {}", session.attempts_made);
+        log!("Parameter adjustment recovery: attempt {}", session.attempts_made);
         
         // Would adjust compute limits, priority fees, etc.
         // Simulating 60% success rate
@@ -485,7 +516,7 @@ impl ErrorRecoveryManager {
 
     /// Execute alternative execution recovery
     fn execute_alternative_execution_recovery(&self, session: &RecoverySession) -> Result<bool> {
-        msg!("Alternative execution recovery: attempt {}", session.attempts_made);
+        log!("Alternative execution recovery: attempt {}", session.attempts_made);
         
         // Would try different execution paths or endpoints
         // Simulating 80% success rate
@@ -494,7 +525,7 @@ impl ErrorRecoveryManager {
 
     /// Execute rollback retry recovery
     fn execute_rollback_retry_recovery(&self, session: &RecoverySession) -> Result<bool> {
-        msg!("Rollback retry recovery: attempt {}", session.attempts_made);
+        log!("Rollback retry recovery: attempt {}", session.attempts_made);
         
         // Would rollback to last known good state and retry
         // Simulating 75% success rate
@@ -503,7 +534,7 @@ impl ErrorRecoveryManager {
 
     /// Execute compensating transaction recovery
     fn execute_compensating_transaction_recovery(&self, session: &RecoverySession) -> Result<bool> {
-        msg!("Compensating transaction recovery: attempt {}", session.attempts_made);
+        log!("Compensating transaction recovery: attempt {}", session.attempts_made);
         
         // Would create compensating transactions
         // Simulating 90% success rate (compensation almost always works)
@@ -512,7 +543,7 @@ impl ErrorRecoveryManager {
 
     /// Execute state reconstruction recovery
     fn execute_state_reconstruction_recovery(&self, session: &RecoverySession) -> Result<bool> {
-        msg!("State reconstruction recovery: attempt {}", session.attempts_made);
+        log!("State reconstruction recovery: attempt {}", session.attempts_made);
         
         // Would reconstruct corrupted state from backups
         // Simulating 50% success rate (complex operation)
@@ -521,7 +552,7 @@ impl ErrorRecoveryManager {
 
     /// Execute graceful degradation recovery
     fn execute_graceful_degradation_recovery(&self, session: &RecoverySession) -> Result<bool> {
-        msg!("Graceful degradation recovery: attempt {}", session.attempts_made);
+        log!("Graceful degradation recovery: attempt {}", session.attempts_made);
         
         // Would provide reduced functionality
         // Simulating 95% success rate (almost always can degrade gracefully)
@@ -577,8 +608,8 @@ impl ErrorRecoveryManager {
     /// Update recovery success rate
     fn update_success_rate(&mut self) {
         let total = self.successful_recoveries + self.failed_recoveries;
-        if total > 0 {
-            self.recovery_success_rate_bps = ((self.successful_recoveries * 10000) / total) as u16;
+        if let Some(rate) = (self.successful_recoveries * 10000).checked_div(total) {
+            self.recovery_success_rate_bps = rate as u16;
         }
     }
 
@@ -594,11 +625,9 @@ impl ErrorRecoveryManager {
             success_rate_bps: self.recovery_success_rate_bps,
             auto_recovery_enabled: self.auto_recovery_enabled,
             aggressive_mode: self.aggressive_mode,
-            avg_attempts_per_session: if total_attempts > 0 {
-                (self.total_recovery_attempts / total_attempts) as f32
-            } else {
-                0.0
-            },
+            avg_attempts_per_session: self.total_recovery_attempts
+                .checked_div(total_attempts)
+                .map_or(0.0, |avg| avg as f32),
         }
     }
 }
@@ -0,0 +1,229 @@
+use anchor_lang::prelude::*;
+use crate::errors::UniversalNftError;
+use crate::recovery::error_recovery::{RecoveryResult, RecoverySession};
+use crate::state::{CrossChainTransfer, TransferStatus};
+
+/// Transfer Insurance Pool for Universal NFT Protocol
+/// Users opt in by paying a premium per bridge; a predefined payout is claimable
+/// once the recovery module gives the insured transfer up as unrecoverable
+#[account]
+#[derive(InitSpace)]
+pub struct InsurancePool {
+    /// Governance authority that sets premium and payout parameters
+    pub authority: Pubkey,
+    /// Lamports charged per insured transfer
+    pub premium_lamports: u64,
+    /// Lamports paid out per valid claim
+    pub payout_lamports: u64,
+    /// Total premiums collected
+    pub total_premiums: u64,
+    /// Total payouts made
+    pub total_payouts: u64,
+    /// Number of policies written
+    pub policies_written: u64,
+    /// Number of claims paid
+    pub claims_paid: u64,
+    /// Whether new policies are accepted
+    pub enabled: bool,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Insurance policy covering a single outbound transfer
+#[account]
+#[derive(InitSpace)]
+pub struct InsurancePolicy {
+    /// Insured transfer record
+    pub transfer: Pubkey,
+    /// NFT mint being transferred
+    pub nft_mint: Pubkey,
+    /// Insured user
+    pub insured: Pubkey,
+    /// Premium paid
+    pub premium_paid: u64,
+    /// Payout locked in at purchase
+    pub payout_amount: u64,
+    /// Purchase timestamp
+    pub purchased_at: i64,
+    /// Whether the payout has been claimed
+    pub claimed: bool,
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl InsurancePool {
+    pub const INIT_SPACE: usize =
+        32 +    // authority
+        8 +     // premium_lamports
+        8 +     // payout_lamports
+        8 +     // total_premiums
+        8 +     // total_payouts
+        8 +     // policies_written
+        8 +     // claims_paid
+        1 +     // enabled
+        1;      // bump
+
+    /// Initialize the insurance pool
+    pub fn initialize(
+        &mut self,
+        authority: Pubkey,
+        premium_lamports: u64,
+        payout_lamports: u64,
+        bump: u8,
+    ) -> Result<()> {
+        Self::validate_parameters(premium_lamports, payout_lamports)?;
+
+        self.authority = authority;
+        self.premium_lamports = premium_lamports;
+        self.payout_lamports = payout_lamports;
+        self.total_premiums = 0;
+        self.total_payouts = 0;
+        self.policies_written = 0;
+        self.claims_paid = 0;
+        self.enabled = true;
+        self.bump = bump;
+
+        log!("Insurance pool initialized");
+        log!("Premium: {} lamports, payout: {} lamports", premium_lamports, payout_lamports);
+
+        Ok(())
+    }
+
+    /// Update premium and payout parameters
+    pub fn update_parameters(
+        &mut self,
+        premium_lamports: u64,
+        payout_lamports: u64,
+        enabled: bool,
+    ) -> Result<()> {
+        Self::validate_parameters(premium_lamports, payout_lamports)?;
+
+        self.premium_lamports = premium_lamports;
+        self.payout_lamports = payout_lamports;
+        self.enabled = enabled;
+
+        log!("Insurance parameters updated");
+        Ok(())
+    }
+
+    fn validate_parameters(premium_lamports: u64, payout_lamports: u64) -> Result<()> {
+        require!(
+            premium_lamports > 0 && payout_lamports >= premium_lamports,
+            UniversalNftError::InvalidInsuranceParameters
+        );
+        Ok(())
+    }
+
+    /// Payout for an NFT with `floor_value`: the pool's payout, capped at the
+    /// floor when the NFT's collection is priced
+    pub fn payout_for(&self, floor_value: u64) -> u64 {
+        if floor_value == 0 {
+            self.payout_lamports
+        } else {
+            self.payout_lamports.min(floor_value)
+        }
+    }
+
+    /// Only a transfer that has just started can be insured, by the holder of
+    /// its receipt, so nobody insures a transfer already known to have failed
+    pub fn require_insurable(transfer: &CrossChainTransfer, receipt_amount: u64) -> Result<()> {
+        require!(
+            transfer.status == TransferStatus::Initiated,
+            UniversalNftError::InvalidTransferStatus
+        );
+        require!(receipt_amount == 1, UniversalNftError::InvalidTransferReceipt);
+        Ok(())
+    }
+
+    /// Record a premium payment and write the policy
+    #[allow(clippy::too_many_arguments)]
+    pub fn write_policy(
+        &mut self,
+        policy: &mut InsurancePolicy,
+        transfer: Pubkey,
+        nft_mint: Pubkey,
+        insured: Pubkey,
+        floor_value: u64,
+        now: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(self.enabled, UniversalNftError::InsuranceDisabled);
+
+        self.total_premiums = self.total_premiums.checked_add(self.premium_lamports)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        self.policies_written = self.policies_written.checked_add(1)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+        policy.transfer = transfer;
+        policy.nft_mint = nft_mint;
+        policy.insured = insured;
+        policy.premium_paid = self.premium_lamports;
+        policy.payout_amount = self.payout_for(floor_value);
+        policy.purchased_at = now;
+        policy.claimed = false;
+        policy.bump = bump;
+
+        Ok(())
+    }
+
+    /// Validate a claim against the insured transfer's recovery outcome,
+    /// returning the payout
+    pub fn validate_claim(
+        &self,
+        policy: &InsurancePolicy,
+        transfer: &CrossChainTransfer,
+        session: &RecoverySession,
+        claimant: Pubkey,
+    ) -> Result<u64> {
+        require!(!policy.claimed, UniversalNftError::InsuranceAlreadyClaimed);
+        require!(policy.insured == claimant, UniversalNftError::Unauthorized);
+        require!(
+            transfer.nft_mint == policy.nft_mint,
+            UniversalNftError::InsuranceNotClaimable
+        );
+
+        // An ordinary revert or cancel returns the NFT; only a transfer the
+        // recovery module could not save pays out
+        require!(
+            transfer.status != TransferStatus::Completed
+                && *session.final_result()? == RecoveryResult::UnrecoverableFailure,
+            UniversalNftError::InsuranceNotClaimable
+        );
+
+        Ok(policy.payout_amount)
+    }
+
+    /// Require the pool to stay rent exempt after paying `amount` out of
+    /// `balance` lamports
+    pub fn require_payable(amount: u64, balance: u64, rent_exempt_minimum: u64) -> Result<()> {
+        require!(
+            amount <= balance.saturating_sub(rent_exempt_minimum),
+            UniversalNftError::InsufficientVaultFunds
+        );
+        Ok(())
+    }
+
+    /// Record a paid claim
+    pub fn record_claim(&mut self, policy: &mut InsurancePolicy, amount: u64) -> Result<()> {
+        policy.claimed = true;
+
+        self.total_payouts = self.total_payouts.checked_add(amount)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        self.claims_paid = self.claims_paid.checked_add(1)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+}
+
+impl InsurancePolicy {
+    pub const INIT_SPACE: usize =
+        32 +    // transfer
+        32 +    // nft_mint
+        32 +    // insured
+        8 +     // premium_paid
+        8 +     // payout_amount
+        8 +     // purchased_at
+        1 +     // claimed
+        1;      // bump
+}
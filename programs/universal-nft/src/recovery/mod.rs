@@ -1,11 +1,13 @@
 pub mod error_recovery;
 pub mod transaction_retry;
 pub mod state_recovery;
-pub mod failover;
 pub mod backup_restore;
+pub mod compensation;
+pub mod insurance;
 
 pub use error_recovery::*;
 pub use transaction_retry::*;
 pub use state_recovery::*;
-pub use failover::*;
-pub use backup_restore::*;
\ No newline at end of file
+pub use backup_restore::*;
+pub use compensation::*;
+pub use insurance::*;
\ No newline at end of file
@@ -1,5 +1,24 @@
 use anchor_lang::prelude::*;
 use crate::errors::UniversalNftError;
+use crate::state::RegisteredChain;
+use crate::utils::MerkleUtils;
+
+/// PDA seeds of the critical accounts committed to by every state checkpoint,
+/// in leaf order: program config and the recovery counters. The treasury
+/// belongs to the governance program and is not part of the core's state.
+///
+/// The registered chain PDAs (`["chain", chain_id]`) follow these leaves in
+/// ascending chain id order. The program keeps no on-chain list of chains, so
+/// callers enumerate them with `getProgramAccounts` on the `RegisteredChain`
+/// discriminator; the checkpoint records how many chains it committed to.
+pub const CHECKPOINT_ACCOUNT_SEEDS: &[&[u8]] = &[
+    b"config",
+    b"error_recovery",
+    b"transaction_retry",
+];
+
+/// Most registered chains a single checkpoint commits to
+pub const MAX_CHECKPOINT_CHAINS: usize = 24;
 
 /// State Recovery System for Universal NFT Protocol
 /// Handles state corruption, data consistency, and automatic state restoration
@@ -39,8 +58,15 @@ pub struct StateCheckpoint {
     pub checkpoint_id: u64,
     /// Checkpoint timestamp
     pub created_at: i64,
-    /// State hash at checkpoint
+    /// Sha256 Merkle root over the critical account data at checkpoint
     pub state_hash: [u8; 32],
+    /// Number of critical accounts committed to by the root
+    pub accounts_committed: u8,
+    /// Leaf hash of each committed account, in leaf order. Restores are
+    /// checked against these. Holds the fixed accounts plus
+    /// `MAX_CHECKPOINT_CHAINS` chains.
+    #[max_len(27)]
+    pub account_leaves: Vec<[u8; 32]>,
     /// Checkpoint type
     pub checkpoint_type: CheckpointType,
     /// Number of operations since last checkpoint
@@ -59,7 +85,7 @@ pub struct StateCheckpoint {
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, InitSpace)]
 pub enum CheckpointType {
     /// Regular periodic checkpoint
     Periodic,
@@ -73,7 +99,7 @@ pub enum CheckpointType {
     Consensus,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct StateMetrics {
     /// Total NFTs in system
     pub total_nfts: u64,
@@ -89,7 +115,7 @@ pub struct StateMetrics {
     pub integrity_score: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, InitSpace)]
 pub enum ValidationStatus {
     Valid,
     CorruptedMinor,
@@ -126,12 +152,12 @@ pub struct StateRecoverySession {
     /// Recovery errors encountered
     pub errors_encountered: u16,
     /// Recovery strategy used
-    pub strategy: RecoveryStrategy,
+    pub strategy: StateRecoveryStrategy,
     /// PDA bump
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, InitSpace)]
 pub enum RecoveryType {
     FullRestore,
     PartialRestore,
@@ -141,7 +167,7 @@ pub enum RecoveryType {
     IndexRebuild,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum RecoveryPhase {
     Initialization,
     Validation,
@@ -152,7 +178,7 @@ pub enum RecoveryPhase {
     Complete,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum RecoverySessionStatus {
     Active,
     Paused,
@@ -161,8 +187,8 @@ pub enum RecoverySessionStatus {
     Cancelled,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
-pub enum RecoveryStrategy {
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, InitSpace)]
+pub enum StateRecoveryStrategy {
     BackwardRecovery,    // Restore from previous checkpoint
     ForwardRecovery,     // Replay operations from checkpoint
     HybridRecovery,      // Combination of backward and forward
@@ -207,24 +233,30 @@ impl StateRecoveryManager {
         self.recovery_mode_active = false;
         self.bump = bump;
 
-        msg!("State recovery manager initialized");
-        msg!("Checkpoint interval: {} seconds", self.checkpoint_interval);
-        msg!("Auto-recovery enabled: {}", self.auto_recovery_enabled);
+        log!("State recovery manager initialized");
+        log!("Checkpoint interval: {} seconds", self.checkpoint_interval);
+        log!("Auto-recovery enabled: {}", self.auto_recovery_enabled);
 
         Ok(())
     }
 
-    /// Create a state checkpoint
+    /// Create a state checkpoint committing to the given critical accounts
     pub fn create_checkpoint(
         &mut self,
         checkpoint: &mut StateCheckpoint,
         checkpoint_type: CheckpointType,
         current_state_metrics: StateMetrics,
+        critical_accounts: &[AccountInfo],
     ) -> Result<()> {
         let now = Clock::get()?.unix_timestamp;
         
-        // Calculate state hash (simplified - would use actual state data)
-        let state_hash = self.calculate_state_hash(&current_state_metrics, now);
+        // Commit to the live account data of the critical account set
+        let account_leaves = Self::calculate_state_leaves(critical_accounts)?;
+        let state_hash = MerkleUtils::compute_root(&account_leaves);
+        let checkpoint_size = critical_accounts
+            .iter()
+            .map(|account| account.data_len() as u64)
+            .sum();
         
         // Validate current state
         let validation_status = self.validate_current_state(&current_state_metrics);
@@ -233,11 +265,13 @@ impl StateRecoveryManager {
         checkpoint.checkpoint_id = self.total_checkpoints;
         checkpoint.created_at = now;
         checkpoint.state_hash = state_hash;
+        checkpoint.accounts_committed = critical_accounts.len() as u8;
+        checkpoint.account_leaves = account_leaves;
         checkpoint.checkpoint_type = checkpoint_type;
         checkpoint.operations_since_last = self.operations_since_validation;
         checkpoint.state_metrics = current_state_metrics;
         checkpoint.validation_status = validation_status;
-        checkpoint.checkpoint_size = 1024 * 1024; // 1MB estimated
+        checkpoint.checkpoint_size = checkpoint_size;
         checkpoint.recovery_priority = self.calculate_recovery_priority(&checkpoint_type);
         checkpoint.recovery_session_id = None;
 
@@ -247,13 +281,74 @@ impl StateRecoveryManager {
         self.last_checkpoint = now;
         self.operations_since_validation = 0;
 
-        msg!("State checkpoint {} created", checkpoint.checkpoint_id);
-        msg!("State hash: {:?}", &state_hash[..8]); // Log first 8 bytes
-        msg!("Validation status: {:?}", validation_status);
+        log!("State checkpoint {} created", checkpoint.checkpoint_id);
+        log!("State hash: {:?}", &state_hash[..8]); // Log first 8 bytes
+        log!("Validation status: {:?}", validation_status);
 
         Ok(())
     }
 
+    /// Recompute the state root from the critical accounts and compare it
+    /// against a stored checkpoint
+    pub fn verify_state_against_checkpoint(
+        checkpoint: &StateCheckpoint,
+        critical_accounts: &[AccountInfo],
+    ) -> Result<bool> {
+        require!(
+            critical_accounts.len() == checkpoint.accounts_committed as usize,
+            UniversalNftError::InvalidCheckpointAccounts
+        );
+
+        let current_root = MerkleUtils::compute_root(&Self::calculate_state_leaves(critical_accounts)?);
+        Ok(current_root == checkpoint.state_hash)
+    }
+
+    /// Compute the Merkle leaves of the critical account set, checking each
+    /// account against its expected PDA. The fixed accounts come first, then
+    /// the registered chains in strictly ascending chain id order.
+    pub fn calculate_state_leaves(critical_accounts: &[AccountInfo]) -> Result<Vec<[u8; 32]>> {
+        require!(
+            critical_accounts.len() >= CHECKPOINT_ACCOUNT_SEEDS.len()
+                && critical_accounts.len() <= CHECKPOINT_ACCOUNT_SEEDS.len() + MAX_CHECKPOINT_CHAINS,
+            UniversalNftError::InvalidCheckpointAccounts
+        );
+        let (fixed_accounts, chain_accounts) = critical_accounts.split_at(CHECKPOINT_ACCOUNT_SEEDS.len());
+
+        for (account, seed) in fixed_accounts.iter().zip(CHECKPOINT_ACCOUNT_SEEDS.iter()) {
+            let (expected, _) = Pubkey::find_program_address(&[seed], &crate::ID);
+            require_keys_eq!(
+                account.key(),
+                expected,
+                UniversalNftError::InvalidCheckpointAccounts
+            );
+        }
+
+        let mut previous_chain_id = None;
+        for account in chain_accounts {
+            require_keys_eq!(*account.owner, crate::ID, UniversalNftError::InvalidCheckpointAccounts);
+            let chain = RegisteredChain::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+            let expected = Pubkey::create_program_address(
+                &[b"chain", chain.chain_id.to_le_bytes().as_ref(), &[chain.bump]],
+                &crate::ID,
+            )
+            .map_err(|_| UniversalNftError::InvalidCheckpointAccounts)?;
+            require_keys_eq!(account.key(), expected, UniversalNftError::InvalidCheckpointAccounts);
+            require!(
+                previous_chain_id.is_none_or(|previous| chain.chain_id > previous),
+                UniversalNftError::InvalidCheckpointAccounts
+            );
+            previous_chain_id = Some(chain.chain_id);
+        }
+
+        critical_accounts
+            .iter()
+            .map(|account| {
+                let data = account.try_borrow_data()?;
+                Ok(MerkleUtils::hash_account_leaf(account.key, account.owner, &data))
+            })
+            .collect()
+    }
+
     /// Initiate state recovery
     pub fn initiate_recovery(
         &mut self,
@@ -290,8 +385,8 @@ impl StateRecoveryManager {
         self.total_recoveries = self.total_recoveries.checked_add(1)
             .ok_or(UniversalNftError::ArithmeticOverflow)?;
 
-        msg!("State recovery session {} initiated", session_id);
-        msg!("Recovery type: {:?}, Strategy: {:?}", recovery_type, strategy);
+        log!("State recovery session {} initiated", session_id);
+        log!("Recovery type: {:?}, Strategy: {:?}", recovery_type, strategy);
 
         Ok(())
     }
@@ -306,27 +401,27 @@ impl StateRecoveryManager {
 
         let phase_completed = match recovery_session.current_phase {
             RecoveryPhase::Initialization => {
-                msg!("Executing initialization phase");
+                log!("Executing initialization phase");
                 self.execute_initialization_phase(recovery_session)?
             }
             RecoveryPhase::Validation => {
-                msg!("Executing validation phase");
+                log!("Executing validation phase");
                 self.execute_validation_phase(recovery_session)?
             }
             RecoveryPhase::DataRetrieval => {
-                msg!("Executing data retrieval phase");
+                log!("Executing data retrieval phase");
                 self.execute_data_retrieval_phase(recovery_session)?
             }
             RecoveryPhase::StateReconstruction => {
-                msg!("Executing state reconstruction phase");
+                log!("Executing state reconstruction phase");
                 self.execute_state_reconstruction_phase(recovery_session)?
             }
             RecoveryPhase::ConsistencyCheck => {
-                msg!("Executing consistency check phase");
+                log!("Executing consistency check phase");
                 self.execute_consistency_check_phase(recovery_session)?
             }
             RecoveryPhase::Finalization => {
-                msg!("Executing finalization phase");
+                log!("Executing finalization phase");
                 self.execute_finalization_phase(recovery_session)?
             }
             RecoveryPhase::Complete => {
@@ -359,8 +454,6 @@ impl StateRecoveryManager {
         recovery_session: &mut StateRecoverySession,
         success: bool,
     ) -> Result<()> {
-        let now = Clock::get()?.unix_timestamp;
-        
         recovery_session.status = if success {
             RecoverySessionStatus::Completed
         } else {
@@ -379,7 +472,7 @@ impl StateRecoveryManager {
 
         self.recovery_mode_active = false;
 
-        msg!("Recovery session {} completed: {}", 
+        log!("Recovery session {} completed: {}", 
              recovery_session.session_id, if success { "SUCCESS" } else { "FAILED" });
         
         Ok(())
@@ -400,7 +493,7 @@ impl StateRecoveryManager {
 
     /// Trigger state validation
     fn trigger_state_validation(&mut self) -> Result<()> {
-        msg!("Triggering state validation after {} operations", self.operations_since_validation);
+        log!("Triggering state validation after {} operations", self.operations_since_validation);
         
         // In real implementation, would perform comprehensive state validation
         // For now, just reset the counter
@@ -417,27 +510,6 @@ impl StateRecoveryManager {
 
     // Private helper methods
 
-    fn calculate_state_hash(&self, metrics: &StateMetrics, timestamp: i64) -> [u8; 32] {
-        // Simplified hash calculation - would use proper cryptographic hash in production
-        let mut hash = [0u8; 32];
-        let data = format!("{}{}{}{}{}",
-            metrics.total_nfts,
-            metrics.active_transfers,
-            metrics.unique_users,
-            metrics.integrity_score,
-            timestamp
-        );
-        
-        // Simple hash (replace with proper SHA-256 in production)
-        for (i, byte) in data.bytes().enumerate() {
-            if i < 32 {
-                hash[i] = byte;
-            }
-        }
-        
-        hash
-    }
-
     fn validate_current_state(&self, metrics: &StateMetrics) -> ValidationStatus {
         // Simplified validation logic
         if metrics.integrity_score >= 95 {
@@ -461,14 +533,14 @@ impl StateRecoveryManager {
         }
     }
 
-    fn determine_recovery_strategy(&self, recovery_type: &RecoveryType) -> RecoveryStrategy {
+    fn determine_recovery_strategy(&self, recovery_type: &RecoveryType) -> StateRecoveryStrategy {
         match recovery_type {
-            RecoveryType::FullRestore => RecoveryStrategy::BackwardRecovery,
-            RecoveryType::PartialRestore => RecoveryStrategy::HybridRecovery,
-            RecoveryType::StateReconstruction => RecoveryStrategy::ReconstructionRecovery,
-            RecoveryType::ConsistencyRepair => RecoveryStrategy::ForwardRecovery,
-            RecoveryType::DataDeduplication => RecoveryStrategy::HybridRecovery,
-            RecoveryType::IndexRebuild => RecoveryStrategy::ReconstructionRecovery,
+            RecoveryType::FullRestore => StateRecoveryStrategy::BackwardRecovery,
+            RecoveryType::PartialRestore => StateRecoveryStrategy::HybridRecovery,
+            RecoveryType::StateReconstruction => StateRecoveryStrategy::ReconstructionRecovery,
+            RecoveryType::ConsistencyRepair => StateRecoveryStrategy::ForwardRecovery,
+            RecoveryType::DataDeduplication => StateRecoveryStrategy::HybridRecovery,
+            RecoveryType::IndexRebuild => StateRecoveryStrategy::ReconstructionRecovery,
         }
     }
 
@@ -488,40 +560,40 @@ impl StateRecoveryManager {
     // Recovery phase execution methods (simplified implementations)
 
     fn execute_initialization_phase(&self, session: &mut StateRecoverySession) -> Result<bool> {
-        msg!("Initializing recovery for session {}", session.session_id);
+        log!("Initializing recovery for session {}", session.session_id);
         // Would initialize recovery environment, allocate resources, etc.
         Ok(true)
     }
 
-    fn execute_validation_phase(&self, session: &mut StateRecoverySession) -> Result<bool> {
-        msg!("Validating source checkpoint and target state");
+    fn execute_validation_phase(&self, _session: &mut StateRecoverySession) -> Result<bool> {
+        log!("Validating source checkpoint and target state");
         // Would validate checkpoint integrity and target state requirements
         Ok(true)
     }
 
     fn execute_data_retrieval_phase(&self, session: &mut StateRecoverySession) -> Result<bool> {
-        msg!("Retrieving data from checkpoint {}", session.source_checkpoint_id);
+        log!("Retrieving data from checkpoint {}", session.source_checkpoint_id);
         // Would retrieve data from checkpoint and prepare for reconstruction
         session.blocks_recovered = session.total_blocks / 3; // Simulate progress
         Ok(true)
     }
 
     fn execute_state_reconstruction_phase(&self, session: &mut StateRecoverySession) -> Result<bool> {
-        msg!("Reconstructing state from retrieved data");
+        log!("Reconstructing state from retrieved data");
         // Would reconstruct state from checkpoint data
         session.blocks_recovered = (session.total_blocks * 2) / 3; // Simulate progress
         Ok(true)
     }
 
     fn execute_consistency_check_phase(&self, session: &mut StateRecoverySession) -> Result<bool> {
-        msg!("Performing consistency checks on reconstructed state");
+        log!("Performing consistency checks on reconstructed state");
         // Would validate reconstructed state consistency
         session.blocks_recovered = session.total_blocks; // Complete
         Ok(true)
     }
 
-    fn execute_finalization_phase(&self, session: &mut StateRecoverySession) -> Result<bool> {
-        msg!("Finalizing recovery and updating system state");
+    fn execute_finalization_phase(&self, _session: &mut StateRecoverySession) -> Result<bool> {
+        log!("Finalizing recovery and updating system state");
         // Would finalize recovery, update pointers, clean up temporary data
         Ok(true)
     }
@@ -529,11 +601,9 @@ impl StateRecoveryManager {
     /// Get recovery statistics
     pub fn get_recovery_stats(&self) -> StateRecoveryStats {
         let total_recoveries = self.successful_recoveries + self.failed_recoveries;
-        let success_rate = if total_recoveries > 0 {
-            (self.successful_recoveries * 10000) / total_recoveries
-        } else {
-            10000
-        };
+        let success_rate = (self.successful_recoveries * 10000)
+            .checked_div(total_recoveries)
+            .unwrap_or(10000);
 
         let now = Clock::get().unwrap().unix_timestamp;
         let time_since_checkpoint = now - self.last_checkpoint;
@@ -2,7 +2,10 @@ use anchor_lang::prelude::*;
 use crate::errors::UniversalNftError;
 
 /// Advanced Transaction Retry System with Intelligent Backoff
-/// Handles failed transactions with sophisticated retry logic and optimization
+/// Handles failed transactions with sophisticated retry logic and optimization.
+/// The program only schedules attempts and records their outcomes: the
+/// off-chain retrier resubmits each attempt and reports how it went, signed
+/// by `reporter`.
 #[account]
 #[derive(InitSpace)]
 pub struct TransactionRetryManager {
@@ -24,6 +27,10 @@ pub struct TransactionRetryManager {
     pub adaptive_retry_enabled: bool,
     /// Last retry attempt timestamp
     pub last_retry_attempt: i64,
+    /// Off-chain retrier allowed to report attempt outcomes
+    pub reporter: Pubkey,
+    /// ID, and PDA seed, of the next retry session
+    pub next_session_id: u64,
     /// PDA bump
     pub bump: u8,
 }
@@ -34,6 +41,7 @@ pub struct RetrySession {
     /// Session ID
     pub session_id: u64,
     /// Original transaction that failed
+    #[max_len(88)]
     pub original_tx_signature: String,
     /// Retry configuration for this session
     pub retry_config: RetryConfig,
@@ -42,6 +50,7 @@ pub struct RetrySession {
     /// Session status
     pub status: RetrySessionStatus,
     /// Failure reasons encountered
+    #[max_len(16)]
     pub failure_reasons: Vec<RetryFailureReason>,
     /// Session start timestamp
     pub started_at: i64,
@@ -49,6 +58,10 @@ pub struct RetrySession {
     pub last_attempt_at: i64,
     /// Next retry scheduled timestamp
     pub next_retry_at: i64,
+    /// Compute unit limit the current or next attempt is submitted with
+    pub compute_unit_limit: u32,
+    /// Priority fee (micro-lamports per compute unit) for that attempt
+    pub priority_fee_micro_lamports: u64,
     /// Total time spent on retries
     pub total_retry_time: u64,
     /// Compute units consumed across all attempts
@@ -56,14 +69,39 @@ pub struct RetrySession {
     /// Fees spent on retry attempts
     pub total_fees_spent: u64,
     /// Final successful transaction signature
+    #[max_len(88)]
     pub successful_tx_signature: Option<String>,
     /// Optimization applied during retries
+    #[max_len(16)]
     pub optimizations_applied: Vec<RetryOptimization>,
+    /// Retry manager that scheduled the session
+    pub retry_manager: Pubkey,
     /// PDA bump
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+/// Retry sessions waiting for their next attempt, kept as a binary min-heap
+/// on due time so the crank always finds the most overdue session first
+#[account]
+#[derive(InitSpace)]
+pub struct RetryQueue {
+    /// Heap of scheduled attempts; `entries[0]` is due soonest
+    #[max_len(64)]
+    pub entries: Vec<QueuedRetry>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// A session's next scheduled attempt
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct QueuedRetry {
+    /// Retry session account
+    pub session: Pubkey,
+    /// The session's `next_retry_at` when it was queued
+    pub due_at: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct RetryConfig {
     /// Maximum number of retry attempts
     pub max_attempts: u8,
@@ -83,7 +121,7 @@ pub struct RetryConfig {
     pub adaptive_adjustments: bool,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum RetrySessionStatus {
     Scheduled,
     InProgress,
@@ -93,7 +131,7 @@ pub enum RetrySessionStatus {
     Paused,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum RetryFailureReason {
     NetworkTimeout,
     InsufficientComputeUnits,
@@ -106,7 +144,7 @@ pub enum RetryFailureReason {
     UnknownError,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct RetryOptimization {
     /// Optimization type applied
     pub optimization_type: OptimizationType,
@@ -120,7 +158,7 @@ pub struct RetryOptimization {
     pub was_successful: bool,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum OptimizationType {
     ComputeUnitIncrease,
     ComputeUnitDecrease,
@@ -132,23 +170,11 @@ pub enum OptimizationType {
     EndpointSwitch,
 }
 
-/// Network condition analyzer for adaptive retry logic
+/// Network condition analyzer for adaptive retry logic. Conditions come from
+/// the retrier's outcome reports, as measured while it resubmitted.
 pub struct NetworkConditionAnalyzer;
 
 impl NetworkConditionAnalyzer {
-    /// Analyze current network conditions
-    pub fn analyze_conditions() -> NetworkConditions {
-        // In real implementation, would query actual network metrics
-        NetworkConditions {
-            congestion_level: CongestionLevel::Medium,
-            average_confirmation_time_ms: 2500,
-            current_base_fee: 5000,
-            suggested_priority_fee: 10000,
-            recommended_compute_units: 200_000,
-            network_stability_score: 85, // 0-100
-        }
-    }
-
     /// Calculate optimal retry parameters based on network conditions
     pub fn calculate_optimal_parameters(
         conditions: &NetworkConditions,
@@ -191,7 +217,7 @@ impl NetworkConditionAnalyzer {
     }
 }
 
-#[derive(Clone)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct NetworkConditions {
     pub congestion_level: CongestionLevel,
     pub average_confirmation_time_ms: u32,
@@ -201,7 +227,7 @@ pub struct NetworkConditions {
     pub network_stability_score: u8,
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
 pub enum CongestionLevel {
     Low,
     Medium,
@@ -229,6 +255,8 @@ impl TransactionRetryManager {
         32 +    // default_config (estimated)
         1 +     // adaptive_retry_enabled
         8 +     // last_retry_attempt
+        32 +    // reporter
+        8 +     // next_session_id
         1;      // bump
 
     /// Initialize transaction retry manager
@@ -247,22 +275,31 @@ impl TransactionRetryManager {
         self.default_config = config;
         self.adaptive_retry_enabled = true;
         self.last_retry_attempt = 0;
+        self.reporter = authority;
+        self.next_session_id = 0;
         self.bump = bump;
 
-        msg!("Transaction retry manager initialized");
-        msg!("Max concurrent sessions: {}", self.max_concurrent_sessions);
-        msg!("Adaptive retry enabled: {}", self.adaptive_retry_enabled);
+        log!("Transaction retry manager initialized");
+        log!("Max concurrent sessions: {}", self.max_concurrent_sessions);
+        log!("Adaptive retry enabled: {}", self.adaptive_retry_enabled);
 
         Ok(())
     }
 
-    /// Schedule a transaction for retry
+    /// Schedule a transaction for retry and queue its first attempt, starting
+    /// from the compute unit limit and priority fee it was sent with
+    #[allow(clippy::too_many_arguments)]
     pub fn schedule_retry(
         &mut self,
         session: &mut RetrySession,
+        session_key: Pubkey,
+        manager_key: Pubkey,
+        queue: &mut RetryQueue,
         session_id: u64,
         original_tx_signature: String,
         failure_reason: RetryFailureReason,
+        compute_unit_limit: u32,
+        priority_fee_micro_lamports: u64,
         custom_config: Option<RetryConfig>,
     ) -> Result<()> {
         require!(
@@ -273,16 +310,8 @@ impl TransactionRetryManager {
         let now = Clock::get()?.unix_timestamp;
         let config = custom_config.unwrap_or(self.default_config.clone());
 
-        // Calculate initial retry delay
-        let initial_delay = if self.adaptive_retry_enabled {
-            let conditions = NetworkConditionAnalyzer::analyze_conditions();
-            let params = NetworkConditionAnalyzer::calculate_optimal_parameters(
-                &conditions, &failure_reason, 1
-            );
-            params.delay_seconds as i64
-        } else {
-            config.initial_delay_seconds as i64
-        };
+        // No conditions are reported before the first attempt
+        let initial_delay = config.initial_delay_seconds as i64;
 
         // Initialize retry session
         session.session_id = session_id;
@@ -294,28 +323,36 @@ impl TransactionRetryManager {
         session.started_at = now;
         session.last_attempt_at = 0;
         session.next_retry_at = now + initial_delay;
+        session.compute_unit_limit = compute_unit_limit;
+        session.priority_fee_micro_lamports = priority_fee_micro_lamports;
         session.total_retry_time = 0;
         session.total_compute_units = 0;
         session.total_fees_spent = 0;
         session.successful_tx_signature = None;
         session.optimizations_applied = Vec::new();
+        session.retry_manager = manager_key;
+        queue.schedule(session_key, session.next_retry_at)?;
 
         // Update manager state
         self.active_retry_sessions = self.active_retry_sessions.checked_add(1)
             .ok_or(UniversalNftError::ArithmeticOverflow)?;
 
-        msg!("Retry session {} scheduled for transaction: {}", 
+        log!("Retry session {} scheduled for transaction: {}", 
              session_id, original_tx_signature);
-        msg!("Initial retry scheduled for: {}", session.next_retry_at);
+        log!("Initial retry scheduled for: {}", session.next_retry_at);
 
         Ok(())
     }
 
-    /// Execute a retry attempt
-    pub fn execute_retry_attempt(
+    /// Start a due retry attempt. The session leaves the queue and waits in
+    /// progress for the retrier to resubmit with the session's compute unit
+    /// limit and priority fee and report the outcome.
+    pub fn start_retry_attempt(
         &mut self,
         session: &mut RetrySession,
-    ) -> Result<RetryAttemptResult> {
+        session_key: &Pubkey,
+        queue: &mut RetryQueue,
+    ) -> Result<()> {
         let now = Clock::get()?.unix_timestamp;
         
         require!(session.status == RetrySessionStatus::Scheduled, UniversalNftError::InvalidTransferStatus);
@@ -326,34 +363,59 @@ impl TransactionRetryManager {
             .ok_or(UniversalNftError::ArithmeticOverflow)?;
         session.status = RetrySessionStatus::InProgress;
         session.last_attempt_at = now;
+        queue.remove(session_key);
 
         // Update global retry statistics
         self.total_retry_attempts = self.total_retry_attempts.checked_add(1)
             .ok_or(UniversalNftError::ArithmeticOverflow)?;
         self.last_retry_attempt = now;
 
-        msg!("Executing retry attempt {} for session {}", 
+        log!("Starting retry attempt {} for session {}", 
              session.current_attempt, session.session_id);
 
-        // Simulate retry attempt (in real implementation, would execute actual transaction)
-        let attempt_result = self.simulate_retry_attempt(session)?;
+        Ok(())
+    }
+
+    /// Record the outcome the retrier reported for the attempt in progress.
+    /// A failed attempt is rescheduled, with adjusted compute and fees, until
+    /// the session runs out of attempts.
+    pub fn record_retry_outcome(
+        &mut self,
+        session: &mut RetrySession,
+        session_key: Pubkey,
+        queue: &mut RetryQueue,
+        report: &RetryOutcomeReport,
+    ) -> Result<()> {
+        require!(session.status == RetrySessionStatus::InProgress, UniversalNftError::InvalidTransferStatus);
+        require!(report.attempt == session.current_attempt, UniversalNftError::InvalidRetryReport);
+
+        let now = Clock::get()?.unix_timestamp;
 
         // Update session based on result
-        match attempt_result.result {
+        match report.result {
             AttemptResult::Success => {
+                require!(!report.tx_signature.is_empty(), UniversalNftError::InvalidRetryReport);
                 session.status = RetrySessionStatus::Successful;
-                session.successful_tx_signature = Some(attempt_result.tx_signature.clone());
+                session.successful_tx_signature = Some(report.tx_signature.clone());
+                for optimization in session.optimizations_applied.iter_mut() {
+                    if optimization.applied_at_attempt == session.current_attempt {
+                        optimization.was_successful = true;
+                    }
+                }
                 self.successful_retries = self.successful_retries.checked_add(1)
                     .ok_or(UniversalNftError::ArithmeticOverflow)?;
                 self.active_retry_sessions = self.active_retry_sessions.saturating_sub(1);
                 
-                msg!("Retry session {} successful after {} attempts", 
+                log!("Retry session {} successful after {} attempts", 
                      session.session_id, session.current_attempt);
             }
             AttemptResult::Failed => {
-                if let Some(reason) = attempt_result.failure_reason {
-                    session.failure_reasons.push(reason.clone());
+                let reason = report.failure_reason.clone()
+                    .ok_or(UniversalNftError::InvalidRetryReport)?;
+                if session.failure_reasons.len() >= RetrySession::MAX_FAILURE_REASONS {
+                    session.failure_reasons.remove(0);
                 }
+                session.failure_reasons.push(reason.clone());
 
                 if session.current_attempt >= session.retry_config.max_attempts {
                     session.status = RetrySessionStatus::Failed;
@@ -361,50 +423,92 @@ impl TransactionRetryManager {
                         .ok_or(UniversalNftError::ArithmeticOverflow)?;
                     self.active_retry_sessions = self.active_retry_sessions.saturating_sub(1);
                     
-                    msg!("Retry session {} failed after {} attempts", 
+                    log!("Retry session {} failed after {} attempts", 
                          session.session_id, session.current_attempt);
                 } else {
                     // Schedule next retry attempt
-                    self.schedule_next_retry(session, &attempt_result.failure_reason)?;
+                    self.schedule_next_retry(session, &reason, report.network.as_ref())?;
+                    queue.schedule(session_key, session.next_retry_at)?;
                 }
             }
         }
 
         // Update session metrics
-        session.total_compute_units = session.total_compute_units.checked_add(attempt_result.compute_units_used as u64)
+        session.total_compute_units = session.total_compute_units.checked_add(report.compute_units_used as u64)
             .ok_or(UniversalNftError::ArithmeticOverflow)?;
-        session.total_fees_spent = session.total_fees_spent.checked_add(attempt_result.fees_spent)
+        session.total_fees_spent = session.total_fees_spent.checked_add(report.fees_spent)
             .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        session.total_retry_time = (now - session.started_at).max(0) as u64;
 
-        if let Some(optimization) = attempt_result.optimization_applied {
-            session.optimizations_applied.push(optimization);
-        }
-
-        Ok(attempt_result)
+        Ok(())
     }
 
-    /// Schedule the next retry attempt
+    /// Schedule the next retry attempt, adjusting its compute unit limit and
+    /// priority fee. Adaptive retries follow the conditions the retrier
+    /// reported; otherwise the session's config applies.
     fn schedule_next_retry(
         &mut self,
         session: &mut RetrySession,
-        failure_reason: &Option<RetryFailureReason>,
+        failure_reason: &RetryFailureReason,
+        conditions: Option<&NetworkConditions>,
     ) -> Result<()> {
         let now = Clock::get()?.unix_timestamp;
         
-        let delay = if self.adaptive_retry_enabled && failure_reason.is_some() {
-            let conditions = NetworkConditionAnalyzer::analyze_conditions();
-            let params = NetworkConditionAnalyzer::calculate_optimal_parameters(
-                &conditions, failure_reason.as_ref().unwrap(), session.current_attempt
+        let (delay, compute_pct, fee_pct) = match conditions {
+            Some(conditions) if self.adaptive_retry_enabled => {
+                let params = NetworkConditionAnalyzer::calculate_optimal_parameters(
+                    conditions, failure_reason, session.current_attempt
+                );
+                (params.delay_seconds as i64, params.compute_unit_adjustment_pct, params.priority_fee_adjustment_pct)
+            }
+            _ => (
+                self.calculate_exponential_backoff_delay(session),
+                session.retry_config.compute_unit_adjustment_pct,
+                session.retry_config.priority_fee_adjustment_pct,
+            ),
+        };
+
+        let next_attempt = session.current_attempt.saturating_add(1);
+        let compute_unit_limit = RetrySession::adjust(session.compute_unit_limit as u64, compute_pct)
+            .min(RetrySession::MAX_COMPUTE_UNIT_LIMIT as u64) as u32;
+        if compute_unit_limit != session.compute_unit_limit {
+            session.record_optimization(
+                if compute_unit_limit > session.compute_unit_limit {
+                    OptimizationType::ComputeUnitIncrease
+                } else {
+                    OptimizationType::ComputeUnitDecrease
+                },
+                session.compute_unit_limit as u64,
+                compute_unit_limit as u64,
+                next_attempt,
             );
-            params.delay_seconds as i64
+            session.compute_unit_limit = compute_unit_limit;
+        }
+
+        let base_fee = if fee_pct > 0 {
+            session.priority_fee_micro_lamports.max(RetrySession::MIN_PRIORITY_FEE)
         } else {
-            self.calculate_exponential_backoff_delay(session)
+            session.priority_fee_micro_lamports
         };
+        let priority_fee = RetrySession::adjust(base_fee, fee_pct);
+        if priority_fee != session.priority_fee_micro_lamports {
+            session.record_optimization(
+                if priority_fee > session.priority_fee_micro_lamports {
+                    OptimizationType::PriorityFeeIncrease
+                } else {
+                    OptimizationType::PriorityFeeDecrease
+                },
+                session.priority_fee_micro_lamports,
+                priority_fee,
+                next_attempt,
+            );
+            session.priority_fee_micro_lamports = priority_fee;
+        }
 
         session.next_retry_at = now + delay;
         session.status = RetrySessionStatus::Scheduled;
 
-        msg!("Next retry for session {} scheduled at {}", 
+        log!("Next retry for session {} scheduled at {}", 
              session.session_id, session.next_retry_at);
 
         Ok(())
@@ -427,58 +531,13 @@ impl TransactionRetryManager {
         (capped_delay + jitter) as i64
     }
 
-    /// Simulate a retry attempt (replace with actual implementation)
-    fn simulate_retry_attempt(&self, session: &RetrySession) -> Result<RetryAttemptResult> {
-        // Simulate network conditions and retry logic
-        let success_probability = match session.current_attempt {
-            1 => 0.3, // 30% success on first retry
-            2 => 0.6, // 60% success on second retry
-            3 => 0.8, // 80% success on third retry
-            _ => 0.9, // 90% success on subsequent retries
-        };
-
-        let random_factor = (session.session_id % 100) as f64 / 100.0;
-        let is_successful = random_factor < success_probability;
-
-        let result = if is_successful {
-            RetryAttemptResult {
-                result: AttemptResult::Success,
-                tx_signature: format!("retry_success_{}", session.session_id),
-                failure_reason: None,
-                compute_units_used: 180_000,
-                fees_spent: 5000,
-                optimization_applied: Some(RetryOptimization {
-                    optimization_type: OptimizationType::ComputeUnitIncrease,
-                    before_value: 150_000,
-                    after_value: 180_000,
-                    applied_at_attempt: session.current_attempt,
-                    was_successful: true,
-                }),
-            }
-        } else {
-            let failure_reasons = [
-                RetryFailureReason::NetworkTimeout,
-                RetryFailureReason::InsufficientComputeUnits,
-                RetryFailureReason::NodeOverloaded,
-                RetryFailureReason::BlockhashExpired,
-            ];
-            let failure_idx = (session.session_id + session.current_attempt as u64) % failure_reasons.len() as u64;
-            
-            RetryAttemptResult {
-                result: AttemptResult::Failed,
-                tx_signature: String::new(),
-                failure_reason: Some(failure_reasons[failure_idx as usize].clone()),
-                compute_units_used: 50_000, // Partial execution
-                fees_spent: 2000,
-                optimization_applied: None,
-            }
-        };
-
-        Ok(result)
-    }
-
-    /// Cancel a retry session
-    pub fn cancel_retry_session(&mut self, session: &mut RetrySession) -> Result<()> {
+    /// Cancel a retry session and drop it from the queue
+    pub fn cancel_retry_session(
+        &mut self,
+        session: &mut RetrySession,
+        session_key: &Pubkey,
+        queue: &mut RetryQueue,
+    ) -> Result<()> {
         require!(
             session.status == RetrySessionStatus::Scheduled || 
             session.status == RetrySessionStatus::Paused,
@@ -486,27 +545,26 @@ impl TransactionRetryManager {
         );
 
         session.status = RetrySessionStatus::Cancelled;
+        queue.remove(session_key);
         self.active_retry_sessions = self.active_retry_sessions.saturating_sub(1);
 
-        msg!("Retry session {} cancelled", session.session_id);
+        log!("Retry session {} cancelled", session.session_id);
         Ok(())
     }
 
     /// Update retry configuration
     pub fn update_retry_config(&mut self, new_config: RetryConfig) -> Result<()> {
         self.default_config = new_config;
-        msg!("Retry configuration updated");
+        log!("Retry configuration updated");
         Ok(())
     }
 
     /// Get retry statistics
     pub fn get_retry_stats(&self) -> RetryStats {
         let total_attempts = self.successful_retries + self.failed_retries;
-        let success_rate = if total_attempts > 0 {
-            (self.successful_retries * 10000) / total_attempts
-        } else {
-            10000
-        };
+        let success_rate = (self.successful_retries * 10000)
+            .checked_div(total_attempts)
+            .unwrap_or(10000);
 
         RetryStats {
             total_retry_attempts: self.total_retry_attempts,
@@ -521,17 +579,139 @@ impl TransactionRetryManager {
     }
 }
 
-#[derive(Clone)]
-pub struct RetryAttemptResult {
+impl RetryQueue {
+    /// Most sessions the queue holds
+    pub const MAX_ENTRIES: usize = 64;
+    /// Compute units `process_due_retries` keeps in hand before starting
+    /// another attempt
+    pub const COMPUTE_PER_RETRY: u64 = 40_000;
+
+    /// Queue a session's attempt at `due_at`, replacing any earlier entry
+    /// for the same session
+    pub fn schedule(&mut self, session: Pubkey, due_at: i64) -> Result<()> {
+        self.remove(&session);
+        require!(self.entries.len() < Self::MAX_ENTRIES, UniversalNftError::RetryQueueFull);
+
+        self.entries.push(QueuedRetry { session, due_at });
+        self.sift_up(self.entries.len() - 1);
+        Ok(())
+    }
+
+    /// Earliest entry if it is due at `now`
+    pub fn peek_due(&self, now: i64) -> Option<QueuedRetry> {
+        self.entries.first().filter(|entry| entry.due_at <= now).copied()
+    }
+
+    /// Remove and return the earliest entry
+    pub fn pop(&mut self) -> Option<QueuedRetry> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let entry = self.entries.swap_remove(0);
+        self.sift_down(0);
+        Some(entry)
+    }
+
+    /// Drop a session's entry, if it is queued
+    pub fn remove(&mut self, session: &Pubkey) {
+        if let Some(index) = self.entries.iter().position(|e| e.session == *session) {
+            self.entries.swap_remove(index);
+            if index < self.entries.len() {
+                self.sift_down(index);
+                self.sift_up(index);
+            }
+        }
+    }
+
+    fn before(a: &QueuedRetry, b: &QueuedRetry) -> bool {
+        (a.due_at, a.session) < (b.due_at, b.session)
+    }
+
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if !Self::before(&self.entries[index], &self.entries[parent]) {
+                break;
+            }
+            self.entries.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut index: usize) {
+        let len = self.entries.len();
+        loop {
+            let mut smallest = index;
+            for child in [2 * index + 1, 2 * index + 2] {
+                if child < len && Self::before(&self.entries[child], &self.entries[smallest]) {
+                    smallest = child;
+                }
+            }
+            if smallest == index {
+                break;
+            }
+            self.entries.swap(index, smallest);
+            index = smallest;
+        }
+    }
+}
+
+impl RetrySession {
+    /// Highest compute unit limit a transaction can request
+    pub const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+    /// Priority fee an attempt starts raising from when the original
+    /// transaction paid none (micro-lamports per compute unit)
+    pub const MIN_PRIORITY_FEE: u64 = 1_000;
+    /// Most optimizations kept per session
+    pub const MAX_OPTIMIZATIONS: usize = 16;
+    /// Most failure reasons kept per session
+    pub const MAX_FAILURE_REASONS: usize = 16;
+    /// Longest transaction signature stored, in base58 characters
+    pub const MAX_SIGNATURE_LEN: usize = 88;
+
+    /// `value` moved by `pct` percent
+    fn adjust(value: u64, pct: i16) -> u64 {
+        let scaled = value as u128 * (100 + pct.max(-100) as i128) as u128 / 100;
+        scaled.min(u64::MAX as u128) as u64
+    }
+
+    fn record_optimization(
+        &mut self,
+        optimization_type: OptimizationType,
+        before_value: u64,
+        after_value: u64,
+        applied_at_attempt: u8,
+    ) {
+        if self.optimizations_applied.len() >= Self::MAX_OPTIMIZATIONS {
+            self.optimizations_applied.remove(0);
+        }
+        self.optimizations_applied.push(RetryOptimization {
+            optimization_type,
+            before_value,
+            after_value,
+            applied_at_attempt,
+            was_successful: false,
+        });
+    }
+}
+
+/// Outcome of one attempt as resubmitted by the off-chain retrier
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RetryOutcomeReport {
+    /// Attempt this reports on; must be the session's current attempt
+    pub attempt: u8,
     pub result: AttemptResult,
+    /// Signature of the resubmitted transaction
     pub tx_signature: String,
+    /// Why the attempt failed, required for failures
     pub failure_reason: Option<RetryFailureReason>,
     pub compute_units_used: u32,
     pub fees_spent: u64,
-    pub optimization_applied: Option<RetryOptimization>,
+    /// Conditions the retrier measured, used for adaptive rescheduling
+    pub network: Option<NetworkConditions>,
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
 pub enum AttemptResult {
     Success,
     Failed,
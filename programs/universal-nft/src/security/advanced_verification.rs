@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+use solana_program::secp256k1_recover::secp256k1_recover;
+use crate::errors::UniversalNftError;
+#[cfg(feature = "governance")]
+use crate::events::ValidatorSetUpdated;
+#[cfg(feature = "governance")]
+use crate::governance::proposals::{Proposal, ProposalStatus, ProposalType};
+use crate::utils::{DomainUtils, SignatureUtils};
+
+/// Advanced Verification for Cross-Chain Messages
+/// Messages are accepted once K of the N registered TSS/observer signers have
+/// signed them, mirroring ZetaChain's observer model instead of trusting a
+/// single secp256k1 key. The signer set and threshold only change through an
+/// executed governance proposal.
+#[account]
+#[derive(InitSpace)]
+pub struct ValidatorSet {
+    /// Ethereum-format addresses of the observer signers
+    #[max_len(32)]
+    pub validators: Vec<[u8; 20]>,
+    /// Signatures required to accept a message
+    pub threshold: u8,
+    /// Incremented on every set change
+    pub epoch: u64,
+    /// ID of the last governance proposal applied to the set
+    pub last_proposal_id: u64,
+    /// Last update timestamp
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// An inbound message K of the N observers signed, consumed by on_call
+#[account]
+#[derive(InitSpace)]
+pub struct QuorumAttestation {
+    /// `LightClientUtils::event_leaf` of the attested message
+    pub leaf: [u8; 32],
+    /// Chain the message came from
+    pub chain_id: u64,
+    /// Validator set epoch that signed it
+    pub epoch: u64,
+    /// Distinct observers that signed
+    pub approvals: u8,
+    /// Whether on_call has already consumed this attestation
+    pub consumed: bool,
+    /// Timestamp the quorum was verified
+    pub attested_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// One observer signature over a message hash
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct QuorumSignature {
+    pub signature: [u8; 64],
+    pub recovery_id: u8,
+}
+
+/// Payload carried in a `SecurityUpdate` proposal's instruction data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ValidatorSetUpdate {
+    pub validators: Vec<[u8; 20]>,
+    pub threshold: u8,
+}
+
+impl ValidatorSet {
+    /// Maximum observer signers in one set
+    pub const MAX_VALIDATORS: usize = 32;
+
+    pub const INIT_SPACE: usize =
+        4 + Self::MAX_VALIDATORS * 20 + // validators
+        1 +  // threshold
+        8 +  // epoch
+        8 +  // last_proposal_id
+        8 +  // updated_at
+        1;   // bump
+
+    /// Initialize the set with its first signers
+    pub fn initialize(&mut self, validators: Vec<[u8; 20]>, threshold: u8, bump: u8) -> Result<()> {
+        Self::validate(&validators, threshold)?;
+
+        self.validators = validators;
+        self.threshold = threshold;
+        self.epoch = 0;
+        self.last_proposal_id = 0;
+        self.updated_at = Clock::get()?.unix_timestamp;
+        self.bump = bump;
+
+        log!("🔐 Validator set initialized: {}-of-{}", threshold, self.validators.len());
+        Ok(())
+    }
+
+    /// Replace the signers from a `SecurityUpdate` proposal targeting this
+    /// account. Only proposals the governance program executed, and so held
+    /// through its timelock, are accepted, each once and in ID order.
+    #[cfg(feature = "governance")]
+    pub fn apply_proposal(&mut self, set_key: Pubkey, proposal: &Proposal) -> Result<()> {
+        require!(
+            proposal.proposal_type == ProposalType::SecurityUpdate
+                && proposal.status == ProposalStatus::Executed
+                && proposal.id > self.last_proposal_id,
+            UniversalNftError::InvalidValidatorSet
+        );
+        require!(proposal.target == Some(set_key), UniversalNftError::InvalidValidatorSet);
+
+        let update = ValidatorSetUpdate::try_from_slice(&proposal.instruction_data)
+            .map_err(|_| UniversalNftError::InvalidValidatorSet)?;
+        Self::validate(&update.validators, update.threshold)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        self.validators = update.validators;
+        self.threshold = update.threshold;
+        self.last_proposal_id = proposal.id;
+        self.epoch = self.epoch
+            .checked_add(1)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        self.updated_at = now;
+
+        emit!(ValidatorSetUpdated {
+            validator_set: set_key,
+            proposal_id: proposal.id,
+            epoch: self.epoch,
+            validator_count: self.validators.len() as u8,
+            threshold: self.threshold,
+            timestamp: now,
+        });
+
+        log!("🔐 Validator set epoch {}: {}-of-{}", self.epoch, self.threshold, self.validators.len());
+        Ok(())
+    }
+
+    /// Hash the observers sign to attest a message from `source_chain_id`.
+    /// The leaf already commits to the sender and message, so no nonce is
+    /// mixed in; each leaf can be attested and consumed once.
+    pub fn signing_hash(source_chain_id: u64, leaf: &[u8; 32]) -> [u8; 32] {
+        DomainUtils::domain_message_hash(&DomainUtils::inbound_domain(source_chain_id), 0, leaf)
+    }
+
+    /// Count distinct registered signers over `message_hash` and require the
+    /// threshold. Signatures from unknown keys are ignored; a signer counted
+    /// twice is counted once.
+    pub fn verify_quorum(&self, message_hash: &[u8; 32], signatures: &[QuorumSignature]) -> Result<u8> {
+        require!(
+            signatures.len() <= Self::MAX_VALIDATORS,
+            UniversalNftError::InvalidMessageFormat
+        );
+
+        let mut seen: u32 = 0;
+        let mut approvals: u8 = 0;
+
+        for sig in signatures {
+            let recovered = match secp256k1_recover(message_hash, sig.recovery_id, &sig.signature) {
+                Ok(pubkey) => pubkey,
+                Err(_) => continue,
+            };
+            let address = SignatureUtils::pubkey_to_ethereum_address(&recovered.to_bytes());
+
+            if let Some(index) = self.validators.iter().position(|v| *v == address) {
+                let bit = 1u32 << index;
+                if seen & bit == 0 {
+                    seen |= bit;
+                    approvals += 1;
+                }
+            }
+        }
+
+        require!(approvals >= self.threshold, UniversalNftError::QuorumNotReached);
+
+        log!("✅ Quorum reached: {}-of-{} (epoch {})", approvals, self.validators.len(), self.epoch);
+        Ok(approvals)
+    }
+
+    fn validate(validators: &[[u8; 20]], threshold: u8) -> Result<()> {
+        require!(
+            !validators.is_empty() && validators.len() <= Self::MAX_VALIDATORS,
+            UniversalNftError::InvalidValidatorSet
+        );
+        require!(
+            threshold > 0 && (threshold as usize) <= validators.len(),
+            UniversalNftError::InvalidValidatorSet
+        );
+        for (i, v) in validators.iter().enumerate() {
+            require!(!validators[..i].contains(v), UniversalNftError::InvalidValidatorSet);
+        }
+        Ok(())
+    }
+}
+
+impl QuorumAttestation {
+    /// Record a verified quorum over `leaf`. The record is keyed by leaf
+    /// alone, so one left unconsumed by an earlier validator set epoch, which
+    /// on_call no longer accepts, is overwritten by the current set's
+    /// attestation. A consumed record is never reopened.
+    pub fn record(
+        &mut self,
+        leaf: [u8; 32],
+        chain_id: u64,
+        epoch: u64,
+        approvals: u8,
+        now: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(!self.consumed, UniversalNftError::QuorumAttestationConsumed);
+
+        self.leaf = leaf;
+        self.chain_id = chain_id;
+        self.epoch = epoch;
+        self.approvals = approvals;
+        self.consumed = false;
+        self.attested_at = now;
+        self.bump = bump;
+        Ok(())
+    }
+}
@@ -1,11 +1,17 @@
 use anchor_lang::prelude::*;
 use crate::errors::UniversalNftError;
+use crate::state::{RuleEnforcement, SecurityRule};
 
 /// Advanced Circuit Breaker Pattern for Cross-Chain Operations
-/// Implements automatic shutdowns when anomalies are detected
+/// Implements automatic shutdowns when anomalies are detected, one breaker per
+/// destination chain. A tripped breaker recovers on its own: after the open
+/// period it admits a single probe transfer per interval, and a successful
+/// probe closes it again.
 #[account]
 #[derive(InitSpace)]
 pub struct CircuitBreaker {
+    /// Destination chain this breaker guards
+    pub chain_id: u64,
     /// Current circuit state
     pub state: CircuitState,
     /// Failure count in current window
@@ -16,6 +22,12 @@ pub struct CircuitBreaker {
     pub window_start: i64,
     /// Last state change timestamp
     pub last_state_change: i64,
+    /// Timestamp the last half-open probe was admitted
+    pub last_probe_at: i64,
+    /// Whether a half-open probe is awaiting its outcome
+    pub probe_in_flight: bool,
+    /// Operations let through in shadow mode that the breaker would have blocked
+    pub shadow_blocks: u64,
     /// Configuration parameters
     pub config: CircuitConfig,
     /// Authority that can manually override
@@ -24,11 +36,11 @@ pub struct CircuitBreaker {
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
 pub enum CircuitState {
     /// Normal operation
     Closed,
-    /// Partial restrictions - some operations allowed
+    /// Recovering - one probe operation allowed per probe interval
     HalfOpen,
     /// Full shutdown - all operations blocked
     Open,
@@ -36,7 +48,7 @@ pub enum CircuitState {
     ManualOverride,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace)]
 pub struct CircuitConfig {
     /// Failure threshold to trigger circuit opening
     pub failure_threshold: u64,
@@ -44,10 +56,10 @@ pub struct CircuitConfig {
     pub failure_window: i64,
     /// Minimum time circuit stays open (seconds)
     pub min_open_duration: i64,
-    /// Success threshold to close circuit from half-open
-    pub success_threshold: u64,
-    /// Maximum operations per window in half-open state
-    pub half_open_limit: u64,
+    /// Minimum time between half-open probes (seconds)
+    pub probe_interval: i64,
+    /// Whether a tripped breaker blocks operations or only reports them
+    pub enforcement: RuleEnforcement,
 }
 
 impl Default for CircuitConfig {
@@ -56,92 +68,84 @@ impl Default for CircuitConfig {
             failure_threshold: 5,
             failure_window: 300,      // 5 minutes
             min_open_duration: 600,   // 10 minutes
-            success_threshold: 3,
-            half_open_limit: 10,
+            probe_interval: 60,       // 1 minute
+            enforcement: RuleEnforcement::Enforce,
         }
     }
 }
 
 impl CircuitBreaker {
     pub const INIT_SPACE: usize = 
+        8 +  // chain_id
         1 +  // state
         8 +  // failure_count
         8 +  // success_count
         8 +  // window_start
         8 +  // last_state_change
-        8 * 5 + // config (5 u64s)
+        8 +  // last_probe_at
+        1 +  // probe_in_flight
+        8 +  // shadow_blocks
+        8 * 4 + 1 + // config (4 u64/i64s, enforcement)
         32 + // authority
         1;   // bump
 
-    /// Initialize circuit breaker
-    pub fn initialize(&mut self, authority: Pubkey, config: Option<CircuitConfig>, bump: u8) {
+    /// Initialize circuit breaker for a destination chain
+    pub fn initialize(
+        &mut self,
+        chain_id: u64,
+        authority: Pubkey,
+        config: Option<CircuitConfig>,
+        bump: u8,
+    ) {
+        self.chain_id = chain_id;
         self.state = CircuitState::Closed;
         self.failure_count = 0;
         self.success_count = 0;
         self.window_start = Clock::get().unwrap().unix_timestamp;
         self.last_state_change = self.window_start;
+        self.last_probe_at = 0;
+        self.probe_in_flight = false;
+        self.shadow_blocks = 0;
         self.config = config.unwrap_or_default();
         self.authority = authority;
         self.bump = bump;
     }
 
-    /// Check if operation should be allowed
-    pub fn check_operation_allowed(&mut self, operation_type: OperationType) -> Result<()> {
-        let now = Clock::get()?.unix_timestamp;
-        
+    /// Check if operation should be allowed. In shadow mode the breaker still
+    /// trips and recovers, but operations it would block are only reported.
+    pub fn check_operation_allowed(&mut self) -> Result<()> {
+        self.check_operation_allowed_at(Clock::get()?.unix_timestamp)
+    }
+
+    /// `check_operation_allowed` for an operation at `now`
+    pub fn check_operation_allowed_at(&mut self, now: i64) -> Result<()> {
+        if self.config.enforcement == RuleEnforcement::Off {
+            return Ok(());
+        }
+
         // Update window if needed
         self.update_window(now)?;
         
-        match self.state {
-            CircuitState::Closed => {
-                // Normal operation - check if we should open circuit
-                if self.should_open_circuit(now) {
-                    self.transition_to_open(now)?;
-                    return Err(UniversalNftError::CircuitBreakerOpen.into());
-                }
-                Ok(())
-            },
-            CircuitState::HalfOpen => {
-                // Limited operation - check limits
-                let total_ops = self.failure_count + self.success_count;
-                if total_ops >= self.config.half_open_limit {
-                    return Err(UniversalNftError::CircuitBreakerRateLimit.into());
-                }
-                
-                // Check if we should close or open based on recent performance
-                if self.should_close_circuit() {
-                    self.transition_to_closed(now)?;
-                } else if self.should_open_circuit(now) {
-                    self.transition_to_open(now)?;
-                    return Err(UniversalNftError::CircuitBreakerOpen.into());
-                }
-                Ok(())
-            },
-            CircuitState::Open => {
-                // Check if enough time has passed to try half-open
-                if now - self.last_state_change >= self.config.min_open_duration {
-                    self.transition_to_half_open(now)?;
-                    Ok(())
-                } else {
-                    Err(UniversalNftError::CircuitBreakerOpen.into())
-                }
-            },
-            CircuitState::ManualOverride => {
-                // Manual override - allow all operations
-                Ok(())
-            }
+        let breach = self.evaluate(now)?;
+        if breach.is_some() && self.config.enforcement == RuleEnforcement::Shadow {
+            self.shadow_blocks = self.shadow_blocks.saturating_add(1);
         }
+        self.config.enforcement.apply(SecurityRule::CircuitBreaker, self.chain_id, breach)
     }
 
     /// Record operation success
     pub fn record_success(&mut self) -> Result<()> {
-        let now = Clock::get()?.unix_timestamp;
+        self.record_success_at(Clock::get()?.unix_timestamp)
+    }
+
+    /// `record_success` for an operation that ended at `now`
+    pub fn record_success_at(&mut self, now: i64) -> Result<()> {
         self.update_window(now)?;
         
         self.success_count = self.success_count.saturating_add(1);
         
-        // Check if we should transition state
-        if self.state == CircuitState::HalfOpen && self.should_close_circuit() {
+        // A successful probe closes the breaker without authority intervention
+        if self.state == CircuitState::HalfOpen && self.probe_in_flight {
             self.transition_to_closed(now)?;
         }
         
@@ -150,13 +154,20 @@ impl CircuitBreaker {
 
     /// Record operation failure
     pub fn record_failure(&mut self) -> Result<()> {
-        let now = Clock::get()?.unix_timestamp;
+        self.record_failure_at(Clock::get()?.unix_timestamp)
+    }
+
+    /// `record_failure` for an operation that ended at `now`. The failure
+    /// that reaches the threshold trips the breaker here, in an instruction
+    /// that succeeds, so the trip persists.
+    pub fn record_failure_at(&mut self, now: i64) -> Result<()> {
         self.update_window(now)?;
         
         self.failure_count = self.failure_count.saturating_add(1);
         
-        // Check if we should open circuit
-        if self.should_open_circuit(now) {
+        // A failed probe re-opens the breaker for another full open period
+        let probe_failed = self.state == CircuitState::HalfOpen && self.probe_in_flight;
+        if probe_failed || self.should_open_circuit(now) {
             self.transition_to_open(now)?;
         }
         
@@ -196,6 +207,39 @@ impl CircuitBreaker {
     }
 
     // Private helper methods
+
+    /// Advance the breaker for an operation at `now` and return why it would
+    /// be blocked, if it would
+    fn evaluate(&mut self, now: i64) -> Result<Option<UniversalNftError>> {
+        match self.state {
+            CircuitState::Closed => {
+                // record_failure trips the breaker; a blocked operation's
+                // writes roll back, so tripping here would not persist
+                if self.should_open_circuit(now) {
+                    return Ok(Some(UniversalNftError::CircuitBreakerOpen));
+                }
+                Ok(None)
+            },
+            CircuitState::HalfOpen => {
+                // One probe at a time, spaced by the probe interval
+                Ok(self.admit_probe(now))
+            },
+            CircuitState::Open => {
+                // Check if enough time has passed to try half-open
+                if now - self.last_state_change >= self.config.min_open_duration {
+                    self.transition_to_half_open(now)?;
+                    Ok(self.admit_probe(now))
+                } else {
+                    Ok(Some(UniversalNftError::CircuitBreakerOpen))
+                }
+            },
+            CircuitState::ManualOverride => {
+                // Manual override - allow all operations
+                Ok(None)
+            }
+        }
+    }
+
     fn update_window(&mut self, now: i64) -> Result<()> {
         if now - self.window_start >= self.config.failure_window {
             self.window_start = now;
@@ -209,14 +253,24 @@ impl CircuitBreaker {
         self.failure_count >= self.config.failure_threshold
     }
 
-    fn should_close_circuit(&self) -> bool {
-        self.success_count >= self.config.success_threshold && self.failure_count == 0
+    /// Admit a probe if none is pending and the probe interval has elapsed.
+    /// A probe whose outcome never arrives is replaced after one interval.
+    fn admit_probe(&mut self, now: i64) -> Option<UniversalNftError> {
+        if now - self.last_probe_at < self.config.probe_interval {
+            return Some(UniversalNftError::CircuitBreakerRateLimit);
+        }
+
+        self.probe_in_flight = true;
+        self.last_probe_at = now;
+        log!("Circuit breaker probe admitted for chain {}", self.chain_id);
+        None
     }
 
     fn transition_to_open(&mut self, now: i64) -> Result<()> {
         self.state = CircuitState::Open;
         self.last_state_change = now;
-        msg!("🚨 Circuit breaker OPENED - system protection activated");
+        self.probe_in_flight = false;
+        log!("🚨 Circuit breaker OPENED - system protection activated");
         Ok(())
     }
 
@@ -225,7 +279,8 @@ impl CircuitBreaker {
         self.last_state_change = now;
         self.failure_count = 0;
         self.success_count = 0;
-        msg!("⚠️ Circuit breaker HALF-OPEN - limited operations allowed");
+        self.probe_in_flight = false;
+        log!("⚠️ Circuit breaker HALF-OPEN - probing chain {}", self.chain_id);
         Ok(())
     }
 
@@ -234,7 +289,8 @@ impl CircuitBreaker {
         self.last_state_change = now;
         self.failure_count = 0;
         self.success_count = 0;
-        msg!("✅ Circuit breaker CLOSED - normal operations resumed");
+        self.probe_in_flight = false;
+        log!("✅ Circuit breaker CLOSED - normal operations resumed");
         Ok(())
     }
 }
@@ -248,14 +304,6 @@ pub struct CircuitHealthMetrics {
     pub time_in_current_state: i64,
 }
 
-#[derive(Clone, Copy)]
-pub enum OperationType {
-    CrossChainTransfer,
-    NFTMinting,
-    MetadataUpdate,
-    SignatureVerification,
-}
-
 /// Enhanced error types for circuit breaker
 impl From<CircuitState> for UniversalNftError {
     fn from(state: CircuitState) -> Self {
@@ -0,0 +1,258 @@
+use anchor_lang::prelude::*;
+use crate::errors::UniversalNftError;
+use crate::events::IncidentLevelChanged;
+
+/// Security Incident Mode for Universal NFT Protocol
+/// Tiered restrictions the emergency role raises during an incident. Each level
+/// includes the restrictions of the levels below it and expires on its own
+/// timer, stepping down one level at a time so a forgotten escalation never
+/// leaves the protocol restricted indefinitely.
+#[account]
+#[derive(InitSpace)]
+pub struct IncidentMode {
+    /// Emergency role allowed to change the level and flag mints
+    pub emergency_authority: Pubkey,
+    /// Current incident level
+    pub level: IncidentLevel,
+    /// Timestamp of the last level change
+    pub level_changed_at: i64,
+    /// Time the current level steps down one level; zero when normal
+    pub expires_at: i64,
+    /// Per-level de-escalation timers
+    pub config: IncidentConfig,
+    /// Mints frozen while in lockdown
+    #[max_len(16)]
+    pub flagged_mints: Vec<Pubkey>,
+    /// PDA bump
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, InitSpace)]
+pub enum IncidentLevel {
+    /// Normal operation
+    Normal,
+    /// Level 1 - rate limits halved
+    Elevated,
+    /// Level 2 - outbound transfers paused
+    OutboundPaused,
+    /// Level 3 - full pause and flagged mints frozen
+    Lockdown,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct IncidentConfig {
+    /// Seconds Level 1 lasts before returning to normal
+    pub elevated_duration: i64,
+    /// Seconds Level 2 lasts before stepping down to Level 1
+    pub outbound_paused_duration: i64,
+    /// Seconds Level 3 lasts before stepping down to Level 2
+    pub lockdown_duration: i64,
+}
+
+impl Default for IncidentConfig {
+    fn default() -> Self {
+        Self {
+            elevated_duration: 6 * 3600,        // 6 hours
+            outbound_paused_duration: 2 * 3600, // 2 hours
+            lockdown_duration: 3600,            // 1 hour
+        }
+    }
+}
+
+impl IncidentLevel {
+    /// Numeric level used in events
+    pub fn as_u8(&self) -> u8 {
+        match self {
+            IncidentLevel::Normal => 0,
+            IncidentLevel::Elevated => 1,
+            IncidentLevel::OutboundPaused => 2,
+            IncidentLevel::Lockdown => 3,
+        }
+    }
+
+    /// Level reached after one automatic de-escalation step
+    pub fn step_down(&self) -> IncidentLevel {
+        match self {
+            IncidentLevel::Lockdown => IncidentLevel::OutboundPaused,
+            IncidentLevel::OutboundPaused => IncidentLevel::Elevated,
+            _ => IncidentLevel::Normal,
+        }
+    }
+}
+
+impl IncidentConfig {
+    /// How long `level` lasts before stepping down
+    pub fn duration(&self, level: IncidentLevel) -> i64 {
+        match level {
+            IncidentLevel::Normal => 0,
+            IncidentLevel::Elevated => self.elevated_duration,
+            IncidentLevel::OutboundPaused => self.outbound_paused_duration,
+            IncidentLevel::Lockdown => self.lockdown_duration,
+        }
+    }
+}
+
+impl IncidentMode {
+    /// Maximum mints that can be flagged for a lockdown freeze
+    pub const MAX_FLAGGED_MINTS: usize = 16;
+
+    pub const INIT_SPACE: usize =
+        32 + // emergency_authority
+        1 +  // level
+        8 +  // level_changed_at
+        8 +  // expires_at
+        8 * 3 + // config (3 i64s)
+        4 + Self::MAX_FLAGGED_MINTS * 32 + // flagged_mints
+        1;   // bump
+
+    /// Initialize incident mode at the normal level
+    pub fn initialize(
+        &mut self,
+        emergency_authority: Pubkey,
+        config: Option<IncidentConfig>,
+        bump: u8,
+    ) -> Result<()> {
+        let config = config.unwrap_or_default();
+        require!(
+            config.elevated_duration > 0
+                && config.outbound_paused_duration > 0
+                && config.lockdown_duration > 0,
+            UniversalNftError::InvalidIncidentConfig
+        );
+
+        self.emergency_authority = emergency_authority;
+        self.level = IncidentLevel::Normal;
+        self.level_changed_at = Clock::get()?.unix_timestamp;
+        self.expires_at = 0;
+        self.config = config;
+        self.flagged_mints = Vec::new();
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Switch to `level` (emergency role only), restarting its timer
+    pub fn set_level(&mut self, signer: Pubkey, level: IncidentLevel) -> Result<()> {
+        require!(signer == self.emergency_authority, UniversalNftError::Unauthorized);
+
+        let now = Clock::get()?.unix_timestamp;
+        let expires_at = match level {
+            IncidentLevel::Normal => 0,
+            _ => now
+                .checked_add(self.config.duration(level))
+                .ok_or(UniversalNftError::ArithmeticOverflow)?,
+        };
+        self.change_level(level, expires_at, now, false);
+        Ok(())
+    }
+
+    /// Apply any de-escalation steps whose timers have run out. Each step's
+    /// timer starts when the previous one expired, so time spent without a
+    /// refresh still counts.
+    pub fn refresh(&mut self, now: i64) -> Result<()> {
+        while self.level != IncidentLevel::Normal && now >= self.expires_at {
+            let next = self.level.step_down();
+            let expires_at = match next {
+                IncidentLevel::Normal => 0,
+                _ => self.expires_at
+                    .checked_add(self.config.duration(next))
+                    .ok_or(UniversalNftError::ArithmeticOverflow)?,
+            };
+            let changed_at = self.expires_at;
+            self.change_level(next, expires_at, changed_at, true);
+        }
+        Ok(())
+    }
+
+    /// Level in effect at `now` once expired timers are applied, without
+    /// recording the steps. `refresh` records them.
+    pub fn level_at(&self, now: i64) -> IncidentLevel {
+        let mut level = self.level;
+        let mut expires_at = self.expires_at;
+        while level != IncidentLevel::Normal && now >= expires_at {
+            level = level.step_down();
+            expires_at = expires_at.saturating_add(self.config.duration(level));
+        }
+        level
+    }
+
+    /// Flag a mint to be frozen during lockdown (emergency role only)
+    pub fn flag_mint(&mut self, signer: Pubkey, mint: Pubkey) -> Result<()> {
+        require!(signer == self.emergency_authority, UniversalNftError::Unauthorized);
+        if self.flagged_mints.contains(&mint) {
+            return Ok(());
+        }
+        require!(
+            self.flagged_mints.len() < Self::MAX_FLAGGED_MINTS,
+            UniversalNftError::TooManyFlaggedMints
+        );
+        self.flagged_mints.push(mint);
+        log!("Mint flagged for incident lockdown: {}", mint);
+        Ok(())
+    }
+
+    /// Remove a mint from the lockdown list (emergency role only)
+    pub fn unflag_mint(&mut self, signer: Pubkey, mint: Pubkey) -> Result<()> {
+        require!(signer == self.emergency_authority, UniversalNftError::Unauthorized);
+        self.flagged_mints.retain(|m| *m != mint);
+        Ok(())
+    }
+
+    /// Rate limit in effect for a base limit; halved from Level 1 up
+    pub fn effective_rate_limit(&self, base_limit: u64) -> u64 {
+        if self.level >= IncidentLevel::Elevated {
+            base_limit / 2
+        } else {
+            base_limit
+        }
+    }
+
+    /// Reject outbound transfers from Level 2 up
+    pub fn check_outbound_allowed(&self) -> Result<()> {
+        require!(
+            self.level < IncidentLevel::OutboundPaused,
+            UniversalNftError::IncidentOutboundPaused
+        );
+        Ok(())
+    }
+
+    /// Reject every state-changing operation at Level 3
+    pub fn check_operation_allowed(&self) -> Result<()> {
+        require!(
+            self.level < IncidentLevel::Lockdown,
+            UniversalNftError::IncidentLockdown
+        );
+        Ok(())
+    }
+
+    /// Whether `mint` is frozen by the current lockdown
+    pub fn is_mint_frozen(&self, mint: &Pubkey) -> bool {
+        self.level == IncidentLevel::Lockdown && self.flagged_mints.contains(mint)
+    }
+
+    fn change_level(&mut self, level: IncidentLevel, expires_at: i64, now: i64, automatic: bool) {
+        let previous = self.level;
+        self.level = level;
+        self.level_changed_at = now;
+        self.expires_at = expires_at;
+
+        emit!(IncidentLevelChanged {
+            previous_level: previous.as_u8(),
+            level: level.as_u8(),
+            automatic,
+            expires_at,
+            flagged_mints: if level == IncidentLevel::Lockdown {
+                self.flagged_mints.len() as u8
+            } else {
+                0
+            },
+            timestamp: now,
+        });
+
+        log!(
+            "Incident level {} -> {}{}",
+            previous.as_u8(),
+            level.as_u8(),
+            if automatic { " (automatic de-escalation)" } else { "" }
+        );
+    }
+}
\ No newline at end of file
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use crate::errors::UniversalNftError;
-use std::collections::HashMap;
+#[cfg(feature = "governance")]
+use crate::governance::proposals::{Proposal, ProposalStatus, ProposalType};
+use crate::state::{BehaviorBaseline, ChainRiskRegistry, CorridorStats, RuleEnforcement, SecurityRule};
 
 /// Advanced ML-Inspired Fraud Detection System
 /// Detects suspicious patterns in cross-chain NFT operations
@@ -13,6 +15,8 @@ pub struct FraudDetectionEngine {
     pub suspicious_patterns: u64,
     /// Total operations analyzed
     pub total_operations: u64,
+    /// Suspicious operations let through because the threshold runs in shadow mode
+    pub shadow_blocks: u64,
     /// Last analysis timestamp
     pub last_analysis: i64,
     /// Configuration parameters
@@ -21,13 +25,15 @@ pub struct FraudDetectionEngine {
     pub authority: Pubkey,
     /// Recent operation signatures for pattern detection
     pub recent_operations: [OperationSignature; 20],
+    /// ID of the last governance proposal applied to `config`
+    pub last_config_proposal: u64,
     /// Current position in circular buffer
     pub operation_index: u8,
     /// PDA bump
     pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
 pub struct FraudConfig {
     /// Risk threshold for blocking operations
     pub risk_threshold: u16,
@@ -39,9 +45,25 @@ pub struct FraudConfig {
     pub min_reputation: u16,
     /// Geographic risk multiplier
     pub geo_risk_multiplier: u16,
+    /// Weight of each risk factor in the combined score
+    pub weights: RiskWeights,
+    /// Whether crossing `risk_threshold` flags operations or is only reported
+    pub enforcement: RuleEnforcement,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+/// Percentage weight of each risk factor; they sum to 100
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct RiskWeights {
+    pub velocity: u8,
+    pub chain_pair: u8,
+    pub value_pattern: u8,
+    pub baseline: u8,
+    pub behavior: u8,
+    pub route: u8,
+    pub reputation: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
 pub struct OperationSignature {
     /// Operation type
     pub op_type: u8,
@@ -67,19 +89,79 @@ impl Default for FraudConfig {
             velocity_threshold: 10,   // 10 ops per minute
             min_reputation: 500,      // 50% minimum reputation
             geo_risk_multiplier: 150, // 1.5x for high-risk regions
+            weights: RiskWeights::default(),
+            enforcement: RuleEnforcement::Enforce,
+        }
+    }
+}
+
+impl Default for RiskWeights {
+    fn default() -> Self {
+        Self {
+            velocity: 25,
+            chain_pair: 20,
+            value_pattern: 15,
+            baseline: 10,
+            behavior: 15,
+            route: 10,
+            reputation: 5,
         }
     }
 }
 
+impl RiskWeights {
+    fn total(&self) -> u16 {
+        [self.velocity, self.chain_pair, self.value_pattern, self.baseline, self.behavior, self.route, self.reputation]
+            .iter()
+            .map(|w| *w as u16)
+            .sum()
+    }
+}
+
+impl FraudConfig {
+    /// Prefix of a `ProtocolUpdate` proposal's instruction data that carries
+    /// a fraud config; the Borsh-encoded config follows
+    pub const PROPOSAL_TAG: [u8; 8] = *b"fraudcfg";
+
+    /// Require every parameter to lie in its safe range, so a proposal can't
+    /// switch fraud checks off or make them block everything
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            (100..=1000).contains(&self.risk_threshold)
+                && (60..=86_400).contains(&self.analysis_window)
+                && (1..=1_000).contains(&self.velocity_threshold)
+                && self.min_reputation <= 1000
+                && (100..=300).contains(&self.geo_risk_multiplier)
+                && self.weights.total() == 100,
+            UniversalNftError::InvalidFraudConfig
+        );
+        Ok(())
+    }
+
+    /// Decode and validate the config a proposal's instruction data carries
+    pub fn from_proposal_data(data: &[u8]) -> Result<Self> {
+        let payload = data
+            .strip_prefix(Self::PROPOSAL_TAG.as_ref())
+            .ok_or(UniversalNftError::InvalidFraudConfig)?;
+        let config = Self::try_from_slice(payload).map_err(|_| UniversalNftError::InvalidFraudConfig)?;
+        config.validate()?;
+        Ok(config)
+    }
+}
+
 impl FraudDetectionEngine {
     pub const INIT_SPACE: usize = 
         2 +     // risk_score
         8 +     // suspicious_patterns
         8 +     // total_operations
+        8 +     // shadow_blocks
         8 +     // last_analysis
         2 * 5 + // config (5 u16s)
+        7 +     // config weights
+        1 +     // config enforcement
         32 +    // authority
         (2 + 8 + 8 + 8 + 4 + 4 + 2) * 20 + // recent_operations array
+        8 +     // last_config_proposal
         1 +     // operation_index
         1;      // bump
 
@@ -88,14 +170,34 @@ impl FraudDetectionEngine {
         self.risk_score = 0;
         self.suspicious_patterns = 0;
         self.total_operations = 0;
+        self.shadow_blocks = 0;
         self.last_analysis = Clock::get().unwrap().unix_timestamp;
         self.config = config.unwrap_or_default();
         self.authority = authority;
         self.recent_operations = [OperationSignature::default(); 20];
+        self.last_config_proposal = 0;
         self.operation_index = 0;
         self.bump = bump;
     }
 
+    /// Apply the fraud config carried by a governance `ProtocolUpdate`
+    /// proposal. Only proposals the governance program executed, and so held
+    /// through its timelock, are accepted, each once and in ID order.
+    #[cfg(feature = "governance")]
+    pub fn apply_config_proposal(&mut self, proposal: &Proposal) -> Result<()> {
+        require!(
+            proposal.proposal_type == ProposalType::ProtocolUpdate
+                && proposal.status == ProposalStatus::Executed
+                && proposal.id > self.last_config_proposal,
+            UniversalNftError::InvalidFraudConfigProposal
+        );
+        self.config = FraudConfig::from_proposal_data(&proposal.instruction_data)?;
+        self.last_config_proposal = proposal.id;
+
+        log!("Fraud config updated by proposal {}", proposal.id);
+        Ok(())
+    }
+
     /// Analyze operation for fraud indicators
     pub fn analyze_operation(&mut self, operation: &OperationAnalysisInput) -> Result<FraudAnalysisResult> {
         let now = Clock::get()?.unix_timestamp;
@@ -126,7 +228,7 @@ impl FraudDetectionEngine {
         let suspicious_patterns = self.detect_patterns()?;
         self.suspicious_patterns = self.suspicious_patterns.saturating_add(suspicious_patterns as u64);
 
-        let result = FraudAnalysisResult {
+        let mut result = FraudAnalysisResult {
             risk_score,
             is_suspicious: risk_score > self.config.risk_threshold,
             detected_patterns: suspicious_patterns,
@@ -134,9 +236,29 @@ impl FraudDetectionEngine {
             confidence: self.calculate_confidence(),
         };
 
+        // Outside enforcement the score is kept but never acted on
+        match self.config.enforcement {
+            RuleEnforcement::Enforce => {}
+            RuleEnforcement::Shadow if result.is_suspicious => {
+                self.shadow_blocks = self.shadow_blocks.saturating_add(1);
+                RuleEnforcement::Shadow.apply(
+                    SecurityRule::FraudScore,
+                    operation.destination_chain_id,
+                    Some(UniversalNftError::FraudRiskTooHigh),
+                )?;
+                result.is_suspicious = false;
+                result.recommendation = FraudRecommendation::Monitor;
+            }
+            RuleEnforcement::Shadow => {}
+            RuleEnforcement::Off => {
+                result.is_suspicious = false;
+                result.recommendation = FraudRecommendation::Allow;
+            }
+        }
+
         // Log significant findings
         if result.is_suspicious {
-            msg!("🚨 FRAUD ALERT: High-risk operation detected (score: {})", risk_score);
+            log!("🚨 FRAUD ALERT: High-risk operation detected (score: {})", risk_score);
         }
 
         self.last_analysis = now;
@@ -152,16 +274,21 @@ impl FraudDetectionEngine {
         risk_factors.push(("velocity", velocity_risk));
 
         // 2. Chain Pair Risk Analysis
-        let chain_risk = self.analyze_chain_pair_risk(operation.source_chain_id, operation.destination_chain_id);
+        let chain_risk = self.analyze_chain_pair_risk(
+            operation.source_chain_id,
+            operation.destination_chain_id,
+            operation.corridor.as_ref(),
+            operation.risk_registry.as_ref(),
+        );
         risk_factors.push(("chain_pair", chain_risk));
 
         // 3. Value Pattern Analysis
         let value_risk = self.analyze_value_patterns(operation.value);
         risk_factors.push(("value_pattern", value_risk));
 
-        // 4. Time-based Analysis (unusual hours, etc.)
-        let temporal_risk = self.analyze_temporal_patterns(now);
-        risk_factors.push(("temporal", temporal_risk));
+        // 4. Deviation from the user's own baseline
+        let baseline_risk = self.analyze_baseline_deviation(operation, now);
+        risk_factors.push(("baseline", baseline_risk));
 
         // 5. User Behavior Analysis
         let behavior_risk = self.analyze_user_behavior(&operation.user_address, now)?;
@@ -178,8 +305,8 @@ impl FraudDetectionEngine {
         // Weighted risk calculation
         let total_risk = self.calculate_weighted_risk(&risk_factors);
         
-        msg!("🔍 Risk Analysis: velocity={}, chain={}, value={}, temporal={}, behavior={}, route={}, reputation={} -> total={}",
-            velocity_risk, chain_risk, value_risk, temporal_risk, behavior_risk, route_risk, reputation_risk, total_risk);
+        log!("🔍 Risk Analysis: velocity={}, chain={}, value={}, baseline={}, behavior={}, route={}, reputation={} -> total={}",
+            velocity_risk, chain_risk, value_risk, baseline_risk, behavior_risk, route_risk, reputation_risk, total_risk);
 
         Ok(total_risk.min(1000))
     }
@@ -201,29 +328,29 @@ impl FraudDetectionEngine {
         }
     }
 
-    /// Analyze risk based on chain pair
-    fn analyze_chain_pair_risk(&self, source: u64, destination: u64) -> u16 {
-        // High-risk chain pairs (based on known attack patterns)
-        let high_risk_chains = [99999, 88888, 77777]; // Example suspicious chain IDs
-        
-        let source_risk = if high_risk_chains.contains(&source) { 200 } else { 0 };
-        let dest_risk = if high_risk_chains.contains(&destination) { 200 } else { 0 };
-        
-        // Unusual chain combinations
-        let combination_risk = match (source, destination) {
-            (900, 1) | (1, 900) => 50,  // Solana <-> Ethereum (normal)
-            (900, 56) | (56, 900) => 50, // Solana <-> BSC (normal)
-            (900, 7000) | (7000, 900) => 30, // Solana <-> ZetaChain (normal)
-            _ => 100, // Unusual combinations
-        };
-
-        (source_risk + dest_risk + combination_risk).min(500)
+    /// Analyze risk based on chain pair and how its corridor has behaved
+    fn analyze_chain_pair_risk(
+        &self,
+        source: u64,
+        destination: u64,
+        corridor: Option<&CorridorStats>,
+        registry: Option<&ChainRiskRegistry>,
+    ) -> u16 {
+        // Decayed failure and latency history of the corridor; corridors
+        // without enough history count as unusual
+        let corridor_risk = corridor.map_or(CorridorStats::UNKNOWN_RISK, CorridorStats::risk);
+
+        // Governance's per-chain risks and pair overrides
+        match registry {
+            Some(registry) => registry.pair_risk(source, destination, corridor_risk),
+            None => corridor_risk,
+        }
     }
 
     /// Analyze value patterns for suspicious amounts
     fn analyze_value_patterns(&self, value: u64) -> u16 {
         // Round number detection (often used in attacks)
-        let round_number_risk = if value % 1000000 == 0 && value > 0 { 100 } else { 0 };
+        let round_number_risk = if value.is_multiple_of(1000000) && value > 0 { 100 } else { 0 };
         
         // Extremely high values
         let high_value_risk = if value > 1000000000000 { 200 } else { 0 }; // > 1T units
@@ -234,16 +361,13 @@ impl FraudDetectionEngine {
         (round_number_risk + high_value_risk + exact_amount_risk).min(300)
     }
 
-    /// Analyze temporal patterns
-    fn analyze_temporal_patterns(&self, timestamp: i64) -> u16 {
-        // Convert to hours (UTC)
-        let hour = ((timestamp % 86400) / 3600) as u8;
-        
-        // Higher risk during unusual hours (2-6 AM UTC when most users sleep)
-        match hour {
-            2..=5 => 100,
-            6..=7 | 23..=1 => 50,
-            _ => 0,
+    /// Analyze how far the operation strays from the user's own baseline:
+    /// value, hour and destination compared with what they usually do, so no
+    /// hour or chain is risky for everyone
+    fn analyze_baseline_deviation(&self, operation: &OperationAnalysisInput, now: i64) -> u16 {
+        match &operation.baseline {
+            Some(baseline) => baseline.deviation(operation.value, operation.destination_chain_id, now),
+            None => BehaviorBaseline::UNKNOWN_RISK,
         }
     }
 
@@ -270,12 +394,12 @@ impl FraudDetectionEngine {
         // Complex routing through multiple chains increases risk
         let route_complexity = operation.route_hops.unwrap_or(1);
         
-        match route_complexity {
-            1 => 0,      // Direct transfer
+        Ok(match route_complexity {
+            0 | 1 => 0,  // Direct transfer
             2 => 50,     // One intermediate
             3 => 150,    // Two intermediates  
             4..=u8::MAX => 300, // Highly complex routing
-        }
+        })
     }
 
     /// Calculate reputation-based risk
@@ -289,14 +413,15 @@ impl FraudDetectionEngine {
 
     /// Calculate weighted risk from multiple factors
     fn calculate_weighted_risk(&self, factors: &[(&str, u16)]) -> u16 {
+        let w = &self.config.weights;
         let weights = [
-            ("velocity", 25),     // 25% weight
-            ("chain_pair", 20),   // 20% weight
-            ("value_pattern", 15), // 15% weight
-            ("temporal", 10),     // 10% weight
-            ("behavior", 15),     // 15% weight
-            ("route", 10),        // 10% weight
-            ("reputation", 5),    // 5% weight
+            ("velocity", w.velocity),
+            ("chain_pair", w.chain_pair),
+            ("value_pattern", w.value_pattern),
+            ("baseline", w.baseline),
+            ("behavior", w.behavior),
+            ("route", w.route),
+            ("reputation", w.reputation),
         ];
 
         let mut weighted_sum = 0u32;
@@ -309,11 +434,7 @@ impl FraudDetectionEngine {
             }
         }
 
-        if total_weight > 0 {
-            (weighted_sum / total_weight) as u16
-        } else {
-            0
-        }
+        weighted_sum.checked_div(total_weight).unwrap_or(0) as u16
     }
 
     /// Detect suspicious patterns in recent operations
@@ -356,12 +477,11 @@ impl FraudDetectionEngine {
             let op2 = &self.recent_operations[i + 1];
             let op3 = &self.recent_operations[i + 2];
             
-            if op1.timestamp > 0 && op2.timestamp > 0 && op3.timestamp > 0 {
-                if op1.source_chain == op3.destination_chain &&
-                   op1.destination_chain == op3.source_chain &&
-                   op1.user_hash == op2.user_hash && op2.user_hash == op3.user_hash {
-                    return Ok(true);
-                }
+            if op1.timestamp > 0 && op2.timestamp > 0 && op3.timestamp > 0 &&
+               op1.source_chain == op3.destination_chain &&
+               op1.destination_chain == op3.source_chain &&
+               op1.user_hash == op2.user_hash && op2.user_hash == op3.user_hash {
+                return Ok(true);
             }
         }
         Ok(false)
@@ -444,6 +564,13 @@ pub struct OperationAnalysisInput {
     pub user_address: Vec<u8>,
     pub user_reputation: Option<u16>,
     pub route_hops: Option<u8>,
+    /// Decayed statistics of the source -> destination corridor, from the
+    /// destination's `ChainMetrics`
+    pub corridor: Option<CorridorStats>,
+    /// Governance's chain risk policy, when the registry exists
+    pub risk_registry: Option<ChainRiskRegistry>,
+    /// The user's behavioral baseline, from their `UserMetrics`
+    pub baseline: Option<BehaviorBaseline>,
 }
 
 pub struct FraudAnalysisResult {
@@ -0,0 +1,246 @@
+use anchor_lang::prelude::*;
+use crate::errors::UniversalNftError;
+use crate::state::{RuleEnforcement, SecurityRule};
+use crate::security::emergency_protocols::IncidentMode;
+
+/// Token-Bucket Rate Limiter for Cross-Chain Operations
+/// Each subject (wallet, chain or collection) holds burst credits that refill
+/// continuously at a sustained rate. Short spikes spend the stored credits
+/// instead of being rejected, while sustained abuse drains the bucket and is
+/// throttled down to the refill rate.
+#[account]
+#[derive(InitSpace)]
+pub struct TokenBucket {
+    /// What this bucket limits
+    pub subject: RateLimitSubject,
+    /// Credits stored, scaled by `CREDIT_SCALE`
+    pub credits: u64,
+    /// Last time credits were refilled
+    pub last_refill: i64,
+    /// Capacity and refill rate
+    pub config: RateLimitConfig,
+    /// Operations allowed since creation
+    pub total_allowed: u64,
+    /// Operations let through in shadow mode that would have been throttled
+    pub total_shadowed: u64,
+    /// Authority that can reconfigure the bucket
+    pub authority: Pubkey,
+    /// PDA bump
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum RateLimitSubject {
+    /// Operations signed by one wallet
+    Wallet(Pubkey),
+    /// Operations to or from one chain
+    Chain(u64),
+    /// Operations on one collection
+    Collection(Pubkey),
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct RateLimitConfig {
+    /// Burst credits the bucket can hold
+    pub burst_capacity: u32,
+    /// Credits refilled per minute (sustained rate)
+    pub refill_per_minute: u32,
+    /// Whether an empty bucket throttles operations or only reports them
+    pub enforcement: RuleEnforcement,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            burst_capacity: 20,    // 20 operations in a burst
+            refill_per_minute: 5,  // 5 operations/minute sustained
+            enforcement: RuleEnforcement::Enforce,
+        }
+    }
+}
+
+impl RateLimitSubject {
+    /// Chain the subject is scoped to, zero for wallets and collections
+    pub fn chain_id(&self) -> u64 {
+        match self {
+            RateLimitSubject::Chain(chain_id) => *chain_id,
+            RateLimitSubject::Wallet(_) | RateLimitSubject::Collection(_) => 0,
+        }
+    }
+
+    /// PDA seed tag of the subject's bucket, after `b"rate_limit"`
+    pub fn seed_tag(&self) -> &'static [u8] {
+        match self {
+            RateLimitSubject::Wallet(_) => b"wallet",
+            RateLimitSubject::Chain(_) => b"chain",
+            RateLimitSubject::Collection(_) => b"collection",
+        }
+    }
+
+    /// PDA seed key bytes of the subject's bucket, after the tag
+    pub fn seed_key(&self) -> Vec<u8> {
+        match self {
+            RateLimitSubject::Wallet(wallet) => wallet.to_bytes().to_vec(),
+            RateLimitSubject::Chain(chain_id) => chain_id.to_le_bytes().to_vec(),
+            RateLimitSubject::Collection(mint) => mint.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TokenBucket {
+    /// Fixed-point scale for fractional credits
+    pub const CREDIT_SCALE: u64 = 1_000_000;
+
+    pub const INIT_SPACE: usize =
+        1 + 32 + // subject
+        8 +  // credits
+        8 +  // last_refill
+        4 + 4 + 1 + // config
+        8 +  // total_allowed
+        8 +  // total_shadowed
+        32 + // authority
+        1;   // bump
+
+    /// Initialize a full bucket for `subject`
+    pub fn initialize(
+        &mut self,
+        subject: RateLimitSubject,
+        config: Option<RateLimitConfig>,
+        authority: Pubkey,
+        bump: u8,
+    ) -> Result<()> {
+        let config = config.unwrap_or_default();
+        Self::validate(&config)?;
+
+        self.subject = subject;
+        self.config = config;
+        self.credits = Self::scaled(config.burst_capacity);
+        self.last_refill = Clock::get()?.unix_timestamp;
+        self.total_allowed = 0;
+        self.total_shadowed = 0;
+        self.authority = authority;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Change capacity and refill rate (authority only); stored credits are
+    /// clamped to the new capacity
+    pub fn update_config(&mut self, signer: Pubkey, config: RateLimitConfig) -> Result<()> {
+        require!(signer == self.authority, UniversalNftError::Unauthorized);
+        Self::validate(&config)?;
+
+        self.refill(Clock::get()?.unix_timestamp, &config);
+        self.config = config;
+        log!(
+            "🪣 Rate limit updated: burst {}, {}/min, {:?}",
+            config.burst_capacity,
+            config.refill_per_minute,
+            config.enforcement
+        );
+        Ok(())
+    }
+
+    /// Spend `cost` credits, refilling first. Under an incident the capacity
+    /// and refill rate are reduced as the incident level dictates. In shadow
+    /// mode an empty bucket is reported and the operation goes through without
+    /// spending credits, so the bucket drains exactly as it would if enforced.
+    pub fn try_consume(&mut self, cost: u32, incident: Option<&IncidentMode>) -> Result<()> {
+        self.try_consume_at(Clock::get()?.unix_timestamp, cost, incident)
+    }
+
+    /// `try_consume` for an operation at `now`
+    pub fn try_consume_at(&mut self, now: i64, cost: u32, incident: Option<&IncidentMode>) -> Result<()> {
+        let config = self.effective_config(incident);
+        self.refill(now, &config);
+
+        let needed = Self::scaled(cost);
+        if self.credits < needed {
+            match config.enforcement {
+                RuleEnforcement::Enforce => {
+                    // A throttled operation's writes roll back, so only the
+                    // error reports it
+                    log!("🚫 Rate limit exceeded, retry in {}s", self.seconds_until(needed, &config));
+                    return Err(UniversalNftError::RateLimitExceeded.into());
+                }
+                RuleEnforcement::Shadow => {
+                    self.total_shadowed = self.total_shadowed.saturating_add(1);
+                    RuleEnforcement::Shadow.apply(
+                        SecurityRule::RateLimit,
+                        self.subject.chain_id(),
+                        Some(UniversalNftError::RateLimitExceeded),
+                    )?;
+                }
+                RuleEnforcement::Off => {}
+            }
+            return self.count_allowed();
+        }
+
+        self.credits -= needed;
+        self.count_allowed()
+    }
+
+    fn count_allowed(&mut self) -> Result<()> {
+        self.total_allowed = self.total_allowed
+            .checked_add(1)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Whole credits available at `now` without spending any
+    pub fn available(&self, now: i64, incident: Option<&IncidentMode>) -> u32 {
+        let config = self.effective_config(incident);
+        let credits = self.credits
+            .saturating_add(Self::refill_amount(now - self.last_refill, &config))
+            .min(Self::scaled(config.burst_capacity));
+        (credits / Self::CREDIT_SCALE) as u32
+    }
+
+    fn effective_config(&self, incident: Option<&IncidentMode>) -> RateLimitConfig {
+        match incident {
+            // Never reduced to zero, so a bucket always refills eventually
+            Some(incident) => RateLimitConfig {
+                burst_capacity: incident
+                    .effective_rate_limit(self.config.burst_capacity as u64)
+                    .max(1) as u32,
+                refill_per_minute: incident
+                    .effective_rate_limit(self.config.refill_per_minute as u64)
+                    .max(1) as u32,
+                enforcement: self.config.enforcement,
+            },
+            None => self.config,
+        }
+    }
+
+    /// Add credits earned since the last refill, capped at `config`'s capacity
+    fn refill(&mut self, now: i64, config: &RateLimitConfig) {
+        self.credits = self.credits
+            .saturating_add(Self::refill_amount(now - self.last_refill, config))
+            .min(Self::scaled(config.burst_capacity));
+        self.last_refill = self.last_refill.max(now);
+    }
+
+    fn refill_amount(elapsed: i64, config: &RateLimitConfig) -> u64 {
+        if elapsed <= 0 {
+            return 0;
+        }
+        (elapsed as u128 * config.refill_per_minute as u128 * Self::CREDIT_SCALE as u128 / 60)
+            .min(u64::MAX as u128) as u64
+    }
+
+    fn seconds_until(&self, needed: u64, config: &RateLimitConfig) -> u64 {
+        let per_second = (config.refill_per_minute as u64 * Self::CREDIT_SCALE / 60).max(1);
+        (needed - self.credits).div_ceil(per_second)
+    }
+
+    fn scaled(credits: u32) -> u64 {
+        credits as u64 * Self::CREDIT_SCALE
+    }
+
+    fn validate(config: &RateLimitConfig) -> Result<()> {
+        require!(
+            config.burst_capacity > 0 && config.refill_per_minute > 0,
+            UniversalNftError::InvalidRateLimitConfig
+        );
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::errors::UniversalNftError;
 
 /// Program configuration account
 #[account]
@@ -6,7 +7,7 @@ use anchor_lang::prelude::*;
 pub struct ProgramConfig {
     /// Authority that can update program settings
     pub authority: Pubkey,
-    /// ZetaChain gateway authority for cross-chain operations
+    /// ZetaChain gateway program. Inbound calls must be CPIs made by it.
     pub gateway_authority: Pubkey,
     /// TSS (Threshold Signature Scheme) authority from ZetaChain
     pub tss_authority: Pubkey,
@@ -27,14 +28,18 @@ pub struct UniversalNft {
     /// Original chain where this NFT was first minted
     pub origin_chain_id: u64,
     /// Original token ID from the source chain
+    #[max_len(64)]
     pub origin_token_id: String,
     /// Current owner of the NFT
     pub owner: Pubkey,
     /// Metadata URI pointing to JSON metadata
+    #[max_len(200)]
     pub uri: String,
     /// NFT name
+    #[max_len(32)]
     pub name: String,
     /// NFT symbol/collection symbol
+    #[max_len(16)]
     pub symbol: String,
     /// Optional collection mint this NFT belongs to
     pub collection_mint: Option<Pubkey>,
@@ -46,6 +51,16 @@ pub struct UniversalNft {
     pub bump: u8,
     /// Whether this NFT is currently locked for cross-chain transfer
     pub is_locked: bool,
+    /// Whether an open dispute freezes further hops for this NFT
+    pub is_disputed: bool,
+    /// Whether the compliance role has frozen this NFT under legal hold
+    pub is_frozen: bool,
+    /// Whether an outbound transfer is in flight, from burn_and_transfer
+    /// until it is confirmed, reverted or cancelled
+    pub transfer_in_progress: bool,
+    /// Whether moderation took the content down; marketplaces hide flagged
+    /// NFTs on every chain
+    pub is_flagged: bool,
 }
 
 /// Cross-chain transfer state
@@ -61,6 +76,7 @@ pub struct CrossChainTransfer {
     /// Sender address on source chain
     pub sender: [u8; 20],
     /// Recipient address on destination chain
+    #[max_len(64)]
     pub recipient: Vec<u8>,
     /// Gas limit for destination transaction
     pub gas_limit: u64,
@@ -72,10 +88,48 @@ pub struct CrossChainTransfer {
     pub status: TransferStatus,
     /// Bump seed for PDA derivation
     pub bump: u8,
+    /// How destination gas was paid
+    pub gas_payment: GasPayment,
+    /// Destination gas fee charged, in lamports or ZRC-20 base units
+    pub gas_fee: u64,
+    /// Caller payload forwarded to the destination contract
+    #[max_len(256)]
+    pub payload: Vec<u8>,
+    /// Solana wallet that started the transfer and paid its gas
+    pub initiator: Pubkey,
+}
+
+/// Destination gas payment method for outbound calls
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum GasPayment {
+    /// Pay in SOL through the gateway
+    Sol,
+    /// Pay in an SPL token representing a ZRC-20 gas token
+    Zrc20,
+}
+
+/// Registered ZRC-20 gas token accepted for destination gas
+#[account]
+#[derive(InitSpace)]
+pub struct GasTokenConfig {
+    /// SPL mint representing the ZRC-20 token on Solana
+    pub zrc20_mint: Pubkey,
+    /// Destination chain whose gas this token pays for
+    pub destination_chain_id: u64,
+    /// Token account collecting gas payments, owned by this config PDA
+    pub vault: Pubkey,
+    /// Token base units charged per unit of destination gas
+    pub price_per_gas_unit: u64,
+    /// Whether the token is currently accepted
+    pub enabled: bool,
+    /// Total gas fees collected in token base units
+    pub total_collected: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
 }
 
 /// Transfer status enumeration
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
 pub enum TransferStatus {
     /// Transfer has been initiated
     Initiated,
@@ -89,8 +143,9 @@ pub enum TransferStatus {
     Cancelled,
 }
 
-/// Cross-chain message types
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+/// Cross-chain message types. The variant index is the wire discriminator,
+/// so new types are only ever appended (see MessageCodec).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub enum CrossChainMessage {
     /// Mint NFT on destination chain
     MintNft {
@@ -118,81 +173,4189 @@ pub enum CrossChainMessage {
         new_name: Option<String>,
         new_symbol: Option<String>,
     },
+    /// Ask the ZetaChain side to read state on an EVM chain
+    QueryRequest {
+        query_id: u64,
+        query_type: QueryType,
+        contract_address: [u8; 20],
+        token_id: String,
+        reply_to: Pubkey,
+    },
+    /// Answer to a previously sent query
+    QueryResponse {
+        query_id: u64,
+        success: bool,
+        data: Vec<u8>,
+    },
+    /// Mint a representation of a Bitcoin inscription relayed by ZetaChain
+    MintFromBitcoin {
+        inscription: InscriptionRef,
+        btc_sender: Vec<u8>,
+        name: String,
+        symbol: String,
+        uri: String,
+        recipient: Pubkey,
+    },
+    /// Mint NFT on destination chain and hand a payload to its receive hook
+    MintNftWithPayload {
+        token_id: String,
+        name: String,
+        symbol: String,
+        uri: String,
+        recipient: Pubkey,
+        collection_mint: Option<Pubkey>,
+        payload: Vec<u8>,
+    },
+    /// Mint several NFTs on destination chain for one recipient
+    MintNftBatch {
+        recipient: Pubkey,
+        items: Vec<BatchMintItem>,
+    },
+    /// Mint several NFTs on destination chain, each to its own recipient
+    MintNftSplit {
+        entries: Vec<SplitMintEntry>,
+    },
+    /// Mint NFT on an EVM chain with metadata translated by the chain's rules
+    /// and an ERC-2981 royalty. Outbound only.
+    MintNftWithRoyalty {
+        token_id: String,
+        name: String,
+        symbol: String,
+        uri: String,
+        recipient: Pubkey,
+        collection_mint: Option<Pubkey>,
+        payload: Vec<u8>,
+        royalty: Erc2981Royalty,
+    },
+    /// Mint NFT on destination chain with the attribute entries written by
+    /// its collection's trait authorities
+    MintNftWithAttributes {
+        token_id: String,
+        name: String,
+        symbol: String,
+        uri: String,
+        recipient: Pubkey,
+        collection_mint: Option<Pubkey>,
+        payload: Vec<u8>,
+        attributes: Vec<NftAttribute>,
+    },
+    /// Mint NFT on destination chain together with the children equipped
+    /// into it, which stay locked on Solana. Outbound only.
+    MintNftBundle {
+        token_id: String,
+        name: String,
+        symbol: String,
+        uri: String,
+        recipient: Pubkey,
+        collection_mint: Option<Pubkey>,
+        payload: Vec<u8>,
+        children: Vec<BatchMintItem>,
+    },
+    /// Proof that a ticket NFT was checked in, for event systems on other
+    /// chains. Outbound only.
+    TicketCheckIn {
+        token_id: String,
+        event_id: u64,
+        holder: Pubkey,
+        checked_in_at: i64,
+    },
+    /// Mint NFT on destination chain with the vesting position it wraps.
+    /// The tokens stay escrowed on Solana and are claimed there once the NFT
+    /// returns.
+    MintNftWithVesting {
+        token_id: String,
+        name: String,
+        symbol: String,
+        uri: String,
+        recipient: Pubkey,
+        collection_mint: Option<Pubkey>,
+        payload: Vec<u8>,
+        vesting: VestingTerms,
+    },
+    /// Revocation of a credential issued on Solana, for verifiers on other
+    /// chains. Outbound only.
+    CredentialRevoked {
+        collection_mint: Pubkey,
+        credential_id: u64,
+        subject: Pubkey,
+        revoked_at: i64,
+    },
+    /// Ask the counterpart contract to expect the other leg of a hashed
+    /// timelock swap. Outbound only.
+    SwapLockRequested {
+        swap: Pubkey,
+        token_id: String,
+        hashlock: [u8; 32],
+        expires_at: i64,
+        maker_receiver: [u8; 20],
+        remote_asset: RemoteAsset,
+    },
+    /// Secret the swap's maker revealed to claim the remote leg, releasing
+    /// the Solana leg to the taker
+    SwapSecretRevealed {
+        swap: Pubkey,
+        secret: [u8; 32],
+    },
+    /// Critical security event reported to the monitoring contract on
+    /// ZetaChain, so counterpart contracts can react. Outbound only.
+    SecurityEvent {
+        sequence: u64,
+        kind: SecurityEventKind,
+        chain_id: u64,
+        value: u64,
+        occurred_at: i64,
+    },
+    /// Administrative pause or resume of a corridor, signed by the TSS.
+    /// Applied with `apply_corridor_pause`, never through `on_call`.
+    CorridorPause {
+        nonce: u64,
+        chain_id: u64,
+        direction: CorridorDirection,
+        paused: bool,
+    },
+    /// Ask the counterpart contract to pause or resume its side of a
+    /// corridor that was changed on Solana. Outbound only.
+    CorridorPauseRequested {
+        nonce: u64,
+        chain_id: u64,
+        direction: CorridorDirection,
+        paused: bool,
+    },
 }
 
-/// Collection information for universal NFTs
+/// ERC-2981 royalty the destination contract reports for a token
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Erc2981Royalty {
+    /// Royalty receiver on the destination chain
+    pub receiver: [u8; 20],
+    /// Royalty in basis points of the sale price
+    pub basis_points: u16,
+}
+
+/// Interchain query types
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Debug)]
+pub enum QueryType {
+    /// Whether a token ID exists on the target contract
+    TokenExists,
+    /// Current owner of a token ID
+    OwnerOf,
+    /// Token URI of a token ID
+    TokenUri,
+}
+
+/// Interchain query status
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum QueryStatus {
+    /// Waiting for the response call
+    Pending,
+    /// Response stored
+    Answered,
+    /// Remote side reported a failure
+    Failed,
+}
+
+/// Outbound interchain query and its stored answer
 #[account]
 #[derive(InitSpace)]
-pub struct UniversalCollection {
-    /// Collection mint account
+pub struct QueryResult {
+    /// Query identifier, taken from the config nonce
+    pub query_id: u64,
+    /// Account that requested the query
+    pub requester: Pubkey,
+    /// EVM chain the query reads from
+    pub target_chain_id: u64,
+    /// What is being asked
+    pub query_type: QueryType,
+    /// Contract queried on the target chain
+    pub contract_address: [u8; 20],
+    /// Token ID queried
+    #[max_len(64)]
+    pub token_id: String,
+    /// Current query status
+    pub status: QueryStatus,
+    /// Raw response payload
+    #[max_len(256)]
+    pub response: Vec<u8>,
+    /// Timestamp the query was sent
+    pub requested_at: i64,
+    /// Timestamp the response was stored
+    pub answered_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Kind of chain registered with the program
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum ChainKind {
+    /// EVM-compatible chain
+    Evm,
+    /// ZetaChain itself
+    ZetaChain,
+    /// Bitcoin through ZetaChain's BTC integration
+    Bitcoin,
+    /// TON
+    Ton,
+    /// Sui
+    Sui,
+}
+
+/// Destination address encoding accepted by a registered chain
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum AddressFormat {
+    /// 20-byte EVM address
+    Evm,
+    /// ASCII base58 or bech32 Bitcoin address
+    Bitcoin,
+    /// 36-byte decoded user-friendly TON address with CRC16 checksum
+    Ton,
+    /// 32-byte Sui address
+    Sui,
+}
+
+impl AddressFormat {
+    /// Address format native to a chain kind
+    pub fn for_chain_kind(kind: &ChainKind) -> Self {
+        match kind {
+            ChainKind::Evm | ChainKind::ZetaChain => AddressFormat::Evm,
+            ChainKind::Bitcoin => AddressFormat::Bitcoin,
+            ChainKind::Ton => AddressFormat::Ton,
+            ChainKind::Sui => AddressFormat::Sui,
+        }
+    }
+}
+
+/// Chain registry entry
+#[account]
+#[derive(InitSpace)]
+pub struct RegisteredChain {
+    /// Chain ID as used by ZetaChain
+    pub chain_id: u64,
+    /// Kind of chain, drives message decoding and address handling
+    pub chain_kind: ChainKind,
+    /// Address format recipients on this chain must use
+    pub address_format: AddressFormat,
+    /// Universal NFT contract on this chain (EVM only, zero if unset)
+    pub counterpart_contract: [u8; 20],
+    /// Whether messages to and from this chain are accepted
+    pub enabled: bool,
+    /// Timestamp the chain was registered
+    pub registered_at: i64,
+    /// Outbound value and count caps
+    pub limits: ChainTransferLimits,
+    /// Rolling 24h outbound volume
+    pub volume: RollingVolume,
+    /// Message layer outbound transfers use by default
+    pub primary_route: BridgeRoute,
+    /// Alternative message layer senders may use instead of the primary
+    pub fallback_route: Option<BridgeRoute>,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Outbound caps for one chain; zero disables a cap
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct ChainTransferLimits {
+    /// Maximum floor value of a single transfer, in lamports
+    pub max_value_per_transfer: u64,
+    /// Maximum floor value sent in any 24h period, in lamports
+    pub daily_value_cap: u64,
+    /// Maximum transfers sent in any 24h period
+    pub daily_transfer_cap: u32,
+    /// Whether breaching a cap rejects the transfer or is only reported
+    pub enforcement: RuleEnforcement,
+}
+
+/// Rollout stage of a security rule. New rules run in `Shadow` first: they are
+/// evaluated and would-be blocks are reported, but nothing is rejected.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug, InitSpace)]
+pub enum RuleEnforcement {
+    /// Rule is not evaluated
+    Off,
+    /// Rule is evaluated and breaches are reported without rejecting
+    Shadow,
+    /// Breaches reject the operation
+    #[default]
+    Enforce,
+}
+
+/// Security rules that can be rolled out in shadow mode
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SecurityRule {
+    /// Per-chain outbound value and count caps
+    ChainLimits,
+    /// Fraud risk score threshold
+    FraudScore,
+    /// Per-user token bucket rate limit
+    RateLimit,
+    /// Per-chain circuit breaker failure threshold
+    CircuitBreaker,
+}
+
+impl RuleEnforcement {
+    /// Resolve a rule breach: `Enforce` returns the error, `Shadow` reports it
+    /// as a would-be block and lets the operation through
+    pub fn apply(self, rule: SecurityRule, subject: u64, breach: Option<UniversalNftError>) -> Result<()> {
+        let error = match (self, breach) {
+            (RuleEnforcement::Off, _) | (_, None) => return Ok(()),
+            (RuleEnforcement::Enforce, Some(error)) => return Err(error.into()),
+            (RuleEnforcement::Shadow, Some(error)) => error,
+        };
+
+        log!("Shadow rule {:?} would have blocked {}: {}", rule, subject, error);
+        emit!(crate::events::ShadowRuleTriggered {
+            rule,
+            subject,
+            error_code: error.into(),
+            timestamp: Clock::get().map_or(0, |clock| clock.unix_timestamp),
+        });
+        Ok(())
+    }
+}
+
+/// Two-window accumulator approximating a rolling 24h total
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug, InitSpace)]
+pub struct RollingVolume {
+    /// Start of the current window
+    pub window_start: i64,
+    /// Value sent in the current window
+    pub current_value: u64,
+    /// Value sent in the previous window
+    pub previous_value: u64,
+    /// Transfers sent in the current window
+    pub current_count: u32,
+    /// Transfers sent in the previous window
+    pub previous_count: u32,
+}
+
+/// Decayed latency and failure averages for one source -> destination
+/// corridor. Each sample moves the averages a fixed share of the way, so
+/// older samples fade out exponentially.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug, InitSpace)]
+pub struct CorridorStats {
+    /// Samples recorded
+    pub samples: u64,
+    /// Decayed average latency (seconds)
+    pub latency_ema_s: u32,
+    /// Decayed share of samples that failed (basis points)
+    pub failure_rate_bps: u16,
+    /// Timestamp of the last sample
+    pub last_sample: i64,
+}
+
+/// Messaging network outbound transfers are sent through
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum MessageLayer {
+    /// ZetaChain gateway
+    ZetaGateway,
+    /// Wormhole core bridge
+    Wormhole,
+    /// LayerZero endpoint
+    LayerZero,
+}
+
+/// How a registered chain is reached through one message layer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct BridgeRoute {
+    /// Layer the message is handed to
+    pub layer: MessageLayer,
+    /// The chain's ID as the layer numbers it (ZetaChain chain ID, Wormhole
+    /// chain ID or LayerZero endpoint ID)
+    pub remote_chain_id: u64,
+    /// Receiving Universal NFT contract on the chain, left-padded to 32 bytes
+    /// (unused by the ZetaChain gateway)
+    pub peer: [u8; 32],
+}
+
+/// Registered message layer program and its health
+#[account]
+#[derive(InitSpace)]
+pub struct MessageLayerConfig {
+    /// Layer this config describes
+    pub layer: MessageLayer,
+    /// Layer program outbound messages are sent to
+    pub program_id: Pubkey,
+    /// Cleared while the layer is degraded, so senders fall back
+    pub operational: bool,
+    /// Timestamp of the last change
+    pub updated_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Reference to a Bitcoin ordinal inscription (`<txid>i<index>`)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Debug)]
+pub struct InscriptionRef {
+    /// Reveal transaction ID
+    pub txid: [u8; 32],
+    /// Inscription index within the reveal transaction
+    pub index: u32,
+}
+
+/// Where a Solana NFT representation originally came from
+#[account]
+#[derive(InitSpace)]
+pub struct NftOrigin {
+    /// The Solana NFT mint
     pub mint: Pubkey,
-    /// Collection authority
+    /// Chain the NFT originated on
+    pub origin_chain_id: u64,
+    /// Kind of the origin chain
+    pub chain_kind: ChainKind,
+    /// Sender address on the origin chain, in its native encoding
+    #[max_len(64)]
+    pub origin_sender: Vec<u8>,
+    /// Originating inscription for Bitcoin-origin NFTs
+    pub inscription: Option<InscriptionRef>,
+    /// Timestamp the origin was recorded
+    pub recorded_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// NFT lifecycle events third-party hook programs can subscribe to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum HookEvent {
+    /// NFT minted into a collection
+    Mint,
+    /// NFT transferred between Solana owners
+    Transfer,
+    /// NFT burned for an outbound cross-chain transfer
+    BridgeOut,
+}
+
+impl HookEvent {
+    /// Bit used for this event in a hook's event mask
+    pub fn mask(&self) -> u8 {
+        match self {
+            HookEvent::Mint => 1 << 0,
+            HookEvent::Transfer => 1 << 1,
+            HookEvent::BridgeOut => 1 << 2,
+        }
+    }
+}
+
+/// External program approved by the program authority to act as a hook
+#[account]
+#[derive(InitSpace)]
+pub struct HookProgram {
+    /// Whitelisted program ID
+    pub program_id: Pubkey,
+    /// Maximum compute units a collection may allow this program per call
+    pub max_compute_units: u32,
+    /// Timestamp the program was whitelisted
+    pub approved_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// A hook registered on a collection
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct HookEntry {
+    /// Hook program invoked via CPI
+    pub program_id: Pubkey,
+    /// Bit mask of subscribed HookEvents
+    pub event_mask: u8,
+    /// Compute units the hook may consume per invocation
+    pub compute_allowance: u32,
+    /// Number of extra accounts the hook expects after its program account
+    pub account_count: u8,
+}
+
+/// Per-collection hook registry
+#[account]
+#[derive(InitSpace)]
+pub struct CollectionHooks {
+    /// Collection mint the hooks apply to
+    pub collection_mint: Pubkey,
+    /// Collection authority managing the hooks
     pub authority: Pubkey,
-    /// Collection name
+    /// Registered hooks, invoked in order
+    #[max_len(4)]
+    pub hooks: Vec<HookEntry>,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Outbound transfer locked now and executed after a review delay
+#[account]
+#[derive(InitSpace)]
+pub struct ScheduledTransfer {
+    /// NFT owner who scheduled the transfer
+    pub owner: Pubkey,
+    /// NFT mint being transferred
+    pub mint: Pubkey,
+    /// Destination chain ID
+    pub destination_chain_id: u64,
+    /// Recipient address on destination chain
+    #[max_len(64)]
+    pub recipient: Vec<u8>,
+    /// Gas limit for destination transaction
+    pub gas_limit: u64,
+    /// Earliest time the transfer can execute; cancellable until then
+    pub execute_after: i64,
+    /// Timestamp the transfer was scheduled
+    pub created_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Outbound transfer of several NFTs to one recipient in a single gateway call
+#[account]
+#[derive(InitSpace)]
+pub struct BatchTransfer {
+    /// Owner who sent the batch
+    pub owner: Pubkey,
+    /// Destination chain ID
+    pub destination_chain_id: u64,
+    /// Recipient address on destination chain
+    #[max_len(64)]
+    pub recipient: Vec<u8>,
+    /// NFT mints included in the batch
+    #[max_len(8)]
+    pub mints: Vec<Pubkey>,
+    /// Gas limit for the destination transaction
+    pub gas_limit: u64,
+    /// Destination gas fee charged once for the batch, in lamports
+    pub gas_fee: u64,
+    /// Transfer nonce for replay protection
+    pub nonce: u64,
+    /// Timestamp of transfer initiation
+    pub timestamp: i64,
+    /// Status of the batch
+    pub status: TransferStatus,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// NFT carried by a batch mint message
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BatchMintItem {
+    pub token_id: String,
     pub name: String,
-    /// Collection symbol
     pub symbol: String,
-    /// Collection URI for metadata
     pub uri: String,
-    /// Total supply of NFTs in this collection
-    pub total_supply: u64,
-    /// Maximum supply (0 for unlimited)
-    pub max_supply: u64,
-    /// Whether this collection is verified
-    pub is_verified: bool,
+    pub collection_mint: Option<Pubkey>,
+}
+
+/// One recipient's NFT in a split delivery message
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SplitMintEntry {
+    pub recipient: Pubkey,
+    pub item: BatchMintItem,
+}
+
+/// Relayer staking parameters and reward pool
+#[account]
+#[derive(InitSpace)]
+pub struct RelayerRegistry {
+    /// Authority that sets relayer parameters
+    pub authority: Pubkey,
+    /// Minimum stake in lamports to submit messages
+    pub min_stake: u64,
+    /// Lamports paid from the pool per delivered message
+    pub reward_per_message: u64,
+    /// Share of stake slashed per invalid submission, in basis points
+    pub slash_bps: u16,
+    /// Seconds a relayer waits between leaving and withdrawing stake
+    pub unbonding_period: i64,
+    /// Total lamports staked by relayers
+    pub total_staked: u64,
+    /// Total lamports slashed into the pool
+    pub total_slashed: u64,
+    /// Number of registered relayers
+    pub relayer_count: u32,
     /// Bump seed for PDA derivation
     pub bump: u8,
 }
 
-impl ProgramConfig {
-    pub const INIT_SPACE: usize = 
-        32 + // authority
-        32 + // gateway_authority
-        32 + // tss_authority
-        8 +  // nonce
-        1 +  // bump
-        1;   // is_paused
+/// Staked relayer allowed to submit inbound messages
+#[account]
+#[derive(InitSpace)]
+pub struct Relayer {
+    /// Relayer signing key
+    pub authority: Pubkey,
+    /// Lamports staked, held by this account above rent
+    pub stake: u64,
+    /// Messages delivered with a valid TSS signature
+    pub messages_delivered: u64,
+    /// Submissions rejected for an invalid signature
+    pub invalid_submissions: u64,
+    /// Total rewards paid
+    pub rewards_earned: u64,
+    /// Whether the relayer may submit messages
+    pub active: bool,
+    /// Time after which stake can be withdrawn; zero while bonded
+    pub unbonding_at: i64,
+    /// Registration timestamp
+    pub registered_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
 }
 
-impl UniversalNft {
-    pub const INIT_SPACE: usize = 
-        32 + // mint
-        8 +  // origin_chain_id
-        4 + 64 + // origin_token_id (String with max 64 chars)
-        32 + // owner
-        4 + 200 + // uri (String with max 200 chars)
-        4 + 32 + // name (String with max 32 chars)
-        4 + 16 + // symbol (String with max 16 chars)
-        1 + 32 + // collection_mint (Option<Pubkey>)
-        8 +  // creation_block
-        8 +  // creation_timestamp
-        1 +  // bump
-        1;   // is_locked
+/// Replay guard for a message delivered by a relayer
+#[account]
+#[derive(InitSpace)]
+pub struct RelayedMessage {
+    /// Hash of source chain, sequence, sender and message
+    pub message_hash: [u8; 32],
+    /// Relayer that delivered the message
+    pub relayer: Pubkey,
+    /// Source chain ID
+    pub source_chain_id: u64,
+    /// Inbound sequence number on the source chain
+    pub sequence: u64,
+    /// Whether a submission carried a valid signature
+    pub delivered: bool,
+    /// Whether on_call executed the message
+    pub executed: bool,
+    /// Whether the relayer claimed its reward for the message
+    pub rewarded: bool,
+    /// Timestamp of the last submission
+    pub submitted_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
 }
 
-impl CrossChainTransfer {
-    pub const INIT_SPACE: usize = 
-        32 + // nft_mint
-        8 +  // source_chain_id
-        8 +  // destination_chain_id
-        20 + // sender
-        4 + 64 + // recipient (Vec<u8> with max 64 bytes)
-        8 +  // gas_limit
-        8 +  // nonce
-        8 +  // timestamp
-        1 +  // status (enum discriminator)
-        1;   // bump
+/// Expected inbound sequence for one source chain
+#[account]
+#[derive(InitSpace)]
+pub struct InboundQueue {
+    /// Source chain ID
+    pub source_chain_id: u64,
+    /// Next sequence to release in order; on_call executes only delivered
+    /// messages below it
+    pub next_sequence: u64,
+    /// Bit `i` marks `next_sequence + i` as received and buffered
+    pub pending_bitmap: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
 }
 
-impl UniversalCollection {
-    pub const INIT_SPACE: usize = 
-        32 + // mint
-        32 + // authority
-        4 + 64 + // name (String with max 64 chars)
-        4 + 16 + // symbol (String with max 16 chars)
-        4 + 200 + // uri (String with max 200 chars)
-        8 +  // total_supply
-        8 +  // max_supply
-        1 +  // is_verified
-        1;   // bump
+/// Compliance role that can place assets under legal hold
+#[account]
+#[derive(InitSpace)]
+pub struct ComplianceConfig {
+    /// Program authority that manages the compliance role
+    pub authority: Pubkey,
+    /// Compliance officer allowed to freeze and unfreeze NFTs
+    pub compliance_officer: Pubkey,
+    /// NFTs currently frozen
+    pub frozen_count: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Header relayer allowed to post source-chain block roots
+#[account]
+#[derive(InitSpace)]
+pub struct LightClientConfig {
+    /// Program authority that manages the header relayer
+    pub authority: Pubkey,
+    /// Relayer allowed to post block header roots
+    pub header_relayer: Pubkey,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Event root of one source-chain block, posted by the header relayer
+#[account]
+#[derive(InitSpace)]
+pub struct BlockHeaderRoot {
+    /// Source chain ID
+    pub chain_id: u64,
+    /// Source block number
+    pub block_number: u64,
+    /// Merkle root over the block's bridge event leaves
+    pub events_root: [u8; 32],
+    /// Relayer that posted the root
+    pub relayer: Pubkey,
+    /// Timestamp the root was posted
+    pub posted_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Marks a collection whose inbound messages need an event proof
+#[account]
+#[derive(InitSpace)]
+pub struct ProofPolicy {
+    /// Collection mint the policy applies to
+    pub collection_mint: Pubkey,
+    /// Whether inbound messages must carry a verified event proof
+    pub required: bool,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Confirmation depth inbound messages from a chain must reach, measured
+/// against the latest block head the header relayer has attested
+#[account]
+#[derive(InitSpace)]
+pub struct ChainFinality {
+    /// Source chain ID
+    pub chain_id: u64,
+    /// Blocks that must follow a message's source block (0 disables the check)
+    pub required_confirmations: u64,
+    /// Highest block number posted by the header relayer
+    pub attested_head: u64,
+    /// Timestamp the head last advanced
+    pub head_updated_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Source-chain event proven against a posted block root
+#[account]
+#[derive(InitSpace)]
+pub struct VerifiedEvent {
+    /// Event leaf hash
+    pub leaf: [u8; 32],
+    /// Source chain ID
+    pub chain_id: u64,
+    /// Block the event was proven in
+    pub block_number: u64,
+    /// Whether on_call has already consumed this proof
+    pub consumed: bool,
+    /// Timestamp the proof was verified
+    pub verified_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Oracle-priced floor value of a collection, used for chain value caps,
+/// loan collateral and insurance payouts
+#[account]
+#[derive(InitSpace)]
+pub struct CollectionFloor {
+    /// Collection mint the price applies to
+    pub collection_mint: Pubkey,
+    /// Price-feed authorities allowed to post the floor price
+    #[max_len(4)]
+    pub price_authorities: Vec<Pubkey>,
+    /// Pyth-style price account quoting the floor in SOL, default for none
+    pub price_feed: Pubkey,
+    /// Seconds a price stays usable for loans and insurance, 0 for no limit
+    pub max_staleness: i64,
+    /// Floor value of one NFT in lamports
+    pub floor_value: u64,
+    /// Timestamp the price was last posted
+    pub updated_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Who may price a collection's floor
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CollectionFloorParams {
+    pub price_authorities: Vec<Pubkey>,
+    pub price_feed: Pubkey,
+    pub max_staleness: i64,
+}
+
+/// Per-collection mint and bridging policy
+#[account]
+#[derive(InitSpace)]
+pub struct CollectionConfig {
+    /// Collection mint the policy applies to
+    pub collection_mint: Pubkey,
+    /// Maximum NFTs minted into the collection (0 for unlimited)
+    pub max_supply: u64,
+    /// NFTs minted into the collection so far
+    pub minted_count: u64,
+    /// Chains NFTs may be bridged to (empty allows every registered chain)
+    #[max_len(16)]
+    pub allowed_destination_chains: Vec<u64>,
+    /// How NFTs leave Solana when bridged
+    pub transfer_mode: CollectionTransferMode,
+    /// Royalty written into new NFTs' metadata
+    pub seller_fee_basis_points: u16,
+    /// Whether minting and bridging are paused for the collection
+    pub is_paused: bool,
+    /// Mint phases scheduled for the collection (0 for unscheduled minting)
+    pub phase_count: u8,
+    /// End of the last scheduled phase
+    pub phases_end: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// How a collection's NFTs leave Solana
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum CollectionTransferMode {
+    /// Token is burned and reminted on return
+    Burn,
+    /// Token is held in program custody and released on return
+    Lock,
+}
+
+/// Settings accepted by `set_collection_config`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CollectionConfigParams {
+    pub max_supply: u64,
+    pub allowed_destination_chains: Vec<u64>,
+    pub transfer_mode: CollectionTransferMode,
+    pub seller_fee_basis_points: u16,
+    pub is_paused: bool,
+}
+
+/// One scheduled window of a collection's drop. Phases never overlap, so at
+/// most one is active at a time; outside every phase minting is closed.
+#[account]
+#[derive(InitSpace)]
+pub struct MintPhase {
+    /// Collection mint the phase belongs to
+    pub collection_mint: Pubkey,
+    /// Position in the collection's schedule
+    pub phase_index: u8,
+    /// Presale or public
+    pub kind: MintPhaseKind,
+    /// Phase opens at this timestamp
+    pub start_time: i64,
+    /// Phase closes at this timestamp (exclusive)
+    pub end_time: i64,
+    /// Lamports charged per mint
+    pub price: u64,
+    /// Wallet receiving mint payments
+    pub funds_recipient: Pubkey,
+    /// Merkle root of allowlisted wallets (required for presale)
+    pub allowlist_root: Option<[u8; 32]>,
+    /// NFTs minted during the phase
+    pub minted_count: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum MintPhaseKind {
+    /// Allowlisted wallets only
+    Presale,
+    /// Open to everyone
+    Public,
+}
+
+/// Settings accepted by `add_mint_phase`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MintPhaseParams {
+    pub kind: MintPhaseKind,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub price: u64,
+    pub funds_recipient: Pubkey,
+    pub allowlist_root: Option<[u8; 32]>,
+}
+
+/// An NFT held by the custody PDA for a recipient without a wallet. It is
+/// released to a claimant whose claim the `claim_key` keypair signs, or to a
+/// wallet the claims service co-signs for. The claim code handed to the
+/// recipient is that keypair's secret.
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimEscrow {
+    /// Public key of the ephemeral keypair whose secret is the claim code
+    pub claim_key: Pubkey,
+    /// The escrowed NFT mint
+    pub mint: Pubkey,
+    /// Wallet that escrowed the NFT and receives it back on cancel
+    pub depositor: Pubkey,
+    /// Token account holding the NFT, owned by the custody PDA
+    pub custody_token_account: Pubkey,
+    /// Service allowed to authorize claims in place of the claim code
+    pub claims_service: Option<Pubkey>,
+    /// Depositor may reclaim the NFT after this timestamp (0 for never)
+    pub expires_at: i64,
+    /// Timestamp the NFT was escrowed
+    pub created_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Holders Merkle root of a collection at a past slot, built by an indexer
+/// and committed before it is revealed so it can't be swapped once published
+#[account]
+#[derive(InitSpace)]
+pub struct HolderSnapshot {
+    /// Collection the snapshot covers
+    pub collection_mint: Pubkey,
+    /// Slot the holder set was taken at
+    pub slot: u64,
+    /// Commitment to the root, holder count and salt
+    pub commitment: [u8; 32],
+    /// Slot the commitment was posted
+    pub committed_slot: u64,
+    /// Merkle root of (owner, mint) leaves, once revealed
+    pub holders_root: Option<[u8; 32]>,
+    /// Number of leaves in the snapshot
+    pub holder_count: u32,
+    /// Timestamp the root was revealed
+    pub revealed_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Permanent burns of a collection's NFTs. Bridge-out burns are not counted,
+/// since the NFT is reminted on the destination chain.
+#[account]
+#[derive(InitSpace)]
+pub struct CollectionBurnStats {
+    /// Collection the counts apply to
+    pub collection_mint: Pubkey,
+    /// NFTs burned from the collection
+    pub total_burned: u64,
+    /// Distinct wallets that have burned from the collection
+    pub unique_burners: u64,
+    /// Timestamp of the latest burn
+    pub last_burn_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// A wallet's permanent burns within one collection, for burn leaderboards
+#[account]
+#[derive(InitSpace)]
+pub struct UserBurnStats {
+    /// Wallet that burned
+    pub user: Pubkey,
+    /// Collection burned from (default key for NFTs without a collection)
+    pub collection_mint: Pubkey,
+    /// NFTs burned
+    pub total_burned: u64,
+    /// Timestamp of the first burn
+    pub first_burn_at: i64,
+    /// Timestamp of the latest burn
+    pub last_burn_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Burn counts returned by `get_burn_stats`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BurnStatsView {
+    pub collection_mint: Pubkey,
+    pub collection_total_burned: u64,
+    pub unique_burners: u64,
+    pub user: Pubkey,
+    pub user_total_burned: u64,
+}
+
+/// Transfer record returned by `get_transfer_status`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TransferStatusView {
+    pub transfer: Pubkey,
+    pub nft_mint: Pubkey,
+    pub destination_chain_id: u64,
+    pub nonce: u64,
+    pub status: TransferStatus,
+    pub timestamp: i64,
+    pub gas_fee: u64,
+}
+
+/// NFT origin returned by `get_nft_origin`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct NftOriginView {
+    pub mint: Pubkey,
+    pub origin_chain_id: u64,
+    pub chain_kind: ChainKind,
+    pub origin_sender: Vec<u8>,
+    pub inscription: Option<InscriptionRef>,
+    pub recorded_at: i64,
+}
+
+/// Chain registration returned by `get_chain_config`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ChainConfigView {
+    pub chain_id: u64,
+    pub chain_kind: ChainKind,
+    pub address_format: AddressFormat,
+    pub counterpart_contract: [u8; 20],
+    pub enabled: bool,
+    pub limits: ChainTransferLimits,
+    /// Outbound floor value over the last 24h, in lamports
+    pub rolling_value: u64,
+    /// Outbound transfers over the last 24h
+    pub rolling_count: u64,
+}
+
+/// User-set lock on all outbound transfers and bridges from a wallet. Lifting
+/// the lock starts a timer, so a compromised key can't move NFTs before the
+/// owner notices and re-locks.
+#[account]
+#[derive(InitSpace)]
+pub struct WalletLock {
+    /// Wallet the lock protects
+    pub wallet: Pubkey,
+    /// Whether the wallet has locked its transfers
+    pub locked: bool,
+    /// Seconds between an unlock request and the lock lapsing
+    pub unlock_delay: i64,
+    /// Timestamp an unlock was requested (0 if none pending)
+    pub unlock_requested_at: i64,
+    /// Timestamp the lock was last engaged
+    pub locked_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Temporary operator key an owner registers for a marketplace or game
+/// server, limited to a set of scopes until `expires_slot`
+#[account]
+#[derive(InitSpace)]
+pub struct OperatorSession {
+    /// Wallet that granted the session
+    pub owner: Pubkey,
+    /// Key allowed to act for the owner
+    pub operator: Pubkey,
+    /// Bitmask of `SessionScope` values granted
+    pub scopes: u8,
+    /// Only chain the operator may bridge to (None for any registered chain)
+    pub bridge_chain_id: Option<u64>,
+    /// Session is valid before this slot
+    pub expires_slot: u64,
+    /// Timestamp the session was registered
+    pub created_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Actions an operator session can be scoped to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SessionScope {
+    /// Move NFTs between Solana wallets (as the owner's token delegate)
+    Transfer,
+    /// Update NFT metadata
+    Metadata,
+    /// Bridge NFTs out (as the owner's token delegate)
+    Bridge,
+}
+
+impl SessionScope {
+    /// Bit used for this scope in a session's scope mask
+    pub fn mask(&self) -> u8 {
+        match self {
+            SessionScope::Transfer => 1 << 0,
+            SessionScope::Metadata => 1 << 1,
+            SessionScope::Bridge => 1 << 2,
+        }
+    }
+}
+
+/// Cost breakdown returned by `estimate_bridge_cost`, in lamports unless noted
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BridgeCostEstimate {
+    /// Base transaction signature fee
+    pub network_fee: u64,
+    /// Rent for the transfer record and receipt accounts created on send
+    pub rent_deposits: u64,
+    /// How destination gas is paid
+    pub gas_payment: GasPayment,
+    /// Destination gas fee, in lamports or ZRC-20 base units per `gas_payment`
+    pub destination_gas_fee: u64,
+    /// Protocol fee (none is charged today)
+    pub protocol_fee: u64,
+    /// Total lamports leaving the sender's wallet
+    pub total_lamports: u64,
+}
+
+/// Protocol-funded vault that pays account rent for inbound mints, so
+/// relayers don't have to front it. Holds the sponsored lamports itself.
+#[account]
+#[derive(InitSpace)]
+pub struct RentVault {
+    /// Whether inbound rent is currently sponsored
+    pub enabled: bool,
+    /// Lamports deposited over the vault's lifetime
+    pub total_funded: u64,
+    /// Lamports paid out as rent over the vault's lifetime
+    pub total_sponsored: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Rent sponsorship accounting for one source chain. A chain can only draw
+/// the rent its own fees funded.
+#[account]
+#[derive(InitSpace)]
+pub struct ChainRentLedger {
+    /// Source chain ID
+    pub chain_id: u64,
+    /// Lamports deposited for this chain
+    pub funded_lamports: u64,
+    /// Lamports of rent paid for this chain's inbound accounts
+    pub sponsored_lamports: u64,
+    /// Number of accounts whose rent was sponsored
+    pub sponsored_accounts: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Automation thread allowed to run the privileged cranks. Permissionless
+/// cranks don't need it.
+#[account]
+#[derive(InitSpace)]
+pub struct CrankConfig {
+    /// Signer of crank instructions, typically an automation thread PDA
+    pub operator: Pubkey,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Kind of maintenance work run by a crank
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum CrankKind {
+    /// Periodic state checkpoint
+    StateCheckpoint,
+    /// Proposal voting closed and tallied. Proposal cranks moved to the
+    /// governance program; kept so later variants keep their encoding.
+    ProposalFinalized,
+    /// Passed proposal missed its execution deadline (no longer emitted)
+    ProposalExpired,
+    /// Scheduled retry attempt
+    RetryAttempt,
+}
+
+/// How outbound NFT metadata is rewritten for a destination chain's standard
+#[account]
+#[derive(InitSpace)]
+pub struct ChainMetadataRules {
+    /// Chain the rules apply to
+    pub chain_id: u64,
+    /// Names longer than this many bytes are truncated (0 keeps them whole)
+    pub max_name_len: u8,
+    /// Symbols longer than this many bytes are truncated (0 keeps them whole)
+    pub max_symbol_len: u8,
+    /// URI prefix to replace, e.g. a public IPFS gateway (empty disables
+    /// rewriting)
+    #[max_len(64)]
+    pub uri_match_prefix: String,
+    /// Replacement for the matched prefix, e.g. `ipfs://`
+    #[max_len(64)]
+    pub uri_replace_prefix: String,
+    /// ERC-2981 receiver on the chain that pays out the Solana creators
+    /// (zero sends no royalty)
+    pub royalty_receiver: [u8; 20],
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Settings accepted by `set_chain_metadata_rules`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ChainMetadataRulesParams {
+    pub max_name_len: u8,
+    pub max_symbol_len: u8,
+    pub uri_match_prefix: String,
+    pub uri_replace_prefix: String,
+    pub royalty_receiver: [u8; 20],
+}
+
+/// Pinning service allowed to post pin receipts
+#[account]
+#[derive(InitSpace)]
+pub struct PinConfig {
+    /// Signer of pin receipts
+    pub pinner: Pubkey,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Storage network holding pinned metadata content
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum PinStorage {
+    /// Pinned on IPFS; the content ID is a CID
+    Ipfs,
+    /// Stored on Arweave; the content ID is a transaction ID
+    Arweave,
+}
+
+/// Evidence that the content behind an NFT's metadata URI is pinned
+#[account]
+#[derive(InitSpace)]
+pub struct PinReceipt {
+    /// NFT the content belongs to
+    pub mint: Pubkey,
+    /// Network the content is pinned on
+    pub storage: PinStorage,
+    /// CID or Arweave transaction ID of the content
+    #[max_len(96)]
+    pub content_id: String,
+    /// Content size in bytes
+    pub size: u64,
+    /// SHA-256 of the metadata URI the receipt was posted for
+    pub uri_hash: [u8; 32],
+    /// Pinner that posted the receipt
+    pub pinner: Pubkey,
+    /// Timestamp the content was pinned
+    pub pinned_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Content moderation settings and totals
+#[account]
+#[derive(InitSpace)]
+pub struct ModerationConfig {
+    /// Program authority that manages the moderator role
+    pub authority: Pubkey,
+    /// Moderator allowed to resolve flags and reinstate content
+    pub moderator: Pubkey,
+    /// Lamports a flagger posts with each flag
+    pub flag_bond: u64,
+    /// Total flags raised
+    pub total_flags: u64,
+    /// Total flags upheld with a takedown
+    pub total_takedowns: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Open content flag against an NFT, holding the flagger's bond
+#[account]
+#[derive(InitSpace)]
+pub struct ContentFlag {
+    /// NFT whose content is flagged
+    pub mint: Pubkey,
+    /// Account that raised the flag and posted the bond
+    pub flagger: Pubkey,
+    /// Hash of the off-chain complaint
+    pub reason_hash: [u8; 32],
+    /// Bond held by this account, in lamports
+    pub bond: u64,
+    /// Timestamp the flag was raised
+    pub flagged_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// How a collection's secondary-sale royalties are split among its creators
+#[account]
+#[derive(InitSpace)]
+pub struct RoyaltyConfig {
+    /// Collection mint the split applies to
+    pub collection_mint: Pubkey,
+    /// Creators and their shares, summing to 10000 basis points
+    #[max_len(5)]
+    pub shares: Vec<RoyaltyShare>,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// One creator's cut of a collection's royalties
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct RoyaltyShare {
+    /// Creator paid the share
+    pub creator: Pubkey,
+    /// Share of each deposit, in basis points
+    pub basis_points: u16,
+}
+
+/// Royalties deposited for a collection by marketplaces, held until claimed.
+/// Each deposit is credited to the creators' ledger entries when it arrives,
+/// so later changes to the split never move royalties already earned.
+#[account]
+#[derive(InitSpace)]
+pub struct RoyaltyEscrow {
+    /// Collection mint the royalties were paid for
+    pub collection_mint: Pubkey,
+    /// Lamports deposited over the escrow's lifetime
+    pub total_deposited: u64,
+    /// Lamports claimed over the escrow's lifetime
+    pub total_claimed: u64,
+    /// Deposits received
+    pub deposit_count: u64,
+    /// Per-creator lifetime earnings and claims
+    #[max_len(8)]
+    pub ledger: Vec<CreatorRoyalty>,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// A creator's royalty account within a collection's escrow
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct CreatorRoyalty {
+    /// Creator the entry belongs to
+    pub creator: Pubkey,
+    /// Lamports credited over the escrow's lifetime
+    pub earned: u64,
+    /// Lamports claimed over the escrow's lifetime
+    pub claimed: u64,
+}
+
+/// Lifetime royalty accounting returned by `get_royalty_accounting`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RoyaltyAccountingView {
+    pub collection_mint: Pubkey,
+    pub total_deposited: u64,
+    pub total_claimed: u64,
+    pub deposit_count: u64,
+    pub creators: Vec<CreatorRoyalty>,
+}
+
+/// Lock/wrap supply counters for one chain. Every original locked in
+/// custody for the chain should back exactly one wrapped representation
+/// outstanding there; `audit_supply_parity` checks the two agree.
+#[account]
+#[derive(InitSpace)]
+pub struct ChainSupplyLedger {
+    /// Chain the counters are kept for
+    pub chain_id: u64,
+    /// Originals held in program custody after bridging to the chain
+    pub originals_locked: u64,
+    /// Wrapped representations outstanding on the chain, as last reported
+    pub wrapped_outstanding: u64,
+    /// Timestamp of the last wrapped supply report
+    pub reported_at: i64,
+    /// Timestamp of the last audit
+    pub last_audit_at: i64,
+    /// Audits that found the counters out of parity
+    pub mismatch_count: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// How a wallet wants NFTs from one source chain delivered. Consulted by
+/// on_call for every mint message naming the wallet as recipient.
+#[account]
+#[derive(InitSpace)]
+pub struct RecipientPreferences {
+    /// Wallet the preferences belong to
+    pub owner: Pubkey,
+    /// Source chain the preferences apply to
+    pub source_chain_id: u64,
+    /// Address deliveries are redirected to instead of the owner
+    pub preferred_recipient: Option<Pubkey>,
+    /// Create the recipient's token account for wrapped NFTs automatically.
+    /// When off, deliveries need a token account the recipient already holds.
+    pub auto_wrap: bool,
+    /// What happens to mints from senders outside `trusted_senders`
+    pub unsolicited: UnsolicitedPolicy,
+    /// Senders on the source chain whose mints are always accepted
+    #[max_len(8)]
+    pub trusted_senders: Vec<[u8; 20]>,
+    /// Timestamp the preferences last changed
+    pub updated_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Settings accepted by `set_recipient_preferences`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RecipientPreferencesParams {
+    pub preferred_recipient: Option<Pubkey>,
+    pub auto_wrap: bool,
+    pub unsolicited: UnsolicitedPolicy,
+    pub trusted_senders: Vec<[u8; 20]>,
+}
+
+/// Handling of inbound mints from senders a recipient hasn't trusted
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum UnsolicitedPolicy {
+    /// Deliver straight to the recipient
+    Accept,
+    /// Hold in quarantine until the recipient claims or rejects it
+    Quarantine,
+    /// Fail the delivery
+    Decline,
+}
+
+/// Unsolicited inbound NFT held in program custody until its recipient
+/// claims it. Rejected or expired NFTs are burned and sent back to the
+/// source chain's sender.
+#[account]
+#[derive(InitSpace)]
+pub struct QuarantinedNft {
+    /// Quarantined NFT
+    pub mint: Pubkey,
+    /// Wallet the NFT was delivered for
+    pub recipient: Pubkey,
+    /// Chain the NFT arrived from
+    pub source_chain_id: u64,
+    /// Sender on the source chain, where a rejected NFT is returned
+    pub sender: [u8; 20],
+    /// Timestamp the NFT was quarantined
+    pub quarantined_at: i64,
+    /// After this timestamp anyone may send the NFT back
+    pub expires_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Protocol fees, set by governance. A new schedule is queued and only takes
+/// effect once its timelock has passed, so payers see every change coming.
+#[account]
+#[derive(InitSpace)]
+pub struct FeeSchedule {
+    /// Fees currently charged
+    pub active: FeeRates,
+    /// Fees queued by governance, waiting out the timelock
+    pub pending: Option<FeeRates>,
+    /// Timestamp the pending fees may be applied (0 if none pending)
+    pub pending_effective_at: i64,
+    /// Timestamp the active fees took effect
+    pub updated_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// One set of protocol fee knobs
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct FeeRates {
+    /// Fee switch; while off nothing is charged
+    pub enabled: bool,
+    /// Lamports charged per mint
+    pub mint_fee: u64,
+    /// Bridge fee, in basis points of the destination gas fee in lamports
+    pub bridge_fee_bps: u16,
+    /// Lamports charged per metadata update
+    pub metadata_update_fee: u64,
+    /// Marketplace fee, in basis points of the sale price
+    pub marketplace_fee_bps: u16,
+    /// Wallet collecting the fees
+    pub fee_recipient: Pubkey,
+    /// Discounts for governance stakers; unused tiers have `min_stake` 0
+    pub stake_tiers: [StakeDiscountTier; 3],
+    /// Collection whose holders get `membership_discount_bps` off
+    pub membership_collection: Option<Pubkey>,
+    /// Discount for holders of the membership collection, in basis points
+    pub membership_discount_bps: u16,
+    /// Referrer's share of each fee charged, in basis points
+    pub referral_share_bps: u16,
+}
+
+/// Referral earnings of one referrer. Its share of each referred fee is
+/// paid into this PDA as the fee is charged and held until claimed.
+#[account]
+#[derive(InitSpace)]
+pub struct ReferrerAccrual {
+    /// Wallet credited for referrals
+    pub referrer: Pubkey,
+    /// Lamports earned over the account's lifetime
+    pub accrued: u64,
+    /// Lamports claimed over the account's lifetime
+    pub claimed: u64,
+    /// Referred fees charged
+    pub referral_count: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Fee discount for payers staking at least `min_stake` governance tokens
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace)]
+pub struct StakeDiscountTier {
+    /// Smallest governance stake that qualifies
+    pub min_stake: u64,
+    /// Discount, in basis points of the fee
+    pub discount_bps: u16,
+}
+
+/// Paying action a protocol fee is charged for
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FeeKind {
+    Mint,
+    Bridge,
+    MetadataUpdate,
+    Marketplace,
+}
+
+/// Promotional campaign refunding part of each eligible protocol fee from
+/// lamports the treasury deposits, until the budget runs out
+#[account]
+#[derive(InitSpace)]
+pub struct RebateCampaign {
+    /// Most the campaign refunds over its lifetime, in lamports
+    pub budget: u64,
+    /// Lamports refunded so far
+    pub spent: u64,
+    /// Lamports refunded per charged fee, capped at the fee
+    pub rebate_per_tx: u64,
+    /// Campaign start timestamp
+    pub starts_at: i64,
+    /// Campaign end timestamp
+    pub ends_at: i64,
+    /// Mask of `FeeKind::mask` bits the campaign refunds
+    pub eligible_kinds: u8,
+    /// Lamports deposited over the campaign's lifetime
+    pub funded: u64,
+    /// Fees rebated
+    pub rebate_count: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Settings accepted by `configure_rebate_campaign`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct RebateCampaignParams {
+    pub budget: u64,
+    pub rebate_per_tx: u64,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub eligible_kinds: u8,
+}
+
+/// Points each protocol activity earns towards a user's non-transferable
+/// balance (analytics builds only)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct PointsWeights {
+    /// Points per NFT minted
+    pub mint: u64,
+    /// Points per NFT bridged out
+    pub bridge: u64,
+    /// Points per governance vote cast
+    pub governance: u64,
+}
+
+/// Protocol activity that earns points
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointsActivity {
+    Mint,
+    Bridge,
+    Governance,
+}
+
+/// Keys a collection allows to write its NFTs' attributes, such as a game's
+/// servers
+#[account]
+#[derive(InitSpace)]
+pub struct TraitAuthorities {
+    /// Collection the authorities write for
+    pub collection_mint: Pubkey,
+    /// Keys allowed to set and remove attribute entries
+    #[max_len(8)]
+    pub authorities: Vec<Pubkey>,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Attribute entries of one NFT, carried with it when it is bridged
+#[account]
+#[derive(InitSpace)]
+pub struct NftAttributes {
+    /// NFT the attributes belong to
+    pub mint: Pubkey,
+    /// Collection whose trait authorities write the entries
+    pub collection_mint: Pubkey,
+    /// Key/value entries, unique by key
+    #[max_len(16)]
+    pub entries: Vec<NftAttribute>,
+    /// Last change timestamp
+    pub updated_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// One attribute entry, e.g. `level` or `durability`. Values are raw bytes
+/// the collection's game interprets.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub struct NftAttribute {
+    #[max_len(32)]
+    pub key: String,
+    #[max_len(64)]
+    pub value: Vec<u8>,
+}
+
+/// Reveal-style collection whose NFTs are assigned metadata from a committed
+/// list by Switchboard randomness. The list root, randomness account and
+/// revealed value stay on-chain as the fairness proof.
+#[account]
+#[derive(InitSpace)]
+pub struct RevealConfig {
+    /// Collection being revealed
+    pub collection_mint: Pubkey,
+    /// Merkle root of the `(index, uri)` list, fixed before the randomness
+    pub list_root: [u8; 32],
+    /// Entries in the list
+    pub item_count: u32,
+    /// Switchboard randomness account committed to
+    pub randomness_account: Pubkey,
+    /// Slot the randomness was committed at
+    pub seed_slot: u64,
+    /// Randomness value, once revealed
+    pub randomness: Option<[u8; 32]>,
+    /// Slot the oracle revealed the randomness in
+    pub reveal_slot: u64,
+    /// NFTs assigned an entry so far
+    pub revealed_count: u32,
+    /// One bit per list entry, set once the entry is assigned
+    #[max_len(1250)]
+    pub assigned: Vec<u8>,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// List entry an NFT was assigned at reveal
+#[account]
+#[derive(InitSpace)]
+pub struct RevealedNft {
+    /// NFT revealed
+    pub mint: Pubkey,
+    /// Collection it was revealed in
+    pub collection_mint: Pubkey,
+    /// Entry the randomness pointed at
+    pub target_index: u32,
+    /// Entry assigned, the first free one from the target
+    pub item_index: u32,
+    /// Reveal timestamp
+    pub revealed_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// NFTs equipped into a parent NFT. The children sit in program custody and
+/// follow the parent wherever its holder takes it.
+#[account]
+#[derive(InitSpace)]
+pub struct NftChildren {
+    /// Parent NFT
+    pub parent_mint: Pubkey,
+    /// Equipped children, in equip order
+    #[max_len(4)]
+    pub children: Vec<EquippedChild>,
+    /// What happens to the children when the parent is bridged out
+    pub bridge_mode: ChildBridgeMode,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Child NFT as it was when equipped, sent along when its parent is bundled
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace, Debug)]
+pub struct EquippedChild {
+    pub mint: Pubkey,
+    #[max_len(64)]
+    pub token_id: String,
+    #[max_len(32)]
+    pub name: String,
+    #[max_len(16)]
+    pub symbol: String,
+    #[max_len(200)]
+    pub uri: String,
+    pub collection_mint: Option<Pubkey>,
+}
+
+/// How a parent with equipped children leaves Solana
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum ChildBridgeMode {
+    /// The parent can't be bridged until its children are unequipped
+    Block,
+    /// The children stay locked in custody and travel in the parent's message
+    Bundle,
+}
+
+/// Link from an equipped NFT to the parent holding it
+#[account]
+#[derive(InitSpace)]
+pub struct NftParent {
+    /// Equipped NFT
+    pub child_mint: Pubkey,
+    /// Parent it is equipped into
+    pub parent_mint: Pubkey,
+    /// Equip timestamp
+    pub equipped_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Crafting recipe that evolves a collection's NFTs: burn the listed
+/// resources and the NFT gets new attributes and, optionally, a new URI
+#[account]
+#[derive(InitSpace)]
+pub struct EvolutionRecipe {
+    /// Collection whose NFTs the recipe applies to
+    pub collection_mint: Pubkey,
+    /// Recipe number within the collection
+    pub recipe_id: u32,
+    /// SPL tokens burned from the owner on each evolution
+    #[max_len(4)]
+    pub inputs: Vec<RecipeInput>,
+    /// Attribute entries the NFT must have, e.g. `stage = 1`
+    #[max_len(4)]
+    pub requires: Vec<NftAttribute>,
+    /// Attribute entries written on evolution, e.g. `stage = 2`
+    #[max_len(4)]
+    pub sets: Vec<NftAttribute>,
+    /// New metadata URI, empty to keep the current one
+    #[max_len(200)]
+    pub uri: String,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Fungible resource a recipe consumes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct RecipeInput {
+    /// Resource mint
+    pub mint: Pubkey,
+    /// Amount burned, in base units
+    pub amount: u64,
+}
+
+/// Settings accepted by `set_evolution_recipe`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct EvolutionRecipeParams {
+    pub inputs: Vec<RecipeInput>,
+    pub requires: Vec<NftAttribute>,
+    pub sets: Vec<NftAttribute>,
+    pub uri: String,
+}
+
+/// Ticketing profile of a collection whose NFTs are event tickets
+#[account]
+#[derive(InitSpace)]
+pub struct TicketingProfile {
+    /// Collection whose NFTs are tickets
+    pub collection_mint: Pubkey,
+    /// Keys allowed to check tickets in at the door
+    #[max_len(8)]
+    pub validators: Vec<Pubkey>,
+    /// Whether a used ticket stays locked so it can't be resold
+    pub freeze_after_use: bool,
+    /// Chain check-in proofs are sent to, 0 for none
+    pub proof_chain_id: u64,
+    /// Contract receiving check-in proofs on that chain
+    pub proof_receiver: [u8; 20],
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Settings accepted by `configure_ticketing`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct TicketingParams {
+    pub validators: Vec<Pubkey>,
+    pub freeze_after_use: bool,
+    pub proof_chain_id: u64,
+    pub proof_receiver: [u8; 20],
+}
+
+/// Event an NFT admits its holder to, and whether it has been used
+#[account]
+#[derive(InitSpace)]
+pub struct Ticket {
+    /// Ticket NFT
+    pub mint: Pubkey,
+    /// Ticketing collection
+    pub collection_mint: Pubkey,
+    /// Event the ticket is for
+    pub event_id: u64,
+    /// Whether the ticket has been checked in
+    pub used: bool,
+    /// Check-in timestamp, 0 while unused
+    pub checked_in_at: i64,
+    /// Validator that checked the ticket in
+    pub validator: Pubkey,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// SPL tokens escrowed behind an NFT, claimable by whoever holds it as they
+/// vest. The escrow stays on Solana while the NFT travels.
+#[account]
+#[derive(InitSpace)]
+pub struct VestingPosition {
+    /// NFT wrapping the position
+    pub nft_mint: Pubkey,
+    /// Amounts and schedule, carried with the NFT when it is bridged
+    pub terms: VestingTerms,
+    /// Wallet that funded the position
+    pub funder: Pubkey,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Amounts and linear schedule of a vesting position. Nothing vests before
+/// the cliff; from then on the amount grows linearly from `start_ts` until
+/// everything has vested at `end_ts`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct VestingTerms {
+    pub token_mint: Pubkey,
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub start_ts: i64,
+    pub cliff_ts: i64,
+    pub end_ts: i64,
+}
+
+/// Proof-of-attendance drop for one event of a collection. Attendees claim a
+/// free compressed NFT minted into the drop's Bubblegum tree, whose delegate
+/// is this PDA. Claims need an allowlist entry, and each entry claims once.
+#[account]
+#[derive(InitSpace)]
+pub struct AttendanceDrop {
+    /// Collection the drop belongs to
+    pub collection_mint: Pubkey,
+    /// Event the drop commemorates
+    pub event_id: u64,
+    /// Bubblegum tree the attendance NFTs are minted into
+    pub merkle_tree: Pubkey,
+    /// Merkle root of the `(index, wallet)` allowlist, one entry per wallet
+    pub allowlist_root: [u8; 32],
+    /// Name template, `{n}` becomes the claim number
+    #[max_len(32)]
+    pub name_template: String,
+    /// Symbol of every attendance NFT
+    #[max_len(16)]
+    pub symbol: String,
+    /// URI template, `{n}` becomes the claim number
+    #[max_len(200)]
+    pub uri_template: String,
+    /// Claims open at this timestamp
+    pub start_time: i64,
+    /// Claims close at this timestamp (exclusive)
+    pub end_time: i64,
+    /// Allowlist entries, and so the most NFTs the drop mints
+    pub max_claims: u32,
+    /// NFTs claimed so far
+    pub claimed_count: u32,
+    /// One bit per allowlist entry, set once the entry claims
+    #[max_len(8000)]
+    pub claimed: Vec<u8>,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Parameters of an attendance drop
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AttendanceDropParams {
+    pub merkle_tree: Pubkey,
+    pub allowlist_root: [u8; 32],
+    pub name_template: String,
+    pub symbol: String,
+    pub uri_template: String,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub max_claims: u32,
+}
+
+/// Credential profile of a collection. Its issuers attest claims about
+/// wallets as soulbound credentials, and revocations can be sent to other
+/// chains.
+#[account]
+#[derive(InitSpace)]
+pub struct CredentialProfile {
+    /// Collection the credentials are issued under
+    pub collection_mint: Pubkey,
+    /// Keys allowed to issue and revoke credentials
+    #[max_len(8)]
+    pub issuers: Vec<Pubkey>,
+    /// Chain revocations are sent to, 0 for none
+    pub revocation_chain_id: u64,
+    /// Contract receiving revocations on that chain
+    pub revocation_receiver: [u8; 20],
+    /// Credentials issued so far, the next credential's ID
+    pub issued_count: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Settings accepted by `configure_credentials`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CredentialProfileParams {
+    pub issuers: Vec<Pubkey>,
+    pub revocation_chain_id: u64,
+    pub revocation_receiver: [u8; 20],
+}
+
+/// Soulbound attestation about a wallet. It is an account keyed by the
+/// profile's sequence, so there is no token to move.
+#[account]
+#[derive(InitSpace)]
+pub struct Credential {
+    /// Collection the credential was issued under
+    pub collection_mint: Pubkey,
+    /// Sequential ID within the collection
+    pub credential_id: u64,
+    /// Wallet the credential is about
+    pub subject: Pubkey,
+    /// Issuer that attested it
+    pub issuer: Pubkey,
+    /// Hash of the credential schema
+    pub schema_hash: [u8; 32],
+    /// Off-chain claims document
+    #[max_len(200)]
+    pub uri: String,
+    /// Issue timestamp
+    pub issued_at: i64,
+    /// Expiry timestamp, 0 for none
+    pub expires_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Entry of the revocation registry. A credential is revoked exactly when
+/// its entry exists.
+#[account]
+#[derive(InitSpace)]
+pub struct CredentialRevocation {
+    /// Credential revoked
+    pub credential: Pubkey,
+    /// Issuer that revoked it
+    pub revoked_by: Pubkey,
+    /// Issuer-defined reason code
+    pub reason: u16,
+    /// Revocation timestamp
+    pub revoked_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Credential status returned by `verify_credential`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CredentialStatusView {
+    pub credential: Pubkey,
+    pub subject: Pubkey,
+    pub issuer: Pubkey,
+    pub schema_hash: [u8; 32],
+    pub issued_at: i64,
+    pub expires_at: i64,
+    pub expired: bool,
+    pub revoked: bool,
+    /// Neither expired nor revoked
+    pub valid: bool,
+}
+
+/// Escrowed swap offered by a maker. The maker's leg waits in the swap
+/// PDA's vault, and accepting pays the taker's leg and releases it in the
+/// same instruction.
+#[account]
+#[derive(InitSpace)]
+pub struct Swap {
+    /// Wallet that offered the swap and escrowed its leg
+    pub maker: Pubkey,
+    /// Maker-chosen ID, unique per maker
+    pub swap_id: u64,
+    /// Only wallet allowed to accept, default for anyone
+    pub taker: Pubkey,
+    /// Leg escrowed by the maker
+    pub maker_asset: SwapAsset,
+    /// Leg the taker pays
+    pub taker_asset: SwapAsset,
+    /// Creation timestamp
+    pub created_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// One leg of a swap: an NFT (amount 1) or an amount of an SPL token
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub struct SwapAsset {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Hashed-timelock swap of an escrowed universal NFT for an asset on another
+/// chain. The maker alone knows the secret behind `hashlock`. Revealing it
+/// to claim the remote leg releases this leg to the taker, and otherwise the
+/// maker is refunded once the lock expires.
+#[account]
+#[derive(InitSpace)]
+pub struct CrossChainSwap {
+    /// Wallet that escrowed the NFT and holds the secret
+    pub maker: Pubkey,
+    /// Maker-chosen ID, unique per maker
+    pub swap_id: u64,
+    /// Escrowed universal NFT
+    pub nft_mint: Pubkey,
+    /// Solana wallet receiving the NFT when the swap completes
+    pub taker: Pubkey,
+    /// Chain the remote leg is locked on
+    pub counterparty_chain_id: u64,
+    /// Maker's address receiving the remote leg
+    pub maker_receiver: [u8; 20],
+    /// Asset the taker locks on the counterparty chain
+    pub remote_asset: RemoteAsset,
+    /// SHA-256 of the maker's secret
+    pub hashlock: [u8; 32],
+    /// The maker can be refunded from this timestamp
+    pub expires_at: i64,
+    /// Transfer record of the lock request, reverted through `on_revert`
+    pub transfer: Pubkey,
+    /// Where the swap stands
+    pub status: CrossChainSwapStatus,
+    /// Revealed secret, zero until known
+    pub secret: [u8; 32],
+    /// Creation timestamp
+    pub created_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// NFT a cross-chain swap's taker locks on the counterparty chain
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub struct RemoteAsset {
+    /// Token contract
+    pub token_contract: [u8; 20],
+    /// Token ID, big-endian uint256
+    pub token_id: [u8; 32],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum CrossChainSwapStatus {
+    /// NFT escrowed, waiting for the secret or the expiry
+    Locked,
+    /// Secret revealed on the counterparty chain, the taker can redeem
+    Redeemable,
+    /// Lock request reverted, the maker can be refunded at once
+    Reverted,
+}
+
+/// Loan against a universal NFT escrowed in the loan PDA's vault. A lender
+/// funds the request, and the borrower repays to get the NFT back or the
+/// lender takes it once the loan is overdue.
+#[account]
+#[derive(InitSpace)]
+pub struct Loan {
+    /// Collateral NFT
+    pub nft_mint: Pubkey,
+    /// Wallet that escrowed the NFT and receives the principal
+    pub borrower: Pubkey,
+    /// Wallet that funded the loan, default until funded
+    pub lender: Pubkey,
+    pub terms: LoanTerms,
+    /// Collateral's floor value in lamports when requested, 0 if unpriced
+    pub collateral_value: u64,
+    /// Funding timestamp, 0 until funded
+    pub funded_at: i64,
+    /// Repayment deadline, after which the lender can liquidate
+    pub due_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Principal and repayment of a loan, in an SPL token (wrapped SOL for SOL)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub struct LoanTerms {
+    pub principal_mint: Pubkey,
+    /// Amount lent to the borrower
+    pub principal: u64,
+    /// Amount the borrower repays, principal plus interest
+    pub repayment: u64,
+    /// Seconds from funding until the loan is due
+    pub duration: i64,
+}
+
+/// Ranking a leaderboard keeps
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum LeaderboardKind {
+    /// Users by NFTs minted
+    Minters,
+    /// Users by outbound transfers
+    Bridgers,
+    /// Collections by outbound transfers
+    BridgedCollections,
+}
+
+/// One ranked key on a leaderboard
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct LeaderboardEntry {
+    /// User or collection mint
+    pub key: Pubkey,
+    pub score: u64,
+}
+
+/// Fixed-size top-N ranking updated as mints and bridges happen, so
+/// dashboards can read it without an indexer (analytics builds only)
+#[account]
+#[derive(InitSpace)]
+pub struct Leaderboard {
+    pub kind: LeaderboardKind,
+    /// Highest score first
+    #[max_len(10)]
+    pub entries: Vec<LeaderboardEntry>,
+    /// Last time an entry changed
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Leaderboard returned by `get_leaderboard`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LeaderboardView {
+    pub kind: LeaderboardKind,
+    pub entries: Vec<LeaderboardEntry>,
+    pub updated_at: i64,
+}
+
+/// A user's usual transfers, learned from their own history so fraud
+/// scoring can flag what is unusual for them rather than for everyone.
+/// Hour and chain weights decay by a sixteenth per transfer.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug, InitSpace)]
+pub struct BehaviorBaseline {
+    /// Transfers learned from
+    pub samples: u32,
+    /// Running estimate of the median transfer value (lamports)
+    pub median_value: u64,
+    /// Decayed weight of each UTC hour transfers were made in
+    pub hour_weights: [u8; 24],
+    /// Chains transferred to most, with `chain_weights`
+    pub chain_ids: [u64; 4],
+    /// Decayed weight of each of `chain_ids`; zero marks an empty slot
+    pub chain_weights: [u8; 4],
+    /// Last transfer learned from
+    pub last_sample: i64,
+}
+
+/// Direction of a corridor between Solana and another chain, seen from Solana
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CorridorDirection {
+    /// Messages from the chain to Solana
+    Inbound,
+    /// Transfers from Solana to the chain
+    Outbound,
+    /// Both directions
+    Both,
+}
+
+/// Pause state of the corridors between Solana and one chain, set by
+/// TSS-signed admin messages or by the program authority
+#[account]
+#[derive(InitSpace)]
+pub struct CorridorPause {
+    /// Chain at the other end of the corridors
+    pub chain_id: u64,
+    /// Whether messages from the chain are rejected
+    pub inbound_paused: bool,
+    /// Whether transfers to the chain are rejected
+    pub outbound_paused: bool,
+    /// Nonce of the last admin message applied; later ones need a higher nonce
+    pub last_admin_nonce: u64,
+    /// Pause requests sent to the chain, the next request's nonce
+    pub requests_sent: u64,
+    /// Timestamp of the last change
+    pub updated_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Critical security events exported to ZetaChain
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SecurityEventKind {
+    /// A chain's circuit breaker opened; value is the failure count
+    BreakerTripped,
+    /// The fraud engine blocked an operation; value is its risk score
+    FraudBlocked,
+    /// The incident level changed; value is the new level
+    IncidentLevelChanged,
+}
+
+/// Where critical security events are exported and who may report them
+#[account]
+#[derive(InitSpace)]
+pub struct SecurityExport {
+    /// ZetaChain chain ID the monitoring contract lives on
+    pub monitor_chain_id: u64,
+    /// Monitoring contract receiving the events
+    pub monitor_contract: [u8; 20],
+    /// Keeper allowed to report events besides the program authority
+    pub reporter: Pubkey,
+    /// Sequence number of the next exported event
+    pub next_sequence: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Settings accepted by `configure_security_export`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SecurityExportParams {
+    pub monitor_chain_id: u64,
+    pub monitor_contract: [u8; 20],
+    pub reporter: Pubkey,
+}
+
+/// Chain-pair risk policy the fraud engine scores transfers with, set by
+/// governance so it can change without an upgrade
+#[account]
+#[derive(InitSpace)]
+pub struct ChainRiskRegistry {
+    /// Risk added for each end of a transfer touching a listed chain
+    #[max_len(16)]
+    pub chain_risks: Vec<ChainRisk>,
+    /// Risks that replace the computed score for specific pairs
+    #[max_len(16)]
+    pub pair_overrides: Vec<PairRiskOverride>,
+    /// Last update
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Risk score of one chain
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct ChainRisk {
+    pub chain_id: u64,
+    /// 0-500
+    pub risk: u16,
+}
+
+/// Fixed risk for transfers from `source_chain_id` to `destination_chain_id`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct PairRiskOverride {
+    pub source_chain_id: u64,
+    pub destination_chain_id: u64,
+    /// 0-500
+    pub risk: u16,
+}
+
+/// New contents of the chain risk registry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ChainRiskParams {
+    pub chain_risks: Vec<ChainRisk>,
+    pub pair_overrides: Vec<PairRiskOverride>,
+}
+
+/// Satellite programs the core program works with
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum SatelliteKind {
+    /// Treasury, DAO and bug bounty
+    Governance,
+    Marketplace,
+    Staking,
+    Lending,
+}
+
+/// Approved deployment of one satellite program
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct SatelliteProgram {
+    pub kind: SatelliteKind,
+    pub program_id: Pubkey,
+    /// Release deployed at `program_id`, bumped by governance on each upgrade
+    pub version: u32,
+}
+
+/// Satellite program IDs the core program accepts accounts and CPIs from, set
+/// by governance so satellites can be redeployed without a core upgrade
+#[account]
+#[derive(InitSpace)]
+pub struct DeploymentRegistry {
+    /// One approved deployment per satellite kind
+    #[max_len(8)]
+    pub satellites: Vec<SatelliteProgram>,
+    /// Last update
+    pub updated_at: i64,
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// Collection information for universal NFTs
+#[account]
+#[derive(InitSpace)]
+pub struct UniversalCollection {
+    /// Collection mint account
+    pub mint: Pubkey,
+    /// Collection authority
+    pub authority: Pubkey,
+    /// Collection name
+    #[max_len(64)]
+    pub name: String,
+    /// Collection symbol
+    #[max_len(16)]
+    pub symbol: String,
+    /// Collection URI for metadata
+    #[max_len(200)]
+    pub uri: String,
+    /// Total supply of NFTs in this collection
+    pub total_supply: u64,
+    /// Maximum supply (0 for unlimited)
+    pub max_supply: u64,
+    /// Whether this collection is verified
+    pub is_verified: bool,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Registry tracking NFTs left in program custody after a failed inbound flow
+#[account]
+#[derive(InitSpace)]
+pub struct OrphanRegistry {
+    /// Total NFTs registered as orphaned
+    pub total_orphaned: u64,
+    /// Total orphaned NFTs claimed by their original recipient
+    pub total_claimed: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// An NFT held by the custody PDA awaiting its original recipient
+#[account]
+#[derive(InitSpace)]
+pub struct OrphanedNft {
+    /// The orphaned NFT mint
+    pub mint: Pubkey,
+    /// Token account holding the NFT, owned by the custody PDA
+    pub custody_token_account: Pubkey,
+    /// Chain the inbound message originated from
+    pub source_chain_id: u64,
+    /// Relayed message hash of the inbound message that failed to deliver
+    /// the NFT, proves the recipient on claim
+    pub message_hash: [u8; 32],
+    /// Timestamp the orphan was registered
+    pub registered_at: i64,
+    /// Recipient that claimed the NFT, once claimed
+    pub claimed_by: Option<Pubkey>,
+    /// Timestamp the orphan was claimed
+    pub claimed_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Dispute settings and arbiter role
+#[account]
+#[derive(InitSpace)]
+pub struct DisputeConfig {
+    /// Program authority that manages the arbiter role
+    pub authority: Pubkey,
+    /// Arbiter allowed to resolve disputes
+    pub arbiter: Pubkey,
+    /// Seconds the arbiter has to resolve a dispute once opened
+    pub resolution_window: i64,
+    /// Lamports the opener escrows in the dispute when opening it
+    pub bond: u64,
+    /// Total disputes opened
+    pub total_opened: u64,
+    /// Total disputes resolved or expired
+    pub total_closed: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Dispute opened against a cross-chain transfer record
+#[account]
+#[derive(InitSpace)]
+pub struct Dispute {
+    /// Transfer record under dispute
+    pub transfer: Pubkey,
+    /// NFT mint frozen by the dispute
+    pub mint: Pubkey,
+    /// Party that opened the dispute
+    pub opened_by: Pubkey,
+    /// NFT owner at the time the dispute was opened
+    pub original_owner: Pubkey,
+    /// Party receiving the rest of the escrow when it is split
+    pub counterparty: Pubkey,
+    /// Hash of the off-chain dispute statement
+    pub reason_hash: [u8; 32],
+    /// Current dispute status
+    pub status: DisputeStatus,
+    /// Share of the escrow awarded to the opener, in basis points
+    pub opener_share_bps: u16,
+    /// Lamports held in the dispute until it is resolved or expires
+    pub escrowed: u64,
+    /// Timestamp the dispute was opened
+    pub opened_at: i64,
+    /// Deadline for arbiter resolution
+    pub deadline: i64,
+    /// Timestamp the dispute was closed
+    pub closed_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+/// Dispute status enumeration
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum DisputeStatus {
+    /// Awaiting arbiter resolution
+    Open,
+    /// Transfer upheld, NFT released
+    Released,
+    /// Transfer reverted to the original owner
+    Reverted,
+    /// Escrow split between the parties
+    Split,
+    /// Deadline passed without resolution
+    Expired,
+}
+
+impl ProgramConfig {
+    pub const INIT_SPACE: usize = 
+        32 + // authority
+        32 + // gateway_authority
+        32 + // tss_authority
+        8 +  // nonce
+        1 +  // bump
+        1;   // is_paused
+}
+
+impl UniversalNft {
+    pub const INIT_SPACE: usize = 
+        32 + // mint
+        8 +  // origin_chain_id
+        4 + 64 + // origin_token_id (String with max 64 chars)
+        32 + // owner
+        4 + 200 + // uri (String with max 200 chars)
+        4 + 32 + // name (String with max 32 chars)
+        4 + 16 + // symbol (String with max 16 chars)
+        1 + 32 + // collection_mint (Option<Pubkey>)
+        8 +  // creation_block
+        8 +  // creation_timestamp
+        1 +  // bump
+        1 +  // is_locked
+        1 +  // is_disputed
+        1 +  // is_frozen
+        1 +  // transfer_in_progress
+        1;   // is_flagged
+
+    /// Fail fast while an outbound transfer of this NFT is in flight, so
+    /// concurrent or nested operations can't act on half-updated state
+    pub fn require_no_transfer_in_progress(&self) -> Result<()> {
+        require!(!self.transfer_in_progress, UniversalNftError::TransferInProgress);
+        Ok(())
+    }
+
+    /// Require `owner` to hold the NFT free of transfers, locks, disputes and
+    /// legal holds, so it can leave for good
+    pub fn require_releasable_by(&self, owner: &Pubkey) -> Result<()> {
+        self.require_no_transfer_in_progress()?;
+        require!(!self.is_locked, UniversalNftError::NftLocked);
+        require!(!self.is_disputed, UniversalNftError::NftUnderDispute);
+        require!(!self.is_frozen, UniversalNftError::NftFrozen);
+        require!(self.owner == *owner, UniversalNftError::InvalidNftOwner);
+        Ok(())
+    }
+}
+
+impl CrossChainTransfer {
+    /// Maximum payload attached to an outbound transfer
+    pub const MAX_PAYLOAD_LEN: usize = 256;
+
+    pub const INIT_SPACE: usize = 
+        32 + // nft_mint
+        8 +  // source_chain_id
+        8 +  // destination_chain_id
+        20 + // sender
+        4 + 64 + // recipient (Vec<u8> with max 64 bytes)
+        8 +  // gas_limit
+        8 +  // nonce
+        8 +  // timestamp
+        1 +  // status (enum discriminator)
+        1 +  // bump
+        1 +  // gas_payment (enum discriminator)
+        8 +  // gas_fee
+        4 + Self::MAX_PAYLOAD_LEN + // payload
+        32;  // initiator
+}
+
+impl UniversalCollection {
+    pub const INIT_SPACE: usize = 
+        32 + // mint
+        32 + // authority
+        4 + 64 + // name (String with max 64 chars)
+        4 + 16 + // symbol (String with max 16 chars)
+        4 + 200 + // uri (String with max 200 chars)
+        8 +  // total_supply
+        8 +  // max_supply
+        1 +  // is_verified
+        1;   // bump
+}
+
+impl OrphanRegistry {
+    pub const INIT_SPACE: usize = 
+        8 +  // total_orphaned
+        8 +  // total_claimed
+        1;   // bump
+}
+
+impl OrphanedNft {
+    pub const INIT_SPACE: usize = 
+        32 + // mint
+        32 + // custody_token_account
+        8 +  // source_chain_id
+        32 + // message_hash
+        8 +  // registered_at
+        1 + 32 + // claimed_by (Option<Pubkey>)
+        8 +  // claimed_at
+        1;   // bump
+}
+
+impl DisputeConfig {
+    pub const INIT_SPACE: usize = 
+        32 + // authority
+        32 + // arbiter
+        8 +  // resolution_window
+        8 +  // bond
+        8 +  // total_opened
+        8 +  // total_closed
+        1;   // bump
+}
+
+impl Dispute {
+    pub const INIT_SPACE: usize = 
+        32 + // transfer
+        32 + // mint
+        32 + // opened_by
+        32 + // original_owner
+        32 + // counterparty
+        32 + // reason_hash
+        1 +  // status (enum discriminator)
+        2 +  // opener_share_bps
+        8 +  // escrowed
+        8 +  // opened_at
+        8 +  // deadline
+        8 +  // closed_at
+        1;   // bump
+}
+
+impl QueryResult {
+    /// Maximum response payload stored on chain
+    pub const MAX_RESPONSE_LEN: usize = 256;
+
+    pub const INIT_SPACE: usize = 
+        8 +  // query_id
+        32 + // requester
+        8 +  // target_chain_id
+        1 +  // query_type (enum discriminator)
+        20 + // contract_address
+        4 + 64 + // token_id (String with max 64 chars)
+        1 +  // status (enum discriminator)
+        4 + Self::MAX_RESPONSE_LEN + // response
+        8 +  // requested_at
+        8 +  // answered_at
+        1;   // bump
+
+    /// Whether an answered TokenExists query confirmed the token exists
+    pub fn confirms_existence(&self) -> bool {
+        self.query_type == QueryType::TokenExists
+            && self.status == QueryStatus::Answered
+            && self.response.first() == Some(&1)
+    }
+}
+
+impl GasTokenConfig {
+    pub const INIT_SPACE: usize = 
+        32 + // zrc20_mint
+        8 +  // destination_chain_id
+        32 + // vault
+        8 +  // price_per_gas_unit
+        1 +  // enabled
+        8 +  // total_collected
+        1;   // bump
+}
+
+impl RegisteredChain {
+    pub const INIT_SPACE: usize = 
+        8 +  // chain_id
+        1 +  // chain_kind (enum discriminator)
+        1 +  // address_format (enum discriminator)
+        20 + // counterpart_contract
+        1 +  // enabled
+        8 +  // registered_at
+        8 + 8 + 4 + 1 + // limits
+        8 + 8 + 8 + 4 + 4 + // volume
+        1 + 8 + 32 + // primary_route
+        1 + 1 + 8 + 32 + // fallback_route
+        1;   // bump
+
+    /// Require the chain to be enabled and of the expected kind
+    pub fn require_kind(&self, chain_id: u64, kind: ChainKind) -> Result<()> {
+        require!(self.chain_id == chain_id, UniversalNftError::ChainNotRegistered);
+        require!(self.enabled, UniversalNftError::ChainDisabled);
+        require!(self.chain_kind == kind, UniversalNftError::ChainKindMismatch);
+        Ok(())
+    }
+
+    /// Require the chain to be enabled and `recipient` to match its address format
+    pub fn require_valid_recipient(&self, chain_id: u64, recipient: &[u8]) -> Result<()> {
+        require!(self.chain_id == chain_id, UniversalNftError::ChainNotRegistered);
+        require!(self.enabled, UniversalNftError::ChainDisabled);
+        match self.address_format {
+            AddressFormat::Evm => crate::utils::CrossChainUtils::validate_evm_destination(
+                recipient,
+                &self.counterpart_contract,
+            )?,
+            _ => crate::utils::CrossChainUtils::validate_address(&self.address_format, recipient)?,
+        };
+        Ok(())
+    }
+
+    /// Recipient field of a message to this chain, encoded per its address format
+    pub fn encode_recipient(&self, recipient: &[u8]) -> Result<Pubkey> {
+        crate::utils::CrossChainUtils::encode_recipient(&self.address_format, recipient)
+    }
+}
+
+impl InscriptionRef {
+    /// Token ID of the inscription's Solana NFT: the hex SHA-256 of the txid
+    /// and little-endian index, as `<txid>i<index>` exceeds 64 characters
+    pub fn token_id(&self) -> String {
+        solana_program::hash::hashv(&[&self.txid, &self.index.to_le_bytes()])
+            .to_bytes()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+}
+
+impl NftOrigin {
+    pub const INIT_SPACE: usize = 
+        32 + // mint
+        8 +  // origin_chain_id
+        1 +  // chain_kind (enum discriminator)
+        4 + 64 + // origin_sender (Vec<u8> with max 64 bytes)
+        1 + 32 + 4 + // inscription (Option<InscriptionRef>)
+        8 +  // recorded_at
+        1;   // bump
+}
+
+impl HookProgram {
+    pub const INIT_SPACE: usize = 
+        32 + // program_id
+        4 +  // max_compute_units
+        8 +  // approved_at
+        1;   // bump
+}
+
+impl CollectionHooks {
+    /// Maximum hooks per collection
+    pub const MAX_HOOKS: usize = 4;
+
+    pub const INIT_SPACE: usize = 
+        32 + // collection_mint
+        32 + // authority
+        4 + Self::MAX_HOOKS * (32 + 1 + 4 + 1) + // hooks (Vec<HookEntry>)
+        1;   // bump
+}
+
+impl ScheduledTransfer {
+    /// Shortest allowed review delay
+    pub const MIN_DELAY_SECONDS: i64 = 60;
+    /// Longest allowed review delay
+    pub const MAX_DELAY_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+    pub const INIT_SPACE: usize = 
+        32 + // owner
+        32 + // mint
+        8 +  // destination_chain_id
+        4 + 64 + // recipient (Vec<u8> with max 64 bytes)
+        8 +  // gas_limit
+        8 +  // execute_after
+        8 +  // created_at
+        1;   // bump
+
+    /// Time a transfer scheduled now with `delay_seconds` of review may execute
+    pub fn execute_after(now: i64, delay_seconds: i64) -> Result<i64> {
+        require!(
+            (Self::MIN_DELAY_SECONDS..=Self::MAX_DELAY_SECONDS).contains(&delay_seconds),
+            UniversalNftError::InvalidScheduleDelay
+        );
+        Ok(now.checked_add(delay_seconds).ok_or(UniversalNftError::ArithmeticOverflow)?)
+    }
+
+    /// Require the review window to still be open
+    pub fn require_cancellable(&self, now: i64) -> Result<()> {
+        require!(now < self.execute_after, UniversalNftError::ScheduleWindowClosed);
+        Ok(())
+    }
+
+    /// Require the delay to have elapsed and the parameters to match the schedule
+    pub fn require_executable(
+        &self,
+        mint: Pubkey,
+        destination_chain_id: u64,
+        recipient: &[u8],
+        gas_limit: u64,
+        now: i64,
+    ) -> Result<()> {
+        require!(now >= self.execute_after, UniversalNftError::ScheduleNotReady);
+        require!(
+            self.mint == mint
+                && self.destination_chain_id == destination_chain_id
+                && self.recipient.as_slice() == recipient
+                && self.gas_limit == gas_limit,
+            UniversalNftError::ScheduleMismatch
+        );
+        Ok(())
+    }
+}
+
+impl BatchTransfer {
+    /// Maximum NFTs in one batch
+    pub const MAX_BATCH_SIZE: usize = 8;
+
+    pub const INIT_SPACE: usize = 
+        32 + // owner
+        8 +  // destination_chain_id
+        4 + 64 + // recipient (Vec<u8> with max 64 bytes)
+        4 + Self::MAX_BATCH_SIZE * 32 + // mints (Vec<Pubkey>)
+        8 +  // gas_limit
+        8 +  // gas_fee
+        8 +  // nonce
+        8 +  // timestamp
+        1 +  // status (enum discriminator)
+        1;   // bump
+}
+
+impl RelayerRegistry {
+    pub const INIT_SPACE: usize = 
+        32 + // authority
+        8 +  // min_stake
+        8 +  // reward_per_message
+        2 +  // slash_bps
+        8 +  // unbonding_period
+        8 +  // total_staked
+        8 +  // total_slashed
+        4 +  // relayer_count
+        1;   // bump
+}
+
+impl Relayer {
+    pub const INIT_SPACE: usize = 
+        32 + // authority
+        8 +  // stake
+        8 +  // messages_delivered
+        8 +  // invalid_submissions
+        8 +  // rewards_earned
+        1 +  // active
+        8 +  // unbonding_at
+        8 +  // registered_at
+        1;   // bump
+}
+
+impl RelayedMessage {
+    pub const INIT_SPACE: usize = 
+        32 + // message_hash
+        32 + // relayer
+        8 +  // source_chain_id
+        8 +  // sequence
+        1 +  // delivered
+        8 +  // submitted_at
+        1;   // bump
+}
+
+impl InboundQueue {
+    pub const INIT_SPACE: usize = 
+        8 +  // source_chain_id
+        8 +  // next_sequence
+        8 +  // pending_bitmap
+        1;   // bump
+}
+
+impl ComplianceConfig {
+    pub const INIT_SPACE: usize = 
+        32 + // authority
+        32 + // compliance_officer
+        8 +  // frozen_count
+        1;   // bump
+
+    /// Place an NFT under legal hold; `held_amount` is the balance of the
+    /// token account being frozen, which must be the one holding the NFT
+    pub fn record_freeze(&mut self, universal_nft: &mut UniversalNft, held_amount: u64) -> Result<()> {
+        require!(!universal_nft.is_frozen, UniversalNftError::NftFrozen);
+        require!(held_amount == 1, UniversalNftError::InvalidNftOwner);
+        universal_nft.is_frozen = true;
+        self.frozen_count = self.frozen_count
+            .checked_add(1)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Release an NFT from legal hold
+    pub fn record_release(&mut self, universal_nft: &mut UniversalNft, held_amount: u64) -> Result<()> {
+        require!(universal_nft.is_frozen, UniversalNftError::NftNotFrozen);
+        require!(held_amount == 1, UniversalNftError::InvalidNftOwner);
+        universal_nft.is_frozen = false;
+        self.frozen_count = self.frozen_count.saturating_sub(1);
+        Ok(())
+    }
+}
+
+impl LightClientConfig {
+    pub const INIT_SPACE: usize = 
+        32 + // authority
+        32 + // header_relayer
+        1;   // bump
+}
+
+impl BlockHeaderRoot {
+    pub const INIT_SPACE: usize = 
+        8 +  // chain_id
+        8 +  // block_number
+        32 + // events_root
+        32 + // relayer
+        8 +  // posted_at
+        1;   // bump
+}
+
+impl ProofPolicy {
+    pub const INIT_SPACE: usize = 
+        32 + // collection_mint
+        1 +  // required
+        1;   // bump
+}
+
+impl VerifiedEvent {
+    pub const INIT_SPACE: usize = 
+        32 + // leaf
+        8 +  // chain_id
+        8 +  // block_number
+        1 +  // consumed
+        8 +  // verified_at
+        1;   // bump
+}
+
+impl CollectionFloor {
+    /// Maximum price-feed authorities per collection
+    pub const MAX_PRICE_AUTHORITIES: usize = 4;
+
+    pub const INIT_SPACE: usize = 
+        32 + // collection_mint
+        4 + Self::MAX_PRICE_AUTHORITIES * 32 + // price_authorities
+        32 + // price_feed
+        8 +  // max_staleness
+        8 +  // floor_value
+        8 +  // updated_at
+        1;   // bump
+
+    pub fn is_price_authority(&self, key: &Pubkey) -> bool {
+        self.price_authorities.contains(key)
+    }
+
+    /// Floor value for consumers that must not act on an old price. A
+    /// collection that was never priced is worth zero.
+    pub fn fresh_value(&self, now: i64) -> Result<u64> {
+        if self.updated_at == 0 {
+            return Ok(0);
+        }
+        require!(
+            self.max_staleness == 0 || now - self.updated_at <= self.max_staleness,
+            UniversalNftError::StaleFloorPrice
+        );
+        Ok(self.floor_value)
+    }
+}
+
+impl CollectionConfig {
+    /// Maximum allowed destination chains
+    pub const MAX_ALLOWED_CHAINS: usize = 16;
+
+    pub const INIT_SPACE: usize = 
+        32 + // collection_mint
+        8 +  // max_supply
+        8 +  // minted_count
+        4 + Self::MAX_ALLOWED_CHAINS * 8 + // allowed_destination_chains
+        1 +  // transfer_mode
+        2 +  // seller_fee_basis_points
+        1 +  // is_paused
+        1 +  // phase_count
+        8 +  // phases_end
+        1;   // bump
+
+    /// Count one mint against the supply cap, returning whether the
+    /// collection just sold out
+    pub fn record_mint(&mut self) -> Result<bool> {
+        require!(!self.is_paused, UniversalNftError::CollectionPaused);
+        require!(
+            self.max_supply == 0 || self.minted_count < self.max_supply,
+            UniversalNftError::SoldOut
+        );
+
+        self.minted_count = self.minted_count
+            .checked_add(1)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        Ok(self.max_supply != 0 && self.minted_count == self.max_supply)
+    }
+
+    /// Require the collection to be live and `destination_chain_id` allowed
+    pub fn require_bridge_allowed(&self, destination_chain_id: u64) -> Result<()> {
+        require!(!self.is_paused, UniversalNftError::CollectionPaused);
+        require!(
+            self.allowed_destination_chains.is_empty()
+                || self.allowed_destination_chains.contains(&destination_chain_id),
+            UniversalNftError::DestinationChainNotAllowed
+        );
+        Ok(())
+    }
+}
+
+impl MintPhase {
+    /// Most phases a collection can schedule
+    pub const MAX_PHASES: u8 = 16;
+
+    pub const INIT_SPACE: usize = 
+        32 + // collection_mint
+        1 +  // phase_index
+        1 +  // kind
+        8 +  // start_time
+        8 +  // end_time
+        8 +  // price
+        32 + // funds_recipient
+        1 + 32 + // allowlist_root
+        8 +  // minted_count
+        1;   // bump
+
+    /// Whether the phase is open at `now`
+    pub fn is_active(&self, now: i64) -> bool {
+        self.start_time <= now && now < self.end_time
+    }
+}
+
+impl ClaimEscrow {
+    pub const INIT_SPACE: usize = 
+        32 + // claim_key
+        32 + // mint
+        32 + // depositor
+        32 + // custody_token_account
+        1 + 32 + // claims_service
+        8 +  // expires_at
+        8 +  // created_at
+        1;   // bump
+}
+
+impl HolderSnapshot {
+    pub const INIT_SPACE: usize = 
+        32 + // collection_mint
+        8 +  // slot
+        32 + // commitment
+        8 +  // committed_slot
+        1 + 32 + // holders_root
+        4 +  // holder_count
+        8 +  // revealed_at
+        1;   // bump
+}
+
+impl CollectionBurnStats {
+    pub const INIT_SPACE: usize = 
+        32 + // collection_mint
+        8 +  // total_burned
+        8 +  // unique_burners
+        8 +  // last_burn_at
+        1;   // bump
+
+    /// Count a burn from the collection, by a wallet burning from it for the
+    /// first time when `first_burn`
+    pub fn record(&mut self, collection_mint: Pubkey, first_burn: bool, now: i64, bump: u8) -> Result<()> {
+        if self.total_burned == 0 {
+            self.collection_mint = collection_mint;
+            self.bump = bump;
+        }
+        self.total_burned = self.total_burned
+            .checked_add(1)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        if first_burn {
+            self.unique_burners = self.unique_burners
+                .checked_add(1)
+                .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        }
+        self.last_burn_at = now;
+        Ok(())
+    }
+}
+
+impl UserBurnStats {
+    pub const INIT_SPACE: usize = 
+        32 + // user
+        32 + // collection_mint
+        8 +  // total_burned
+        8 +  // first_burn_at
+        8 +  // last_burn_at
+        1;   // bump
+
+    /// Count a burn by `user`, returning whether it is their first from the
+    /// collection
+    pub fn record(&mut self, user: Pubkey, collection_mint: Pubkey, now: i64, bump: u8) -> Result<bool> {
+        let first_burn = self.total_burned == 0;
+        if first_burn {
+            self.user = user;
+            self.collection_mint = collection_mint;
+            self.first_burn_at = now;
+            self.bump = bump;
+        }
+        self.total_burned = self.total_burned
+            .checked_add(1)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        self.last_burn_at = now;
+        Ok(first_burn)
+    }
+}
+
+impl WalletLock {
+    /// Shortest unlock delay a wallet can choose
+    pub const MIN_UNLOCK_DELAY: i64 = 3_600;
+    /// Longest unlock delay a wallet can choose
+    pub const MAX_UNLOCK_DELAY: i64 = 30 * 86_400;
+
+    pub const INIT_SPACE: usize = 
+        32 + // wallet
+        1 +  // locked
+        8 +  // unlock_delay
+        8 +  // unlock_requested_at
+        8 +  // locked_at
+        1;   // bump
+
+    /// Timestamp the pending unlock takes effect, if one was requested
+    pub fn unlocks_at(&self) -> Option<i64> {
+        (self.unlock_requested_at != 0)
+            .then(|| self.unlock_requested_at.saturating_add(self.unlock_delay))
+    }
+
+    /// Whether outbound transfers are blocked at `now`
+    pub fn is_active(&self, now: i64) -> bool {
+        self.locked && self.unlocks_at().is_none_or(|unlocks_at| now < unlocks_at)
+    }
+
+    /// Engage the lock for `wallet`, cancelling any pending unlock. An active
+    /// lock's delay can't be shortened, so a stolen key can't speed up its
+    /// own unlock.
+    pub fn lock(&mut self, wallet: Pubkey, unlock_delay: i64, now: i64, bump: u8) -> Result<()> {
+        require!(
+            (Self::MIN_UNLOCK_DELAY..=Self::MAX_UNLOCK_DELAY).contains(&unlock_delay),
+            UniversalNftError::InvalidUnlockDelay
+        );
+        if self.is_active(now) {
+            require!(unlock_delay >= self.unlock_delay, UniversalNftError::InvalidUnlockDelay);
+        }
+
+        self.wallet = wallet;
+        self.locked = true;
+        self.unlock_delay = unlock_delay;
+        self.unlock_requested_at = 0;
+        self.locked_at = now;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Start the unlock timer, returning when transfers unlock
+    pub fn request_unlock(&mut self, now: i64) -> Result<i64> {
+        require!(
+            self.is_active(now) && self.unlock_requested_at == 0,
+            UniversalNftError::WalletNotLocked
+        );
+        self.unlock_requested_at = now;
+        Ok(now.saturating_add(self.unlock_delay))
+    }
+}
+
+impl OperatorSession {
+    /// Longest session, about 7 days of slots
+    pub const MAX_SESSION_SLOTS: u64 = 1_512_000;
+    /// Mask covering every defined scope
+    pub const ALL_SCOPES: u8 = 0b111;
+
+    pub const INIT_SPACE: usize = 
+        32 + // owner
+        32 + // operator
+        1 +  // scopes
+        1 + 8 + // bridge_chain_id
+        8 +  // expires_slot
+        8 +  // created_at
+        1;   // bump
+
+    /// Validate the terms of a session `owner` grants `operator` at `slot`:
+    /// known scopes only, another key, and a bounded lifetime
+    pub fn validate_terms(owner: &Pubkey, operator: &Pubkey, scopes: u8, expires_slot: u64, slot: u64) -> Result<()> {
+        require!(
+            scopes != 0 && scopes & !Self::ALL_SCOPES == 0,
+            UniversalNftError::InvalidOperatorSession
+        );
+        require!(operator != owner, UniversalNftError::InvalidOperatorSession);
+        require!(
+            expires_slot > slot && expires_slot - slot <= Self::MAX_SESSION_SLOTS,
+            UniversalNftError::InvalidOperatorSession
+        );
+        Ok(())
+    }
+
+    /// Require the session to grant `scope` at `slot`, and for bridging,
+    /// to allow `destination_chain_id`
+    pub fn authorize(
+        &self,
+        scope: SessionScope,
+        destination_chain_id: Option<u64>,
+        slot: u64,
+    ) -> Result<()> {
+        require!(slot < self.expires_slot, UniversalNftError::OperatorSessionExpired);
+        require!(
+            self.scopes & scope.mask() != 0,
+            UniversalNftError::OperatorScopeNotGranted
+        );
+        if let (Some(allowed), Some(destination)) = (self.bridge_chain_id, destination_chain_id) {
+            require!(allowed == destination, UniversalNftError::OperatorScopeNotGranted);
+        }
+        Ok(())
+    }
+}
+
+impl RentVault {
+    pub const INIT_SPACE: usize = 
+        1 +  // enabled
+        8 +  // total_funded
+        8 +  // total_sponsored
+        1;   // bump
+}
+
+impl ChainRentLedger {
+    pub const INIT_SPACE: usize = 
+        8 +  // chain_id
+        8 +  // funded_lamports
+        8 +  // sponsored_lamports
+        8 +  // sponsored_accounts
+        1;   // bump
+
+    /// Lamports this chain can still draw
+    pub fn remaining(&self) -> u64 {
+        self.funded_lamports.saturating_sub(self.sponsored_lamports)
+    }
+}
+
+impl BatchMintItem {
+    /// Remaining accounts each item after the first is delivered with:
+    /// universal NFT record, mint, metadata PDA and recipient token account
+    pub const DELIVERY_ACCOUNTS: usize = 4;
+}
+
+impl SplitMintEntry {
+    /// Maximum entries in one message. Each may create an associated token
+    /// account, so this keeps on_call within a single transaction's budget.
+    pub const MAX_ENTRIES: usize = 6;
+    /// Remaining accounts each entry is delivered with: universal NFT
+    /// record, mint, metadata PDA, recipient and recipient token account
+    pub const DELIVERY_ACCOUNTS: usize = 5;
+}
+
+impl CrankConfig {
+    pub const INIT_SPACE: usize = 
+        32 + // operator
+        1;   // bump
+}
+
+impl MessageLayerConfig {
+    pub const INIT_SPACE: usize = 
+        1 +  // layer
+        32 + // program_id
+        1 +  // operational
+        8 +  // updated_at
+        1;   // bump
+
+    /// Seed byte identifying the layer in the config PDA
+    pub fn seed(layer: MessageLayer) -> [u8; 1] {
+        [layer as u8]
+    }
+}
+
+impl ChainMetadataRules {
+    pub const INIT_SPACE: usize = 
+        8 +  // chain_id
+        1 +  // max_name_len
+        1 +  // max_symbol_len
+        4 + 64 + // uri_match_prefix
+        4 + 64 + // uri_replace_prefix
+        20 + // royalty_receiver
+        1;   // bump
+    pub const MAX_PREFIX_LEN: usize = 64;
+}
+
+impl PinConfig {
+    pub const INIT_SPACE: usize = 
+        32 + // pinner
+        1;   // bump
+}
+
+impl PinReceipt {
+    pub const INIT_SPACE: usize = 
+        32 + // mint
+        1 +  // storage
+        4 + 96 + // content_id
+        8 +  // size
+        32 + // uri_hash
+        32 + // pinner
+        8 +  // pinned_at
+        1;   // bump
+    pub const MAX_CONTENT_ID_LEN: usize = 96;
+}
+
+impl ModerationConfig {
+    pub const INIT_SPACE: usize = 
+        32 + // authority
+        32 + // moderator
+        8 +  // flag_bond
+        8 +  // total_flags
+        8 +  // total_takedowns
+        1;   // bump
+}
+
+impl ContentFlag {
+    pub const INIT_SPACE: usize = 
+        32 + // mint
+        32 + // flagger
+        32 + // reason_hash
+        8 +  // bond
+        8 +  // flagged_at
+        1;   // bump
+}
+
+impl RoyaltyConfig {
+    /// Maximum creators sharing a collection's royalties
+    pub const MAX_CREATORS: usize = 5;
+
+    pub const INIT_SPACE: usize = 
+        32 + // collection_mint
+        4 + Self::MAX_CREATORS * (32 + 2) + // shares (Vec<RoyaltyShare>)
+        1;   // bump
+}
+
+impl RoyaltyEscrow {
+    /// Maximum ledger entries, leaving room for past creators with unclaimed
+    /// balances after the split changes
+    pub const MAX_LEDGER_ENTRIES: usize = 8;
+
+    pub const INIT_SPACE: usize = 
+        32 + // collection_mint
+        8 +  // total_deposited
+        8 +  // total_claimed
+        8 +  // deposit_count
+        4 + Self::MAX_LEDGER_ENTRIES * (32 + 8 + 8) + // ledger (Vec<CreatorRoyalty>)
+        1;   // bump
+}
+
+impl CreatorRoyalty {
+    /// Lamports the creator can claim now
+    pub fn unclaimed(&self) -> u64 {
+        self.earned.saturating_sub(self.claimed)
+    }
+}
+
+impl ChainSupplyLedger {
+    pub const INIT_SPACE: usize = 
+        8 +  // chain_id
+        8 +  // originals_locked
+        8 +  // wrapped_outstanding
+        8 +  // reported_at
+        8 +  // last_audit_at
+        8 +  // mismatch_count
+        1;   // bump
+
+    /// Whether every locked original is matched by one wrapped representation
+    pub fn in_parity(&self) -> bool {
+        self.originals_locked == self.wrapped_outstanding
+    }
+}
+
+impl ChainFinality {
+    pub const INIT_SPACE: usize = 
+        8 +  // chain_id
+        8 +  // required_confirmations
+        8 +  // attested_head
+        8 +  // head_updated_at
+        1;   // bump
+
+    /// Check a message from `source_block` is deep enough below the attested
+    /// head. Messages without a source block only pass when no depth is set.
+    pub fn require_final(&self, source_block: Option<u64>) -> Result<()> {
+        if self.required_confirmations == 0 {
+            return Ok(());
+        }
+        let source_block = source_block.ok_or(UniversalNftError::SourceBlockRequired)?;
+        let final_at = source_block
+            .checked_add(self.required_confirmations)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        require!(self.attested_head >= final_at, UniversalNftError::SourceBlockNotFinal);
+        Ok(())
+    }
+}
+
+impl RecipientPreferences {
+    /// Maximum trusted senders per source chain
+    pub const MAX_TRUSTED_SENDERS: usize = 8;
+
+    pub const INIT_SPACE: usize = 
+        32 + // owner
+        8 +  // source_chain_id
+        1 + 32 + // preferred_recipient
+        1 +  // auto_wrap
+        1 +  // unsolicited
+        4 + Self::MAX_TRUSTED_SENDERS * 20 + // trusted_senders
+        8 +  // updated_at
+        1;   // bump
+
+    /// Whether the owner asked for mints from `sender`
+    pub fn is_solicited(&self, sender: &[u8; 20]) -> bool {
+        self.trusted_senders.contains(sender)
+    }
+}
+
+impl QuarantinedNft {
+    /// How long a recipient has to claim or reject a quarantined NFT
+    pub const QUARANTINE_PERIOD: i64 = 14 * 24 * 60 * 60;
+    /// Destination gas limit for returning an NFT to its sender
+    pub const RETURN_GAS_LIMIT: u64 = 300_000;
+
+    pub const INIT_SPACE: usize = 
+        32 + // mint
+        32 + // recipient
+        8 +  // source_chain_id
+        20 + // sender
+        8 +  // quarantined_at
+        8 +  // expires_at
+        1;   // bump
+}
+
+impl FeeSchedule {
+    /// Delay between governance queuing new fees and them taking effect
+    pub const TIMELOCK: i64 = 2 * 86_400;
+    /// Highest basis-point fee governance can set
+    pub const MAX_FEE_BPS: u16 = 1_000;
+
+    pub const INIT_SPACE: usize = 
+        FeeRates::INIT_SPACE + // active
+        1 + FeeRates::INIT_SPACE + // pending
+        8 +  // pending_effective_at
+        8 +  // updated_at
+        1;   // bump
+
+    /// Mint fee in lamports
+    pub fn mint_fee(&self) -> u64 {
+        if self.active.enabled { self.active.mint_fee } else { 0 }
+    }
+
+    /// Bridge fee in lamports on a destination gas fee of `gas_fee` lamports
+    pub fn bridge_fee(&self, gas_fee: u64) -> Result<u64> {
+        if !self.active.enabled {
+            return Ok(0);
+        }
+        FeeRates::bps_of(gas_fee, self.active.bridge_fee_bps)
+    }
+
+    /// Metadata update fee in lamports
+    pub fn metadata_update_fee(&self) -> u64 {
+        if self.active.enabled { self.active.metadata_update_fee } else { 0 }
+    }
+
+    /// Marketplace fee on a sale at `price`
+    pub fn marketplace_fee(&self, price: u64) -> Result<u64> {
+        if !self.active.enabled {
+            return Ok(0);
+        }
+        FeeRates::bps_of(price, self.active.marketplace_fee_bps)
+    }
+}
+
+impl FeeRates {
+    pub const INIT_SPACE: usize = 
+        1 +  // enabled
+        8 +  // mint_fee
+        2 +  // bridge_fee_bps
+        8 +  // metadata_update_fee
+        2 +  // marketplace_fee_bps
+        32 + // fee_recipient
+        3 * (8 + 2) + // stake_tiers
+        1 + 32 + // membership_collection
+        2 +  // membership_discount_bps
+        2;   // referral_share_bps
+
+    /// Check the rates are within what governance may set
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.bridge_fee_bps <= FeeSchedule::MAX_FEE_BPS
+                && self.marketplace_fee_bps <= FeeSchedule::MAX_FEE_BPS,
+            UniversalNftError::InvalidFeeSchedule
+        );
+        require!(
+            self.fee_recipient != Pubkey::default(),
+            UniversalNftError::InvalidFeeSchedule
+        );
+        require!(
+            self.membership_discount_bps <= 10_000
+                && self.referral_share_bps <= 10_000
+                && self.stake_tiers.iter().all(|tier| tier.discount_bps <= 10_000),
+            UniversalNftError::InvalidFeeSchedule
+        );
+        Ok(())
+    }
+
+    /// Best stake-tier discount for `staked` governance tokens
+    pub fn stake_discount_bps(&self, staked: u64) -> u16 {
+        self.stake_tiers
+            .iter()
+            .filter(|tier| tier.min_stake > 0 && staked >= tier.min_stake)
+            .map(|tier| tier.discount_bps)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// `fee` less a `discount_bps` discount
+    pub fn discounted(fee: u64, discount_bps: u16) -> Result<u64> {
+        Ok(fee - Self::bps_of(fee, discount_bps.min(10_000))?)
+    }
+
+    /// `bps` basis points of `amount`, rounded down
+    pub fn bps_of(amount: u64, bps: u16) -> Result<u64> {
+        let fee = (amount as u128)
+            .checked_mul(bps as u128)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?
+            / 10_000;
+        Ok(fee as u64)
+    }
+}
+
+impl ReferrerAccrual {
+    pub const INIT_SPACE: usize = 
+        32 + // referrer
+        8 +  // accrued
+        8 +  // claimed
+        8 +  // referral_count
+        1;   // bump
+
+    /// Lamports earned but not yet claimed
+    pub fn unclaimed(&self) -> u64 {
+        self.accrued.saturating_sub(self.claimed)
+    }
+}
+
+impl FeeKind {
+    /// Bit for this kind in `RebateCampaign::eligible_kinds`
+    pub fn mask(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+impl RebateCampaign {
+    /// Mask covering every fee kind
+    pub const ALL_KINDS: u8 = 0b1111;
+
+    pub const INIT_SPACE: usize = 
+        8 +  // budget
+        8 +  // spent
+        8 +  // rebate_per_tx
+        8 +  // starts_at
+        8 +  // ends_at
+        1 +  // eligible_kinds
+        8 +  // funded
+        8 +  // rebate_count
+        1;   // bump
+
+    /// Rebate owed on a `fee` of `kind` charged at `now`, before checking
+    /// the campaign's balance
+    pub fn rebate_for(&self, kind: FeeKind, fee: u64, now: i64) -> u64 {
+        if self.eligible_kinds & kind.mask() == 0 || now < self.starts_at || now >= self.ends_at {
+            return 0;
+        }
+        self.rebate_per_tx
+            .min(fee)
+            .min(self.budget.saturating_sub(self.spent))
+    }
+}
+
+impl TraitAuthorities {
+    /// Most trait authorities a collection can name
+    pub const MAX_AUTHORITIES: usize = 8;
+
+    pub fn is_authority(&self, key: &Pubkey) -> bool {
+        self.authorities.contains(key)
+    }
+}
+
+impl NftAttributes {
+    /// Most entries an NFT can hold
+    pub const MAX_ENTRIES: usize = 16;
+    /// Longest key in bytes
+    pub const MAX_KEY_LEN: usize = 32;
+    /// Longest value in bytes
+    pub const MAX_VALUE_LEN: usize = 64;
+
+    /// Check entries against the account's bounds and for duplicate keys
+    pub fn validate_entries(entries: &[NftAttribute]) -> Result<()> {
+        require!(entries.len() <= Self::MAX_ENTRIES, UniversalNftError::InvalidNftAttribute);
+        for (i, entry) in entries.iter().enumerate() {
+            require!(
+                !entry.key.is_empty()
+                    && entry.key.len() <= Self::MAX_KEY_LEN
+                    && entry.value.len() <= Self::MAX_VALUE_LEN,
+                UniversalNftError::InvalidNftAttribute
+            );
+            require!(
+                !entries[..i].iter().any(|other| other.key == entry.key),
+                UniversalNftError::InvalidNftAttribute
+            );
+        }
+        Ok(())
+    }
+
+    /// Insert an entry or replace the value under its key
+    pub fn set(&mut self, key: String, value: Vec<u8>) -> Result<()> {
+        require!(
+            !key.is_empty() && key.len() <= Self::MAX_KEY_LEN && value.len() <= Self::MAX_VALUE_LEN,
+            UniversalNftError::InvalidNftAttribute
+        );
+        match self.entries.iter_mut().find(|entry| entry.key == key) {
+            Some(entry) => entry.value = value,
+            None => {
+                require!(
+                    self.entries.len() < Self::MAX_ENTRIES,
+                    UniversalNftError::TooManyNftAttributes
+                );
+                self.entries.push(NftAttribute { key, value });
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove the entry under `key`
+    pub fn remove(&mut self, key: &str) -> Result<()> {
+        let index = self.entries
+            .iter()
+            .position(|entry| entry.key == key)
+            .ok_or(UniversalNftError::NftAttributeNotFound)?;
+        self.entries.remove(index);
+        Ok(())
+    }
+
+    /// Whether every entry in `required` is present with the same value
+    pub fn contains_all(&self, required: &[NftAttribute]) -> bool {
+        required.iter().all(|entry| self.entries.contains(entry))
+    }
+}
+
+impl RevealConfig {
+    /// Largest list a collection can commit, one bit each in `assigned`
+    pub const MAX_ITEMS: u32 = 10_000;
+
+    /// Assign the first free entry at or after `target`, wrapping around
+    pub fn assign(&mut self, target: u32) -> Result<u32> {
+        require!(
+            self.revealed_count < self.item_count,
+            UniversalNftError::RevealListExhausted
+        );
+        let mut index = target % self.item_count;
+        while self.assigned[(index / 8) as usize] & (1 << (index % 8)) != 0 {
+            index = (index + 1) % self.item_count;
+        }
+        self.assigned[(index / 8) as usize] |= 1 << (index % 8);
+        self.revealed_count += 1;
+        Ok(index)
+    }
+}
+
+impl NftChildren {
+    pub const MAX_CHILDREN: usize = 4;
+
+    /// Fail if the parent may not leave while it holds children
+    pub fn require_bridgeable(&self) -> Result<()> {
+        require!(
+            self.children.is_empty() || self.bridge_mode == ChildBridgeMode::Bundle,
+            UniversalNftError::ChildrenEquipped
+        );
+        Ok(())
+    }
+
+    pub fn equip(&mut self, child: EquippedChild) -> Result<()> {
+        require!(
+            self.children.len() < Self::MAX_CHILDREN,
+            UniversalNftError::TooManyChildren
+        );
+        self.children.push(child);
+        Ok(())
+    }
+
+    pub fn unequip(&mut self, mint: &Pubkey) -> Result<EquippedChild> {
+        let position = self.children
+            .iter()
+            .position(|child| child.mint == *mint)
+            .ok_or(UniversalNftError::ChildNotEquipped)?;
+        Ok(self.children.remove(position))
+    }
+}
+
+impl EquippedChild {
+    /// The child as an item of a bundle message
+    pub fn to_batch_item(&self) -> BatchMintItem {
+        BatchMintItem {
+            token_id: self.token_id.clone(),
+            name: self.name.clone(),
+            symbol: self.symbol.clone(),
+            uri: self.uri.clone(),
+            collection_mint: self.collection_mint,
+        }
+    }
+}
+
+impl EvolutionRecipe {
+    /// Most resources, required entries or written entries in a recipe
+    pub const MAX_RECIPE_ENTRIES: usize = 4;
+
+    /// Check recipe settings: at least one resource, each mint once with a
+    /// nonzero amount, and something for the evolution to change
+    pub fn validate(params: &EvolutionRecipeParams) -> Result<()> {
+        require!(
+            !params.inputs.is_empty()
+                && params.inputs.len() <= Self::MAX_RECIPE_ENTRIES
+                && params.requires.len() <= Self::MAX_RECIPE_ENTRIES
+                && params.sets.len() <= Self::MAX_RECIPE_ENTRIES,
+            UniversalNftError::InvalidEvolutionRecipe
+        );
+        for (i, input) in params.inputs.iter().enumerate() {
+            require!(
+                input.amount > 0 && !params.inputs[..i].iter().any(|other| other.mint == input.mint),
+                UniversalNftError::InvalidEvolutionRecipe
+            );
+        }
+        require!(
+            !params.sets.is_empty() || !params.uri.is_empty(),
+            UniversalNftError::InvalidEvolutionRecipe
+        );
+        NftAttributes::validate_entries(&params.requires)?;
+        NftAttributes::validate_entries(&params.sets)?;
+        Ok(())
+    }
+}
+
+impl TicketingProfile {
+    pub const MAX_VALIDATORS: usize = 8;
+    /// Destination gas limit for a check-in proof
+    pub const PROOF_GAS_LIMIT: u64 = 200_000;
+
+    pub fn is_validator(&self, key: &Pubkey) -> bool {
+        self.validators.contains(key)
+    }
+}
+
+impl Ticket {
+    /// Mark the ticket used for `event_id`. A ticket checks in once.
+    pub fn check_in(&mut self, event_id: u64, validator: Pubkey, now: i64) -> Result<()> {
+        require!(!self.used, UniversalNftError::TicketAlreadyUsed);
+        require!(self.event_id == event_id, UniversalNftError::TicketEventMismatch);
+        self.used = true;
+        self.checked_in_at = now;
+        self.validator = validator;
+        Ok(())
+    }
+}
+
+impl VestingTerms {
+    /// Check a new position: something to vest, and a cliff inside a
+    /// nonempty schedule
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.total_amount > 0
+                && self.claimed_amount == 0
+                && self.start_ts < self.end_ts
+                && self.start_ts <= self.cliff_ts
+                && self.cliff_ts <= self.end_ts,
+            UniversalNftError::InvalidVestingSchedule
+        );
+        Ok(())
+    }
+
+    /// Amount vested at `now`, claimed or not
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        if now < self.cliff_ts {
+            return 0;
+        }
+        if now >= self.end_ts {
+            return self.total_amount;
+        }
+        let elapsed = (now - self.start_ts) as u128;
+        let duration = (self.end_ts - self.start_ts) as u128;
+        (self.total_amount as u128 * elapsed / duration) as u64
+    }
+
+    /// Amount the holder can claim at `now`
+    pub fn claimable(&self, now: i64) -> u64 {
+        self.vested_amount(now).saturating_sub(self.claimed_amount)
+    }
+
+    pub fn is_fully_claimed(&self) -> bool {
+        self.claimed_amount >= self.total_amount
+    }
+}
+
+impl AttendanceDrop {
+    /// Largest allowlist a drop can hold, one bit each in `claimed`
+    pub const MAX_CLAIMS: u32 = 64_000;
+    /// Placeholder replaced by the claim number in templates
+    pub const CLAIM_NUMBER: &'static str = "{n}";
+
+    /// Fill a template for claim number `n`
+    pub fn render(template: &str, n: u32) -> String {
+        template.replace(Self::CLAIM_NUMBER, &n.to_string())
+    }
+
+    /// Mark allowlist entry `index` claimed and return its claim number
+    pub fn claim(&mut self, index: u32) -> Result<u32> {
+        require!(index < self.max_claims, UniversalNftError::NotOnAllowlist);
+        let (byte, bit) = ((index / 8) as usize, 1u8 << (index % 8));
+        require!(self.claimed[byte] & bit == 0, UniversalNftError::AttendanceAlreadyClaimed);
+        self.claimed[byte] |= bit;
+        self.claimed_count += 1;
+        Ok(self.claimed_count)
+    }
+
+    pub fn is_open(&self, now: i64) -> bool {
+        now >= self.start_time && now < self.end_time
+    }
+}
+
+impl CredentialProfile {
+    pub const MAX_ISSUERS: usize = 8;
+    /// Destination gas limit for a revocation message
+    pub const REVOCATION_GAS_LIMIT: u64 = 200_000;
+
+    pub fn is_issuer(&self, key: &Pubkey) -> bool {
+        self.issuers.contains(key)
+    }
+}
+
+impl Credential {
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at != 0 && now >= self.expires_at
+    }
+}
+
+impl Swap {
+    /// Check a new offer: two different mints, each moving something
+    pub fn validate(maker_asset: &SwapAsset, taker_asset: &SwapAsset) -> Result<()> {
+        require!(
+            maker_asset.amount > 0 && taker_asset.amount > 0 && maker_asset.mint != taker_asset.mint,
+            UniversalNftError::InvalidSwapTerms
+        );
+        Ok(())
+    }
+
+    pub fn can_accept(&self, taker: &Pubkey) -> bool {
+        self.taker == Pubkey::default() || self.taker == *taker
+    }
+
+    /// Whether `key` may cancel: the maker, or the named taker declining
+    pub fn is_party(&self, key: &Pubkey) -> bool {
+        *key == self.maker || (self.taker != Pubkey::default() && *key == self.taker)
+    }
+}
+
+impl CrossChainSwap {
+    /// Shortest lock, leaving the remote leg time to lock and expire first
+    pub const MIN_TIMELOCK_SECONDS: i64 = 2 * 60 * 60;
+    /// Longest lock
+    pub const MAX_TIMELOCK_SECONDS: i64 = 7 * 24 * 60 * 60;
+    /// Destination gas limit for the lock request
+    pub const LOCK_GAS_LIMIT: u64 = 300_000;
+
+    pub fn validate_timelock(expires_at: i64, now: i64) -> Result<()> {
+        require!(
+            expires_at >= now + Self::MIN_TIMELOCK_SECONDS && expires_at <= now + Self::MAX_TIMELOCK_SECONDS,
+            UniversalNftError::InvalidSwapTerms
+        );
+        Ok(())
+    }
+
+    /// Record the secret revealed on the counterparty chain, already checked
+    /// against the hashlock
+    pub fn reveal(&mut self, secret: [u8; 32]) -> Result<()> {
+        require!(self.status == CrossChainSwapStatus::Locked, UniversalNftError::InvalidSwapState);
+        self.secret = secret;
+        self.status = CrossChainSwapStatus::Redeemable;
+        Ok(())
+    }
+
+    /// Check the NFT can go to the taker at `now`. A secret the counterparty
+    /// chain relayed stays good after expiry.
+    pub fn require_redeemable(&self, now: i64) -> Result<()> {
+        require!(
+            self.status == CrossChainSwapStatus::Redeemable
+                || (self.status == CrossChainSwapStatus::Locked && now < self.expires_at),
+            UniversalNftError::InvalidSwapState
+        );
+        Ok(())
+    }
+
+    /// Check the maker can be refunded at `now`
+    pub fn require_refundable(&self, now: i64) -> Result<()> {
+        require!(
+            self.status == CrossChainSwapStatus::Reverted
+                || (self.status == CrossChainSwapStatus::Locked && now >= self.expires_at),
+            UniversalNftError::InvalidSwapState
+        );
+        Ok(())
+    }
+}
+
+impl Loan {
+    /// Shortest loan
+    pub const MIN_DURATION_SECONDS: i64 = 24 * 60 * 60;
+    /// Longest loan
+    pub const MAX_DURATION_SECONDS: i64 = 365 * 24 * 60 * 60;
+    /// Largest SOL principal against a priced collateral, in basis points of
+    /// its floor value
+    pub const MAX_LTV_BPS: u64 = 7_000;
+
+    /// Whether a lender has funded the loan
+    pub fn is_active(&self) -> bool {
+        self.lender != Pubkey::default()
+    }
+
+    /// Whether the lender can take the collateral at `now`
+    pub fn is_defaulted(&self, now: i64) -> bool {
+        self.is_active() && now >= self.due_at
+    }
+}
+
+impl LoanTerms {
+    /// Check a loan request: something lent, repaid in full, for a bounded term
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.principal > 0
+                && self.repayment >= self.principal
+                && self.duration >= Loan::MIN_DURATION_SECONDS
+                && self.duration <= Loan::MAX_DURATION_SECONDS,
+            UniversalNftError::InvalidLoanTerms
+        );
+        Ok(())
+    }
+
+    /// Bound the principal of a SOL loan by the collateral's floor value.
+    /// The floor is quoted in lamports, so loans in other tokens, and loans
+    /// against unpriced collateral, are left to the lender to size.
+    pub fn require_within_ltv(&self, collateral_value: u64) -> Result<()> {
+        if self.principal_mint != spl_token::native_mint::ID || collateral_value == 0 {
+            return Ok(());
+        }
+        let max_principal = collateral_value as u128 * Loan::MAX_LTV_BPS as u128 / 10_000;
+        require!(
+            self.principal as u128 <= max_principal,
+            UniversalNftError::LoanToValueExceeded
+        );
+        Ok(())
+    }
+}
+
+impl LeaderboardKind {
+    /// Seed byte of the kind's leaderboard PDA
+    pub fn seed(&self) -> u8 {
+        match self {
+            LeaderboardKind::Minters => 0,
+            LeaderboardKind::Bridgers => 1,
+            LeaderboardKind::BridgedCollections => 2,
+        }
+    }
+}
+
+impl Leaderboard {
+    /// Entries each leaderboard keeps
+    pub const MAX_ENTRIES: usize = 10;
+
+    /// Set `key`'s score. On a full board, a new key must beat the lowest
+    /// entry, which it replaces.
+    pub fn record(&mut self, key: Pubkey, score: u64, now: i64) {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.key == key) {
+            entry.score = score;
+        } else if self.entries.len() < Self::MAX_ENTRIES {
+            self.entries.push(LeaderboardEntry { key, score });
+        } else {
+            match self.entries.last_mut() {
+                Some(last) if score > last.score => *last = LeaderboardEntry { key, score },
+                _ => return,
+            }
+        }
+        self.settle(now);
+    }
+
+    /// Add one to `key`'s score. On a full board, a new key replaces the
+    /// lowest entry and takes over its score plus one, so the board keeps
+    /// the heaviest keys in bounded space at the cost of overcounting
+    /// newcomers.
+    pub fn increment(&mut self, key: Pubkey, now: i64) {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.key == key) {
+            entry.score = entry.score.saturating_add(1);
+        } else if self.entries.len() < Self::MAX_ENTRIES {
+            self.entries.push(LeaderboardEntry { key, score: 1 });
+        } else if let Some(last) = self.entries.last_mut() {
+            *last = LeaderboardEntry { key, score: last.score.saturating_add(1) };
+        }
+        self.settle(now);
+    }
+
+    /// Keep entries highest first; ties keep their earlier rank
+    fn settle(&mut self, now: i64) {
+        self.entries.sort_by_key(|e| std::cmp::Reverse(e.score));
+        self.updated_at = now;
+    }
+}
+
+impl CorridorStats {
+    /// Share of the gap to each new sample the averages move (basis points)
+    pub const SAMPLE_WEIGHT_BPS: u64 = 1_000;
+    /// Samples needed before the averages are trusted
+    pub const MIN_SAMPLES: u64 = 20;
+    /// Failure rate at which the corridor counts as degraded
+    pub const DEGRADED_FAILURE_BPS: u16 = 1_000;
+    /// Failure rate a degraded corridor must fall back under to recover
+    pub const RECOVERED_FAILURE_BPS: u16 = 500;
+    /// Average latency at which the corridor counts as degraded (seconds)
+    pub const DEGRADED_LATENCY_S: u32 = 30 * 60;
+    /// Chain-pair risk of a corridor without enough samples
+    pub const UNKNOWN_RISK: u16 = 100;
+
+    /// Fold in one transfer's outcome. The first sample sets the averages.
+    pub fn record(&mut self, latency_s: u32, succeeded: bool, now: i64) {
+        let failure_bps = if succeeded { 0 } else { 10_000 };
+        if self.samples == 0 {
+            self.latency_ema_s = latency_s;
+            self.failure_rate_bps = failure_bps;
+        } else {
+            self.latency_ema_s = Self::decay(self.latency_ema_s as u64, latency_s as u64) as u32;
+            self.failure_rate_bps = Self::decay(self.failure_rate_bps as u64, failure_bps as u64) as u16;
+        }
+        self.samples = self.samples.saturating_add(1);
+        self.last_sample = now;
+    }
+
+    /// Whether enough samples were recorded to judge the corridor
+    pub fn is_established(&self) -> bool {
+        self.samples >= Self::MIN_SAMPLES
+    }
+
+    /// Whether the corridor fails or lags enough to be degraded
+    pub fn is_degraded(&self) -> bool {
+        self.is_established()
+            && (self.failure_rate_bps >= Self::DEGRADED_FAILURE_BPS
+                || self.latency_ema_s >= Self::DEGRADED_LATENCY_S)
+    }
+
+    /// Whether a degraded corridor is healthy enough to be restored. The gap
+    /// to the degradation threshold keeps it from flapping.
+    pub fn is_recovered(&self) -> bool {
+        self.failure_rate_bps < Self::RECOVERED_FAILURE_BPS
+            && self.latency_ema_s < Self::DEGRADED_LATENCY_S
+    }
+
+    /// Chain-pair risk (0-500): a tenth of the failure rate in basis points,
+    /// plus 100 while latency is degraded
+    pub fn risk(&self) -> u16 {
+        if !self.is_established() {
+            return Self::UNKNOWN_RISK;
+        }
+        let latency_risk = if self.latency_ema_s >= Self::DEGRADED_LATENCY_S { 100 } else { 0 };
+        (self.failure_rate_bps / 10 + latency_risk).min(500)
+    }
+
+    fn decay(average: u64, sample: u64) -> u64 {
+        (average * (10_000 - Self::SAMPLE_WEIGHT_BPS) + sample * Self::SAMPLE_WEIGHT_BPS) / 10_000
+    }
+}
+
+impl ChainRiskRegistry {
+    /// Most chains and most pair overrides the registry holds
+    pub const MAX_ENTRIES: usize = 16;
+    /// Highest risk a chain or pair can be given
+    pub const MAX_RISK: u16 = 500;
+
+    /// Require the lists to fit, stay within `MAX_RISK` and name each chain
+    /// and pair once
+    pub fn validate(params: &ChainRiskParams) -> Result<()> {
+        require!(
+            params.chain_risks.len() <= Self::MAX_ENTRIES
+                && params.pair_overrides.len() <= Self::MAX_ENTRIES,
+            UniversalNftError::InvalidChainRisk
+        );
+        for (i, entry) in params.chain_risks.iter().enumerate() {
+            require!(
+                entry.risk <= Self::MAX_RISK
+                    && !params.chain_risks[..i].iter().any(|e| e.chain_id == entry.chain_id),
+                UniversalNftError::InvalidChainRisk
+            );
+        }
+        for (i, entry) in params.pair_overrides.iter().enumerate() {
+            require!(
+                entry.risk <= Self::MAX_RISK
+                    && !params.pair_overrides[..i].iter().any(|e| {
+                        e.source_chain_id == entry.source_chain_id
+                            && e.destination_chain_id == entry.destination_chain_id
+                    }),
+                UniversalNftError::InvalidChainRisk
+            );
+        }
+        Ok(())
+    }
+
+    /// Risk of a chain; unlisted chains carry none
+    pub fn chain_risk(&self, chain_id: u64) -> u16 {
+        self.chain_risks
+            .iter()
+            .find(|e| e.chain_id == chain_id)
+            .map_or(0, |e| e.risk)
+    }
+
+    /// Risk of a transfer from `source` to `destination`: the pair's
+    /// override if it has one, else both chains' risks plus
+    /// `corridor_risk`, capped at `MAX_RISK`
+    pub fn pair_risk(&self, source: u64, destination: u64, corridor_risk: u16) -> u16 {
+        if let Some(entry) = self.pair_overrides.iter().find(|e| {
+            e.source_chain_id == source && e.destination_chain_id == destination
+        }) {
+            return entry.risk;
+        }
+        self.chain_risk(source)
+            .saturating_add(self.chain_risk(destination))
+            .saturating_add(corridor_risk)
+            .min(Self::MAX_RISK)
+    }
+}
+
+impl BehaviorBaseline {
+    /// Transfers needed before deviations are scored
+    pub const MIN_SAMPLES: u32 = 5;
+    /// Weight a transfer adds to its hour and chain
+    pub const SAMPLE_WEIGHT: u8 = 16;
+    /// Share of hour weight, over the hour and its neighbours, below which
+    /// an hour is unusual (basis points)
+    pub const USUAL_HOUR_BPS: u32 = 1_000;
+    /// Multiple of the median above which a value is unusual
+    pub const UNUSUAL_VALUE_MULTIPLE: u64 = 4;
+    /// Multiple of the median above which a value is extreme
+    pub const EXTREME_VALUE_MULTIPLE: u64 = 10;
+    /// Deviation score of users without enough history
+    pub const UNKNOWN_RISK: u16 = 50;
+
+    /// Learn from a transfer of `value` to `chain_id` at `now`
+    pub fn record(&mut self, value: u64, chain_id: u64, now: i64) {
+        if self.samples == 0 {
+            self.median_value = value;
+        } else {
+            // Frugal median: step an eighth of the estimate towards the sample
+            let step = (self.median_value / 8).max(1);
+            if value > self.median_value {
+                self.median_value = self.median_value.saturating_add(step).min(value);
+            } else if value < self.median_value {
+                self.median_value = self.median_value.saturating_sub(step).max(value);
+            }
+        }
+
+        for weight in self.hour_weights.iter_mut().chain(self.chain_weights.iter_mut()) {
+            *weight = (*weight as u16 * 15 / 16) as u8;
+        }
+        let hour = Self::hour(now);
+        self.hour_weights[hour] = self.hour_weights[hour].saturating_add(Self::SAMPLE_WEIGHT);
+
+        // Known chain, else the empty or lightest slot
+        let known = self.chain_ids
+            .iter()
+            .zip(self.chain_weights.iter())
+            .position(|(id, weight)| *id == chain_id && *weight > 0);
+        let slot = match known {
+            Some(slot) => slot,
+            None => {
+                let slot = (0..self.chain_weights.len())
+                    .min_by_key(|&i| self.chain_weights[i])
+                    .unwrap_or(0);
+                self.chain_ids[slot] = chain_id;
+                self.chain_weights[slot] = 0;
+                slot
+            }
+        };
+        self.chain_weights[slot] = self.chain_weights[slot].saturating_add(Self::SAMPLE_WEIGHT);
+
+        self.samples = self.samples.saturating_add(1);
+        self.last_sample = now;
+    }
+
+    /// Whether the user has transferred enough to have a baseline
+    pub fn is_established(&self) -> bool {
+        self.samples >= Self::MIN_SAMPLES
+    }
+
+    /// Whether the user usually transfers around `now`'s UTC hour. The
+    /// neighbouring hours count, so a user's day can drift a little.
+    pub fn is_usual_hour(&self, now: i64) -> bool {
+        let total: u32 = self.hour_weights.iter().map(|w| *w as u32).sum();
+        if total == 0 {
+            return false;
+        }
+        let hour = Self::hour(now);
+        let around: u32 = [23, 0, 1]
+            .iter()
+            .map(|offset| self.hour_weights[(hour + offset) % 24] as u32)
+            .sum();
+        around * 10_000 / total >= Self::USUAL_HOUR_BPS
+    }
+
+    /// Whether the user has transferred to `chain_id` recently
+    pub fn is_usual_chain(&self, chain_id: u64) -> bool {
+        self.chain_ids
+            .iter()
+            .zip(self.chain_weights.iter())
+            .any(|(id, weight)| *id == chain_id && *weight > 0)
+    }
+
+    /// How far a transfer strays from the baseline (0-300): value well above
+    /// the median, an hour the user doesn't transfer in and a chain they
+    /// don't use each add to it
+    pub fn deviation(&self, value: u64, chain_id: u64, now: i64) -> u16 {
+        if !self.is_established() {
+            return Self::UNKNOWN_RISK;
+        }
+        let median = self.median_value.max(1);
+        let value_risk = if value / median >= Self::EXTREME_VALUE_MULTIPLE {
+            150
+        } else if value / median >= Self::UNUSUAL_VALUE_MULTIPLE {
+            75
+        } else {
+            0
+        };
+        let hour_risk = if self.is_usual_hour(now) { 0 } else { 75 };
+        let chain_risk = if self.is_usual_chain(chain_id) { 0 } else { 75 };
+        value_risk + hour_risk + chain_risk
+    }
+
+    fn hour(now: i64) -> usize {
+        (now.rem_euclid(86_400) / 3_600) as usize
+    }
+}
+
+impl SecurityExport {
+    /// Destination gas limit for a security event message
+    pub const GAS_LIMIT: u64 = 200_000;
+    /// ZetaChain mainnet and testnet, where the monitoring contract may live
+    pub const MONITOR_CHAINS: [u64; 2] = [7000, 7001];
+
+    pub fn can_report(&self, signer: &Pubkey, authority: &Pubkey) -> bool {
+        signer == &self.reporter || signer == authority
+    }
+}
+
+impl CorridorPause {
+    /// Destination gas limit for a pause request message
+    pub const REQUEST_GAS_LIMIT: u64 = 200_000;
+
+    pub fn set(&mut self, direction: CorridorDirection, paused: bool, now: i64) {
+        if direction != CorridorDirection::Outbound {
+            self.inbound_paused = paused;
+        }
+        if direction != CorridorDirection::Inbound {
+            self.outbound_paused = paused;
+        }
+        self.updated_at = now;
+    }
+
+    pub fn is_paused(&self, direction: CorridorDirection) -> bool {
+        match direction {
+            CorridorDirection::Inbound => self.inbound_paused,
+            CorridorDirection::Outbound => self.outbound_paused,
+            CorridorDirection::Both => self.inbound_paused || self.outbound_paused,
+        }
+    }
+}
+
+impl DeploymentRegistry {
+    /// Most satellites the registry lists
+    pub const MAX_SATELLITES: usize = 8;
+
+    /// Require the list to fit and name each kind once, with a real program
+    pub fn validate(satellites: &[SatelliteProgram]) -> Result<()> {
+        require!(
+            satellites.len() <= Self::MAX_SATELLITES,
+            UniversalNftError::InvalidDeploymentRegistry
+        );
+        for (i, satellite) in satellites.iter().enumerate() {
+            require!(
+                satellite.program_id != Pubkey::default()
+                    && !satellites[..i].iter().any(|s| s.kind == satellite.kind),
+                UniversalNftError::InvalidDeploymentRegistry
+            );
+        }
+        Ok(())
+    }
+
+    /// Approved program of a satellite kind, if it's deployed
+    pub fn program_id(&self, kind: SatelliteKind) -> Option<Pubkey> {
+        self.satellites.iter().find(|s| s.kind == kind).map(|s| s.program_id)
+    }
+
+    /// Require `program_id` to be the approved deployment of `kind`
+    pub fn require_program(&self, kind: SatelliteKind, program_id: &Pubkey) -> Result<()> {
+        require!(
+            self.program_id(kind) == Some(*program_id),
+            UniversalNftError::UnapprovedSatelliteProgram
+        );
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -1,12 +1,25 @@
 use anchor_lang::prelude::*;
 use solana_program::{
     keccak,
-    program_error::ProgramError,
-    secp256k1_recover::{secp256k1_recover, Secp256k1Pubkey},
+    secp256k1_recover::secp256k1_recover,
 };
-use libsecp256k1::{PublicKey, SecretKey, Message, sign, verify};
 use sha2::{Sha256, Digest};
 use crate::errors::UniversalNftError;
+use crate::security::{
+    self, CircuitBreaker, FraudDetectionEngine, FraudRecommendation, IncidentMode, OperationAnalysisInput,
+    QuorumAttestation, TokenBucket, ValidatorSet,
+};
+use crate::state::{
+    AddressFormat, BatchMintItem, BridgeCostEstimate, ChainMetadataRules, ChainRentLedger, ChainRiskRegistry,
+    ChainTransferLimits, ClaimEscrow, CollectionConfig, CollectionFloor, CollectionHooks, CollectionTransferMode,
+    CorridorDirection, CorridorPause, CreatorRoyalty, CrossChainMessage, CrossChainTransfer, HookEvent,
+    DeploymentRegistry, FeeKind, FeeRates, FeeSchedule, GasPayment, InboundQueue, LeaderboardKind, MintPhase,
+    MintPhaseKind, NftAttribute, NftAttributes, NftChildren, OperatorSession, PointsActivity, RebateCampaign,
+    RecipientPreferences, ReferrerAccrual, RegisteredChain, RelayedMessage, RentVault, RollingVolume, RoyaltyConfig, RoyaltyEscrow, RoyaltyShare,
+    SecurityRule, SessionScope, TransferStatus, UniversalNft, UnsolicitedPolicy, VestingPosition, VestingTerms, WalletLock,
+};
+
+pub mod validate;
 
 /// Utilities for signature verification and cross-chain operations
 pub struct SignatureUtils;
@@ -48,8 +61,8 @@ impl SignatureUtils {
         hasher.update(mint.to_bytes());
         hasher.update(block_number.to_le_bytes());
         hasher.update(timestamp.to_le_bytes());
-        let result = hasher.finalize();
-        bs58::encode(result).into_string()
+        // Base58 of the 32-byte digest, the same encoding Pubkey displays with
+        Pubkey::new_from_array(hasher.finalize().into()).to_string()
     }
 
     /// Validate cross-chain message format
@@ -84,22 +97,59 @@ impl SignatureUtils {
 pub struct CrossChainUtils;
 
 impl CrossChainUtils {
-    /// Validate chain ID
-    pub fn validate_chain_id(chain_id: u64) -> Result<bool> {
-        // Define supported chain IDs
-        const SUPPORTED_CHAINS: &[u64] = &[
-            7000, // ZetaChain Mainnet
-            7001, // ZetaChain Testnet
-            1,    // Ethereum Mainnet
-            5,    // Ethereum Goerli
-            56,   // BSC Mainnet
-            97,   // BSC Testnet
-        ];
+    /// Validate a chain ID against its registry entry: the chain must be
+    /// registered and enabled
+    pub fn validate_chain_id(chain_id: u64, chain: &RegisteredChain) -> Result<bool> {
+        require!(chain.chain_id == chain_id, UniversalNftError::ChainNotRegistered);
+        require!(chain.enabled, UniversalNftError::ChainDisabled);
+        Ok(true)
+    }
 
-        if SUPPORTED_CHAINS.contains(&chain_id) {
-            Ok(true)
-        } else {
-            Err(UniversalNftError::InvalidChainId.into())
+    /// Validate chain IDs against registry entries passed as accounts, one
+    /// `["chain", chain_id]` PDA per chain in the same order
+    pub fn validate_registered_chain_ids(chain_ids: &[u64], chains: &[AccountInfo]) -> Result<()> {
+        require!(chains.len() >= chain_ids.len(), UniversalNftError::ChainNotRegistered);
+        for (chain_id, chain_info) in chain_ids.iter().zip(chains) {
+            let (expected, _) = Pubkey::find_program_address(
+                &[b"chain", chain_id.to_le_bytes().as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(chain_info.key(), expected, UniversalNftError::ChainNotRegistered);
+            require_keys_eq!(*chain_info.owner, crate::ID, UniversalNftError::ChainNotRegistered);
+            let data = chain_info.try_borrow_data()?;
+            let chain = RegisteredChain::try_deserialize(&mut &data[..])?;
+            Self::validate_chain_id(*chain_id, &chain)?;
+        }
+        Ok(())
+    }
+
+    /// A transfer payload must fit its transfer record
+    pub fn validate_payload(payload: &[u8]) -> Result<()> {
+        require!(
+            payload.len() <= CrossChainTransfer::MAX_PAYLOAD_LEN,
+            UniversalNftError::PayloadTooLarge
+        );
+        Ok(())
+    }
+
+    /// Carry a non-empty payload on a plain mint message, so the destination
+    /// hands it to its receive hook
+    pub fn attach_payload(message: CrossChainMessage, payload: Vec<u8>) -> CrossChainMessage {
+        match message {
+            CrossChainMessage::MintNft { token_id, name, symbol, uri, recipient, collection_mint }
+                if !payload.is_empty() =>
+            {
+                CrossChainMessage::MintNftWithPayload {
+                    token_id,
+                    name,
+                    symbol,
+                    uri,
+                    recipient,
+                    collection_mint,
+                    payload,
+                }
+            }
+            message => message,
         }
     }
 
@@ -114,12 +164,143 @@ impl CrossChainUtils {
         }
     }
 
+    /// Validate a destination address against its chain's registered format
+    pub fn validate_address(format: &AddressFormat, address: &[u8]) -> Result<bool> {
+        match format {
+            AddressFormat::Evm => {
+                require!(address.len() == 20, UniversalNftError::InvalidAddressFormat);
+                Ok(true)
+            }
+            AddressFormat::Bitcoin => Self::validate_bitcoin_address(address)
+                .map_err(|_| UniversalNftError::InvalidAddressFormat.into()),
+            AddressFormat::Ton => Self::validate_ton_address(address),
+            AddressFormat::Sui => {
+                require!(
+                    address.len() == 32 && address.iter().any(|b| *b != 0),
+                    UniversalNftError::InvalidAddressFormat
+                );
+                Ok(true)
+            }
+        }
+    }
+
+    /// Encode a validated destination address into the 32-byte recipient of a
+    /// cross-chain message. EVM addresses are left-padded like an ABI word and
+    /// Sui addresses fit as they are. TON and Bitcoin addresses are longer, so
+    /// the message carries their SHA-256; the full address travels as the
+    /// gateway call's recipient bytes.
+    pub fn encode_recipient(format: &AddressFormat, address: &[u8]) -> Result<Pubkey> {
+        let mut word = [0u8; 32];
+        match format {
+            AddressFormat::Evm => {
+                require!(address.len() == 20, UniversalNftError::InvalidAddressFormat);
+                word[12..].copy_from_slice(address);
+            }
+            AddressFormat::Sui => {
+                require!(address.len() == 32, UniversalNftError::InvalidAddressFormat);
+                word.copy_from_slice(address);
+            }
+            AddressFormat::Ton | AddressFormat::Bitcoin => word = Sha256::digest(address).into(),
+        }
+        Ok(Pubkey::new_from_array(word))
+    }
+
+    /// Validate an EVM destination. Raw bytes carry no EIP-55 checksum, so
+    /// this rejects the addresses that are detectably wrong instead.
+    pub fn validate_evm_destination(address: &[u8], counterpart: &[u8; 20]) -> Result<bool> {
+        /// Well-known burn sinks
+        const BURN_ADDRESSES: &[[u8; 20]] = &[
+            // 0x000000000000000000000000000000000000dEaD
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xde, 0xad],
+            // 0xdEaD000000000000000000000000000000000000
+            [0xde, 0xad, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            // 0xFFfFfFffFFfffFFfFFfFFFFFffFFFffffFfFFFfF
+            [0xff; 20],
+        ];
+
+        require!(address.len() == 20, UniversalNftError::InvalidEvmAddressLength);
+        require!(
+            address.iter().any(|b| *b != 0),
+            UniversalNftError::ZeroDestinationAddress
+        );
+
+        // Precompiles 0x01..=0x0a cannot hold tokens
+        let is_precompile = address[..19].iter().all(|b| *b == 0) && address[19] <= 0x0a;
+        let is_burn = BURN_ADDRESSES.iter().any(|burn| burn.as_slice() == address);
+        require!(!is_precompile && !is_burn, UniversalNftError::BurnDestinationAddress);
+
+        require!(
+            *counterpart == [0u8; 20] || counterpart.as_slice() != address,
+            UniversalNftError::CounterpartDestinationAddress
+        );
+        Ok(true)
+    }
+
+    /// Validate a decoded user-friendly TON address:
+    /// flags (1) | workchain (1) | account hash (32) | crc16 (2)
+    pub fn validate_ton_address(address: &[u8]) -> Result<bool> {
+        require!(address.len() == 36, UniversalNftError::InvalidAddressFormat);
+
+        // Bounceable 0x11 / non-bounceable 0x51, optionally with the testnet bit
+        let flags = address[0] & 0x7f;
+        require!(flags == 0x11 || flags == 0x51, UniversalNftError::InvalidAddressFormat);
+
+        // Basechain (0) or masterchain (-1)
+        require!(
+            address[1] == 0x00 || address[1] == 0xff,
+            UniversalNftError::InvalidAddressFormat
+        );
+
+        let checksum = u16::from_be_bytes([address[34], address[35]]);
+        require!(
+            Self::crc16_xmodem(&address[..34]) == checksum,
+            UniversalNftError::InvalidAddressFormat
+        );
+        Ok(true)
+    }
+
+    /// CRC-16/XMODEM as used by TON address checksums
+    fn crc16_xmodem(data: &[u8]) -> u16 {
+        let mut crc: u16 = 0;
+        for byte in data {
+            crc ^= (*byte as u16) << 8;
+            for _ in 0..8 {
+                crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x1021 } else { crc << 1 };
+            }
+        }
+        crc
+    }
+
+    /// Validate a Bitcoin address carried as ASCII bytes (base58 or bech32)
+    pub fn validate_bitcoin_address(address: &[u8]) -> Result<bool> {
+        let address = core::str::from_utf8(address)
+            .map_err(|_| UniversalNftError::InvalidBitcoinAddress)?;
+
+        let is_bech32 = ["bc1", "tb1", "bcrt1"].iter().any(|hrp| address.starts_with(hrp));
+        let valid = if is_bech32 {
+            // Segwit v0 and taproot addresses, lowercase bech32 charset
+            (42..=64).contains(&address.len())
+                && address.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        } else {
+            // Legacy P2PKH / P2SH, base58 excludes 0, O, I and l
+            (26..=35).contains(&address.len())
+                && matches!(address.as_bytes()[0], b'1' | b'3' | b'm' | b'n' | b'2')
+                && address.chars().all(|c| c.is_ascii_alphanumeric() && !"0OIl".contains(c))
+        };
+
+        if valid {
+            Ok(true)
+        } else {
+            Err(UniversalNftError::InvalidBitcoinAddress.into())
+        }
+    }
+
     /// Validate gas limit for cross-chain operations
     pub fn validate_gas_limit(gas_limit: u64) -> Result<bool> {
         const MIN_GAS_LIMIT: u64 = 21000;
         const MAX_GAS_LIMIT: u64 = 10_000_000;
 
-        if gas_limit >= MIN_GAS_LIMIT && gas_limit <= MAX_GAS_LIMIT {
+        if (MIN_GAS_LIMIT..=MAX_GAS_LIMIT).contains(&gas_limit) {
             Ok(true)
         } else {
             Err(UniversalNftError::InsufficientGasLimit.into())
@@ -216,40 +397,5110 @@ impl RentUtils {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Sha256 Merkle tree utilities for state commitments
+pub struct MerkleUtils;
 
-    #[test]
-    fn test_generate_token_id() {
-        let mint = Pubkey::new_unique();
-        let block_number = 12345;
-        let timestamp = 1640995200; // Example timestamp
-        
-        let token_id = SignatureUtils::generate_token_id(&mint, block_number, timestamp);
-        assert!(!token_id.is_empty());
-        assert!(token_id.len() > 10);
+impl MerkleUtils {
+    /// Domain prefix for leaf hashes
+    const LEAF_PREFIX: u8 = 0x00;
+    /// Domain prefix for interior node hashes
+    const NODE_PREFIX: u8 = 0x01;
+
+    /// Hash an account into a Merkle leaf over its key, owner and data
+    pub fn hash_account_leaf(key: &Pubkey, owner: &Pubkey, data: &[u8]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update([Self::LEAF_PREFIX]);
+        hasher.update(key.to_bytes());
+        hasher.update(owner.to_bytes());
+        hasher.update(data);
+        hasher.finalize().into()
     }
 
-    #[test]
-    fn test_validate_name() {
-        assert!(MetadataUtils::validate_name("My NFT").is_ok());
-        assert!(MetadataUtils::validate_name("").is_err());
-        assert!(MetadataUtils::validate_name(&"a".repeat(50)).is_err());
+    /// Hash two child nodes into their parent
+    pub fn hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update([Self::NODE_PREFIX]);
+        hasher.update(left);
+        hasher.update(right);
+        hasher.finalize().into()
     }
 
-    #[test]
-    fn test_validate_symbol() {
-        assert!(MetadataUtils::validate_symbol("NFT").is_ok());
-        assert!(MetadataUtils::validate_symbol("").is_err());
-        assert!(MetadataUtils::validate_symbol("NFT!@#").is_err());
+    /// Compute the Merkle root of a list of leaves.
+    /// An odd node at the end of a level is promoted unchanged.
+    pub fn compute_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+        if leaves.is_empty() {
+            return [0u8; 32];
+        }
+
+        let mut level = leaves.to_vec();
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => Self::hash_node(left, right),
+                    [single] => *single,
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+        level[0]
     }
 
-    #[test]
-    fn test_validate_uri() {
-        assert!(MetadataUtils::validate_uri("https://example.com/metadata.json").is_ok());
-        assert!(MetadataUtils::validate_uri("ipfs://QmHash").is_ok());
-        assert!(MetadataUtils::validate_uri("invalid://uri").is_err());
-        assert!(MetadataUtils::validate_uri("").is_err());
+    /// Check a proof whose sibling pairs are hashed in sorted order, so it
+    /// carries no position bits
+    pub fn verify_sorted_proof(leaf: &[u8; 32], proof: &[[u8; 32]], root: &[u8; 32]) -> bool {
+        let node = proof.iter().fold(*leaf, |node, sibling| {
+            if node <= *sibling {
+                Self::hash_node(&node, sibling)
+            } else {
+                Self::hash_node(sibling, &node)
+            }
+        });
+        node == *root
+    }
+}
+
+/// Destination gas fee math for outbound calls
+pub struct GasFeeUtils;
+
+impl GasFeeUtils {
+    /// Lamports charged per unit of destination gas when paying in SOL
+    pub const SOL_LAMPORTS_PER_GAS_UNIT: u64 = 1;
+
+    /// Fee for `gas_limit` units at `price_per_gas_unit` base units each
+    pub fn calculate_gas_fee(gas_limit: u64, price_per_gas_unit: u64) -> Result<u64> {
+        require!(price_per_gas_unit > 0, UniversalNftError::InvalidGasPrice);
+        gas_limit
+            .checked_mul(price_per_gas_unit)
+            .ok_or(UniversalNftError::ArithmeticOverflow.into())
+    }
+
+    /// Fee in lamports for the SOL payment path
+    pub fn sol_gas_fee(gas_limit: u64) -> Result<u64> {
+        Self::calculate_gas_fee(gas_limit, Self::SOL_LAMPORTS_PER_GAS_UNIT)
+    }
+}
+
+/// Up-front cost of an outbound transfer, shown to users before they sign
+pub struct BridgeCostUtils;
+
+impl BridgeCostUtils {
+    /// Lamports per transaction signature
+    pub const SIGNATURE_FEE: u64 = 5_000;
+    /// SPL mint account size, for the transfer receipt mint
+    pub const MINT_ACCOUNT_LEN: usize = 82;
+    /// SPL token account size, for the transfer receipt holder
+    pub const TOKEN_ACCOUNT_LEN: usize = 165;
+
+    /// Estimate a burn_and_transfer. `price_per_gas_unit` is the registered
+    /// ZRC-20 price and is required for the ZRC-20 path.
+    pub fn estimate(
+        gas_limit: u64,
+        gas_payment: GasPayment,
+        price_per_gas_unit: Option<u64>,
+        rent: &Rent,
+    ) -> Result<BridgeCostEstimate> {
+        let destination_gas_fee = match gas_payment {
+            GasPayment::Sol => GasFeeUtils::sol_gas_fee(gas_limit)?,
+            GasPayment::Zrc20 => GasFeeUtils::calculate_gas_fee(
+                gas_limit,
+                price_per_gas_unit.ok_or(UniversalNftError::InvalidGasTokenAccounts)?,
+            )?,
+        };
+
+        let rent_deposits = [
+            8 + CrossChainTransfer::INIT_SPACE,
+            Self::MINT_ACCOUNT_LEN,
+            Self::TOKEN_ACCOUNT_LEN,
+        ]
+        .iter()
+        .map(|len| rent.minimum_balance(*len))
+        .sum::<u64>();
+
+        let network_fee = Self::SIGNATURE_FEE;
+        let protocol_fee = 0;
+        let gas_lamports = if gas_payment == GasPayment::Sol { destination_gas_fee } else { 0 };
+        let total_lamports = [network_fee, rent_deposits, gas_lamports, protocol_fee]
+            .iter()
+            .try_fold(0u64, |total, fee| total.checked_add(*fee))
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+        Ok(BridgeCostEstimate {
+            network_fee,
+            rent_deposits,
+            gas_payment,
+            destination_gas_fee,
+            protocol_fee,
+            total_lamports,
+        })
+    }
+}
+
+/// Relayer message hashing and slashing math
+pub struct RelayerUtils;
+
+impl RelayerUtils {
+    /// Basis point denominator for slashing
+    pub const MAX_SLASH_BPS: u16 = 10_000;
+
+    /// Hash a relayed inbound message; the TSS signs it inside the signing domain
+    pub fn relayed_message_hash(
+        source_chain_id: u64,
+        sequence: u64,
+        sender: &[u8; 20],
+        message: &[u8],
+    ) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(source_chain_id.to_le_bytes());
+        hasher.update(sequence.to_le_bytes());
+        hasher.update(sender);
+        hasher.update(message);
+        hasher.finalize().into()
+    }
+
+    /// Lamports slashed from `stake` at `slash_bps`
+    pub fn slash_amount(stake: u64, slash_bps: u16) -> Result<u64> {
+        require!(slash_bps <= Self::MAX_SLASH_BPS, UniversalNftError::InvalidRelayerParameters);
+        let amount = (stake as u128)
+            .checked_mul(slash_bps as u128)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?
+            / Self::MAX_SLASH_BPS as u128;
+        Ok(amount as u64)
+    }
+}
+
+/// Inbound sequence ordering with a bounded reorder window
+pub struct SequenceUtils;
+
+impl SequenceUtils {
+    /// Sequences accepted ahead of the next expected one
+    pub const MAX_REORDER_WINDOW: u64 = 64;
+
+    /// Accept `sequence` into a queue expecting `next_sequence`, where bit `i`
+    /// of `pending` marks `next_sequence + i` as already received. Returns the
+    /// new next sequence and pending bitmap; everything below the new next
+    /// sequence is released in order.
+    pub fn accept(next_sequence: u64, pending: u64, sequence: u64) -> Result<(u64, u64)> {
+        require!(sequence >= next_sequence, UniversalNftError::SequenceAlreadyProcessed);
+        let offset = sequence - next_sequence;
+        require!(offset < Self::MAX_REORDER_WINDOW, UniversalNftError::SequenceGapTooLarge);
+        require!(pending & (1u64 << offset) == 0, UniversalNftError::SequenceAlreadyProcessed);
+
+        let pending = pending | (1u64 << offset);
+        let released = pending.trailing_ones() as u64;
+        let next_sequence = next_sequence
+            .checked_add(released)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        let pending = pending.checked_shr(released as u32).unwrap_or(0);
+
+        Ok((next_sequence, pending))
+    }
+
+    /// Consume the relayed delivery of an inbound message. The queue PDA is
+    /// address-checked by the caller's seeds; an uninitialized queue means no
+    /// relayer has delivered from the chain and nothing is required. Otherwise
+    /// the message must have been delivered and released, so every earlier
+    /// sequence from the chain was delivered before it executes.
+    pub fn consume_released(
+        queue_account: &AccountInfo,
+        record: Option<&mut RelayedMessage>,
+        source_chain_id: u64,
+        sender: &[u8; 20],
+        message: &[u8],
+    ) -> Result<()> {
+        if queue_account.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*queue_account.owner, crate::ID, UniversalNftError::RelayedMessageRequired);
+        let queue = {
+            let data = queue_account.try_borrow_data()?;
+            InboundQueue::try_deserialize(&mut &data[..])?
+        };
+
+        let record = record.ok_or(UniversalNftError::RelayedMessageRequired)?;
+        require!(
+            record.delivered
+                && record.source_chain_id == source_chain_id
+                && record.message_hash
+                    == RelayerUtils::relayed_message_hash(source_chain_id, record.sequence, sender, message),
+            UniversalNftError::RelayedMessageRequired
+        );
+        require!(record.sequence < queue.next_sequence, UniversalNftError::MessageNotReleased);
+        require!(!record.executed, UniversalNftError::RelayedMessageConsumed);
+        record.executed = true;
+        Ok(())
+    }
+}
+
+/// Utilities for the per-chain circuit breakers guarding cross-chain traffic
+pub struct CircuitBreakerUtils;
+
+impl CircuitBreakerUtils {
+    /// Admit an operation on the breaker's chain, taking the probe slot when
+    /// the breaker is half-open. The PDA is address-checked by the caller's
+    /// seeds; an uninitialized PDA means the chain has no breaker.
+    pub fn check(breaker_account: &AccountInfo) -> Result<()> {
+        Self::update(breaker_account, CircuitBreaker::check_operation_allowed)
+    }
+
+    /// Record how an operation on the breaker's chain ended. A successful
+    /// probe closes the breaker; a failed one re-opens it.
+    pub fn record(breaker_account: &AccountInfo, succeeded: bool) -> Result<()> {
+        Self::update(breaker_account, |breaker| {
+            if succeeded {
+                breaker.record_success()
+            } else {
+                breaker.record_failure()
+            }
+        })
+    }
+
+    fn update(
+        breaker_account: &AccountInfo,
+        apply: impl FnOnce(&mut CircuitBreaker) -> Result<()>,
+    ) -> Result<()> {
+        if breaker_account.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*breaker_account.owner, crate::ID, UniversalNftError::InvalidCircuitBreaker);
+
+        let mut breaker = {
+            let data = breaker_account.try_borrow_data()?;
+            CircuitBreaker::try_deserialize(&mut &data[..])?
+        };
+        apply(&mut breaker)?;
+
+        let mut data = breaker_account.try_borrow_mut_data()?;
+        breaker.try_serialize(&mut &mut data[..])
+    }
+}
+
+/// Utilities for the protocol-wide incident mode
+pub struct IncidentUtils;
+
+impl IncidentUtils {
+    /// Incident mode with expired timers applied, or `None` if it was never
+    /// set up. The PDA is address-checked by the caller's seeds and only read,
+    /// so steps taken here are not recorded; `refresh_incident_mode` does that.
+    pub fn current(incident_account: &AccountInfo) -> Result<Option<IncidentMode>> {
+        if incident_account.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(*incident_account.owner, crate::ID, UniversalNftError::InvalidIncidentConfig);
+
+        let data = incident_account.try_borrow_data()?;
+        let mut incident = IncidentMode::try_deserialize(&mut &data[..])?;
+        incident.level = incident.level_at(Clock::get()?.unix_timestamp);
+        Ok(Some(incident))
+    }
+
+    /// Fail if the current level halts all operations, or freezes `mint`
+    pub fn require_operational(incident_account: &AccountInfo, mint: Option<&Pubkey>) -> Result<()> {
+        let Some(incident) = Self::current(incident_account)? else {
+            return Ok(());
+        };
+        if let Some(mint) = mint {
+            require!(!incident.is_mint_frozen(mint), UniversalNftError::NftFrozen);
+        }
+        incident.check_operation_allowed()
+    }
+
+    /// Fail if the current level pauses outbound transfers, halts all
+    /// operations, or freezes `mint`
+    pub fn require_outbound(incident_account: &AccountInfo, mint: &Pubkey) -> Result<()> {
+        let Some(incident) = Self::current(incident_account)? else {
+            return Ok(());
+        };
+        require!(!incident.is_mint_frozen(mint), UniversalNftError::NftFrozen);
+        incident.check_operation_allowed()?;
+        incident.check_outbound_allowed()
+    }
+}
+
+/// Utilities for the token-bucket rate limits on mints and outbound transfers
+pub struct RateLimitUtils;
+
+impl RateLimitUtils {
+    /// Spend one credit from a subject's bucket, whose capacity the current
+    /// incident level may reduce. The PDAs are address-checked by the
+    /// caller's seeds; an uninitialized bucket means the subject is unlimited.
+    pub fn consume(bucket_account: &AccountInfo, incident_account: &AccountInfo) -> Result<()> {
+        if bucket_account.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*bucket_account.owner, crate::ID, UniversalNftError::InvalidRateLimitConfig);
+
+        let mut bucket = {
+            let data = bucket_account.try_borrow_data()?;
+            TokenBucket::try_deserialize(&mut &data[..])?
+        };
+        let incident = IncidentUtils::current(incident_account)?;
+        bucket.try_consume(1, incident.as_ref())?;
+
+        let mut data = bucket_account.try_borrow_mut_data()?;
+        bucket.try_serialize(&mut &mut data[..])
+    }
+}
+
+/// Utilities for scoring outbound transfers with the fraud engine
+pub struct FraudUtils;
+
+impl FraudUtils {
+    /// Score an outbound transfer of `value` by `owner` and refuse it when the
+    /// engine calls it suspicious. The PDAs are address-checked by the
+    /// caller's seeds; an uninitialized engine means no screening, and an
+    /// uninitialized registry scores chain pairs from corridor history alone.
+    pub fn screen(
+        engine_account: &AccountInfo,
+        registry_account: &AccountInfo,
+        destination_chain_id: u64,
+        value: u64,
+        owner: &Pubkey,
+    ) -> Result<()> {
+        if engine_account.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*engine_account.owner, crate::ID, UniversalNftError::InvalidFraudConfig);
+
+        let risk_registry = if registry_account.data_is_empty() {
+            None
+        } else {
+            require_keys_eq!(*registry_account.owner, crate::ID, UniversalNftError::InvalidChainRisk);
+            let data = registry_account.try_borrow_data()?;
+            Some(ChainRiskRegistry::try_deserialize(&mut &data[..])?)
+        };
+
+        let mut engine = {
+            let data = engine_account.try_borrow_data()?;
+            FraudDetectionEngine::try_deserialize(&mut &data[..])?
+        };
+        let result = engine.analyze_operation(&OperationAnalysisInput {
+            operation_type: security::OperationType::CrossChainTransfer,
+            source_chain_id: DomainUtils::SOLANA_CHAIN_ID,
+            destination_chain_id,
+            value,
+            user_address: owner.to_bytes().to_vec(),
+            user_reputation: None,
+            route_hops: None,
+            corridor: None,
+            risk_registry,
+            baseline: None,
+        })?;
+        require!(
+            !result.is_suspicious && !matches!(result.recommendation, FraudRecommendation::Block),
+            UniversalNftError::FraudRiskTooHigh
+        );
+
+        let mut data = engine_account.try_borrow_mut_data()?;
+        engine.try_serialize(&mut &mut data[..])
+    }
+}
+
+/// Utilities for the K-of-N observer quorum on inbound messages
+pub struct QuorumUtils;
+
+impl QuorumUtils {
+    /// Consume the quorum attestation of the inbound message with `leaf`. The
+    /// validator set PDA is address-checked by the caller's seeds; an
+    /// uninitialized PDA means no quorum is required. Attestations signed by
+    /// an earlier set are refused, so rotating out a set voids what it signed.
+    pub fn consume(
+        validator_set_account: &AccountInfo,
+        attestation: Option<&mut QuorumAttestation>,
+        leaf: &[u8; 32],
+    ) -> Result<()> {
+        if validator_set_account.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*validator_set_account.owner, crate::ID, UniversalNftError::InvalidValidatorSet);
+        let validator_set = {
+            let data = validator_set_account.try_borrow_data()?;
+            ValidatorSet::try_deserialize(&mut &data[..])?
+        };
+
+        let attestation = attestation.ok_or(UniversalNftError::QuorumAttestationRequired)?;
+        require!(
+            attestation.leaf == *leaf && attestation.epoch == validator_set.epoch,
+            UniversalNftError::QuorumAttestationRequired
+        );
+        require!(!attestation.consumed, UniversalNftError::QuorumAttestationConsumed);
+        attestation.consumed = true;
+        Ok(())
+    }
+}
+
+/// Canonical signing domain bound into every verified message hash, so a
+/// signature produced for one deployment or cluster can't be replayed on another
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SigningDomain {
+    /// Program deployment the message is addressed to
+    pub program_id: Pubkey,
+    /// Genesis hash of the Solana cluster
+    pub genesis_hash: [u8; 32],
+    /// Chain the message originates from
+    pub source_chain_id: u64,
+    /// Chain the message is delivered to
+    pub destination_chain_id: u64,
+}
+
+/// Message-hash domain separation
+pub struct DomainUtils;
+
+impl DomainUtils {
+    /// Version tag prefixed to every domain-separated hash
+    pub const DOMAIN_TAG: &'static [u8] = b"universal-nft:v1";
+
+    /// Chain ID this program uses for Solana
+    pub const SOLANA_CHAIN_ID: u64 = 900;
+
+    /// mainnet-beta genesis hash (5eykt4UsFv8P8NJdTREpY1vzqKqZKvdpKuc147dw2N9d)
+    pub const MAINNET_GENESIS_HASH: [u8; 32] = [
+        69, 41, 105, 152, 166, 248, 226, 167, 132, 219, 93, 159, 149, 225, 143, 194,
+        63, 112, 68, 26, 16, 57, 68, 104, 1, 8, 152, 121, 176, 140, 126, 240,
+    ];
+
+    /// devnet genesis hash (EtWTRABZaYq6iMfeYKouRu166VU2xqa1wcaWoxPkrZBG)
+    pub const DEVNET_GENESIS_HASH: [u8; 32] = [
+        206, 89, 219, 80, 128, 252, 44, 109, 59, 207, 124, 169, 7, 18, 211, 194,
+        229, 230, 194, 143, 39, 240, 223, 187, 153, 83, 189, 176, 137, 76, 3, 171,
+    ];
+
+    /// testnet genesis hash (4uhcVJyU9pJkvQyS88uRDiswHXSCkY3zQawwpjk2NsNY)
+    pub const TESTNET_GENESIS_HASH: [u8; 32] = [
+        58, 19, 46, 206, 16, 48, 94, 193, 131, 7, 37, 80, 47, 162, 183, 231,
+        235, 129, 87, 233, 18, 61, 76, 31, 101, 74, 113, 120, 113, 97, 220, 33,
+    ];
+
+    /// Genesis hash of the cluster this build targets. The runtime can't read
+    /// the genesis hash, so the cluster is fixed at build time with the
+    /// `devnet` or `testnet` feature; mainnet-beta is the default.
+    pub fn cluster_genesis_hash() -> [u8; 32] {
+        if cfg!(feature = "devnet") {
+            Self::DEVNET_GENESIS_HASH
+        } else if cfg!(feature = "testnet") {
+            Self::TESTNET_GENESIS_HASH
+        } else {
+            Self::MAINNET_GENESIS_HASH
+        }
+    }
+
+    /// Domain for a message from `source_chain_id` delivered to this deployment
+    pub fn inbound_domain(source_chain_id: u64) -> SigningDomain {
+        SigningDomain {
+            program_id: crate::ID,
+            genesis_hash: Self::cluster_genesis_hash(),
+            source_chain_id,
+            destination_chain_id: Self::SOLANA_CHAIN_ID,
+        }
+    }
+
+    /// Hash that signers actually sign: the domain, the nonce and the hash of
+    /// the message payload
+    pub fn domain_message_hash(
+        domain: &SigningDomain,
+        nonce: u64,
+        payload_hash: &[u8; 32],
+    ) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(Self::DOMAIN_TAG);
+        hasher.update(domain.program_id.as_ref());
+        hasher.update(domain.genesis_hash);
+        hasher.update(domain.source_chain_id.to_le_bytes());
+        hasher.update(domain.destination_chain_id.to_le_bytes());
+        hasher.update(nonce.to_le_bytes());
+        hasher.update(payload_hash);
+        hasher.finalize().into()
+    }
+}
+
+/// Merkle proofs of source-chain bridge events
+pub struct LightClientUtils;
+
+impl LightClientUtils {
+    /// Deepest proof accepted (covers 2^32 events per block)
+    pub const MAX_PROOF_DEPTH: usize = 32;
+
+    /// Leaf committed to by the header relayer for one bridge event. Leaves and
+    /// inner nodes use distinct prefixes so a node can't be passed off as a leaf.
+    pub fn event_leaf(source_chain_id: u64, sender: &[u8; 20], message: &[u8]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update([0u8]);
+        hasher.update(source_chain_id.to_le_bytes());
+        hasher.update(sender);
+        hasher.update(message);
+        hasher.finalize().into()
+    }
+
+    /// Fold `proof` into a root, where bit `i` of `leaf_index` says whether the
+    /// node at depth `i` is a right child
+    pub fn compute_root(leaf: &[u8; 32], proof: &[[u8; 32]], leaf_index: u64) -> Result<[u8; 32]> {
+        require!(proof.len() <= Self::MAX_PROOF_DEPTH, UniversalNftError::InvalidEventProof);
+        require!(
+            leaf_index.checked_shr(proof.len() as u32).unwrap_or(0) == 0,
+            UniversalNftError::InvalidEventProof
+        );
+
+        let mut node = *leaf;
+        for (depth, sibling) in proof.iter().enumerate() {
+            let mut hasher = Sha256::new();
+            hasher.update([1u8]);
+            if (leaf_index >> depth) & 1 == 0 {
+                hasher.update(node);
+                hasher.update(sibling);
+            } else {
+                hasher.update(sibling);
+                hasher.update(node);
+            }
+            node = hasher.finalize().into();
+        }
+        Ok(node)
+    }
+}
+
+/// Per-chain outbound value and count caps over a rolling 24h window
+pub struct VolumeCapUtils;
+
+impl VolumeCapUtils {
+    /// Length of one accounting window
+    pub const WINDOW_SECONDS: i64 = 86_400;
+
+    /// Advance `volume` to the window containing `now`
+    pub fn roll(volume: RollingVolume, now: i64) -> RollingVolume {
+        let elapsed = now.saturating_sub(volume.window_start);
+        if elapsed < Self::WINDOW_SECONDS {
+            return volume;
+        }
+
+        // Windows stay aligned to the first window start
+        let windows = elapsed / Self::WINDOW_SECONDS;
+        let window_start = volume.window_start + windows * Self::WINDOW_SECONDS;
+        let (previous_value, previous_count) = if windows == 1 {
+            (volume.current_value, volume.current_count)
+        } else {
+            (0, 0)
+        };
+
+        RollingVolume {
+            window_start,
+            current_value: 0,
+            previous_value,
+            current_count: 0,
+            previous_count,
+        }
+    }
+
+    /// Estimated total over the last 24h: the previous window weighted by how
+    /// much of it still overlaps, plus the current window
+    pub fn rolling_total(previous: u64, current: u64, elapsed_in_window: i64) -> u64 {
+        let remaining = (Self::WINDOW_SECONDS - elapsed_in_window.clamp(0, Self::WINDOW_SECONDS)) as u128;
+        let weighted = previous as u128 * remaining / Self::WINDOW_SECONDS as u128;
+        (weighted as u64).saturating_add(current)
+    }
+
+    /// Check `count` transfers worth `value` against `limits` and return the
+    /// updated volume. Breaches are only reported when the limits run in
+    /// shadow mode, and caps are skipped entirely when they are off.
+    pub fn record(
+        limits: &ChainTransferLimits,
+        chain_id: u64,
+        volume: RollingVolume,
+        value: u64,
+        count: u32,
+        now: i64,
+    ) -> Result<RollingVolume> {
+        // The first transfer on a chain opens its first window
+        let mut volume = if volume.window_start == 0 {
+            RollingVolume { window_start: now, ..volume }
+        } else {
+            Self::roll(volume, now)
+        };
+        volume.current_value = volume.current_value
+            .checked_add(value)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        volume.current_count = volume.current_count
+            .checked_add(count)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+
+        limits.enforcement.apply(
+            SecurityRule::ChainLimits,
+            chain_id,
+            Self::breach(limits, &volume, value, now),
+        )?;
+        Ok(volume)
+    }
+
+    /// First cap `volume` breaches after adding a transfer worth `value`
+    pub fn breach(
+        limits: &ChainTransferLimits,
+        volume: &RollingVolume,
+        value: u64,
+        now: i64,
+    ) -> Option<UniversalNftError> {
+        if limits.max_value_per_transfer > 0 && value > limits.max_value_per_transfer {
+            return Some(UniversalNftError::TransferValueCapExceeded);
+        }
+
+        let elapsed = now - volume.window_start;
+        if limits.daily_value_cap > 0
+            && Self::rolling_total(volume.previous_value, volume.current_value, elapsed) > limits.daily_value_cap
+        {
+            return Some(UniversalNftError::DailyVolumeCapExceeded);
+        }
+        if limits.daily_transfer_cap > 0
+            && Self::rolling_total(volume.previous_count as u64, volume.current_count as u64, elapsed)
+                > limits.daily_transfer_cap as u64
+        {
+            return Some(UniversalNftError::DailyTransferCapExceeded);
+        }
+        None
+    }
+
+    /// Floor value of an NFT in `collection_mint`. NFTs outside a collection,
+    /// and collections with no posted price, count as zero value.
+    pub fn load_floor_value(
+        collection_mint: Option<Pubkey>,
+        floor_account: Option<&AccountInfo>,
+    ) -> Result<u64> {
+        let collection_mint = match collection_mint {
+            Some(mint) => mint,
+            None => return Ok(0),
+        };
+
+        let floor_account = floor_account.ok_or(UniversalNftError::MissingCollectionFloor)?;
+        Ok(FloorPriceUtils::load(&collection_mint, floor_account)?.map_or(0, |floor| floor.floor_value))
+    }
+}
+
+/// Collection floor prices, posted by price-feed authorities or read from a
+/// Pyth-style price account
+pub struct FloorPriceUtils;
+
+impl FloorPriceUtils {
+    /// Pyth price account magic number
+    pub const PYTH_MAGIC: u32 = 0xa1b2c3d4;
+    /// Pyth account type of a price account
+    pub const PYTH_PRICE_ACCOUNT_TYPE: u32 = 3;
+    /// Pyth aggregate status of a price that is trading
+    pub const PYTH_STATUS_TRADING: u32 = 1;
+
+    const ACCOUNT_TYPE_OFFSET: usize = 8;
+    const EXPONENT_OFFSET: usize = 20;
+    const TIMESTAMP_OFFSET: usize = 96;
+    const AGGREGATE_PRICE_OFFSET: usize = 208;
+    const AGGREGATE_STATUS_OFFSET: usize = 224;
+
+    /// Load the floor of `collection_mint`, checking the account is its
+    /// floor PDA. A PDA that was never configured has no floor.
+    pub fn load(collection_mint: &Pubkey, floor_account: &AccountInfo) -> Result<Option<CollectionFloor>> {
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"collection_floor", collection_mint.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            floor_account.key(),
+            expected,
+            UniversalNftError::MissingCollectionFloor
+        );
+
+        if floor_account.data_is_empty() {
+            return Ok(None);
+        }
+
+        let data = floor_account.try_borrow_data()?;
+        Ok(Some(CollectionFloor::try_deserialize(&mut &data[..])?))
+    }
+
+    /// Fresh floor value of an NFT in `collection_mint`, for loans and
+    /// insurance. NFTs outside a collection, and unpriced collections, count
+    /// as zero value.
+    pub fn fresh_value(
+        collection_mint: Option<Pubkey>,
+        floor_account: Option<&AccountInfo>,
+        now: i64,
+    ) -> Result<u64> {
+        let collection_mint = match collection_mint {
+            Some(mint) => mint,
+            None => return Ok(0),
+        };
+        let floor_account = floor_account.ok_or(UniversalNftError::MissingCollectionFloor)?;
+        match Self::load(&collection_mint, floor_account)? {
+            Some(floor) => floor.fresh_value(now),
+            None => Ok(0),
+        }
+    }
+
+    /// Lamport value and publish time of a Pyth-style price account quoting
+    /// SOL. Only trading, positive prices are accepted.
+    pub fn parse_pyth_price(data: &[u8]) -> Result<(u64, i64)> {
+        require!(
+            data.len() >= Self::AGGREGATE_STATUS_OFFSET + 4,
+            UniversalNftError::InvalidPriceFeed
+        );
+        let read_u32 = |offset: usize| u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+        let read_i64 = |offset: usize| i64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        require!(
+            read_u32(0) == Self::PYTH_MAGIC
+                && read_u32(Self::ACCOUNT_TYPE_OFFSET) == Self::PYTH_PRICE_ACCOUNT_TYPE
+                && read_u32(Self::AGGREGATE_STATUS_OFFSET) == Self::PYTH_STATUS_TRADING,
+            UniversalNftError::InvalidPriceFeed
+        );
+
+        let price = read_i64(Self::AGGREGATE_PRICE_OFFSET);
+        require!(price > 0, UniversalNftError::InvalidPriceFeed);
+        let exponent = read_u32(Self::EXPONENT_OFFSET) as i32;
+
+        // Price is `price * 10^exponent` SOL, or `price * 10^(exponent + 9)` lamports
+        let scale = exponent + 9;
+        let lamports = if scale >= 0 {
+            10u128
+                .checked_pow(scale as u32)
+                .and_then(|factor| (price as u128).checked_mul(factor))
+        } else {
+            10u128.checked_pow(scale.unsigned_abs()).map(|factor| price as u128 / factor)
+        };
+        let lamports = lamports
+            .and_then(|value| u64::try_from(value).ok())
+            .ok_or(UniversalNftError::InvalidPriceFeed)?;
+
+        Ok((lamports, read_i64(Self::TIMESTAMP_OFFSET)))
+    }
+}
+
+/// Per-collection policy lookup
+pub struct CollectionConfigUtils;
+
+impl CollectionConfigUtils {
+    /// Load the policy for `collection_mint`. Collection NFTs must pass the
+    /// config PDA so a policy can't be skipped; an uninitialized PDA means the
+    /// collection has no policy.
+    pub fn load_collection_config(
+        collection_mint: Option<Pubkey>,
+        config_account: Option<&AccountInfo>,
+    ) -> Result<Option<CollectionConfig>> {
+        let collection_mint = match collection_mint {
+            Some(mint) => mint,
+            None => return Ok(None),
+        };
+
+        let config_account = config_account.ok_or(UniversalNftError::MissingCollectionConfig)?;
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"collection_config", collection_mint.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            config_account.key(),
+            expected,
+            UniversalNftError::MissingCollectionConfig
+        );
+
+        if config_account.data_is_empty() {
+            return Ok(None);
+        }
+
+        let data = config_account.try_borrow_data()?;
+        let config = CollectionConfig::try_deserialize(&mut &data[..])?;
+        Ok(Some(config))
+    }
+
+    /// Write back a policy loaded by `load_collection_config`
+    pub fn store_collection_config(
+        config: &CollectionConfig,
+        config_account: &AccountInfo,
+    ) -> Result<()> {
+        require!(config_account.is_writable, UniversalNftError::MissingCollectionConfig);
+        let mut data = config_account.try_borrow_mut_data()?;
+        config.try_serialize(&mut &mut data[..])?;
+        Ok(())
+    }
+}
+
+/// Mint phase resolution and presale allowlist proofs
+pub struct MintPhaseUtils;
+
+impl MintPhaseUtils {
+    /// Deepest allowlist proof accepted
+    pub const MAX_PROOF_DEPTH: usize = 24;
+
+    /// Allowlist leaf for a wallet
+    pub fn allowlist_leaf(wallet: &Pubkey) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update([0u8]);
+        hasher.update(wallet.as_ref());
+        hasher.finalize().into()
+    }
+
+    /// Check `wallet` against an allowlist root
+    pub fn verify_allowlist(root: &[u8; 32], wallet: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        proof.len() <= Self::MAX_PROOF_DEPTH
+            && MerkleUtils::verify_sorted_proof(&Self::allowlist_leaf(wallet), proof, root)
+    }
+
+    /// Resolve the phase governing a mint at `now`. Collections without a
+    /// schedule mint freely; scheduled collections must pass the active phase,
+    /// and are closed before, between and after their phases.
+    pub fn resolve_phase<'a>(
+        config: &CollectionConfig,
+        phase: Option<&'a MintPhase>,
+        now: i64,
+    ) -> Result<Option<&'a MintPhase>> {
+        if config.phase_count == 0 {
+            return Ok(None);
+        }
+
+        let phase = phase.ok_or(UniversalNftError::MintClosed)?;
+        require_keys_eq!(
+            phase.collection_mint,
+            config.collection_mint,
+            UniversalNftError::InvalidMintPhase
+        );
+        require!(phase.is_active(now), UniversalNftError::MintClosed);
+        Ok(Some(phase))
+    }
+
+    /// Check that `wallet` may mint in `phase`
+    pub fn check_eligibility(phase: &MintPhase, wallet: &Pubkey, proof: &[[u8; 32]]) -> Result<()> {
+        let root = match (phase.kind, phase.allowlist_root) {
+            (_, Some(root)) => root,
+            (MintPhaseKind::Public, None) => return Ok(()),
+            (MintPhaseKind::Presale, None) => return Err(UniversalNftError::InvalidMintPhase.into()),
+        };
+        require!(
+            Self::verify_allowlist(&root, wallet, proof),
+            UniversalNftError::NotOnAllowlist
+        );
+        Ok(())
+    }
+}
+
+/// Holder snapshots committed by an off-chain indexer
+pub struct SnapshotUtils;
+
+impl SnapshotUtils {
+    /// Deepest holder proof accepted
+    pub const MAX_PROOF_DEPTH: usize = 32;
+
+    /// Leaf for one NFT held by `owner` at the snapshot slot
+    pub fn holder_leaf(owner: &Pubkey, mint: &Pubkey) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update([0u8]);
+        hasher.update(owner.as_ref());
+        hasher.update(mint.as_ref());
+        hasher.finalize().into()
+    }
+
+    /// Commitment posted before the holders root is revealed
+    pub fn commitment(holders_root: &[u8; 32], holder_count: u32, salt: &[u8; 32]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(holders_root);
+        hasher.update(holder_count.to_le_bytes());
+        hasher.update(salt);
+        hasher.finalize().into()
+    }
+
+    /// Check that `owner` held `mint` in the snapshot with `holders_root`
+    pub fn verify_holder(
+        holders_root: &[u8; 32],
+        owner: &Pubkey,
+        mint: &Pubkey,
+        proof: &[[u8; 32]],
+    ) -> bool {
+        proof.len() <= Self::MAX_PROOF_DEPTH
+            && MerkleUtils::verify_sorted_proof(&Self::holder_leaf(owner, mint), proof, holders_root)
+    }
+}
+
+/// User-level wallet transfer locks
+pub struct WalletLockUtils;
+
+impl WalletLockUtils {
+    /// Reject outbound movement while the wallet's lock is engaged. The lock
+    /// PDA is address-checked by the caller's seeds; an uninitialized PDA
+    /// means the wallet never locked.
+    pub fn require_unlocked(lock_account: &AccountInfo) -> Result<()> {
+        Self::require_unlocked_at(lock_account, Clock::get()?.unix_timestamp)
+    }
+
+    /// `require_unlocked` at `now`
+    pub fn require_unlocked_at(lock_account: &AccountInfo, now: i64) -> Result<()> {
+        if lock_account.data_is_empty() {
+            return Ok(());
+        }
+
+        let data = lock_account.try_borrow_data()?;
+        let lock = WalletLock::try_deserialize(&mut &data[..])?;
+        require!(!lock.is_active(now), UniversalNftError::WalletTransfersLocked);
+        Ok(())
+    }
+}
+
+/// Accounts a payer may pass to claim a protocol fee discount or rebate
+#[derive(Default)]
+pub struct FeeDiscountAccounts<'a, 'info> {
+    /// Payer's stake in the governance program
+    pub governance_stake: Option<&'a AccountInfo<'info>>,
+    /// Registry naming the governance deployment stakes must belong to,
+    /// required with `governance_stake`
+    pub deployment_registry: Option<&'a DeploymentRegistry>,
+    /// Payer's token account holding a membership collection NFT
+    pub membership_token_account: Option<&'a AccountInfo<'info>>,
+    /// Universal NFT record of the held membership NFT
+    pub membership_nft: Option<&'a AccountInfo<'info>>,
+    /// Rebate campaign PDA, refunding part of the fee while it runs
+    pub rebate_campaign: Option<&'a AccountInfo<'info>>,
+}
+
+/// Registered referrer named by a paying instruction
+pub struct FeeReferral<'a, 'info> {
+    pub referrer: Pubkey,
+    /// Referrer's `ReferrerAccrual` PDA
+    pub accrual: &'a AccountInfo<'info>,
+}
+
+/// Protocol fees charged by paying instructions
+pub struct FeeUtils;
+
+impl FeeUtils {
+    /// Load the fee schedule. The PDA is address-checked by the caller's
+    /// seeds; an uninitialized PDA means governance never set fees.
+    pub fn load(schedule_account: &AccountInfo) -> Result<Option<FeeSchedule>> {
+        if schedule_account.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(
+            *schedule_account.owner,
+            crate::ID,
+            UniversalNftError::InvalidFeeSchedule
+        );
+
+        let data = schedule_account.try_borrow_data()?;
+        Ok(Some(FeeSchedule::try_deserialize(&mut &data[..])?))
+    }
+
+    /// Discount `payer` qualifies for with the accounts passed. Stake tiers
+    /// and membership don't stack; the larger discount applies.
+    pub fn discount_bps(rates: &FeeRates, payer: &Pubkey, discounts: &FeeDiscountAccounts) -> Result<u16> {
+        let mut discount_bps = 0;
+
+        if let Some(stake_account) = discounts.governance_stake {
+            let registry = discounts.deployment_registry.ok_or(UniversalNftError::InvalidFeeDiscount)?;
+            let staked = Self::governance_stake_amount(stake_account, payer, registry)?;
+            discount_bps = discount_bps.max(rates.stake_discount_bps(staked));
+        }
+
+        if let (Some(token_account), Some(nft_account)) =
+            (discounts.membership_token_account, discounts.membership_nft)
+        {
+            let collection = rates.membership_collection.ok_or(UniversalNftError::InvalidFeeDiscount)?;
+            require_keys_eq!(
+                *token_account.owner,
+                anchor_spl::token::ID,
+                UniversalNftError::InvalidFeeDiscount
+            );
+            require_keys_eq!(*nft_account.owner, crate::ID, UniversalNftError::InvalidFeeDiscount);
+
+            let tokens = {
+                let data = token_account.try_borrow_data()?;
+                anchor_spl::token::TokenAccount::try_deserialize(&mut &data[..])?
+            };
+            let nft = {
+                let data = nft_account.try_borrow_data()?;
+                UniversalNft::try_deserialize(&mut &data[..])?
+            };
+            require!(
+                tokens.owner == *payer
+                    && tokens.amount == 1
+                    && tokens.mint == nft.mint
+                    && nft.collection_mint == Some(collection),
+                UniversalNftError::InvalidFeeDiscount
+            );
+            discount_bps = discount_bps.max(rates.membership_discount_bps);
+        }
+
+        Ok(discount_bps)
+    }
+
+    /// Tokens `payer` has staked in the approved governance program
+    #[cfg(feature = "governance")]
+    fn governance_stake_amount(
+        stake_account: &AccountInfo,
+        payer: &Pubkey,
+        registry: &DeploymentRegistry,
+    ) -> Result<u64> {
+        use crate::governance::dao::GovernanceStake;
+        use crate::state::SatelliteKind;
+
+        registry.require_program(SatelliteKind::Governance, stake_account.owner)?;
+        let data = stake_account.try_borrow_data()?;
+        let stake = GovernanceStake::try_deserialize(&mut &data[..])?;
+        require_keys_eq!(stake.staker, *payer, UniversalNftError::InvalidFeeDiscount);
+        Ok(stake.amount)
+    }
+
+    #[cfg(not(feature = "governance"))]
+    fn governance_stake_amount(
+        _stake_account: &AccountInfo,
+        _payer: &Pubkey,
+        _registry: &DeploymentRegistry,
+    ) -> Result<u64> {
+        err!(UniversalNftError::FeatureDisabled)
+    }
+
+    /// Check a referrer named by a paying instruction. Nobody the fee is paid
+    /// by or for may refer themselves, and the referrer must have registered.
+    pub fn referral<'a, 'info>(
+        referrer: Option<Pubkey>,
+        accrual: Option<&'a AccountInfo<'info>>,
+        excluded: &[Pubkey],
+    ) -> Result<Option<FeeReferral<'a, 'info>>> {
+        let referrer = match referrer {
+            Some(referrer) => referrer,
+            None => return Ok(None),
+        };
+        require!(!excluded.contains(&referrer), UniversalNftError::SelfReferral);
+
+        let accrual = accrual.ok_or(UniversalNftError::InvalidReferral)?;
+        let (expected, _) = Pubkey::find_program_address(&[b"referral", referrer.as_ref()], &crate::ID);
+        require_keys_eq!(accrual.key(), expected, UniversalNftError::InvalidReferral);
+        require_keys_eq!(*accrual.owner, crate::ID, UniversalNftError::InvalidReferral);
+
+        Ok(Some(FeeReferral { referrer, accrual }))
+    }
+
+    /// Move `amount` lamports, less any discount the payer qualifies for,
+    /// from `payer` to the schedule's fee recipient. A referrer's share goes
+    /// to its accrual PDA instead.
+    #[allow(clippy::too_many_arguments)]
+    pub fn charge<'info>(
+        schedule: &FeeSchedule,
+        kind: FeeKind,
+        amount: u64,
+        payer: &AccountInfo<'info>,
+        fee_recipient: Option<&AccountInfo<'info>>,
+        discounts: &FeeDiscountAccounts<'_, 'info>,
+        referral: Option<&FeeReferral<'_, 'info>>,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let discount_bps = Self::discount_bps(&schedule.active, &payer.key(), discounts)?;
+        let amount = FeeRates::discounted(amount, discount_bps)?;
+        if amount == 0 {
+            return Ok(());
+        }
+        let fee_recipient = fee_recipient.ok_or(UniversalNftError::InvalidFeeRecipient)?;
+        require_keys_eq!(
+            fee_recipient.key(),
+            schedule.active.fee_recipient,
+            UniversalNftError::InvalidFeeRecipient
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let mut referral_share = 0;
+        if let Some(referral) = referral {
+            referral_share = FeeRates::bps_of(amount, schedule.active.referral_share_bps)?;
+            Self::transfer(payer, referral.accrual, referral_share, system_program)?;
+
+            let mut data = referral.accrual.try_borrow_mut_data()?;
+            let mut accrual = ReferrerAccrual::try_deserialize(&mut &data[..])?;
+            accrual.accrued = accrual.accrued
+                .checked_add(referral_share)
+                .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            accrual.referral_count = accrual.referral_count
+                .checked_add(1)
+                .ok_or(UniversalNftError::ArithmeticOverflow)?;
+            accrual.try_serialize(&mut &mut data[..])?;
+
+            emit!(crate::events::ReferralFeeAccrued {
+                referrer: referral.referrer,
+                payer: payer.key(),
+                kind,
+                amount: referral_share,
+                timestamp: now,
+            });
+        }
+        Self::transfer(payer, fee_recipient, amount - referral_share, system_program)?;
+
+        emit!(crate::events::ProtocolFeeCharged {
+            payer: payer.key(),
+            kind,
+            amount,
+            discount_bps,
+            fee_recipient: fee_recipient.key(),
+            timestamp: now,
+        });
+
+        if let Some(campaign) = discounts.rebate_campaign {
+            Self::rebate(campaign, kind, amount, payer, now)?;
+        }
+        Ok(())
+    }
+
+    /// Refund part of a charged fee from the rebate campaign, if it is
+    /// running, covers `kind` and still has budget and lamports
+    fn rebate<'info>(
+        campaign_account: &AccountInfo<'info>,
+        kind: FeeKind,
+        fee: u64,
+        payer: &AccountInfo<'info>,
+        now: i64,
+    ) -> Result<()> {
+        let (expected, _) = Pubkey::find_program_address(&[b"rebate_campaign"], &crate::ID);
+        require_keys_eq!(campaign_account.key(), expected, UniversalNftError::InvalidRebateCampaign);
+        require_keys_eq!(*campaign_account.owner, crate::ID, UniversalNftError::InvalidRebateCampaign);
+
+        let mut campaign = {
+            let data = campaign_account.try_borrow_data()?;
+            RebateCampaign::try_deserialize(&mut &data[..])?
+        };
+        let reserve = Rent::get()?.minimum_balance(campaign_account.data_len());
+        let spare = campaign_account.lamports().saturating_sub(reserve);
+        let rebate = campaign.rebate_for(kind, fee, now).min(spare);
+        if rebate == 0 {
+            return Ok(());
+        }
+
+        campaign.spent = campaign.spent
+            .checked_add(rebate)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        campaign.rebate_count = campaign.rebate_count
+            .checked_add(1)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        {
+            let mut data = campaign_account.try_borrow_mut_data()?;
+            campaign.try_serialize(&mut &mut data[..])?;
+        }
+        **campaign_account.try_borrow_mut_lamports()? -= rebate;
+        **payer.try_borrow_mut_lamports()? += rebate;
+
+        emit!(crate::events::FeeRebated {
+            payer: payer.key(),
+            kind,
+            amount: rebate,
+            remaining_budget: campaign.budget.saturating_sub(campaign.spent),
+            timestamp: now,
+        });
+        Ok(())
+    }
+
+    fn transfer<'info>(
+        from: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+        amount: u64,
+        system_program: &AccountInfo<'info>,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+        let cpi_accounts = anchor_lang::system_program::Transfer {
+            from: from.clone(),
+            to: to.clone(),
+        };
+        let cpi_ctx = CpiContext::new(system_program.clone(), cpi_accounts);
+        anchor_lang::system_program::transfer(cpi_ctx, amount)
+    }
+}
+
+/// Where and how an inbound mint is delivered
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RecipientDelivery {
+    /// Wallet that receives the NFT
+    pub recipient: Pubkey,
+    /// Whether the recipient's token account may be created for it
+    pub create_token_account: bool,
+    /// Whether the NFT is held in quarantine for the recipient to claim
+    pub quarantine: bool,
+}
+
+/// Recipient preferences consulted when delivering inbound mints
+pub struct RecipientPreferencesUtils;
+
+impl RecipientPreferencesUtils {
+    /// Load `recipient`'s preferences for `source_chain_id`. The account must
+    /// be the preferences PDA, so a delivery can't skip it; an uninitialized
+    /// PDA means the recipient set none.
+    pub fn load(
+        preferences_account: &AccountInfo,
+        recipient: &Pubkey,
+        source_chain_id: u64,
+    ) -> Result<Option<RecipientPreferences>> {
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"recipient_preferences", recipient.as_ref(), source_chain_id.to_le_bytes().as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            preferences_account.key(),
+            expected,
+            UniversalNftError::InvalidRecipientPreferences
+        );
+        if preferences_account.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(
+            *preferences_account.owner,
+            crate::ID,
+            UniversalNftError::InvalidRecipientPreferences
+        );
+
+        let data = preferences_account.try_borrow_data()?;
+        Ok(Some(RecipientPreferences::try_deserialize(&mut &data[..])?))
+    }
+
+    /// Apply preferences to a mint from `sender` addressed to `recipient`.
+    /// Without preferences the NFT goes to the named recipient as before.
+    pub fn resolve(
+        preferences: Option<&RecipientPreferences>,
+        recipient: Pubkey,
+        sender: &[u8; 20],
+    ) -> Result<RecipientDelivery> {
+        let preferences = match preferences {
+            Some(preferences) => preferences,
+            None => {
+                return Ok(RecipientDelivery { recipient, create_token_account: true, quarantine: false })
+            }
+        };
+
+        let quarantine = if preferences.is_solicited(sender) {
+            false
+        } else {
+            match preferences.unsolicited {
+                UnsolicitedPolicy::Accept => false,
+                UnsolicitedPolicy::Quarantine => true,
+                UnsolicitedPolicy::Decline => {
+                    return Err(UniversalNftError::UnsolicitedDeliveryDeclined.into())
+                }
+            }
+        };
+        Ok(RecipientDelivery {
+            recipient: preferences.preferred_recipient.unwrap_or(recipient),
+            create_token_account: preferences.auto_wrap,
+            quarantine,
+        })
+    }
+}
+
+/// Scoped operator sessions acting for an NFT owner
+pub struct OperatorSessionUtils;
+
+impl OperatorSessionUtils {
+    /// Load the session at `[b"operator_session", owner, operator]`, address-
+    /// checked by the caller's seeds. An uninitialized PDA means no session.
+    pub fn load_session(session_account: &AccountInfo) -> Result<Option<OperatorSession>> {
+        if session_account.data_is_empty() {
+            return Ok(None);
+        }
+
+        let data = session_account.try_borrow_data()?;
+        Ok(Some(OperatorSession::try_deserialize(&mut &data[..])?))
+    }
+
+    /// Allow `signer` to act on `nft_owner`'s NFT: either it is the owner, or
+    /// it holds a live session granting `scope`
+    pub fn authorize_signer(
+        nft_owner: &Pubkey,
+        signer: &Pubkey,
+        session_account: &AccountInfo,
+        scope: SessionScope,
+        destination_chain_id: Option<u64>,
+    ) -> Result<()> {
+        if signer == nft_owner {
+            return Ok(());
+        }
+
+        let session = Self::load_session(session_account)?
+            .ok_or(UniversalNftError::InvalidNftOwner)?;
+        session.authorize(scope, destination_chain_id, Clock::get()?.slot)
+    }
+}
+
+/// Versioned wire format for cross-chain payloads: a version byte, then the
+/// message-type discriminator and that message's borsh-encoded fields.
+/// Version 2 puts the source block number (u64 LE) between the version byte
+/// and a version 1 body, for chains with a confirmation depth requirement.
+pub struct MessageCodec;
+
+impl MessageCodec {
+    /// Payload version this program emits
+    pub const CURRENT_VERSION: u8 = 1;
+    /// Payload version carrying the source block number
+    pub const SOURCE_BLOCK_VERSION: u8 = 2;
+    /// Message types defined in version 1, discriminators 0..N. New types
+    /// are appended to CrossChainMessage and raise this count.
+    pub const V1_MESSAGE_TYPES: u8 = 21;
+
+    /// Encode a message in the current payload version
+    pub fn encode(message: &CrossChainMessage) -> Result<Vec<u8>> {
+        let mut data = vec![Self::CURRENT_VERSION];
+        message
+            .serialize(&mut data)
+            .map_err(|_| UniversalNftError::InvalidMessageFormat)?;
+        Ok(data)
+    }
+
+    /// Decode a payload of any supported version
+    pub fn decode(data: &[u8]) -> Result<CrossChainMessage> {
+        let (version, body) = data
+            .split_first()
+            .ok_or(UniversalNftError::InvalidMessageFormat)?;
+        match *version {
+            1 => Self::decode_v1(body),
+            2 => Self::decode_v1(body.get(8..).ok_or(UniversalNftError::InvalidMessageFormat)?),
+            _ => Err(UniversalNftError::UnsupportedMessageVersion.into()),
+        }
+    }
+
+    /// Encode a message with the source block it was emitted in
+    pub fn encode_with_source_block(message: &CrossChainMessage, block_number: u64) -> Result<Vec<u8>> {
+        let mut data = vec![Self::SOURCE_BLOCK_VERSION];
+        data.extend_from_slice(&block_number.to_le_bytes());
+        data.extend_from_slice(&Self::encode(message)?[1..]);
+        Ok(data)
+    }
+
+    /// Source block number of a payload, if its version carries one
+    pub fn source_block(data: &[u8]) -> Option<u64> {
+        match data.split_first() {
+            Some((&Self::SOURCE_BLOCK_VERSION, body)) => {
+                body.get(..8).map(|b| u64::from_le_bytes(b.try_into().unwrap()))
+            }
+            _ => None,
+        }
+    }
+
+    fn decode_v1(body: &[u8]) -> Result<CrossChainMessage> {
+        let message_type = *body.first().ok_or(UniversalNftError::InvalidMessageFormat)?;
+        require!(
+            message_type < Self::V1_MESSAGE_TYPES,
+            UniversalNftError::UnsupportedMessageType
+        );
+        CrossChainMessage::try_from_slice(body)
+            .map_err(|_| UniversalNftError::InvalidMessageFormat.into())
+    }
+}
+
+/// Rewrites outbound metadata to fit a destination chain's standard
+pub struct MetadataTranslationUtils;
+
+impl MetadataTranslationUtils {
+    /// Cut `value` to at most `max_len` bytes on a character boundary. Zero
+    /// leaves it whole.
+    pub fn truncate(value: &str, max_len: u8) -> String {
+        let max_len = max_len as usize;
+        if max_len == 0 || value.len() <= max_len {
+            return value.to_string();
+        }
+        let mut end = max_len;
+        while !value.is_char_boundary(end) {
+            end -= 1;
+        }
+        value[..end].trim_end().to_string()
+    }
+
+    /// Replace `match_prefix` at the start of `uri` with `replace_prefix`.
+    /// URIs without the prefix, and rules without one, pass through.
+    pub fn rewrite_uri(uri: &str, match_prefix: &str, replace_prefix: &str) -> Result<String> {
+        let rewritten = match uri.strip_prefix(match_prefix) {
+            Some(rest) if !match_prefix.is_empty() => format!("{}{}", replace_prefix, rest),
+            _ => return Ok(uri.to_string()),
+        };
+        MetadataUtils::validate_uri(&rewritten)?;
+        Ok(rewritten)
+    }
+
+    /// Apply a chain's rules to an NFT's name, symbol and URI
+    pub fn translate(
+        rules: &ChainMetadataRules,
+        name: &str,
+        symbol: &str,
+        uri: &str,
+    ) -> Result<(String, String, String)> {
+        Ok((
+            Self::truncate(name, rules.max_name_len),
+            Self::truncate(symbol, rules.max_symbol_len),
+            Self::rewrite_uri(uri, &rules.uri_match_prefix, &rules.uri_replace_prefix)?,
+        ))
+    }
+}
+
+/// Rent sponsorship for accounts created by inbound messages
+pub struct RentSponsorUtils;
+
+impl RentSponsorUtils {
+    /// Whether the vault can pay `lamports` of rent for the ledger's chain.
+    /// `spare_lamports` is the vault balance above its own rent-exempt minimum.
+    pub fn can_sponsor(
+        vault: &RentVault,
+        ledger: &ChainRentLedger,
+        spare_lamports: u64,
+        lamports: u64,
+    ) -> bool {
+        vault.enabled && ledger.remaining() >= lamports && spare_lamports >= lamports
+    }
+
+    /// Charge sponsored rent to the vault and the chain's ledger
+    pub fn record(vault: &mut RentVault, ledger: &mut ChainRentLedger, lamports: u64) -> Result<()> {
+        vault.total_sponsored = vault.total_sponsored
+            .checked_add(lamports)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        ledger.sponsored_lamports = ledger.sponsored_lamports
+            .checked_add(lamports)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        ledger.sponsored_accounts = ledger.sponsored_accounts
+            .checked_add(1)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// Where an inbound NFT's token comes from
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InboundSource {
+    /// Minted by the program's mint authority: a first arrival, or an NFT
+    /// that was burned when it left
+    Mint,
+    /// Released from program custody, where it waited while it was away
+    Custody,
+}
+
+/// Delivery of NFTs arriving from other chains
+pub struct InboundDeliveryUtils;
+
+impl InboundDeliveryUtils {
+    /// Seed of the mint prepared for a token's first arrival. Token IDs run
+    /// past the 32-byte seed limit, so the seed is their hash.
+    pub fn token_id_seed(token_id: &str) -> [u8; 32] {
+        Sha256::digest(token_id.as_bytes()).into()
+    }
+
+    /// Whether an outbound NFT waits in custody instead of being burned.
+    /// Lock-mode collections always keep it, and so do NFTs whose master
+    /// edition holds the mint authority, since a burned one couldn't return.
+    pub fn locks_outbound(lock_mode: bool, reissuable: bool) -> bool {
+        lock_mode || !reissuable
+    }
+
+    /// How an inbound message delivers an NFT. A token in custody comes out
+    /// only while the NFT's bridge lock is held; otherwise the NFT must have
+    /// no live token, so a replayed message never delivers a second one.
+    pub fn source(
+        supply: u64,
+        reissuable: bool,
+        custody_amount: u64,
+        bridged_out: bool,
+    ) -> Result<InboundSource> {
+        if bridged_out && custody_amount == 1 {
+            return Ok(InboundSource::Custody);
+        }
+        require!(supply == 0, UniversalNftError::NftAlreadyOnSolana);
+        require!(reissuable, UniversalNftError::InvalidMintAuthority);
+        Ok(InboundSource::Mint)
+    }
+}
+
+/// Arbitration of disputed outbound transfers
+pub struct DisputeUtils;
+
+impl DisputeUtils {
+    /// Basis points representing the full escrow
+    pub const MAX_SPLIT_BPS: u16 = 10_000;
+
+    /// Only a transfer still in flight can be disputed or reverted. Once it
+    /// completes the NFT is live on its destination chain, and a revert
+    /// would leave it on both chains.
+    pub fn require_disputable(status: &TransferStatus) -> Result<()> {
+        require!(
+            matches!(status, TransferStatus::Initiated | TransferStatus::Processing),
+            UniversalNftError::InvalidTransferStatus
+        );
+        Ok(())
+    }
+
+    /// Escrow paid to the opener and to the counterparty for the opener's share
+    pub fn payouts(escrowed: u64, opener_share_bps: u16) -> Result<(u64, u64)> {
+        require!(opener_share_bps <= Self::MAX_SPLIT_BPS, UniversalNftError::InvalidSplitShare);
+        let opener_payout = escrowed
+            .checked_mul(opener_share_bps as u64)
+            .and_then(|share| share.checked_div(Self::MAX_SPLIT_BPS as u64))
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        Ok((opener_payout, escrowed - opener_payout))
+    }
+
+    /// How a revert returns the NFT: from custody when the transfer locked it
+    /// there, otherwise by minting it again, which only a burned NFT allows
+    pub fn revert_source(custody_amount: Option<u64>, supply: u64) -> Result<InboundSource> {
+        match custody_amount {
+            Some(amount) => {
+                require!(amount == 1, UniversalNftError::NftNotInCustody);
+                Ok(InboundSource::Custody)
+            }
+            None => {
+                require!(supply == 0, UniversalNftError::InvalidDisputeRevertAccounts);
+                Ok(InboundSource::Mint)
+            }
+        }
+    }
+}
+
+/// Checks for NFTs sent together in one batched outbound transfer
+pub struct BatchTransferUtils;
+
+impl BatchTransferUtils {
+    /// Floor values need one price account per item, so value-capped chains
+    /// take single transfers only
+    pub fn require_uncapped(limits: &ChainTransferLimits) -> Result<()> {
+        require!(
+            limits.max_value_per_transfer == 0 && limits.daily_value_cap == 0,
+            UniversalNftError::BatchValueCapUnsupported
+        );
+        Ok(())
+    }
+
+    /// The same checks as a single transfer, for an NFT held in `token_owner`'s
+    /// account of `mint` and not already in the batch
+    pub fn require_transferable(
+        universal_nft: &UniversalNft,
+        mint: Pubkey,
+        token_mint: Pubkey,
+        token_owner: Pubkey,
+        owner: Pubkey,
+        batched: &[Pubkey],
+    ) -> Result<()> {
+        require_keys_eq!(universal_nft.mint, mint, UniversalNftError::InvalidBatchAccounts);
+        require_keys_eq!(token_mint, mint, UniversalNftError::InvalidBatchAccounts);
+        require!(!batched.contains(&mint), UniversalNftError::InvalidBatchAccounts);
+
+        universal_nft.require_no_transfer_in_progress()?;
+        require!(!universal_nft.is_locked, UniversalNftError::NftLocked);
+        require!(!universal_nft.is_disputed, UniversalNftError::NftUnderDispute);
+        require!(!universal_nft.is_frozen, UniversalNftError::NftFrozen);
+        require!(universal_nft.owner == owner, UniversalNftError::InvalidNftOwner);
+        require!(token_owner == owner, UniversalNftError::InvalidNftOwner);
+        Ok(())
+    }
+
+    /// Hooks can't be skipped and custody needs per-NFT accounts, so
+    /// collections with bridge-out hooks or in lock mode are rejected rather
+    /// than ignored
+    pub fn require_batchable_collection(
+        hooks: Option<&CollectionHooks>,
+        collection_config: Option<&CollectionConfig>,
+        destination_chain_id: u64,
+    ) -> Result<()> {
+        if let Some(hooks) = hooks {
+            require!(
+                !hooks.hooks.iter().any(|h| h.event_mask & HookEvent::BridgeOut.mask() != 0),
+                UniversalNftError::BatchHooksUnsupported
+            );
+        }
+        if let Some(collection_config) = collection_config {
+            collection_config.require_bridge_allowed(destination_chain_id)?;
+            require!(
+                collection_config.transfer_mode == CollectionTransferMode::Burn,
+                UniversalNftError::BatchTransferModeUnsupported
+            );
+        }
+        Ok(())
+    }
+
+    /// Batch message entry for an NFT
+    pub fn item(universal_nft: &UniversalNft) -> BatchMintItem {
+        BatchMintItem {
+            token_id: universal_nft.origin_token_id.clone(),
+            name: universal_nft.name.clone(),
+            symbol: universal_nft.symbol.clone(),
+            uri: universal_nft.uri.clone(),
+            collection_mint: universal_nft.collection_mint,
+        }
+    }
+}
+
+/// Receipt tokens held by senders of in-flight outbound transfers
+pub struct ReceiptUtils;
+
+impl ReceiptUtils {
+    /// Seconds after which an unconfirmed transfer can be cancelled by its receipt holder
+    pub const TRANSFER_EXPIRY_SECONDS: i64 = 24 * 60 * 60;
+
+    /// Only a transfer still in flight is confirmed or cancelled, so its
+    /// receipt is burned once
+    pub fn require_in_flight(status: &TransferStatus) -> Result<()> {
+        require!(
+            matches!(status, TransferStatus::Initiated | TransferStatus::Processing),
+            UniversalNftError::InvalidTransferStatus
+        );
+        Ok(())
+    }
+
+    /// The holder of a transfer's receipt may cancel it once it has gone
+    /// unconfirmed past the expiry window
+    pub fn require_cancellable(transfer: &CrossChainTransfer, receipt_amount: u64, now: i64) -> Result<()> {
+        Self::require_in_flight(&transfer.status)?;
+        let expires_at = transfer.timestamp
+            .checked_add(Self::TRANSFER_EXPIRY_SECONDS)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        require!(now > expires_at, UniversalNftError::TransferNotExpired);
+        require!(receipt_amount == 1, UniversalNftError::InvalidTransferReceipt);
+        Ok(())
+    }
+}
+
+/// Recovery of NFTs left in custody by inbound messages that never executed
+pub struct OrphanUtils;
+
+impl OrphanUtils {
+    /// Require `record` to be a relayed message that was delivered but never
+    /// executed, and `message` with its sequence and sender to be what it
+    /// recorded
+    pub fn require_failed_delivery(
+        record: &RelayedMessage,
+        sequence: u64,
+        sender: &[u8; 20],
+        message: &[u8],
+    ) -> Result<()> {
+        require!(record.delivered && !record.executed, UniversalNftError::DeliveryNotFailed);
+        require!(
+            record.sequence == sequence
+                && RelayerUtils::relayed_message_hash(record.source_chain_id, sequence, sender, message)
+                    == record.message_hash,
+            UniversalNftError::OrphanProofMismatch
+        );
+        Ok(())
+    }
+
+    /// Recipient of a mint message for the NFT with `token_id`
+    pub fn recipient(message: &[u8], token_id: &str) -> Result<Pubkey> {
+        match MessageCodec::decode(message)? {
+            CrossChainMessage::MintNft { token_id: minted, recipient, .. } if minted == token_id => Ok(recipient),
+            _ => Err(UniversalNftError::OrphanProofMismatch.into()),
+        }
+    }
+}
+
+/// Claim-code escrow of NFTs minted for recipients without a wallet
+pub struct ClaimEscrowUtils;
+
+impl ClaimEscrowUtils {
+    /// An escrow either never expires or expires in the future
+    pub fn validate_expiry(expires_at: i64, now: i64) -> Result<()> {
+        require!(expires_at == 0 || expires_at > now, UniversalNftError::InvalidClaimEscrow);
+        Ok(())
+    }
+
+    /// A claim is authorized by the claim key signing, or by the escrow's
+    /// claims service signing in its place, while the escrow is unexpired
+    pub fn require_claimable(
+        escrow: &ClaimEscrow,
+        claim_signer: Option<Pubkey>,
+        claims_service: Option<Pubkey>,
+        now: i64,
+    ) -> Result<()> {
+        require!(
+            escrow.expires_at == 0 || now < escrow.expires_at,
+            UniversalNftError::ClaimEscrowExpired
+        );
+        let authorized = match (claim_signer, claims_service) {
+            (Some(signer), _) => signer == escrow.claim_key,
+            (None, Some(service)) => escrow.claims_service == Some(service),
+            (None, None) => false,
+        };
+        require!(authorized, UniversalNftError::InvalidClaimProof);
+        Ok(())
+    }
+
+    /// The depositor takes the NFT back only once an expiring escrow lapses
+    pub fn require_cancellable(escrow: &ClaimEscrow, now: i64) -> Result<()> {
+        require!(
+            escrow.expires_at != 0 && now >= escrow.expires_at,
+            UniversalNftError::ClaimEscrowNotExpired
+        );
+        Ok(())
+    }
+}
+
+/// Splits deposited royalties among a collection's creators
+pub struct RoyaltyUtils;
+
+impl RoyaltyUtils {
+    /// Check a split names at most `MAX_CREATORS` distinct creators whose
+    /// shares sum to 10000 basis points
+    pub fn validate_shares(shares: &[RoyaltyShare]) -> Result<()> {
+        require!(
+            !shares.is_empty() && shares.len() <= RoyaltyConfig::MAX_CREATORS,
+            UniversalNftError::InvalidRoyaltyShares
+        );
+        let total: u32 = shares.iter().map(|s| s.basis_points as u32).sum();
+        require!(total == 10_000, UniversalNftError::InvalidRoyaltyShares);
+        for (i, share) in shares.iter().enumerate() {
+            require!(share.basis_points > 0, UniversalNftError::InvalidRoyaltyShares);
+            require!(
+                shares[..i].iter().all(|s| s.creator != share.creator),
+                UniversalNftError::InvalidRoyaltyShares
+            );
+        }
+        Ok(())
+    }
+
+    /// Each creator's cut of `amount`. Rounding dust goes to the first
+    /// creator, so the cuts always add up to `amount`.
+    pub fn split(amount: u64, shares: &[RoyaltyShare]) -> Vec<u64> {
+        let mut cuts: Vec<u64> = shares
+            .iter()
+            .map(|s| (amount as u128 * s.basis_points as u128 / 10_000) as u64)
+            .collect();
+        let dust = amount - cuts.iter().sum::<u64>();
+        if let Some(first) = cuts.first_mut() {
+            *first += dust;
+        }
+        cuts
+    }
+
+    /// Credit a deposit to the escrow ledger. Entries of creators who have
+    /// claimed everything and are no longer in the split are dropped to make
+    /// room for new ones.
+    pub fn credit(escrow: &mut RoyaltyEscrow, shares: &[RoyaltyShare], amount: u64) -> Result<()> {
+        escrow.ledger.retain(|entry| {
+            entry.unclaimed() > 0 || shares.iter().any(|s| s.creator == entry.creator)
+        });
+
+        for (share, cut) in shares.iter().zip(Self::split(amount, shares)) {
+            let index = match escrow.ledger.iter().position(|e| e.creator == share.creator) {
+                Some(index) => index,
+                None => {
+                    require!(
+                        escrow.ledger.len() < RoyaltyEscrow::MAX_LEDGER_ENTRIES,
+                        UniversalNftError::RoyaltyLedgerFull
+                    );
+                    escrow.ledger.push(CreatorRoyalty {
+                        creator: share.creator,
+                        earned: 0,
+                        claimed: 0,
+                    });
+                    escrow.ledger.len() - 1
+                }
+            };
+            let entry = &mut escrow.ledger[index];
+            entry.earned = entry.earned
+                .checked_add(cut)
+                .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        }
+
+        escrow.total_deposited = escrow.total_deposited
+            .checked_add(amount)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        escrow.deposit_count = escrow.deposit_count
+            .checked_add(1)
+            .ok_or(UniversalNftError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// Randomness read from a Switchboard On-Demand randomness account
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SwitchboardRandomness {
+    /// Slot the randomness was committed at
+    pub seed_slot: u64,
+    /// Slot the oracle revealed it in, 0 until then
+    pub reveal_slot: u64,
+    /// Revealed value
+    pub value: [u8; 32],
+}
+
+/// Verifiable-randomness reveals of committed metadata lists
+pub struct RevealUtils;
+
+impl RevealUtils {
+    /// Switchboard On-Demand program on mainnet-beta
+    pub const SWITCHBOARD_PROGRAM_ID: Pubkey = solana_program::pubkey!("SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv");
+    /// Switchboard On-Demand program on devnet
+    pub const SWITCHBOARD_DEVNET_PROGRAM_ID: Pubkey =
+        solana_program::pubkey!("Aio4gaXjXzJNVLtzwtNVmSqGKpANtXhybbkhtAC94ji2");
+    /// Deepest list proof accepted
+    pub const MAX_PROOF_DEPTH: usize = 16;
+
+    // RandomnessAccountData layout after the 8-byte discriminator:
+    // authority, queue, seed_slothash, seed_slot, oracle, reveal_slot, value
+    const SEED_SLOT_OFFSET: usize = 8 + 32 + 32 + 32;
+    const REVEAL_SLOT_OFFSET: usize = Self::SEED_SLOT_OFFSET + 8 + 32;
+    const VALUE_OFFSET: usize = Self::REVEAL_SLOT_OFFSET + 8;
+
+    /// Switchboard program of the cluster this build targets
+    pub fn switchboard_program_id() -> Pubkey {
+        if cfg!(feature = "devnet") {
+            Self::SWITCHBOARD_DEVNET_PROGRAM_ID
+        } else {
+            Self::SWITCHBOARD_PROGRAM_ID
+        }
+    }
+
+    /// Read a randomness account, checking it belongs to Switchboard
+    pub fn read_randomness(account: &AccountInfo) -> Result<SwitchboardRandomness> {
+        require_keys_eq!(
+            *account.owner,
+            Self::switchboard_program_id(),
+            UniversalNftError::InvalidRandomnessAccount
+        );
+        let data = account.try_borrow_data()?;
+        Self::parse_randomness(&data)
+    }
+
+    /// Parse the randomness fields of a Switchboard account's data
+    pub fn parse_randomness(data: &[u8]) -> Result<SwitchboardRandomness> {
+        require!(
+            data.len() >= Self::VALUE_OFFSET + 32,
+            UniversalNftError::InvalidRandomnessAccount
+        );
+        let read_u64 = |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        Ok(SwitchboardRandomness {
+            seed_slot: read_u64(Self::SEED_SLOT_OFFSET),
+            reveal_slot: read_u64(Self::REVEAL_SLOT_OFFSET),
+            value: data[Self::VALUE_OFFSET..Self::VALUE_OFFSET + 32].try_into().unwrap(),
+        })
+    }
+
+    /// Leaf for list entry `index`
+    pub fn item_leaf(index: u32, uri: &str) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update([0u8]);
+        hasher.update(index.to_le_bytes());
+        hasher.update(uri.as_bytes());
+        hasher.finalize().into()
+    }
+
+    /// Check that entry `index` of the list with `list_root` is `uri`
+    pub fn verify_item(list_root: &[u8; 32], index: u32, uri: &str, proof: &[[u8; 32]]) -> bool {
+        proof.len() <= Self::MAX_PROOF_DEPTH
+            && MerkleUtils::verify_sorted_proof(&Self::item_leaf(index, uri), proof, list_root)
+    }
+
+    /// Entry the randomness points an NFT at, before collisions are resolved
+    pub fn target_index(randomness: &[u8; 32], mint: &Pubkey, item_count: u32) -> u32 {
+        let mut hasher = Sha256::new();
+        hasher.update(randomness);
+        hasher.update(mint.as_ref());
+        let digest: [u8; 32] = hasher.finalize().into();
+        (u64::from_le_bytes(digest[..8].try_into().unwrap()) % item_count as u64) as u32
+    }
+}
+
+/// Utilities for the attribute entries trait authorities write on NFTs
+pub struct NftAttributeUtils;
+
+impl NftAttributeUtils {
+    /// Entries of an NFT's attributes PDA, empty when the account is
+    pub fn load_entries(attributes: &AccountInfo) -> Result<Vec<NftAttribute>> {
+        if attributes.data_is_empty() {
+            return Ok(Vec::new());
+        }
+        require_keys_eq!(*attributes.owner, crate::ID, UniversalNftError::InvalidNftAttribute);
+        let data = attributes.try_borrow_data()?;
+        Ok(NftAttributes::try_deserialize(&mut &data[..])?.entries)
+    }
+}
+
+/// Utilities for NFTs equipped into other NFTs
+pub struct NestingUtils;
+
+impl NestingUtils {
+    /// An NFT's children record, `None` when the account is empty
+    pub fn load_children(children: &AccountInfo) -> Result<Option<NftChildren>> {
+        if children.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(*children.owner, crate::ID, UniversalNftError::InvalidNestedNft);
+        let data = children.try_borrow_data()?;
+        Ok(Some(NftChildren::try_deserialize(&mut &data[..])?))
+    }
+}
+
+/// Utilities for vesting positions wrapped by NFTs
+pub struct VestingUtils;
+
+impl VestingUtils {
+    /// Terms of an NFT's vesting position, `None` when the account is empty
+    pub fn load_terms(position: &AccountInfo) -> Result<Option<VestingTerms>> {
+        if position.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(*position.owner, crate::ID, UniversalNftError::VestingPositionMismatch);
+        let data = position.try_borrow_data()?;
+        Ok(Some(VestingPosition::try_deserialize(&mut &data[..])?.terms))
+    }
+}
+
+/// Universal NFT records of swap legs. A leg whose mint has no record is a
+/// plain SPL token, and its record PDA is empty.
+pub struct SwapUtils;
+
+impl SwapUtils {
+    /// Check that `holder` may hand over a leg and point its record at
+    /// `new_owner`
+    pub fn escrow_record(record: &AccountInfo, holder: &Pubkey, new_owner: &Pubkey, amount: u64) -> Result<()> {
+        if record.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*record.owner, crate::ID, UniversalNftError::InvalidSwapTerms);
+        let mut data = record.try_borrow_mut_data()?;
+        let mut nft = UniversalNft::try_deserialize(&mut &data[..])?;
+        require!(amount == 1, UniversalNftError::InvalidSwapTerms);
+        nft.require_no_transfer_in_progress()?;
+        require!(!nft.is_locked, UniversalNftError::NftLocked);
+        require!(!nft.is_disputed, UniversalNftError::NftUnderDispute);
+        require!(!nft.is_frozen, UniversalNftError::NftFrozen);
+        require_keys_eq!(nft.owner, *holder, UniversalNftError::InvalidNftOwner);
+        nft.owner = *new_owner;
+        nft.try_serialize(&mut &mut data[..])?;
+        Ok(())
+    }
+
+    /// A leg's universal NFT record, or None for a plain SPL token
+    pub fn read_record(record: &AccountInfo) -> Result<Option<UniversalNft>> {
+        if record.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(*record.owner, crate::ID, UniversalNftError::InvalidSwapTerms);
+        let data = record.try_borrow_data()?;
+        Ok(Some(UniversalNft::try_deserialize(&mut &data[..])?))
+    }
+
+    /// Check a hashed-timelock secret against its SHA-256 hashlock
+    pub fn verify_secret(secret: &[u8; 32], hashlock: &[u8; 32]) -> Result<()> {
+        let digest: [u8; 32] = Sha256::digest(secret).into();
+        require!(digest == *hashlock, UniversalNftError::InvalidSwapSecret);
+        Ok(())
+    }
+
+    /// Point an escrowed leg's record at the wallet it is released to
+    pub fn release_record(record: &AccountInfo, new_owner: &Pubkey) -> Result<()> {
+        if record.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*record.owner, crate::ID, UniversalNftError::InvalidSwapTerms);
+        let mut data = record.try_borrow_mut_data()?;
+        let mut nft = UniversalNft::try_deserialize(&mut &data[..])?;
+        nft.owner = *new_owner;
+        nft.try_serialize(&mut &mut data[..])?;
+        Ok(())
+    }
+}
+
+/// Bubblegum `MetadataArgs`, borsh-encoded field for field
+#[derive(AnchorSerialize)]
+struct BubblegumMetadataArgs {
+    name: String,
+    symbol: String,
+    uri: String,
+    seller_fee_basis_points: u16,
+    primary_sale_happened: bool,
+    is_mutable: bool,
+    edition_nonce: Option<u8>,
+    /// `TokenStandard`, 0 is non-fungible
+    token_standard: Option<u8>,
+    /// `Collection { verified, key }`
+    collection: Option<(bool, Pubkey)>,
+    /// `Uses`, never set
+    uses: Option<u8>,
+    /// `TokenProgramVersion`, 0 is the original SPL token program
+    token_program_version: u8,
+    /// `Creator { address, verified, share }`
+    creators: Vec<(Pubkey, bool, u8)>,
+}
+
+/// Proof-of-attendance drops minted as Bubblegum compressed NFTs
+pub struct AttendanceUtils;
+
+impl AttendanceUtils {
+    /// Bubblegum program, the same on every cluster
+    pub const BUBBLEGUM_PROGRAM_ID: Pubkey = solana_program::pubkey!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+    /// SPL Account Compression program
+    pub const COMPRESSION_PROGRAM_ID: Pubkey =
+        solana_program::pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+    /// SPL Noop program Bubblegum logs leaves through
+    pub const NOOP_PROGRAM_ID: Pubkey = solana_program::pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+    /// Deepest allowlist proof accepted
+    pub const MAX_PROOF_DEPTH: usize = 20;
+
+    /// Anchor discriminator of Bubblegum's `mint_v1`
+    const MINT_V1_DISCRIMINATOR: [u8; 8] = [145, 98, 192, 118, 184, 147, 118, 104];
+    // TreeConfig layout after the 8-byte discriminator:
+    // tree_creator, tree_delegate, total_mint_capacity, num_minted
+    const TREE_DELEGATE_OFFSET: usize = 8 + 32;
+    const MINT_CAPACITY_OFFSET: usize = Self::TREE_DELEGATE_OFFSET + 32;
+    const NUM_MINTED_OFFSET: usize = Self::MINT_CAPACITY_OFFSET + 8;
+
+    /// Allowlist leaf for entry `index`, held by `wallet`
+    pub fn allowlist_leaf(index: u32, wallet: &Pubkey) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update([0u8]);
+        hasher.update(index.to_le_bytes());
+        hasher.update(wallet.as_ref());
+        hasher.finalize().into()
+    }
+
+    /// Check that entry `index` of the allowlist with `root` is `wallet`
+    pub fn verify_allowlist(root: &[u8; 32], index: u32, wallet: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        proof.len() <= Self::MAX_PROOF_DEPTH
+            && MerkleUtils::verify_sorted_proof(&Self::allowlist_leaf(index, wallet), proof, root)
+    }
+
+    /// Delegate and remaining mint capacity of a Bubblegum tree config
+    pub fn parse_tree_config(data: &[u8]) -> Result<(Pubkey, u64)> {
+        require!(
+            data.len() >= Self::NUM_MINTED_OFFSET + 8,
+            UniversalNftError::InvalidAttendanceTree
+        );
+        let read_u64 = |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        let delegate = Pubkey::new_from_array(
+            data[Self::TREE_DELEGATE_OFFSET..Self::MINT_CAPACITY_OFFSET].try_into().unwrap(),
+        );
+        let remaining = read_u64(Self::MINT_CAPACITY_OFFSET).saturating_sub(read_u64(Self::NUM_MINTED_OFFSET));
+        Ok((delegate, remaining))
+    }
+
+    /// Bubblegum `mint_v1` of a compressed NFT to `leaf_owner`, signed by the
+    /// tree's delegate
+    #[allow(clippy::too_many_arguments)]
+    pub fn mint_v1_instruction(
+        tree_config: Pubkey,
+        merkle_tree: Pubkey,
+        leaf_owner: Pubkey,
+        payer: Pubkey,
+        tree_delegate: Pubkey,
+        name: String,
+        symbol: String,
+        uri: String,
+        collection_mint: Pubkey,
+    ) -> Result<solana_program::instruction::Instruction> {
+        use solana_program::instruction::{AccountMeta, Instruction};
+
+        let args = BubblegumMetadataArgs {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            primary_sale_happened: true,
+            is_mutable: false,
+            edition_nonce: None,
+            token_standard: Some(0),
+            collection: Some((false, collection_mint)),
+            uses: None,
+            token_program_version: 0,
+            creators: Vec::new(),
+        };
+        let mut data = Self::MINT_V1_DISCRIMINATOR.to_vec();
+        args.serialize(&mut data).map_err(|_| UniversalNftError::InvalidAttendanceDrop)?;
+
+        Ok(Instruction {
+            program_id: Self::BUBBLEGUM_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(tree_config, false),
+                AccountMeta::new_readonly(leaf_owner, false),
+                AccountMeta::new_readonly(leaf_owner, false),
+                AccountMeta::new(merkle_tree, false),
+                AccountMeta::new(payer, true),
+                AccountMeta::new_readonly(tree_delegate, true),
+                AccountMeta::new_readonly(Self::NOOP_PROGRAM_ID, false),
+                AccountMeta::new_readonly(Self::COMPRESSION_PROGRAM_ID, false),
+                AccountMeta::new_readonly(solana_program::system_program::ID, false),
+            ],
+            data,
+        })
+    }
+}
+
+/// Utilities for sampling chain corridors as outbound transfers end
+pub struct CorridorUtils;
+
+impl CorridorUtils {
+    /// Record how `transfer` ended in its destination's `ChainMetrics`,
+    /// measuring latency from when it started. Passing no metrics account
+    /// records nothing.
+    #[cfg(feature = "analytics")]
+    pub fn record_outbound(
+        transfer: &CrossChainTransfer,
+        succeeded: bool,
+        chain_metrics: Option<&AccountInfo>,
+    ) -> Result<()> {
+        use crate::analytics::{ChainMetrics, ChainStatus};
+
+        let chain_metrics = match chain_metrics {
+            Some(chain_metrics) => chain_metrics,
+            None => return Ok(()),
+        };
+        let (expected, _) = Pubkey::find_program_address(
+            &[b"chain_metrics", transfer.destination_chain_id.to_le_bytes().as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(chain_metrics.key(), expected, UniversalNftError::InvalidChainMetrics);
+        require_keys_eq!(*chain_metrics.owner, crate::ID, UniversalNftError::InvalidChainMetrics);
+
+        let mut metrics = {
+            let data = chain_metrics.try_borrow_data()?;
+            ChainMetrics::try_deserialize(&mut &data[..])?
+        };
+        let now = Clock::get()?.unix_timestamp;
+        let latency_s = now.saturating_sub(transfer.timestamp).clamp(0, u32::MAX as i64) as u32;
+        if let Some(status) = metrics.record_outbound(latency_s, succeeded, now) {
+            emit!(crate::events::CorridorStatusChanged {
+                source_chain_id: DomainUtils::SOLANA_CHAIN_ID,
+                destination_chain_id: transfer.destination_chain_id,
+                degraded: status == ChainStatus::Degraded,
+                latency_ema_s: metrics.outbound.latency_ema_s,
+                failure_rate_bps: metrics.outbound.failure_rate_bps,
+                timestamp: now,
+            });
+        }
+
+        let mut data = chain_metrics.try_borrow_mut_data()?;
+        metrics.try_serialize(&mut &mut data[..])
+    }
+
+    #[cfg(not(feature = "analytics"))]
+    pub fn record_outbound(
+        _transfer: &CrossChainTransfer,
+        _succeeded: bool,
+        chain_metrics: Option<&AccountInfo>,
+    ) -> Result<()> {
+        require!(chain_metrics.is_none(), UniversalNftError::FeatureDisabled);
+        Ok(())
+    }
+}
+
+/// Utilities for corridor pauses and the admin messages that set them
+pub struct CorridorPauseUtils;
+
+impl CorridorPauseUtils {
+    /// Fail with `CorridorPaused` if `direction` is paused. The PDA is
+    /// address-checked by the caller's seeds; an uninitialized PDA means the
+    /// corridor was never paused.
+    pub fn require_open(pause_account: &AccountInfo, direction: CorridorDirection) -> Result<()> {
+        if pause_account.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*pause_account.owner, crate::ID, UniversalNftError::InvalidCorridorPause);
+
+        let data = pause_account.try_borrow_data()?;
+        let pause = CorridorPause::try_deserialize(&mut &data[..])?;
+        require!(!pause.is_paused(direction), UniversalNftError::CorridorPaused);
+        Ok(())
+    }
+
+    /// Hash the TSS signs for an admin message from `source_chain_id`
+    pub fn signing_hash(source_chain_id: u64, nonce: u64, message: &[u8]) -> [u8; 32] {
+        let payload_hash: [u8; 32] = Sha256::digest(message).into();
+        DomainUtils::domain_message_hash(&DomainUtils::inbound_domain(source_chain_id), nonce, &payload_hash)
+    }
+}
+
+/// Utilities for keeping `Leaderboard`s current as mints and bridges happen
+pub struct LeaderboardUtils;
+
+impl LeaderboardUtils {
+    /// Rank `user` on a user leaderboard by the count their metrics hold for
+    /// the board's activity. Passing no leaderboard or no metrics updates
+    /// nothing.
+    #[cfg(feature = "analytics")]
+    pub fn record_user(
+        kind: LeaderboardKind,
+        user: &Pubkey,
+        user_metrics: Option<&AccountInfo>,
+        leaderboard: Option<&AccountInfo>,
+    ) -> Result<()> {
+        use crate::analytics::UserMetrics;
+
+        let (user_metrics, leaderboard) = match (user_metrics, leaderboard) {
+            (Some(user_metrics), Some(leaderboard)) => (user_metrics, leaderboard),
+            _ => return Ok(()),
+        };
+        require_keys_eq!(*user_metrics.owner, crate::ID, UniversalNftError::InvalidPointsAccount);
+        let metrics = {
+            let data = user_metrics.try_borrow_data()?;
+            UserMetrics::try_deserialize(&mut &data[..])?
+        };
+        require_keys_eq!(metrics.user, *user, UniversalNftError::InvalidPointsAccount);
+
+        let score = match kind {
+            LeaderboardKind::Minters => metrics.nfts_minted as u64,
+            LeaderboardKind::Bridgers => metrics.transfers_initiated as u64,
+            LeaderboardKind::BridgedCollections => return err!(UniversalNftError::InvalidLeaderboard),
+        };
+        Self::update(kind, leaderboard, |board, now| board.record(*user, score, now))
+    }
+
+    /// Count an outbound transfer of `collection_mint` on the bridged
+    /// collections leaderboard. NFTs outside a collection aren't ranked.
+    #[cfg(feature = "analytics")]
+    pub fn record_collection_bridge(
+        collection_mint: Option<Pubkey>,
+        leaderboard: Option<&AccountInfo>,
+    ) -> Result<()> {
+        match (collection_mint, leaderboard) {
+            (Some(collection_mint), Some(leaderboard)) => Self::update(
+                LeaderboardKind::BridgedCollections,
+                leaderboard,
+                |board, now| board.increment(collection_mint, now),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    #[cfg(feature = "analytics")]
+    fn update(
+        kind: LeaderboardKind,
+        leaderboard: &AccountInfo,
+        apply: impl FnOnce(&mut crate::state::Leaderboard, i64),
+    ) -> Result<()> {
+        use crate::state::Leaderboard;
+
+        let (expected, _) = Pubkey::find_program_address(&[b"leaderboard", &[kind.seed()]], &crate::ID);
+        require_keys_eq!(leaderboard.key(), expected, UniversalNftError::InvalidLeaderboard);
+        require_keys_eq!(*leaderboard.owner, crate::ID, UniversalNftError::InvalidLeaderboard);
+
+        let mut board = {
+            let data = leaderboard.try_borrow_data()?;
+            Leaderboard::try_deserialize(&mut &data[..])?
+        };
+        apply(&mut board, Clock::get()?.unix_timestamp);
+
+        let mut data = leaderboard.try_borrow_mut_data()?;
+        board.try_serialize(&mut &mut data[..])
+    }
+
+    #[cfg(not(feature = "analytics"))]
+    pub fn record_user(
+        _kind: LeaderboardKind,
+        _user: &Pubkey,
+        _user_metrics: Option<&AccountInfo>,
+        leaderboard: Option<&AccountInfo>,
+    ) -> Result<()> {
+        require!(leaderboard.is_none(), UniversalNftError::FeatureDisabled);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "analytics"))]
+    pub fn record_collection_bridge(
+        _collection_mint: Option<Pubkey>,
+        leaderboard: Option<&AccountInfo>,
+    ) -> Result<()> {
+        require!(leaderboard.is_none(), UniversalNftError::FeatureDisabled);
+        Ok(())
+    }
+}
+
+/// Utilities for attributing operations to a `UsageSegment`
+pub struct UsageSegmentUtils;
+
+impl UsageSegmentUtils {
+    /// Record a mint, or an outbound transfer when `bridged_value` is set,
+    /// in the segment the caller passed. The segment must belong to the
+    /// NFT's collection; its integration ID is whatever the caller opened it
+    /// for. Passing no segment records nothing.
+    #[cfg(feature = "analytics")]
+    pub fn record(
+        collection_mint: Option<Pubkey>,
+        bridged_value: Option<u64>,
+        usage_segment: Option<&AccountInfo>,
+    ) -> Result<()> {
+        use crate::analytics::UsageSegment;
+
+        let usage_segment = match usage_segment {
+            Some(usage_segment) => usage_segment,
+            None => return Ok(()),
+        };
+        require_keys_eq!(*usage_segment.owner, crate::ID, UniversalNftError::InvalidUsageSegment);
+        let mut segment = {
+            let data = usage_segment.try_borrow_data()?;
+            UsageSegment::try_deserialize(&mut &data[..])?
+        };
+        require_keys_eq!(
+            segment.collection_mint,
+            collection_mint.unwrap_or_default(),
+            UniversalNftError::InvalidUsageSegment
+        );
+        let expected = Pubkey::create_program_address(
+            &[
+                b"usage_segment",
+                segment.collection_mint.as_ref(),
+                segment.integration_id.to_le_bytes().as_ref(),
+                &[segment.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| UniversalNftError::InvalidUsageSegment)?;
+        require_keys_eq!(usage_segment.key(), expected, UniversalNftError::InvalidUsageSegment);
+
+        let now = Clock::get()?.unix_timestamp;
+        match bridged_value {
+            Some(value) => segment.record_bridge(value, now)?,
+            None => segment.record_mint(now)?,
+        }
+
+        let mut data = usage_segment.try_borrow_mut_data()?;
+        segment.try_serialize(&mut &mut data[..])
+    }
+
+    #[cfg(not(feature = "analytics"))]
+    pub fn record(
+        _collection_mint: Option<Pubkey>,
+        _bridged_value: Option<u64>,
+        usage_segment: Option<&AccountInfo>,
+    ) -> Result<()> {
+        require!(usage_segment.is_none(), UniversalNftError::FeatureDisabled);
+        Ok(())
+    }
+}
+
+/// Utilities for the non-transferable activity points kept in `UserMetrics`
+pub struct PointsUtils;
+
+impl PointsUtils {
+    /// Record an activity in `user`'s metrics and credit its points.
+    /// Users who have not opened their metrics account pass neither account
+    /// and accrue nothing; without the points config the activity is counted
+    /// but earns no points.
+    #[cfg(feature = "analytics")]
+    pub fn record(
+        activity: PointsActivity,
+        user: &Pubkey,
+        user_metrics: Option<&AccountInfo>,
+        points_config: Option<&AccountInfo>,
+    ) -> Result<()> {
+        use crate::analytics::{PointsConfig, UserMetrics};
+
+        let user_metrics = match user_metrics {
+            Some(user_metrics) => user_metrics,
+            None => return Ok(()),
+        };
+        let (expected, _) = Pubkey::find_program_address(&[b"user_metrics", user.as_ref()], &crate::ID);
+        require_keys_eq!(user_metrics.key(), expected, UniversalNftError::InvalidPointsAccount);
+        require_keys_eq!(*user_metrics.owner, crate::ID, UniversalNftError::InvalidPointsAccount);
+
+        let points = match points_config {
+            Some(points_config) => {
+                let (expected, _) = Pubkey::find_program_address(&[b"points_config"], &crate::ID);
+                require_keys_eq!(points_config.key(), expected, UniversalNftError::InvalidPointsAccount);
+                require_keys_eq!(*points_config.owner, crate::ID, UniversalNftError::InvalidPointsAccount);
+                let data = points_config.try_borrow_data()?;
+                let config = PointsConfig::try_deserialize(&mut &data[..])?;
+                config.weight(activity)
+            }
+            None => 0,
+        };
+
+        let mut metrics = {
+            let data = user_metrics.try_borrow_data()?;
+            UserMetrics::try_deserialize(&mut &data[..])?
+        };
+        match activity {
+            PointsActivity::Mint => metrics.record_nft_mint()?,
+            PointsActivity::Bridge => metrics.record_transfer()?,
+            PointsActivity::Governance => {}
+        }
+        metrics.award_points(points)?;
+
+        let mut data = user_metrics.try_borrow_mut_data()?;
+        metrics.try_serialize(&mut &mut data[..])?;
+
+        if points > 0 {
+            emit!(crate::events::PointsAwarded {
+                user: *user,
+                activity,
+                points,
+                balance: metrics.points,
+                timestamp: metrics.last_interaction,
+            });
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "analytics"))]
+    pub fn record(
+        _activity: PointsActivity,
+        _user: &Pubkey,
+        user_metrics: Option<&AccountInfo>,
+        points_config: Option<&AccountInfo>,
+    ) -> Result<()> {
+        require!(
+            user_metrics.is_none() && points_config.is_none(),
+            UniversalNftError::FeatureDisabled
+        );
+        Ok(())
+    }
+}
+
+/// Utilities for learning users' behavioral baselines in `UserMetrics`
+pub struct BaselineUtils;
+
+impl BaselineUtils {
+    /// Learn from `user`'s outbound transfer of `value` to `chain_id`.
+    /// Users who have not opened their metrics account pass none and have
+    /// no baseline.
+    #[cfg(feature = "analytics")]
+    pub fn record_transfer(
+        user: &Pubkey,
+        value: u64,
+        chain_id: u64,
+        user_metrics: Option<&AccountInfo>,
+    ) -> Result<()> {
+        use crate::analytics::UserMetrics;
+
+        let user_metrics = match user_metrics {
+            Some(user_metrics) => user_metrics,
+            None => return Ok(()),
+        };
+        require_keys_eq!(*user_metrics.owner, crate::ID, UniversalNftError::InvalidPointsAccount);
+        let mut metrics = {
+            let data = user_metrics.try_borrow_data()?;
+            UserMetrics::try_deserialize(&mut &data[..])?
+        };
+        require_keys_eq!(metrics.user, *user, UniversalNftError::InvalidPointsAccount);
+        metrics.baseline.record(value, chain_id, Clock::get()?.unix_timestamp);
+
+        let mut data = user_metrics.try_borrow_mut_data()?;
+        metrics.try_serialize(&mut &mut data[..])
+    }
+
+    #[cfg(not(feature = "analytics"))]
+    pub fn record_transfer(
+        _user: &Pubkey,
+        _value: u64,
+        _chain_id: u64,
+        user_metrics: Option<&AccountInfo>,
+    ) -> Result<()> {
+        require!(user_metrics.is_none(), UniversalNftError::FeatureDisabled);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{
+        AttendanceDrop, BehaviorBaseline, BridgeRoute, ChainKind, ChainRisk, ChainRiskParams, ChainRiskRegistry,
+        ChildBridgeMode, CollectionBurnStats, CollectionFloor, ComplianceConfig, CorridorStats, CrossChainSwap,
+        CrossChainSwapStatus, EquippedChild, Erc2981Royalty, EvolutionRecipe, EvolutionRecipeParams, InscriptionRef,
+        Leaderboard, LeaderboardEntry, Loan, LoanTerms, MessageLayer, PairRiskOverride, RecipeInput, RegisteredChain,
+        RemoteAsset, RevealConfig, RuleEnforcement, SatelliteKind, SatelliteProgram, ScheduledTransfer,
+        SecurityEventKind, StakeDiscountTier, Swap, SwapAsset, Ticket, UserBurnStats,
+    };
+
+    #[test]
+    fn test_generate_token_id() {
+        let mint = Pubkey::new_unique();
+        let block_number = 12345;
+        let timestamp = 1640995200; // Example timestamp
+        
+        let token_id = SignatureUtils::generate_token_id(&mint, block_number, timestamp);
+        assert!(!token_id.is_empty());
+        assert!(token_id.len() > 10);
+    }
+
+    #[test]
+    fn test_validate_name() {
+        assert!(MetadataUtils::validate_name("My NFT").is_ok());
+        assert!(MetadataUtils::validate_name("").is_err());
+        assert!(MetadataUtils::validate_name(&"a".repeat(50)).is_err());
+    }
+
+    #[test]
+    fn test_validate_symbol() {
+        assert!(MetadataUtils::validate_symbol("NFT").is_ok());
+        assert!(MetadataUtils::validate_symbol("").is_err());
+        assert!(MetadataUtils::validate_symbol("NFT!@#").is_err());
+    }
+
+    #[test]
+    fn test_validate_uri() {
+        assert!(MetadataUtils::validate_uri("https://example.com/metadata.json").is_ok());
+        assert!(MetadataUtils::validate_uri("ipfs://QmHash").is_ok());
+        assert!(MetadataUtils::validate_uri("invalid://uri").is_err());
+        assert!(MetadataUtils::validate_uri("").is_err());
+    }
+
+    #[test]
+    fn test_merkle_root() {
+        let owner = Pubkey::new_unique();
+        let leaves: Vec<[u8; 32]> = (0..3u8)
+            .map(|i| MerkleUtils::hash_account_leaf(&Pubkey::new_unique(), &owner, &[i; 8]))
+            .collect();
+
+        assert_eq!(MerkleUtils::compute_root(&[]), [0u8; 32]);
+        assert_eq!(MerkleUtils::compute_root(&leaves[..1]), leaves[0]);
+
+        let expected = MerkleUtils::hash_node(
+            &MerkleUtils::hash_node(&leaves[0], &leaves[1]),
+            &leaves[2],
+        );
+        assert_eq!(MerkleUtils::compute_root(&leaves), expected);
+
+        let mut reordered = leaves.clone();
+        reordered.swap(0, 1);
+        assert_ne!(MerkleUtils::compute_root(&reordered), expected);
+    }
+
+    #[test]
+    fn test_validate_bitcoin_address() {
+        assert!(CrossChainUtils::validate_bitcoin_address(b"1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa").is_ok());
+        assert!(CrossChainUtils::validate_bitcoin_address(
+            b"bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"
+        ).is_ok());
+        assert!(CrossChainUtils::validate_bitcoin_address(b"0x1234").is_err());
+        assert!(CrossChainUtils::validate_bitcoin_address(b"1A1zP1eP5QGefi2DMPTfTL5SLmv7Div0Na").is_err());
+        assert!(CrossChainUtils::validate_bitcoin_address(&[0xff; 34]).is_err());
+    }
+
+    #[test]
+    fn test_validate_address() {
+        assert!(CrossChainUtils::validate_address(&AddressFormat::Evm, &[1u8; 20]).is_ok());
+        assert!(CrossChainUtils::validate_address(&AddressFormat::Evm, &[1u8; 32]).is_err());
+        assert!(CrossChainUtils::validate_address(&AddressFormat::Sui, &[1u8; 32]).is_ok());
+        assert!(CrossChainUtils::validate_address(&AddressFormat::Sui, &[0u8; 32]).is_err());
+        assert!(CrossChainUtils::validate_address(&AddressFormat::Bitcoin, &[1u8; 20]).is_err());
+
+        let mut ton = vec![0x11, 0x00];
+        ton.extend_from_slice(&[7u8; 32]);
+        let crc = CrossChainUtils::crc16_xmodem(&ton);
+        ton.extend_from_slice(&crc.to_be_bytes());
+        assert!(CrossChainUtils::validate_address(&AddressFormat::Ton, &ton).is_ok());
+        ton[35] ^= 1;
+        assert!(CrossChainUtils::validate_address(&AddressFormat::Ton, &ton).is_err());
+
+        assert_eq!(CrossChainUtils::crc16_xmodem(b"123456789"), 0x31c3);
+    }
+
+    #[test]
+    fn test_encode_recipient() {
+        let evm = CrossChainUtils::encode_recipient(&AddressFormat::Evm, &[1u8; 20]).unwrap();
+        assert_eq!(evm.to_bytes()[..12], [0u8; 12]);
+        assert_eq!(evm.to_bytes()[12..], [1u8; 20]);
+        assert!(CrossChainUtils::encode_recipient(&AddressFormat::Evm, &[1u8; 32]).is_err());
+
+        let sui = CrossChainUtils::encode_recipient(&AddressFormat::Sui, &[2u8; 32]).unwrap();
+        assert_eq!(sui.to_bytes(), [2u8; 32]);
+
+        let btc = b"bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq";
+        let encoded = CrossChainUtils::encode_recipient(&AddressFormat::Bitcoin, btc).unwrap();
+        let expected: [u8; 32] = Sha256::digest(btc).into();
+        assert_eq!(encoded.to_bytes(), expected);
+    }
+
+    #[test]
+    fn test_validate_evm_destination() {
+        let counterpart = [9u8; 20];
+        assert!(CrossChainUtils::validate_evm_destination(&[1u8; 20], &counterpart).is_ok());
+        assert!(CrossChainUtils::validate_evm_destination(&[1u8; 32], &counterpart).is_err());
+        assert!(CrossChainUtils::validate_evm_destination(&[0u8; 20], &counterpart).is_err());
+        assert!(CrossChainUtils::validate_evm_destination(&[0xffu8; 20], &counterpart).is_err());
+        assert!(CrossChainUtils::validate_evm_destination(&counterpart, &counterpart).is_err());
+        assert!(CrossChainUtils::validate_evm_destination(&counterpart, &[0u8; 20]).is_ok());
+
+        let mut dead = [0u8; 20];
+        dead[18] = 0xde;
+        dead[19] = 0xad;
+        assert!(CrossChainUtils::validate_evm_destination(&dead, &counterpart).is_err());
+
+        let mut precompile = [0u8; 20];
+        precompile[19] = 0x01;
+        assert!(CrossChainUtils::validate_evm_destination(&precompile, &counterpart).is_err());
+    }
+
+    #[test]
+    fn test_gas_fee() {
+        assert_eq!(GasFeeUtils::sol_gas_fee(21_000).unwrap(), 21_000);
+        assert_eq!(GasFeeUtils::calculate_gas_fee(100_000, 30).unwrap(), 3_000_000);
+        assert!(GasFeeUtils::calculate_gas_fee(100_000, 0).is_err());
+        assert!(GasFeeUtils::calculate_gas_fee(u64::MAX, 2).is_err());
+    }
+
+    #[test]
+    fn test_relayer_slash_amount() {
+        assert_eq!(RelayerUtils::slash_amount(1_000_000, 1_000).unwrap(), 100_000);
+        assert_eq!(RelayerUtils::slash_amount(1_000_000, 10_000).unwrap(), 1_000_000);
+        assert_eq!(RelayerUtils::slash_amount(u64::MAX, 10_000).unwrap(), u64::MAX);
+        assert!(RelayerUtils::slash_amount(1_000_000, 10_001).is_err());
+    }
+
+    #[test]
+    fn test_relayed_message_hash() {
+        let sender = [7u8; 20];
+        let hash = RelayerUtils::relayed_message_hash(7001, 5, &sender, b"message");
+        assert_eq!(hash, RelayerUtils::relayed_message_hash(7001, 5, &sender, b"message"));
+        assert_ne!(hash, RelayerUtils::relayed_message_hash(1, 5, &sender, b"message"));
+        assert_ne!(hash, RelayerUtils::relayed_message_hash(7001, 6, &sender, b"message"));
+        assert_ne!(hash, RelayerUtils::relayed_message_hash(7001, 5, &[8u8; 20], b"message"));
+    }
+
+    #[test]
+    fn test_sequence_in_order() {
+        assert_eq!(SequenceUtils::accept(0, 0, 0).unwrap(), (1, 0));
+        assert_eq!(SequenceUtils::accept(1, 0, 1).unwrap(), (2, 0));
+        assert!(SequenceUtils::accept(2, 0, 1).is_err());
+    }
+
+    #[test]
+    fn test_sequence_reordering() {
+        // 2 and 1 arrive before 0; all three release once 0 lands
+        let (next, pending) = SequenceUtils::accept(0, 0, 2).unwrap();
+        assert_eq!((next, pending), (0, 0b100));
+        let (next, pending) = SequenceUtils::accept(next, pending, 1).unwrap();
+        assert_eq!((next, pending), (0, 0b110));
+        assert!(SequenceUtils::accept(next, pending, 1).is_err());
+        assert_eq!(SequenceUtils::accept(next, pending, 0).unwrap(), (3, 0));
+    }
+
+    #[test]
+    fn test_sequence_gap_limit() {
+        assert!(SequenceUtils::accept(0, 0, SequenceUtils::MAX_REORDER_WINDOW).is_err());
+        let (next, pending) = SequenceUtils::accept(0, 0, SequenceUtils::MAX_REORDER_WINDOW - 1).unwrap();
+        assert_eq!((next, pending), (0, 1u64 << 63));
+
+        // Filling the whole window releases all of it
+        let pending = !1u64;
+        assert_eq!(SequenceUtils::accept(0, pending, 0).unwrap(), (64, 0));
+    }
+
+    #[test]
+    fn test_domain_message_hash_binding() {
+        let payload = [3u8; 32];
+        let domain = DomainUtils::inbound_domain(7001);
+        let hash = DomainUtils::domain_message_hash(&domain, 1, &payload);
+        assert_eq!(hash, DomainUtils::domain_message_hash(&domain, 1, &payload));
+        assert_ne!(hash, DomainUtils::domain_message_hash(&domain, 2, &payload));
+
+        // Every domain field changes the hash
+        let other_cluster = SigningDomain { genesis_hash: DomainUtils::DEVNET_GENESIS_HASH, ..domain };
+        let other_cluster = if other_cluster == domain {
+            SigningDomain { genesis_hash: DomainUtils::MAINNET_GENESIS_HASH, ..domain }
+        } else {
+            other_cluster
+        };
+        let other_program = SigningDomain { program_id: Pubkey::new_unique(), ..domain };
+        let other_source = SigningDomain { source_chain_id: 1, ..domain };
+        let other_destination = SigningDomain { destination_chain_id: 7000, ..domain };
+        for other in [other_cluster, other_program, other_source, other_destination] {
+            assert_ne!(hash, DomainUtils::domain_message_hash(&other, 1, &payload));
+        }
+    }
+
+    #[test]
+    fn test_event_proof_root() {
+        let sender = [9u8; 20];
+        let leaves: Vec<[u8; 32]> = (0..4u8)
+            .map(|i| LightClientUtils::event_leaf(1, &sender, &[i]))
+            .collect();
+        let node = |l: &[u8; 32], r: &[u8; 32]| -> [u8; 32] {
+            let mut hasher = Sha256::new();
+            hasher.update([1u8]);
+            hasher.update(l);
+            hasher.update(r);
+            hasher.finalize().into()
+        };
+        let left = node(&leaves[0], &leaves[1]);
+        let right = node(&leaves[2], &leaves[3]);
+        let root = node(&left, &right);
+
+        let proof = [leaves[3], left];
+        assert_eq!(LightClientUtils::compute_root(&leaves[2], &proof, 2).unwrap(), root);
+        assert_ne!(LightClientUtils::compute_root(&leaves[2], &proof, 3).unwrap(), root);
+        assert_ne!(LightClientUtils::compute_root(&leaves[1], &proof, 2).unwrap(), root);
+
+        // Index must fit in the proof depth
+        assert!(LightClientUtils::compute_root(&leaves[2], &proof, 4).is_err());
+    }
+
+    #[test]
+    fn test_volume_caps() {
+        let limits = ChainTransferLimits {
+            max_value_per_transfer: 100,
+            daily_value_cap: 250,
+            daily_transfer_cap: 3,
+            enforcement: RuleEnforcement::Enforce,
+        };
+        let start = 1_000_000;
+
+        assert!(VolumeCapUtils::record(&limits, 1, RollingVolume::default(), 101, 1, start).is_err());
+
+        let volume = VolumeCapUtils::record(&limits, 1, RollingVolume::default(), 100, 1, start).unwrap();
+        assert_eq!(volume.window_start, start);
+        let volume = VolumeCapUtils::record(&limits, 1, volume, 100, 1, start + 10).unwrap();
+        assert!(VolumeCapUtils::record(&limits, 1, volume, 100, 1, start + 20).is_err());
+        let volume = VolumeCapUtils::record(&limits, 1, volume, 50, 1, start + 20).unwrap();
+        assert!(VolumeCapUtils::record(&limits, 1, volume, 0, 1, start + 30).is_err());
+
+        // Half a day into the next window, half of the previous volume still counts
+        let half = start + VolumeCapUtils::WINDOW_SECONDS + VolumeCapUtils::WINDOW_SECONDS / 2;
+        let volume = VolumeCapUtils::record(&limits, 1, volume, 100, 1, half).unwrap();
+        assert_eq!(volume.previous_value, 250);
+        assert_eq!(volume.current_value, 100);
+        assert!(VolumeCapUtils::record(&limits, 1, volume, 30, 0, half).is_err());
+
+        // After two idle windows everything has expired
+        let later = start + 4 * VolumeCapUtils::WINDOW_SECONDS;
+        let volume = VolumeCapUtils::record(&limits, 1, volume, 100, 1, later).unwrap();
+        assert_eq!((volume.previous_value, volume.current_value), (0, 100));
+    }
+
+    #[test]
+    fn test_volume_caps_shadow_mode() {
+        let mut limits = ChainTransferLimits {
+            max_value_per_transfer: 100,
+            daily_value_cap: 150,
+            daily_transfer_cap: 0,
+            enforcement: RuleEnforcement::Shadow,
+        };
+        let volume = RollingVolume::default();
+
+        // Breaches are reported but the transfer is still counted
+        assert!(matches!(
+            VolumeCapUtils::breach(&limits, &volume, 101, 1),
+            Some(UniversalNftError::TransferValueCapExceeded)
+        ));
+        let volume = VolumeCapUtils::record(&limits, 1, volume, 101, 1, 1).unwrap();
+        let volume = VolumeCapUtils::record(&limits, 1, volume, 100, 1, 2).unwrap();
+        assert_eq!(volume.current_value, 201);
+        assert!(matches!(
+            VolumeCapUtils::breach(&limits, &volume, 0, 2),
+            Some(UniversalNftError::DailyVolumeCapExceeded)
+        ));
+
+        // Promoting the rule starts rejecting
+        limits.enforcement = RuleEnforcement::Enforce;
+        assert!(VolumeCapUtils::record(&limits, 1, volume, 0, 1, 3).is_err());
+        limits.enforcement = RuleEnforcement::Off;
+        assert!(VolumeCapUtils::record(&limits, 1, volume, 500, 1, 3).is_ok());
+    }
+
+    #[test]
+    fn test_volume_caps_unlimited() {
+        let limits = ChainTransferLimits::default();
+        let volume = VolumeCapUtils::record(&limits, 1, RollingVolume::default(), u64::MAX, 1, 1).unwrap();
+        assert_eq!(volume.current_value, u64::MAX);
+    }
+
+    #[test]
+    fn test_allowlist_proof() {
+        let wallets: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = wallets.iter().map(MintPhaseUtils::allowlist_leaf).collect();
+        let node = |a: &[u8; 32], b: &[u8; 32]| -> [u8; 32] {
+            let (l, r) = if a <= b { (a, b) } else { (b, a) };
+            let mut hasher = Sha256::new();
+            hasher.update([1u8]);
+            hasher.update(l);
+            hasher.update(r);
+            hasher.finalize().into()
+        };
+        let pair = node(&leaves[0], &leaves[1]);
+        let root = node(&pair, &leaves[2]);
+
+        assert!(MintPhaseUtils::verify_allowlist(&root, &wallets[0], &[leaves[1], leaves[2]]));
+        assert!(MintPhaseUtils::verify_allowlist(&root, &wallets[1], &[leaves[0], leaves[2]]));
+        assert!(MintPhaseUtils::verify_allowlist(&root, &wallets[2], &[pair]));
+        assert!(!MintPhaseUtils::verify_allowlist(&root, &Pubkey::new_unique(), &[pair]));
+        assert!(!MintPhaseUtils::verify_allowlist(&root, &wallets[0], &[leaves[2], leaves[1]]));
+    }
+
+    #[test]
+    fn test_holder_snapshot_proof() {
+        let holders: Vec<(Pubkey, Pubkey)> = (0..2).map(|_| (Pubkey::new_unique(), Pubkey::new_unique())).collect();
+        let leaves: Vec<[u8; 32]> = holders.iter().map(|(o, m)| SnapshotUtils::holder_leaf(o, m)).collect();
+        let root = if leaves[0] <= leaves[1] {
+            MerkleUtils::hash_node(&leaves[0], &leaves[1])
+        } else {
+            MerkleUtils::hash_node(&leaves[1], &leaves[0])
+        };
+
+        assert!(SnapshotUtils::verify_holder(&root, &holders[0].0, &holders[0].1, &[leaves[1]]));
+        assert!(SnapshotUtils::verify_holder(&root, &holders[1].0, &holders[1].1, &[leaves[0]]));
+        // Holding a different mint than the one snapshotted doesn't verify
+        assert!(!SnapshotUtils::verify_holder(&root, &holders[0].0, &holders[1].1, &[leaves[1]]));
+
+        let salt = [5u8; 32];
+        let commitment = SnapshotUtils::commitment(&root, 2, &salt);
+        assert_ne!(commitment, SnapshotUtils::commitment(&root, 3, &salt));
+        assert_ne!(commitment, SnapshotUtils::commitment(&root, 2, &[6u8; 32]));
+    }
+
+    #[test]
+    fn test_bridge_cost_estimate() {
+        let rent = Rent::default();
+        let sol = BridgeCostUtils::estimate(100_000, GasPayment::Sol, None, &rent).unwrap();
+        assert_eq!(sol.destination_gas_fee, 100_000);
+        assert_eq!(sol.protocol_fee, 0);
+        assert_eq!(sol.total_lamports, sol.network_fee + sol.rent_deposits + 100_000);
+
+        // ZRC-20 gas is paid in tokens, so it stays out of the lamport total
+        let zrc20 = BridgeCostUtils::estimate(100_000, GasPayment::Zrc20, Some(30), &rent).unwrap();
+        assert_eq!(zrc20.destination_gas_fee, 3_000_000);
+        assert_eq!(zrc20.total_lamports, zrc20.network_fee + zrc20.rent_deposits);
+        assert!(BridgeCostUtils::estimate(100_000, GasPayment::Zrc20, None, &rent).is_err());
+    }
+
+    #[test]
+    fn test_error_codes_are_stable() {
+        // Clients map these numbers to messages, so they must never shift
+        assert_eq!(u32::from(UniversalNftError::Unauthorized), 6000);
+        assert_eq!(u32::from(UniversalNftError::InvalidChainId), 6006);
+        assert_eq!(u32::from(UniversalNftError::InsufficientGasLimit), 6008);
+        assert_eq!(u32::from(UniversalNftError::OperatorScopeNotGranted), 6151);
+    }
+
+    #[test]
+    fn test_rent_sponsorship_is_capped_per_chain() {
+        let mut vault = RentVault { enabled: true, total_funded: 3_000, total_sponsored: 0, bump: 0 };
+        let mut ledger = ChainRentLedger {
+            chain_id: 1,
+            funded_lamports: 1_000,
+            sponsored_lamports: 0,
+            sponsored_accounts: 0,
+            bump: 0,
+        };
+
+        assert!(RentSponsorUtils::can_sponsor(&vault, &ledger, 3_000, 1_000));
+        // Another chain's deposits don't extend this chain's allowance
+        assert!(!RentSponsorUtils::can_sponsor(&vault, &ledger, 3_000, 1_001));
+        assert!(!RentSponsorUtils::can_sponsor(&vault, &ledger, 999, 1_000));
+
+        RentSponsorUtils::record(&mut vault, &mut ledger, 600).unwrap();
+        assert_eq!(ledger.remaining(), 400);
+        assert_eq!(ledger.sponsored_accounts, 1);
+        assert_eq!(vault.total_sponsored, 600);
+        assert!(!RentSponsorUtils::can_sponsor(&vault, &ledger, 3_000, 600));
+
+        vault.enabled = false;
+        assert!(!RentSponsorUtils::can_sponsor(&vault, &ledger, 3_000, 100));
+    }
+
+    #[test]
+    fn test_message_codec_versioning() {
+        let message = CrossChainMessage::BurnNft {
+            token_id: "42".to_string(),
+            owner: Pubkey::new_unique(),
+        };
+        let data = MessageCodec::encode(&message).unwrap();
+        assert_eq!(data[0], MessageCodec::CURRENT_VERSION);
+        assert_eq!(data[1], 1); // BurnNft discriminator
+        assert!(matches!(
+            MessageCodec::decode(&data).unwrap(),
+            CrossChainMessage::BurnNft { token_id, .. } if token_id == "42"
+        ));
+
+        // Every defined type decodes; anything past the last is reported as unknown
+        let royalty = MessageCodec::encode(&CrossChainMessage::MintNftWithRoyalty {
+            token_id: "42".to_string(),
+            name: "NFT".to_string(),
+            symbol: "NFT".to_string(),
+            uri: "ipfs://cid".to_string(),
+            recipient: Pubkey::new_unique(),
+            collection_mint: None,
+            payload: vec![],
+            royalty: Erc2981Royalty { receiver: [1u8; 20], basis_points: 500 },
+        }).unwrap();
+        assert_eq!(royalty[1], MessageCodec::V1_MESSAGE_TYPES - 11);
+        assert!(MessageCodec::decode(&royalty).is_ok());
+
+        let attributes = MessageCodec::encode(&CrossChainMessage::MintNftWithAttributes {
+            token_id: "42".to_string(),
+            name: "NFT".to_string(),
+            symbol: "NFT".to_string(),
+            uri: "ipfs://cid".to_string(),
+            recipient: Pubkey::new_unique(),
+            collection_mint: Some(Pubkey::new_unique()),
+            payload: vec![],
+            attributes: vec![NftAttribute { key: "level".to_string(), value: vec![7] }],
+        }).unwrap();
+        assert_eq!(attributes[1], MessageCodec::V1_MESSAGE_TYPES - 10);
+        assert!(matches!(
+            MessageCodec::decode(&attributes).unwrap(),
+            CrossChainMessage::MintNftWithAttributes { attributes, .. } if attributes[0].value == vec![7]
+        ));
+
+        let bundle = MessageCodec::encode(&CrossChainMessage::MintNftBundle {
+            token_id: "42".to_string(),
+            name: "NFT".to_string(),
+            symbol: "NFT".to_string(),
+            uri: "ipfs://cid".to_string(),
+            recipient: Pubkey::new_unique(),
+            collection_mint: None,
+            payload: vec![],
+            children: vec![BatchMintItem {
+                token_id: "43".to_string(),
+                name: "Sword".to_string(),
+                symbol: "NFT".to_string(),
+                uri: "ipfs://sword".to_string(),
+                collection_mint: None,
+            }],
+        }).unwrap();
+        assert_eq!(bundle[1], MessageCodec::V1_MESSAGE_TYPES - 9);
+        assert!(matches!(
+            MessageCodec::decode(&bundle).unwrap(),
+            CrossChainMessage::MintNftBundle { children, .. } if children[0].token_id == "43"
+        ));
+
+        let check_in = MessageCodec::encode(&CrossChainMessage::TicketCheckIn {
+            token_id: "42".to_string(),
+            event_id: 7,
+            holder: Pubkey::new_unique(),
+            checked_in_at: 1_700_000_000,
+        }).unwrap();
+        assert_eq!(check_in[1], MessageCodec::V1_MESSAGE_TYPES - 8);
+        assert!(MessageCodec::decode(&check_in).is_ok());
+
+        let terms = VestingTerms {
+            token_mint: Pubkey::new_unique(),
+            total_amount: 1_000,
+            claimed_amount: 250,
+            start_ts: 0,
+            cliff_ts: 100,
+            end_ts: 400,
+        };
+        let vesting = MessageCodec::encode(&CrossChainMessage::MintNftWithVesting {
+            token_id: "42".to_string(),
+            name: "NFT".to_string(),
+            symbol: "NFT".to_string(),
+            uri: "ipfs://cid".to_string(),
+            recipient: Pubkey::new_unique(),
+            collection_mint: None,
+            payload: vec![],
+            vesting: terms,
+        }).unwrap();
+        assert_eq!(vesting[1], MessageCodec::V1_MESSAGE_TYPES - 7);
+        assert!(matches!(
+            MessageCodec::decode(&vesting).unwrap(),
+            CrossChainMessage::MintNftWithVesting { vesting, .. } if vesting == terms
+        ));
+
+        let revocation = MessageCodec::encode(&CrossChainMessage::CredentialRevoked {
+            collection_mint: Pubkey::new_unique(),
+            credential_id: 3,
+            subject: Pubkey::new_unique(),
+            revoked_at: 1_700_000_000,
+        }).unwrap();
+        assert_eq!(revocation[1], MessageCodec::V1_MESSAGE_TYPES - 6);
+        assert!(matches!(
+            MessageCodec::decode(&revocation).unwrap(),
+            CrossChainMessage::CredentialRevoked { credential_id: 3, .. }
+        ));
+
+        let swap = Pubkey::new_unique();
+        let lock = MessageCodec::encode(&CrossChainMessage::SwapLockRequested {
+            swap,
+            token_id: "1".to_string(),
+            hashlock: [2u8; 32],
+            expires_at: 1_700_000_000,
+            maker_receiver: [3u8; 20],
+            remote_asset: RemoteAsset { token_contract: [4u8; 20], token_id: [5u8; 32] },
+        }).unwrap();
+        assert_eq!(lock[1], MessageCodec::V1_MESSAGE_TYPES - 5);
+        assert!(matches!(
+            MessageCodec::decode(&lock).unwrap(),
+            CrossChainMessage::SwapLockRequested { swap: s, hashlock, .. } if s == swap && hashlock == [2u8; 32]
+        ));
+
+        let reveal = MessageCodec::encode(&CrossChainMessage::SwapSecretRevealed {
+            swap,
+            secret: [6u8; 32],
+        }).unwrap();
+        assert_eq!(reveal[1], MessageCodec::V1_MESSAGE_TYPES - 4);
+        assert!(matches!(
+            MessageCodec::decode(&reveal).unwrap(),
+            CrossChainMessage::SwapSecretRevealed { secret, .. } if secret == [6u8; 32]
+        ));
+
+        let security = MessageCodec::encode(&CrossChainMessage::SecurityEvent {
+            sequence: 4,
+            kind: SecurityEventKind::BreakerTripped,
+            chain_id: 1,
+            value: 5,
+            occurred_at: 1_700_000_000,
+        }).unwrap();
+        assert_eq!(security[1], MessageCodec::V1_MESSAGE_TYPES - 3);
+        assert!(matches!(
+            MessageCodec::decode(&security).unwrap(),
+            CrossChainMessage::SecurityEvent { sequence: 4, kind: SecurityEventKind::BreakerTripped, .. }
+        ));
+
+        let pause = MessageCodec::encode(&CrossChainMessage::CorridorPause {
+            nonce: 1,
+            chain_id: 1,
+            direction: CorridorDirection::Inbound,
+            paused: true,
+        }).unwrap();
+        assert_eq!(pause[1], MessageCodec::V1_MESSAGE_TYPES - 2);
+        assert!(matches!(
+            MessageCodec::decode(&pause).unwrap(),
+            CrossChainMessage::CorridorPause { nonce: 1, paused: true, .. }
+        ));
+
+        let request = MessageCodec::encode(&CrossChainMessage::CorridorPauseRequested {
+            nonce: 0,
+            chain_id: 1,
+            direction: CorridorDirection::Both,
+            paused: false,
+        }).unwrap();
+        assert_eq!(request[1], MessageCodec::V1_MESSAGE_TYPES - 1);
+        assert!(matches!(
+            MessageCodec::decode(&request).unwrap(),
+            CrossChainMessage::CorridorPauseRequested { direction: CorridorDirection::Both, .. }
+        ));
+
+        let mut unknown_type = data.clone();
+        unknown_type[1] = MessageCodec::V1_MESSAGE_TYPES;
+        assert_eq!(
+            MessageCodec::decode(&unknown_type).unwrap_err(),
+            UniversalNftError::UnsupportedMessageType.into()
+        );
+
+        let with_block = MessageCodec::encode_with_source_block(&message, 19_000_000).unwrap();
+        assert_eq!(with_block[0], MessageCodec::SOURCE_BLOCK_VERSION);
+        assert_eq!(MessageCodec::source_block(&with_block), Some(19_000_000));
+        assert_eq!(MessageCodec::source_block(&data), None);
+        assert!(matches!(
+            MessageCodec::decode(&with_block).unwrap(),
+            CrossChainMessage::BurnNft { token_id, .. } if token_id == "42"
+        ));
+        assert!(MessageCodec::decode(&with_block[..5]).is_err());
+
+        let mut unknown_version = data;
+        unknown_version[0] = MessageCodec::SOURCE_BLOCK_VERSION + 1;
+        assert_eq!(
+            MessageCodec::decode(&unknown_version).unwrap_err(),
+            UniversalNftError::UnsupportedMessageVersion.into()
+        );
+        assert!(MessageCodec::decode(&[]).is_err());
+    }
+    #[test]
+    fn test_metadata_translation() {
+        assert_eq!(MetadataTranslationUtils::truncate("Universal Dragon", 0), "Universal Dragon");
+        assert_eq!(MetadataTranslationUtils::truncate("Universal Dragon", 10), "Universal");
+        assert_eq!(MetadataTranslationUtils::truncate("Drägon", 3), "Dr");
+
+        let gateway = "https://nftstorage.link/ipfs/";
+        assert_eq!(
+            MetadataTranslationUtils::rewrite_uri("https://nftstorage.link/ipfs/bafy/1.json", gateway, "ipfs://").unwrap(),
+            "ipfs://bafy/1.json"
+        );
+        assert_eq!(
+            MetadataTranslationUtils::rewrite_uri("ar://tx", gateway, "ipfs://").unwrap(),
+            "ar://tx"
+        );
+        assert_eq!(
+            MetadataTranslationUtils::rewrite_uri("ipfs://bafy", "", "https://").unwrap(),
+            "ipfs://bafy"
+        );
+        assert!(MetadataTranslationUtils::rewrite_uri("ipfs://bafy", "ipfs://", "ftp://").is_err());
+    }
+
+    #[test]
+    fn test_royalty_split() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        let shares = vec![
+            RoyaltyShare { creator: a, basis_points: 3_333 },
+            RoyaltyShare { creator: b, basis_points: 6_667 },
+        ];
+        assert!(RoyaltyUtils::validate_shares(&shares).is_ok());
+        assert!(RoyaltyUtils::validate_shares(&[RoyaltyShare { creator: a, basis_points: 9_999 }]).is_err());
+        assert!(RoyaltyUtils::validate_shares(&[
+            RoyaltyShare { creator: a, basis_points: 5_000 },
+            RoyaltyShare { creator: a, basis_points: 5_000 },
+        ]).is_err());
+
+        assert_eq!(RoyaltyUtils::split(100, &shares), vec![34, 66]);
+
+        let mut escrow = RoyaltyEscrow {
+            collection_mint: Pubkey::default(),
+            total_deposited: 0,
+            total_claimed: 0,
+            deposit_count: 0,
+            ledger: vec![],
+            bump: 0,
+        };
+        RoyaltyUtils::credit(&mut escrow, &shares, 100).unwrap();
+        RoyaltyUtils::credit(&mut escrow, &[RoyaltyShare { creator: b, basis_points: 10_000 }], 50).unwrap();
+        assert_eq!(escrow.total_deposited, 150);
+        assert_eq!(escrow.ledger[0].earned, 34);
+        assert_eq!(escrow.ledger[1].earned, 116);
+
+        // Fully claimed creators outside the split are dropped
+        escrow.ledger[0].claimed = 34;
+        RoyaltyUtils::credit(&mut escrow, &[RoyaltyShare { creator: b, basis_points: 10_000 }], 10).unwrap();
+        assert_eq!(escrow.ledger.len(), 1);
+        assert_eq!(escrow.ledger[0].earned, 126);
+    }
+
+    #[test]
+    fn test_recipient_preferences() {
+        let owner = Pubkey::new_unique();
+        let vault = Pubkey::new_unique();
+        let trusted = [7u8; 20];
+        let stranger = [9u8; 20];
+
+        let default = RecipientPreferencesUtils::resolve(None, owner, &stranger).unwrap();
+        assert_eq!(
+            default,
+            RecipientDelivery { recipient: owner, create_token_account: true, quarantine: false }
+        );
+
+        let mut preferences = RecipientPreferences {
+            owner,
+            source_chain_id: 1,
+            preferred_recipient: Some(vault),
+            auto_wrap: false,
+            unsolicited: UnsolicitedPolicy::Decline,
+            trusted_senders: vec![trusted],
+            updated_at: 0,
+            bump: 0,
+        };
+        let delivery = RecipientPreferencesUtils::resolve(Some(&preferences), owner, &trusted).unwrap();
+        assert_eq!(
+            delivery,
+            RecipientDelivery { recipient: vault, create_token_account: false, quarantine: false }
+        );
+        assert_eq!(
+            RecipientPreferencesUtils::resolve(Some(&preferences), owner, &stranger).unwrap_err(),
+            UniversalNftError::UnsolicitedDeliveryDeclined.into()
+        );
+
+        preferences.unsolicited = UnsolicitedPolicy::Quarantine;
+        let quarantined = RecipientPreferencesUtils::resolve(Some(&preferences), owner, &stranger).unwrap();
+        assert!(quarantined.quarantine);
+        assert_eq!(quarantined.recipient, vault);
+
+        preferences.unsolicited = UnsolicitedPolicy::Accept;
+        assert!(!RecipientPreferencesUtils::resolve(Some(&preferences), owner, &stranger).unwrap().quarantine);
+    }
+    #[test]
+    fn test_fee_schedule() {
+        let rates = FeeRates {
+            enabled: true,
+            mint_fee: 10_000,
+            bridge_fee_bps: 250,
+            metadata_update_fee: 5_000,
+            marketplace_fee_bps: 100,
+            fee_recipient: Pubkey::new_unique(),
+            stake_tiers: [
+                StakeDiscountTier { min_stake: 1_000, discount_bps: 1_000 },
+                StakeDiscountTier { min_stake: 10_000, discount_bps: 2_500 },
+                StakeDiscountTier::default(),
+            ],
+            membership_collection: None,
+            membership_discount_bps: 0,
+            referral_share_bps: 2_000,
+        };
+        let mut schedule = FeeSchedule {
+            active: rates,
+            pending: None,
+            pending_effective_at: 0,
+            updated_at: 0,
+            bump: 0,
+        };
+        assert_eq!(schedule.mint_fee(), 10_000);
+        assert_eq!(schedule.bridge_fee(300_000).unwrap(), 7_500);
+        assert_eq!(schedule.marketplace_fee(1_999).unwrap(), 19);
+        assert_eq!(schedule.bridge_fee(u64::MAX).unwrap(), u64::MAX / 40);
+
+        // The switch turns every fee off without touching the rates
+        schedule.active.enabled = false;
+        assert_eq!(schedule.mint_fee(), 0);
+        assert_eq!(schedule.metadata_update_fee(), 0);
+        assert_eq!(schedule.bridge_fee(300_000).unwrap(), 0);
+
+        assert!(rates.validate().is_ok());
+        assert!(FeeRates { bridge_fee_bps: FeeSchedule::MAX_FEE_BPS + 1, ..rates }.validate().is_err());
+        assert!(FeeRates { fee_recipient: Pubkey::default(), ..rates }.validate().is_err());
+
+        // The best tier the stake reaches applies; an empty tier never does
+        assert_eq!(rates.stake_discount_bps(0), 0);
+        assert_eq!(rates.stake_discount_bps(999), 0);
+        assert_eq!(rates.stake_discount_bps(5_000), 1_000);
+        assert_eq!(rates.stake_discount_bps(10_000), 2_500);
+        assert_eq!(FeeRates::discounted(10_000, 2_500).unwrap(), 7_500);
+        assert_eq!(FeeRates::discounted(10_000, 10_000).unwrap(), 0);
+        assert_eq!(FeeRates::discounted(3, 5_000).unwrap(), 2);
+
+        let mut campaign = RebateCampaign {
+            budget: 1_500,
+            spent: 0,
+            rebate_per_tx: 1_000,
+            starts_at: 100,
+            ends_at: 200,
+            eligible_kinds: FeeKind::Mint.mask() | FeeKind::Bridge.mask(),
+            funded: 0,
+            rebate_count: 0,
+            bump: 0,
+        };
+        assert_eq!(campaign.rebate_for(FeeKind::Mint, 10_000, 150), 1_000);
+        assert_eq!(campaign.rebate_for(FeeKind::Bridge, 400, 150), 400);
+        assert_eq!(campaign.rebate_for(FeeKind::MetadataUpdate, 10_000, 150), 0);
+        assert_eq!(campaign.rebate_for(FeeKind::Mint, 10_000, 99), 0);
+        assert_eq!(campaign.rebate_for(FeeKind::Mint, 10_000, 200), 0);
+        campaign.spent = 1_000;
+        assert_eq!(campaign.rebate_for(FeeKind::Mint, 10_000, 150), 500);
+    }
+    #[test]
+    fn test_nft_attributes() {
+        let mut attributes = NftAttributes {
+            mint: Pubkey::new_unique(),
+            collection_mint: Pubkey::new_unique(),
+            entries: vec![],
+            updated_at: 0,
+            bump: 0,
+        };
+        attributes.set("level".to_string(), vec![1]).unwrap();
+        attributes.set("durability".to_string(), vec![100]).unwrap();
+        attributes.set("level".to_string(), vec![2]).unwrap();
+        assert_eq!(attributes.entries.len(), 2);
+        assert_eq!(attributes.entries[0].value, vec![2]);
+
+        attributes.remove("durability").unwrap();
+        assert_eq!(attributes.entries.len(), 1);
+        assert_eq!(
+            attributes.remove("durability").unwrap_err(),
+            UniversalNftError::NftAttributeNotFound.into()
+        );
+
+        // Keys and values are bounded, and entries are capped
+        assert!(attributes.set(String::new(), vec![]).is_err());
+        assert!(attributes.set("k".repeat(NftAttributes::MAX_KEY_LEN + 1), vec![]).is_err());
+        assert!(attributes.set("level".to_string(), vec![0; NftAttributes::MAX_VALUE_LEN + 1]).is_err());
+        for i in 1..NftAttributes::MAX_ENTRIES {
+            attributes.set(format!("slot{}", i), vec![]).unwrap();
+        }
+        assert_eq!(
+            attributes.set("extra".to_string(), vec![]).unwrap_err(),
+            UniversalNftError::TooManyNftAttributes.into()
+        );
+        assert!(NftAttributes::validate_entries(&attributes.entries).is_ok());
+
+        // Inbound entries are checked the same way, including duplicate keys
+        let duplicate = vec![
+            NftAttribute { key: "level".to_string(), value: vec![1] },
+            NftAttribute { key: "level".to_string(), value: vec![2] },
+        ];
+        assert!(NftAttributes::validate_entries(&duplicate).is_err());
+    }
+
+    #[test]
+    fn test_evolution_recipe() {
+        let stage = |value: u8| NftAttribute { key: "stage".to_string(), value: vec![value] };
+        let ore = Pubkey::new_unique();
+        let mut params = EvolutionRecipeParams {
+            inputs: vec![RecipeInput { mint: ore, amount: 10 }],
+            requires: vec![stage(1)],
+            sets: vec![stage(2)],
+            uri: String::new(),
+        };
+        assert!(EvolutionRecipe::validate(&params).is_ok());
+
+        // A recipe burns something, each mint once, and changes something
+        params.inputs.push(RecipeInput { mint: ore, amount: 5 });
+        assert!(EvolutionRecipe::validate(&params).is_err());
+        params.inputs = vec![RecipeInput { mint: ore, amount: 0 }];
+        assert!(EvolutionRecipe::validate(&params).is_err());
+        params.inputs = vec![];
+        assert!(EvolutionRecipe::validate(&params).is_err());
+        params.inputs = vec![RecipeInput { mint: ore, amount: 10 }];
+        params.sets = vec![];
+        assert!(EvolutionRecipe::validate(&params).is_err());
+        params.uri = "ipfs://stage2".to_string();
+        assert!(EvolutionRecipe::validate(&params).is_ok());
+
+        // Requirements match on key and value
+        let mut attributes = NftAttributes {
+            mint: Pubkey::new_unique(),
+            collection_mint: Pubkey::new_unique(),
+            entries: vec![],
+            updated_at: 0,
+            bump: 0,
+        };
+        assert!(attributes.contains_all(&[]));
+        assert!(!attributes.contains_all(&[stage(1)]));
+        attributes.set("stage".to_string(), vec![1]).unwrap();
+        assert!(attributes.contains_all(&[stage(1)]));
+        assert!(!attributes.contains_all(&[stage(2)]));
+    }
+
+    #[test]
+    fn test_vesting_schedule() {
+        let mut terms = VestingTerms {
+            token_mint: Pubkey::new_unique(),
+            total_amount: 1_000,
+            claimed_amount: 0,
+            start_ts: 1_000,
+            cliff_ts: 1_250,
+            end_ts: 2_000,
+        };
+        assert!(terms.validate().is_ok());
+
+        // Nothing before the cliff, then linear from the start, then everything
+        assert_eq!(terms.vested_amount(1_249), 0);
+        assert_eq!(terms.vested_amount(1_250), 250);
+        assert_eq!(terms.vested_amount(1_500), 500);
+        assert_eq!(terms.vested_amount(5_000), 1_000);
+
+        terms.claimed_amount = 250;
+        assert_eq!(terms.claimable(1_500), 250);
+        assert_eq!(terms.claimable(1_250), 0);
+        assert!(terms.validate().is_err());
+
+        // Schedules need a duration and a cliff inside it
+        terms.claimed_amount = 0;
+        terms.cliff_ts = 2_001;
+        assert!(terms.validate().is_err());
+        terms.cliff_ts = 1_000;
+        terms.end_ts = 1_000;
+        assert!(terms.validate().is_err());
+    }
+
+    #[test]
+    fn test_ticket_check_in() {
+        let mut ticket = Ticket {
+            mint: Pubkey::new_unique(),
+            collection_mint: Pubkey::new_unique(),
+            event_id: 7,
+            used: false,
+            checked_in_at: 0,
+            validator: Pubkey::default(),
+            bump: 0,
+        };
+        let validator = Pubkey::new_unique();
+
+        // Another event's door turns the ticket away
+        assert_eq!(
+            ticket.check_in(8, validator, 1_700_000_000).unwrap_err(),
+            UniversalNftError::TicketEventMismatch.into()
+        );
+        assert!(!ticket.used);
+
+        ticket.check_in(7, validator, 1_700_000_000).unwrap();
+        assert!(ticket.used);
+        assert_eq!(ticket.validator, validator);
+
+        // A ticket checks in once
+        assert_eq!(
+            ticket.check_in(7, validator, 1_700_000_100).unwrap_err(),
+            UniversalNftError::TicketAlreadyUsed.into()
+        );
+        assert_eq!(ticket.checked_in_at, 1_700_000_000);
+    }
+
+    #[test]
+    fn test_nft_children() {
+        let child = |mint: Pubkey| EquippedChild {
+            mint,
+            token_id: mint.to_string(),
+            name: "Sword".to_string(),
+            symbol: "NFT".to_string(),
+            uri: "ipfs://sword".to_string(),
+            collection_mint: None,
+        };
+        let mut children = NftChildren {
+            parent_mint: Pubkey::new_unique(),
+            children: vec![],
+            bridge_mode: ChildBridgeMode::Block,
+            bump: 0,
+        };
+        assert!(children.require_bridgeable().is_ok());
+
+        let first = Pubkey::new_unique();
+        children.equip(child(first)).unwrap();
+        for _ in 1..NftChildren::MAX_CHILDREN {
+            children.equip(child(Pubkey::new_unique())).unwrap();
+        }
+        assert_eq!(
+            children.equip(child(Pubkey::new_unique())).unwrap_err(),
+            UniversalNftError::TooManyChildren.into()
+        );
+
+        // Block mode holds the parent while it has children; bundle mode lets it go
+        assert_eq!(
+            children.require_bridgeable().unwrap_err(),
+            UniversalNftError::ChildrenEquipped.into()
+        );
+        children.bridge_mode = ChildBridgeMode::Bundle;
+        assert!(children.require_bridgeable().is_ok());
+        assert_eq!(children.children[0].to_batch_item().token_id, first.to_string());
+
+        assert_eq!(children.unequip(&first).unwrap().mint, first);
+        assert_eq!(
+            children.unequip(&first).unwrap_err(),
+            UniversalNftError::ChildNotEquipped.into()
+        );
+    }
+    #[test]
+    fn test_reveal_assignment() {
+        // Switchboard account data with seed slot 10, reveal slot 12 and value 7s
+        let mut data = vec![0u8; 8 + 32 * 3 + 8 + 32 + 8 + 32 + 224];
+        data[104..112].copy_from_slice(&10u64.to_le_bytes());
+        data[144..152].copy_from_slice(&12u64.to_le_bytes());
+        data[152..184].copy_from_slice(&[7u8; 32]);
+        let randomness = RevealUtils::parse_randomness(&data).unwrap();
+        assert_eq!(randomness.seed_slot, 10);
+        assert_eq!(randomness.reveal_slot, 12);
+        assert_eq!(randomness.value, [7u8; 32]);
+        assert!(RevealUtils::parse_randomness(&data[..150]).is_err());
+
+        // Entries are bound to their index
+        let first = RevealUtils::item_leaf(0, "ipfs://cid/0");
+        let second = RevealUtils::item_leaf(1, "ipfs://cid/1");
+        let root = if first <= second {
+            MerkleUtils::hash_node(&first, &second)
+        } else {
+            MerkleUtils::hash_node(&second, &first)
+        };
+        assert!(RevealUtils::verify_item(&root, 0, "ipfs://cid/0", &[second]));
+        assert!(RevealUtils::verify_item(&root, 1, "ipfs://cid/1", &[first]));
+        assert!(!RevealUtils::verify_item(&root, 1, "ipfs://cid/0", &[first]));
+
+        let mut config = RevealConfig {
+            collection_mint: Pubkey::new_unique(),
+            list_root: [0u8; 32],
+            item_count: 3,
+            randomness_account: Pubkey::new_unique(),
+            seed_slot: 10,
+            randomness: Some([7u8; 32]),
+            reveal_slot: 12,
+            revealed_count: 0,
+            assigned: vec![0u8; 1],
+            bump: 0,
+        };
+        let target = RevealUtils::target_index(&[7u8; 32], &Pubkey::new_unique(), 3);
+        assert!(target < 3);
+
+        // Collisions move to the next free entry, wrapping around
+        assert_eq!(config.assign(2).unwrap(), 2);
+        assert_eq!(config.assign(2).unwrap(), 0);
+        assert_eq!(config.assign(0).unwrap(), 1);
+        assert_eq!(
+            config.assign(1).unwrap_err(),
+            UniversalNftError::RevealListExhausted.into()
+        );
+    }
+    #[test]
+    fn test_attendance_drop() {
+        let wallets: Vec<Pubkey> = (0..2).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = wallets
+            .iter()
+            .enumerate()
+            .map(|(i, wallet)| AttendanceUtils::allowlist_leaf(i as u32, wallet))
+            .collect();
+        let root = MerkleUtils::hash_node(&leaves[0].min(leaves[1]), &leaves[0].max(leaves[1]));
+
+        // Entries are bound to their index
+        assert!(AttendanceUtils::verify_allowlist(&root, 0, &wallets[0], &[leaves[1]]));
+        assert!(AttendanceUtils::verify_allowlist(&root, 1, &wallets[1], &[leaves[0]]));
+        assert!(!AttendanceUtils::verify_allowlist(&root, 1, &wallets[0], &[leaves[1]]));
+
+        let mut attendance = AttendanceDrop {
+            collection_mint: Pubkey::new_unique(),
+            event_id: 42,
+            merkle_tree: Pubkey::new_unique(),
+            allowlist_root: root,
+            name_template: "Summit 2026 #{n}".to_string(),
+            symbol: "POAP".to_string(),
+            uri_template: "https://poap.example/{n}.json".to_string(),
+            start_time: 100,
+            end_time: 200,
+            max_claims: 2,
+            claimed_count: 0,
+            claimed: vec![0u8; 1],
+            bump: 0,
+        };
+        assert!(attendance.is_open(100) && !attendance.is_open(200));
+
+        // Each entry claims once, numbered in claim order
+        assert_eq!(attendance.claim(1).unwrap(), 1);
+        assert!(attendance.claim(1).is_err());
+        assert!(attendance.claim(2).is_err());
+        assert_eq!(attendance.claim(0).unwrap(), 2);
+        assert_eq!(AttendanceDrop::render(&attendance.name_template, 2), "Summit 2026 #2");
+        assert_eq!(AttendanceDrop::render(&attendance.uri_template, 17), "https://poap.example/17.json");
+
+        // Tree config: discriminator, creator, delegate, capacity, minted
+        let delegate = Pubkey::new_unique();
+        let mut data = vec![0u8; 8 + 32];
+        data.extend_from_slice(delegate.as_ref());
+        data.extend_from_slice(&1_024u64.to_le_bytes());
+        data.extend_from_slice(&24u64.to_le_bytes());
+        assert_eq!(AttendanceUtils::parse_tree_config(&data).unwrap(), (delegate, 1_000));
+        assert!(AttendanceUtils::parse_tree_config(&data[..80]).is_err());
+    }
+    #[test]
+    fn test_swap_terms() {
+        let nft = SwapAsset { mint: Pubkey::new_unique(), amount: 1 };
+        let usdc = SwapAsset { mint: Pubkey::new_unique(), amount: 250_000_000 };
+        assert!(Swap::validate(&nft, &usdc).is_ok());
+        assert!(Swap::validate(&nft, &nft).is_err());
+        assert!(Swap::validate(&nft, &SwapAsset { amount: 0, ..usdc }).is_err());
+
+        let (maker, taker) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut swap = Swap {
+            maker,
+            swap_id: 1,
+            taker: Pubkey::default(),
+            maker_asset: nft,
+            taker_asset: usdc,
+            created_at: 0,
+            bump: 0,
+        };
+
+        // Open offers take anyone and only the maker can cancel them
+        assert!(swap.can_accept(&taker));
+        assert!(swap.is_party(&maker) && !swap.is_party(&taker));
+        assert!(!swap.is_party(&Pubkey::default()));
+
+        swap.taker = taker;
+        assert!(!swap.can_accept(&Pubkey::new_unique()));
+        assert!(swap.is_party(&taker));
+    }
+    #[test]
+    fn test_cross_chain_swap_hashlock() {
+        let secret = [7u8; 32];
+        let hashlock: [u8; 32] = Sha256::digest(secret).into();
+        assert!(SwapUtils::verify_secret(&secret, &hashlock).is_ok());
+        assert_eq!(
+            SwapUtils::verify_secret(&[8u8; 32], &hashlock).unwrap_err(),
+            UniversalNftError::InvalidSwapSecret.into()
+        );
+
+        let now = 1_700_000_000;
+        assert!(CrossChainSwap::validate_timelock(now + CrossChainSwap::MIN_TIMELOCK_SECONDS, now).is_ok());
+        assert!(CrossChainSwap::validate_timelock(now + 60, now).is_err());
+        assert!(CrossChainSwap::validate_timelock(now + CrossChainSwap::MAX_TIMELOCK_SECONDS + 1, now).is_err());
+
+        let expires_at = now + CrossChainSwap::MIN_TIMELOCK_SECONDS;
+        let mut swap = CrossChainSwap {
+            maker: Pubkey::new_unique(),
+            swap_id: 1,
+            nft_mint: Pubkey::new_unique(),
+            taker: Pubkey::new_unique(),
+            counterparty_chain_id: 1,
+            maker_receiver: [1u8; 20],
+            remote_asset: RemoteAsset { token_contract: [2u8; 20], token_id: [3u8; 32] },
+            hashlock,
+            expires_at,
+            transfer: Pubkey::new_unique(),
+            status: CrossChainSwapStatus::Locked,
+            secret: [0u8; 32],
+            created_at: now,
+            bump: 0,
+        };
+
+        // Locked swaps redeem before expiry and refund from it
+        assert!(swap.require_redeemable(now).is_ok());
+        assert!(swap.require_refundable(now).is_err());
+        assert!(swap.require_redeemable(expires_at).is_err());
+        assert!(swap.require_refundable(expires_at).is_ok());
+
+        // A relayed secret keeps the taker's claim past expiry
+        swap.reveal(secret).unwrap();
+        assert_eq!(swap.status, CrossChainSwapStatus::Redeemable);
+        assert!(swap.require_redeemable(expires_at).is_ok());
+        assert!(swap.require_refundable(expires_at).is_err());
+        assert!(swap.reveal(secret).is_err());
+
+        // A reverted lock request refunds the maker at once
+        swap.status = CrossChainSwapStatus::Reverted;
+        assert!(swap.require_refundable(now).is_ok());
+        assert!(swap.require_redeemable(now).is_err());
+    }
+    #[test]
+    fn test_loan_terms() {
+        let terms = LoanTerms {
+            principal_mint: Pubkey::new_unique(),
+            principal: 1_000_000_000,
+            repayment: 1_100_000_000,
+            duration: 30 * 24 * 60 * 60,
+        };
+        assert!(terms.validate().is_ok());
+        assert!(LoanTerms { principal: 0, ..terms }.validate().is_err());
+        assert!(LoanTerms { repayment: terms.principal - 1, ..terms }.validate().is_err());
+        assert!(LoanTerms { duration: 60, ..terms }.validate().is_err());
+        assert!(LoanTerms { duration: Loan::MAX_DURATION_SECONDS + 1, ..terms }.validate().is_err());
+
+        // SOL loans against priced collateral are bounded by the floor
+        let sol_terms = LoanTerms { principal_mint: spl_token::native_mint::ID, ..terms };
+        assert!(sol_terms.require_within_ltv(0).is_ok());
+        assert!(sol_terms.require_within_ltv(1_500_000_000).is_ok());
+        assert!(sol_terms.require_within_ltv(1_400_000_000).is_err());
+        assert!(terms.require_within_ltv(1_400_000_000).is_ok());
+
+        let mut loan = Loan {
+            nft_mint: Pubkey::new_unique(),
+            borrower: Pubkey::new_unique(),
+            lender: Pubkey::default(),
+            terms,
+            collateral_value: 0,
+            funded_at: 0,
+            due_at: 0,
+            bump: 0,
+        };
+
+        // Unfunded requests never default
+        assert!(!loan.is_active());
+        assert!(!loan.is_defaulted(i64::MAX));
+
+        loan.lender = Pubkey::new_unique();
+        loan.funded_at = 1_700_000_000;
+        loan.due_at = loan.funded_at + terms.duration;
+        assert!(loan.is_active());
+        assert!(!loan.is_defaulted(loan.due_at - 1));
+        assert!(loan.is_defaulted(loan.due_at));
+    }
+    #[test]
+    fn test_floor_price() {
+        let mut data = vec![0u8; 240];
+        data[0..4].copy_from_slice(&FloorPriceUtils::PYTH_MAGIC.to_le_bytes());
+        data[8..12].copy_from_slice(&FloorPriceUtils::PYTH_PRICE_ACCOUNT_TYPE.to_le_bytes());
+        data[20..24].copy_from_slice(&(-8i32).to_le_bytes());
+        data[96..104].copy_from_slice(&1_700_000_000i64.to_le_bytes());
+        data[208..216].copy_from_slice(&1_250_000_000i64.to_le_bytes());
+        data[224..228].copy_from_slice(&FloorPriceUtils::PYTH_STATUS_TRADING.to_le_bytes());
+
+        // 12.5 SOL
+        assert_eq!(
+            FloorPriceUtils::parse_pyth_price(&data).unwrap(),
+            (12_500_000_000, 1_700_000_000)
+        );
+
+        let mut halted = data.clone();
+        halted[224..228].copy_from_slice(&0u32.to_le_bytes());
+        assert!(FloorPriceUtils::parse_pyth_price(&halted).is_err());
+        let mut negative = data.clone();
+        negative[208..216].copy_from_slice(&(-1i64).to_le_bytes());
+        assert!(FloorPriceUtils::parse_pyth_price(&negative).is_err());
+        assert!(FloorPriceUtils::parse_pyth_price(&data[..200]).is_err());
+
+        let mut floor = CollectionFloor {
+            collection_mint: Pubkey::new_unique(),
+            price_authorities: vec![Pubkey::new_unique()],
+            price_feed: Pubkey::default(),
+            max_staleness: 3600,
+            floor_value: 0,
+            updated_at: 0,
+            bump: 0,
+        };
+        assert!(floor.is_price_authority(&floor.price_authorities[0]));
+        assert!(!floor.is_price_authority(&Pubkey::new_unique()));
+
+        // Never priced counts as zero; priced must be fresh
+        assert_eq!(floor.fresh_value(i64::MAX).unwrap(), 0);
+        floor.floor_value = 5_000_000_000;
+        floor.updated_at = 1_700_000_000;
+        assert_eq!(floor.fresh_value(1_700_003_600).unwrap(), 5_000_000_000);
+        assert_eq!(
+            floor.fresh_value(1_700_003_601).unwrap_err(),
+            UniversalNftError::StaleFloorPrice.into()
+        );
+        floor.max_staleness = 0;
+        assert!(floor.fresh_value(i64::MAX).is_ok());
+    }
+    #[test]
+    fn test_leaderboard() {
+        let mut board = Leaderboard {
+            kind: LeaderboardKind::Minters,
+            entries: Vec::new(),
+            updated_at: 0,
+            bump: 0,
+        };
+        let users: Vec<Pubkey> = (0..=Leaderboard::MAX_ENTRIES).map(|_| Pubkey::new_unique()).collect();
+
+        // Filling the board keeps it sorted highest first
+        for (i, user) in users.iter().take(Leaderboard::MAX_ENTRIES).enumerate() {
+            board.record(*user, i as u64 + 1, 100);
+        }
+        assert_eq!(board.entries.len(), Leaderboard::MAX_ENTRIES);
+        assert_eq!(board.entries[0].score, Leaderboard::MAX_ENTRIES as u64);
+        assert_eq!(board.updated_at, 100);
+
+        // A newcomer must beat the lowest entry to get on a full board
+        let newcomer = users[Leaderboard::MAX_ENTRIES];
+        board.record(newcomer, 1, 200);
+        assert!(!board.entries.iter().any(|e| e.key == newcomer));
+        assert_eq!(board.updated_at, 100);
+        board.record(newcomer, 5, 200);
+        assert_eq!(board.entries.len(), Leaderboard::MAX_ENTRIES);
+        assert!(!board.entries.iter().any(|e| e.key == users[0]));
+        assert!(board.entries.windows(2).all(|w| w[0].score >= w[1].score));
+
+        // Updating an existing key re-ranks it
+        board.record(users[1], 100, 300);
+        assert_eq!(board.entries[0], LeaderboardEntry { key: users[1], score: 100 });
+        assert_eq!(board.entries.len(), Leaderboard::MAX_ENTRIES);
+
+        // Counting boards take over the lowest entry's count
+        let mut collections = Leaderboard {
+            kind: LeaderboardKind::BridgedCollections,
+            entries: Vec::new(),
+            updated_at: 0,
+            bump: 0,
+        };
+        let hot = users[0];
+        for _ in 0..3 {
+            collections.increment(hot, 1);
+        }
+        for user in users.iter().skip(1).take(Leaderboard::MAX_ENTRIES - 1) {
+            collections.increment(*user, 1);
+        }
+        assert_eq!(collections.entries[0], LeaderboardEntry { key: hot, score: 3 });
+        collections.increment(newcomer, 2);
+        assert_eq!(collections.entries.len(), Leaderboard::MAX_ENTRIES);
+        assert_eq!(collections.entries[1], LeaderboardEntry { key: newcomer, score: 2 });
+    }
+    #[test]
+    fn test_corridor_stats() {
+        let mut corridor = CorridorStats::default();
+        assert_eq!(corridor.risk(), CorridorStats::UNKNOWN_RISK);
+
+        // The first sample sets the averages, later ones move them a tenth
+        corridor.record(100, true, 1);
+        assert_eq!(corridor.latency_ema_s, 100);
+        corridor.record(200, false, 2);
+        assert_eq!(corridor.latency_ema_s, 110);
+        assert_eq!(corridor.failure_rate_bps, 1_000);
+
+        // Degradation waits for enough samples
+        assert!(!corridor.is_degraded());
+        for i in 0..CorridorStats::MIN_SAMPLES {
+            corridor.record(60, true, 3 + i as i64);
+        }
+        assert!(corridor.is_established());
+        assert!(!corridor.is_degraded());
+        assert!(corridor.risk() < CorridorStats::UNKNOWN_RISK);
+
+        // A run of failures degrades it; older samples fade back out
+        for i in 0..3 {
+            corridor.record(60, false, 100 + i);
+        }
+        assert!(corridor.is_degraded());
+        assert!(corridor.risk() >= 200);
+        while !corridor.is_recovered() {
+            corridor.record(60, true, 200);
+        }
+        assert!(!corridor.is_degraded());
+
+        // Slow confirmations degrade it too
+        for _ in 0..40 {
+            corridor.record(CorridorStats::DEGRADED_LATENCY_S * 2, true, 300);
+        }
+        assert!(corridor.is_degraded());
+        assert!(!corridor.is_recovered());
+    }
+    #[test]
+    fn test_chain_risk_registry() {
+        let params = ChainRiskParams {
+            chain_risks: vec![ChainRisk { chain_id: 56, risk: 150 }, ChainRisk { chain_id: 97, risk: 400 }],
+            pair_overrides: vec![PairRiskOverride { source_chain_id: 900, destination_chain_id: 97, risk: 20 }],
+        };
+        assert!(ChainRiskRegistry::validate(&params).is_ok());
+
+        let registry = ChainRiskRegistry {
+            chain_risks: params.chain_risks.clone(),
+            pair_overrides: params.pair_overrides.clone(),
+            updated_at: 0,
+            bump: 0,
+        };
+        // Unlisted chains carry no risk of their own
+        assert_eq!(registry.pair_risk(900, 1, 30), 30);
+        assert_eq!(registry.pair_risk(900, 56, 30), 180);
+        assert_eq!(registry.pair_risk(56, 97, 100), ChainRiskRegistry::MAX_RISK);
+        // Overrides apply to their direction only
+        assert_eq!(registry.pair_risk(900, 97, 100), 20);
+        assert_eq!(registry.pair_risk(97, 900, 100), ChainRiskRegistry::MAX_RISK);
+
+        let mut too_risky = params.clone();
+        too_risky.chain_risks[0].risk = ChainRiskRegistry::MAX_RISK + 1;
+        assert!(ChainRiskRegistry::validate(&too_risky).is_err());
+
+        let mut repeated = params.clone();
+        repeated.pair_overrides.push(repeated.pair_overrides[0]);
+        assert!(ChainRiskRegistry::validate(&repeated).is_err());
+
+        let too_many = ChainRiskParams {
+            chain_risks: (0..=ChainRiskRegistry::MAX_ENTRIES as u64).map(|chain_id| ChainRisk { chain_id, risk: 0 }).collect(),
+            pair_overrides: Vec::new(),
+        };
+        assert!(ChainRiskRegistry::validate(&too_many).is_err());
+    }
+    #[test]
+    fn test_behavior_baseline() {
+        // 14:00 UTC
+        let afternoon = 14 * 3_600;
+        let mut baseline = BehaviorBaseline::default();
+        assert_eq!(baseline.deviation(1_000, 1, afternoon), BehaviorBaseline::UNKNOWN_RISK);
+
+        for (i, value) in [900, 1_100, 1_000, 1_050, 950, 1_000].into_iter().enumerate() {
+            baseline.record(value, 1, afternoon + i as i64 * 86_400);
+        }
+        assert!(baseline.is_established());
+        assert!((900..=1_100).contains(&baseline.median_value));
+
+        // The user's usual transfer scores nothing, an hour either side too
+        assert_eq!(baseline.deviation(1_000, 1, afternoon), 0);
+        assert_eq!(baseline.deviation(1_000, 1, afternoon + 3_600), 0);
+        // Each kind of deviation adds up
+        assert_eq!(baseline.deviation(5_000, 1, afternoon), 75);
+        assert_eq!(baseline.deviation(50_000, 1, afternoon), 150);
+        assert_eq!(baseline.deviation(1_000, 1, 3 * 3_600), 75);
+        assert_eq!(baseline.deviation(1_000, 56, afternoon), 75);
+        assert_eq!(baseline.deviation(50_000, 56, 3 * 3_600), 300);
+
+        // A night-time user is not penalized for the night
+        let mut night_owl = BehaviorBaseline::default();
+        for i in 0..BehaviorBaseline::MIN_SAMPLES {
+            night_owl.record(1_000, 1, 3 * 3_600 + i as i64 * 86_400);
+        }
+        assert_eq!(night_owl.deviation(1_000, 1, 3 * 3_600), 0);
+
+        // Chains that stop being used fade out
+        for i in 0..60 {
+            baseline.record(1_000, 56 + i % 4, afternoon);
+        }
+        assert!(!baseline.is_usual_chain(1));
+        assert!(baseline.is_usual_chain(56));
+    }
+    #[test]
+    fn test_corridor_pause() {
+        let mut pause = CorridorPause {
+            chain_id: 1,
+            inbound_paused: false,
+            outbound_paused: false,
+            last_admin_nonce: 0,
+            requests_sent: 0,
+            updated_at: 0,
+            bump: 255,
+        };
+
+        pause.set(CorridorDirection::Outbound, true, 10);
+        assert!(pause.is_paused(CorridorDirection::Outbound));
+        assert!(!pause.is_paused(CorridorDirection::Inbound));
+        assert!(pause.is_paused(CorridorDirection::Both));
+
+        pause.set(CorridorDirection::Both, true, 20);
+        pause.set(CorridorDirection::Outbound, false, 30);
+        assert!(pause.is_paused(CorridorDirection::Inbound));
+        assert!(!pause.is_paused(CorridorDirection::Outbound));
+        assert_eq!(pause.updated_at, 30);
+
+        // The TSS signs over the source chain, nonce and exact payload
+        let message = b"pause";
+        let hash = CorridorPauseUtils::signing_hash(7000, 1, message);
+        assert_ne!(hash, CorridorPauseUtils::signing_hash(7001, 1, message));
+        assert_ne!(hash, CorridorPauseUtils::signing_hash(7000, 2, message));
+        assert_ne!(hash, CorridorPauseUtils::signing_hash(7000, 1, b"resume"));
+    }
+    #[test]
+    fn test_deployment_registry() {
+        let governance = Pubkey::new_unique();
+        let satellites = vec![
+            SatelliteProgram { kind: SatelliteKind::Governance, program_id: governance, version: 3 },
+            SatelliteProgram { kind: SatelliteKind::Lending, program_id: Pubkey::new_unique(), version: 1 },
+        ];
+        assert!(DeploymentRegistry::validate(&satellites).is_ok());
+
+        let registry = DeploymentRegistry { satellites: satellites.clone(), updated_at: 0, bump: 255 };
+        assert_eq!(registry.program_id(SatelliteKind::Governance), Some(governance));
+        assert_eq!(registry.program_id(SatelliteKind::Marketplace), None);
+        assert!(registry.require_program(SatelliteKind::Governance, &governance).is_ok());
+        assert!(registry.require_program(SatelliteKind::Governance, &Pubkey::new_unique()).is_err());
+        assert!(registry.require_program(SatelliteKind::Marketplace, &governance).is_err());
+
+        let mut repeated = satellites.clone();
+        repeated[1].kind = SatelliteKind::Governance;
+        assert!(DeploymentRegistry::validate(&repeated).is_err());
+
+        let mut unset = satellites;
+        unset[1].program_id = Pubkey::default();
+        assert!(DeploymentRegistry::validate(&unset).is_err());
+    }
+    #[test]
+    fn test_retry_queue_order() {
+        use crate::recovery::transaction_retry::RetryQueue;
+
+        let sessions: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        let mut queue = RetryQueue { entries: Vec::new(), bump: 255 };
+        for (session, due_at) in sessions.iter().zip([50, 10, 40, 30, 20]) {
+            queue.schedule(*session, due_at).unwrap();
+        }
+
+        // Rescheduling replaces the session's entry; removal drops it
+        queue.schedule(sessions[0], 5).unwrap();
+        queue.remove(&sessions[2]);
+        assert_eq!(queue.entries.len(), 4);
+
+        assert_eq!(queue.peek_due(4), None);
+        assert_eq!(queue.peek_due(5).map(|e| e.session), Some(sessions[0]));
+        let order: Vec<i64> = std::iter::from_fn(|| queue.pop()).map(|e| e.due_at).collect();
+        assert_eq!(order, vec![5, 10, 20, 30]);
+
+        for i in 0..RetryQueue::MAX_ENTRIES {
+            queue.schedule(Pubkey::new_unique(), i as i64).unwrap();
+        }
+        assert!(queue.schedule(Pubkey::new_unique(), 0).is_err());
+    }
+
+    fn outbound_transfer(initiator: Pubkey) -> crate::state::CrossChainTransfer {
+        crate::state::CrossChainTransfer {
+            nft_mint: Pubkey::new_unique(),
+            source_chain_id: 900,
+            destination_chain_id: 7001,
+            sender: [0u8; 20],
+            recipient: vec![1u8; 20],
+            gas_limit: 200_000,
+            nonce: 1,
+            timestamp: 0,
+            status: crate::state::TransferStatus::Initiated,
+            bump: 255,
+            gas_payment: crate::state::GasPayment::Sol,
+            gas_fee: 5_000,
+            payload: Vec::new(),
+            initiator,
+        }
+    }
+
+    fn recovery_session(transfer: &crate::state::CrossChainTransfer) -> crate::recovery::RecoverySession {
+        use crate::recovery::error_recovery::*;
+
+        RecoverySession {
+            session_id: 0,
+            original_error: ErrorType::CrossChainTimeout,
+            recovery_strategy: RecoveryStrategy::AlternativeExecution,
+            operation_context: OperationContext {
+                operation_type: "burn_and_transfer".to_string(),
+                user: transfer.initiator,
+                nft_mint: Some(transfer.nft_mint),
+                target_chain: Some(transfer.destination_chain_id),
+                failed_signature: None,
+                compute_units_used: 0,
+                fees_paid: transfer.gas_fee,
+            },
+            attempts_made: 0,
+            max_attempts: 4,
+            status: RecoveryStatus::InProgress,
+            started_at: 0,
+            completed_at: None,
+            actions_taken: Vec::new(),
+            outcome: None,
+            resources_consumed: ResourceUsage {
+                compute_units: 0,
+                fees_spent: 0,
+                duration_seconds: 0,
+                network_requests: 0,
+            },
+            bump: 255,
+        }
+    }
+
+    fn conclude_session(session: &mut crate::recovery::RecoverySession, result: crate::recovery::RecoveryResult) {
+        use crate::recovery::error_recovery::*;
+
+        session.status = match result {
+            RecoveryResult::FullRecovery | RecoveryResult::PartialRecovery => RecoveryStatus::Successful,
+            _ => RecoveryStatus::Failed,
+        };
+        session.outcome = Some(RecoveryOutcome {
+            result,
+            new_signature: None,
+            compensation: None,
+            lessons_learned: String::new(),
+        });
+    }
+    #[test]
+    fn test_compensation_claim_requires_failed_recovery() {
+        use crate::recovery::compensation::{CompensationVault, UserCompensationLedger};
+        use crate::recovery::error_recovery::{CompensationType, RecoveryResult};
+        use crate::state::{GasPayment, TransferStatus};
+
+        let user = Pubkey::new_unique();
+        let transfer = outbound_transfer(user);
+        let mut session = recovery_session(&transfer);
+        let vault = CompensationVault {
+            authority: Pubkey::new_unique(),
+            treasury: Pubkey::new_unique(),
+            per_user_cap: 8_000,
+            total_funded: 0,
+            total_paid: 0,
+            claims_paid: 0,
+            claims_enabled: true,
+            created_at: 0,
+            bump: 255,
+        };
+        let mut ledger = UserCompensationLedger {
+            user,
+            total_claimed: 0,
+            claim_count: 0,
+            last_claim_at: 0,
+            bump: 255,
+        };
+
+        // Nothing is owed while the session is still running
+        assert!(vault.validate_claim(&transfer, &session, &ledger, user).is_err());
+
+        // A recovered transfer owes nothing
+        conclude_session(&mut session, RecoveryResult::FullRecovery);
+        assert!(vault.validate_claim(&transfer, &session, &ledger, user).is_err());
+
+        // A failed recovery refunds the gas to the initiator only, even
+        // though the gateway never reverted the transfer
+        conclude_session(&mut session, RecoveryResult::UnrecoverableFailure);
+        let (kind, amount) = vault.validate_claim(&transfer, &session, &ledger, user).unwrap();
+        assert!(kind == CompensationType::FeeRefund);
+        assert_eq!(amount, 5_000);
+        assert!(vault.validate_claim(&transfer, &session, &ledger, Pubkey::new_unique()).is_err());
+
+        // The per-user cap holds across claims
+        ledger.total_claimed = 5_000;
+        assert!(vault.validate_claim(&transfer, &session, &ledger, user).is_err());
+        ledger.total_claimed = 0;
+
+        // A transfer that landed after all is never refunded
+        let mut landed = transfer.clone();
+        landed.status = TransferStatus::Completed;
+        assert!(vault.validate_claim(&landed, &session, &ledger, user).is_err());
+
+        let mut token_gas = transfer;
+        token_gas.gas_payment = GasPayment::Zrc20;
+        assert!(vault.validate_claim(&token_gas, &session, &ledger, user).is_err());
+    }
+
+    #[test]
+    fn test_insurance_pays_only_unrecoverable_failures() {
+        use crate::recovery::error_recovery::RecoveryResult;
+        use crate::recovery::insurance::{InsurancePolicy, InsurancePool};
+        use crate::state::TransferStatus;
+
+        let user = Pubkey::new_unique();
+        let transfer = outbound_transfer(user);
+        let mut session = recovery_session(&transfer);
+        let pool = InsurancePool {
+            authority: Pubkey::new_unique(),
+            premium_lamports: 1_000,
+            payout_lamports: 50_000,
+            total_premiums: 1_000,
+            total_payouts: 0,
+            policies_written: 1,
+            claims_paid: 0,
+            enabled: true,
+            bump: 255,
+        };
+        let mut policy = InsurancePolicy {
+            transfer: Pubkey::new_unique(),
+            nft_mint: transfer.nft_mint,
+            insured: user,
+            premium_paid: 1_000,
+            payout_amount: pool.payout_for(20_000),
+            purchased_at: 0,
+            claimed: false,
+            bump: 255,
+        };
+        assert_eq!(policy.payout_amount, 20_000);
+
+        // No payout while recovery is still running
+        assert!(pool.validate_claim(&policy, &transfer, &session, user).is_err());
+
+        // A plain revert or cancel gives the NFT back and pays nothing
+        let mut reverted = transfer.clone();
+        reverted.status = TransferStatus::Reverted;
+        assert!(pool.validate_claim(&policy, &reverted, &session, user).is_err());
+        for result in [
+            RecoveryResult::FullRecovery,
+            RecoveryResult::PartialRecovery,
+            RecoveryResult::CompensatedFailure,
+        ] {
+            conclude_session(&mut session, result);
+            assert!(pool.validate_claim(&policy, &reverted, &session, user).is_err());
+        }
+
+        // An unrecoverable failure pays the insured once
+        conclude_session(&mut session, RecoveryResult::UnrecoverableFailure);
+        assert_eq!(pool.validate_claim(&policy, &transfer, &session, user).unwrap(), 20_000);
+        assert!(pool.validate_claim(&policy, &transfer, &session, Pubkey::new_unique()).is_err());
+
+        let mut other_nft = policy.clone();
+        other_nft.nft_mint = Pubkey::new_unique();
+        assert!(pool.validate_claim(&other_nft, &transfer, &session, user).is_err());
+
+        policy.claimed = true;
+        assert!(pool.validate_claim(&policy, &transfer, &session, user).is_err());
+    }
+
+    #[test]
+    fn test_circuit_breaker_half_open() {
+        use crate::security::circuit_breaker::{CircuitBreaker, CircuitConfig, CircuitState};
+
+        let mut breaker = CircuitBreaker {
+            chain_id: 7001,
+            state: CircuitState::Closed,
+            failure_count: 0,
+            success_count: 0,
+            window_start: 0,
+            last_state_change: 0,
+            last_probe_at: 0,
+            probe_in_flight: false,
+            shadow_blocks: 0,
+            config: CircuitConfig {
+                failure_threshold: 3,
+                failure_window: 300,
+                min_open_duration: 600,
+                probe_interval: 60,
+                enforcement: RuleEnforcement::Enforce,
+            },
+            authority: Pubkey::new_unique(),
+            bump: 255,
+        };
+
+        // The failure that reaches the threshold trips the breaker
+        breaker.record_failure_at(5).unwrap();
+        breaker.record_failure_at(6).unwrap();
+        assert!(breaker.check_operation_allowed_at(7).is_ok());
+        breaker.record_failure_at(10).unwrap();
+        assert_eq!(breaker.state, CircuitState::Open);
+        assert!(breaker.check_operation_allowed_at(100).is_err());
+
+        // After the open period a single probe goes through per interval
+        assert!(breaker.check_operation_allowed_at(610).is_ok());
+        assert_eq!(breaker.state, CircuitState::HalfOpen);
+        assert!(breaker.probe_in_flight);
+        assert!(breaker.check_operation_allowed_at(620).is_err());
+
+        // A failed probe re-opens it for another full period
+        breaker.record_failure_at(630).unwrap();
+        assert_eq!(breaker.state, CircuitState::Open);
+        assert!(breaker.check_operation_allowed_at(1_000).is_err());
+
+        // A probe whose outcome never arrives is replaced after an interval,
+        // and a successful probe closes the breaker
+        assert!(breaker.check_operation_allowed_at(1_230).is_ok());
+        assert!(breaker.check_operation_allowed_at(1_290).is_ok());
+        breaker.record_success_at(1_300).unwrap();
+        assert_eq!(breaker.state, CircuitState::Closed);
+        assert_eq!(breaker.failure_count, 0);
+        assert!(breaker.check_operation_allowed_at(1_301).is_ok());
+
+        // A check never changes a closed breaker's state: a rejected
+        // operation's writes would roll back anyway
+        breaker.failure_count = 3;
+        assert!(breaker.check_operation_allowed_at(1_302).is_err());
+        assert_eq!(breaker.state, CircuitState::Closed);
+
+        // Shadow mode lets blocked operations through and counts them
+        breaker.state = CircuitState::Open;
+        breaker.last_state_change = 1_302;
+        breaker.config.enforcement = RuleEnforcement::Shadow;
+        assert!(breaker.check_operation_allowed_at(1_303).is_ok());
+        assert_eq!(breaker.shadow_blocks, 1);
+    }
+
+    #[test]
+    fn test_token_bucket() {
+        use crate::security::rate_limiter::{RateLimitConfig, RateLimitSubject, TokenBucket};
+
+        let mut bucket = TokenBucket {
+            subject: RateLimitSubject::Chain(7001),
+            credits: 3 * TokenBucket::CREDIT_SCALE,
+            last_refill: 0,
+            config: RateLimitConfig {
+                burst_capacity: 3,
+                refill_per_minute: 6,
+                enforcement: RuleEnforcement::Enforce,
+            },
+            total_allowed: 0,
+            total_shadowed: 0,
+            authority: Pubkey::new_unique(),
+            bump: 255,
+        };
+
+        // A burst spends the stored credits, then throttles
+        for _ in 0..3 {
+            bucket.try_consume_at(0, 1, None).unwrap();
+        }
+        assert!(bucket.try_consume_at(0, 1, None).is_err());
+        assert_eq!(bucket.total_allowed, 3);
+
+        // Credits refill continuously at the sustained rate, one per 10s
+        assert_eq!(bucket.available(5, None), 0);
+        assert!(bucket.try_consume_at(5, 1, None).is_err());
+        bucket.try_consume_at(10, 1, None).unwrap();
+        assert!(bucket.try_consume_at(15, 1, None).is_err());
+
+        // Refill never exceeds the burst capacity
+        assert_eq!(bucket.available(3_600, None), 3);
+        bucket.try_consume_at(3_600, 3, None).unwrap();
+        assert!(bucket.try_consume_at(3_600, 1, None).is_err());
+
+        // A clock that goes backwards earns nothing
+        assert_eq!(bucket.available(1_000, None), 0);
+
+        // Shadow mode lets an empty bucket through without spending credits
+        bucket.config.enforcement = RuleEnforcement::Shadow;
+        bucket.try_consume_at(3_600, 1, None).unwrap();
+        assert_eq!(bucket.total_shadowed, 1);
+        assert_eq!(bucket.credits, 0);
+        assert_eq!(bucket.total_allowed, 6);
+    }
+
+    #[test]
+    fn test_observer_quorum() {
+        use crate::security::{QuorumAttestation, QuorumSignature};
+
+        let keys: Vec<libsecp256k1::SecretKey> = (1..=4u8)
+            .map(|i| libsecp256k1::SecretKey::parse(&[i; 32]).unwrap())
+            .collect();
+        let address = |key: &libsecp256k1::SecretKey| {
+            let public = libsecp256k1::PublicKey::from_secret_key(key).serialize();
+            SignatureUtils::pubkey_to_ethereum_address(public[1..].try_into().unwrap())
+        };
+        let sign = |key: &libsecp256k1::SecretKey, hash: &[u8; 32]| {
+            let (signature, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(hash), key);
+            QuorumSignature { signature: signature.serialize(), recovery_id: recovery_id.serialize() }
+        };
+
+        // A 2-of-3 set; the fourth key is not registered
+        let set = ValidatorSet {
+            validators: keys[..3].iter().map(address).collect(),
+            threshold: 2,
+            epoch: 1,
+            last_proposal_id: 0,
+            updated_at: 0,
+            bump: 255,
+        };
+        let leaf = LightClientUtils::event_leaf(7001, &[1u8; 20], b"message");
+        let hash = ValidatorSet::signing_hash(7001, &leaf);
+
+        assert_eq!(set.verify_quorum(&hash, &[sign(&keys[0], &hash), sign(&keys[2], &hash)]).unwrap(), 2);
+        assert_eq!(set.verify_quorum(&hash, &keys[..3].iter().map(|k| sign(k, &hash)).collect::<Vec<_>>()).unwrap(), 3);
+
+        // Duplicate and unknown signers don't count towards the threshold
+        assert!(set.verify_quorum(&hash, &[sign(&keys[0], &hash), sign(&keys[0], &hash)]).is_err());
+        assert!(set.verify_quorum(&hash, &[sign(&keys[0], &hash), sign(&keys[3], &hash)]).is_err());
+
+        // Signatures over another chain's hash don't count either
+        let other_hash = ValidatorSet::signing_hash(56, &leaf);
+        assert!(set.verify_quorum(&hash, &[sign(&keys[0], &other_hash), sign(&keys[1], &other_hash)]).is_err());
+
+        // An unconsumed attestation from an earlier epoch is replaced by the
+        // current set's; a consumed one is never reopened
+        let mut attestation = QuorumAttestation {
+            leaf,
+            chain_id: 7001,
+            epoch: 0,
+            approvals: 2,
+            consumed: false,
+            attested_at: 0,
+            bump: 255,
+        };
+        attestation.record(leaf, 7001, set.epoch, 2, 100, 255).unwrap();
+        assert_eq!(attestation.epoch, 1);
+        attestation.consumed = true;
+        assert!(attestation.record(leaf, 7001, 2, 2, 200, 255).is_err());
+    }
+
+    #[test]
+    fn test_inbound_round_trip() {
+        // First arrival: a prepared mint with no token yet is minted into
+        assert_eq!(InboundDeliveryUtils::source(0, true, 0, false).unwrap(), InboundSource::Mint);
+
+        // A reissuable NFT is burned on its way out and minted again on return
+        assert!(!InboundDeliveryUtils::locks_outbound(false, true));
+        assert_eq!(InboundDeliveryUtils::source(0, true, 0, true).unwrap(), InboundSource::Mint);
+        // Once back, a replayed message finds the live token
+        assert_eq!(
+            InboundDeliveryUtils::source(1, true, 0, false).unwrap_err(),
+            UniversalNftError::NftAlreadyOnSolana.into()
+        );
+
+        // A master edition NFT waits in custody and comes out on return
+        assert!(InboundDeliveryUtils::locks_outbound(false, false));
+        assert_eq!(InboundDeliveryUtils::source(1, false, 1, true).unwrap(), InboundSource::Custody);
+        assert!(InboundDeliveryUtils::source(1, false, 0, false).is_err());
+        // A burned master edition NFT can't be minted again
+        assert_eq!(
+            InboundDeliveryUtils::source(0, false, 0, true).unwrap_err(),
+            UniversalNftError::InvalidMintAuthority.into()
+        );
+
+        // Lock-mode collections lock even reissuable NFTs
+        assert!(InboundDeliveryUtils::locks_outbound(true, true));
+        assert_eq!(InboundDeliveryUtils::source(1, true, 1, true).unwrap(), InboundSource::Custody);
+
+        // Custody held for another flow, without the bridge lock, is not released
+        assert_eq!(
+            InboundDeliveryUtils::source(1, true, 1, false).unwrap_err(),
+            UniversalNftError::NftAlreadyOnSolana.into()
+        );
+    }
+
+    #[test]
+    fn test_inbound_mint_seed() {
+        let seed = InboundDeliveryUtils::token_id_seed(&"7".repeat(64));
+        assert_eq!(seed, InboundDeliveryUtils::token_id_seed(&"7".repeat(64)));
+        assert_ne!(seed, InboundDeliveryUtils::token_id_seed("7"));
+    }
+    #[test]
+    fn test_registry_chain_id() {
+        let mut chain = RegisteredChain {
+            chain_id: 8332,
+            chain_kind: ChainKind::Bitcoin,
+            address_format: AddressFormat::Bitcoin,
+            counterpart_contract: [0u8; 20],
+            enabled: true,
+            registered_at: 0,
+            limits: ChainTransferLimits::default(),
+            volume: RollingVolume::default(),
+            primary_route: BridgeRoute {
+                layer: MessageLayer::ZetaGateway,
+                remote_chain_id: 8332,
+                peer: [0u8; 32],
+            },
+            fallback_route: None,
+            bump: 255,
+        };
+        assert!(CrossChainUtils::validate_chain_id(8332, &chain).unwrap());
+        // Chains outside the old hardcoded list are accepted once registered
+        chain.chain_id = 42161;
+        assert!(CrossChainUtils::validate_chain_id(42161, &chain).unwrap());
+        assert_eq!(
+            CrossChainUtils::validate_chain_id(8332, &chain).unwrap_err(),
+            UniversalNftError::ChainNotRegistered.into()
+        );
+        chain.enabled = false;
+        assert_eq!(
+            CrossChainUtils::validate_chain_id(42161, &chain).unwrap_err(),
+            UniversalNftError::ChainDisabled.into()
+        );
+    }
+
+    #[test]
+    fn test_inscription_token_id() {
+        let inscription = InscriptionRef { txid: [7u8; 32], index: 0 };
+        let token_id = inscription.token_id();
+        assert_eq!(token_id.len(), 64);
+        assert_eq!(token_id, inscription.token_id());
+        assert!(token_id.chars().all(|c| c.is_ascii_hexdigit()));
+        let next = InscriptionRef { txid: [7u8; 32], index: 1 };
+        assert_ne!(next.token_id(), token_id);
+    }
+    #[test]
+    fn test_dispute_only_in_flight() {
+        assert!(DisputeUtils::require_disputable(&TransferStatus::Initiated).is_ok());
+        assert!(DisputeUtils::require_disputable(&TransferStatus::Processing).is_ok());
+        for status in [TransferStatus::Completed, TransferStatus::Reverted, TransferStatus::Cancelled] {
+            assert_eq!(
+                DisputeUtils::require_disputable(&status).unwrap_err(),
+                UniversalNftError::InvalidTransferStatus.into()
+            );
+        }
+    }
+
+    #[test]
+    fn test_dispute_resolve_release() {
+        // Upholding the transfer pays the whole escrow to the counterparty
+        assert_eq!(DisputeUtils::payouts(1_000_000, 0).unwrap(), (0, 1_000_000));
+        assert_eq!(DisputeUtils::payouts(1_000_001, 2_500).unwrap(), (250_000, 750_001));
+        assert_eq!(
+            DisputeUtils::payouts(1_000_000, DisputeUtils::MAX_SPLIT_BPS + 1).unwrap_err(),
+            UniversalNftError::InvalidSplitShare.into()
+        );
+    }
+
+    #[test]
+    fn test_dispute_resolve_revert() {
+        // Reverting refunds the opener in full
+        assert_eq!(
+            DisputeUtils::payouts(1_000_000, DisputeUtils::MAX_SPLIT_BPS).unwrap(),
+            (1_000_000, 0)
+        );
+
+        // A locked NFT leaves custody; one no longer there can't be returned
+        assert_eq!(DisputeUtils::revert_source(Some(1), 1).unwrap(), InboundSource::Custody);
+        assert_eq!(
+            DisputeUtils::revert_source(Some(0), 1).unwrap_err(),
+            UniversalNftError::NftNotInCustody.into()
+        );
+
+        // A burned NFT is minted again, a live one never is
+        assert_eq!(DisputeUtils::revert_source(None, 0).unwrap(), InboundSource::Mint);
+        assert_eq!(
+            DisputeUtils::revert_source(None, 1).unwrap_err(),
+            UniversalNftError::InvalidDisputeRevertAccounts.into()
+        );
+    }
+    #[test]
+    fn test_orphan_failed_delivery() {
+        let recipient = Pubkey::new_unique();
+        let sender = [9u8; 20];
+        let message = MessageCodec::encode(&CrossChainMessage::MintNft {
+            token_id: "42".to_string(),
+            name: "Name".to_string(),
+            symbol: "SYM".to_string(),
+            uri: "https://example.com/42.json".to_string(),
+            recipient,
+            collection_mint: None,
+        })
+        .unwrap();
+        let mut record = RelayedMessage {
+            message_hash: RelayerUtils::relayed_message_hash(1, 7, &sender, &message),
+            relayer: Pubkey::new_unique(),
+            source_chain_id: 1,
+            sequence: 7,
+            delivered: true,
+            executed: false,
+            rewarded: false,
+            submitted_at: 0,
+            bump: 255,
+        };
+
+        assert!(OrphanUtils::require_failed_delivery(&record, 7, &sender, &message).is_ok());
+        assert_eq!(OrphanUtils::recipient(&message, "42").unwrap(), recipient);
+
+        // A message the record didn't relay, or one for another NFT, proves nothing
+        assert_eq!(
+            OrphanUtils::require_failed_delivery(&record, 8, &sender, &message).unwrap_err(),
+            UniversalNftError::OrphanProofMismatch.into()
+        );
+        assert_eq!(
+            OrphanUtils::require_failed_delivery(&record, 7, &[1u8; 20], &message).unwrap_err(),
+            UniversalNftError::OrphanProofMismatch.into()
+        );
+        assert_eq!(
+            OrphanUtils::recipient(&message, "43").unwrap_err(),
+            UniversalNftError::OrphanProofMismatch.into()
+        );
+
+        // Executed deliveries didn't fail
+        record.executed = true;
+        assert_eq!(
+            OrphanUtils::require_failed_delivery(&record, 7, &sender, &message).unwrap_err(),
+            UniversalNftError::DeliveryNotFailed.into()
+        );
+    }
+    #[test]
+    fn test_claim_escrow() {
+        let claim_key = Pubkey::new_unique();
+        let service = Pubkey::new_unique();
+        let mut escrow = ClaimEscrow {
+            claim_key,
+            mint: Pubkey::new_unique(),
+            depositor: Pubkey::new_unique(),
+            custody_token_account: Pubkey::new_unique(),
+            claims_service: Some(service),
+            expires_at: 2_000,
+            created_at: 1_000,
+            bump: 255,
+        };
+
+        assert!(ClaimEscrowUtils::validate_expiry(0, 1_000).is_ok());
+        assert!(ClaimEscrowUtils::validate_expiry(2_000, 1_000).is_ok());
+        assert_eq!(
+            ClaimEscrowUtils::validate_expiry(1_000, 1_000).unwrap_err(),
+            UniversalNftError::InvalidClaimEscrow.into()
+        );
+
+        // The claim key or the claims service authorizes a claim
+        assert!(ClaimEscrowUtils::require_claimable(&escrow, Some(claim_key), None, 1_500).is_ok());
+        assert!(ClaimEscrowUtils::require_claimable(&escrow, None, Some(service), 1_500).is_ok());
+
+        // Any other key doesn't, and a wrong claim key isn't rescued by the service
+        let other = Pubkey::new_unique();
+        for (signer, svc) in [(Some(other), None), (None, Some(other)), (None, None), (Some(other), Some(service))] {
+            assert_eq!(
+                ClaimEscrowUtils::require_claimable(&escrow, signer, svc, 1_500).unwrap_err(),
+                UniversalNftError::InvalidClaimProof.into()
+            );
+        }
+
+        // Without a claims service only the claim key works
+        escrow.claims_service = None;
+        assert_eq!(
+            ClaimEscrowUtils::require_claimable(&escrow, None, Some(service), 1_500).unwrap_err(),
+            UniversalNftError::InvalidClaimProof.into()
+        );
+
+        // Claims stop and cancellation opens at expiry
+        assert_eq!(
+            ClaimEscrowUtils::require_claimable(&escrow, Some(claim_key), None, 2_000).unwrap_err(),
+            UniversalNftError::ClaimEscrowExpired.into()
+        );
+        assert_eq!(
+            ClaimEscrowUtils::require_cancellable(&escrow, 1_999).unwrap_err(),
+            UniversalNftError::ClaimEscrowNotExpired.into()
+        );
+        assert!(ClaimEscrowUtils::require_cancellable(&escrow, 2_000).is_ok());
+
+        // An escrow that never expires is never cancelled
+        escrow.expires_at = 0;
+        assert!(ClaimEscrowUtils::require_claimable(&escrow, Some(claim_key), None, i64::MAX).is_ok());
+        assert_eq!(
+            ClaimEscrowUtils::require_cancellable(&escrow, i64::MAX).unwrap_err(),
+            UniversalNftError::ClaimEscrowNotExpired.into()
+        );
+    }
+    #[test]
+    fn test_transfer_payload() {
+        assert!(CrossChainUtils::validate_payload(&[]).is_ok());
+        assert!(CrossChainUtils::validate_payload(&[7u8; CrossChainTransfer::MAX_PAYLOAD_LEN]).is_ok());
+        assert_eq!(
+            CrossChainUtils::validate_payload(&[7u8; CrossChainTransfer::MAX_PAYLOAD_LEN + 1]).unwrap_err(),
+            UniversalNftError::PayloadTooLarge.into()
+        );
+
+        let recipient = Pubkey::new_unique();
+        let mint_message = || CrossChainMessage::MintNft {
+            token_id: "42".to_string(),
+            name: "Name".to_string(),
+            symbol: "SYM".to_string(),
+            uri: "https://example.com/42.json".to_string(),
+            recipient,
+            collection_mint: None,
+        };
+
+        // No payload sends the plain mint message
+        assert!(matches!(
+            CrossChainUtils::attach_payload(mint_message(), Vec::new()),
+            CrossChainMessage::MintNft { .. }
+        ));
+
+        // A payload reaches the destination intact
+        let payload = vec![7u8; CrossChainTransfer::MAX_PAYLOAD_LEN];
+        let message = CrossChainUtils::attach_payload(mint_message(), payload.clone());
+        match MessageCodec::decode(&MessageCodec::encode(&message).unwrap()).unwrap() {
+            CrossChainMessage::MintNftWithPayload { token_id, recipient: to, payload: delivered, .. } => {
+                assert_eq!(token_id, "42");
+                assert_eq!(to, recipient);
+                assert_eq!(delivered, payload);
+            }
+            other => panic!("unexpected message {:?}", other),
+        }
+    }
+    #[test]
+    fn test_transfer_receipt() {
+        let mut transfer = CrossChainTransfer {
+            nft_mint: Pubkey::new_unique(),
+            source_chain_id: 900,
+            destination_chain_id: 1,
+            sender: [0u8; 20],
+            recipient: vec![1u8; 20],
+            gas_limit: 100_000,
+            nonce: 1,
+            timestamp: 1_000,
+            status: TransferStatus::Initiated,
+            bump: 255,
+            gas_payment: GasPayment::Sol,
+            gas_fee: 0,
+            payload: Vec::new(),
+            initiator: Pubkey::new_unique(),
+        };
+        let expiry = 1_000 + ReceiptUtils::TRANSFER_EXPIRY_SECONDS;
+
+        assert!(ReceiptUtils::require_in_flight(&TransferStatus::Initiated).is_ok());
+        assert!(ReceiptUtils::require_in_flight(&TransferStatus::Processing).is_ok());
+
+        // Cancellable only after the window, by the holder of the unburned receipt
+        assert_eq!(
+            ReceiptUtils::require_cancellable(&transfer, 1, expiry).unwrap_err(),
+            UniversalNftError::TransferNotExpired.into()
+        );
+        assert!(ReceiptUtils::require_cancellable(&transfer, 1, expiry + 1).is_ok());
+        assert_eq!(
+            ReceiptUtils::require_cancellable(&transfer, 0, expiry + 1).unwrap_err(),
+            UniversalNftError::InvalidTransferReceipt.into()
+        );
+
+        // A confirmed or already cancelled transfer is never confirmed or cancelled again
+        for status in [TransferStatus::Completed, TransferStatus::Cancelled] {
+            assert_eq!(
+                ReceiptUtils::require_in_flight(&status).unwrap_err(),
+                UniversalNftError::InvalidTransferStatus.into()
+            );
+            transfer.status = status;
+            assert_eq!(
+                ReceiptUtils::require_cancellable(&transfer, 1, expiry + 1).unwrap_err(),
+                UniversalNftError::InvalidTransferStatus.into()
+            );
+        }
+    }
+    #[test]
+    fn test_scheduled_transfer() {
+        let recipient = vec![1u8; 20];
+        let mint = Pubkey::new_unique();
+        for delay in [0, ScheduledTransfer::MIN_DELAY_SECONDS - 1, ScheduledTransfer::MAX_DELAY_SECONDS + 1] {
+            assert_eq!(
+                ScheduledTransfer::execute_after(1_000, delay).unwrap_err(),
+                UniversalNftError::InvalidScheduleDelay.into()
+            );
+        }
+        assert_eq!(
+            ScheduledTransfer::execute_after(i64::MAX, ScheduledTransfer::MIN_DELAY_SECONDS).unwrap_err(),
+            UniversalNftError::ArithmeticOverflow.into()
+        );
+
+        let execute_after = ScheduledTransfer::execute_after(1_000, 3_600).unwrap();
+        assert_eq!(execute_after, 4_600);
+        let scheduled = ScheduledTransfer {
+            owner: Pubkey::new_unique(),
+            mint,
+            destination_chain_id: 1,
+            recipient: recipient.clone(),
+            gas_limit: 100_000,
+            execute_after,
+            created_at: 1_000,
+            bump: 255,
+        };
+
+        // Cancellable during the window, executable only after it
+        assert!(scheduled.require_cancellable(4_599).is_ok());
+        assert_eq!(
+            scheduled.require_executable(mint, 1, &recipient, 100_000, 4_599).unwrap_err(),
+            UniversalNftError::ScheduleNotReady.into()
+        );
+        assert_eq!(
+            scheduled.require_cancellable(4_600).unwrap_err(),
+            UniversalNftError::ScheduleWindowClosed.into()
+        );
+        assert!(scheduled.require_executable(mint, 1, &recipient, 100_000, 4_600).is_ok());
+
+        // The executed transfer is the one that was reviewed
+        for (mint, chain, to, gas) in [
+            (Pubkey::new_unique(), 1, recipient.clone(), 100_000),
+            (mint, 56, recipient.clone(), 100_000),
+            (mint, 1, vec![2u8; 20], 100_000),
+            (mint, 1, recipient.clone(), 200_000),
+        ] {
+            assert_eq!(
+                scheduled.require_executable(mint, chain, &to, gas, 4_600).unwrap_err(),
+                UniversalNftError::ScheduleMismatch.into()
+            );
+        }
+    }
+    #[test]
+    fn test_insurance_policy_and_payout() {
+        use crate::recovery::insurance::{InsurancePolicy, InsurancePool};
+        use crate::state::TransferStatus;
+
+        let user = Pubkey::new_unique();
+        let mut transfer = outbound_transfer(user);
+        let mut pool = InsurancePool {
+            authority: Pubkey::new_unique(),
+            premium_lamports: 0,
+            payout_lamports: 0,
+            total_premiums: 0,
+            total_payouts: 0,
+            policies_written: 0,
+            claims_paid: 0,
+            enabled: false,
+            bump: 0,
+        };
+
+        // Payouts never undercut the premium
+        assert_eq!(
+            pool.initialize(pool.authority, 1_000, 999, 255).unwrap_err(),
+            UniversalNftError::InvalidInsuranceParameters.into()
+        );
+        pool.initialize(pool.authority, 1_000, 50_000, 255).unwrap();
+
+        // The receipt holder insures a transfer that has just started
+        assert!(InsurancePool::require_insurable(&transfer, 1).is_ok());
+        assert_eq!(
+            InsurancePool::require_insurable(&transfer, 0).unwrap_err(),
+            UniversalNftError::InvalidTransferReceipt.into()
+        );
+        transfer.status = TransferStatus::Processing;
+        assert_eq!(
+            InsurancePool::require_insurable(&transfer, 1).unwrap_err(),
+            UniversalNftError::InvalidTransferStatus.into()
+        );
+
+        // Premiums are recorded and the payout is capped at a priced NFT's floor
+        let mut policy = InsurancePolicy {
+            transfer: Pubkey::default(),
+            nft_mint: Pubkey::default(),
+            insured: Pubkey::default(),
+            premium_paid: 0,
+            payout_amount: 0,
+            purchased_at: 0,
+            claimed: true,
+            bump: 0,
+        };
+        let transfer_key = Pubkey::new_unique();
+        pool.write_policy(&mut policy, transfer_key, transfer.nft_mint, user, 20_000, 1_700, 254).unwrap();
+        assert_eq!((pool.total_premiums, pool.policies_written), (1_000, 1));
+        assert_eq!(policy.transfer, transfer_key);
+        assert_eq!((policy.premium_paid, policy.payout_amount), (1_000, 20_000));
+        assert_eq!(policy.purchased_at, 1_700);
+        assert!(!policy.claimed);
+        assert_eq!(pool.payout_for(0), 50_000);
+        assert_eq!(pool.payout_for(80_000), 50_000);
+
+        // A disabled pool writes no policies
+        pool.update_parameters(1_000, 50_000, false).unwrap();
+        assert_eq!(
+            pool.write_policy(&mut policy, transfer_key, transfer.nft_mint, user, 0, 1_700, 254).unwrap_err(),
+            UniversalNftError::InsuranceDisabled.into()
+        );
+
+        // The pool pays out only what stays above its rent exempt minimum
+        assert!(InsurancePool::require_payable(20_000, 21_000, 1_000).is_ok());
+        assert_eq!(
+            InsurancePool::require_payable(20_000, 20_999, 1_000).unwrap_err(),
+            UniversalNftError::InsufficientVaultFunds.into()
+        );
+
+        pool.record_claim(&mut policy, 20_000).unwrap();
+        assert!(policy.claimed);
+        assert_eq!((pool.total_payouts, pool.claims_paid), (20_000, 1));
+    }
+    fn owned_nft(owner: Pubkey) -> UniversalNft {
+        UniversalNft {
+            mint: Pubkey::new_unique(),
+            origin_chain_id: 900,
+            origin_token_id: "42".to_string(),
+            owner,
+            uri: "https://example.com/42.json".to_string(),
+            name: "Name".to_string(),
+            symbol: "SYM".to_string(),
+            collection_mint: None,
+            creation_block: 0,
+            creation_timestamp: 0,
+            bump: 255,
+            is_locked: false,
+            is_disputed: false,
+            is_frozen: false,
+            transfer_in_progress: false,
+            is_flagged: false,
+        }
+    }
+
+    #[test]
+    fn test_batch_transfer_items() {
+        let owner = Pubkey::new_unique();
+        let nft = owned_nft(owner);
+        let mint = nft.mint;
+        assert!(BatchTransferUtils::require_transferable(&nft, mint, mint, owner, owner, &[]).is_ok());
+
+        // Items must match their accounts and appear once
+        for (mint_key, token_mint, batched) in [
+            (Pubkey::new_unique(), mint, vec![]),
+            (mint, Pubkey::new_unique(), vec![]),
+            (mint, mint, vec![mint]),
+        ] {
+            assert_eq!(
+                BatchTransferUtils::require_transferable(&nft, mint_key, token_mint, owner, owner, &batched)
+                    .unwrap_err(),
+                UniversalNftError::InvalidBatchAccounts.into()
+            );
+        }
+
+        // Only the owner's own NFTs, from the owner's own token account
+        let other = Pubkey::new_unique();
+        assert_eq!(
+            BatchTransferUtils::require_transferable(&nft, mint, mint, owner, other, &[]).unwrap_err(),
+            UniversalNftError::InvalidNftOwner.into()
+        );
+        assert_eq!(
+            BatchTransferUtils::require_transferable(&nft, mint, mint, other, owner, &[]).unwrap_err(),
+            UniversalNftError::InvalidNftOwner.into()
+        );
+
+        // Held NFTs stay put
+        for (held_nft, error) in [
+            (UniversalNft { transfer_in_progress: true, ..nft.clone() }, UniversalNftError::TransferInProgress),
+            (UniversalNft { is_locked: true, ..nft.clone() }, UniversalNftError::NftLocked),
+            (UniversalNft { is_disputed: true, ..nft.clone() }, UniversalNftError::NftUnderDispute),
+            (UniversalNft { is_frozen: true, ..nft.clone() }, UniversalNftError::NftFrozen),
+        ] {
+            assert_eq!(
+                BatchTransferUtils::require_transferable(&held_nft, mint, mint, owner, owner, &[]).unwrap_err(),
+                error.into()
+            );
+        }
+
+        let item = BatchTransferUtils::item(&nft);
+        assert_eq!((item.token_id.as_str(), item.collection_mint), ("42", None));
+    }
+
+    #[test]
+    fn test_batch_transfer_collections() {
+        let limits = ChainTransferLimits::default();
+        assert!(BatchTransferUtils::require_uncapped(&limits).is_ok());
+        assert_eq!(
+            BatchTransferUtils::require_uncapped(&ChainTransferLimits { daily_value_cap: 1, ..limits }).unwrap_err(),
+            UniversalNftError::BatchValueCapUnsupported.into()
+        );
+        // A transfer count cap doesn't need prices
+        assert!(BatchTransferUtils::require_uncapped(&ChainTransferLimits { daily_transfer_cap: 5, ..limits }).is_ok());
+
+        let collection_mint = Pubkey::new_unique();
+        let mut config = CollectionConfig {
+            collection_mint,
+            max_supply: 0,
+            minted_count: 0,
+            allowed_destination_chains: vec![1],
+            transfer_mode: CollectionTransferMode::Burn,
+            seller_fee_basis_points: 0,
+            is_paused: false,
+            phase_count: 0,
+            phases_end: 0,
+            bump: 255,
+        };
+        let mut hooks = CollectionHooks {
+            collection_mint,
+            authority: Pubkey::new_unique(),
+            hooks: vec![crate::state::HookEntry {
+                program_id: Pubkey::new_unique(),
+                event_mask: HookEvent::Mint.mask() | HookEvent::Transfer.mask(),
+                compute_allowance: 10_000,
+                account_count: 0,
+            }],
+            bump: 255,
+        };
+        assert!(BatchTransferUtils::require_batchable_collection(None, None, 1).is_ok());
+        assert!(BatchTransferUtils::require_batchable_collection(Some(&hooks), Some(&config), 1).is_ok());
+
+        // The collection's destination allowlist still applies
+        assert_eq!(
+            BatchTransferUtils::require_batchable_collection(Some(&hooks), Some(&config), 56).unwrap_err(),
+            UniversalNftError::DestinationChainNotAllowed.into()
+        );
+
+        // Custody and bridge-out hooks need a single transfer
+        config.transfer_mode = CollectionTransferMode::Lock;
+        assert_eq!(
+            BatchTransferUtils::require_batchable_collection(None, Some(&config), 1).unwrap_err(),
+            UniversalNftError::BatchTransferModeUnsupported.into()
+        );
+        hooks.hooks[0].event_mask |= HookEvent::BridgeOut.mask();
+        assert_eq!(
+            BatchTransferUtils::require_batchable_collection(Some(&hooks), None, 1).unwrap_err(),
+            UniversalNftError::BatchHooksUnsupported.into()
+        );
+    }
+    #[test]
+    fn test_compliance_freeze() {
+        let owner = Pubkey::new_unique();
+        let mut nft = owned_nft(owner);
+        let mut compliance = ComplianceConfig {
+            authority: Pubkey::new_unique(),
+            compliance_officer: Pubkey::new_unique(),
+            frozen_count: 0,
+            bump: 255,
+        };
+
+        // Only the account holding the NFT is frozen
+        assert_eq!(
+            compliance.record_freeze(&mut nft, 0).unwrap_err(),
+            UniversalNftError::InvalidNftOwner.into()
+        );
+        compliance.record_freeze(&mut nft, 1).unwrap();
+        assert!(nft.is_frozen);
+        assert_eq!(compliance.frozen_count, 1);
+        assert_eq!(
+            compliance.record_freeze(&mut nft, 1).unwrap_err(),
+            UniversalNftError::NftFrozen.into()
+        );
+
+        // A frozen NFT can't leave through a bridge transfer
+        assert_eq!(
+            BatchTransferUtils::require_transferable(&nft, nft.mint, nft.mint, owner, owner, &[]).unwrap_err(),
+            UniversalNftError::NftFrozen.into()
+        );
+
+        compliance.record_release(&mut nft, 1).unwrap();
+        assert!(!nft.is_frozen);
+        assert_eq!(compliance.frozen_count, 0);
+        assert_eq!(
+            compliance.record_release(&mut nft, 1).unwrap_err(),
+            UniversalNftError::NftNotFrozen.into()
+        );
+        assert!(BatchTransferUtils::require_transferable(&nft, nft.mint, nft.mint, owner, owner, &[]).is_ok());
+    }
+    #[test]
+    fn test_burn_stats() {
+        let owner = Pubkey::new_unique();
+        let nft = owned_nft(owner);
+        assert!(nft.require_releasable_by(&owner).is_ok());
+        assert_eq!(
+            nft.require_releasable_by(&Pubkey::new_unique()).unwrap_err(),
+            UniversalNftError::InvalidNftOwner.into()
+        );
+        assert_eq!(
+            UniversalNft { is_locked: true, ..nft.clone() }.require_releasable_by(&owner).unwrap_err(),
+            UniversalNftError::NftLocked.into()
+        );
+        assert_eq!(
+            UniversalNft { is_frozen: true, ..nft.clone() }.require_releasable_by(&owner).unwrap_err(),
+            UniversalNftError::NftFrozen.into()
+        );
+
+        let collection_mint = Pubkey::new_unique();
+        let mut collection = CollectionBurnStats {
+            collection_mint: Pubkey::default(),
+            total_burned: 0,
+            unique_burners: 0,
+            last_burn_at: 0,
+            bump: 0,
+        };
+        let fresh_user = || UserBurnStats {
+            user: Pubkey::default(),
+            collection_mint: Pubkey::default(),
+            total_burned: 0,
+            first_burn_at: 0,
+            last_burn_at: 0,
+            bump: 0,
+        };
+
+        // A wallet's burns all count, but the wallet counts as one burner
+        let mut first = fresh_user();
+        for now in [10, 20] {
+            let first_burn = first.record(owner, collection_mint, now, 254).unwrap();
+            collection.record(collection_mint, first_burn, now, 253).unwrap();
+        }
+        assert_eq!((first.user, first.collection_mint), (owner, collection_mint));
+        assert_eq!((first.total_burned, first.first_burn_at, first.last_burn_at), (2, 10, 20));
+        assert_eq!((collection.total_burned, collection.unique_burners), (2, 1));
+
+        let mut second = fresh_user();
+        let first_burn = second.record(Pubkey::new_unique(), collection_mint, 30, 252).unwrap();
+        assert!(first_burn);
+        collection.record(collection_mint, first_burn, 30, 253).unwrap();
+        assert_eq!((collection.total_burned, collection.unique_burners), (3, 2));
+        assert_eq!((collection.collection_mint, collection.last_burn_at, collection.bump), (collection_mint, 30, 253));
+    }
+    #[test]
+    fn test_wallet_lock() {
+        let wallet = Pubkey::new_unique();
+        let mut lock = WalletLock {
+            wallet: Pubkey::default(),
+            locked: false,
+            unlock_delay: 0,
+            unlock_requested_at: 0,
+            locked_at: 0,
+            bump: 0,
+        };
+        assert!(!lock.is_active(0));
+
+        for delay in [WalletLock::MIN_UNLOCK_DELAY - 1, WalletLock::MAX_UNLOCK_DELAY + 1] {
+            assert_eq!(
+                lock.lock(wallet, delay, 100, 255).unwrap_err(),
+                UniversalNftError::InvalidUnlockDelay.into()
+            );
+        }
+        lock.lock(wallet, 7_200, 100, 255).unwrap();
+        assert!(lock.is_active(100));
+
+        // An active lock's delay can only grow
+        assert_eq!(
+            lock.lock(wallet, 3_600, 200, 255).unwrap_err(),
+            UniversalNftError::InvalidUnlockDelay.into()
+        );
+
+        // Transfers stay blocked for the whole delay after an unlock request
+        assert_eq!(lock.request_unlock(1_000).unwrap(), 8_200);
+        assert_eq!(
+            lock.request_unlock(1_001).unwrap_err(),
+            UniversalNftError::WalletNotLocked.into()
+        );
+        assert!(lock.is_active(8_199));
+        assert!(!lock.is_active(8_200));
+
+        // Locking again cancels the pending unlock
+        let mut relocked = lock.clone();
+        relocked.lock(wallet, 7_200, 5_000, 255).unwrap();
+        assert!(relocked.is_active(i64::MAX));
+
+        // Once the unlock has passed, a new lock may use a shorter delay
+        assert_eq!(
+            lock.request_unlock(9_000).unwrap_err(),
+            UniversalNftError::WalletNotLocked.into()
+        );
+        lock.lock(wallet, 3_600, 9_000, 255).unwrap();
+        assert_eq!(lock.unlock_delay, 3_600);
+
+        // Outbound paths read the lock PDA; an uncreated one never blocks
+        let key = Pubkey::new_unique();
+        let mut data = Vec::new();
+        lock.try_serialize(&mut data).unwrap();
+        let mut lamports = 1;
+        let lock_info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &crate::ID, false, 0);
+        assert_eq!(
+            WalletLockUtils::require_unlocked_at(&lock_info, 9_000).unwrap_err(),
+            UniversalNftError::WalletTransfersLocked.into()
+        );
+        lock.request_unlock(9_000).unwrap();
+        let mut data = Vec::new();
+        lock.try_serialize(&mut data).unwrap();
+        let mut lamports = 1;
+        let lock_info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &crate::ID, false, 0);
+        assert!(WalletLockUtils::require_unlocked_at(&lock_info, 12_600).is_ok());
+
+        let mut empty = Vec::new();
+        let mut lamports = 0;
+        let never_locked = AccountInfo::new(&key, false, false, &mut lamports, &mut empty, &crate::ID, false, 0);
+        assert!(WalletLockUtils::require_unlocked_at(&never_locked, 0).is_ok());
+    }
+    #[test]
+    fn test_operator_session() {
+        let owner = Pubkey::new_unique();
+        let operator = Pubkey::new_unique();
+        let bridge = SessionScope::Bridge.mask();
+        assert!(OperatorSession::validate_terms(&owner, &operator, bridge, 1_100, 100).is_ok());
+        for (operator, scopes, expires_slot) in [
+            (operator, 0, 1_100),
+            (operator, 1 << 3, 1_100),
+            (owner, bridge, 1_100),
+            (operator, bridge, 100),
+            (operator, bridge, 101 + OperatorSession::MAX_SESSION_SLOTS),
+        ] {
+            assert_eq!(
+                OperatorSession::validate_terms(&owner, &operator, scopes, expires_slot, 100).unwrap_err(),
+                UniversalNftError::InvalidOperatorSession.into()
+            );
+        }
+
+        let mut session = OperatorSession {
+            owner,
+            operator,
+            scopes: bridge | SessionScope::Metadata.mask(),
+            bridge_chain_id: Some(1),
+            expires_slot: 1_100,
+            created_at: 0,
+            bump: 255,
+        };
+
+        // Granted scopes only, to the named chain, until expiry
+        assert!(session.authorize(SessionScope::Bridge, Some(1), 500).is_ok());
+        assert!(session.authorize(SessionScope::Metadata, None, 500).is_ok());
+        assert_eq!(
+            session.authorize(SessionScope::Transfer, None, 500).unwrap_err(),
+            UniversalNftError::OperatorScopeNotGranted.into()
+        );
+        assert_eq!(
+            session.authorize(SessionScope::Bridge, Some(56), 500).unwrap_err(),
+            UniversalNftError::OperatorScopeNotGranted.into()
+        );
+        assert_eq!(
+            session.authorize(SessionScope::Bridge, Some(1), 1_100).unwrap_err(),
+            UniversalNftError::OperatorSessionExpired.into()
+        );
+
+        // A revoked session grants nothing
+        session.scopes = 0;
+        session.expires_slot = 0;
+        assert_eq!(
+            session.authorize(SessionScope::Metadata, None, 0).unwrap_err(),
+            UniversalNftError::OperatorSessionExpired.into()
+        );
+
+        // The owner needs no session; anyone else without one is refused
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mut data = Vec::new();
+        let no_session = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &crate::ID, false, 0);
+        assert!(
+            OperatorSessionUtils::authorize_signer(&owner, &owner, &no_session, SessionScope::Bridge, Some(1)).is_ok()
+        );
+        assert_eq!(
+            OperatorSessionUtils::authorize_signer(&owner, &operator, &no_session, SessionScope::Bridge, Some(1))
+                .unwrap_err(),
+            UniversalNftError::InvalidNftOwner.into()
+        );
     }
 }
\ No newline at end of file
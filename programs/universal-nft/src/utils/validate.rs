@@ -0,0 +1,93 @@
+//! Pre-flight account checks run at the top of instructions. Anchor's
+//! constraint failures only name the constraint; these report which
+//! property of which account is wrong.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::errors::UniversalNftError;
+
+/// Token account must be `owner`'s associated token account for `mint`
+pub fn associated_token_account(
+    token_account: &Account<TokenAccount>,
+    mint: &Pubkey,
+    owner: &Pubkey,
+) -> Result<()> {
+    require_keys_eq!(token_account.mint, *mint, UniversalNftError::InvalidAssociatedTokenAccount);
+    require_keys_eq!(token_account.owner, *owner, UniversalNftError::InvalidNftOwner);
+    require_keys_eq!(
+        token_account.key(),
+        get_associated_token_address(owner, mint),
+        UniversalNftError::InvalidAssociatedTokenAccount
+    );
+    Ok(())
+}
+
+/// Mint must describe a single non-divisible token
+pub fn nft_mint(mint: &Mint) -> Result<()> {
+    require!(mint.decimals == 0, UniversalNftError::InvalidNftDecimals);
+    require!(mint.supply == 1, UniversalNftError::InvalidNftSupply);
+    Ok(())
+}
+
+/// Mint must be a universal NFT: a single non-divisible token whose mint
+/// authority is the program's mint authority PDA, or the master edition that
+/// takes it over at mint time. Neither will mint a second token, which rules
+/// out fungible tokens presented as NFTs.
+pub fn universal_nft_mint(mint: &Account<Mint>) -> Result<()> {
+    nft_mint(mint)?;
+    universal_mint_authority(mint)
+}
+
+/// Mint an inbound message delivers into: a universal NFT that was burned
+/// when it left Solana, or prepared for its first arrival (supply 0), or
+/// one held in custody while it was away (supply 1)
+pub fn inbound_universal_nft_mint(mint: &Account<Mint>) -> Result<()> {
+    require!(mint.decimals == 0, UniversalNftError::InvalidNftDecimals);
+    require!(mint.supply <= 1, UniversalNftError::InvalidNftSupply);
+    universal_mint_authority(mint)
+}
+
+/// Whether the program's mint authority PDA still holds the mint, so a
+/// burned NFT can be minted again. A master edition never mints it again.
+pub fn reissuable(mint: &Mint) -> bool {
+    let (program_authority, _) = Pubkey::find_program_address(&[b"mint_authority"], &crate::ID);
+    mint.mint_authority == COption::Some(program_authority)
+}
+
+fn universal_mint_authority(mint: &Account<Mint>) -> Result<()> {
+    let mint_key = mint.key();
+    let (program_authority, _) = Pubkey::find_program_address(&[b"mint_authority"], &crate::ID);
+    let (master_edition, _) = Pubkey::find_program_address(
+        &[b"metadata", mpl_token_metadata::ID.as_ref(), mint_key.as_ref(), b"edition"],
+        &mpl_token_metadata::ID,
+    );
+    require!(
+        mint.mint_authority == COption::Some(program_authority)
+            || mint.mint_authority == COption::Some(master_edition),
+        UniversalNftError::InvalidMintAuthority
+    );
+    Ok(())
+}
+
+/// Account must be the Token Metadata PDA for `mint`
+pub fn metadata_pda(metadata: &Pubkey, mint: &Pubkey) -> Result<()> {
+    let (expected, _) = Pubkey::find_program_address(
+        &[b"metadata", mpl_token_metadata::ID.as_ref(), mint.as_ref()],
+        &mpl_token_metadata::ID,
+    );
+    require_keys_eq!(*metadata, expected, UniversalNftError::InvalidMetadataAccount);
+    Ok(())
+}
+
+/// Token account must hold the NFT and have `delegate` approved for it
+pub fn delegated_to(token_account: &Account<TokenAccount>, delegate: &Pubkey) -> Result<()> {
+    require!(token_account.amount == 1, UniversalNftError::InvalidNftSupply);
+    require!(
+        token_account.delegate == COption::Some(*delegate) && token_account.delegated_amount >= 1,
+        UniversalNftError::InvalidTokenDelegate
+    );
+    Ok(())
+}
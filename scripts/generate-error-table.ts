@@ -0,0 +1,57 @@
+/**
+ * Generates the SDK's error table from the program IDL.
+ *
+ * Run after `anchor build`:
+ *   npx ts-node scripts/generate-error-table.ts
+ *
+ * Program error messages may end in "Hint: ...", which is split out into a
+ * separate remediation field for front-ends.
+ */
+
+import fs from "fs";
+import path from "path";
+
+const IDL_PATH = path.join(__dirname, "../target/idl/universal_nft.json");
+const OUTPUT_PATH = path.join(__dirname, "../sdk/typescript/src/errorTable.ts");
+const HINT_MARKER = ". Hint: ";
+
+interface IdlError {
+  code: number;
+  name: string;
+  msg?: string;
+}
+
+function main() {
+  const idl = JSON.parse(fs.readFileSync(IDL_PATH, "utf8"));
+  const errors: IdlError[] = idl.errors ?? [];
+
+  const rows = errors.map(({ code, name, msg = "" }) => {
+    const split = msg.indexOf(HINT_MARKER);
+    const message = split === -1 ? msg : msg.slice(0, split);
+    const hint = split === -1 ? null : msg.slice(split + HINT_MARKER.length);
+    return `  ${code}: { code: ${code}, name: ${JSON.stringify(name)}, message: ${JSON.stringify(
+      message
+    )}, hint: ${JSON.stringify(hint)} },`;
+  });
+
+  const output = [
+    "// Generated by scripts/generate-error-table.ts from the program IDL. Do not edit.",
+    "",
+    "export interface ProgramErrorEntry {",
+    "  code: number;",
+    "  name: string;",
+    "  message: string;",
+    "  hint: string | null;",
+    "}",
+    "",
+    "export const PROGRAM_ERRORS: Record<number, ProgramErrorEntry> = {",
+    ...rows,
+    "};",
+    "",
+  ].join("\n");
+
+  fs.writeFileSync(OUTPUT_PATH, output);
+  console.log(`Wrote ${errors.length} errors to ${path.relative(process.cwd(), OUTPUT_PATH)}`);
+}
+
+main();
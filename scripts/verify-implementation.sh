@@ -44,7 +44,7 @@ run_test "Core program files exist" "[ -f 'programs/universal-nft/src/lib.rs' ]
 run_test "State definitions exist" "[ -f 'programs/universal-nft/src/state.rs' ]"
 run_test "Instructions module exists" "[ -d 'programs/universal-nft/src/instructions' ]"
 run_test "Security modules exist" "[ -d 'programs/universal-nft/src/security' ] && [ -f 'programs/universal-nft/src/security/circuit_breaker.rs' ]"
-run_test "Governance modules exist" "[ -d 'programs/universal-nft/src/governance' ] && [ -f 'programs/universal-nft/src/governance/dao.rs' ]"
+run_test "Governance program exists" "[ -f 'programs/universal-nft-governance/src/lib.rs' ] && [ -f 'programs/universal-nft-governance/src/dao.rs' ]"
 run_test "Analytics modules exist" "[ -d 'programs/universal-nft/src/analytics' ] && [ -f 'programs/universal-nft/src/analytics/metrics.rs' ]"
 run_test "Recovery modules exist" "[ -d 'programs/universal-nft/src/recovery' ] && [ -f 'programs/universal-nft/src/recovery/error_recovery.rs' ]"
 
@@ -132,10 +132,11 @@ echo "-------------------------------------------"
 
 run_test "Circuit breaker implementation" "grep -q 'CircuitBreaker' programs/universal-nft/src/security/circuit_breaker.rs"
 run_test "Fraud detection system" "grep -q 'FraudDetection' programs/universal-nft/src/security/fraud_detection.rs"
-run_test "DAO governance system" "grep -q 'UniversalNftDAO' programs/universal-nft/src/governance/dao.rs"
+run_test "DAO governance system" "grep -q 'UniversalNftDAO' programs/universal-nft-governance/src/dao.rs"
 run_test "Analytics and monitoring" "grep -q 'MetricsCollector' programs/universal-nft/src/analytics/metrics.rs"
 run_test "Error recovery system" "grep -q 'ErrorRecoveryManager' programs/universal-nft/src/recovery/error_recovery.rs"
 run_test "Enterprise solutions" "grep -q 'EnterpriseManager' integrations/enterprise/enterprise_solutions.rs"
+run_test "Governance PDAs only referenced through seed constants" "! grep -rnE 'b\"(treasury|bounty_program|bounty_report)\"' programs/universal-nft/src"
 
 # FINAL RESULTS
 echo ""
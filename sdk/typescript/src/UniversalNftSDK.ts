@@ -10,6 +10,8 @@ import {
   PublicKey, 
   Transaction, 
   TransactionInstruction,
+  VersionedTransaction,
+  AddressLookupTableAccount,
   Keypair,
   SystemProgram,
   SYSVAR_RENT_PUBKEY,
@@ -24,6 +26,15 @@ import {
 } from '@solana/spl-token';
 import { Program, AnchorProvider, web3, BN } from '@coral-xyz/anchor';
 import { EventEmitter } from 'events';
+import {
+  programStaticAccounts,
+  createLookupTableInstructions,
+  extendLookupTableInstructions,
+  missingAddresses,
+  fetchLookupTables,
+  waitForLookupTableActivation,
+  buildV0Transaction,
+} from './lookupTables';
 
 // Type definitions
 export interface UniversalNftConfig {
@@ -34,6 +45,7 @@ export interface UniversalNftConfig {
   gatewayProgramId?: PublicKey;
   enableAnalytics?: boolean;
   retryConfig?: RetryConfig;
+  lookupTables?: PublicKey[];
 }
 
 export interface RetryConfig {
@@ -205,7 +217,7 @@ export class UniversalNftSDK extends EventEmitter {
       
       // Create instruction
       const instruction = await this.program!.methods
-        .mintNft(metadata.name, metadata.symbol, metadata.uri, metadata.collection || null)
+        .mintNft(metadata.name, metadata.symbol, metadata.uri, metadata.collection || null, [], null)
         .accounts({
           config: configPda,
           universalNft: universalNftPda,
@@ -213,7 +225,10 @@ export class UniversalNftSDK extends EventEmitter {
           metadata: metadataPda,
           masterEdition: masterEditionPda,
           tokenAccount,
-          mintAuthority: universalNftPda,
+          mintAuthority: PublicKey.findProgramAddressSync(
+            [Buffer.from('mint_authority')],
+            this.program!.programId
+          )[0],
           owner,
           payer: this.provider!.wallet.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -291,9 +306,9 @@ export class UniversalNftSDK extends EventEmitter {
         this.config.programId
       );
       
-      // Get current nonce
+      // The transfer PDA is derived from the current nonce
       const config = await this.program!.account.programConfig.fetch(configPda);
-      const nonce = config.nonce.add(new BN(1));
+      const nonce = config.nonce;
       
       const [transferPda] = PublicKey.findProgramAddressSync(
         [
@@ -440,6 +455,50 @@ export class UniversalNftSDK extends EventEmitter {
     return this.performanceProfiler.getMetrics();
   }
 
+  /**
+   * 🗂️ ADDRESS LOOKUP TABLES
+   */
+  async createLookupTable(extraAddresses: PublicKey[] = []): Promise<PublicKey> {
+    const payer = this.provider!.wallet.publicKey;
+    const recentSlot = await this.config.connection.getSlot('finalized');
+    const addresses = [
+      ...programStaticAccounts(this.config.programId, this.config.gatewayProgramId),
+      ...extraAddresses,
+    ];
+    
+    const { lookupTable, instructions } = createLookupTableInstructions(
+      payer,
+      payer,
+      recentSlot,
+      addresses
+    );
+    
+    // Create and each extend go in their own legacy transaction
+    for (const instruction of instructions) {
+      const transaction = await this.createLegacyTransaction([instruction]);
+      await this.sendTransactionWithRetry(transaction);
+    }
+    
+    await waitForLookupTableActivation(this.config.connection, lookupTable);
+    this.config.lookupTables = [...(this.config.lookupTables || []), lookupTable];
+    
+    return lookupTable;
+  }
+
+  async extendLookupTable(lookupTable: PublicKey, addresses: PublicKey[]): Promise<void> {
+    const payer = this.provider!.wallet.publicKey;
+    const [table] = await fetchLookupTables(this.config.connection, [lookupTable]);
+    const missing = missingAddresses(table, addresses);
+    if (missing.length === 0) return;
+    
+    for (const instruction of extendLookupTableInstructions(lookupTable, payer, payer, missing)) {
+      const transaction = await this.createLegacyTransaction([instruction]);
+      await this.sendTransactionWithRetry(transaction);
+    }
+    
+    await waitForLookupTableActivation(this.config.connection, lookupTable);
+  }
+
   /**
    * 🔧 UTILITY METHODS
    */
@@ -449,28 +508,43 @@ export class UniversalNftSDK extends EventEmitter {
       priorityFee?: number;
       computeUnitLimit?: number;
     }
-  ): Promise<Transaction> {
-    const transaction = new Transaction();
+  ): Promise<VersionedTransaction> {
+    const allInstructions: TransactionInstruction[] = [];
     
     // Add compute budget instructions if specified
     if (options?.computeUnitLimit) {
-      const computeBudgetIx = web3.ComputeBudgetProgram.setComputeUnitLimit({
+      allInstructions.push(web3.ComputeBudgetProgram.setComputeUnitLimit({
         units: options.computeUnitLimit,
-      });
-      transaction.add(computeBudgetIx);
+      }));
     }
     
     if (options?.priorityFee) {
-      const priorityFeeIx = web3.ComputeBudgetProgram.setComputeUnitPrice({
+      allInstructions.push(web3.ComputeBudgetProgram.setComputeUnitPrice({
         microLamports: options.priorityFee,
-      });
-      transaction.add(priorityFeeIx);
+      }));
     }
     
     // Add main instructions
-    transaction.add(...instructions);
+    allInstructions.push(...instructions);
+    
+    // Compile as v0 against the configured lookup tables
+    const lookupTables: AddressLookupTableAccount[] = this.config.lookupTables?.length
+      ? await fetchLookupTables(this.config.connection, this.config.lookupTables)
+      : [];
+    
+    return buildV0Transaction(
+      this.config.connection,
+      this.provider!.wallet.publicKey,
+      allInstructions,
+      lookupTables
+    );
+  }
+
+  private async createLegacyTransaction(
+    instructions: TransactionInstruction[]
+  ): Promise<Transaction> {
+    const transaction = new Transaction().add(...instructions);
     
-    // Set recent blockhash
     const { blockhash } = await this.config.connection.getLatestBlockhash();
     transaction.recentBlockhash = blockhash;
     transaction.feePayer = this.provider!.wallet.publicKey;
@@ -479,18 +553,14 @@ export class UniversalNftSDK extends EventEmitter {
   }
 
   private async sendTransactionWithRetry(
-    transaction: Transaction,
+    transaction: Transaction | VersionedTransaction,
     signers: Keypair[] = []
   ): Promise<string> {
     const { maxRetries, retryDelay, exponentialBackoff } = this.config.retryConfig!;
     
     for (let attempt = 0; attempt <= maxRetries; attempt++) {
       try {
-        if (signers.length > 0) {
-          transaction.partialSign(...signers);
-        }
-        
-        const signature = await this.provider!.sendAndConfirm(transaction);
+        const signature = await this.provider!.sendAndConfirm(transaction, signers);
         return signature;
         
       } catch (error) {
@@ -716,4 +786,6 @@ class CacheManager {
 // Export additional utilities
 export * from './types';
 export * from './utils';
+export * from './lookupTables';
+export * from './errors';
 export * from './constants';
\ No newline at end of file
@@ -0,0 +1,291 @@
+// Generated by scripts/generate-error-table.ts from the program IDL. Do not edit.
+
+export interface ProgramErrorEntry {
+  code: number;
+  name: string;
+  message: string;
+  hint: string | null;
+}
+
+export const PROGRAM_ERRORS: Record<number, ProgramErrorEntry> = {
+  6000: { code: 6000, name: "Unauthorized", message: "Unauthorized access - caller is not the authority", hint: null },
+  6001: { code: 6001, name: "ProgramPaused", message: "Program is currently paused", hint: "retry once the program is unpaused" },
+  6002: { code: 6002, name: "InvalidGatewayAuthority", message: "Invalid gateway authority", hint: "cross-chain calls must arrive through the ZetaChain gateway" },
+  6003: { code: 6003, name: "InvalidTssSignature", message: "Invalid TSS signature", hint: "the message must be signed by the current TSS address" },
+  6004: { code: 6004, name: "InvalidMessageFormat", message: "Invalid cross-chain message format", hint: "re-encode the payload with the SDK message encoder" },
+  6005: { code: 6005, name: "NftLocked", message: "NFT is currently locked for cross-chain transfer", hint: "wait for the pending cross-chain transfer to complete or revert" },
+  6006: { code: 6006, name: "InvalidChainId", message: "Invalid chain ID", hint: "use a chain ID from the chain registry" },
+  6007: { code: 6007, name: "InvalidRecipient", message: "Invalid recipient address", hint: "check the recipient address for the destination chain" },
+  6008: { code: 6008, name: "InsufficientGasLimit", message: "Insufficient gas limit for cross-chain operation", hint: "raise the gas limit; estimate_bridge_cost returns current fees" },
+  6009: { code: 6009, name: "NonceMismatch", message: "Nonce mismatch - potential replay attack", hint: "refetch the program nonce and rebuild the message" },
+  6010: { code: 6010, name: "InvalidSignatureRecovery", message: "Invalid signature recovery", hint: null },
+  6011: { code: 6011, name: "TransferAlreadyExists", message: "Transfer already exists", hint: null },
+  6012: { code: 6012, name: "TransferNotFound", message: "Transfer not found", hint: "check the transfer nonce and the chain it was sent from" },
+  6013: { code: 6013, name: "InvalidTransferStatus", message: "Invalid transfer status", hint: "refetch the transfer; it may already be completed or reverted" },
+  6014: { code: 6014, name: "NftNotFound", message: "NFT not found", hint: null },
+  6015: { code: 6015, name: "InvalidNftOwner", message: "Invalid NFT owner", hint: null },
+  6016: { code: 6016, name: "CollectionVerificationFailed", message: "Collection verification failed", hint: null },
+  6017: { code: 6017, name: "MaxSupplyExceeded", message: "Maximum supply exceeded", hint: null },
+  6018: { code: 6018, name: "InvalidMetadataUri", message: "Invalid metadata URI", hint: null },
+  6019: { code: 6019, name: "InvalidTokenName", message: "Invalid token name", hint: null },
+  6020: { code: 6020, name: "InvalidTokenSymbol", message: "Invalid token symbol", hint: null },
+  6021: { code: 6021, name: "ArithmeticOverflow", message: "Arithmetic overflow", hint: null },
+  6022: { code: 6022, name: "InvalidInstructionOrigin", message: "Invalid instruction origin", hint: null },
+  6023: { code: 6023, name: "InvalidCallOrigin", message: "Cross-chain call origin validation failed", hint: null },
+  6024: { code: 6024, name: "MessageHashMismatch", message: "Message hash mismatch", hint: "the signed hash must cover the exact message bytes sent" },
+  6025: { code: 6025, name: "InvalidEcdsaSignature", message: "Invalid ECDSA signature format", hint: null },
+  6026: { code: 6026, name: "PublicKeyRecoveryFailed", message: "Public key recovery failed", hint: null },
+  6027: { code: 6027, name: "SenderVerificationFailed", message: "Sender verification failed", hint: null },
+  6028: { code: 6028, name: "InvalidCompensationAmount", message: "Compensation amount must be greater than zero", hint: null },
+  6029: { code: 6029, name: "CompensationClaimsDisabled", message: "Compensation claims are currently disabled", hint: null },
+  6030: { code: 6030, name: "RecoverySessionNotCompleted", message: "Recovery session has not completed", hint: null },
+  6031: { code: 6031, name: "NoCompensationOwed", message: "No compensation is owed for this recovery session", hint: null },
+  6032: { code: 6032, name: "UnsupportedCompensationType", message: "Compensation type cannot be paid from the vault", hint: null },
+  6033: { code: 6033, name: "CompensationCapExceeded", message: "Per-user compensation cap exceeded", hint: null },
+  6034: { code: 6034, name: "InsufficientVaultFunds", message: "Insufficient funds in compensation vault", hint: null },
+  6035: { code: 6035, name: "InvalidCheckpointAccounts", message: "Checkpoint accounts do not match the critical account set", hint: null },
+  6036: { code: 6036, name: "InvalidSnapshotPage", message: "Snapshot page out of range", hint: null },
+  6037: { code: 6037, name: "InvalidBackupTarget", message: "Account is not a valid backup target", hint: null },
+  6038: { code: 6038, name: "InvalidRestoreChunk", message: "Invalid restore chunk", hint: null },
+  6039: { code: 6039, name: "RecoveryModeInactive", message: "Recovery mode is not active", hint: null },
+  6040: { code: 6040, name: "RestoreHashMismatch", message: "Restored data does not match the archived snapshot hash", hint: null },
+  6041: { code: 6041, name: "OrphanAlreadyClaimed", message: "Orphaned NFT has already been claimed", hint: null },
+  6042: { code: 6042, name: "OrphanProofMismatch", message: "Original message does not match the orphan record", hint: null },
+  6043: { code: 6043, name: "NftNotInCustody", message: "NFT is not held in program custody", hint: null },
+  6044: { code: 6044, name: "NftUnderDispute", message: "NFT is frozen by an open dispute", hint: null },
+  6045: { code: 6045, name: "InvalidDisputeParty", message: "Signer is not a party to the disputed transfer", hint: null },
+  6046: { code: 6046, name: "DisputeNotOpen", message: "Dispute is not open", hint: null },
+  6047: { code: 6047, name: "DisputeDeadlinePassed", message: "Dispute resolution deadline has passed", hint: null },
+  6048: { code: 6048, name: "DisputeDeadlineNotReached", message: "Dispute resolution deadline has not been reached", hint: null },
+  6049: { code: 6049, name: "InvalidResolutionWindow", message: "Invalid dispute resolution window", hint: null },
+  6050: { code: 6050, name: "InvalidSplitShare", message: "Split share must not exceed 10000 basis points", hint: null },
+  6051: { code: 6051, name: "InvalidQueryParameters", message: "Invalid interchain query parameters", hint: null },
+  6052: { code: 6052, name: "InvalidQueryAccount", message: "Query result account does not match the query ID", hint: null },
+  6053: { code: 6053, name: "QueryNotPending", message: "Query is not pending", hint: null },
+  6054: { code: 6054, name: "QueryChainMismatch", message: "Query response came from the wrong chain", hint: null },
+  6055: { code: 6055, name: "QueryResponseTooLarge", message: "Query response exceeds maximum length", hint: null },
+  6056: { code: 6056, name: "InvalidGasPrice", message: "Gas price must be greater than zero", hint: "ask the gas token authority to publish a price" },
+  6057: { code: 6057, name: "GasTokenDisabled", message: "Gas token is not enabled", hint: "pay gas in SOL or another enabled gas token" },
+  6058: { code: 6058, name: "InvalidGasTokenAccounts", message: "Gas token accounts missing or invalid for ZRC-20 payment", hint: "pass the gas token mint and payer token account" },
+  6059: { code: 6059, name: "GasTokenChainMismatch", message: "Gas token is not registered for the destination chain", hint: "choose a gas token registered for the destination chain" },
+  6060: { code: 6060, name: "ChainNotRegistered", message: "Chain is not registered", hint: "bridge only to chains listed in the chain registry" },
+  6061: { code: 6061, name: "ChainDisabled", message: "Chain is disabled", hint: "the destination chain is temporarily disabled; retry later" },
+  6062: { code: 6062, name: "ChainKindMismatch", message: "Message does not match the registered chain kind", hint: "encode the message for the destination chain's kind" },
+  6063: { code: 6063, name: "InvalidBitcoinAddress", message: "Invalid Bitcoin address", hint: "use a valid bech32 or base58 Bitcoin address" },
+  6064: { code: 6064, name: "InvalidInscriptionReference", message: "Invalid inscription reference", hint: null },
+  6065: { code: 6065, name: "InvalidOriginAccount", message: "Invalid NFT origin account", hint: null },
+  6066: { code: 6066, name: "InvalidAddressFormat", message: "Recipient does not match the destination chain's address format", hint: null },
+  6067: { code: 6067, name: "AddressFormatMismatch", message: "Address format is not valid for the chain kind", hint: "encode the recipient in the destination chain's address format" },
+  6068: { code: 6068, name: "InvalidEvmAddressLength", message: "EVM address must be exactly 20 bytes", hint: "pass the raw 20-byte address, not a hex string" },
+  6069: { code: 6069, name: "ZeroDestinationAddress", message: "Destination address is the zero address", hint: "set a non-zero recipient address" },
+  6070: { code: 6070, name: "BurnDestinationAddress", message: "Destination address is a known burn address", hint: "bridging to a burn address would lose the NFT; use a real recipient" },
+  6071: { code: 6071, name: "CounterpartDestinationAddress", message: "Destination address is the program's counterpart contract", hint: "send to a user wallet, not the counterpart contract" },
+  6072: { code: 6072, name: "PayloadTooLarge", message: "Transfer payload exceeds maximum length", hint: "shorten the metadata URI or remove optional payload fields" },
+  6073: { code: 6073, name: "MissingCollectionHooks", message: "Collection hook registry account is required for collection NFTs", hint: null },
+  6074: { code: 6074, name: "InvalidHookAccounts", message: "Hook accounts missing or invalid", hint: null },
+  6075: { code: 6075, name: "HookComputeExceeded", message: "Hook exceeded its compute allowance", hint: null },
+  6076: { code: 6076, name: "InvalidHookConfiguration", message: "Invalid hook configuration", hint: null },
+  6077: { code: 6077, name: "TransferNotExpired", message: "Transfer has not expired yet", hint: "revert is only possible after the transfer timeout" },
+  6078: { code: 6078, name: "InvalidTransferReceipt", message: "Transfer receipt missing or invalid", hint: null },
+  6079: { code: 6079, name: "InvalidScheduleDelay", message: "Schedule delay out of range", hint: null },
+  6080: { code: 6080, name: "ScheduleNotReady", message: "Scheduled transfer delay has not elapsed", hint: null },
+  6081: { code: 6081, name: "ScheduleWindowClosed", message: "Scheduled transfer can no longer be cancelled", hint: null },
+  6082: { code: 6082, name: "ScheduleMismatch", message: "Transfer parameters do not match the schedule", hint: null },
+  6083: { code: 6083, name: "InvalidInsuranceParameters", message: "Invalid insurance premium or payout parameters", hint: null },
+  6084: { code: 6084, name: "InsuranceDisabled", message: "Insurance pool is not accepting new policies", hint: null },
+  6085: { code: 6085, name: "InsuranceNotClaimable", message: "Transfer did not end in an unrecoverable failure", hint: null },
+  6086: { code: 6086, name: "InsuranceAlreadyClaimed", message: "Insurance payout already claimed", hint: null },
+  6087: { code: 6087, name: "InvalidBatchSize", message: "Batch must contain between 1 and 8 NFTs", hint: "split the batch into groups of at most 8 NFTs" },
+  6088: { code: 6088, name: "InvalidBatchAccounts", message: "Batch accounts missing or invalid", hint: null },
+  6089: { code: 6089, name: "BatchHooksUnsupported", message: "Collections with bridge-out hooks must be transferred individually", hint: "bridge each NFT from this collection in its own transaction" },
+  6090: { code: 6090, name: "InvalidRelayerParameters", message: "Invalid relayer registry parameters", hint: null },
+  6091: { code: 6091, name: "InsufficientRelayerStake", message: "Relayer stake below the registry minimum", hint: null },
+  6092: { code: 6092, name: "RelayerInactive", message: "Relayer is not active", hint: null },
+  6093: { code: 6093, name: "RelayerUnbonding", message: "Relayer is already unbonding", hint: null },
+  6094: { code: 6094, name: "RelayerNotUnbonding", message: "Relayer stake is still bonded", hint: null },
+  6095: { code: 6095, name: "MessageAlreadyRelayed", message: "Message has already been relayed", hint: null },
+  6096: { code: 6096, name: "SequenceAlreadyProcessed", message: "Inbound sequence already processed", hint: null },
+  6097: { code: 6097, name: "SequenceGapTooLarge", message: "Inbound sequence too far ahead of the expected sequence", hint: "relay the earlier messages from this chain first" },
+  6098: { code: 6098, name: "NftFrozen", message: "NFT is frozen under legal hold", hint: null },
+  6099: { code: 6099, name: "NftNotFrozen", message: "NFT is not frozen", hint: null },
+  6100: { code: 6100, name: "CircuitBreakerOpen", message: "Circuit breaker is open for this chain", hint: "wait for the open period to pass before a probe is admitted" },
+  6101: { code: 6101, name: "CircuitBreakerRateLimit", message: "Circuit breaker is half-open and its probe interval has not passed", hint: null },
+  6102: { code: 6102, name: "InvalidCircuitBreaker", message: "Invalid circuit breaker account or config", hint: null },
+  6103: { code: 6103, name: "InvalidIncidentConfig", message: "Invalid incident mode configuration", hint: null },
+  6104: { code: 6104, name: "IncidentOutboundPaused", message: "Outbound transfers are paused by incident mode", hint: "outbound bridging is paused during an incident; retry later" },
+  6105: { code: 6105, name: "IncidentLockdown", message: "Protocol is in incident lockdown", hint: "the protocol is in lockdown; retry once it is lifted" },
+  6106: { code: 6106, name: "TooManyFlaggedMints", message: "Too many mints flagged for incident lockdown", hint: null },
+  6107: { code: 6107, name: "InvalidValidatorSet", message: "Invalid validator set or threshold", hint: null },
+  6108: { code: 6108, name: "QuorumNotReached", message: "Not enough validator signatures to reach quorum", hint: "collect more validator signatures before submitting" },
+  6109: { code: 6109, name: "QuorumAttestationRequired", message: "Inbound message needs an observer quorum attestation", hint: "collect K-of-N observer signatures and call attest_inbound_message first" },
+  6110: { code: 6110, name: "QuorumAttestationConsumed", message: "Observer quorum attestation was already consumed", hint: null },
+  6111: { code: 6111, name: "UnauthorizedHeaderRelayer", message: "Caller is not the header relayer", hint: null },
+  6112: { code: 6112, name: "InvalidEventProof", message: "Event proof does not match the posted block root", hint: null },
+  6113: { code: 6113, name: "EventProofRequired", message: "Collection requires a verified event proof", hint: null },
+  6114: { code: 6114, name: "EventProofConsumed", message: "Event proof has already been consumed", hint: null },
+  6115: { code: 6115, name: "RateLimitExceeded", message: "Rate limit exceeded", hint: "wait for the rate limit window to reset" },
+  6116: { code: 6116, name: "InvalidRateLimitConfig", message: "Invalid rate limit configuration", hint: null },
+  6117: { code: 6117, name: "TransferValueCapExceeded", message: "Transfer value exceeds the chain's per-transfer cap", hint: "the NFT's floor value is above this chain's cap; choose another chain" },
+  6118: { code: 6118, name: "DailyVolumeCapExceeded", message: "Chain's rolling 24h value cap exceeded", hint: "the chain's daily value cap is reached; retry in 24h" },
+  6119: { code: 6119, name: "DailyTransferCapExceeded", message: "Chain's rolling 24h transfer cap exceeded", hint: "the chain's daily transfer cap is reached; retry in 24h" },
+  6120: { code: 6120, name: "MissingCollectionFloor", message: "Collection floor price account missing or invalid", hint: "pass the collection floor price account" },
+  6121: { code: 6121, name: "UnauthorizedPriceOracle", message: "Caller is not one of the collection's price authorities", hint: null },
+  6122: { code: 6122, name: "BatchValueCapUnsupported", message: "Batch transfers are not supported to chains with value caps", hint: "bridge each NFT in its own transaction to value-capped chains" },
+  6123: { code: 6123, name: "InvalidBountyParameters", message: "Invalid bug bounty parameters", hint: null },
+  6124: { code: 6124, name: "BountyProgramClosed", message: "Bug bounty program is not accepting reports", hint: null },
+  6125: { code: 6125, name: "BountyReportAlreadyResolved", message: "Bug bounty report has already been resolved", hint: null },
+  6126: { code: 6126, name: "MissingCollectionConfig", message: "Collection config account is required for collection NFTs", hint: null },
+  6127: { code: 6127, name: "InvalidCollectionConfig", message: "Invalid collection configuration", hint: null },
+  6128: { code: 6128, name: "CollectionPaused", message: "Collection is paused", hint: "the collection authority has paused this collection" },
+  6129: { code: 6129, name: "DestinationChainNotAllowed", message: "Collection does not allow bridging to this chain", hint: "choose a chain the collection allows" },
+  6130: { code: 6130, name: "InvalidCustodyAccount", message: "Custody token account missing or invalid", hint: null },
+  6131: { code: 6131, name: "BatchTransferModeUnsupported", message: "Lock-mode collections must be transferred individually", hint: "bridge each lock-mode NFT in its own transaction" },
+  6132: { code: 6132, name: "SoldOut", message: "Collection is sold out", hint: null },
+  6133: { code: 6133, name: "InvalidMaxSupply", message: "Max supply can only be lowered, and not below the minted count", hint: null },
+  6134: { code: 6134, name: "InvalidMintPhase", message: "Invalid mint phase", hint: null },
+  6135: { code: 6135, name: "MintClosed", message: "Minting is closed: no mint phase is active", hint: null },
+  6136: { code: 6136, name: "NotOnAllowlist", message: "Wallet is not on the phase allowlist", hint: null },
+  6137: { code: 6137, name: "InvalidFundsRecipient", message: "Funds recipient does not match the mint phase", hint: null },
+  6138: { code: 6138, name: "InvalidClaimEscrow", message: "Invalid claim escrow parameters", hint: null },
+  6139: { code: 6139, name: "InvalidClaimProof", message: "Claim key or claims service signature is missing or wrong", hint: null },
+  6140: { code: 6140, name: "ClaimEscrowExpired", message: "Claim escrow has expired", hint: null },
+  6141: { code: 6141, name: "ClaimEscrowNotExpired", message: "Claim escrow has not expired", hint: null },
+  6142: { code: 6142, name: "InvalidSnapshot", message: "Invalid holder snapshot", hint: null },
+  6143: { code: 6143, name: "SnapshotAlreadyRevealed", message: "Holder snapshot has already been revealed", hint: null },
+  6144: { code: 6144, name: "SnapshotCommitmentMismatch", message: "Revealed snapshot does not match its commitment", hint: null },
+  6145: { code: 6145, name: "InvalidBurnStats", message: "Burn statistics accounts do not match", hint: null },
+  6146: { code: 6146, name: "WalletTransfersLocked", message: "Wallet has locked its outbound transfers", hint: "unlock transfers with unlock_my_transfers and wait out the delay" },
+  6147: { code: 6147, name: "InvalidUnlockDelay", message: "Unlock delay is outside the allowed range or shorter than the active lock's", hint: null },
+  6148: { code: 6148, name: "WalletNotLocked", message: "Wallet is not locked or an unlock is already pending", hint: null },
+  6149: { code: 6149, name: "InvalidOperatorSession", message: "Invalid operator session", hint: null },
+  6150: { code: 6150, name: "OperatorSessionExpired", message: "Operator session has expired", hint: "the NFT owner must register a new operator session" },
+  6151: { code: 6151, name: "OperatorScopeNotGranted", message: "Operator session does not grant this action", hint: "the NFT owner must grant this scope or chain to the operator" },
+  6152: { code: 6152, name: "InvalidAssociatedTokenAccount", message: "Token account is not the owner's associated token account for this mint", hint: null },
+  6153: { code: 6153, name: "InvalidNftSupply", message: "Mint supply is not exactly one", hint: null },
+  6154: { code: 6154, name: "InvalidNftDecimals", message: "Mint decimals must be zero", hint: null },
+  6155: { code: 6155, name: "InvalidMetadataAccount", message: "Metadata account is not the Token Metadata PDA for this mint", hint: null },
+  6156: { code: 6156, name: "InvalidTokenDelegate", message: "Signer is not the approved delegate for this token account", hint: "the owner must approve the delegate first" },
+  6157: { code: 6157, name: "InvalidMintAuthority", message: "Mint authority is not held by the program, so the mint is not a universal NFT", hint: null },
+  6158: { code: 6158, name: "InvalidRentFunding", message: "Rent vault funding amount must be greater than zero", hint: null },
+  6159: { code: 6159, name: "InvalidRentLedger", message: "Rent ledger does not belong to the message's source chain", hint: "pass the rent_ledger PDA derived from the source chain ID" },
+  6160: { code: 6160, name: "MissingRecipientAccounts", message: "Inbound mint is missing the recipient's accounts", hint: "pass recipient, recipient_token_account, payer, system_program, token_program and associated_token_program" },
+  6161: { code: 6161, name: "UnsupportedMessageVersion", message: "Cross-chain payload version is not supported", hint: "prefix payloads with the version byte this program accepts" },
+  6162: { code: 6162, name: "UnsupportedMessageType", message: "Cross-chain message type is not supported by this payload version", hint: "the counterpart sent a message type this program does not know" },
+  6163: { code: 6163, name: "TransferInProgress", message: "An outbound transfer of this NFT is already in progress", hint: "wait until it is confirmed, reverted or cancelled" },
+  6164: { code: 6164, name: "InvalidCrankOperator", message: "Signer is not the registered crank operator", hint: null },
+  6165: { code: 6165, name: "CrankNotDue", message: "Nothing is due for this crank yet", hint: "reschedule the automation thread for a later time" },
+  6166: { code: 6166, name: "FeatureDisabled", message: "Instruction is not part of this build", hint: "deploy a build with the governance feature" },
+  6167: { code: 6167, name: "MessageLayerUnavailable", message: "Message layer not operational or not routed to this chain", hint: "pass the config of the chain's primary or fallback layer" },
+  6168: { code: 6168, name: "InvalidBridgeRoute", message: "Invalid bridge route", hint: "the remote chain ID must fit the layer and non-gateway routes need a peer" },
+  6169: { code: 6169, name: "InvalidMetadataRules", message: "Invalid metadata rules", hint: "URI prefixes are at most 64 bytes and a matched prefix needs a replacement" },
+  6170: { code: 6170, name: "InvalidPinner", message: "Signer is not the registered pinner", hint: null },
+  6171: { code: 6171, name: "InvalidPinReceipt", message: "Invalid pin receipt", hint: "content ID must be 1-96 bytes and size non-zero" },
+  6172: { code: 6172, name: "InvalidModerator", message: "Signer is not the moderator", hint: null },
+  6173: { code: 6173, name: "ContentAlreadyTakenDown", message: "Content has already been taken down", hint: null },
+  6174: { code: 6174, name: "ContentNotTakenDown", message: "Content has not been taken down", hint: null },
+  6175: { code: 6175, name: "InvalidRoyaltyShares", message: "Royalty shares must name up to 5 distinct creators summing to 10000 basis points", hint: null },
+  6176: { code: 6176, name: "InvalidRoyaltyAmount", message: "Royalty deposit must be greater than zero", hint: null },
+  6177: { code: 6177, name: "RoyaltyLedgerFull", message: "Royalty escrow ledger is full", hint: null },
+  6178: { code: 6178, name: "NoRoyaltiesToClaim", message: "No royalties to claim", hint: null },
+  6179: { code: 6179, name: "InvalidSystemMonitor", message: "Account is not the system monitor", hint: null },
+  6180: { code: 6180, name: "SourceBlockRequired", message: "Message must carry its source block number for this chain", hint: null },
+  6181: { code: 6181, name: "SourceBlockNotFinal", message: "Source block has not reached the chain's required confirmations", hint: null },
+  6182: { code: 6182, name: "InvalidRecipientPreferences", message: "Invalid recipient preferences", hint: null },
+  6183: { code: 6183, name: "UnsolicitedDeliveryDeclined", message: "Recipient does not accept mints from this sender", hint: null },
+  6184: { code: 6184, name: "RecipientTokenAccountRequired", message: "Recipient must already hold a token account for this NFT", hint: null },
+  6185: { code: 6185, name: "InvalidQuarantineAccount", message: "Invalid quarantine account", hint: null },
+  6186: { code: 6186, name: "QuarantineNotExpired", message: "Quarantined NFT has not expired yet", hint: null },
+  6187: { code: 6187, name: "InvalidFeeSchedule", message: "Invalid fee schedule", hint: null },
+  6188: { code: 6188, name: "FeeScheduleTimelocked", message: "Fee schedule update is still timelocked", hint: null },
+  6189: { code: 6189, name: "NoPendingFeeSchedule", message: "No fee schedule update is pending", hint: null },
+  6190: { code: 6190, name: "InvalidFeeRecipient", message: "Fee recipient does not match the fee schedule", hint: null },
+  6191: { code: 6191, name: "InvalidFeeDiscount", message: "Fee discount accounts do not qualify the payer", hint: null },
+  6192: { code: 6192, name: "SelfReferral", message: "Referrer cannot be the payer or NFT owner", hint: null },
+  6193: { code: 6193, name: "InvalidReferral", message: "Referral account does not match the referrer", hint: null },
+  6194: { code: 6194, name: "NoReferralRewards", message: "No referral rewards to claim", hint: null },
+  6195: { code: 6195, name: "InvalidRebateCampaign", message: "Invalid rebate campaign", hint: null },
+  6196: { code: 6196, name: "InvalidPointsAccount", message: "Points account does not match the user or program", hint: null },
+  6197: { code: 6197, name: "InsufficientPoints", message: "Not enough points to redeem", hint: null },
+  6198: { code: 6198, name: "TooManyPointsRedeemers", message: "Too many points redeemers", hint: null },
+  6199: { code: 6199, name: "InvalidNftAttribute", message: "Invalid NFT attribute", hint: null },
+  6200: { code: 6200, name: "TooManyNftAttributes", message: "NFT has too many attributes", hint: null },
+  6201: { code: 6201, name: "NftAttributeNotFound", message: "NFT attribute not found", hint: null },
+  6202: { code: 6202, name: "AttributesUnsupportedOnRoute", message: "Attributes cannot be carried on this route", hint: null },
+  6203: { code: 6203, name: "TooManyTraitAuthorities", message: "Too many trait authorities", hint: null },
+  6204: { code: 6204, name: "InvalidRevealList", message: "Invalid reveal list", hint: null },
+  6205: { code: 6205, name: "InvalidRandomnessAccount", message: "Randomness account is not a fresh Switchboard commitment", hint: null },
+  6206: { code: 6206, name: "RandomnessNotResolved", message: "Randomness has not been revealed by the oracle", hint: null },
+  6207: { code: 6207, name: "RevealMintingOpen", message: "Collection minting must be closed before the reveal", hint: "sell out or reduce the max supply to the minted count" },
+  6208: { code: 6208, name: "CollectionNotRevealed", message: "Collection has not been revealed", hint: null },
+  6209: { code: 6209, name: "RevealListExhausted", message: "Every reveal list entry is assigned", hint: null },
+  6210: { code: 6210, name: "InvalidRevealProof", message: "Reveal list proof does not match the committed root", hint: null },
+  6211: { code: 6211, name: "CollectionAlreadyRevealed", message: "Collection has already been revealed", hint: null },
+  6212: { code: 6212, name: "ChildrenEquipped", message: "NFT has children equipped and its bridge mode blocks transfer", hint: null },
+  6213: { code: 6213, name: "TooManyChildren", message: "Parent NFT has the maximum number of children", hint: null },
+  6214: { code: 6214, name: "ChildNotEquipped", message: "NFT is not equipped into this parent", hint: null },
+  6215: { code: 6215, name: "InvalidNestedNft", message: "NFT can't be equipped into this parent", hint: null },
+  6216: { code: 6216, name: "ChildrenUnsupportedOnRoute", message: "Destination route can't carry equipped children", hint: null },
+  6217: { code: 6217, name: "InvalidEvolutionRecipe", message: "Evolution recipe settings are invalid", hint: null },
+  6218: { code: 6218, name: "EvolutionRequirementsNotMet", message: "NFT does not meet the recipe's attribute requirements", hint: null },
+  6219: { code: 6219, name: "InvalidResourceAccounts", message: "Resource accounts do not match the recipe inputs", hint: null },
+  6220: { code: 6220, name: "TicketAlreadyUsed", message: "Ticket has already been checked in", hint: null },
+  6221: { code: 6221, name: "TicketEventMismatch", message: "Ticket is for a different event", hint: null },
+  6222: { code: 6222, name: "TooManyTicketValidators", message: "Too many ticket validators", hint: null },
+  6223: { code: 6223, name: "InvalidTicketProofDestination", message: "Ticketing proof destination is invalid", hint: null },
+  6224: { code: 6224, name: "InvalidVestingSchedule", message: "Vesting schedule is invalid", hint: null },
+  6225: { code: 6225, name: "NothingVested", message: "No vested tokens to claim", hint: null },
+  6226: { code: 6226, name: "VestingPositionMismatch", message: "Vesting terms do not match the position on Solana", hint: null },
+  6227: { code: 6227, name: "VestingUnsupportedOnRoute", message: "Destination route can't carry a vesting position", hint: null },
+  6228: { code: 6228, name: "InvalidAttendanceDrop", message: "Attendance drop parameters are invalid", hint: null },
+  6229: { code: 6229, name: "AttendanceAlreadyClaimed", message: "Allowlist entry has already claimed its attendance NFT", hint: null },
+  6230: { code: 6230, name: "AttendanceDropClosed", message: "Attendance drop is not open for claims", hint: null },
+  6231: { code: 6231, name: "InvalidAttendanceTree", message: "Bubblegum tree is not delegated to the attendance drop", hint: null },
+  6232: { code: 6232, name: "TooManyCredentialIssuers", message: "Credential profile names too many issuers", hint: null },
+  6233: { code: 6233, name: "InvalidCredential", message: "Credential parameters are invalid", hint: null },
+  6234: { code: 6234, name: "InvalidRevocationDestination", message: "Revocation destination is invalid", hint: null },
+  6235: { code: 6235, name: "InvalidSwapTerms", message: "Swap terms are invalid", hint: null },
+  6236: { code: 6236, name: "NotSwapParty", message: "Signer is not a party to the swap", hint: null },
+  6237: { code: 6237, name: "InvalidSwapState", message: "Swap is not in a state that allows this", hint: null },
+  6238: { code: 6238, name: "InvalidSwapSecret", message: "Secret does not match the swap's hashlock", hint: null },
+  6239: { code: 6239, name: "InvalidLoanTerms", message: "Loan terms are invalid", hint: null },
+  6240: { code: 6240, name: "InvalidLoanState", message: "Loan is not in a state that allows this", hint: null },
+  6241: { code: 6241, name: "LoanNotDefaulted", message: "Loan is not overdue", hint: null },
+  6242: { code: 6242, name: "TooManyPriceAuthorities", message: "Too many price authorities for a collection floor", hint: null },
+  6243: { code: 6243, name: "StaleFloorPrice", message: "Collection floor price is stale", hint: null },
+  6244: { code: 6244, name: "InvalidPriceFeed", message: "Price feed account is invalid or not trading", hint: null },
+  6245: { code: 6245, name: "InvalidMaintenanceWindow", message: "Maintenance window must end after it starts and in the future", hint: null },
+  6246: { code: 6246, name: "InvalidRemediationFlags", message: "Remediation flags name an unknown action", hint: null },
+  6247: { code: 6247, name: "InvalidRemediationTarget", message: "Remediation target account missing or does not match the action", hint: null },
+  6248: { code: 6248, name: "RemediationAlreadyReverted", message: "Remediation has already been reverted", hint: null },
+  6249: { code: 6249, name: "InvalidBillingAccount", message: "Billing account belongs to a different client", hint: null },
+  6250: { code: 6250, name: "InvalidUsageSegment", message: "Usage segment account is invalid or belongs to another collection", hint: null },
+  6251: { code: 6251, name: "InvalidLeaderboard", message: "Leaderboard account is invalid or of the wrong kind", hint: null },
+  6252: { code: 6252, name: "InvalidChainMetrics", message: "Chain metrics account does not match the chain or its name is too long", hint: null },
+  6253: { code: 6253, name: "InvalidChainRisk", message: "Chain risk entries exceed the registry's capacity, exceed the maximum risk or repeat a chain or pair", hint: null },
+  6254: { code: 6254, name: "InvalidFraudConfig", message: "Fraud config parameters are out of bounds, their weights don't sum to 100, or the proposal data is malformed", hint: null },
+  6255: { code: 6255, name: "InvalidFraudConfigProposal", message: "Proposal is not an executed, unapplied fraud config update", hint: null },
+  6256: { code: 6256, name: "FraudRiskTooHigh", message: "Operation's fraud risk score exceeds the configured threshold", hint: null },
+  6257: { code: 6257, name: "InvalidSecurityMonitor", message: "Security monitor must be a nonzero contract on ZetaChain", hint: null },
+  6258: { code: 6258, name: "CorridorPaused", message: "Corridor is paused", hint: "wait for the corridor to be resumed on both sides" },
+  6259: { code: 6259, name: "InvalidCorridorPause", message: "Corridor pause message is malformed, for another chain or not newer than the last one applied, or the chain has no counterpart contract", hint: null },
+  6260: { code: 6260, name: "InvalidDeploymentRegistry", message: "Deployment registry lists a satellite kind twice, a default program ID, or too many satellites", hint: null },
+  6261: { code: 6261, name: "UnapprovedSatelliteProgram", message: "Program is not the approved deployment of this satellite", hint: "check the deployment registry" },
+  6262: { code: 6262, name: "RetryQueueFull", message: "Retry queue is full", hint: "process due retries before scheduling more" },
+  6263: { code: 6263, name: "InvalidRetryReport", message: "Retry outcome report is for another attempt or is missing its signature or failure reason", hint: null },
+  6264: { code: 6264, name: "InvalidDisputeRevertAccounts", message: "Dispute revert needs the original owner's token account", hint: "pass the custody token account for locked NFTs" },
+  6265: { code: 6265, name: "RelayedMessageRequired", message: "Message from a relayed chain needs its relayed delivery record", hint: "submit it with submit_relayed_message first" },
+  6266: { code: 6266, name: "MessageNotReleased", message: "Relayed message is buffered behind a missing sequence", hint: "deliver the earlier sequences first" },
+  6267: { code: 6267, name: "RelayedMessageConsumed", message: "Relayed message was already executed", hint: null },
+  6268: { code: 6268, name: "RelayedMessageNotExecuted", message: "Relayed message has not been executed yet", hint: "rewards are paid once on_call executes the message" },
+  6269: { code: 6269, name: "RelayerRewardClaimed", message: "Relayer reward for this message was already claimed", hint: null },
+  6270: { code: 6270, name: "QuerySenderMismatch", message: "Query response came from a contract other than the one queried", hint: null },
+  6271: { code: 6271, name: "LoanTermsMismatch", message: "Loan terms differ from the terms the lender expected", hint: "re-read the loan and fund it with its current terms" },
+  6272: { code: 6272, name: "InvalidRetrySession", message: "Invalid retry session parameters", hint: null },
+  6273: { code: 6273, name: "TransferNftMismatch", message: "Universal NFT account does not belong to the transfer's mint", hint: null },
+  6274: { code: 6274, name: "LoanToValueExceeded", message: "Loan principal exceeds the maximum loan-to-value of the collateral's floor", hint: null },
+  6275: { code: 6275, name: "NftAlreadyOnSolana", message: "NFT already has a live token on Solana, so an inbound message can't deliver it again", hint: null },
+  6276: { code: 6276, name: "InboundTokenMismatch", message: "Inbound message's token ID does not match the universal NFT it is delivered into", hint: null },
+  6277: { code: 6277, name: "InvalidTokenId", message: "Token ID must be 1 to 64 bytes", hint: null },
+  6278: { code: 6278, name: "DeliveryNotFailed", message: "Relayed message was not delivered, or on_call already executed it", hint: null },
+  6279: { code: 6279, name: "NftHeldForAnotherFlow", message: "NFT is held in custody for an escrow, dispute, quarantine or nesting", hint: null },
+};
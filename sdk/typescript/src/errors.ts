@@ -0,0 +1,55 @@
+/**
+ * Program error lookup
+ *
+ * Maps failed transactions back to the program's error table so front-ends
+ * can show what went wrong and, for cross-chain failures, what to do next.
+ */
+
+import { PROGRAM_ERRORS, ProgramErrorEntry } from './errorTable';
+
+export { PROGRAM_ERRORS };
+export type { ProgramErrorEntry };
+
+const CUSTOM_ERROR_PATTERN = /custom program error: (0x[0-9a-f]+)/i;
+
+/**
+ * Look up a program error by numeric code
+ */
+export function getProgramError(code: number): ProgramErrorEntry | undefined {
+  return PROGRAM_ERRORS[code];
+}
+
+/**
+ * Extract the program error from an Anchor error, a transaction error with
+ * logs, or a raw error code
+ */
+export function parseProgramError(error: unknown): ProgramErrorEntry | undefined {
+  if (typeof error === 'number') {
+    return getProgramError(error);
+  }
+
+  const anchorCode = (error as any)?.error?.errorCode?.number ?? (error as any)?.code;
+  if (typeof anchorCode === 'number') {
+    return getProgramError(anchorCode);
+  }
+
+  const logs: string[] = (error as any)?.logs ?? [];
+  const text = [String((error as any)?.message ?? ''), ...logs].join('\n');
+  const match = text.match(CUSTOM_ERROR_PATTERN);
+  return match ? getProgramError(parseInt(match[1], 16)) : undefined;
+}
+
+/**
+ * Format a failure for display, appending the remediation hint when present
+ */
+export function formatProgramError(error: unknown): string {
+  const entry = parseProgramError(error);
+  if (!entry) {
+    return error instanceof Error ? error.message : String(error);
+  }
+  return entry.hint ? `${entry.message}. ${capitalize(entry.hint)}.` : entry.message;
+}
+
+function capitalize(text: string): string {
+  return text.charAt(0).toUpperCase() + text.slice(1);
+}
@@ -0,0 +1,184 @@
+/**
+ * Address Lookup Table helpers
+ *
+ * Mint + metadata + collection + gateway instructions reference more accounts
+ * than fit in a legacy transaction. These helpers keep the program's static
+ * accounts in a lookup table and build v0 transactions against it.
+ */
+
+import {
+  AddressLookupTableAccount,
+  AddressLookupTableProgram,
+  Connection,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  SYSVAR_RENT_PUBKEY,
+  TransactionInstruction,
+  TransactionMessage,
+  VersionedTransaction,
+} from '@solana/web3.js';
+import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from '@solana/spl-token';
+
+export const MPL_TOKEN_METADATA_PROGRAM_ID = new PublicKey(
+  'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s'
+);
+
+// Addresses per extend instruction that still fit in a legacy transaction
+const MAX_ADDRESSES_PER_EXTEND = 30;
+
+/**
+ * Accounts shared by every mint and bridge instruction of the program
+ */
+export function programStaticAccounts(
+  programId: PublicKey,
+  gatewayProgramId?: PublicKey
+): PublicKey[] {
+  const pda = (seed: string) =>
+    PublicKey.findProgramAddressSync([Buffer.from(seed)], programId)[0];
+
+  const accounts = [
+    programId,
+    pda('config'),
+    pda('receipt_authority'),
+    pda('nft_custody'),
+    TOKEN_PROGRAM_ID,
+    ASSOCIATED_TOKEN_PROGRAM_ID,
+    SystemProgram.programId,
+    SYSVAR_RENT_PUBKEY,
+    SYSVAR_INSTRUCTIONS_PUBKEY,
+    MPL_TOKEN_METADATA_PROGRAM_ID,
+  ];
+
+  if (gatewayProgramId) {
+    accounts.push(gatewayProgramId);
+  }
+
+  return accounts;
+}
+
+/**
+ * Instructions that create a lookup table and fill it with `addresses`
+ */
+export function createLookupTableInstructions(
+  authority: PublicKey,
+  payer: PublicKey,
+  recentSlot: number,
+  addresses: PublicKey[]
+): { lookupTable: PublicKey; instructions: TransactionInstruction[] } {
+  const [createIx, lookupTable] = AddressLookupTableProgram.createLookupTable({
+    authority,
+    payer,
+    recentSlot,
+  });
+
+  return {
+    lookupTable,
+    instructions: [
+      createIx,
+      ...extendLookupTableInstructions(lookupTable, authority, payer, addresses),
+    ],
+  };
+}
+
+/**
+ * Instructions that append `addresses` to an existing lookup table, in
+ * chunks small enough for one legacy transaction each
+ */
+export function extendLookupTableInstructions(
+  lookupTable: PublicKey,
+  authority: PublicKey,
+  payer: PublicKey,
+  addresses: PublicKey[]
+): TransactionInstruction[] {
+  const instructions: TransactionInstruction[] = [];
+
+  for (let i = 0; i < addresses.length; i += MAX_ADDRESSES_PER_EXTEND) {
+    instructions.push(
+      AddressLookupTableProgram.extendLookupTable({
+        lookupTable,
+        authority,
+        payer,
+        addresses: addresses.slice(i, i + MAX_ADDRESSES_PER_EXTEND),
+      })
+    );
+  }
+
+  return instructions;
+}
+
+/**
+ * Addresses from `candidates` not yet stored in the table
+ */
+export function missingAddresses(
+  table: AddressLookupTableAccount,
+  candidates: PublicKey[]
+): PublicKey[] {
+  const present = new Set(table.state.addresses.map(a => a.toBase58()));
+  return candidates.filter(a => !present.has(a.toBase58()));
+}
+
+/**
+ * Fetch lookup table accounts, failing if any is missing
+ */
+export async function fetchLookupTables(
+  connection: Connection,
+  addresses: PublicKey[]
+): Promise<AddressLookupTableAccount[]> {
+  const tables: AddressLookupTableAccount[] = [];
+
+  for (const address of addresses) {
+    const { value } = await connection.getAddressLookupTable(address);
+    if (!value) {
+      throw new Error(`Lookup table ${address.toBase58()} not found`);
+    }
+    tables.push(value);
+  }
+
+  return tables;
+}
+
+/**
+ * Wait until a freshly extended table is usable. Addresses added in a slot
+ * can only be looked up by transactions in later slots.
+ */
+export async function waitForLookupTableActivation(
+  connection: Connection,
+  lookupTable: PublicKey,
+  timeoutMs: number = 30_000
+): Promise<AddressLookupTableAccount> {
+  const deadline = Date.now() + timeoutMs;
+
+  while (Date.now() < deadline) {
+    const [{ value }, slot] = await Promise.all([
+      connection.getAddressLookupTable(lookupTable),
+      connection.getSlot('confirmed'),
+    ]);
+    if (value && slot > value.state.lastExtendedSlot) {
+      return value;
+    }
+    await new Promise(resolve => setTimeout(resolve, 400));
+  }
+
+  throw new Error(`Lookup table ${lookupTable.toBase58()} not activated in time`);
+}
+
+/**
+ * Compile `instructions` into an unsigned v0 transaction
+ */
+export async function buildV0Transaction(
+  connection: Connection,
+  payer: PublicKey,
+  instructions: TransactionInstruction[],
+  lookupTables: AddressLookupTableAccount[] = []
+): Promise<VersionedTransaction> {
+  const { blockhash } = await connection.getLatestBlockhash();
+
+  const message = new TransactionMessage({
+    payerKey: payer,
+    recentBlockhash: blockhash,
+    instructions,
+  }).compileToV0Message(lookupTables);
+
+  return new VersionedTransaction(message);
+}
\ No newline at end of file
@@ -21,6 +21,7 @@ describe("Cross-Chain Integration Tests", () => {
 
   let authority: Keypair;
   let configPda: PublicKey;
+  let mintAuthorityPda: PublicKey;
   let gatewayAuthority: Keypair;
   let tssAuthority: Keypair;
 
@@ -37,6 +38,10 @@ describe("Cross-Chain Integration Tests", () => {
       [Buffer.from("config")],
       program.programId
     );
+    [mintAuthorityPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mint_authority")],
+      program.programId
+    );
 
     // Initialize program
     await program.methods
@@ -106,7 +111,8 @@ describe("Cross-Chain Integration Tests", () => {
           "Cross-Chain Test NFT #1",
           "CCTNFT1",
           "https://arweave.net/test-metadata-1.json",
-          null
+          null,
+          []
         )
         .accounts({
           config: configPda,
@@ -115,7 +121,7 @@ describe("Cross-Chain Integration Tests", () => {
           metadata: metadataPda,
           masterEdition: masterEditionPda,
           tokenAccount: tokenAccount,
-          mintAuthority: universalNftPda,
+          mintAuthority: mintAuthorityPda,
           owner: owner.publicKey,
           payer: authority.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -153,7 +159,7 @@ describe("Cross-Chain Integration Tests", () => {
 
       // Get current nonce for transfer PDA
       const config = await program.account.programConfig.fetch(configPda);
-      const nonce = config.nonce.add(new anchor.BN(1));
+      const nonce = config.nonce;
 
       [transferPda] = PublicKey.findProgramAddressSync(
         [
@@ -328,7 +334,8 @@ describe("Cross-Chain Integration Tests", () => {
           "Token ID Test NFT",
           "TIDNFT",
           "https://arweave.net/token-id-test.json",
-          null
+          null,
+          []
         )
         .accounts({
           config: configPda,
@@ -337,7 +344,7 @@ describe("Cross-Chain Integration Tests", () => {
           metadata: metadataPda,
           masterEdition: masterEditionPda,
           tokenAccount: tokenAccount,
-          mintAuthority: universalNftPda,
+          mintAuthority: mintAuthorityPda,
           owner: owner.publicKey,
           payer: authority.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
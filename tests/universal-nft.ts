@@ -1,9 +1,15 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { UniversalNft } from "../target/types/universal_nft";
-import { PublicKey, Keypair, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js";
+import { PublicKey, Keypair, SystemProgram, SYSVAR_RENT_PUBKEY, Transaction } from "@solana/web3.js";
 import { TOKEN_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
 import { expect } from "chai";
+import {
+  programStaticAccounts,
+  createLookupTableInstructions,
+  waitForLookupTableActivation,
+  buildV0Transaction,
+} from "../sdk/typescript/src/lookupTables";
 
 describe("universal-nft", () => {
   // Configure the client to use the local cluster.
@@ -23,6 +29,7 @@ describe("universal-nft", () => {
   let metadataPda: PublicKey;
   let masterEditionPda: PublicKey;
   let tokenAccount: PublicKey;
+  let lookupTable: anchor.web3.AddressLookupTableAccount;
 
   // Constants
   const MPL_TOKEN_METADATA_PROGRAM_ID = new PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
@@ -112,15 +119,37 @@ describe("universal-nft", () => {
     expect(config.tssAuthority.toString()).to.equal(tssAuthority.publicKey.toString());
   });
 
+  it("Create address lookup table for static accounts", async () => {
+    const recentSlot = await provider.connection.getSlot("finalized");
+    const { lookupTable: tableAddress, instructions } = createLookupTableInstructions(
+      authority.publicKey,
+      authority.publicKey,
+      recentSlot,
+      programStaticAccounts(program.programId)
+    );
+
+    for (const instruction of instructions) {
+      await provider.sendAndConfirm(new Transaction().add(instruction), [authority]);
+    }
+
+    lookupTable = await waitForLookupTableActivation(provider.connection, tableAddress);
+    expect(lookupTable.state.addresses.length).to.equal(
+      programStaticAccounts(program.programId).length
+    );
+  });
+
   it("Mint Universal NFT", async () => {
     const name = "Test Universal NFT";
     const symbol = "TUNFT";
     const uri = "https://example.com/metadata.json";
 
-    const mintAuthorityPda = universalNftPda;
+    const [mintAuthorityPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mint_authority")],
+      program.programId
+    );
 
-    const tx = await program.methods
-      .mintNft(name, symbol, uri, null)
+    const mintIx = await program.methods
+      .mintNft(name, symbol, uri, null, [])
       .accounts({
         config: configPda,
         universalNft: universalNftPda,
@@ -136,8 +165,16 @@ describe("universal-nft", () => {
         systemProgram: SystemProgram.programId,
         rent: SYSVAR_RENT_PUBKEY,
       })
-      .signers([authority, mint])
-      .rpc();
+      .instruction();
+
+    // Mint references too many accounts for comfort in a legacy transaction
+    const v0Tx = await buildV0Transaction(
+      provider.connection,
+      provider.publicKey!,
+      [mintIx],
+      [lookupTable]
+    );
+    const tx = await provider.sendAndConfirm(v0Tx, [authority, mint]);
 
     console.log("Mint NFT transaction signature", tx);
 
@@ -300,7 +337,7 @@ describe("universal-nft", () => {
 
     try {
       await program.methods
-        .mintNft("Paused NFT", "PNFT", "https://example.com/paused.json", null)
+        .mintNft("Paused NFT", "PNFT", "https://example.com/paused.json", null, [])
         .accounts({
           config: configPda,
           universalNft: newUniversalNftPda,
@@ -385,7 +422,7 @@ describe("universal-nft", () => {
       const testTokenAccount = await getAssociatedTokenAddress(testMint.publicKey, user.publicKey);
 
       const tx = await program.methods
-        .mintNft(`Compute Test NFT ${i}`, "CTNFT", `https://example.com/compute-${i}.json`, null)
+        .mintNft(`Compute Test NFT ${i}`, "CTNFT", `https://example.com/compute-${i}.json`, null, [])
         .accounts({
           config: configPda,
           universalNft: testUniversalNftPda,
@@ -393,7 +430,10 @@ describe("universal-nft", () => {
           metadata: testMetadataPda,
           masterEdition: testMasterEditionPda,
           tokenAccount: testTokenAccount,
-          mintAuthority: testUniversalNftPda,
+          mintAuthority: PublicKey.findProgramAddressSync(
+            [Buffer.from("mint_authority")],
+            program.programId
+          )[0],
           owner: user.publicKey,
           payer: authority.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
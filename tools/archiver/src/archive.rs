@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::events::StateSnapshotPage;
+
+/// Archived snapshot of one account at one slot, stored as
+/// `<archive>/<account>/<slot>.json`
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AccountSnapshot {
+    pub account: String,
+    pub owner: String,
+    pub slot: u64,
+    pub data_len: u32,
+    pub data_hash: String,
+    pub total_pages: u32,
+    /// Page index -> base64 page data
+    pub pages: BTreeMap<u32, String>,
+}
+
+impl AccountSnapshot {
+    fn from_page(page: &StateSnapshotPage) -> Self {
+        Self {
+            account: Pubkey::new_from_array(page.account).to_string(),
+            owner: Pubkey::new_from_array(page.owner).to_string(),
+            slot: page.slot,
+            data_len: page.data_len,
+            data_hash: hex_encode(&page.data_hash),
+            total_pages: page.total_pages,
+            pages: BTreeMap::new(),
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.pages.len() as u32 == self.total_pages
+    }
+
+    /// Reassemble the account data and check it against the exported hash
+    pub fn assemble(&self) -> Result<Vec<u8>> {
+        if !self.is_complete() {
+            bail!(
+                "snapshot of {} at slot {} has {}/{} pages",
+                self.account, self.slot, self.pages.len(), self.total_pages
+            );
+        }
+
+        let mut data = Vec::with_capacity(self.data_len as usize);
+        for page in self.pages.values() {
+            data.extend(STANDARD.decode(page)?);
+        }
+
+        if data.len() != self.data_len as usize {
+            bail!("reassembled {} bytes, expected {}", data.len(), self.data_len);
+        }
+        if hex_encode(&Sha256::digest(&data)) != self.data_hash {
+            bail!("data hash mismatch: pages were exported from different account states");
+        }
+
+        Ok(data)
+    }
+}
+
+pub struct Archive {
+    root: PathBuf,
+}
+
+impl Archive {
+    pub fn open(root: impl AsRef<Path>) -> Result<Self> {
+        fs::create_dir_all(root.as_ref())?;
+        Ok(Self { root: root.as_ref().to_path_buf() })
+    }
+
+    fn snapshot_path(&self, account: &str, slot: u64) -> PathBuf {
+        self.root.join(account).join(format!("{}.json", slot))
+    }
+
+    /// Merge decoded pages into the archive, returning the number stored
+    pub fn store_pages(&self, pages: &[StateSnapshotPage]) -> Result<usize> {
+        for page in pages {
+            let account = Pubkey::new_from_array(page.account).to_string();
+            let path = self.snapshot_path(&account, page.slot);
+
+            let mut snapshot = match self.load(&account, page.slot) {
+                Ok(existing) => existing,
+                Err(_) => AccountSnapshot::from_page(page),
+            };
+            if snapshot.data_hash != hex_encode(&page.data_hash) {
+                bail!("page {} of {} at slot {} conflicts with archived hash", page.page, account, page.slot);
+            }
+            snapshot.pages.insert(page.page, STANDARD.encode(&page.data));
+
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(&path, serde_json::to_vec_pretty(&snapshot)?)?;
+        }
+        Ok(pages.len())
+    }
+
+    pub fn load(&self, account: &str, slot: u64) -> Result<AccountSnapshot> {
+        let bytes = fs::read(self.snapshot_path(account, slot))?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    /// All archived slots for an account, oldest first
+    pub fn slots(&self, account: &str) -> Result<Vec<u64>> {
+        let dir = self.root.join(account);
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut slots: Vec<u64> = fs::read_dir(dir)?
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                entry.path().file_stem()?.to_str()?.parse().ok()
+            })
+            .collect();
+        slots.sort_unstable();
+        Ok(slots)
+    }
+
+    /// Latest complete snapshot for an account
+    pub fn latest_complete(&self, account: &str) -> Result<AccountSnapshot> {
+        for slot in self.slots(account)?.into_iter().rev() {
+            let snapshot = self.load(account, slot)?;
+            if snapshot.is_complete() {
+                return Ok(snapshot);
+            }
+        }
+        bail!("no complete snapshot archived for {}", account)
+    }
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
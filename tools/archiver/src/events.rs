@@ -0,0 +1,78 @@
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use borsh::{BorshDeserialize, BorshSerialize};
+use sha2::{Digest, Sha256};
+
+/// Log prefix Anchor uses for `emit!` event payloads
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+/// Mirror of the on-chain `StateSnapshotPage` event
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct StateSnapshotPage {
+    pub account: [u8; 32],
+    pub owner: [u8; 32],
+    pub slot: u64,
+    pub page: u32,
+    pub total_pages: u32,
+    pub data_len: u32,
+    pub data_hash: [u8; 32],
+    pub data: Vec<u8>,
+}
+
+/// Anchor discriminator for a named event or instruction
+pub fn discriminator(namespace: &str, name: &str) -> [u8; 8] {
+    let hash = Sha256::digest(format!("{}:{}", namespace, name).as_bytes());
+    let mut out = [0u8; 8];
+    out.copy_from_slice(&hash[..8]);
+    out
+}
+
+/// Decode every snapshot page event found in a set of transaction log lines
+pub fn decode_snapshot_pages<'a, I>(log_lines: I) -> Result<Vec<StateSnapshotPage>>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let expected = discriminator("event", "StateSnapshotPage");
+    let mut pages = Vec::new();
+
+    for line in log_lines {
+        let Some(payload) = line.trim().strip_prefix(PROGRAM_DATA_PREFIX) else {
+            continue;
+        };
+        let bytes = STANDARD
+            .decode(payload)
+            .map_err(|e| anyhow!("invalid base64 event payload: {}", e))?;
+        if bytes.len() < 8 || bytes[..8] != expected {
+            continue;
+        }
+        pages.push(StateSnapshotPage::try_from_slice(&bytes[8..])?);
+    }
+
+    Ok(pages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_snapshot_page() {
+        let page = StateSnapshotPage {
+            account: [1; 32],
+            owner: [2; 32],
+            slot: 42,
+            page: 0,
+            total_pages: 1,
+            data_len: 3,
+            data_hash: [3; 32],
+            data: vec![7, 8, 9],
+        };
+        let mut payload = discriminator("event", "StateSnapshotPage").to_vec();
+        payload.extend(page.try_to_vec().unwrap());
+        let line = format!("{}{}", PROGRAM_DATA_PREFIX, STANDARD.encode(payload));
+
+        let logs = ["Program log: Instruction: ExportStateSnapshot", line.as_str()];
+        let decoded = decode_snapshot_pages(logs.iter().copied()).unwrap();
+        assert_eq!(decoded, vec![page]);
+    }
+}
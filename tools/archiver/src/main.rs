@@ -0,0 +1,106 @@
+//! Off-chain archiver for Universal NFT state snapshots.
+//!
+//! `ingest` decodes `StateSnapshotPage` events from transaction logs
+//! (one log line per line, as printed by `solana logs` or read from
+//! `meta.logMessages`) into an on-disk archive. `restore` turns the latest
+//! complete snapshot of an account into unsigned restore transactions for the
+//! program authority to sign. The program applies the restored data only if
+//! it matches the account's leaf in the given state checkpoint.
+
+mod archive;
+mod events;
+mod restore;
+
+use std::env;
+use std::fs;
+
+use anyhow::{bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use solana_sdk::{hash::Hash, pubkey::Pubkey};
+
+use archive::Archive;
+
+const USAGE: &str = "usage:
+  universal-nft-archiver ingest <archive-dir> <log-file>...
+  universal-nft-archiver list <archive-dir> <account>
+  universal-nft-archiver restore <archive-dir> <account> <program-id> <authority> <checkpoint-id> <leaf-index> <recent-blockhash>";
+
+fn main() -> Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("ingest") if args.len() >= 3 => ingest(&args[1], &args[2..]),
+        Some("list") if args.len() == 3 => list(&args[1], &args[2]),
+        Some("restore") if args.len() == 8 => restore(&args[1..]),
+        _ => bail!(USAGE),
+    }
+}
+
+fn ingest(archive_dir: &str, log_files: &[String]) -> Result<()> {
+    let archive = Archive::open(archive_dir)?;
+
+    for log_file in log_files {
+        let logs = fs::read_to_string(log_file)
+            .with_context(|| format!("reading {}", log_file))?;
+        let pages = events::decode_snapshot_pages(logs.lines())?;
+        let stored = archive.store_pages(&pages)?;
+        println!("{}: archived {} snapshot pages", log_file, stored);
+    }
+
+    Ok(())
+}
+
+fn list(archive_dir: &str, account: &str) -> Result<()> {
+    let archive = Archive::open(archive_dir)?;
+
+    for slot in archive.slots(account)? {
+        let snapshot = archive.load(account, slot)?;
+        println!(
+            "slot {}: {}/{} pages, {} bytes, hash {}",
+            slot,
+            snapshot.pages.len(),
+            snapshot.total_pages,
+            snapshot.data_len,
+            snapshot.data_hash
+        );
+    }
+
+    Ok(())
+}
+
+fn restore(args: &[String]) -> Result<()> {
+    let [archive_dir, account, program_id, authority, checkpoint_id, leaf_index, recent_blockhash] =
+        args
+    else {
+        bail!(USAGE);
+    };
+    let archive = Archive::open(archive_dir)?;
+    let snapshot = archive.latest_complete(account)?;
+
+    let program_id: Pubkey = program_id.parse().context("invalid program id")?;
+    let authority: Pubkey = authority.parse().context("invalid authority")?;
+    let checkpoint_id: u64 = checkpoint_id.parse().context("invalid checkpoint id")?;
+    let leaf_index: u8 = leaf_index.parse().context("invalid leaf index")?;
+    let recent_blockhash: Hash = recent_blockhash.parse().context("invalid blockhash")?;
+
+    let transactions = restore::build_restore_transactions(
+        &program_id,
+        &authority,
+        &snapshot,
+        checkpoint_id,
+        leaf_index,
+        recent_blockhash,
+    )?;
+
+    eprintln!(
+        "restoring {} from slot {} in {} transactions",
+        snapshot.account,
+        snapshot.slot,
+        transactions.len()
+    );
+    for tx in transactions {
+        println!("{}", STANDARD.encode(bincode::serialize(&tx)?));
+    }
+
+    Ok(())
+}
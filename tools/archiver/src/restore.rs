@@ -0,0 +1,115 @@
+use anyhow::Result;
+use borsh::BorshSerialize;
+use solana_sdk::{
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+    transaction::Transaction,
+};
+
+use crate::archive::AccountSnapshot;
+use crate::events::discriminator;
+
+/// Bytes written per restore instruction; must not exceed the program's
+/// `MAX_RESTORE_CHUNK`
+pub const RESTORE_CHUNK_SIZE: usize = 900;
+
+#[derive(BorshSerialize)]
+struct BeginStateRestoreArgs {
+    checkpoint_id: u64,
+    leaf_index: u8,
+}
+
+#[derive(BorshSerialize)]
+struct RestoreStateChunkArgs {
+    offset: u32,
+    chunk: Vec<u8>,
+}
+
+/// Build the unsigned restore transactions for an archived snapshot: open a
+/// restore buffer against checkpoint `checkpoint_id`, stage the data in
+/// chunks, then apply it. The program only applies the data if it matches
+/// the account's leaf `leaf_index` in that checkpoint.
+pub fn build_restore_transactions(
+    program_id: &Pubkey,
+    authority: &Pubkey,
+    snapshot: &AccountSnapshot,
+    checkpoint_id: u64,
+    leaf_index: u8,
+    recent_blockhash: Hash,
+) -> Result<Vec<Transaction>> {
+    let data = snapshot.assemble()?;
+    let target: Pubkey = snapshot.account.parse()?;
+    let (config, _) = Pubkey::find_program_address(&[b"config"], program_id);
+    let (checkpoint, _) = Pubkey::find_program_address(
+        &[b"state_checkpoint", &checkpoint_id.to_le_bytes()],
+        program_id,
+    );
+    let (buffer, _) =
+        Pubkey::find_program_address(&[b"restore_buffer", target.as_ref()], program_id);
+
+    let mut instructions = Vec::new();
+
+    let mut ix_data = discriminator("global", "begin_state_restore").to_vec();
+    ix_data.extend(
+        BeginStateRestoreArgs {
+            checkpoint_id,
+            leaf_index,
+        }
+        .try_to_vec()?,
+    );
+    instructions.push(Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(checkpoint, false),
+            AccountMeta::new_readonly(target, false),
+            AccountMeta::new(buffer, false),
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        data: ix_data,
+    });
+
+    for (i, chunk) in data.chunks(RESTORE_CHUNK_SIZE).enumerate() {
+        let args = RestoreStateChunkArgs {
+            offset: (i * RESTORE_CHUNK_SIZE) as u32,
+            chunk: chunk.to_vec(),
+        };
+
+        let mut ix_data = discriminator("global", "restore_state_chunk").to_vec();
+        ix_data.extend(args.try_to_vec()?);
+        instructions.push(Instruction {
+            program_id: *program_id,
+            accounts: vec![
+                AccountMeta::new(buffer, false),
+                AccountMeta::new_readonly(*authority, true),
+            ],
+            data: ix_data,
+        });
+    }
+
+    instructions.push(Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(buffer, false),
+            AccountMeta::new_readonly(checkpoint, false),
+            AccountMeta::new(target, false),
+            AccountMeta::new(*authority, true),
+        ],
+        data: discriminator("global", "apply_state_restore").to_vec(),
+    });
+
+    let transactions = instructions
+        .into_iter()
+        .map(|instruction| {
+            let mut tx = Transaction::new_with_payer(&[instruction], Some(authority));
+            tx.message.recent_blockhash = recent_blockhash;
+            tx
+        })
+        .collect();
+
+    Ok(transactions)
+}
@@ -0,0 +1,107 @@
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use borsh::{BorshDeserialize, BorshSerialize};
+use sha2::{Digest, Sha256};
+
+/// Log prefix Anchor uses for `emit!` event payloads
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+/// Mirror of the on-chain `NftMinted` event
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct NftMinted {
+    pub mint: [u8; 32],
+    pub owner: [u8; 32],
+    pub uri: String,
+    pub timestamp: i64,
+}
+
+/// Mirror of the on-chain `NftMetadataUpdated` event
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct NftMetadataUpdated {
+    pub mint: [u8; 32],
+    pub uri: String,
+    pub timestamp: i64,
+}
+
+/// A metadata URI an NFT was minted with or updated to
+#[derive(Clone, Debug, PartialEq)]
+pub struct UriEvent {
+    pub mint: [u8; 32],
+    pub uri: String,
+}
+
+/// Anchor discriminator for a named event or instruction
+pub fn discriminator(namespace: &str, name: &str) -> [u8; 8] {
+    let hash = Sha256::digest(format!("{}:{}", namespace, name).as_bytes());
+    let mut out = [0u8; 8];
+    out.copy_from_slice(&hash[..8]);
+    out
+}
+
+/// Decode every mint and metadata update event found in a set of
+/// transaction log lines, in log order
+pub fn decode_uri_events<'a, I>(log_lines: I) -> Result<Vec<UriEvent>>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let minted = discriminator("event", "NftMinted");
+    let updated = discriminator("event", "NftMetadataUpdated");
+    let mut events = Vec::new();
+
+    for line in log_lines {
+        let Some(payload) = line.trim().strip_prefix(PROGRAM_DATA_PREFIX) else {
+            continue;
+        };
+        let bytes = STANDARD
+            .decode(payload)
+            .map_err(|e| anyhow!("invalid base64 event payload: {}", e))?;
+        if bytes.len() < 8 {
+            continue;
+        }
+        if bytes[..8] == minted {
+            let event = NftMinted::try_from_slice(&bytes[8..])?;
+            events.push(UriEvent { mint: event.mint, uri: event.uri });
+        } else if bytes[..8] == updated {
+            let event = NftMetadataUpdated::try_from_slice(&bytes[8..])?;
+            events.push(UriEvent { mint: event.mint, uri: event.uri });
+        }
+    }
+
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event_line<T: BorshSerialize>(name: &str, event: &T) -> String {
+        let mut payload = discriminator("event", name).to_vec();
+        payload.extend(event.try_to_vec().unwrap());
+        format!("{}{}", PROGRAM_DATA_PREFIX, STANDARD.encode(payload))
+    }
+
+    #[test]
+    fn test_decode_uri_events() {
+        let minted = event_line("NftMinted", &NftMinted {
+            mint: [1; 32],
+            owner: [2; 32],
+            uri: "ipfs://bafy/1.json".to_string(),
+            timestamp: 1,
+        });
+        let updated = event_line("NftMetadataUpdated", &NftMetadataUpdated {
+            mint: [1; 32],
+            uri: "ar://tx".to_string(),
+            timestamp: 2,
+        });
+
+        let logs = ["Program log: Instruction: MintNft", minted.as_str(), updated.as_str()];
+        let decoded = decode_uri_events(logs.iter().copied()).unwrap();
+        assert_eq!(
+            decoded,
+            vec![
+                UriEvent { mint: [1; 32], uri: "ipfs://bafy/1.json".to_string() },
+                UriEvent { mint: [1; 32], uri: "ar://tx".to_string() },
+            ]
+        );
+    }
+}
@@ -0,0 +1,90 @@
+//! Pinning companion for Universal NFT metadata.
+//!
+//! Reads `NftMinted` and `NftMetadataUpdated` events from transaction logs
+//! (one log line per line, as printed by `solana logs` or read from
+//! `meta.logMessages`), pins the content behind each NFT's latest URI, and
+//! prints unsigned `record_pin_receipt` transactions for the registered
+//! pinner to sign. IPFS content is pinned through a Kubo RPC endpoint
+//! (`IPFS_API_URL`, default `http://127.0.0.1:5001`); Arweave content is
+//! checked through a gateway (`ARWEAVE_GATEWAY`, default
+//! `https://arweave.net`). Content served from other HTTP hosts is added to
+//! IPFS, so the receipt points at a copy the pinner controls.
+
+mod events;
+mod pin;
+mod receipt;
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+
+use anyhow::{bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use solana_sdk::{hash::Hash, pubkey::Pubkey};
+
+use pin::Pinner;
+
+const USAGE: &str = "usage:
+  universal-nft-pinner scan <log-file>...
+  universal-nft-pinner pin <program-id> <pinner> <recent-blockhash> <log-file>...";
+
+fn main() -> Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("scan") if args.len() >= 2 => scan(&args[1..]),
+        Some("pin") if args.len() >= 5 => pin(&args[1], &args[2], &args[3], &args[4..]),
+        _ => bail!(USAGE),
+    }
+}
+
+/// Latest metadata URI of every NFT seen in the logs
+fn latest_uris(log_files: &[String]) -> Result<BTreeMap<Pubkey, String>> {
+    let mut uris = BTreeMap::new();
+
+    for log_file in log_files {
+        let logs = fs::read_to_string(log_file)
+            .with_context(|| format!("reading {}", log_file))?;
+        for event in events::decode_uri_events(logs.lines())? {
+            uris.insert(Pubkey::new_from_array(event.mint), event.uri);
+        }
+    }
+
+    Ok(uris)
+}
+
+fn scan(log_files: &[String]) -> Result<()> {
+    for (mint, uri) in latest_uris(log_files)? {
+        println!("{} {}", mint, uri);
+    }
+
+    Ok(())
+}
+
+fn pin(program_id: &str, pinner: &str, recent_blockhash: &str, log_files: &[String]) -> Result<()> {
+    let program_id: Pubkey = program_id.parse().context("invalid program id")?;
+    let pinner: Pubkey = pinner.parse().context("invalid pinner")?;
+    let recent_blockhash: Hash = recent_blockhash.parse().context("invalid blockhash")?;
+
+    let service = Pinner::new(
+        env::var("IPFS_API_URL").unwrap_or_else(|_| "http://127.0.0.1:5001".to_string()),
+        env::var("ARWEAVE_GATEWAY").unwrap_or_else(|_| "https://arweave.net".to_string()),
+    );
+
+    for (mint, uri) in latest_uris(log_files)? {
+        // One unreachable URI shouldn't hold back receipts for the rest
+        let pinned = match service.pin(&uri) {
+            Ok(pinned) => pinned,
+            Err(e) => {
+                eprintln!("{}: {:#}", mint, e);
+                continue;
+            }
+        };
+        eprintln!("{}: pinned {} ({} bytes)", mint, pinned.content_id, pinned.size);
+
+        let tx = receipt::build_receipt_transaction(&program_id, &pinner, &mint, &pinned, recent_blockhash)?;
+        println!("{}", STANDARD.encode(bincode::serialize(&tx)?));
+    }
+
+    Ok(())
+}
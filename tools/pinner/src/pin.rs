@@ -0,0 +1,203 @@
+use std::io::Read;
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Deserialize;
+
+/// Largest content the pinner downloads to re-host from a plain HTTP URI
+const MAX_FETCH_BYTES: u64 = 50 * 1024 * 1024;
+
+/// Storage network a receipt names; mirrors the on-chain `PinStorage`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Storage {
+    Ipfs,
+    Arweave,
+}
+
+/// Where a metadata URI's content lives
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ContentRef {
+    /// IPFS content, from `ipfs://` or an HTTP gateway `/ipfs/` path
+    Ipfs { cid: String, path: String },
+    /// Arweave transaction, from `ar://` or an arweave.net link
+    Arweave { tx_id: String },
+    /// Any other HTTP(S) URI; the content is added to IPFS
+    Http { url: String },
+}
+
+impl ContentRef {
+    pub fn parse(uri: &str) -> Result<Self> {
+        if let Some(rest) = uri.strip_prefix("ipfs://") {
+            return Ok(Self::ipfs(rest.trim_start_matches("ipfs/")));
+        }
+        if let Some(tx_id) = uri.strip_prefix("ar://") {
+            return Ok(Self::Arweave { tx_id: tx_id.to_string() });
+        }
+        if !(uri.starts_with("https://") || uri.starts_with("http://")) {
+            bail!("unsupported metadata URI: {}", uri);
+        }
+
+        if let Some((_, rest)) = uri.split_once("/ipfs/") {
+            return Ok(Self::ipfs(rest));
+        }
+        let host_and_path = uri.split_once("://").map(|(_, rest)| rest).unwrap_or(uri);
+        if let Some(tx_id) = host_and_path.strip_prefix("arweave.net/") {
+            return Ok(Self::Arweave { tx_id: tx_id.to_string() });
+        }
+        Ok(Self::Http { url: uri.to_string() })
+    }
+
+    fn ipfs(cid_and_path: &str) -> Self {
+        let (cid, path) = cid_and_path.split_once('/').unwrap_or((cid_and_path, ""));
+        Self::Ipfs { cid: cid.to_string(), path: path.to_string() }
+    }
+}
+
+/// Content pinned for a receipt
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pinned {
+    pub storage: Storage,
+    /// CID (with any path inside it) or Arweave transaction ID
+    pub content_id: String,
+    pub size: u64,
+}
+
+#[derive(Deserialize)]
+struct FilesStat {
+    #[serde(rename = "CumulativeSize")]
+    cumulative_size: u64,
+}
+
+#[derive(Deserialize)]
+struct AddResult {
+    #[serde(rename = "Hash")]
+    hash: String,
+}
+
+/// Pins content through a Kubo (go-ipfs) RPC endpoint and checks Arweave
+/// availability through a gateway
+pub struct Pinner {
+    ipfs_api: String,
+    arweave_gateway: String,
+}
+
+impl Pinner {
+    pub fn new(ipfs_api: String, arweave_gateway: String) -> Self {
+        Self {
+            ipfs_api: ipfs_api.trim_end_matches('/').to_string(),
+            arweave_gateway: arweave_gateway.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Pin the content behind `uri`
+    pub fn pin(&self, uri: &str) -> Result<Pinned> {
+        match ContentRef::parse(uri)? {
+            ContentRef::Ipfs { cid, path } => self.pin_ipfs(&cid, &path),
+            ContentRef::Arweave { tx_id } => self.check_arweave(&tx_id),
+            ContentRef::Http { url } => self.add_to_ipfs(&url),
+        }
+    }
+
+    fn pin_ipfs(&self, cid: &str, path: &str) -> Result<Pinned> {
+        ureq::post(&format!("{}/api/v0/pin/add", self.ipfs_api))
+            .query("arg", cid)
+            .call()
+            .with_context(|| format!("pinning {}", cid))?;
+
+        let content_id = if path.is_empty() {
+            cid.to_string()
+        } else {
+            format!("{}/{}", cid, path)
+        };
+        let stat: FilesStat = ureq::post(&format!("{}/api/v0/files/stat", self.ipfs_api))
+            .query("arg", &format!("/ipfs/{}", content_id))
+            .call()
+            .with_context(|| format!("sizing {}", content_id))?
+            .into_json()?;
+
+        Ok(Pinned { storage: Storage::Ipfs, content_id, size: stat.cumulative_size })
+    }
+
+    /// Arweave content is permanent once mined; the receipt records that it is
+    fn check_arweave(&self, tx_id: &str) -> Result<Pinned> {
+        let size: u64 = ureq::get(&format!("{}/tx/{}/data_size", self.arweave_gateway, tx_id))
+            .call()
+            .with_context(|| format!("looking up Arweave tx {}", tx_id))?
+            .into_string()?
+            .trim()
+            .parse()
+            .context("invalid Arweave data size")?;
+        if size == 0 {
+            bail!("Arweave tx {} has no data yet", tx_id);
+        }
+
+        Ok(Pinned { storage: Storage::Arweave, content_id: tx_id.to_string(), size })
+    }
+
+    /// Download content served over plain HTTP and add it to IPFS pinned
+    fn add_to_ipfs(&self, url: &str) -> Result<Pinned> {
+        let mut content = Vec::new();
+        ureq::get(url)
+            .call()
+            .with_context(|| format!("fetching {}", url))?
+            .into_reader()
+            .take(MAX_FETCH_BYTES + 1)
+            .read_to_end(&mut content)?;
+        if content.len() as u64 > MAX_FETCH_BYTES {
+            bail!("{} is larger than {} bytes", url, MAX_FETCH_BYTES);
+        }
+
+        let boundary = "universal-nft-pinner-boundary";
+        let mut body = format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"content\"\r\n\
+             Content-Type: application/octet-stream\r\n\r\n",
+            boundary
+        )
+        .into_bytes();
+        body.extend_from_slice(&content);
+        body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+
+        let added: AddResult = ureq::post(&format!("{}/api/v0/add", self.ipfs_api))
+            .query("pin", "true")
+            .query("cid-version", "1")
+            .set("Content-Type", &format!("multipart/form-data; boundary={}", boundary))
+            .send_bytes(&body)
+            .map_err(|e| anyhow!("adding {} to IPFS: {}", url, e))?
+            .into_json()?;
+
+        Ok(Pinned { storage: Storage::Ipfs, content_id: added.hash, size: content.len() as u64 })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_content_ref() {
+        assert_eq!(
+            ContentRef::parse("ipfs://bafyabc/1.json").unwrap(),
+            ContentRef::Ipfs { cid: "bafyabc".to_string(), path: "1.json".to_string() }
+        );
+        assert_eq!(
+            ContentRef::parse("ipfs://ipfs/bafyabc").unwrap(),
+            ContentRef::Ipfs { cid: "bafyabc".to_string(), path: String::new() }
+        );
+        assert_eq!(
+            ContentRef::parse("https://nftstorage.link/ipfs/bafyabc/meta.json").unwrap(),
+            ContentRef::Ipfs { cid: "bafyabc".to_string(), path: "meta.json".to_string() }
+        );
+        assert_eq!(
+            ContentRef::parse("https://arweave.net/abc123").unwrap(),
+            ContentRef::Arweave { tx_id: "abc123".to_string() }
+        );
+        assert_eq!(
+            ContentRef::parse("ar://abc123").unwrap(),
+            ContentRef::Arweave { tx_id: "abc123".to_string() }
+        );
+        assert_eq!(
+            ContentRef::parse("https://example.com/1.json").unwrap(),
+            ContentRef::Http { url: "https://example.com/1.json".to_string() }
+        );
+        assert!(ContentRef::parse("ftp://example.com/1.json").is_err());
+    }
+}
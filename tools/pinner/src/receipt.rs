@@ -0,0 +1,74 @@
+use anyhow::{bail, Result};
+use borsh::BorshSerialize;
+use solana_sdk::{
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+    transaction::Transaction,
+};
+
+use crate::events::discriminator;
+use crate::pin::{Pinned, Storage};
+
+/// Longest content ID the program accepts; must match `PinReceipt::MAX_CONTENT_ID_LEN`
+pub const MAX_CONTENT_ID_LEN: usize = 96;
+
+#[derive(BorshSerialize)]
+enum PinStorage {
+    Ipfs,
+    Arweave,
+}
+
+#[derive(BorshSerialize)]
+struct RecordPinReceiptArgs {
+    storage: PinStorage,
+    content_id: String,
+    size: u64,
+}
+
+/// Build the unsigned `record_pin_receipt` transaction for pinned content,
+/// paid for and signed by the pinner
+pub fn build_receipt_transaction(
+    program_id: &Pubkey,
+    pinner: &Pubkey,
+    mint: &Pubkey,
+    pinned: &Pinned,
+    recent_blockhash: Hash,
+) -> Result<Transaction> {
+    if pinned.content_id.len() > MAX_CONTENT_ID_LEN {
+        bail!("content ID {} is longer than {} bytes", pinned.content_id, MAX_CONTENT_ID_LEN);
+    }
+
+    let (pin_config, _) = Pubkey::find_program_address(&[b"pin_config"], program_id);
+    let (universal_nft, _) =
+        Pubkey::find_program_address(&[b"universal_nft", mint.as_ref()], program_id);
+    let (pin_receipt, _) = Pubkey::find_program_address(&[b"pin_receipt", mint.as_ref()], program_id);
+
+    let args = RecordPinReceiptArgs {
+        storage: match pinned.storage {
+            Storage::Ipfs => PinStorage::Ipfs,
+            Storage::Arweave => PinStorage::Arweave,
+        },
+        content_id: pinned.content_id.clone(),
+        size: pinned.size,
+    };
+    let mut data = discriminator("global", "record_pin_receipt").to_vec();
+    data.extend(args.try_to_vec()?);
+
+    let instruction = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(pin_config, false),
+            AccountMeta::new_readonly(universal_nft, false),
+            AccountMeta::new(pin_receipt, false),
+            AccountMeta::new(*pinner, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    };
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(pinner));
+    tx.message.recent_blockhash = recent_blockhash;
+    Ok(tx)
+}
@@ -0,0 +1,79 @@
+use anyhow::{anyhow, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use borsh::{BorshDeserialize, BorshSerialize};
+use sha2::{Digest, Sha256};
+
+/// Log prefix Anchor uses for `emit!` event payloads
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+/// Mirror of the on-chain `RetryAttemptStarted` event
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, PartialEq)]
+pub struct RetryAttemptStarted {
+    pub session: [u8; 32],
+    pub session_id: u64,
+    pub attempt: u8,
+    pub original_tx_signature: String,
+    pub compute_unit_limit: u32,
+    pub priority_fee_micro_lamports: u64,
+    pub timestamp: i64,
+}
+
+/// Anchor discriminator for a named event, instruction or account
+pub fn discriminator(namespace: &str, name: &str) -> [u8; 8] {
+    let hash = Sha256::digest(format!("{}:{}", namespace, name).as_bytes());
+    let mut out = [0u8; 8];
+    out.copy_from_slice(&hash[..8]);
+    out
+}
+
+/// Decode every retry attempt started in a set of transaction log lines, in
+/// log order
+pub fn decode_attempts<'a, I>(log_lines: I) -> Result<Vec<RetryAttemptStarted>>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let started = discriminator("event", "RetryAttemptStarted");
+    let mut attempts = Vec::new();
+
+    for line in log_lines {
+        let Some(payload) = line.trim().strip_prefix(PROGRAM_DATA_PREFIX) else {
+            continue;
+        };
+        let bytes = STANDARD
+            .decode(payload)
+            .map_err(|e| anyhow!("invalid base64 event payload: {}", e))?;
+        if bytes.len() >= 8 && bytes[..8] == started {
+            attempts.push(RetryAttemptStarted::try_from_slice(&bytes[8..])?);
+        }
+    }
+
+    Ok(attempts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_attempts() {
+        let attempt = RetryAttemptStarted {
+            session: [3; 32],
+            session_id: 7,
+            attempt: 2,
+            original_tx_signature: "5xYz".to_string(),
+            compute_unit_limit: 240_000,
+            priority_fee_micro_lamports: 1_500,
+            timestamp: 1_700_000_000,
+        };
+        let mut payload = discriminator("event", "RetryAttemptStarted").to_vec();
+        payload.extend(attempt.try_to_vec().unwrap());
+        let line = format!("{}{}", PROGRAM_DATA_PREFIX, STANDARD.encode(payload));
+
+        let mut other = discriminator("event", "CrankExecuted").to_vec();
+        other.extend([0u8; 16]);
+        let other = format!("{}{}", PROGRAM_DATA_PREFIX, STANDARD.encode(other));
+
+        let logs = ["Program log: Instruction: ProcessDueRetries", other.as_str(), line.as_str()];
+        assert_eq!(decode_attempts(logs.iter().copied()).unwrap(), vec![attempt]);
+    }
+}
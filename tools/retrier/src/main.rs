@@ -0,0 +1,163 @@
+//! Retry companion for Universal NFT transactions.
+//!
+//! Reads `RetryAttemptStarted` events from transaction logs (one log line
+//! per line, as printed by `solana logs` or read from `meta.logMessages`),
+//! rebuilds each attempt's failed transaction with the compute unit limit
+//! and priority fee the program chose, resubmits it with a fresh blockhash,
+//! and reports the outcome through `report_retry_outcome`. Only legacy
+//! transactions signed solely by the retrier's keypair can be rebuilt. RPC
+//! requests go to `SOLANA_RPC_URL` (default `http://127.0.0.1:8899`).
+
+mod events;
+mod report;
+mod resubmit;
+mod rpc;
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+
+use anyhow::{anyhow, bail, Context, Result};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signature, Signer},
+};
+
+use events::RetryAttemptStarted;
+use report::{RetryOutcomeReport, RetrySessionHead, RetrySessionStatus};
+use resubmit::Resubmitter;
+use rpc::Rpc;
+
+const USAGE: &str = "usage:
+  universal-nft-retrier scan <log-file>...
+  universal-nft-retrier run <program-id> <keypair-file> <log-file>...";
+
+/// Times a report is posted before giving up on it
+const REPORT_TRIES: usize = 3;
+
+fn main() -> Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("scan") if args.len() >= 2 => scan(&args[1..]),
+        Some("run") if args.len() >= 4 => run(&args[1], &args[2], &args[3..]),
+        _ => bail!(USAGE),
+    }
+}
+
+/// Latest attempt started for every retry session seen in the logs
+fn latest_attempts(log_files: &[String]) -> Result<BTreeMap<Pubkey, RetryAttemptStarted>> {
+    let mut attempts = BTreeMap::new();
+
+    for log_file in log_files {
+        let logs = fs::read_to_string(log_file)
+            .with_context(|| format!("reading {}", log_file))?;
+        for attempt in events::decode_attempts(logs.lines())? {
+            let session = Pubkey::new_from_array(attempt.session);
+            match attempts.get(&session) {
+                Some(RetryAttemptStarted { attempt: seen, .. }) if *seen >= attempt.attempt => {}
+                _ => {
+                    attempts.insert(session, attempt);
+                }
+            }
+        }
+    }
+
+    Ok(attempts)
+}
+
+fn scan(log_files: &[String]) -> Result<()> {
+    for (session, attempt) in latest_attempts(log_files)? {
+        println!(
+            "{} attempt {} {} cu={} fee={}",
+            session,
+            attempt.attempt,
+            attempt.original_tx_signature,
+            attempt.compute_unit_limit,
+            attempt.priority_fee_micro_lamports,
+        );
+    }
+
+    Ok(())
+}
+
+fn run(program_id: &str, keypair_file: &str, log_files: &[String]) -> Result<()> {
+    let program_id: Pubkey = program_id.parse().context("invalid program id")?;
+    let keypair = read_keypair_file(keypair_file)
+        .map_err(|e| anyhow!("reading keypair {}: {}", keypair_file, e))?;
+
+    let rpc = Rpc::new(env::var("SOLANA_RPC_URL").unwrap_or_else(|_| "http://127.0.0.1:8899".to_string()));
+    let resubmitter = Resubmitter::new(&rpc, &keypair);
+
+    for (session, attempt) in latest_attempts(log_files)? {
+        // Logs are replayed freely; only attempts still awaiting an outcome
+        // are resubmitted, so none is sent twice
+        let head = match rpc.account_data(&session)? {
+            Some(data) => RetrySessionHead::decode(&data)?,
+            None => {
+                eprintln!("{}: session account closed", session);
+                continue;
+            }
+        };
+        if head.original_tx_signature != attempt.original_tx_signature {
+            eprintln!("{}: event does not match the session account", session);
+            continue;
+        }
+        if head.status != RetrySessionStatus::InProgress || head.current_attempt != attempt.attempt {
+            eprintln!("{}: attempt {} already settled", session, attempt.attempt);
+            continue;
+        }
+
+        let report = match resubmitter.resubmit(&attempt) {
+            Ok(report) => report,
+            Err(e) => {
+                eprintln!("{}: {:#}", session, e);
+                continue;
+            }
+        };
+        eprintln!(
+            "{}: session {} attempt {} {:?} {}",
+            session, head.session_id, report.attempt, report.result, report.tx_signature,
+        );
+
+        // The attempt is out; failing to report it would leave the session
+        // waiting, and a rerun would send the same intent again
+        let signature = send_report(&rpc, &program_id, &session, &keypair, &report)
+            .with_context(|| format!("{}: attempt {} sent as {} but not reported", session, report.attempt, report.tx_signature))?;
+        println!("{}", signature);
+    }
+
+    Ok(())
+}
+
+/// Post an outcome report, with a fresh blockhash each try
+fn send_report(
+    rpc: &Rpc,
+    program_id: &Pubkey,
+    session: &Pubkey,
+    keypair: &Keypair,
+    report: &RetryOutcomeReport,
+) -> Result<Signature> {
+    let mut last_error = None;
+
+    for _ in 0..REPORT_TRIES {
+        let (blockhash, last_valid_block_height) = rpc.latest_blockhash()?;
+        let mut tx = report::build_report_transaction(
+            program_id,
+            session,
+            &keypair.pubkey(),
+            report,
+            blockhash,
+        )?;
+        tx.sign(&[keypair], blockhash);
+        if let Err(rejection) = rpc.send_transaction(&tx)? {
+            bail!("report rejected: {}", rejection);
+        }
+        match resubmit::confirm(rpc, &tx.signatures[0], last_valid_block_height) {
+            Ok(()) => return Ok(tx.signatures[0]),
+            Err(e) => last_error = Some(e),
+        }
+    }
+
+    Err(last_error.unwrap_or_else(|| anyhow!("report not sent")))
+}
@@ -0,0 +1,138 @@
+use anyhow::{bail, Result};
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_sdk::{
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    transaction::Transaction,
+};
+
+use crate::events::discriminator;
+
+/// Mirrors the on-chain `AttemptResult`
+#[derive(BorshSerialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttemptResult {
+    Success,
+    Failed,
+}
+
+/// Mirrors the on-chain `RetryFailureReason`; variant order must match
+#[derive(BorshSerialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RetryFailureReason {
+    NetworkTimeout,
+    InsufficientComputeUnits,
+    InsufficientPriorityFee,
+    BlockhashExpired,
+    AccountNotFound,
+    InsufficientFunds,
+    SimulationFailed,
+    NodeOverloaded,
+    UnknownError,
+}
+
+/// Mirrors the on-chain `CongestionLevel`
+#[derive(BorshSerialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CongestionLevel {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+/// Mirrors the on-chain `NetworkConditions`
+#[derive(BorshSerialize, Clone, Debug, PartialEq, Eq)]
+pub struct NetworkConditions {
+    pub congestion_level: CongestionLevel,
+    pub average_confirmation_time_ms: u32,
+    pub current_base_fee: u64,
+    pub suggested_priority_fee: u64,
+    pub recommended_compute_units: u32,
+    pub network_stability_score: u8,
+}
+
+/// Mirrors the on-chain `RetryOutcomeReport`
+#[derive(BorshSerialize, Clone, Debug, PartialEq, Eq)]
+pub struct RetryOutcomeReport {
+    pub attempt: u8,
+    pub result: AttemptResult,
+    pub tx_signature: String,
+    pub failure_reason: Option<RetryFailureReason>,
+    pub compute_units_used: u32,
+    pub fees_spent: u64,
+    pub network: Option<NetworkConditions>,
+}
+
+/// Mirrors the on-chain `RetrySessionStatus`
+#[derive(BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RetrySessionStatus {
+    Scheduled,
+    InProgress,
+    Successful,
+    Failed,
+    Cancelled,
+    Paused,
+}
+
+#[derive(BorshDeserialize)]
+struct RetryConfig {
+    _max_attempts: u8,
+    _initial_delay_seconds: u16,
+    _backoff_multiplier_bps: u16,
+    _max_delay_seconds: u32,
+    _jitter_percentage_bps: u16,
+    _compute_unit_adjustment_pct: i16,
+    _priority_fee_adjustment_pct: i16,
+    _adaptive_adjustments: bool,
+}
+
+/// Leading fields of the on-chain `RetrySession` account, enough to tell
+/// whether an attempt is still waiting for its outcome
+#[derive(BorshDeserialize)]
+pub struct RetrySessionHead {
+    pub session_id: u64,
+    pub original_tx_signature: String,
+    _retry_config: RetryConfig,
+    pub current_attempt: u8,
+    pub status: RetrySessionStatus,
+}
+
+impl RetrySessionHead {
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        if data.len() < 8 || data[..8] != discriminator("account", "RetrySession") {
+            bail!("not a retry session account");
+        }
+        // Only the head is decoded; the rest of the account is left unread
+        Ok(Self::deserialize(&mut &data[8..])?)
+    }
+}
+
+/// Build the unsigned `report_retry_outcome` transaction for an attempt,
+/// paid for and signed by the reporter
+pub fn build_report_transaction(
+    program_id: &Pubkey,
+    session: &Pubkey,
+    reporter: &Pubkey,
+    report: &RetryOutcomeReport,
+    recent_blockhash: Hash,
+) -> Result<Transaction> {
+    let (retry_manager, _) = Pubkey::find_program_address(&[b"retry_manager"], program_id);
+    let (retry_queue, _) = Pubkey::find_program_address(&[b"retry_queue"], program_id);
+
+    let mut data = discriminator("global", "report_retry_outcome").to_vec();
+    data.extend(report.try_to_vec()?);
+
+    let instruction = Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(retry_manager, false),
+            AccountMeta::new(*session, false),
+            AccountMeta::new(retry_queue, false),
+            AccountMeta::new_readonly(*reporter, true),
+        ],
+        data,
+    };
+
+    let mut tx = Transaction::new_with_payer(&[instruction], Some(reporter));
+    tx.message.recent_blockhash = recent_blockhash;
+    Ok(tx)
+}
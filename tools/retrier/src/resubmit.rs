@@ -0,0 +1,346 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, bail, Result};
+use solana_sdk::{
+    compute_budget::{self, ComputeBudgetInstruction},
+    instruction::{AccountMeta, Instruction},
+    message::{Message, VersionedMessage},
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+
+use crate::events::RetryAttemptStarted;
+use crate::report::{AttemptResult, CongestionLevel, NetworkConditions, RetryFailureReason, RetryOutcomeReport};
+use crate::rpc::Rpc;
+
+/// Lamports charged per signature, before priority fees
+const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+
+/// How often the status of a resubmitted transaction is polled
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Headroom added to the compute units an attempt consumed when
+/// recommending a limit
+const COMPUTE_HEADROOM_PCT: u64 = 10;
+
+/// Re-create the instructions of a legacy message, dropping its compute
+/// budget instructions and prepending ones for the given limit and price
+pub fn rebuild_instructions(
+    message: &Message,
+    compute_unit_limit: u32,
+    priority_fee_micro_lamports: u64,
+) -> Result<Vec<Instruction>> {
+    let mut instructions = vec![
+        ComputeBudgetInstruction::set_compute_unit_limit(compute_unit_limit),
+        ComputeBudgetInstruction::set_compute_unit_price(priority_fee_micro_lamports),
+    ];
+
+    for compiled in &message.instructions {
+        let program_id = *message
+            .account_keys
+            .get(compiled.program_id_index as usize)
+            .ok_or_else(|| anyhow!("instruction program index out of range"))?;
+        if program_id == compute_budget::id() {
+            continue;
+        }
+
+        let accounts = compiled
+            .accounts
+            .iter()
+            .map(|&index| {
+                let index = index as usize;
+                let pubkey = *message
+                    .account_keys
+                    .get(index)
+                    .ok_or_else(|| anyhow!("instruction account index out of range"))?;
+                Ok(AccountMeta {
+                    pubkey,
+                    is_signer: message.is_signer(index),
+                    is_writable: message.is_writable(index),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        instructions.push(Instruction { program_id, accounts, data: compiled.data.clone() });
+    }
+
+    Ok(instructions)
+}
+
+/// Map a transaction or RPC error to the failure reason the program tracks
+pub fn classify_failure(error: &str) -> RetryFailureReason {
+    let error = error.to_ascii_lowercase();
+
+    if error.contains("blockhashnotfound") || error.contains("blockhash not found") {
+        RetryFailureReason::BlockhashExpired
+    } else if error.contains("computationalbudgetexceeded") || error.contains("exceeded cus meter") {
+        RetryFailureReason::InsufficientComputeUnits
+    } else if error.contains("insufficientfunds") || error.contains("insufficient funds") {
+        RetryFailureReason::InsufficientFunds
+    } else if error.contains("accountnotfound") {
+        RetryFailureReason::AccountNotFound
+    } else if error.contains("node is behind") || error.contains("node is unhealthy") {
+        RetryFailureReason::NodeOverloaded
+    } else if error.contains("simulation failed") {
+        RetryFailureReason::SimulationFailed
+    } else {
+        RetryFailureReason::UnknownError
+    }
+}
+
+/// Describe the network as one attempt saw it: how long it took to land
+/// (or to give up), recent priority fees on the accounts it writes, and the
+/// compute it needed
+pub fn network_conditions(
+    confirmation_time: Duration,
+    recent_priority_fees: &[u64],
+    recommended_compute_units: u32,
+) -> NetworkConditions {
+    let confirmation_ms = u32::try_from(confirmation_time.as_millis()).unwrap_or(u32::MAX);
+    let (congestion_level, network_stability_score) = match confirmation_ms {
+        0..=1_499 => (CongestionLevel::Low, 100),
+        1_500..=3_999 => (CongestionLevel::Medium, 80),
+        4_000..=9_999 => (CongestionLevel::High, 60),
+        _ => (CongestionLevel::Critical, 30),
+    };
+
+    // 75th percentile of what recent transactions on the same accounts paid
+    let mut fees = recent_priority_fees.to_vec();
+    fees.sort_unstable();
+    let suggested_priority_fee = fees.get(fees.len() * 3 / 4).copied().unwrap_or(0);
+
+    NetworkConditions {
+        congestion_level,
+        average_confirmation_time_ms: confirmation_ms,
+        current_base_fee: LAMPORTS_PER_SIGNATURE,
+        suggested_priority_fee,
+        recommended_compute_units,
+        network_stability_score,
+    }
+}
+
+/// How a resubmitted transaction ended up
+enum Landing {
+    Landed,
+    Failed(String),
+    Rejected(String),
+    Expired,
+}
+
+/// Rebuilds failed transactions with an attempt's compute budget, resubmits
+/// them and works out the outcome to report
+pub struct Resubmitter<'a> {
+    rpc: &'a Rpc,
+    payer: &'a Keypair,
+}
+
+impl<'a> Resubmitter<'a> {
+    pub fn new(rpc: &'a Rpc, payer: &'a Keypair) -> Self {
+        Self { rpc, payer }
+    }
+
+    /// Resubmit the transaction behind an attempt and work out the outcome.
+    /// Errors mean nothing was sent, so the attempt is left unreported.
+    pub fn resubmit(&self, attempt: &RetryAttemptStarted) -> Result<RetryOutcomeReport> {
+        let original: Signature = attempt
+            .original_tx_signature
+            .parse()
+            .map_err(|e| anyhow!("invalid original signature: {:?}", e))?;
+        let fetched = self
+            .rpc
+            .transaction(&original)?
+            .ok_or_else(|| anyhow!("original transaction {} not found", original))?;
+
+        // The original landed after all; there is nothing to resubmit
+        if fetched.err.is_none() {
+            return Ok(RetryOutcomeReport {
+                attempt: attempt.attempt,
+                result: AttemptResult::Success,
+                tx_signature: original.to_string(),
+                failure_reason: None,
+                compute_units_used: 0,
+                fees_spent: 0,
+                network: None,
+            });
+        }
+
+        let message = match &fetched.transaction.message {
+            VersionedMessage::Legacy(message) => message,
+            VersionedMessage::V0(_) => bail!("versioned transactions with lookup tables are not supported"),
+        };
+        if message.header.num_required_signatures != 1 || message.account_keys.first() != Some(&self.payer.pubkey()) {
+            bail!("transaction {} needs signers other than the retrier's keypair", original);
+        }
+
+        let instructions =
+            rebuild_instructions(message, attempt.compute_unit_limit, attempt.priority_fee_micro_lamports)?;
+        let writable: Vec<Pubkey> = message
+            .account_keys
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| message.is_writable(*index))
+            .map(|(_, key)| *key)
+            .collect();
+
+        let (blockhash, last_valid_block_height) = self.rpc.latest_blockhash()?;
+        let tx = Transaction::new_signed_with_payer(&instructions, Some(&self.payer.pubkey()), &[self.payer], blockhash);
+        let signature = tx.signatures[0];
+
+        let started = Instant::now();
+        let landing = self.submit_and_wait(&tx, &signature, last_valid_block_height);
+        let confirmation_time = started.elapsed();
+
+        let mut report = RetryOutcomeReport {
+            attempt: attempt.attempt,
+            result: AttemptResult::Failed,
+            tx_signature: signature.to_string(),
+            failure_reason: None,
+            compute_units_used: 0,
+            fees_spent: 0,
+            network: None,
+        };
+        match &landing {
+            Landing::Landed => report.result = AttemptResult::Success,
+            Landing::Failed(error) | Landing::Rejected(error) => {
+                report.failure_reason = Some(classify_failure(error))
+            }
+            Landing::Expired => report.failure_reason = Some(RetryFailureReason::NetworkTimeout),
+        }
+
+        // Executed transactions pay fees and burn compute whether or not they
+        // succeed. The attempt is already out, so these lookups must not fail it.
+        let mut recommended_compute_units = attempt.compute_unit_limit;
+        if matches!(landing, Landing::Landed | Landing::Failed(_)) {
+            if let Ok(Some(landed)) = self.rpc.transaction(&signature) {
+                let consumed = landed.compute_units_consumed.unwrap_or(0);
+                report.compute_units_used = consumed.min(u32::MAX as u64) as u32;
+                report.fees_spent = landed.fee;
+                if consumed > 0 && report.failure_reason != Some(RetryFailureReason::InsufficientComputeUnits) {
+                    let with_headroom = consumed * (100 + COMPUTE_HEADROOM_PCT) / 100;
+                    recommended_compute_units = with_headroom.min(u32::MAX as u64) as u32;
+                }
+            }
+        }
+        let recent_fees = self.rpc.recent_priority_fees(&writable).unwrap_or_default();
+        let network = network_conditions(confirmation_time, &recent_fees, recommended_compute_units);
+        // Outbid while it waited: blame the fee rather than the network
+        if matches!(landing, Landing::Expired) && attempt.priority_fee_micro_lamports < network.suggested_priority_fee {
+            report.failure_reason = Some(RetryFailureReason::InsufficientPriorityFee);
+        }
+        report.network = Some(network);
+
+        Ok(report)
+    }
+
+    /// Send a transaction and poll it until it lands, fails, or its
+    /// blockhash expires. Expiry is only declared once the transaction can
+    /// no longer land, so a later attempt never executes it twice.
+    fn submit_and_wait(&self, tx: &Transaction, signature: &Signature, last_valid_block_height: u64) -> Landing {
+        match self.rpc.send_transaction(tx) {
+            Ok(Ok(())) => {}
+            Ok(Err(rejection)) => return Landing::Rejected(rejection),
+            // The node may still have received it; keep polling
+            Err(e) => eprintln!("{}: sending failed: {:#}", signature, e),
+        }
+
+        match poll(self.rpc, signature, last_valid_block_height) {
+            Some(Ok(())) => Landing::Landed,
+            Some(Err(error)) => Landing::Failed(error),
+            None => Landing::Expired,
+        }
+    }
+}
+
+/// Poll a sent transaction until it is confirmed (`Some(Ok)`), fails
+/// (`Some(Err)` with the error), or its blockhash expires (`None`). RPC
+/// errors are retried: giving up early could let a later attempt execute
+/// the same intent twice.
+fn poll(rpc: &Rpc, signature: &Signature, last_valid_block_height: u64) -> Option<std::result::Result<(), String>> {
+    loop {
+        // Height is read before the status, so a landing in between isn't missed
+        let checked = rpc
+            .block_height()
+            .and_then(|height| Ok((height > last_valid_block_height, rpc.signature_status(signature)?)));
+        match checked {
+            Ok((_, Some(status))) if status.err.is_some() => {
+                return Some(Err(status.err.map(|err| err.to_string()).unwrap_or_default()));
+            }
+            Ok((_, Some(status))) if status.is_confirmed() => return Some(Ok(())),
+            Ok((true, _)) => return None,
+            Ok(_) => {}
+            Err(e) => eprintln!("{}: polling failed: {:#}", signature, e),
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Wait until a transaction is confirmed, failing if it errors or its
+/// blockhash expires first
+pub fn confirm(rpc: &Rpc, signature: &Signature, last_valid_block_height: u64) -> Result<()> {
+    match poll(rpc, signature, last_valid_block_height) {
+        Some(Ok(())) => Ok(()),
+        Some(Err(error)) => bail!("transaction {} failed: {}", signature, error),
+        None => bail!("transaction {} expired before confirming", signature),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rebuild_instructions() {
+        let payer = Pubkey::new_unique();
+        let program = Pubkey::new_unique();
+        let writable = Pubkey::new_unique();
+        let readonly = Pubkey::new_unique();
+        let original = Instruction {
+            program_id: program,
+            accounts: vec![
+                AccountMeta::new(payer, true),
+                AccountMeta::new(writable, false),
+                AccountMeta::new_readonly(readonly, false),
+            ],
+            data: vec![1, 2, 3],
+        };
+        let message = Message::new(
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(200_000),
+                original.clone(),
+            ],
+            Some(&payer),
+        );
+
+        let rebuilt = rebuild_instructions(&message, 240_000, 1_500).unwrap();
+        assert_eq!(
+            rebuilt,
+            vec![
+                ComputeBudgetInstruction::set_compute_unit_limit(240_000),
+                ComputeBudgetInstruction::set_compute_unit_price(1_500),
+                original,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_classify_failure() {
+        let cases = [
+            ("Transaction simulation failed: Blockhash not found", RetryFailureReason::BlockhashExpired),
+            (r#"{"InstructionError":[1,"ComputationalBudgetExceeded"]}"#, RetryFailureReason::InsufficientComputeUnits),
+            (r#""InsufficientFundsForFee""#, RetryFailureReason::InsufficientFunds),
+            (r#""AccountNotFound""#, RetryFailureReason::AccountNotFound),
+            ("Node is behind by 42 slots", RetryFailureReason::NodeOverloaded),
+            ("Transaction simulation failed: Error processing Instruction 0", RetryFailureReason::SimulationFailed),
+            (r#"{"InstructionError":[0,{"Custom":6001}]}"#, RetryFailureReason::UnknownError),
+        ];
+        for (error, reason) in cases {
+            assert_eq!(classify_failure(error), reason, "{}", error);
+        }
+
+        let network = network_conditions(Duration::from_millis(5_000), &[0, 100, 2_000, 500], 220_000);
+        assert_eq!(network.congestion_level, CongestionLevel::High);
+        assert_eq!(network.suggested_priority_fee, 2_000);
+        assert_eq!(network.recommended_compute_units, 220_000);
+    }
+}
@@ -0,0 +1,176 @@
+use anyhow::{anyhow, bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use solana_sdk::{
+    hash::Hash,
+    pubkey::Pubkey,
+    signature::Signature,
+    transaction::{Transaction, VersionedTransaction},
+};
+
+/// A confirmed transaction fetched by signature
+pub struct FetchedTransaction {
+    pub transaction: VersionedTransaction,
+    pub fee: u64,
+    pub compute_units_consumed: Option<u64>,
+    /// Execution error, if the transaction failed
+    pub err: Option<Value>,
+}
+
+/// Processing status of a submitted signature
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureStatus {
+    pub err: Option<Value>,
+    pub confirmation_status: Option<String>,
+}
+
+impl SignatureStatus {
+    pub fn is_confirmed(&self) -> bool {
+        matches!(self.confirmation_status.as_deref(), Some("confirmed") | Some("finalized"))
+    }
+}
+
+#[derive(Deserialize)]
+struct Contextual<T> {
+    value: T,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LatestBlockhash {
+    blockhash: String,
+    last_valid_block_height: u64,
+}
+
+#[derive(Deserialize)]
+struct AccountInfo {
+    data: (String, String),
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionMeta {
+    err: Option<Value>,
+    fee: u64,
+    compute_units_consumed: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct TransactionResponse {
+    transaction: (String, String),
+    meta: Option<TransactionMeta>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PrioritizationFee {
+    prioritization_fee: u64,
+}
+
+/// Minimal Solana JSON-RPC client
+pub struct Rpc {
+    url: String,
+}
+
+impl Rpc {
+    pub fn new(url: String) -> Self {
+        Self { url }
+    }
+
+    /// Send a request, returning the JSON-RPC error object as `Ok(Err(..))`
+    /// when the node answers with one
+    fn request(&self, method: &str, params: Value) -> Result<std::result::Result<Value, Value>> {
+        let mut response: Value = ureq::post(&self.url)
+            .send_json(json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params }))
+            .with_context(|| format!("calling {}", method))?
+            .into_json()
+            .with_context(|| format!("reading {} response", method))?;
+
+        if let Some(error) = response.get_mut("error") {
+            return Ok(Err(error.take()));
+        }
+        Ok(Ok(response["result"].take()))
+    }
+
+    fn call<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T> {
+        match self.request(method, params)? {
+            Ok(result) => serde_json::from_value(result)
+                .with_context(|| format!("unexpected {} response", method)),
+            Err(error) => bail!("{} failed: {}", method, error),
+        }
+    }
+
+    pub fn account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>> {
+        let info: Contextual<Option<AccountInfo>> = self.call(
+            "getAccountInfo",
+            json!([address.to_string(), { "encoding": "base64", "commitment": "confirmed" }]),
+        )?;
+        info.value
+            .map(|account| STANDARD.decode(account.data.0).context("invalid account data"))
+            .transpose()
+    }
+
+    pub fn transaction(&self, signature: &Signature) -> Result<Option<FetchedTransaction>> {
+        let response: Option<TransactionResponse> = self.call(
+            "getTransaction",
+            json!([signature.to_string(), {
+                "encoding": "base64",
+                "commitment": "confirmed",
+                "maxSupportedTransactionVersion": 0,
+            }]),
+        )?;
+        let Some(response) = response else {
+            return Ok(None);
+        };
+
+        let bytes = STANDARD.decode(response.transaction.0).context("invalid transaction data")?;
+        let transaction = bincode::deserialize(&bytes).context("invalid transaction")?;
+        let meta = response.meta.ok_or_else(|| anyhow!("transaction {} has no status meta", signature))?;
+        Ok(Some(FetchedTransaction {
+            transaction,
+            fee: meta.fee,
+            compute_units_consumed: meta.compute_units_consumed,
+            err: meta.err,
+        }))
+    }
+
+    /// Latest blockhash and the last block height a transaction using it can land in
+    pub fn latest_blockhash(&self) -> Result<(Hash, u64)> {
+        let latest: Contextual<LatestBlockhash> =
+            self.call("getLatestBlockhash", json!([{ "commitment": "confirmed" }]))?;
+        let blockhash = latest.value.blockhash.parse().map_err(|e| anyhow!("invalid blockhash: {:?}", e))?;
+        Ok((blockhash, latest.value.last_valid_block_height))
+    }
+
+    pub fn block_height(&self) -> Result<u64> {
+        self.call("getBlockHeight", json!([{ "commitment": "confirmed" }]))
+    }
+
+    /// Submit a signed transaction. `Ok(Err(message))` means the node
+    /// rejected it, for example in preflight, so it can never land.
+    pub fn send_transaction(&self, tx: &Transaction) -> Result<std::result::Result<(), String>> {
+        let encoded = STANDARD.encode(bincode::serialize(tx)?);
+        let response = self.request(
+            "sendTransaction",
+            json!([encoded, { "encoding": "base64", "preflightCommitment": "confirmed" }]),
+        )?;
+        Ok(response.map(|_| ()).map_err(|error| error.to_string()))
+    }
+
+    pub fn signature_status(&self, signature: &Signature) -> Result<Option<SignatureStatus>> {
+        let statuses: Contextual<Vec<Option<SignatureStatus>>> =
+            self.call("getSignatureStatuses", json!([[signature.to_string()]]))?;
+        Ok(statuses.value.into_iter().next().flatten())
+    }
+
+    /// Priority fees (micro-lamports per compute unit) paid in recent blocks
+    /// by transactions writing any of the given accounts
+    pub fn recent_priority_fees(&self, accounts: &[Pubkey]) -> Result<Vec<u64>> {
+        let accounts: Vec<String> = accounts.iter().map(Pubkey::to_string).collect();
+        let fees: Vec<PrioritizationFee> = self.call("getRecentPrioritizationFees", json!([accounts]))?;
+        Ok(fees.into_iter().map(|fee| fee.prioritization_fee).collect())
+    }
+}